@@ -0,0 +1,771 @@
+use crate::command_helper::{self, BuildOptions, Dependency, Template};
+use crate::llvm::doc::DocFormat;
+
+// The command line `sprs <command> [arguments]`. It is parsed completely before anything runs,
+// so a misspelled option stops with the usage of the command instead of being ignored.
+
+// Exit codes: 0 success, 1 the command failed, 2 the command line was wrong.
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+
+pub enum Command {
+    New {
+        path: String,
+        template: Template,
+    },
+    Init {
+        name: Option<String>,
+        template: Template,
+    },
+    Build(BuildOptions),
+    // `file` is a single .sprs file to run without a project
+    Run {
+        file: Option<String>,
+        options: BuildOptions,
+        watch: bool, // --watch: rebuild and restart on every change of the sources
+    },
+    Flash(BuildOptions),
+    Test {
+        options: BuildOptions,
+        runner: Option<String>,
+        examples: bool, // --examples: the golden-output programs instead of test_* functions
+    },
+    Check(BuildOptions),
+    Debug(BuildOptions),
+    Lint,
+    Fix,
+    Migrate,
+    Clean {
+        all: bool,
+    },
+    Rename {
+        symbol: String,
+        to: String,
+        file: String,
+    },
+    FuzzGrammar {
+        iterations: usize,
+        seed: Option<u64>,
+    },
+    Add {
+        name: String,
+        dependency: Dependency,
+    },
+    Remove {
+        name: String,
+    },
+    Index {
+        output: Option<String>,
+    },
+    Repl,
+    Lsp,
+    // --tokens and/or --ast of one file
+    Dump {
+        file: String,
+        tokens: bool,
+        ast: bool,
+    },
+    Doc {
+        format: DocFormat,
+        output: Option<String>,
+        private: bool,
+    },
+    DecodePanic {
+        code: String,
+        table: Option<String>,
+    },
+    // without a code, the list of codes
+    Explain {
+        code: Option<String>,
+    },
+    Help {
+        command: Option<&'static CommandInfo>,
+        all: bool,
+    },
+    Version,
+}
+
+// A command line that was not accepted; `command` is the one whose usage to print.
+pub struct UsageError {
+    pub message: String,
+    pub command: Option<&'static CommandInfo>,
+}
+
+pub struct CommandInfo {
+    pub name: &'static str,
+    usage: &'static str, // the arguments after the name
+    about: &'static str,
+    options: &'static [(&'static str, &'static str)], // besides the build options
+}
+
+const TEMPLATE_OPTION: (&str, &str) = (
+    "--template <name>",
+    "Project template: cli (default), embedded, lib",
+);
+
+const COMMANDS: &[CommandInfo] = &[
+    CommandInfo {
+        name: "init",
+        usage: "[--name <name>] [--template <name>]",
+        about: "Initialize the project in the current directory",
+        options: &[
+            ("--name <name>", "Set the name of the project"),
+            TEMPLATE_OPTION,
+        ],
+    },
+    CommandInfo {
+        name: "new",
+        usage: "<name> [--template <name>]",
+        about: "Create the directory <name> with a new project in it",
+        options: &[TEMPLATE_OPTION],
+    },
+    CommandInfo {
+        name: "build",
+        usage: "[options]",
+        about: "Build the project",
+        options: &[],
+    },
+    CommandInfo {
+        name: "run",
+        usage: "[<file.sprs>] [options] [--watch]",
+        about: "Run the project, or build and run a single file without a project",
+        options: &[(
+            "--watch",
+            "Rebuild and restart the program whenever a source file or sprs.toml changes",
+        )],
+    },
+    CommandInfo {
+        name: "flash",
+        usage: "[options]",
+        about: "Build the project and run flash.command from sprs.toml",
+        options: &[],
+    },
+    CommandInfo {
+        name: "test",
+        usage: "[options] [--runner <cmd>] [--examples]",
+        about: "Build and run the test_* functions of main.sprs",
+        options: &[
+            (
+                "--examples",
+                "Run the programs under tests/ (or examples/) and compare their output with the .expected files",
+            ),
+            (
+                "--runner <cmd>",
+                "Run the test image through <cmd>, {file} is its path",
+            ),
+        ],
+    },
+    CommandInfo {
+        name: "check",
+        usage: "[options]",
+        about: "Report the errors and warnings of a build without generating code or linking",
+        options: &[],
+    },
+    CommandInfo {
+        name: "debug",
+        usage: "[options]",
+        about: "Build the project in debug mode (the interpreter is not supported yet)",
+        options: &[],
+    },
+    CommandInfo {
+        name: "repl",
+        usage: "",
+        about: "Evaluate statements, items and expressions one at a time with the interpreter",
+        options: &[],
+    },
+    CommandInfo {
+        name: "lsp",
+        usage: "",
+        about: "Run a language server on stdin/stdout for editors",
+        options: &[],
+    },
+    CommandInfo {
+        name: "lint",
+        usage: "",
+        about: "Check the project with the lint rules from sprs.toml",
+        options: &[],
+    },
+    CommandInfo {
+        name: "fix",
+        usage: "",
+        about: "Apply the suggested fixes (missing `;`, unused names) to the sources",
+        options: &[],
+    },
+    CommandInfo {
+        name: "rename",
+        usage: "--symbol <old> --to <new> <file>",
+        about: "Rename a function, struct, variable or module everywhere",
+        options: &[
+            ("--symbol <old>", "The name to replace"),
+            ("--to <new>", "The new name"),
+        ],
+    },
+    CommandInfo {
+        name: "fuzz-grammar",
+        usage: "[--iterations <n>] [--seed <s>]",
+        about: "Compile generated programs and report compiler panics",
+        options: &[
+            ("--iterations <n>", "Number of programs, 1000 by default"),
+            ("--seed <s>", "Seed of the generator, random by default"),
+        ],
+    },
+    CommandInfo {
+        name: "migrate",
+        usage: "",
+        about: "Move the sources to the next edition and update sprs.toml",
+        options: &[],
+    },
+    CommandInfo {
+        name: "clean",
+        usage: "[--all]",
+        about: "Remove the output directory and its cache",
+        options: &[("--all", "Also remove the git dependencies in vendor/")],
+    },
+    CommandInfo {
+        name: "add",
+        usage: "<name> --path <dir> | <name> --git <url> [--rev <rev>]",
+        about: "Add a dependency to the [dependencies] of sprs.toml",
+        options: &[
+            ("--path <dir>", "The Sprs project in <dir>"),
+            (
+                "--git <url>",
+                "A git repository, fetched into vendor/ by the next build",
+            ),
+            ("--rev <rev>", "Branch, tag or commit of the repository"),
+        ],
+    },
+    CommandInfo {
+        name: "remove",
+        usage: "<name>",
+        about: "Remove a dependency from sprs.toml",
+        options: &[],
+    },
+    CommandInfo {
+        name: "index",
+        usage: "[--output <path>]",
+        about: "Write the definitions and references of the project to <out_dir>/index.json",
+        options: &[("--output <path>", "Write the index to <path> instead")],
+    },
+    CommandInfo {
+        name: "doc",
+        usage: "[--format html|markdown] [--output <dir>] [--private]",
+        about: "Write the documentation of every package from its ## comments to <out_dir>/doc",
+        options: &[
+            ("--format <format>", "html (default) or markdown"),
+            ("--output <dir>", "Write the pages to <dir> instead"),
+            ("--private", "Also document the items that are not pub"),
+        ],
+    },
+    CommandInfo {
+        name: "dump",
+        usage: "--tokens|--ast <file>",
+        about: "Print the tokens or the AST of a file, with their spans",
+        options: &[
+            ("--tokens", "The tokens from the lexer"),
+            ("--ast", "The parsed items"),
+        ],
+    },
+    CommandInfo {
+        name: "decode-panic",
+        usage: "<code> [--table <path>]",
+        about: "Look up a --panic-codes panic in <name>.panics.json",
+        options: &[(
+            "--table <path>",
+            "The panic table, <out_dir>/<name>.panics.json by default",
+        )],
+    },
+    CommandInfo {
+        name: "explain",
+        usage: "[<code>]",
+        about: "Describe the compiler error <code> (e.g. E0003) with an example, or list the codes",
+        options: &[],
+    },
+    CommandInfo {
+        name: "help",
+        usage: "[<command>] [--all]",
+        about: "Show this help message, or the options of <command>",
+        options: &[("--all", "Show all available commands and options")],
+    },
+    CommandInfo {
+        name: "version",
+        usage: "",
+        about: "Show compiler version",
+        options: &[],
+    },
+];
+
+// The options of `parse_build_options` and the commands they make a difference for.
+const BUILD_OPTIONS: &[(&str, &str, &[&str])] = &[
+    (
+        "--overflow-checks",
+        "Panic on integer overflow",
+        &["build", "run", "flash", "debug"],
+    ),
+    (
+        "--emit-header",
+        "Write the C header sprs.h to the output directory",
+        &["build", "run", "flash", "debug"],
+    ),
+    (
+        "--deny-warnings",
+        "Fail the build when there are warnings",
+        &["build", "run", "flash", "test", "check"],
+    ),
+    (
+        "--panic-codes",
+        "Replace panic messages with 16-bit codes and write <name>.panics.json",
+        &["build", "run", "flash", "test"],
+    ),
+    (
+        "--compress-strings",
+        "Store long string literals compressed, unpacked on first use",
+        &["build", "run", "flash", "test"],
+    ),
+    (
+        "--debug-alloc",
+        "Report the lists, ranges, structs and strings still allocated when the program exits",
+        &["build", "run"],
+    ),
+    (
+        "--no-std",
+        "Build the freestanding runtime without std or libc and skip linking",
+        &["build", "run"],
+    ),
+    (
+        "--release",
+        "Leave out the assert! checks and optimize as -O2",
+        &["build", "run", "flash", "test"],
+    ),
+    (
+        "--emit=<kinds>",
+        "Write only these artifacts to the output directory: ir (.ll), asm (.s), obj (.o), exe; comma-separated",
+        &["build"],
+    ),
+    (
+        "--size-report",
+        "Print the code size of every function and the text/data/bss totals of the objects",
+        &["build", "run", "flash", "test"],
+    ),
+    (
+        "--debug",
+        "Emit DWARF debug info, so gdb and lldb can step through the .sprs source",
+        &["build", "run", "flash", "test"],
+    ),
+    (
+        "-O0..-O3",
+        "Optimization level, -O is -O2; -O0 (the default) only promotes locals to registers",
+        &["build", "run", "flash", "test"],
+    ),
+    (
+        "--target <triple>",
+        "Compile for <triple> instead of the #define target",
+        &["build", "run", "flash", "test", "check"],
+    ),
+    (
+        "--bin <name>",
+        "Build the [[bin]] <name> of sprs.toml; build and check alone take all of them",
+        &["build", "run", "flash", "test", "check"],
+    ),
+    (
+        "-D <name>=<value>",
+        "Define a symbol for #if in every module",
+        &["build", "run", "debug", "flash", "test", "check"],
+    ),
+    (
+        "-v, --verbose",
+        "Also print the time of every build phase and the commands run",
+        &["build", "run", "flash", "test", "check"],
+    ),
+    (
+        "-q, --quiet",
+        "Print nothing but errors and warnings",
+        &["build", "run", "flash", "test", "check"],
+    ),
+];
+
+fn find(name: &str) -> Option<&'static CommandInfo> {
+    COMMANDS.iter().find(|c| c.name == name)
+}
+
+// `args` is the command line without the program name.
+pub fn parse(args: &[String]) -> Result<Command, UsageError> {
+    let Some(name) = args.first() else {
+        return Ok(Command::Help {
+            command: None,
+            all: false,
+        });
+    };
+    match name.as_str() {
+        "--help" | "-h" => {
+            return Ok(Command::Help {
+                command: None,
+                all: false,
+            });
+        }
+        "--version" | "-V" => return Ok(Command::Version),
+        _ => {}
+    }
+    let Some(info) = find(name) else {
+        return Err(UsageError {
+            message: format!("Unknown command: {}", name),
+            command: None,
+        });
+    };
+    let rest = &args[1..];
+    if info.name != "help" && rest.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Ok(Command::Help {
+            command: Some(info),
+            all: false,
+        });
+    }
+    parse_command(info, rest).map_err(|message| UsageError {
+        message,
+        command: Some(info),
+    })
+}
+
+struct Args<'a> {
+    iter: std::slice::Iter<'a, String>,
+}
+
+impl<'a> Args<'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        self.iter.next().map(String::as_str)
+    }
+
+    // The value given after `option`.
+    fn value(&mut self, option: &str) -> Result<String, String> {
+        self.next()
+            .map(str::to_string)
+            .ok_or_else(|| format!("Missing value for {}", option))
+    }
+
+    fn number<T: std::str::FromStr>(&mut self, option: &str) -> Result<T, String> {
+        self.value(option)?
+            .parse()
+            .map_err(|_| format!("{} expects a number", option))
+    }
+
+    // The only argument left, which must not look like an option.
+    fn positional(&mut self, what: &str) -> Result<String, String> {
+        match self.next() {
+            Some(arg) if !arg.starts_with('-') => Ok(arg.to_string()),
+            Some(arg) => Err(unexpected(arg)),
+            None => Err(format!("Missing {}", what)),
+        }
+    }
+
+    fn end(&mut self) -> Result<(), String> {
+        match self.next() {
+            Some(arg) => Err(unexpected(arg)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn unexpected(arg: &str) -> String {
+    if arg.starts_with('-') {
+        format!("Unknown option: {}", arg)
+    } else {
+        format!("Unexpected argument: {}", arg)
+    }
+}
+
+fn parse_command(info: &'static CommandInfo, rest: &[String]) -> Result<Command, String> {
+    let mut args = Args { iter: rest.iter() };
+    let command = match info.name {
+        "new" => {
+            let path = args.positional("project directory")?;
+            let mut template = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--template" => template = Some(args.value(arg)?),
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::New {
+                path,
+                template: Template::parse(template.as_deref())?,
+            }
+        }
+        "init" => {
+            let mut name = None;
+            let mut template = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--name" => name = Some(args.value(arg)?),
+                    "--template" => template = Some(args.value(arg)?),
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::Init {
+                name,
+                template: Template::parse(template.as_deref())?,
+            }
+        }
+        "build" => Command::Build(command_helper::parse_build_options(rest)?),
+        "run" => {
+            // `sprs run <file.sprs>` runs a single file without a project
+            let file = rest.first().filter(|arg| arg.ends_with(".sprs")).cloned();
+            let options = if file.is_some() { &rest[1..] } else { rest };
+            let watch = options.iter().any(|arg| arg == "--watch");
+            let options: Vec<String> = options
+                .iter()
+                .filter(|arg| *arg != "--watch")
+                .cloned()
+                .collect();
+            Command::Run {
+                file,
+                options: command_helper::parse_build_options(&options)?,
+                watch,
+            }
+        }
+        "flash" => Command::Flash(command_helper::parse_build_options(rest)?),
+        "test" => {
+            let examples = rest.iter().any(|arg| arg == "--examples");
+            let rest: Vec<String> = rest
+                .iter()
+                .filter(|arg| *arg != "--examples")
+                .cloned()
+                .collect();
+            let (mut options, runner) = command_helper::parse_test_options(&rest)?;
+            // the examples are ordinary programs with a main
+            options.test = !examples;
+            Command::Test {
+                options,
+                runner,
+                examples,
+            }
+        }
+        "check" => Command::Check(command_helper::parse_build_options(rest)?),
+        "debug" => Command::Debug(command_helper::parse_build_options(rest)?),
+        "repl" => args.end().map(|_| Command::Repl)?,
+        "lsp" => args.end().map(|_| Command::Lsp)?,
+        "lint" => args.end().map(|_| Command::Lint)?,
+        "fix" => args.end().map(|_| Command::Fix)?,
+        "migrate" => args.end().map(|_| Command::Migrate)?,
+        "clean" => {
+            let mut all = false;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--all" => all = true,
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::Clean { all }
+        }
+        "rename" => {
+            let (mut symbol, mut to, mut file) = (None, None, None);
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--symbol" => symbol = Some(args.value(arg)?),
+                    "--to" => to = Some(args.value(arg)?),
+                    _ if arg.starts_with('-') || file.is_some() => return Err(unexpected(arg)),
+                    _ => file = Some(arg.to_string()),
+                }
+            }
+            Command::Rename {
+                symbol: symbol.ok_or("Missing --symbol")?,
+                to: to.ok_or("Missing --to")?,
+                file: file.ok_or("Missing file")?,
+            }
+        }
+        "fuzz-grammar" => {
+            let mut iterations = 1000;
+            let mut seed = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--iterations" => iterations = args.number(arg)?,
+                    "--seed" => seed = Some(args.number(arg)?),
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::FuzzGrammar { iterations, seed }
+        }
+        "add" => {
+            let name = args.positional("dependency name")?;
+            let mut dependency = Dependency::default();
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--path" => dependency.path = Some(args.value(arg)?),
+                    "--git" => dependency.git = Some(args.value(arg)?),
+                    "--rev" => dependency.rev = Some(args.value(arg)?),
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            match (&dependency.path, &dependency.git, &dependency.rev) {
+                (Some(_), None, None) | (None, Some(_), _) => {}
+                _ => return Err("Expected either --path or --git (with --rev)".to_string()),
+            }
+            Command::Add { name, dependency }
+        }
+        "remove" => {
+            let name = args.positional("dependency name")?;
+            args.end()?;
+            Command::Remove { name }
+        }
+        "index" => {
+            let mut output = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--output" => output = Some(args.value(arg)?),
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::Index { output }
+        }
+        "doc" => {
+            let mut format = DocFormat::Html;
+            let mut output = None;
+            let mut private = false;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--format" => format = DocFormat::parse(&args.value(arg)?)?,
+                    "--output" => output = Some(args.value(arg)?),
+                    "--private" => private = true,
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::Doc {
+                format,
+                output,
+                private,
+            }
+        }
+        "dump" => {
+            let (mut tokens, mut ast, mut file) = (false, false, None);
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--tokens" => tokens = true,
+                    "--ast" => ast = true,
+                    _ if arg.starts_with('-') || file.is_some() => return Err(unexpected(arg)),
+                    _ => file = Some(arg.to_string()),
+                }
+            }
+            if !tokens && !ast {
+                return Err("Expected --tokens or --ast".to_string());
+            }
+            Command::Dump {
+                file: file.ok_or("Missing file")?,
+                tokens,
+                ast,
+            }
+        }
+        "decode-panic" => {
+            let code = args.positional("panic code")?;
+            let mut table = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--table" => table = Some(args.value(arg)?),
+                    _ => return Err(unexpected(arg)),
+                }
+            }
+            Command::DecodePanic { code, table }
+        }
+        "explain" => {
+            let code = args.next().map(str::to_string);
+            if let Some(code) = &code {
+                if code.starts_with('-') {
+                    return Err(unexpected(code));
+                }
+            }
+            args.end()?;
+            Command::Explain { code }
+        }
+        "help" => {
+            let mut command = None;
+            let mut all = false;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--all" => all = true,
+                    _ if arg.starts_with('-') || command.is_some() => {
+                        return Err(unexpected(arg));
+                    }
+                    _ => {
+                        command =
+                            Some(find(arg).ok_or_else(|| format!("Unknown command: {}", arg))?)
+                    }
+                }
+            }
+            Command::Help { command, all }
+        }
+        "version" => args.end().map(|_| Command::Version)?,
+        _ => unreachable!("command {} has no parser", info.name),
+    };
+    Ok(command)
+}
+
+fn usage(info: &CommandInfo) -> String {
+    format!("sprs {} {}", info.name, info.usage)
+        .trim_end()
+        .to_string()
+}
+
+pub fn print_usage_error(error: &UsageError) {
+    eprintln!("error: {}", error.message);
+    match error.command {
+        Some(info) => {
+            eprintln!("Usage: {}", usage(info));
+            eprintln!("Run `sprs {} --help` for its options.", info.name);
+        }
+        None => eprintln!("Run `sprs help` for the list of commands."),
+    }
+}
+
+pub fn print_help(command: Option<&CommandInfo>, all: bool) {
+    match command {
+        Some(info) => print_command_help(info),
+        None => print_overview(all),
+    }
+}
+
+fn print_option(flag: &str, about: &str) {
+    println!("  {:<20} {}", flag, about);
+}
+
+fn print_overview(all: bool) {
+    println!("Sprs Compiler{}:", if all { " Full Help" } else { " Help" });
+    println!("Usage: sprs <command> [arguments]");
+    println!();
+    println!("Commands:");
+    for info in COMMANDS {
+        print_option(info.name, info.about);
+    }
+    if all {
+        for info in COMMANDS.iter().filter(|info| !info.options.is_empty()) {
+            println!();
+            println!("Options of {}:", info.name);
+            for (flag, about) in info.options {
+                print_option(flag, about);
+            }
+        }
+        println!();
+        println!("Build options:");
+        for (flag, about, commands) in BUILD_OPTIONS {
+            print_option(flag, &format!("{} ({})", about, commands.join("/")));
+        }
+        println!();
+        println!("This is the Sprs compiler, a simple compiler for the Sprs programming language.");
+        println!("For more information, visit the official documentation.");
+    } else {
+        println!();
+        println!("Run `sprs <command> --help` for the options of a command, or `sprs help --all`.");
+    }
+}
+
+fn print_command_help(info: &CommandInfo) {
+    println!("{}", info.about);
+    println!();
+    println!("Usage: {}", usage(info));
+    println!();
+    println!("Options:");
+    for (flag, about) in info.options {
+        print_option(flag, about);
+    }
+    for (flag, about, commands) in BUILD_OPTIONS {
+        if commands.contains(&info.name) {
+            print_option(flag, about);
+        }
+    }
+    print_option("-h, --help", "Show this help message");
+}
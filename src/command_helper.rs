@@ -1,34 +1,728 @@
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
+use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+use crate::front::lexer::Edition;
+use crate::front::preprocess;
+use crate::llvm::build_log::Verbosity;
+use crate::llvm::compiler::OS;
+use crate::vendor;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct ProjectConfig {
     pub name: String,
     pub version: String,
     pub src_dir: String,
     pub out_dir: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry: Option<String>, // file in src_dir compiled as the main module, see entry_path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bin_name: Option<String>, // file name of the linked program, `name` by default
+    #[serde(default, rename = "bin", skip_serializing_if = "Vec::is_empty")]
+    pub bins: Vec<BinConfig>, // `[[bin]]` tables, see select_bins
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dependencies: BTreeMap<String, Dependency>, // name -> project, see dependency_roots
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edition: Option<String>, // "2025" or "2026" (the default), see lexer::Edition
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flash: Option<FlashConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<RuntimeConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<LinkConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Profiles>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lint: BTreeMap<String, String>, // `sprs lint` rule -> "allow", "warn" or "deny"
+}
+
+pub const DEFAULT_ENTRY: &str = "main.sprs";
+
+// The file the build starts from: `<src_dir>/<entry>`, main.sprs unless sprs.toml sets `entry`.
+// Whatever its file name, it is the `main` module.
+pub fn entry_path(config: Option<&ProjectConfig>, src_path: &str) -> String {
+    let entry = config
+        .and_then(|c| c.entry.as_deref())
+        .unwrap_or(DEFAULT_ENTRY);
+    format!("{}/{}", src_path, entry)
+}
+
+// `[[bin]]` table of sprs.toml: one of several programs of the project, e.g. the firmware
+// and a host tool. Each is built from its own entry file into `<out_dir>/<name>/`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BinConfig {
+    pub name: String,
+    pub entry: String, // file in src_dir
+}
+
+// The `[[bin]]`s a command builds: the one named by `--bin`, the only one, or every one when
+// `all` (`sprs build`). Empty when sprs.toml has none, then the project's `entry` is built.
+pub fn select_bins(config: Option<&ProjectConfig>, bin: Option<&str>, all: bool) -> Result<Vec<BinConfig>, String> {
+    let bins = config.map(|c| c.bins.as_slice()).unwrap_or(&[]);
+    let names = || bins.iter().map(|b| b.name.as_str()).collect::<Vec<_>>().join(", ");
+    match bin {
+        Some(name) if bins.is_empty() => Err(format!("--bin {}: sprs.toml has no [[bin]] tables", name)),
+        Some(name) => match bins.iter().find(|b| b.name == name) {
+            Some(found) => Ok(vec![found.clone()]),
+            None => Err(format!("No binary named {} in sprs.toml (available: {})", name, names())),
+        },
+        None if bins.len() > 1 && !all => Err(format!(
+            "sprs.toml has several binaries, choose one with --bin (available: {})",
+            names()
+        )),
+        None => Ok(bins.to_vec()),
+    }
+}
+
+// sprs.toml as the build of `bin` sees it: its entry file, name and output directory.
+pub fn bin_config(config: &ProjectConfig, bin: &BinConfig) -> ProjectConfig {
+    let mut config = config.clone();
+    config.out_dir = format!("{}/{}", config.out_dir, bin.name);
+    config.entry = Some(bin.entry.clone());
+    config.bin_name = Some(bin.name.clone());
+    config
+}
+
+// `[dependencies]` entry of sprs.toml: another Sprs project whose modules are imported as
+// `<name>.<module>`, e.g. `mylib = { path = "../mylib" }` and `import mylib.uart;`. A `git`
+// dependency is cloned into vendor/ by `sprs build`, see vendor.rs.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Dependency {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>, // project directory with its own sprs.toml
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<String>, // repository url
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>, // branch, tag or commit of `git`; the default branch if not set
+}
+
+// The source directory of every dependency: its path plus the `src_dir` of its sprs.toml.
+pub fn dependency_roots(config: Option<&ProjectConfig>) -> Result<HashMap<String, String>, String> {
+    let mut roots = HashMap::new();
+    for (name, dependency) in config.map(|c| &c.dependencies).into_iter().flatten() {
+        roots.insert(name.clone(), dependency_root(name, dependency)?);
+    }
+    Ok(roots)
+}
+
+fn dependency_root(name: &str, dependency: &Dependency) -> Result<String, String> {
+    if !preprocess::is_symbol_name(name) {
+        return Err(format!("Invalid dependency name in sprs.toml: {} (it is used in imports)", name));
+    }
+    let dir = match (&dependency.path, &dependency.git) {
+        (Some(path), None) => path.clone(),
+        (None, Some(_)) => {
+            let dir = vendor::vendor_dir(name);
+            if !Path::new(&dir).exists() {
+                return Err(format!("Dependency {} is not fetched yet, run `sprs build`", name));
+            }
+            dir
+        }
+        _ => return Err(format!("Dependency {} needs either `path` or `git` in sprs.toml", name)),
+    };
+    let manifest = format!("{}/sprs.toml", dir);
+    let content = std::fs::read_to_string(&manifest)
+        .map_err(|e| format!("Dependency {}: failed to read {}: {}", name, manifest, e))?;
+    let dependency_config: ProjectConfig = toml::from_str(&content)
+        .map_err(|e| format!("Dependency {}: failed to parse {}: {}", name, manifest, e))?;
+    Ok(format!("{}/{}", dir, dependency_config.src_dir))
+}
+
+// `sprs add <name> --path <dir>` or `--git <url> [--rev <rev>]`: adds the dependency to
+// sprs.toml, or replaces it. Like set_project_edition, only its line is touched, so comments
+// and layout are kept. A git dependency is fetched by the next build.
+pub fn add_dependency(name: &str, dependency: &Dependency) -> Result<(), String> {
+    if !preprocess::is_symbol_name(name) {
+        return Err(format!("Invalid dependency name: {} (it is used in imports)", name));
+    }
+    let content = std::fs::read_to_string("sprs.toml")
+        .map_err(|e| format!("Failed to read sprs.toml: {}", e))?;
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let value = toml::Value::try_from(dependency)
+        .map_err(|e| format!("Invalid dependency {}: {}", name, e))?;
+    let line = format!("{} = {}", name, value);
+    match dependency_section(&lines) {
+        Some((start, end)) => match dependency_line(&lines, start, end, name) {
+            Some(idx) => lines[idx] = line,
+            None => {
+                // after the last entry, not after the blank line before the next section
+                let mut at = end;
+                while at > start + 1 && lines[at - 1].trim().is_empty() {
+                    at -= 1;
+                }
+                lines.insert(at, line);
+            }
+        },
+        None => {
+            if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push("[dependencies]".to_string());
+            lines.push(line);
+        }
+    }
+
+    let content = lines.join("\n") + "\n";
+    let config: ProjectConfig = toml::from_str(&content)
+        .map_err(|e| format!("sprs.toml would not parse after adding {}: {}", name, e))?;
+    if let Some(dependency) = config.dependencies.get(name).filter(|d| d.git.is_none()) {
+        dependency_root(name, dependency)?;
+    }
+    std::fs::write("sprs.toml", content).map_err(|e| format!("Failed to write sprs.toml: {}", e))
+}
+
+// `sprs remove <name>`: drops the dependency's line from sprs.toml.
+pub fn remove_dependency(name: &str) -> Result<(), String> {
+    let content = std::fs::read_to_string("sprs.toml")
+        .map_err(|e| format!("Failed to read sprs.toml: {}", e))?;
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let idx = dependency_section(&lines)
+        .and_then(|(start, end)| dependency_line(&lines, start, end, name))
+        .ok_or_else(|| format!("No dependency named {} in the [dependencies] of sprs.toml", name))?;
+    lines.remove(idx);
+    std::fs::write("sprs.toml", lines.join("\n") + "\n")
+        .map_err(|e| format!("Failed to write sprs.toml: {}", e))
+}
+
+// The `[dependencies]` header line and the line the next section starts at.
+fn dependency_section(lines: &[String]) -> Option<(usize, usize)> {
+    let start = lines.iter().position(|l| l.trim() == "[dependencies]")?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map_or(lines.len(), |i| start + 1 + i);
+    Some((start, end))
+}
+
+fn dependency_line(lines: &[String], start: usize, end: usize, name: &str) -> Option<usize> {
+    (start + 1..end).find(|&i| {
+        lines[i]
+            .split_once('=')
+            .is_some_and(|(key, _)| key.trim() == name)
+    })
+}
+
+// `[flash]` section of sprs.toml, used by embedded targets after linking.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FlashConfig {
+    #[serde(default)]
+    pub formats: Vec<String>, // "bin" and/or "hex", written next to the .elf
+    #[serde(default = "default_objcopy")]
+    pub objcopy: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artifact: Option<String>, // file substituted for `{file}`: "elf" (default), "bin" or "hex"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>, // e.g. "probe-rs download --chip <chip> {file}"
+}
+
+// `[runtime]` section of sprs.toml: how the runtime is built.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RuntimeConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocator: Option<String>, // "libc", "bump" or "pool", see runtime_allocator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heap_size: Option<u64>, // bytes of the bump/pool heap
+}
+
+pub const ALLOCATORS: &[&str] = &["libc", "bump", "pool"];
+
+// The `[runtime] allocator` of sprs.toml, None for the target's default, and the heap size.
+pub fn runtime_allocator(
+    config: Option<&ProjectConfig>,
+) -> Result<(Option<String>, Option<u64>), String> {
+    let Some(runtime) = config.and_then(|c| c.runtime.as_ref()) else {
+        return Ok((None, None));
+    };
+    if let Some(name) = &runtime.allocator {
+        if !ALLOCATORS.contains(&name.as_str()) {
+            return Err(format!(
+                "Unknown allocator in sprs.toml: {} (available: {})",
+                name,
+                ALLOCATORS.join(", ")
+            ));
+        }
+    }
+    if runtime.heap_size == Some(0) {
+        return Err("heap_size in sprs.toml must be greater than 0".to_string());
+    }
+    Ok((runtime.allocator.clone(), runtime.heap_size))
+}
+
+// `[profile.dev]` and `[profile.release]` of sprs.toml, chosen by `--release`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Profiles {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dev: Option<Profile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<Profile>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Profile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opt_level: Option<u8>, // 0..3, as -O0..-O3
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overflow_checks: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<bool>, // DWARF debug info, as --debug
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub panic: Option<String>, // "message" or "codes" (--panic-codes)
+}
+
+// Applies the profile of the build to `options`. A flag given on the command line wins over
+// the profile.
+pub fn apply_profile(options: &mut BuildOptions, config: Option<&ProjectConfig>) -> Result<(), String> {
+    let profiles = config.and_then(|c| c.profile.as_ref());
+    let (name, profile) = if options.release {
+        ("release", profiles.and_then(|p| p.release.as_ref()))
+    } else {
+        ("dev", profiles.and_then(|p| p.dev.as_ref()))
+    };
+    let Some(profile) = profile else {
+        return Ok(());
+    };
+
+    if let Some(level) = profile.opt_level {
+        if level > 3 {
+            return Err(format!("opt_level in [profile.{}] must be 0 to 3, found {}", name, level));
+        }
+        options.opt_level.get_or_insert(level);
+    }
+    options.overflow_checks |= profile.overflow_checks.unwrap_or(false);
+    options.debug |= profile.debug.unwrap_or(false);
+    match profile.panic.as_deref() {
+        None | Some("message") => {}
+        Some("codes") => options.panic_codes = true,
+        Some(other) => {
+            return Err(format!(
+                "Unknown panic strategy in [profile.{}]: {} (available: message, codes)",
+                name, other
+            ));
+        }
+    }
+    Ok(())
+}
+
+// `[link]` section of sprs.toml: the linker and what it gets besides the objects and the runtime.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LinkConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linker: Option<String>, // e.g. "riscv64-unknown-elf-gcc"; clang when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linker_script: Option<String>, // passed as `-T`, replaces link.ld on RISC-V
+    #[serde(default)]
+    pub flags: Vec<String>, // passed to the linker as they are
+    #[serde(default)]
+    pub libs: Vec<String>, // "m" links libm, as `-lm`
+    #[serde(default)]
+    pub search_paths: Vec<String>, // directories searched for the libs, as `-L`
+    #[serde(default)]
+    pub lto: bool, // optimize the modules as one, see llvm_executer::link_modules_for_lto
+}
+
+// The linker arguments for the `[link]` section of sprs.toml, except the linker script.
+pub fn link_args(config: Option<&ProjectConfig>) -> Vec<String> {
+    let Some(link) = config.and_then(|c| c.link.as_ref()) else {
+        return Vec::new();
+    };
+    let mut args = link.flags.clone();
+    args.extend(link.search_paths.iter().map(|path| format!("-L{}", path)));
+    args.extend(link.libs.iter().map(|lib| format!("-l{}", lib)));
+    args
+}
+
+fn default_objcopy() -> String {
+    "llvm-objcopy".to_string()
+}
+
+// sprs.toml in the current directory, if there is one.
+pub fn load_project_config() -> Option<ProjectConfig> {
+    let content = std::fs::read_to_string("sprs.toml").ok()?;
+    if content.is_empty() {
+        return None;
+    }
+    match toml::from_str(&content) {
+        Ok(cfg) => Some(cfg),
+        // a workspace root needs no [project] fields of its own
+        Err(_) if load_workspace_config().is_some() => None,
+        Err(e) => {
+            eprintln!("Failed to parse sprs.toml: {}", e);
+            None
+        }
+    }
+}
+
+// `[workspace]` of a root sprs.toml: the directories of the member projects, each with its
+// own sprs.toml.
+#[derive(Deserialize, Debug)]
+pub struct WorkspaceConfig {
+    pub members: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct WorkspaceFile {
+    workspace: Option<WorkspaceConfig>,
+}
+
+// The `[workspace]` of sprs.toml in the current directory, if it is a workspace root.
+pub fn load_workspace_config() -> Option<WorkspaceConfig> {
+    let content = std::fs::read_to_string("sprs.toml").ok()?;
+    toml::from_str::<WorkspaceFile>(&content).ok()?.workspace
+}
+
+// `sprs clean`: removes out_dir, which holds the artifact cache too. `all` also removes the
+// vendored git dependencies; sprs.lock stays, so the next build fetches the same commits.
+pub fn clean_project(all: bool) -> Result<(), String> {
+    if let Some(workspace) = load_workspace_config() {
+        remove_dir(".sprs-cache")?;
+        let root = std::env::current_dir()
+            .map_err(|e| format!("Failed to read the current directory: {}", e))?;
+        for member in &workspace.members {
+            std::env::set_current_dir(root.join(member))
+                .map_err(|e| format!("Workspace member {}: {}", member, e))?;
+            let result = clean_project(all);
+            std::env::set_current_dir(&root)
+                .map_err(|e| format!("Failed to return to {}: {}", root.display(), e))?;
+            result?;
+        }
+        return Ok(());
+    }
+
+    let config = load_project_config().ok_or_else(|| "No sprs.toml in the current directory".to_string())?;
+    // an out_dir of "." or one that holds the sources would take the project with it
+    let out_dir = std::fs::canonicalize(&config.out_dir).ok();
+    let protected = [".", config.src_dir.as_str()]
+        .iter()
+        .filter_map(|dir| std::fs::canonicalize(dir).ok())
+        .any(|dir| out_dir.as_ref().is_some_and(|out| dir.starts_with(out)));
+    if protected {
+        return Err(format!("Refusing to remove out_dir {}: it contains the project", config.out_dir));
+    }
+    remove_dir(&config.out_dir)?;
+    if all {
+        remove_dir(vendor::VENDOR_DIR)?;
+    }
+    Ok(())
+}
+
+fn remove_dir(dir: &str) -> Result<(), String> {
+    if !Path::new(dir).exists() {
+        return Ok(());
+    }
+    std::fs::remove_dir_all(dir).map_err(|e| format!("Failed to remove {}: {}", dir, e))?;
+    println!("Removed {}", dir);
+    Ok(())
+}
+
+// The `edition` of sprs.toml; the latest one when it is not set.
+pub fn project_edition(config: Option<&ProjectConfig>) -> Result<Edition, String> {
+    match config.and_then(|c| c.edition.as_deref()) {
+        None => Ok(Edition::default()),
+        Some(name) => Edition::from_name(name).ok_or_else(|| {
+            let known: Vec<&str> = Edition::ALL.iter().map(|e| e.name()).collect();
+            format!(
+                "Unknown edition in sprs.toml: {} (available: {})",
+                name,
+                known.join(", ")
+            )
+        }),
+    }
+}
+
+// Rewrites only the `edition` line of sprs.toml, so comments and layout stay as they are.
+pub fn set_project_edition(edition: Edition) -> Result<(), String> {
+    let content = std::fs::read_to_string("sprs.toml")
+        .map_err(|e| format!("Failed to read sprs.toml: {}", e))?;
+    let line = format!("edition = \"{}\"", edition.name());
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    // top-level keys come before the first `[section]`
+    let top_level = lines
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+    let existing = lines[..top_level].iter().position(|l| {
+        l.split_once('=')
+            .is_some_and(|(key, _)| key.trim() == "edition")
+    });
+    match existing {
+        Some(idx) => lines[idx] = line,
+        None => lines.insert(top_level, line),
+    }
+    std::fs::write("sprs.toml", lines.join("\n") + "\n")
+        .map_err(|e| format!("Failed to write sprs.toml: {}", e))
+}
+
+// An artifact of `--emit=`, written to the output directory.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Emit {
+    Ir,  // <module>.ll
+    Asm, // <module>.s
+    Obj, // <module>.o
+    Exe, // the linked program
+}
+
+impl Emit {
+    fn parse(name: &str) -> Result<Emit, String> {
+        match name {
+            "ir" => Ok(Emit::Ir),
+            "asm" => Ok(Emit::Asm),
+            "obj" => Ok(Emit::Obj),
+            "exe" => Ok(Emit::Exe),
+            _ => Err(format!("Unknown --emit kind: {} (available: ir, asm, obj, exe)", name)),
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct BuildOptions {
+    pub overflow_checks: bool,
+    pub emit_header: bool,
+    pub deny_warnings: bool, // warnings fail the build
+    pub panic_codes: bool,   // panics pass a 16-bit code instead of the message
+    pub compress_strings: bool, // long string literals are stored LZSS-packed
+    pub debug_alloc: bool,  // the runtime reports the allocations still live at exit
+    pub no_std: bool,       // freestanding runtime without std/libc, not linked
+    pub target: Option<OS>, // `--target <triple>`, overrides the `#define` in the source
+    pub test: bool,         // entry point runs the `test_*` functions (`sprs test`)
+    pub release: bool,      // `assert!` checks are left out, optimized as -O2
+    pub opt_level: Option<u8>, // `-O0`..`-O3`, `-O` is -O2
+    pub debug: bool,        // DWARF debug info for gdb/lldb
+    pub emit: Vec<Emit>,    // `--emit=ir,asm,...`; empty is `ir,obj,exe`
+    pub size_report: bool,  // print the code size of every function and the section totals
+    pub bin: Option<String>, // `--bin <name>`, one of the `[[bin]]`s of sprs.toml
+    pub cache_dir: Option<String>, // the workspace's artifact cache, not a command line option
+    pub edition: Edition,   // from sprs.toml, not a command line option
+    pub defines: Vec<(String, String)>, // `-D NAME=VALUE`, seen by `#if` in every module
+    pub verbosity: Verbosity, // `-v`/`--verbose`, `-q`/`--quiet`
+}
+
+impl BuildOptions {
+    // -O0..-O3: the `-O` option, or -O2 for `--release`
+    pub fn effective_opt_level(&self) -> u8 {
+        self.opt_level.unwrap_or(if self.release { 2 } else { 0 })
+    }
+
+    // Whether `--emit` asks for `kind`; without `--emit`, everything but asm is written.
+    pub fn emits(&self, kind: Emit) -> bool {
+        if self.emit.is_empty() {
+            kind != Emit::Asm
+        } else {
+            self.emit.contains(&kind)
+        }
+    }
+}
+
+pub fn parse_build_options(args: &[String]) -> Result<BuildOptions, String> {
+    let mut options = BuildOptions::default();
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--overflow-checks" => options.overflow_checks = true,
+            "--emit-header" => options.emit_header = true,
+            "--deny-warnings" => options.deny_warnings = true,
+            "--panic-codes" => options.panic_codes = true,
+            "--compress-strings" => options.compress_strings = true,
+            "--debug-alloc" => options.debug_alloc = true,
+            "--no-std" => options.no_std = true,
+            "--release" => options.release = true,
+            "--debug" => options.debug = true,
+            "--size-report" => options.size_report = true,
+            "-v" | "--verbose" => options.verbosity = Verbosity::Verbose,
+            "-q" | "--quiet" => options.verbosity = Verbosity::Quiet,
+            "-O" => options.opt_level = Some(2),
+            "-O0" | "-O1" | "-O2" | "-O3" => options.opt_level = Some(arg.as_bytes()[2] - b'0'),
+            "--target" => {
+                let triple = iter
+                    .next()
+                    .ok_or_else(|| "Missing value for --target".to_string())?;
+                options.target = Some(
+                    OS::from_triple(triple)
+                        .ok_or_else(|| format!("Unsupported target: {}", triple))?,
+                );
+            }
+            "--bin" => {
+                let name = iter
+                    .next()
+                    .ok_or_else(|| "Missing value for --bin".to_string())?;
+                options.bin = Some(name.clone());
+            }
+            "-D" => {
+                let define = iter
+                    .next()
+                    .ok_or_else(|| "Missing value for -D".to_string())?;
+                options.defines.push(parse_define(define)?);
+            }
+            _ if arg.starts_with("-D") => options.defines.push(parse_define(&arg[2..])?),
+            _ if arg.starts_with("--emit=") => {
+                for kind in arg["--emit=".len()..].split(',') {
+                    options.emit.push(Emit::parse(kind)?);
+                }
+            }
+            _ => return Err(format!("Unknown build option: {}", arg)),
+        }
+    }
+
+    Ok(options)
+}
+
+// `NAME=VALUE`, or `NAME` for the value 1
+fn parse_define(define: &str) -> Result<(String, String), String> {
+    let (name, value) = define.split_once('=').unwrap_or((define, "1"));
+    if !preprocess::is_symbol_name(name) || value.is_empty() || value.contains(char::is_whitespace) {
+        return Err(format!("Invalid -D option: {} (expected NAME=VALUE)", define));
+    }
+    Ok((name.to_string(), value.to_string()))
 }
 
-pub fn get_all_arguments(args: Vec<String>) -> Vec<String> {
-    let mut all_args = Vec::new();
-    let mut skip_next = false;
+// `sprs test` takes the build options plus `--runner <cmd>`.
+pub fn parse_test_options(args: &[String]) -> Result<(BuildOptions, Option<String>), String> {
+    let mut runner = None;
+    let mut rest = Vec::new();
 
-    for (_idx, arg) in args.iter().enumerate() {
-        if skip_next {
-            skip_next = false;
-            continue;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--runner" {
+            runner = Some(
+                iter.next()
+                    .ok_or_else(|| "Missing value for --runner".to_string())?
+                    .clone(),
+            );
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+
+    let mut options = parse_build_options(&rest)?;
+    options.test = true;
+    Ok((options, runner))
+}
+
+// Linker script written by `sprs init --template embedded`.
+const RISCV_LINKER_SCRIPT: &str = r#"/* Linker script for rv32imac bare-metal targets. Adjust MEMORY for your chip. */
+OUTPUT_ARCH("riscv")
+ENTRY(_start)
+
+MEMORY
+{
+    FLASH (rx)  : ORIGIN = 0x20000000, LENGTH = 512K
+    RAM   (rwx) : ORIGIN = 0x80000000, LENGTH = 16K
+}
+
+/* Board support code defines sprs_write(ptr, len) to send println! output somewhere,
+ * e.g. a UART. Without it the output is discarded. sprs_clock_ms() and sprs_sleep_ms(ms)
+ * back now! and sleep!; without them the clock reads 0 and sleep! returns at once. */
+/* INPUT(board.o) */
+PROVIDE(sprs_write = __sprs_write_null);
+PROVIDE(sprs_clock_ms = __sprs_clock_ms_null);
+PROVIDE(sprs_sleep_ms = __sprs_sleep_ms_null);
+
+SECTIONS
+{
+    .text : {
+        KEEP(*(.text.init))
+        *(.text .text.*)
+    } > FLASH
+
+    .rodata : {
+        *(.rodata .rodata.* .srodata .srodata.*)
+    } > FLASH
+
+    .data : {
+        . = ALIGN(4);
+        _sdata = .;
+        __global_pointer$ = . + 0x800;
+        *(.sdata .sdata.* .data .data.*)
+        . = ALIGN(4);
+        _edata = .;
+    } > RAM AT > FLASH
+    _sidata = LOADADDR(.data);
+
+    .bss (NOLOAD) : {
+        . = ALIGN(4);
+        _sbss = .;
+        *(.sbss .sbss.* .bss .bss.* COMMON)
+        . = ALIGN(4);
+        _ebss = .;
+    } > RAM
+
+    _stack_top = ORIGIN(RAM) + LENGTH(RAM);
+}
+"#;
+
+// Starter project of `sprs init`/`sprs new --template <name>`.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Template {
+    Cli,      // a program for the host (the default)
+    Embedded, // bare-metal RISC-V with link.ld and a [flash] section
+    Lib,      // a module for other projects to import, with tests
+}
+
+impl Template {
+    pub fn parse(name: Option<&str>) -> Result<Template, String> {
+        match name {
+            None | Some("cli") => Ok(Template::Cli),
+            // `embedded-riscv` is the name from before there were other templates
+            Some("embedded") | Some("embedded-riscv") => Ok(Template::Embedded),
+            Some("lib") => Ok(Template::Lib),
+            Some(other) => Err(format!(
+                "Unknown template: {} (available: cli, embedded, lib)",
+                other
+            )),
         }
+    }
 
-        if arg.starts_with("--") {
-            all_args.push(arg.clone());
+    fn main_source(self) -> &'static str {
+        match self {
+            Template::Cli => "fn main() {\n    println!(\"Hello, Sprs!\");\n}\n",
+            Template::Embedded => {
+                "#define RiscV\n\nfn main() {\n    println!(\"Hello from the board!\");\n}\n"
+            }
+            // `sprs test` runs test_add; other projects use the module as `<name>.util`
+            Template::Lib => {
+                "import util;\n\nfn main() {\n    println!(util.add(2, 3));\n}\n\nfn test_add() {\n    assert!(util.add(2, 3) == 5, \"2 + 3\");\n}\n"
+            }
         }
     }
+}
 
-    all_args
+// The module of the `lib` template, src/util.sprs.
+const LIB_MODULE: &str = "pub fn add(a, b) {\n    return a + b;\n}\n";
+
+// `sprs new <name>`: creates the directory <name> and runs `init` in it; false when it fails.
+pub fn new_project(path: &str, template: Template) -> bool {
+    if Path::new(path).exists() {
+        eprintln!("{} already exists; run `sprs init` inside it instead", path);
+        return false;
+    }
+    let Some(name) = Path::new(path).file_name().map(|n| n.to_string_lossy().into_owned()) else {
+        eprintln!("Invalid project directory: {}", path);
+        return false;
+    };
+    let root = match std::env::current_dir() {
+        Ok(root) => root,
+        Err(e) => {
+            eprintln!("Failed to read the current directory: {}", e);
+            return false;
+        }
+    };
+    if let Err(e) = std::fs::create_dir_all(path).and_then(|_| std::env::set_current_dir(path)) {
+        eprintln!("Failed to create {}: {}", path, e);
+        return false;
+    }
+    let ok = init_project(Some(&name), template);
+    if let Err(e) = std::env::set_current_dir(root) {
+        eprintln!("Failed to return to the previous directory: {}", e);
+        return false;
+    }
+    ok
 }
 
-pub fn init_project(mut name: Option<&str>) {
+pub fn init_project(mut name: Option<&str>, template: Template) -> bool {
+        let embedded_riscv = template == Template::Embedded;
+        let mut ok = true;
 
         if name.is_none() {
             name = Some("sprs_project");
@@ -41,6 +735,21 @@ pub fn init_project(mut name: Option<&str>) {
             version: "0.1.0".to_string(),
             src_dir: "src".to_string(),
             out_dir: "out".to_string(),
+            entry: Some(DEFAULT_ENTRY.to_string()),
+            bin_name: None,
+            bins: Vec::new(),
+            dependencies: BTreeMap::new(),
+            edition: Some(Edition::default().name().to_string()),
+            flash: embedded_riscv.then(|| FlashConfig {
+                formats: vec!["bin".to_string(), "hex".to_string()],
+                objcopy: default_objcopy(),
+                artifact: None,
+                command: None,
+            }),
+            runtime: None,
+            link: None,
+            profile: None,
+            lint: BTreeMap::new(),
         };
 
         match toml::to_string_pretty(&config) {
@@ -49,81 +758,64 @@ pub fn init_project(mut name: Option<&str>) {
                     Ok(mut file) => {
                         if let Err(e) = std::io::Write::write_all(&mut file, toml_str.as_bytes()) {
                             eprintln!("Failed to write to sprs.toml: {}", e);
+                            ok = false;
                         } else {
                             println!("Project initialized successfully with sprs.toml");
                         }
                     }
                     Err(e) => {
                         eprintln!("Failed to create sprs.toml: {}", e);
+                        ok = false;
                     }
                 }
             }
             Err(e) => {
                 eprintln!("Failed to serialize project config: {}", e);
+                ok = false;
             }
         }
 
-        if let Err(e) = std::fs::create_dir_all("src") {
-            eprintln!("Failed to create src directory: {}", e);
-            return;
+        if let Err(e) = std::fs::create_dir_all(&config.src_dir) {
+            eprintln!("Failed to create {} directory: {}", config.src_dir, e);
+            return false;
         }
 
-        match File::create("src/main.sprs") {
+        let entry = entry_path(Some(&config), &config.src_dir);
+        match File::create(&entry) {
             Ok(mut file) => {
-                let default_code =r#"fn main() {
-    println("Hello, Sprs!");
-}
-"#;
+                let default_code = template.main_source();
                 if let Err(e) = std::io::Write::write_all(&mut file, default_code.as_bytes()) {
-                    eprintln!("Failed to write to src/main.sprs: {}", e);
+                    eprintln!("Failed to write to {}: {}", entry, e);
+                    ok = false;
                 } else {
-                    println!("Created src/main.sprs with default code.");
+                    println!("Created {} with default code.", entry);
                 }
             }
             Err(e) => {
-                eprintln!("Failed to create src/main.sprs: {}", e);
+                eprintln!("Failed to create {}: {}", entry, e);
+                ok = false;
             }
         }
 
-    }
-
-pub enum HelpCommand {
-    All,
-    NoArg,
-}
-
-pub fn help_print(help: HelpCommand) {
-    match help {
-        HelpCommand::All => {
-            println!("Sprs Compiler Full Help:");
-            println!("Usage: sprs <source_file.sprs> [options]");
-            println!("Options:");
-            println!("---This Section is 'Command' Section---");
-            println!("  init <?args>  Initialize the project");
-            println!("  build         Build the project");
-            println!("  run           Run the project");
-            println!("  help          Show this help message");
-            println!("  version       Show compiler version");
-            println!("---This Section is 'Option' Section---");
-            println!("  --name <name>  Set the name of the project");
-            println!("  --all           Show all available commands and options");
-            println!();
-            println!(
-                "This is the Sprs compiler, a simple compiler for the Sprs programming language."
-            );
-            println!("For more information, visit the official documentation.");
+        if template == Template::Lib {
+            let module = format!("{}/util.sprs", config.src_dir);
+            if let Err(e) = std::fs::write(&module, LIB_MODULE) {
+                eprintln!("Failed to write {}: {}", module, e);
+                ok = false;
+            } else {
+                println!("Created {}; other projects import it as `{}.util`.", module, config.name);
+            }
         }
-        HelpCommand::NoArg => {
-            println!("Sprs Compiler Help:");
-            println!("Usage: sprs <source_file.sprs> [options]");
-            println!("Options:");
-            println!("---This Section is 'Command' Section---");
-            println!("  init <?args>  Initialize the project");
-            println!("  help          Show this help message");
-            println!("  version       Show compiler version");
-            println!("---This Section is 'Option' Section---");
-            println!("  --name <name>  Set the name of the project");
-            println!("  --all           Show all available commands and options");
+
+        if embedded_riscv {
+            if let Err(e) = std::fs::write("link.ld", RISCV_LINKER_SCRIPT) {
+                eprintln!("Failed to write link.ld: {}", e);
+                ok = false;
+            } else {
+                println!("Created link.ld for rv32imac; adjust MEMORY for your chip.");
+            }
+            println!("Set flash.command in sprs.toml to use `sprs flash`.");
         }
+
+        ok
     }
-}
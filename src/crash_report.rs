@@ -0,0 +1,115 @@
+use std::backtrace::Backtrace;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::command_helper;
+
+// Crash reports: a compiler panic is caught at the top of `main` and written to a
+// `sprs-crash-<time>` directory with everything needed to reproduce it: the compiler version,
+// the command line, the panic message with its backtrace, sprs.toml and the project sources.
+
+const ISSUES_URL: &str = "https://github.com/johmaru/sprs/issues";
+
+// Filled by the panic hook; the backtrace has to be taken while the panic is unwinding.
+static PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+pub fn install_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        let report = format!("{}\n\nbacktrace:\n{}", info, Backtrace::force_capture());
+        if let Ok(mut panic) = PANIC.lock() {
+            *panic = Some(report);
+        }
+    }));
+}
+
+// Writes the report for the panic the hook saw and prints where it is.
+pub fn report(argv: &[String]) {
+    let panic = PANIC
+        .lock()
+        .ok()
+        .and_then(|mut panic| panic.take())
+        .unwrap_or_else(|| "panic without a message".to_string());
+    let headline = panic
+        .lines()
+        .nth(1)
+        .unwrap_or("panic without a message")
+        .to_string();
+
+    match write_report(argv, &panic) {
+        Ok(dir) => eprintln!(
+            "error: the compiler crashed ({}); please open an issue at {} and attach {} (it contains your source files)",
+            headline,
+            ISSUES_URL,
+            dir.display()
+        ),
+        Err(e) => {
+            eprintln!("{}", panic);
+            eprintln!(
+                "error: the compiler crashed and the crash report could not be written ({}); please open an issue at {} with the output above",
+                e, ISSUES_URL
+            );
+        }
+    }
+}
+
+fn write_report(argv: &[String], panic: &str) -> Result<PathBuf, String> {
+    let time = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let dir = PathBuf::from(format!("sprs-crash-{}", time));
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let args = argv.get(1..).unwrap_or_default().join(" ");
+    let text = format!(
+        "sprs version: {}\nhost: {}-{}\ncommand: sprs {}\n\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        args,
+        panic
+    );
+    write(&dir.join("report.txt"), text.as_bytes())?;
+
+    if let Ok(config) = std::fs::read("sprs.toml") {
+        write(&dir.join("sprs.toml"), &config)?;
+    }
+    let src_dir = command_helper::load_project_config()
+        .map(|c| c.src_dir)
+        .unwrap_or_else(|| "src".to_string());
+    copy_sources(Path::new(&src_dir), &dir.join(&src_dir))?;
+    // a file given on the command line, e.g. `sprs run app.sprs`
+    for arg in argv.iter().skip(2).filter(|a| a.ends_with(".sprs")) {
+        if let Ok(source) = std::fs::read(arg) {
+            let name = Path::new(arg).file_name().unwrap_or_default();
+            write(&dir.join(name), &source)?;
+        }
+    }
+    Ok(dir)
+}
+
+fn copy_sources(from: &Path, to: &Path) -> Result<(), String> {
+    let Ok(entries) = std::fs::read_dir(from) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            copy_sources(&path, &to.join(entry.file_name()))?;
+        } else if path.extension().is_some_and(|ext| ext == "sprs") {
+            let source = std::fs::read(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            write(&to.join(entry.file_name()), &source)?;
+        }
+    }
+    Ok(())
+}
+
+fn write(path: &Path, content: &[u8]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
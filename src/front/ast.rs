@@ -1,34 +1,41 @@
-use crate::interpreter::type_helper::Type;
+// the type of the `>> type` annotations, for plugins that build calls
+pub use crate::interpreter::type_helper::Type;
 
+pub mod visit;
+
+// Operators, calls and indexing keep the span of the whole expression, so panics and lint
+// findings can point at it rather than at the statement.
 #[derive(Debug, PartialEq, Clone)]
 pub enum Expr {
-    Number(i64),                             // Value
-    Float(f64),                              // Value
-    Str(String),                             // Value
-    Bool(bool),                              // Value
-    Add(Box<Expr>, Box<Expr>),               // Lhs, Rhs
-    Mul(Box<Expr>, Box<Expr>),               // Lhs, Rhs
-    Minus(Box<Expr>, Box<Expr>),             // Lhs, Rhs
-    Div(Box<Expr>, Box<Expr>),               // Lhs, Rhs
-    Mod(Box<Expr>, Box<Expr>),               // Lhs, Rhs
-    Eq(Box<Expr>, Box<Expr>),                // Lhs, Rhs
-    Neq(Box<Expr>, Box<Expr>),               // Lhs, Rhs
-    Lt(Box<Expr>, Box<Expr>),                // Lhs, Rhs
-    Gt(Box<Expr>, Box<Expr>),                // Lhs, Rhs
-    Le(Box<Expr>, Box<Expr>),                // Lhs, Rhs
-    Ge(Box<Expr>, Box<Expr>),                // Lhs, Rhs
-    If(Box<Expr>, Box<Expr>, Box<Expr>),     // Cond, Then, Else
-    Call(String, Vec<Expr>, Option<Type>),   // Ident, Args, RetTy
-    Var(String),                             // Ident
-    Increment(Box<Expr>),                    // Ident
-    Decrement(Box<Expr>),                    // Ident
-    List(Vec<Expr>),                         // Elements
-    Range(Box<Expr>, Box<Expr>),             // Start, End
-    Index(Box<Expr>, Box<Expr>),             // Collection, Index
-    ModuleAccess(String, String, Vec<Expr>), // Module, functionName, args e.g. module.ident
-    FieldAccess(Box<Expr>, String),          // e.g. struct.field
+    Number(i64),                                 // Value
+    Float(f64),                                  // Value
+    Str(String),                                 // Value
+    Char(char),                                  // Value
+    Bool(bool),                                  // Value
+    Add(Box<Expr>, Box<Expr>, Span),             // Lhs, Rhs, Span
+    Mul(Box<Expr>, Box<Expr>, Span),             // Lhs, Rhs, Span
+    Minus(Box<Expr>, Box<Expr>, Span),           // Lhs, Rhs, Span
+    Div(Box<Expr>, Box<Expr>, Span),             // Lhs, Rhs, Span
+    Mod(Box<Expr>, Box<Expr>, Span),             // Lhs, Rhs, Span
+    Eq(Box<Expr>, Box<Expr>, Span),              // Lhs, Rhs, Span
+    Neq(Box<Expr>, Box<Expr>, Span),             // Lhs, Rhs, Span
+    Lt(Box<Expr>, Box<Expr>, Span),              // Lhs, Rhs, Span
+    Gt(Box<Expr>, Box<Expr>, Span),              // Lhs, Rhs, Span
+    Le(Box<Expr>, Box<Expr>, Span),              // Lhs, Rhs, Span
+    Ge(Box<Expr>, Box<Expr>, Span),              // Lhs, Rhs, Span
+    In(Box<Expr>, Box<Expr>, Span),              // Needle, Container, Span
+    If(Box<Expr>, Box<Expr>, Box<Expr>),         // Cond, Then, Else
+    Call(String, Vec<Expr>, Option<Type>, Span), // Ident, Args, RetTy, Span
+    Var(String),                                 // Ident
+    Increment(Box<Expr>),                        // Ident
+    Decrement(Box<Expr>),                        // Ident
+    List(Vec<Expr>),                             // Elements
+    Range(Box<Expr>, Box<Expr>),                 // Start, End
+    Index(Box<Expr>, Box<Expr>, Span),           // Collection, Index, Span
+    ModuleAccess(String, String, Vec<Expr>),     // Module, functionName, args e.g. module.ident
+    FieldAccess(Box<Expr>, String),              // e.g. struct.field
     Unit(),
-    StructInit(String, Vec<(String, Expr)>), // StructName, Fields
+    StructInit(String, Vec<(String, Expr)>),     // StructName, Fields
 
     // System types
     TypeI8,
@@ -45,20 +52,73 @@ pub enum Expr {
     TypeF64,
 }
 
+impl Expr {
+    // The span of an operator, call or index expression. None for the other variants and for
+    // nodes built without a position (`Span::default()`), e.g. by a plugin macro.
+    pub fn span(&self) -> Option<Span> {
+        let span = match self {
+            Expr::Add(_, _, span)
+            | Expr::Mul(_, _, span)
+            | Expr::Minus(_, _, span)
+            | Expr::Div(_, _, span)
+            | Expr::Mod(_, _, span)
+            | Expr::Eq(_, _, span)
+            | Expr::Neq(_, _, span)
+            | Expr::Lt(_, _, span)
+            | Expr::Gt(_, _, span)
+            | Expr::Le(_, _, span)
+            | Expr::Ge(_, _, span)
+            | Expr::In(_, _, span)
+            | Expr::Index(_, _, span)
+            | Expr::Call(_, _, _, span) => *span,
+            _ => return None,
+        };
+        (span != Span::default()).then_some(span)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct FunctionParam {
     pub ident: String,
+    pub span: Span,
+}
+
+// `import sensors;`, `import sensors as s;` or `import math.{sin, cos};`
+#[derive(Debug, PartialEq)]
+pub struct Import {
+    pub module: String,
+    pub alias: Option<String>,
+    pub names: Vec<String>, // selected functions, callable without the module prefix
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Item {
-    Import(String),
+    Import(Import),
     Package(String),
     VarItem(VarDecl),
     FunctionItem(Function),
     Preprocessor(String),
     EnumItem(Enum),
     StructItem(Struct),
+    ExternFnItem(ExternFunction),
+}
+
+// `extern fn gpio_write(pin >> i32, val >> i32);`: a C function linked in from outside.
+// Arguments are converted to the declared types at each call.
+#[derive(Debug, PartialEq)]
+pub struct ExternFunction {
+    pub ident: String,
+    pub params: Vec<ExternParam>,
+    pub ret_ty: Option<Type>,
+    pub span: Span, // `extern fn name(params)`
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExternParam {
+    pub ident: String,
+    pub ty: Type,
+    pub span: Span,
 }
 
 #[derive(Debug, PartialEq)]
@@ -69,12 +129,14 @@ pub struct Function {
     pub blk: Vec<Stmt>,
     pub is_public: bool,
     pub ret_ty: Option<Type>,
+    pub span: Span, // `fn name(params)`
 }
 
 #[derive(Debug, PartialEq)]
 pub struct VarDecl {
     pub ident: String,
     pub expr: Option<Expr>,
+    pub ident_span: Span,
 }
 #[derive(Debug, PartialEq)]
 pub struct AssignStmt {
@@ -109,10 +171,24 @@ pub enum Suffix {
     Struct(Vec<(String, Expr)>),
 }
 
+// Byte offsets into the module source; error_helper turns them into line:col.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub span: Span,
+}
+
 #[derive(Debug, PartialEq)]
-pub enum Stmt {
+pub enum StmtKind {
     Var(VarDecl),
     Assign(AssignStmt),
+    MultiAssign(Vec<AssignStmt>), // a, b = b, a;
     Expr(Expr),
     If {
         cond: Expr,
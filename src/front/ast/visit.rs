@@ -0,0 +1,389 @@
+use crate::front::ast::{
+    AssignStmt, Enum, Expr, Function, FunctionParam, Import, Item, Stmt, StmtKind, Struct,
+    StructField, VarDecl,
+};
+
+// Traversal over the AST for lints, the formatter and other analyses.
+// Every method defaults to the matching `walk_*` function, which visits the children;
+// override a method to act on a node and call the `walk_*` function to keep descending.
+
+pub trait Visitor<'ast> {
+    fn visit_item(&mut self, item: &'ast Item) {
+        walk_item(self, item);
+    }
+
+    fn visit_import(&mut self, _import: &'ast Import) {}
+
+    fn visit_function(&mut self, func: &'ast Function) {
+        walk_function(self, func);
+    }
+
+    fn visit_param(&mut self, _param: &'ast FunctionParam) {}
+
+    fn visit_enum(&mut self, _enm: &'ast Enum) {}
+
+    fn visit_struct(&mut self, strct: &'ast Struct) {
+        walk_struct(self, strct);
+    }
+
+    fn visit_struct_field(&mut self, field: &'ast StructField) {
+        walk_struct_field(self, field);
+    }
+
+    fn visit_var_decl(&mut self, var: &'ast VarDecl) {
+        walk_var_decl(self, var);
+    }
+
+    fn visit_assign(&mut self, assign: &'ast AssignStmt) {
+        walk_assign(self, assign);
+    }
+
+    // A function body or an `if`/`while` block.
+    fn visit_block(&mut self, stmts: &'ast [Stmt]) {
+        walk_block(self, stmts);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'ast Stmt) {
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        walk_expr(self, expr);
+    }
+}
+
+pub fn walk_items<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, items: &'ast [Item]) {
+    for item in items {
+        v.visit_item(item);
+    }
+}
+
+pub fn walk_item<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, item: &'ast Item) {
+    match item {
+        Item::Import(import) => v.visit_import(import),
+        Item::VarItem(var) => v.visit_var_decl(var),
+        Item::FunctionItem(func) => v.visit_function(func),
+        Item::EnumItem(enm) => v.visit_enum(enm),
+        Item::StructItem(strct) => v.visit_struct(strct),
+        Item::Package(_) | Item::Preprocessor(_) | Item::ExternFnItem(_) => {}
+    }
+}
+
+pub fn walk_function<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, func: &'ast Function) {
+    for param in &func.params {
+        v.visit_param(param);
+    }
+    v.visit_block(&func.blk);
+}
+
+pub fn walk_struct<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, strct: &'ast Struct) {
+    for field in &strct.fields {
+        v.visit_struct_field(field);
+    }
+    for method in &strct._methods {
+        v.visit_function(method);
+    }
+}
+
+pub fn walk_struct_field<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, field: &'ast StructField) {
+    if let Some(expr) = &field.default_value {
+        v.visit_expr(expr);
+    }
+}
+
+pub fn walk_var_decl<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, var: &'ast VarDecl) {
+    if let Some(expr) = &var.expr {
+        v.visit_expr(expr);
+    }
+}
+
+pub fn walk_assign<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, assign: &'ast AssignStmt) {
+    v.visit_expr(&assign.expr);
+}
+
+pub fn walk_block<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, stmts: &'ast [Stmt]) {
+    for stmt in stmts {
+        v.visit_stmt(stmt);
+    }
+}
+
+pub fn walk_stmt<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, stmt: &'ast Stmt) {
+    match &stmt.kind {
+        StmtKind::Var(var) => v.visit_var_decl(var),
+        StmtKind::Assign(assign) => v.visit_assign(assign),
+        StmtKind::MultiAssign(assigns) => {
+            for assign in assigns {
+                v.visit_assign(assign);
+            }
+        }
+        StmtKind::Expr(expr) => v.visit_expr(expr),
+        StmtKind::If {
+            cond,
+            then_blk,
+            else_blk,
+        } => {
+            v.visit_expr(cond);
+            v.visit_block(then_blk);
+            if let Some(else_blk) = else_blk {
+                v.visit_block(else_blk);
+            }
+        }
+        StmtKind::While { cond, body } => {
+            v.visit_expr(cond);
+            v.visit_block(body);
+        }
+        StmtKind::Return(expr) => {
+            if let Some(expr) = expr {
+                v.visit_expr(expr);
+            }
+        }
+        StmtKind::EnumItem(enm) => v.visit_enum(enm),
+    }
+}
+
+pub fn walk_expr<'ast, V: Visitor<'ast> + ?Sized>(v: &mut V, expr: &'ast Expr) {
+    match expr {
+        Expr::Add(l, r, _)
+        | Expr::Mul(l, r, _)
+        | Expr::Minus(l, r, _)
+        | Expr::Div(l, r, _)
+        | Expr::Mod(l, r, _)
+        | Expr::Eq(l, r, _)
+        | Expr::Neq(l, r, _)
+        | Expr::Lt(l, r, _)
+        | Expr::Gt(l, r, _)
+        | Expr::Le(l, r, _)
+        | Expr::Ge(l, r, _)
+        | Expr::In(l, r, _)
+        | Expr::Range(l, r)
+        | Expr::Index(l, r, _) => {
+            v.visit_expr(l);
+            v.visit_expr(r);
+        }
+        Expr::If(cond, then_expr, else_expr) => {
+            v.visit_expr(cond);
+            v.visit_expr(then_expr);
+            v.visit_expr(else_expr);
+        }
+        Expr::Call(_, args, _, _) | Expr::ModuleAccess(_, _, args) | Expr::List(args) => {
+            for arg in args {
+                v.visit_expr(arg);
+            }
+        }
+        Expr::Increment(e) | Expr::Decrement(e) | Expr::FieldAccess(e, _) => v.visit_expr(e),
+        Expr::StructInit(_, fields) => {
+            for (_, e) in fields {
+                v.visit_expr(e);
+            }
+        }
+        Expr::Number(_)
+        | Expr::Float(_)
+        | Expr::Str(_)
+        | Expr::Char(_)
+        | Expr::Bool(_)
+        | Expr::Var(_)
+        | Expr::Unit()
+        | Expr::TypeI8
+        | Expr::TypeU8
+        | Expr::TypeI16
+        | Expr::TypeU16
+        | Expr::TypeI32
+        | Expr::TypeU32
+        | Expr::TypeI64
+        | Expr::TypeU64
+        | Expr::TypeF16
+        | Expr::TypeF32
+        | Expr::TypeF64 => {}
+    }
+}
+
+// Same traversal with mutable access, for passes that rewrite the tree in place.
+pub trait VisitorMut {
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        walk_item_mut(self, item);
+    }
+
+    fn visit_import_mut(&mut self, _import: &mut Import) {}
+
+    fn visit_function_mut(&mut self, func: &mut Function) {
+        walk_function_mut(self, func);
+    }
+
+    fn visit_param_mut(&mut self, _param: &mut FunctionParam) {}
+
+    fn visit_enum_mut(&mut self, _enm: &mut Enum) {}
+
+    fn visit_struct_mut(&mut self, strct: &mut Struct) {
+        walk_struct_mut(self, strct);
+    }
+
+    fn visit_struct_field_mut(&mut self, field: &mut StructField) {
+        walk_struct_field_mut(self, field);
+    }
+
+    fn visit_var_decl_mut(&mut self, var: &mut VarDecl) {
+        walk_var_decl_mut(self, var);
+    }
+
+    fn visit_assign_mut(&mut self, assign: &mut AssignStmt) {
+        walk_assign_mut(self, assign);
+    }
+
+    // Gets the Vec so statements can be inserted or removed.
+    fn visit_block_mut(&mut self, stmts: &mut Vec<Stmt>) {
+        walk_block_mut(self, stmts);
+    }
+
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        walk_stmt_mut(self, stmt);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        walk_expr_mut(self, expr);
+    }
+}
+
+pub fn walk_items_mut<V: VisitorMut + ?Sized>(v: &mut V, items: &mut [Item]) {
+    for item in items {
+        v.visit_item_mut(item);
+    }
+}
+
+pub fn walk_item_mut<V: VisitorMut + ?Sized>(v: &mut V, item: &mut Item) {
+    match item {
+        Item::Import(import) => v.visit_import_mut(import),
+        Item::VarItem(var) => v.visit_var_decl_mut(var),
+        Item::FunctionItem(func) => v.visit_function_mut(func),
+        Item::EnumItem(enm) => v.visit_enum_mut(enm),
+        Item::StructItem(strct) => v.visit_struct_mut(strct),
+        Item::Package(_) | Item::Preprocessor(_) | Item::ExternFnItem(_) => {}
+    }
+}
+
+pub fn walk_function_mut<V: VisitorMut + ?Sized>(v: &mut V, func: &mut Function) {
+    for param in &mut func.params {
+        v.visit_param_mut(param);
+    }
+    v.visit_block_mut(&mut func.blk);
+}
+
+pub fn walk_struct_mut<V: VisitorMut + ?Sized>(v: &mut V, strct: &mut Struct) {
+    for field in &mut strct.fields {
+        v.visit_struct_field_mut(field);
+    }
+    for method in &mut strct._methods {
+        v.visit_function_mut(method);
+    }
+}
+
+pub fn walk_struct_field_mut<V: VisitorMut + ?Sized>(v: &mut V, field: &mut StructField) {
+    if let Some(expr) = &mut field.default_value {
+        v.visit_expr_mut(expr);
+    }
+}
+
+pub fn walk_var_decl_mut<V: VisitorMut + ?Sized>(v: &mut V, var: &mut VarDecl) {
+    if let Some(expr) = &mut var.expr {
+        v.visit_expr_mut(expr);
+    }
+}
+
+pub fn walk_assign_mut<V: VisitorMut + ?Sized>(v: &mut V, assign: &mut AssignStmt) {
+    v.visit_expr_mut(&mut assign.expr);
+}
+
+pub fn walk_block_mut<V: VisitorMut + ?Sized>(v: &mut V, stmts: &mut Vec<Stmt>) {
+    for stmt in stmts {
+        v.visit_stmt_mut(stmt);
+    }
+}
+
+pub fn walk_stmt_mut<V: VisitorMut + ?Sized>(v: &mut V, stmt: &mut Stmt) {
+    match &mut stmt.kind {
+        StmtKind::Var(var) => v.visit_var_decl_mut(var),
+        StmtKind::Assign(assign) => v.visit_assign_mut(assign),
+        StmtKind::MultiAssign(assigns) => {
+            for assign in assigns {
+                v.visit_assign_mut(assign);
+            }
+        }
+        StmtKind::Expr(expr) => v.visit_expr_mut(expr),
+        StmtKind::If {
+            cond,
+            then_blk,
+            else_blk,
+        } => {
+            v.visit_expr_mut(cond);
+            v.visit_block_mut(then_blk);
+            if let Some(else_blk) = else_blk {
+                v.visit_block_mut(else_blk);
+            }
+        }
+        StmtKind::While { cond, body } => {
+            v.visit_expr_mut(cond);
+            v.visit_block_mut(body);
+        }
+        StmtKind::Return(expr) => {
+            if let Some(expr) = expr {
+                v.visit_expr_mut(expr);
+            }
+        }
+        StmtKind::EnumItem(enm) => v.visit_enum_mut(enm),
+    }
+}
+
+pub fn walk_expr_mut<V: VisitorMut + ?Sized>(v: &mut V, expr: &mut Expr) {
+    match expr {
+        Expr::Add(l, r, _)
+        | Expr::Mul(l, r, _)
+        | Expr::Minus(l, r, _)
+        | Expr::Div(l, r, _)
+        | Expr::Mod(l, r, _)
+        | Expr::Eq(l, r, _)
+        | Expr::Neq(l, r, _)
+        | Expr::Lt(l, r, _)
+        | Expr::Gt(l, r, _)
+        | Expr::Le(l, r, _)
+        | Expr::Ge(l, r, _)
+        | Expr::In(l, r, _)
+        | Expr::Range(l, r)
+        | Expr::Index(l, r, _) => {
+            v.visit_expr_mut(l);
+            v.visit_expr_mut(r);
+        }
+        Expr::If(cond, then_expr, else_expr) => {
+            v.visit_expr_mut(cond);
+            v.visit_expr_mut(then_expr);
+            v.visit_expr_mut(else_expr);
+        }
+        Expr::Call(_, args, _, _) | Expr::ModuleAccess(_, _, args) | Expr::List(args) => {
+            for arg in args {
+                v.visit_expr_mut(arg);
+            }
+        }
+        Expr::Increment(e) | Expr::Decrement(e) | Expr::FieldAccess(e, _) => v.visit_expr_mut(e),
+        Expr::StructInit(_, fields) => {
+            for (_, e) in fields {
+                v.visit_expr_mut(e);
+            }
+        }
+        Expr::Number(_)
+        | Expr::Float(_)
+        | Expr::Str(_)
+        | Expr::Char(_)
+        | Expr::Bool(_)
+        | Expr::Var(_)
+        | Expr::Unit()
+        | Expr::TypeI8
+        | Expr::TypeU8
+        | Expr::TypeI16
+        | Expr::TypeU16
+        | Expr::TypeI32
+        | Expr::TypeU32
+        | Expr::TypeI64
+        | Expr::TypeU64
+        | Expr::TypeF16
+        | Expr::TypeF32
+        | Expr::TypeF64 => {}
+    }
+}
@@ -0,0 +1,244 @@
+use crate::front::ast::{Item, Span};
+use crate::front::diagnostics::Diagnostic;
+use crate::front::lexer::{Edition, Lexer, Token};
+use crate::front::preprocess::{self, Symbols};
+use crate::interpreter::runner::parse_module_spanned;
+
+// Lossless view of a module for tools that rewrite source (formatter, rename, extract function).
+// The AST drops whitespace and comments; here every byte of the file belongs to a token or to
+// the trivia before it, so `to_source()` gives back the input exactly and an edit to one span
+// leaves the layout of the rest untouched.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    Whitespace, // same set the lexer skips: space, tab, CR, LF, form feed
+    Comment,    // `# ...` or `## ...` up to the end of the line
+    Unknown,    // characters the lexer rejected
+    Disabled,   // `#if`/`#else`/`#endif` lines, valued `#define`s and inactive branches
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CstToken {
+    pub token: Token,
+    pub span: Span,
+    pub leading: Vec<Trivia>, // whitespace and comments between the previous token and this one
+}
+
+pub struct TokenStream<'src> {
+    pub source: &'src str,
+    pub tokens: Vec<CstToken>,
+    pub trailing: Vec<Trivia>, // after the last token
+}
+
+// Tokens plus the AST of a module that parsed without errors.
+pub struct SyntaxTree<'src> {
+    pub tokens: TokenStream<'src>,
+    pub items: Vec<ItemNode>,
+}
+
+// An item with where it came from, so a formatter or doc generator can move or print it
+// together with its comments.
+#[derive(Debug, PartialEq)]
+pub struct ItemNode {
+    pub item: Item,
+    pub span: Span,                       // first to last token of the item
+    pub leading_comments: Vec<Trivia>,    // the comment lines directly above it
+    pub trailing_comment: Option<Trivia>, // a comment after it on the same line
+}
+
+impl ItemNode {
+    // The item with its comments.
+    pub fn full_span(&self) -> Span {
+        Span {
+            start: self
+                .leading_comments
+                .first()
+                .map_or(self.span.start, |c| c.span.start),
+            end: self
+                .trailing_comment
+                .as_ref()
+                .map_or(self.span.end, |c| c.span.end),
+        }
+    }
+}
+
+impl<'src> TokenStream<'src> {
+    // Never fails: text the lexer rejects is kept as `Unknown` trivia.
+    pub fn lex(source: &'src str) -> Self {
+        Self::lex_preprocessed(source, source, Edition::default())
+    }
+
+    // Tokens of `active` (the preprocessor output for `source`), with the text the
+    // preprocessor blanked out kept as `Disabled` trivia.
+    pub fn lex_preprocessed(source: &'src str, active: &str, edition: Edition) -> Self {
+        let mut tokens = Vec::new();
+        let mut pos = 0;
+        for (start, token, end) in Lexer::with_edition(active, edition).filter_map(Result::ok) {
+            tokens.push(CstToken {
+                token,
+                span: Span { start, end },
+                leading: split_trivia(source, active, pos, start),
+            });
+            pos = end;
+        }
+
+        TokenStream {
+            source,
+            tokens,
+            trailing: split_trivia(source, active, pos, source.len()),
+        }
+    }
+
+    pub fn text(&self, span: Span) -> &'src str {
+        &self.source[span.start..span.end]
+    }
+
+    // Tokens that lie entirely inside `span`, e.g. the tokens of a statement.
+    pub fn tokens_in(&self, span: Span) -> &[CstToken] {
+        let from = self.tokens.partition_point(|t| t.span.start < span.start);
+        let to = self.tokens.partition_point(|t| t.span.end <= span.end);
+        &self.tokens[from..to.max(from)]
+    }
+
+    // The last token that ends at or before `offset`.
+    pub fn token_before(&self, offset: usize) -> Option<&CstToken> {
+        let idx = self.tokens.partition_point(|t| t.span.end <= offset);
+        idx.checked_sub(1).map(|i| &self.tokens[i])
+    }
+
+    // A comment on the line of the token that ends at `offset`, after it.
+    pub fn trailing_comment(&self, offset: usize) -> Option<Trivia> {
+        let idx = self.tokens.partition_point(|t| t.span.end <= offset);
+        let trivia = match self.tokens.get(idx) {
+            Some(next) => &next.leading,
+            None => &self.trailing,
+        };
+        let mut iter = trivia.iter();
+        let mut first = iter.next()?;
+        if first.kind == TriviaKind::Whitespace && !self.text(first.span).contains('\n') {
+            first = iter.next()?;
+        }
+        (first.kind == TriviaKind::Comment).then(|| first.clone())
+    }
+
+    // The comments above the token that starts at `offset`, up to the nearest blank line,
+    // preprocessor line or comment that trails the previous token.
+    pub fn leading_comments(&self, offset: usize) -> Vec<Trivia> {
+        let idx = self.tokens.partition_point(|t| t.span.start < offset);
+        let Some(token) = self.tokens.get(idx) else {
+            return Vec::new();
+        };
+        let previous = idx
+            .checked_sub(1)
+            .and_then(|i| self.trailing_comment(self.tokens[i].span.end));
+
+        let mut comments = Vec::new();
+        for trivia in token.leading.iter().rev() {
+            match trivia.kind {
+                TriviaKind::Comment if previous.as_ref() == Some(trivia) => break,
+                TriviaKind::Comment => comments.push(trivia.clone()),
+                TriviaKind::Whitespace if self.text(trivia.span).matches('\n').count() < 2 => {}
+                _ => break,
+            }
+        }
+        comments.reverse();
+        comments
+    }
+
+    // Reassembles the file; equal to the lexed source.
+    pub fn to_source(&self) -> String {
+        let mut out = String::with_capacity(self.source.len());
+        for token in &self.tokens {
+            for trivia in &token.leading {
+                out.push_str(self.text(trivia.span));
+            }
+            out.push_str(self.text(token.span));
+        }
+        for trivia in &self.trailing {
+            out.push_str(self.text(trivia.span));
+        }
+        out
+    }
+}
+
+impl<'src> SyntaxTree<'src> {
+    // Lossless parse mode: the usual AST, with the token stream it was parsed from and the
+    // span and comments of every item.
+    pub fn parse(
+        source: &'src str,
+        file_path: &str,
+        symbols: &mut Symbols,
+        edition: Edition,
+    ) -> Result<Self, Vec<Diagnostic>> {
+        let active = preprocess::preprocess(source, file_path, symbols)?;
+        Self::parse_preprocessed(source, &active, file_path, edition)
+    }
+
+    // `parse` of a module the preprocessor already ran on; `active` is its output for `source`.
+    pub fn parse_preprocessed(
+        source: &'src str,
+        active: &str,
+        file_path: &str,
+        edition: Edition,
+    ) -> Result<Self, Vec<Diagnostic>> {
+        let items = parse_module_spanned(active, file_path, edition)?;
+        let tokens = TokenStream::lex_preprocessed(source, active, edition);
+        let items = items
+            .into_iter()
+            .map(|(item, span)| ItemNode {
+                leading_comments: tokens.leading_comments(span.start),
+                trailing_comment: tokens.trailing_comment(span.end),
+                item,
+                span,
+            })
+            .collect();
+        Ok(SyntaxTree { tokens, items })
+    }
+}
+
+// Splits the text between two tokens into whitespace, comment, unknown and disabled runs.
+fn split_trivia(source: &str, active: &str, start: usize, end: usize) -> Vec<Trivia> {
+    let mut trivia: Vec<Trivia> = Vec::new();
+    let mut pos = start;
+    while pos < end {
+        let rest = &source[pos..end];
+        let (kind, len) = if source.as_bytes()[pos] != active.as_bytes()[pos] {
+            // the preprocessor blanks whole lines
+            (TriviaKind::Disabled, rest.find('\n').unwrap_or(rest.len()))
+        } else if rest.starts_with("# ") || rest.starts_with("##") {
+            (TriviaKind::Comment, rest.find('\n').unwrap_or(rest.len()))
+        } else {
+            let ws = rest.len()
+                - rest
+                    .trim_start_matches([' ', '\t', '\r', '\n', '\x0c'])
+                    .len();
+            if ws > 0 {
+                (TriviaKind::Whitespace, ws)
+            } else {
+                let c = rest.chars().next().unwrap();
+                (TriviaKind::Unknown, c.len_utf8())
+            }
+        };
+
+        match trivia.last_mut() {
+            Some(last) if last.kind == kind && kind != TriviaKind::Comment => {
+                last.span.end += len;
+            }
+            _ => trivia.push(Trivia {
+                kind,
+                span: Span {
+                    start: pos,
+                    end: pos + len,
+                },
+            }),
+        }
+        pos += len;
+    }
+    trivia
+}
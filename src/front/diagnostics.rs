@@ -0,0 +1,366 @@
+use crate::front::ast::Span;
+use crate::front::error_codes;
+use std::collections::HashMap;
+use std::io::IsTerminal;
+
+// Error/warning collector shared by the parser and the compiler.
+// Everything found in one run is reported together instead of stopping at the first error.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            Severity::Error => "\x1b[1;31m",
+            Severity::Warning => "\x1b[1;33m",
+        }
+    }
+}
+
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    pub primary: bool, // underlined with `^`, secondary labels use `-`
+}
+
+// Machine-applicable suggestion: replace `span` of the file with `replacement`
+// (an empty span inserts). Printed as `= help:` and applied by `sprs fix`.
+#[derive(Debug, Clone)]
+pub struct Fix {
+    pub span: Span,
+    pub replacement: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<&'static str>, // see front::error_codes
+    pub message: String,
+    pub file: Option<String>,
+    pub labels: Vec<Label>,
+    pub fixes: Vec<Fix>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Diagnostic {
+            severity,
+            code: match severity {
+                Severity::Error => error_codes::code_for_message(&message),
+                Severity::Warning => None,
+            },
+            message,
+            file: None,
+            labels: Vec::new(),
+            fixes: Vec::new(),
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn in_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn with_primary(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: true,
+        });
+        self
+    }
+
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: false,
+        });
+        self
+    }
+
+    pub fn with_fix(
+        mut self,
+        span: Span,
+        replacement: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.fixes.push(Fix {
+            span,
+            replacement: replacement.into(),
+            message: message.into(),
+        });
+        self
+    }
+
+    // `source` is the text of `self.file`; without it only the header is printed.
+    pub fn render(&self, source: Option<&str>, color: bool) -> String {
+        let paint = |code: &str, text: &str| {
+            if color {
+                format!("{}{}{}", code, text, RESET)
+            } else {
+                text.to_string()
+            }
+        };
+
+        let header = match self.code {
+            Some(code) => format!("{}[{}]:", self.severity.as_str(), code),
+            None => format!("{}:", self.severity.as_str()),
+        };
+        let mut out = format!(
+            "{}{}\n",
+            paint(self.severity.color(), &header),
+            paint(BOLD, &format!(" {}", self.message))
+        );
+
+        let primary = self
+            .labels
+            .iter()
+            .find(|l| l.primary)
+            .or(self.labels.first());
+
+        let (Some(file), Some(source)) = (&self.file, source) else {
+            if let Some(file) = &self.file {
+                out.push_str(&format!("  {} {}\n", paint(BLUE, "-->"), file));
+            }
+            return out;
+        };
+
+        if let Some(primary) = primary {
+            let (line, col) = line_col(source, primary.span.start);
+            out.push_str(&format!(
+                "  {} {}:{}:{}\n",
+                paint(BLUE, "-->"),
+                file,
+                line,
+                col
+            ));
+        } else {
+            out.push_str(&format!("  {} {}\n", paint(BLUE, "-->"), file));
+            return out;
+        }
+
+        let mut labels: Vec<&Label> = self.labels.iter().collect();
+        labels.sort_by_key(|l| l.span.start);
+        let last_line = labels
+            .iter()
+            .map(|l| line_col(source, l.span.end.max(l.span.start)).0)
+            .max()
+            .unwrap_or(1);
+        let gutter = last_line.to_string().len();
+        let bar = paint(BLUE, &format!("{} |", " ".repeat(gutter)));
+        out.push_str(&format!("{}\n", bar));
+
+        let lines: Vec<&str> = source.lines().collect();
+        for label in labels {
+            let start = line_col(source, label.span.start);
+            // the end offset is exclusive
+            let end = line_col(source, label.span.end.max(label.span.start + 1) - 1);
+            let mark = if label.primary { "^" } else { "-" };
+            let mark_color = if label.primary {
+                self.severity.color()
+            } else {
+                BLUE
+            };
+
+            for line in start.0..=end.0 {
+                let text = lines.get(line - 1).copied().unwrap_or("");
+                let from = if line == start.0 { start.1 } else { 1 };
+                let to = if line == end.0 {
+                    end.1
+                } else {
+                    text.chars().count().max(from)
+                };
+                out.push_str(&format!(
+                    "{} {}\n",
+                    paint(BLUE, &format!("{:>width$} |", line, width = gutter)),
+                    text
+                ));
+
+                let underline = format!(
+                    "{}{}",
+                    " ".repeat(from - 1),
+                    mark.repeat(to.saturating_sub(from) + 1)
+                );
+                let message = if line == end.0 && !label.message.is_empty() {
+                    format!(" {}", label.message)
+                } else {
+                    String::new()
+                };
+                out.push_str(&format!(
+                    "{} {}\n",
+                    bar,
+                    paint(mark_color, &format!("{}{}", underline, message))
+                ));
+            }
+        }
+        for fix in &self.fixes {
+            out.push_str(&format!(
+                "{} {}\n",
+                paint(BLUE, &format!("{} =", " ".repeat(gutter))),
+                paint(BOLD, &format!("help: {}", fix.message))
+            ));
+        }
+        out
+    }
+}
+
+#[derive(Default)]
+pub struct Diagnostics {
+    items: Vec<Diagnostic>,
+    sources: HashMap<String, String>, // file path -> source text
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_source(&mut self, file: &str, source: &str) {
+        self.sources.insert(file.to_string(), source.to_string());
+    }
+
+    pub fn push(&mut self, diagnostic: Diagnostic) {
+        self.items.push(diagnostic);
+    }
+
+    pub fn extend(&mut self, diagnostics: impl IntoIterator<Item = Diagnostic>) {
+        self.items.extend(diagnostics);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.items.iter()
+    }
+
+    pub fn source(&self, file: &str) -> Option<&str> {
+        self.sources.get(file).map(String::as_str)
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .count()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|d| d.severity == Severity::Warning)
+            .count()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn render(&self, color: bool) -> String {
+        self.items
+            .iter()
+            .map(|d| {
+                let source = d.file.as_ref().and_then(|f| self.sources.get(f));
+                d.render(source.map(|s| s.as_str()), color)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Suggested fixes of all diagnostics, by file.
+    pub fn fixes_by_file(&self) -> HashMap<String, Vec<Fix>> {
+        let mut fixes: HashMap<String, Vec<Fix>> = HashMap::new();
+        for diagnostic in &self.items {
+            if let Some(file) = &diagnostic.file {
+                fixes
+                    .entry(file.clone())
+                    .or_default()
+                    .extend(diagnostic.fixes.iter().cloned());
+            }
+        }
+        fixes
+    }
+
+    // Prints everything to stderr, colored when stderr is a terminal and NO_COLOR is unset.
+    pub fn emit(&self) {
+        let color = std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+        eprint!("{}", self.render(color));
+    }
+}
+
+// Applies the fixes to `source`, skipping any that overlap an earlier one.
+// Returns the new text and how many fixes were applied.
+pub fn apply_fixes(source: &str, fixes: &[Fix]) -> (String, usize) {
+    let mut fixes: Vec<&Fix> = fixes
+        .iter()
+        .filter(|f| f.span.start <= f.span.end && f.span.end <= source.len())
+        .collect();
+    fixes.sort_by_key(|f| (f.span.start, f.span.end));
+    fixes.dedup_by(|a, b| a.span == b.span && a.replacement == b.replacement);
+
+    let mut out = String::with_capacity(source.len());
+    let mut pos = 0;
+    let mut applied = 0;
+    for fix in fixes {
+        if fix.span.start < pos {
+            continue;
+        }
+        out.push_str(&source[pos..fix.span.start]);
+        out.push_str(&fix.replacement);
+        pos = fix.span.end;
+        applied += 1;
+    }
+    out.push_str(&source[pos..]);
+    (out, applied)
+}
+
+// 1-based line and column (in chars) of a byte offset.
+pub fn line_col(source: &str, offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut col = 1;
+    for (i, c) in source.char_indices() {
+        if i >= offset {
+            break;
+        }
+        if c == '\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += 1;
+        }
+    }
+    (line, col)
+}
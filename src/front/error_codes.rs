@@ -0,0 +1,241 @@
+// Stable codes of compiler errors, shown as `error[E0003]: ...` and explained by
+// `sprs explain E0003`. A code is never reused for another kind of error once released.
+//
+// Errors raised deep in the code generator are plain strings; `messages` are the texts that
+// identify such an error, so `Diagnostic::error` can find its code. Diagnostics built where the
+// kind is known set the code with `with_code` instead.
+
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub messages: &'static [&'static str], // contained in the message of every error of this kind
+    pub explanation: &'static str,
+}
+
+pub const SYNTAX_ERROR: &str = "E0001";
+pub const DUPLICATE_FUNCTION: &str = "E0008";
+pub const BAD_IMPORT: &str = "E0009";
+pub const PREPROCESSOR_ERROR: &str = "E0012";
+
+// Looked up in order, so a more specific message comes before a general one.
+pub const ERROR_CODES: &[ErrorCode] = &[
+    ErrorCode {
+        code: SYNTAX_ERROR,
+        title: "syntax error",
+        messages: &[],
+        explanation: "\
+The source does not follow the grammar of Sprs: a token is missing, misplaced or not
+a valid token at all. The parser recovers at the next `;`, so one build reports every
+syntax error of a module.
+
+    fn main() {
+        var x = 1
+        println!(x);
+    }
+
+Here the `;` after `var x = 1` is missing. `sprs fix` inserts a missing `;` where the
+diagnostic suggests one.",
+    },
+    ErrorCode {
+        code: "E0002",
+        title: "undefined variable",
+        messages: &["Undefined variable"],
+        explanation: "\
+A name is read or assigned that no `var` in scope declares.
+
+    fn main() {
+        count = 3;
+    }
+
+Declare the variable first with `var count = 3;`. A `var` inside an `if` or `while`
+block is not visible after the block.",
+    },
+    ErrorCode {
+        code: "E0003",
+        title: "undefined function",
+        messages: &["Undefined function"],
+        explanation: "\
+A function is called that is not defined in the module, not imported and not a
+builtin.
+
+    fn main() {
+        greet();
+    }
+
+Define `fn greet()` in the module, or import the module that defines it
+(`import hello;` and call `hello.greet()`, or `import hello.{greet};`).",
+    },
+    ErrorCode {
+        code: "E0004",
+        title: "type mismatch",
+        messages: &["Type mismatch"],
+        explanation: "\
+A `return` gives a value whose type does not match the return type after `>>`.
+
+    fn label() >> str {
+        return 3;
+    }
+
+Return a value of the declared type, or change the return type.",
+    },
+    ErrorCode {
+        code: "E0005",
+        title: "use of moved value",
+        messages: &[], // a panic, not a compile error
+        explanation: "\
+A variable is read after its value was moved. Strings, lists, ranges and structs are
+moved when they are passed to a function or used in a struct literal; numbers and
+booleans are copied. This is checked when the program runs: it panics with
+`use of moved value: <name>`.
+
+    fn show(list) {
+        println!(list);
+    }
+
+    fn main() {
+        var a = [1, 2, 3];
+        show(a);
+        println!(a);
+    }
+
+Pass a copy with `show(clone!(a));` to keep using `a`.",
+    },
+    ErrorCode {
+        code: "E0006",
+        title: "undefined struct",
+        messages: &["Undefined struct"],
+        explanation: "\
+A struct literal or field access names a struct that is not defined.
+
+    fn main() {
+        var p = Point { x = 1, y = 2 };
+    }
+
+Define it with `struct Point { x >> i64, y >> i64 }`, or `pub struct` in the module
+that other modules take it from.",
+    },
+    ErrorCode {
+        code: "E0007",
+        title: "undefined enum variant",
+        messages: &["Undefined enum variant"],
+        explanation: "\
+An `Enum.Variant` names a variant the enum does not have, or an enum that is not
+defined.
+
+    pub enum Animal {
+        Dog,
+        Cat,
+    }
+
+    fn main() {
+        println!(Animal.Cow);
+    }
+
+Use one of the variants listed in the enum, or add the variant to it.",
+    },
+    ErrorCode {
+        code: DUPLICATE_FUNCTION,
+        title: "function defined more than once",
+        messages: &[],
+        explanation: "\
+Two functions (or extern fns) of one module have the same name. Functions are not
+overloaded by their parameters.
+
+    fn add(a, b) { return a + b; }
+    fn add(x, y, z) { return x + y + z; }
+
+Rename one of them, e.g. to `add3`. `sprs rename` renames a function with its calls.",
+    },
+    ErrorCode {
+        code: BAD_IMPORT,
+        title: "function not found or private in another module",
+        messages: &["is private; declare it with `pub fn`"],
+        explanation: "\
+An `import module.{name}` list or a `module.name()` call names a function the module
+does not define, or one that is not `pub`. Only `pub fn`s can be used outside their
+module.
+
+    # greet.sprs
+    fn name() >> str { return \"sprs\"; }
+
+    # main.sprs
+    import greet.{name};
+
+Declare the function with `pub fn name()`, or fix the name.",
+    },
+    ErrorCode {
+        code: "E0010",
+        title: "module not found",
+        messages: &["Module '"],
+        explanation: "\
+A module is used that was never imported, or whose file does not exist. The module
+`sensors` is the file `sensors.sprs` in the source directory, or a module of a
+dependency in `sprs.toml`.
+
+    fn main() {
+        sensors.read_temp(4);
+    }
+
+Add `import sensors;` and check that `src/sensors.sprs` exists.",
+    },
+    ErrorCode {
+        code: "E0011",
+        title: "wrong arguments to a macro or extern fn",
+        messages: &[" expects ", "argument must be"],
+        explanation: "\
+A builtin macro or an `extern fn` is called with the wrong number or kind of
+arguments.
+
+    fn main() {
+        var n = rand!(10);
+    }
+
+`rand!` takes the lower and the upper bound: `rand!(0, 10)`. The message names what
+was expected.",
+    },
+    ErrorCode {
+        code: PREPROCESSOR_ERROR,
+        title: "invalid #if directive",
+        messages: &[],
+        explanation: "\
+An `#if`, `#else`, `#endif` or `#define` line is malformed, unbalanced or compares a
+symbol the wrong way.
+
+    #if BOARD == uno
+    fn led_on() { }
+
+The block above has no `#endif`. Every `#if` needs one, and `#else` may appear once
+per `#if`.",
+    },
+    ErrorCode {
+        code: "E0013",
+        title: "not supported on the target",
+        messages: &["is not supported on the AVR target"],
+        explanation: "\
+The program uses a feature the selected target cannot provide. On AVR, where `Int` is
+32 bits wide, that is anything that needs 64 bits: float literals, `Float` and `fp64`,
+`i64`/`u64` and integer literals outside the 32-bit range.
+
+    #define Avr
+    fn main() {
+        var ratio = 0.5;
+    }
+
+Use integers of at most 32 bits there, or keep the code out of the AVR build with an
+`#if` block.",
+    },
+];
+
+pub fn find(code: &str) -> Option<&'static ErrorCode> {
+    ERROR_CODES
+        .iter()
+        .find(|entry| entry.code.eq_ignore_ascii_case(code))
+}
+
+// The code of a compiler error from its message, for errors raised as plain strings.
+pub fn code_for_message(message: &str) -> Option<&'static str> {
+    ERROR_CODES
+        .iter()
+        .find(|entry| entry.messages.iter().any(|text| message.contains(text)))
+        .map(|entry| entry.code)
+}
@@ -28,11 +28,13 @@ pub enum Token {
     Semi,
     Comma,
     StrLiteral(String),
+    CharLiteral(char),
     Bool(bool),
     If,
     Then,
     Else,
     While,
+    In,
     Ident(String),
     Num(i64),
     Float(f64),
@@ -41,6 +43,7 @@ pub enum Token {
     Preprocessor,
     Package,
     Import,
+    As,
     Var,
     Public,
     Enum,
@@ -122,6 +125,23 @@ enum RawTok {
         slice[1..slice.len()-1].to_string()
     })]
     StrLiteral(String),
+    // 'a', or one of the escapes '\n' '\r' '\t' '\0' '\\' '\''
+    #[regex(r"'([^'\\\n]|\\[nrt0\\'])'", |lex| {
+        let slice = lex.slice();
+        let mut chars = slice[1..slice.len()-1].chars();
+        match (chars.next(), chars.next()) {
+            (Some('\\'), Some(escaped)) => Some(match escaped {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                other => other,
+            }),
+            (Some(c), _) => Some(c),
+            _ => None,
+        }
+    })]
+    CharLiteral(char),
     #[token("if")]
     If,
     #[token("then")]
@@ -130,6 +150,8 @@ enum RawTok {
     Else,
     #[token("while")]
     While,
+    #[token("in")]
+    In,
     #[regex(r"[A-Za-z_][A-Za-z0-9_]*!?")]
     Ident,
     #[regex(r"[0-9]+\.[0-9]+")]
@@ -138,7 +160,8 @@ enum RawTok {
     Num,
     #[regex(r"[ \t\r\n\f]+", logos::skip)]
     WS,
-    #[regex(r"# [^\n]*", logos::skip)]
+    // `# comment`, or `## doc comment` (see llvm/doc.rs)
+    #[regex(r"#[ #][^\n]*", logos::skip)]
     Comment,
     #[token("true")]
     True,
@@ -156,6 +179,8 @@ enum RawTok {
     Package,
     #[token("import")]
     Import,
+    #[token("as")]
+    As,
     #[token("var")]
     Var,
     #[token("pub")]
@@ -202,16 +227,74 @@ enum RawTok {
     TypeF64,
 }
 
+// Language editions, set with `edition` in sprs.toml. A keyword added in a later edition is
+// an ordinary identifier in the earlier ones, so older programs that use it as a name still
+// compile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Edition {
+    E2025,
+    #[default]
+    E2026, // `in` and `as` became keywords
+}
+
+// Keywords that were not keywords in the first edition, with the edition that added them.
+const EDITION_KEYWORDS: &[(&str, Edition)] = &[("in", Edition::E2026), ("as", Edition::E2026)];
+
+impl Edition {
+    pub const ALL: &[Edition] = &[Edition::E2025, Edition::E2026];
+
+    pub fn from_name(name: &str) -> Option<Edition> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|edition| edition.name() == name)
+    }
+
+    // The edition that made `word` a keyword, None for first-edition keywords and names.
+    pub fn keyword_since(word: &str) -> Option<Edition> {
+        EDITION_KEYWORDS
+            .iter()
+            .find(|(keyword, _)| *keyword == word)
+            .map(|(_, since)| *since)
+    }
+
+    // Words that became keywords in this edition.
+    pub fn new_keywords(self) -> Vec<&'static str> {
+        EDITION_KEYWORDS
+            .iter()
+            .filter(|(_, since)| *since == self)
+            .map(|(keyword, _)| *keyword)
+            .collect()
+    }
+
+    pub fn next(self) -> Option<Edition> {
+        Self::ALL.iter().copied().find(|edition| *edition > self)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Edition::E2025 => "2025",
+            Edition::E2026 => "2026",
+        }
+    }
+}
+
 pub struct Lexer<'input> {
     input: &'input str,
     inner: logos::Lexer<'input, RawTok>,
+    edition: Edition,
 }
 
 impl<'input> Lexer<'input> {
     pub fn new(input: &'input str) -> Self {
+        Self::with_edition(input, Edition::default())
+    }
+
+    pub fn with_edition(input: &'input str, edition: Edition) -> Self {
         Self {
             input,
             inner: RawTok::lexer(input),
+            edition,
         }
     }
 }
@@ -258,10 +341,12 @@ impl<'input> Iterator for Lexer<'input> {
             RawTok::Semi => Token::Semi,
             RawTok::Comma => Token::Comma,
             RawTok::StrLiteral(s) => Token::StrLiteral(s),
+            RawTok::CharLiteral(c) => Token::CharLiteral(c),
             RawTok::If => Token::If,
             RawTok::Then => Token::Then,
             RawTok::Else => Token::Else,
             RawTok::While => Token::While,
+            RawTok::In => Token::In,
             RawTok::Ident => Token::Ident(text.to_string()),
             RawTok::Num => Token::Num(text.parse().unwrap()),
             RawTok::Float => Token::Float(text.parse().unwrap()),
@@ -273,6 +358,7 @@ impl<'input> Iterator for Lexer<'input> {
             RawTok::Preprocessor => Token::Preprocessor,
             RawTok::Package => Token::Package,
             RawTok::Import => Token::Import,
+            RawTok::As => Token::As,
             RawTok::Var => Token::Var,
             RawTok::Public => Token::Public,
             RawTok::Enum => Token::Enum,
@@ -299,6 +385,152 @@ impl<'input> Iterator for Lexer<'input> {
             RawTok::TypeF32 => Token::TypeF32,
             RawTok::TypeF64 => Token::TypeF64,
         };
+        // a keyword from a later edition, e.g. `in` used as a variable name in 2025 code
+        let newer = EDITION_KEYWORDS
+            .iter()
+            .any(|(keyword, since)| *keyword == text && *since > self.edition);
+        let t = if newer {
+            Token::Ident(text.to_string())
+        } else {
+            t
+        };
         Some(Ok((s, t, e)))
     }
 }
+
+// Grammar terminal name -> how it is written in source, for "expected ..." messages.
+const TERMINALS: &[(&str, &str)] = &[
+    ("LBrace", "`{`"),
+    ("RBrace", "`}`"),
+    ("LBracket", "`[`"),
+    ("RBracket", "`]`"),
+    ("LParen", "`(`"),
+    ("RParen", "`)`"),
+    ("Plus", "`+`"),
+    ("PlusPlus", "`++`"),
+    ("Star", "`*`"),
+    ("Minus", "`-`"),
+    ("MinusMinus", "`--`"),
+    ("Div", "`/`"),
+    ("Mod", "`%`"),
+    ("Assign", "`=`"),
+    ("EqEq", "`==`"),
+    ("Neq", "`!=`"),
+    ("Lt", "`<`"),
+    ("Gt", "`>`"),
+    ("GtGt", "`>>`"),
+    ("Le", "`<=`"),
+    ("Ge", "`>=`"),
+    ("Dot", "`.`"),
+    ("DotDot", "`..`"),
+    ("Semi", "`;`"),
+    ("Comma", "`,`"),
+    ("StrLiteral", "a string literal"),
+    ("CharLiteral", "a character literal"),
+    ("If", "`if`"),
+    ("Then", "`then`"),
+    ("Else", "`else`"),
+    ("While", "`while`"),
+    ("In", "`in`"),
+    ("IDENT", "an identifier"),
+    ("NUM", "an integer literal"),
+    ("FLOAT", "a float literal"),
+    ("TRUE", "`true`"),
+    ("FALSE", "`false`"),
+    ("Function", "`fn`"),
+    ("Return", "`return`"),
+    ("Preprocessor", "`#define`"),
+    ("Import", "`import`"),
+    ("As", "`as`"),
+    ("Package", "`pkg`"),
+    ("Var", "`var`"),
+    ("Public", "`pub`"),
+    ("Enum", "`enum`"),
+    ("Struct", "`struct`"),
+];
+
+// Describes an entry of lalrpop's `expected` list. Type keywords are reported as
+// "a type name" by the caller, so they are not listed here.
+pub fn describe_terminal(name: &str) -> String {
+    let name = name.trim_matches('"');
+    TERMINALS
+        .iter()
+        .find(|(terminal, _)| *terminal == name)
+        .map(|(_, text)| text.to_string())
+        .unwrap_or_else(|| format!("`{}`", name))
+}
+
+pub fn is_type_terminal(name: &str) -> bool {
+    name.trim_matches('"').starts_with("Type")
+}
+
+impl Token {
+    // How the token reads in an error message, e.g. "identifier `x`" or "`;`".
+    pub fn describe(&self) -> String {
+        let text = match self {
+            Token::LBrace => "{",
+            Token::RBrace => "}",
+            Token::LBracket => "[",
+            Token::RBracket => "]",
+            Token::LParen => "(",
+            Token::RParen => ")",
+            Token::Plus => "+",
+            Token::PlusPlus => "++",
+            Token::Star => "*",
+            Token::Minus => "-",
+            Token::MinusMinus => "--",
+            Token::Div => "/",
+            Token::Mod => "%",
+            Token::Assign => "=",
+            Token::EqEq => "==",
+            Token::Neq => "!=",
+            Token::Lt => "<",
+            Token::Gt => ">",
+            Token::GtGt => ">>",
+            Token::Le => "<=",
+            Token::Ge => ">=",
+            Token::Dot => ".",
+            Token::DotDot => "..",
+            Token::Semi => ";",
+            Token::Comma => ",",
+            Token::StrLiteral(s) => return format!("string literal \"{}\"", s),
+            Token::CharLiteral(c) => return format!("character literal {:?}", c),
+            Token::Bool(b) => return format!("`{}`", b),
+            Token::If => "if",
+            Token::Then => "then",
+            Token::Else => "else",
+            Token::While => "while",
+            Token::In => "in",
+            Token::Ident(name) => return format!("identifier `{}`", name),
+            Token::Num(n) => return format!("integer literal `{}`", n),
+            Token::Float(f) => return format!("float literal `{}`", f),
+            Token::Function => "fn",
+            Token::Return => "return",
+            Token::Preprocessor => "#define",
+            Token::Package => "pkg",
+            Token::Import => "import",
+            Token::As => "as",
+            Token::Var => "var",
+            Token::Public => "pub",
+            Token::Enum => "enum",
+            Token::Struct => "struct",
+            Token::TypeInt => "int",
+            Token::TypeFloat => "fp",
+            Token::TypeBool => "bool",
+            Token::TypeStr => "str",
+            Token::TypeUnit => "unit",
+            Token::TypeI8 => "i8",
+            Token::TypeU8 => "u8",
+            Token::TypeI16 => "i16",
+            Token::TypeU16 => "u16",
+            Token::TypeI32 => "i32",
+            Token::TypeU32 => "u32",
+            Token::TypeI64 => "i64",
+            Token::TypeU64 => "u64",
+            Token::TypeF16 => "fp16",
+            Token::TypeF32 => "fp32",
+            Token::TypeF64 => "fp64",
+        };
+        format!("`{}`", text)
+    }
+}
@@ -1,2 +1,6 @@
 pub mod ast;
-pub mod lexer;
\ No newline at end of file
+pub mod cst;
+pub mod diagnostics;
+pub mod error_codes;
+pub mod lexer;
+pub mod preprocess;
@@ -0,0 +1,244 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::front::ast::Span;
+use crate::front::diagnostics::Diagnostic;
+use crate::front::error_codes;
+
+// Conditional compilation, run on the source text before it is lexed:
+//   #define DEBUG 1                  symbol with a value, a bare `#define Name` has the value 1
+//   #if DEBUG, #if !DEBUG            true when the symbol is defined and not 0
+//   #if BOARD == uno, #if BOARD != uno
+//   #else, #endif                    `#if` blocks can be nested
+// Directive lines, valued `#define`s and inactive branches are replaced with spaces (newlines
+// are kept), so a span into the result is a span into the original file. A bare `#define Name`
+// stays in the text because the compiler reads the target (`#define RiscV`) from it.
+
+pub struct Symbols {
+    values: HashMap<String, String>,
+    fixed: HashSet<String>, // given with `-D`, a `#define` in the source does not override them
+}
+
+impl Symbols {
+    pub fn new(defines: &[(String, String)]) -> Self {
+        Symbols {
+            values: defines.iter().cloned().collect(),
+            fixed: defines.iter().map(|(name, _)| name.clone()).collect(),
+        }
+    }
+
+    // Symbols the compiler defines, such as the target ones. A `-D` of the same name wins.
+    pub fn predefine(&mut self, defines: &[(String, String)]) {
+        for (name, value) in defines {
+            if self.fixed.insert(name.clone()) {
+                self.values.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    fn define(&mut self, name: &str, value: &str) {
+        if !self.fixed.contains(name) {
+            self.values.insert(name.to_string(), value.to_string());
+        }
+    }
+
+    fn is_true(&self, name: &str) -> bool {
+        self.values.get(name).is_some_and(|value| value != "0")
+    }
+
+    fn eval(&self, cond: &str) -> Result<bool, String> {
+        if let Some((name, value)) = cond.split_once("==") {
+            Ok(self.values.get(symbol_name(name)?).map(String::as_str) == Some(value.trim()))
+        } else if let Some((name, value)) = cond.split_once("!=") {
+            Ok(self.values.get(symbol_name(name)?).map(String::as_str) != Some(value.trim()))
+        } else if let Some(name) = cond.strip_prefix('!') {
+            Ok(!self.is_true(symbol_name(name)?))
+        } else {
+            Ok(self.is_true(symbol_name(cond)?))
+        }
+    }
+}
+
+struct Branch {
+    span: Span, // the `#if` line
+    active: bool,
+    parent_active: bool,
+    seen_else: bool,
+}
+
+// Symbols defined in `source` are added to `symbols`, so modules processed later see them too.
+pub fn preprocess(
+    source: &str,
+    path: &str,
+    symbols: &mut Symbols,
+) -> Result<String, Vec<Diagnostic>> {
+    let mut out = String::with_capacity(source.len());
+    let mut branches: Vec<Branch> = Vec::new();
+    let mut errors = Vec::new();
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let indent = line.len() - line.trim_start().len();
+        let span = Span {
+            start: offset + indent,
+            end: offset + line.trim_end().len(),
+        };
+        offset += line.len();
+
+        let active = branches.last().is_none_or(|b| b.active);
+        let mut error = |message: String, label: &str| {
+            errors.push(
+                Diagnostic::error(message)
+                    .with_code(error_codes::PREPROCESSOR_ERROR)
+                    .in_file(path)
+                    .with_primary(span, label),
+            );
+        };
+
+        let keep = match directive(line) {
+            None => active,
+            Some(("if", cond)) => {
+                let value = active
+                    && symbols.eval(cond).unwrap_or_else(|e| {
+                        error(e, "in this `#if`");
+                        false
+                    });
+                branches.push(Branch {
+                    span,
+                    active: value,
+                    parent_active: active,
+                    seen_else: false,
+                });
+                false
+            }
+            Some(("else", rest)) => {
+                match branches.last_mut() {
+                    Some(b) if !b.seen_else => {
+                        b.seen_else = true;
+                        b.active = b.parent_active && !b.active;
+                    }
+                    Some(_) => error("`#else` after `#else`".to_string(), "second `#else`"),
+                    None => error("`#else` without `#if`".to_string(), "no matching `#if`"),
+                }
+                if !rest.is_empty() {
+                    error(format!("unexpected `{}` after `#else`", rest), "");
+                }
+                false
+            }
+            Some(("endif", rest)) => {
+                if branches.pop().is_none() {
+                    error("`#endif` without `#if`".to_string(), "no matching `#if`");
+                }
+                if !rest.is_empty() {
+                    error(format!("unexpected `{}` after `#endif`", rest), "");
+                }
+                false
+            }
+            Some(("define", _)) if !active => false,
+            Some(("define", rest)) => {
+                let mut words = rest.split_whitespace();
+                match (words.next().map(symbol_name), words.next(), words.next()) {
+                    (Some(Ok(name)), None, _) => {
+                        symbols.define(name, "1");
+                        true
+                    }
+                    (Some(Ok(name)), Some(value), None) => {
+                        symbols.define(name, value);
+                        false
+                    }
+                    _ => {
+                        error(
+                            "expected `#define NAME` or `#define NAME VALUE`".to_string(),
+                            "",
+                        );
+                        false
+                    }
+                }
+            }
+            Some(_) if !active => false,
+            Some((name, _)) => {
+                error(
+                    format!("unknown preprocessor directive `#{}`", name),
+                    "expected `#define`, `#if`, `#else` or `#endif`",
+                );
+                false
+            }
+        };
+
+        if keep {
+            out.push_str(line);
+        } else {
+            for c in line.chars() {
+                match c {
+                    '\n' | '\r' => out.push(c),
+                    _ => (0..c.len_utf8()).for_each(|_| out.push(' ')),
+                }
+            }
+        }
+    }
+
+    for branch in branches {
+        errors.push(
+            Diagnostic::error("`#if` without `#endif`")
+                .with_code(error_codes::PREPROCESSOR_ERROR)
+                .in_file(path)
+                .with_primary(branch.span, "this block is never closed"),
+        );
+    }
+
+    if errors.is_empty() {
+        Ok(out)
+    } else {
+        Err(errors)
+    }
+}
+
+// TARGET_<ARCH> and POINTER_WIDTH_<bits> for a target triple,
+// e.g. TARGET_X86_64 and POINTER_WIDTH_64 for x86_64-pc-linux-gnu.
+pub fn target_symbols(triple: &str) -> Vec<(String, String)> {
+    let arch = triple.split('-').next().unwrap_or_default();
+    let (name, width) = match arch {
+        "x86_64" => ("X86_64", 64),
+        "i386" | "i586" | "i686" => ("X86", 32),
+        "aarch64" | "arm64" => ("AARCH64", 64),
+        _ if arch.starts_with("arm") || arch.starts_with("thumb") => ("ARM", 32),
+        "riscv32" => ("RISCV32", 32),
+        "riscv64" => ("RISCV64", 64),
+        "wasm32" => ("WASM32", 32),
+        "avr" => ("AVR", 16),
+        _ => return Vec::new(),
+    };
+    vec![
+        (format!("TARGET_{}", name), "1".to_string()),
+        (format!("POINTER_WIDTH_{}", width), "1".to_string()),
+    ]
+}
+
+// `#if A == 1 # comment` -> ("if", "A == 1"); None for code and `# ` comments.
+fn directive(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim_start().strip_prefix('#')?;
+    let name_len = rest
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(rest.len());
+    if name_len == 0 {
+        return None;
+    }
+    let args = &rest[name_len..];
+    let args = args.split_once("# ").map_or(args, |(args, _)| args);
+    Some((&rest[..name_len], args.trim()))
+}
+
+pub fn is_symbol_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn symbol_name(text: &str) -> Result<&str, String> {
+    let name = text.trim();
+    if is_symbol_name(name) {
+        Ok(name)
+    } else {
+        Err(format!("expected a symbol name, found `{}`", name))
+    }
+}
@@ -1,12 +1,17 @@
 // auto-generated: "lalrpop 0.22.2"
-// sha3: 16c6439297661e0a85d1c755430b784f012f2206a7326d1e16c3c4816bb55fa3
+// sha3: 79eada880c0dd5f0026baa9868a3ccb78b9eea2864acad448384bb4c025677cc
 use crate::front::ast::{
     Item, 
     VarDecl, 
     Expr, 
     Stmt, 
+    StmtKind,
+    Span,
     Function, 
     FunctionParam, 
+    ExternFunction,
+    ExternParam,
+    Import,
     Enum, 
     AssignStmt,
     Struct,
@@ -15,7 +20,7 @@ use crate::front::ast::{
 };
 use crate::front::lexer::Token;
 use crate::interpreter::type_helper::Type;
-use lalrpop_util::ParseError;
+use lalrpop_util::{ErrorRecovery, ParseError};
 use half::f16;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
@@ -33,8 +38,13 @@ mod __parse__Start {
     VarDecl, 
     Expr, 
     Stmt, 
+    StmtKind,
+    Span,
     Function, 
     FunctionParam, 
+    ExternFunction,
+    ExternParam,
+    Import,
     Enum, 
     AssignStmt,
     Struct,
@@ -43,7 +53,7 @@ mod __parse__Start {
 };
     use crate::front::lexer::Token;
     use crate::interpreter::type_helper::Type;
-    use lalrpop_util::ParseError;
+    use lalrpop_util::{ErrorRecovery, ParseError};
     use half::f16;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
@@ -56,638 +66,817 @@ mod __parse__Start {
     pub(crate) enum __Symbol<>
      {
         Variant0(Token),
-        Variant1(Expr),
-        Variant2(alloc::vec::Vec<Expr>),
-        Variant3(FunctionParam),
-        Variant4(alloc::vec::Vec<FunctionParam>),
-        Variant5(String),
-        Variant6(alloc::vec::Vec<String>),
-        Variant7(StructField),
-        Variant8(alloc::vec::Vec<StructField>),
-        Variant9(Vec<Expr>),
-        Variant10(Vec<Stmt>),
-        Variant11(bool),
-        Variant12(Enum),
-        Variant13(Vec<String>),
-        Variant14(f16),
-        Variant15(f32),
-        Variant16(f64),
-        Variant17(Option<Expr>),
-        Variant18(Type),
-        Variant19(Option<Type>),
-        Variant20(()),
+        Variant1(__lalrpop_util::ErrorRecovery<usize, Token, String>),
+        Variant2(String),
+        Variant3(Option<String>),
+        Variant4(Expr),
+        Variant5(alloc::vec::Vec<Expr>),
+        Variant6(ExternParam),
+        Variant7(alloc::vec::Vec<ExternParam>),
+        Variant8(FunctionParam),
+        Variant9(alloc::vec::Vec<FunctionParam>),
+        Variant10(alloc::vec::Vec<String>),
+        Variant11(StructField),
+        Variant12(alloc::vec::Vec<StructField>),
+        Variant13(usize),
+        Variant14(Vec<Expr>),
+        Variant15(Vec<Stmt>),
+        Variant16(bool),
+        Variant17(char),
+        Variant18(Option<Token>),
+        Variant19(Enum),
+        Variant20(Vec<String>),
         Variant21(Item),
-        Variant22(i16),
-        Variant23(i32),
-        Variant24(i64),
-        Variant25(i8),
-        Variant26(Stmt),
-        Variant27(alloc::vec::Vec<Item>),
-        Variant28(Vec<StructField>),
-        Variant29(Vec<FunctionParam>),
-        Variant30(Vec<Item>),
-        Variant31(Struct),
-        Variant32((String, Expr)),
-        Variant33(Vec<(String, Expr)>),
-        Variant34(u16),
-        Variant35(u32),
-        Variant36(u64),
-        Variant37(u8),
-        Variant38(VarDecl),
-        Variant39(Vec<VarDecl>),
+        Variant22(()),
+        Variant23(Vec<ExternParam>),
+        Variant24(f16),
+        Variant25(f32),
+        Variant26(f64),
+        Variant27(Option<Expr>),
+        Variant28(Type),
+        Variant29(Option<Type>),
+        Variant30(i16),
+        Variant31(i32),
+        Variant32(i64),
+        Variant33(i8),
+        Variant34(StmtKind),
+        Variant35(Import),
+        Variant36(Vec<StructField>),
+        Variant37(Vec<FunctionParam>),
+        Variant38((Item, Span)),
+        Variant39(alloc::vec::Vec<(Item, Span)>),
+        Variant40(Vec<(Item, Span)>),
+        Variant41(Stmt),
+        Variant42(Struct),
+        Variant43((String, Expr)),
+        Variant44(Vec<(String, Expr)>),
+        Variant45(u16),
+        Variant46(u32),
+        Variant47(u64),
+        Variant48(u8),
+        Variant49(VarDecl),
+        Variant50(Vec<VarDecl>),
     }
     const __ACTION: &[i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, 6, 4, 5, 7, 98, -175, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, -218, 0, 7, 5, 0, 6, 8, 136, -218, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, 6, 4, 5, 7, 98, -175, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 9, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, -218, 0, 7, 5, 0, 6, 8, 136, -218, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, -154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 15, 148, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 15, 0, 16, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 16
-        0, 0, 0, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, -203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        0, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 194, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 22
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 23
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, -254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, -249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, -205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 30
-        0, 0, 15, 0, 16, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 31
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 32
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 33
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 35
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 36
-        0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 38
-        -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 39
-        0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -256, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, -202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 41
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 187, 183, 193, 198, 191, 197, 188, 194, 189, 195, 190, 196, 184, 185, 186,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 44
-        0, 0, 0, 0, 0, 202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, -224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 187, 183, 193, 198, 191, 197, 188, 194, 189, 195, 190, 196, 184, 185, 186,
+        0, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 235, 231, 241, 246, 239, 245, 236, 242, 237, 243, 238, 244, 232, 233, 234, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        -224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        0, 0, 15, 0, 16, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, -253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 55
-        0, -193, -193, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -193, -193, 0, 0, -193, -193, -193, -193, -193, -193, 0, 0, -193, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, -193, -193, -193, -193, -193, -193, -193, -193, -193, -193, -193,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 235, 231, 241, 246, 239, 245, 236, 242, 237, 243, 238, 244, 232, 233, 234, 0,
         // State 56
-        0, 0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        0, 221, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 61, 0, 0, 63, 102, 127, 126, 129, 125, 0, 0, 62, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 263, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 60
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 235, 231, 241, 246, 239, 245, 236, 242, 237, 243, 238, 244, 232, 233, 234, 0,
         // State 61
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 62
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 0, 0, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        0, 0, 15, 257, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        0, 0, 15, 0, 16, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 68
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 22, 0, 23, 279, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 70
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 22, 0, 23, 280, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 71
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 22, 282, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 72
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, -244, -244, 0, -244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -244, -244, -244, 0, 0, -244, 0, -244, -244, -244, -244, -244, 0, 0, -244, 0, 0, 0, 0, -244, 0, 0, 0, 0, 0, 0, 0, 0, -244, -244, -244, -244, -244, -244, -244, -244, -244, -244, -244, -244,
         // State 73
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 22, 0, 23, 284, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 74
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 0, 0, 0, 286, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 290, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 81, 0, 0, 83, 0, 135, 170, 169, 172, 168, 0, 0, 82, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 291,
         // State 76
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        0, 0, 15, 0, 16, 271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 22, 0, 23, 294, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 78
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 296, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        40, 0, -48, 0, 41, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        0, 0, 66, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 251, 244, 248, 245, 249, 246, 250, 241, 242, 243,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 81
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 300, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 82
-        0, 0, 15, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 83
-        0, 0, 0, 278, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 326, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 84
-        0, 0, 0, 0, 0, 281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 85
-        56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 86
-        0, 0, 15, 0, 16, 286, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 102, 127, 126, 129, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 140, 133, 137, 134, 138, 135, 139, 130, 131, 132,
+        73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        0, 0, 0, 0, 0, 288, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 332, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 88
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, -134, -134, -134, -134, -134, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 334, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 89
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, -129, -129, -129, -129, -129, -129, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 90
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, -132, -132, -132, -132, -132, -132, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 91
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, 0, -138, -138, -138, -138, -138, -138, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, -133, -133, -133, -133, -133, -133, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 93
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, -131, -131, -131, -131, -131, -131, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 94
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 95
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, -135, -135, -135, -135, -135, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 96
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, -130, -130, -130, -130, -130, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 97
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174, 0, 0, 0, 0, 0, 0, -174, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 98
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, 0, -139, -139, -139, -139, -139, -139, -139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 99
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 100
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 101
-        -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, -122, -122, 0, -122, -122, -122, -122, -122, -122, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 102
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 347, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 103
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, -173, -173, -173, -173, -173, -173, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 104
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 105
-        0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 88, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 324, 317, 321, 318, 322, 319, 323, 314, 315, 316, 0,
         // State 106
-        13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 107
-        14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 108
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, -128, -128, -128, -128, -128, -128, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 354, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 109
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, -153, -153, -153, -153, -153, -153, -153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 110
-        0, -233, -233, 0, -233, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -233, -233, 0, 0, -233, -233, -233, -233, -233, -233, 0, -233, -233, -233, -233, -233, -233, -233, -233, -233, 0, 0, 0, 0, 0, -233, -233, -233, -233, -233, -233, -233, -233, -233, -233, -233,
+        0, 0, 0, 0, 0, 358, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 111
-        0, -90, 0, -90, 0, -90, 22, 0, 0, 21, 0, 0, 0, -90, -90, -90, -90, -90, 0, -90, -90, 0, -90, -90, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 361, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 112
-        0, -164, -164, -164, 0, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, 0, -164, -164, -164, -164, -164, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 113
-        0, -40, -40, -40, 0, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, 0, -40, -40, -40, -40, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 114
-        0, -177, 0, -177, 0, -177, 0, 0, 0, 0, 0, 0, 0, -177, 24, 29, 28, 26, 0, 27, 25, 0, 23, -177, 0, 0, -177, 0, 0, 0, 0, 0, 0, 0, -177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 365, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 115
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 366, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 116
-        0, -38, -38, -38, 0, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, -38, -38, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 367, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 117
-        30, -36, -36, -36, 31, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, 0, -36, -36, -36, -36, -36, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 118
-        0, -23, 0, -23, 0, -23, -23, 0, 34, -23, 0, 32, 33, -23, -23, -23, -23, -23, 0, -23, -23, 0, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 368, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 119
-        0, -37, -37, -37, 0, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 370, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        0, -230, 36, -230, 0, -230, -230, 147, -230, -230, 146, -230, -230, -230, -230, -230, -230, -230, 0, -230, -230, 35, -230, -230, 0, 0, -230, 0, 0, 0, 0, 0, 0, 0, -230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 372, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 121
-        0, -103, 0, -103, 0, -103, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 22, 0, 23, 373, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 167, 0, 0, 0, 0, 0, 135, 170, 169, 172, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 183, 176, 180, 177, 181, 178, 182, 173, 174, 175, 0,
         // State 122
-        0, -39, -39, -39, 0, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, -39, -39, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, -173, 0, -173, -173, 0, -173, -173, -173, -173, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 123
-        0, -147, 0, -147, 0, -147, -147, 0, -147, -147, 0, -147, -147, -147, -147, -147, -147, -147, 0, -147, -147, 0, -147, -147, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, -168, 0, -168, -168, 0, -168, -168, -168, -168, -168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        -83, -83, -83, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, -83, -83, -83, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -167, 0, 0, 0, 0, 0, -167, 0, -167, -167, 0, -167, -167, -167, -167, -167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        -114, -114, -114, -114, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, -114, -114, -114, -114, -114, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        -152, -152, -152, -152, 0, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, 0, -152, -152, -152, -152, -152, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, -171, 0, -171, -171, 0, -171, -171, -171, -171, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        -195, -195, -195, -195, 0, -195, -195, -195, -195, -195, -195, -195, -195, -195, -195, -195, -195, -195, 0, -195, -195, -195, -195, -195, 0, 0, -195, 0, 0, 0, 0, 0, 0, 0, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -225, 0, 0, 0, 0, 0, -225, 0, -225, -225, 0, -225, -225, -225, -225, -225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 128
-        -82, -82, -82, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, -82, -82, -82, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, -172, 0, -172, -172, 0, -172, -172, -172, -172, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 129
-        0, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, -170, 0, -170, -170, 0, -170, -170, -170, -170, -170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 130
-        0, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -228, 0, 0, 0, 0, 0, -228, 0, -228, -228, 0, -228, -228, -228, -228, -228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 131
-        0, -51, -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 132
-        0, -43, -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, -43, -43, -43, -43, -43, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174, 0, 0, 0, 0, 0, -174, 0, -174, -174, 0, -174, -174, -174, -174, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 133
-        0, -45, -45, -45, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, -45, -45, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, -169, 0, -169, -169, 0, -169, -169, -169, -169, -169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 134
-        0, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, 0, 0, 0, -155, 0, 0, -155, -155, 0, 0, 0, 0, -155, -155, 0, -155, -155, -155, -155, -155, -155, -155, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 135
-        0, -41, -41, -41, 0, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -217, 0, 0, 0, 0, 0, 0, 0, -217, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 136
-        0, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 137
-        0, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -229, 0, 0, 0, 0, 0, -229, 0, -229, -229, 0, -229, -229, -229, -229, -229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 138
-        0, -48, -48, -48, 0, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        0, -42, -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -216, 0, 0, 0, 0, 0, -216, 0, -216, -216, 0, -216, -216, -216, -216, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 142
-        0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 143
-        0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 144
-        0, -232, -232, 0, -232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -232, -232, 0, 0, -232, -232, -232, -232, -232, -232, 0, -232, -232, -232, -232, -232, -232, -232, -232, -232, 0, 0, 0, 0, 0, -232, -232, -232, -232, -232, -232, -232, -232, -232, -232, -232,
+        0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 145
-        0, -159, -159, -159, 0, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, 0, -159, -159, -159, -159, -159, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 146
-        0, -158, -158, -158, 0, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, 0, -158, -158, -158, -158, -158, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 147
-        0, -54, -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, 0, 0, 0, -162, 0, -162, -162, 0, -162, -162, -162, -162, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 148
-        0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, 0, 0, 0, 0, -190, 0, -190, -190, 0, -190, -190, -190, -190, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 149
-        0, -52, -52, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -284, -284, 0, -284, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -284, -284, -284, 0, 0, -284, 0, -284, -284, -284, -284, -284, 0, -284, -284, -284, -284, 0, -284, -284, -284, -284, -284, 0, 0, 0, 0, 0, -284, -284, -284, -284, -284, -284, -284, -284, -284, -284, -284, -284,
         // State 150
-        0, 0, 0, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 151
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, 0, -98, -98, -98, -98, -98, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 152
-        0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -114, 0, -114, 0, -114, 31, 0, 0, 30, 0, 0, 0, -114, -114, -114, -114, -114, 0, -114, -114, 0, -114, -114, 0, 0, 0, -114, 0, 0, -114, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 153
-        0, -201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -205, -205, -205, 0, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, 0, -205, -205, -205, -205, -205, 0, 0, 0, -205, 0, 0, -205, 0, 0, 0, 0, 0, -205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 154
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -196, 0, -196, -196, -196, -196, -196, -196, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -53, -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53, -53, 0, 0, 0, -53, 0, 0, -53, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 155
-        0, -199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -52, -52, -52, 0, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52, -52, 0, 0, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 156
-        0, -22, 0, -22, 0, -22, -22, 0, 34, -22, 0, 32, 33, -22, -22, -22, -22, -22, 0, -22, -22, 0, -22, -22, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -220, 0, -220, 0, -220, 0, 0, 0, 0, 0, 0, 0, -220, 33, 39, 38, 35, 0, 37, 34, 0, 32, -220, 0, 0, 0, -220, 0, 0, 36, 0, 0, 0, 0, 0, -220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 157
-        0, -21, 0, -21, 0, -21, -21, 0, 34, -21, 0, 32, 33, -21, -21, -21, -21, -21, 0, -21, -21, 0, -21, -21, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 158
-        0, -176, 0, -176, 0, -176, 0, 0, 0, 0, 0, 0, 0, -176, 24, 29, 28, 26, 0, 27, 25, 0, 0, -176, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -50, -50, -50, 0, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50, -50, 0, 0, 0, -50, 0, 0, -50, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 159
-        0, -84, 0, -84, 0, -84, 22, 0, 0, 21, 0, 0, 0, -84, -84, -84, -84, -84, 0, -84, -84, 0, -84, -84, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        40, -48, -48, -48, 41, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, 0, -48, -48, -48, -48, -48, 0, 0, 0, -48, 0, 0, -48, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 160
-        0, -89, 0, -89, 0, -89, 22, 0, 0, 21, 0, 0, 0, -89, -89, -89, -89, -89, 0, -89, -89, 0, -89, -89, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -33, 0, -33, 0, -33, -33, 0, 44, -33, 0, 42, 43, -33, -33, -33, -33, -33, 0, -33, -33, 0, -33, -33, 0, 0, 0, -33, 0, 0, -33, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 161
-        0, -87, 0, -87, 0, -87, 22, 0, 0, 21, 0, 0, 0, -87, -87, -87, -87, -87, 0, -87, -87, 0, -87, -87, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -49, -49, -49, 0, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49, -49, 0, 0, 0, -49, 0, 0, -49, 0, 0, 0, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 162
-        0, -88, 0, -88, 0, -88, 22, 0, 0, 21, 0, 0, 0, -88, -88, -88, -88, -88, 0, -88, -88, 0, -88, -88, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -281, 46, -281, 0, -281, -281, 193, -281, -281, 192, -281, -281, -281, -281, -281, -281, -281, 0, -281, -281, 45, -281, -281, 0, 0, 0, -281, 0, 0, -281, 0, 0, 0, 0, 0, -281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 163
-        0, -86, 0, -86, 0, -86, 22, 0, 0, 21, 0, 0, 0, -86, -86, -86, -86, -86, 0, -86, -86, 0, -86, -86, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -128, 0, -128, 0, -128, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 164
-        0, -85, 0, -85, 0, -85, 22, 0, 0, 21, 0, 0, 0, -85, -85, -85, -85, -85, 0, -85, -85, 0, -85, -85, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -51, -51, -51, 0, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51, -51, 0, 0, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 165
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -184, 0, -184, 0, -184, -184, 0, -184, -184, 0, -184, -184, -184, -184, -184, -184, -184, 0, -184, -184, 0, -184, -184, 0, 0, 0, -184, 0, 0, -184, 0, 0, 0, 0, 0, -184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 166
-        0, -206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, -104, -104, -104, -104, 0, 0, 0, -104, 0, 0, -104, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 167
-        0, 200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -103, -103, -103, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, 0, -103, -103, -103, -103, -103, 0, 0, 0, -103, 0, 0, -103, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 168
-        0, -32, -32, -32, 0, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, 0, -32, -32, -32, -32, -32, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, 0, 0, 0, -147, 0, 0, -147, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 169
-        0, -145, 0, -145, 0, -145, -145, 0, -145, -145, 0, -145, -145, -145, -145, -145, -145, -145, 0, -145, -145, 0, -145, -145, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -189, -189, -189, -189, 0, -189, -189, -189, -189, -189, -189, -189, -189, -189, -189, -189, -189, -189, 0, -189, -189, -189, -189, -189, 0, 0, 0, -189, 0, 0, -189, 0, 0, 0, 0, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 170
-        0, -146, 0, -146, 0, -146, -146, 0, -146, -146, 0, -146, -146, -146, -146, -146, -146, -146, 0, -146, -146, 0, -146, -146, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -246, -246, -246, -246, 0, -246, -246, -246, -246, -246, -246, -246, -246, -246, -246, -246, -246, -246, 0, -246, -246, -246, -246, -246, 0, 0, 0, -246, 0, 0, -246, 0, 0, 0, 0, 0, -246, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 171
-        0, -144, 0, -144, 0, -144, -144, 0, -144, -144, 0, -144, -144, -144, -144, -144, -144, -144, 0, -144, -144, 0, -144, -144, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -102, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, -102, -102, -102, -102, -102, 0, 0, 0, -102, 0, 0, -102, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 172
-        0, -163, -163, -163, 54, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, 0, -163, -163, -163, -163, -163, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -62, -62, -62, 0, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, -62, 0, -62, -62, -62, -62, -62, 0, 0, 0, -62, 0, 0, -62, 0, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 173
-        0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -63, -63, -63, 0, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, -63, -63, 0, 0, 0, -63, 0, 0, -63, 0, 0, 0, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 174
-        0, 0, 0, 204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -64, -64, -64, 0, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, 0, -64, -64, -64, -64, -64, 0, 0, 0, -64, 0, 0, -64, 0, 0, 0, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 175
-        0, -55, -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, -55, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -56, -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, -56, 0, 0, 0, -56, 0, 0, -56, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 176
-        0, -53, -53, -53, 0, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53, -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -58, -58, -58, 0, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, 0, 0, 0, -58, 0, 0, -58, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 177
-        0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -60, -60, -60, 0, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, -60, -60, 0, 0, 0, -60, 0, 0, -60, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 178
-        0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -54, -54, -54, 0, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54, -54, 0, 0, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 179
-        0, -200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -57, -57, -57, 0, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, -57, -57, -57, 0, 0, 0, -57, 0, 0, -57, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 180
-        0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -59, -59, -59, 0, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, -59, -59, -59, 0, 0, 0, -59, 0, 0, -59, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 181
-        0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -61, -61, -61, 0, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, -61, -61, 0, 0, 0, -61, 0, 0, -61, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 182
-        -212, -212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -55, -55, -55, 0, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, 0, -55, -55, -55, -55, -55, 0, 0, 0, -55, 0, 0, -55, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 183
-        -223, -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 184
-        -224, -224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 185
-        -225, -225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 186
-        -211, -211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 187
-        -217, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 188
-        -219, -219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 189
-        -221, -221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, 0, 0, -161, 0, -161, -161, 0, -161, -161, -161, -161, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 190
-        -215, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -283, -283, 0, -283, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -283, -283, -283, 0, 0, -283, 0, -283, -283, -283, -283, -283, 0, -283, -283, -283, -283, 0, -283, -283, -283, -283, -283, 0, 0, 0, 0, 0, -283, -283, -283, -283, -283, -283, -283, -283, -283, -283, -283, -283,
         // State 191
-        -210, -210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -198, -198, -198, 0, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, 0, -198, -198, -198, -198, -198, 0, 0, 0, -198, 0, 0, -198, 0, 0, 0, 0, 0, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 192
-        -213, -213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -197, -197, -197, 0, -197, -197, -197, -197, -197, -197, -197, -197, -197, -197, -197, -197, -197, 0, -197, -197, -197, -197, -197, 0, 0, 0, -197, 0, 0, -197, 0, 0, 0, 0, 0, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 193
-        -218, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -67, -67, -67, 0, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, -67, -67, -67, -67, -67, 0, 0, 0, -67, 0, 0, -67, 0, 0, 0, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 194
-        -220, -220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 195
-        -222, -222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -65, -65, -65, 0, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, -65, -65, -65, -65, 0, 0, 0, -65, 0, 0, -65, 0, 0, 0, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 196
-        -216, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 197
-        -214, -214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 198
-        0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, -123, 0, -123, -123, 0, -123, -123, -123, -123, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 199
-        0, -35, -35, -35, 0, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, 0, -35, -35, -35, -35, -35, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 200
-        0, 0, 0, 0, 0, 214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 201
-        0, -33, -33, -33, 0, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, 0, -33, -33, -33, -33, -33, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -247, 0, 0, 0, 0, 0, -247, 0, -247, -247, 0, -247, -247, -247, -247, -247, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 202
-        0, -157, -157, -157, 0, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, 0, -157, -157, -157, -157, -157, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -250, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 203
-        0, -56, -56, -56, 0, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, 0, -56, -56, -56, -56, -56, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 204
-        0, 0, 0, -4, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 256, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 205
-        0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -32, 0, -32, 0, -32, -32, 0, 44, -32, 0, 42, 43, -32, -32, -32, -32, -32, 0, -32, -32, 0, -32, -32, 0, 0, 0, -32, 0, 0, -32, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 206
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, 0, -116, -116, -116, -116, -116, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -31, 0, -31, 0, -31, -31, 0, 44, -31, 0, 42, 43, -31, -31, -31, -31, -31, 0, -31, -31, 0, -31, -31, 0, 0, 0, -31, 0, 0, -31, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 207
-        -180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -219, 0, -219, 0, -219, 0, 0, 0, 0, 0, 0, 0, -219, 33, 39, 38, 35, 0, 37, 34, 0, 0, -219, 0, 0, 0, -219, 0, 0, 36, 0, 0, 0, 0, 0, -219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 208
-        0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -107, 0, -107, 0, -107, 31, 0, 0, 30, 0, 0, 0, -107, -107, -107, -107, -107, 0, -107, -107, 0, -107, -107, 0, 0, 0, -107, 0, 0, -107, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 209
-        0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -112, 0, -112, 0, -112, 31, 0, 0, 30, 0, 0, 0, -112, -112, -112, -112, -112, 0, -112, -112, 0, -112, -112, 0, 0, 0, -112, 0, 0, -112, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 210
-        0, -204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -110, 0, -110, 0, -110, 31, 0, 0, 30, 0, 0, 0, -110, -110, -110, -110, -110, 0, -110, -110, 0, -110, -110, 0, 0, 0, -110, 0, 0, -110, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 211
-        0, -208, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -113, 0, -113, 0, -113, 31, 0, 0, 30, 0, 0, 0, -113, -113, -113, -113, -113, 0, -113, -113, 0, -113, -113, 0, 0, 0, -113, 0, 0, -113, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 212
-        0, -207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -111, 0, -111, 0, -111, 31, 0, 0, 30, 0, 0, 0, -111, -111, -111, -111, -111, 0, -111, -111, 0, -111, -111, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 213
-        0, -34, -34, -34, 0, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, 0, -34, -34, -34, -34, -34, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -109, 0, -109, 0, -109, 31, 0, 0, 30, 0, 0, 0, -109, -109, -109, -109, -109, 0, -109, -109, 0, -109, -109, 0, 0, 0, -109, 0, 0, -109, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 214
-        0, -160, -160, -160, 0, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, 0, -160, -160, -160, -160, -160, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -108, 0, -108, 0, -108, 31, 0, 0, 30, 0, 0, 0, -108, -108, -108, -108, -108, 0, -108, -108, 0, -108, -108, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 215
-        0, 0, 0, -5, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 216
-        0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -257, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 217
-        0, -161, -161, -161, 0, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, 0, -161, -161, -161, -161, -161, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 257, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 218
-        0, -194, -194, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -194, -194, 0, 0, -194, -194, -194, -194, -194, -194, 0, 0, -194, 0, 0, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, -194, -194, -194, -194, -194, -194, -194, -194, -194, -194, -194,
+        0, -42, -42, -42, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42, -42, 0, 0, 0, -42, 0, 0, -42, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 219
-        0, -184, -184, 0, -184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -184, -184, 0, 0, -184, -184, -184, -184, -184, -184, 0, 0, -184, 0, 0, 0, -184, 0, 0, 0, 0, 0, 0, 0, 0, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184,
+        0, -182, 0, -182, 0, -182, -182, 0, -182, -182, 0, -182, -182, -182, -182, -182, -182, -182, 0, -182, -182, 0, -182, -182, 0, 0, 0, -182, 0, 0, -182, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 220
-        0, -81, -81, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, -81, 0, -81, -81, -81, -81, -81, -81, -81, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, 0, 0, 0, 0, 0, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81,
+        0, -183, 0, -183, 0, -183, -183, 0, -183, -183, 0, -183, -183, -183, -183, -183, -183, -183, 0, -183, -183, 0, -183, -183, 0, 0, 0, -183, 0, 0, -183, 0, 0, 0, 0, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 221
-        0, -209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -181, 0, -181, 0, -181, -181, 0, -181, -181, 0, -181, -181, -181, -181, -181, -181, -181, 0, -181, -181, 0, -181, -181, 0, 0, 0, -181, 0, 0, -181, 0, 0, 0, 0, 0, -181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 222
-        0, -162, -162, -162, 0, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, 0, -162, -162, -162, -162, -162, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -204, -204, -204, 71, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, 0, -204, -204, -204, -204, -204, 0, 0, 0, -204, 0, 0, -204, 0, 0, 0, 0, 0, -204, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 223
-        0, -185, -185, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -185, -185, 0, 0, -185, -185, -185, -185, -185, -185, 0, 0, -185, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, -185, -185, -185, -185, -185, -185, -185, -185, -185, -185, -185,
+        0, 0, 0, 260, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 224
-        0, -191, -191, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -191, -191, 0, 0, -191, -191, -191, -191, -191, -191, 0, 0, -191, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, -191, -191, -191, -191, -191, -191, -191, -191, -191, -191, -191,
+        0, 0, 0, 261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 225
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -69, -69, -69, 0, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, -69, -69, 0, 0, 0, -69, 0, 0, -69, 0, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 226
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -66, -66, -66, 0, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, -66, -66, -66, -66, 0, 0, 0, -66, 0, 0, -66, 0, 0, 0, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 227
-        0, -190, -190, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, -190, 0, 0, -190, -190, -190, -190, -190, -190, 0, 0, -190, 0, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, -190, -190, -190, -190, -190, -190, -190, -190, -190, -190, -190,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 228
-        -97, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -97, -97, -97, -97, 0, -97, -97, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 229
-        -172, 0, -172, 0, 0, 0, -172, -172, -172, -172, -172, -172, -172, 0, -172, -172, -172, -172, 0, -172, -172, -172, -172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 230
-        -64, 0, -64, 0, 0, 0, -64, -64, -64, -64, -64, -64, -64, 0, -64, -64, -64, -64, 0, -64, -64, -64, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -263, -263, 0, 0, 0, -263, 0, 0, 0, 0, 0, 0, 0, -263, 0, 0, 0, 0, 0, 0, 0, 0, 0, -263, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -263, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 231
-        -179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 77, 76, 74, 0, 75, 73, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -274, -274, 0, 0, 0, -274, 0, 0, 0, 0, 0, 0, 0, -274, 0, 0, 0, 0, 0, 0, 0, 0, 0, -274, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -274, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 232
-        -62, 0, -62, 0, 0, 0, -62, -62, -62, -62, -62, -62, -62, 0, -62, -62, -62, -62, 0, -62, -62, -62, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -275, -275, 0, 0, 0, -275, 0, 0, 0, 0, 0, 0, 0, -275, 0, 0, 0, 0, 0, 0, 0, 0, 0, -275, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -275, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 233
-        -60, 0, -60, 0, 78, 0, -60, -60, -60, -60, -60, -60, -60, 0, -60, -60, -60, -60, 0, -60, -60, -60, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -276, -276, 0, 0, 0, -276, 0, 0, 0, 0, 0, 0, 0, -276, 0, 0, 0, 0, 0, 0, 0, 0, 0, -276, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -276, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 234
-        -26, 0, 0, 0, 0, 0, -26, 0, 81, -26, 0, 79, 80, 0, -26, -26, -26, -26, 0, -26, -26, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -262, -262, 0, 0, 0, -262, 0, 0, 0, 0, 0, 0, 0, -262, 0, 0, 0, 0, 0, 0, 0, 0, 0, -262, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -262, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 235
-        -61, 0, -61, 0, 0, 0, -61, -61, -61, -61, -61, -61, -61, 0, -61, -61, -61, -61, 0, -61, -61, -61, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -268, -268, 0, 0, 0, -268, 0, 0, 0, 0, 0, 0, 0, -268, 0, 0, 0, 0, 0, 0, 0, 0, 0, -268, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -268, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 236
-        -231, 0, 83, 0, 0, 0, -231, 256, -231, -231, 255, -231, -231, 0, -231, -231, -231, -231, 0, -231, -231, 82, -231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -270, -270, 0, 0, 0, -270, 0, 0, 0, 0, 0, 0, 0, -270, 0, 0, 0, 0, 0, 0, 0, 0, 0, -270, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -270, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 237
-        -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -272, -272, 0, 0, 0, -272, 0, 0, 0, 0, 0, 0, 0, -272, 0, 0, 0, 0, 0, 0, 0, 0, 0, -272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -272, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 238
-        -63, 0, -63, 0, 0, 0, -63, -63, -63, -63, -63, -63, -63, 0, -63, -63, -63, -63, 0, -63, -63, -63, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -266, -266, 0, 0, 0, -266, 0, 0, 0, 0, 0, 0, 0, -266, 0, 0, 0, 0, 0, 0, 0, 0, 0, -266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 239
-        -151, 0, 0, 0, 0, 0, -151, 0, -151, -151, 0, -151, -151, 0, -151, -151, -151, -151, 0, -151, -151, 0, -151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -261, -261, 0, 0, 0, -261, 0, 0, 0, 0, 0, 0, 0, -261, 0, 0, 0, 0, 0, 0, 0, 0, 0, -261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 240
-        -73, 0, -73, 0, 0, 0, -73, -73, -73, -73, -73, -73, -73, 0, -73, -73, -73, -73, 0, -73, -73, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -264, -264, 0, 0, 0, -264, 0, 0, 0, 0, 0, 0, 0, -264, 0, 0, 0, 0, 0, 0, 0, 0, 0, -264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 241
-        -74, 0, -74, 0, 0, 0, -74, -74, -74, -74, -74, -74, -74, 0, -74, -74, -74, -74, 0, -74, -74, -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -269, -269, 0, 0, 0, -269, 0, 0, 0, 0, 0, 0, 0, -269, 0, 0, 0, 0, 0, 0, 0, 0, 0, -269, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -269, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 242
-        -75, 0, -75, 0, 0, 0, -75, -75, -75, -75, -75, -75, -75, 0, -75, -75, -75, -75, 0, -75, -75, -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -271, -271, 0, 0, 0, -271, 0, 0, 0, 0, 0, 0, 0, -271, 0, 0, 0, 0, 0, 0, 0, 0, 0, -271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 243
-        -67, 0, -67, 0, 0, 0, -67, -67, -67, -67, -67, -67, -67, 0, -67, -67, -67, -67, 0, -67, -67, -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -273, -273, 0, 0, 0, -273, 0, 0, 0, 0, 0, 0, 0, -273, 0, 0, 0, 0, 0, 0, 0, 0, 0, -273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 244
-        -69, 0, -69, 0, 0, 0, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, -69, 0, -69, -69, -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -267, -267, 0, 0, 0, -267, 0, 0, 0, 0, 0, 0, 0, -267, 0, 0, 0, 0, 0, 0, 0, 0, 0, -267, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -267, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 245
-        -71, 0, -71, 0, 0, 0, -71, -71, -71, -71, -71, -71, -71, 0, -71, -71, -71, -71, 0, -71, -71, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -265, -265, 0, 0, 0, -265, 0, 0, 0, 0, 0, 0, 0, -265, 0, 0, 0, 0, 0, 0, 0, 0, 0, -265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 246
-        -65, 0, -65, 0, 0, 0, -65, -65, -65, -65, -65, -65, -65, 0, -65, -65, -65, -65, 0, -65, -65, -65, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 247
-        -68, 0, -68, 0, 0, 0, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, -68, 0, -68, -68, -68, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 248
-        -70, 0, -70, 0, 0, 0, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, -70, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -251, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -251, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 249
-        -72, 0, -72, 0, 0, 0, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, -72, 0, -72, -72, -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 250
-        -66, 0, -66, 0, 0, 0, -66, -66, -66, -66, -66, -66, -66, 0, -66, -66, -66, -66, 0, -66, -66, -66, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 251
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 260, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 252
-        0, -189, -189, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -189, -189, 0, 0, -189, -189, -189, -189, -189, -189, 0, 0, -189, 0, 0, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, -189, -189, -189, -189, -189, -189, -189, -189, -189, -189, -189,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 273, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 253
-        0, -188, -188, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -188, -188, 0, 0, -188, -188, -188, -188, -188, -188, 0, 0, -188, 0, 0, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, -188, -188, -188, -188, -188, -188, -188, -188, -188, -188, -188,
+        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 254
-        -167, 0, -167, 0, 0, 0, -167, -167, -167, -167, -167, -167, -167, 0, -167, -167, -167, -167, 0, -167, -167, -167, -167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 274, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 255
-        -166, 0, -166, 0, 0, 0, -166, -166, -166, -166, -166, -166, -166, 0, -166, -166, -166, -166, 0, -166, -166, -166, -166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, -164, 0, -164, -164, 0, -164, -164, -164, -164, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 256
-        -78, 0, -78, 0, 0, 0, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, -78, 0, -78, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, -47, -47, -47, -47, 0, 0, 0, -47, 0, 0, -47, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 257
-        0, 0, 0, 0, 0, 279, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 278, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 258
-        -76, 0, -76, 0, 0, 0, -76, -76, -76, -76, -76, -76, -76, 0, -76, -76, -76, -76, 0, -76, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -44, -44, -44, 0, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, 0, -44, -44, -44, -44, -44, 0, 0, 0, -44, 0, 0, -44, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 259
-        0, -192, -192, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -192, -192, 0, 0, -192, -192, -192, -192, -192, -192, 0, 0, -192, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, -192, -192, -192, -192, -192, -192, -192, -192, -192, -192, -192,
+        0, -196, -196, -196, 0, -196, -196, -196, -196, -196, -196, -196, -196, -196, -196, -196, -196, -196, 0, -196, -196, -196, -196, -196, 0, 0, 0, -196, 0, 0, -196, 0, 0, 0, 0, 0, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 260
-        0, -186, -186, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -186, -186, 0, 86, -186, -186, -186, -186, -186, -186, 0, 0, -186, 0, 0, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186,
+        0, -71, -71, -71, 0, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, 0, -71, -71, -71, -71, -71, 0, 0, 0, -71, 0, 0, -71, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 261
-        -25, 0, 0, 0, 0, 0, -25, 0, 81, -25, 0, 79, 80, 0, -25, -25, -25, -25, 0, -25, -25, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -7, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 262
-        -24, 0, 0, 0, 0, 0, -24, 0, 81, -24, 0, 79, 80, 0, -24, -24, -24, -24, 0, -24, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -68, -68, -68, 0, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, -68, -68, 0, 0, 0, -68, 0, 0, -68, 0, 0, 0, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 263
-        -178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 77, 76, 74, 0, 75, 73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, -130, 0, -130, -130, 0, -130, -130, -130, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 264
-        -91, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -91, -91, -91, -91, 0, -91, -91, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 265
-        -96, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -96, -96, -96, -96, 0, -96, -96, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 266
-        -94, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -94, -94, -94, -94, 0, -94, -94, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 267
-        -95, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -95, -95, -95, -95, 0, -95, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, -149, 0, -149, -149, 0, -149, -149, -149, -149, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 268
-        -93, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -93, -93, -93, -93, 0, -93, -93, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 269
-        -92, 0, 0, 0, 0, 0, 70, 0, 0, 69, 0, 0, 0, 0, -92, -92, -92, -92, 0, -92, -92, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 270
-        -57, 0, -57, 0, 0, 0, -57, -57, -57, -57, -57, -57, -57, 0, -57, -57, -57, -57, 0, -57, -57, -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 271
-        -149, 0, 0, 0, 0, 0, -149, 0, -149, -149, 0, -149, -149, 0, -149, -149, -149, -149, 0, -149, -149, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 283, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 272
-        -150, 0, 0, 0, 0, 0, -150, 0, -150, -150, 0, -150, -150, 0, -150, -150, -150, -150, 0, -150, -150, 0, -150, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -166, 0, 0, 0, 0, 0, -166, 0, -166, -166, 0, -166, -166, -166, -166, -166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 273
-        -148, 0, 0, 0, 0, 0, -148, 0, -148, -148, 0, -148, -148, 0, -148, -148, -148, -148, 0, -148, -148, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, 0, -163, 0, -163, -163, 0, -163, -163, -163, -163, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 274
-        -171, 0, -171, 0, 87, 0, -171, -171, -171, -171, -171, -171, -171, 0, -171, -171, -171, -171, 0, -171, -171, -171, -171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 275
-        0, 0, 0, 282, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 276
-        0, 0, 0, 283, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -258, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 277
-        -79, 0, -79, 0, 0, 0, -79, -79, -79, -79, -79, -79, -79, 0, -79, -79, -79, -79, 0, -79, -79, -79, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -46, -46, -46, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46, -46, 0, 0, 0, -46, 0, 0, -46, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 278
-        -77, 0, -77, 0, 0, 0, -77, -77, -77, -77, -77, -77, -77, 0, -77, -77, -77, -77, 0, -77, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -43, -43, -43, 0, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, 0, -43, -43, -43, -43, -43, 0, 0, 0, -43, 0, 0, -43, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 279
-        0, 0, 0, 0, 0, 285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -199, -199, -199, 0, -199, -199, -199, -199, -199, -199, -199, -199, -199, -199, -199, -199, -199, 0, -199, -199, -199, -199, -199, 0, 0, 0, -199, 0, 0, -199, 0, 0, 0, 0, 0, -199, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 280
-        -58, 0, -58, 0, 0, 0, -58, -58, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, 0, -58, -58, -58, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, -8, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 281
-        -165, 0, -165, 0, 0, 0, -165, -165, -165, -165, -165, -165, -165, 0, -165, -165, -165, -165, 0, -165, -165, -165, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -70, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, -70, -70, 0, 0, 0, -70, 0, 0, -70, 0, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 282
-        -80, 0, -80, 0, 0, 0, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, -80, -80, 0, -80, -80, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, 0, 0, 0, 0, 0, -165, 0, -165, -165, 0, -165, -165, -165, -165, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 283
-        0, -187, -187, 0, -187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -187, -187, 0, 0, -187, -187, -187, -187, -187, -187, 0, 0, -187, 0, 0, 0, -187, 0, 0, 0, 0, 0, 0, 0, 0, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187,
+        0, -45, -45, -45, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, -45, -45, -45, 0, 0, 0, -45, 0, 0, -45, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 284
-        -59, 0, -59, 0, 0, 0, -59, -59, -59, -59, -59, -59, -59, 0, -59, -59, -59, -59, 0, -59, -59, -59, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 293, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 285
-        -168, 0, -168, 0, 0, 0, -168, -168, -168, -168, -168, -168, -168, 0, -168, -168, -168, -168, 0, -168, -168, -168, -168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -201, -201, -201, 0, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, 0, -201, -201, -201, -201, -201, 0, 0, 0, -201, 0, 0, -201, 0, 0, 0, 0, 0, -201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 286
-        0, 0, 0, 0, 0, 289, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -245, -245, 0, -245, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -245, -245, -245, 0, 0, -245, 0, -245, -245, -245, -245, -245, 0, 0, -245, 0, 0, 0, 0, -245, 0, 0, 0, 0, 0, 0, 0, 0, -245, -245, -245, -245, -245, -245, -245, -245, -245, -245, -245, -245,
         // State 287
-        -169, 0, -169, 0, 0, 0, -169, -169, -169, -169, -169, -169, -169, 0, -169, -169, -169, -169, 0, -169, -169, -169, -169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -232, -232, 0, -232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -232, -232, -232, 0, 0, -232, 0, -232, -232, -232, -232, -232, 0, 0, -232, 0, 0, 0, 0, -232, 0, 0, 0, 0, 0, 0, 0, 0, -232, -232, -232, -232, -232, -232, -232, -232, -232, -232, -232, -232,
         // State 288
-        -170, 0, -170, 0, 0, 0, -170, -170, -170, -170, -170, -170, -170, 0, -170, -170, -170, -170, 0, -170, -170, -170, -170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -233, -233, 0, -233, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -233, -233, -233, 0, 0, -233, 0, -233, -233, -233, -233, -233, 0, 0, -233, 0, 0, 0, 0, -233, 0, 0, 0, 0, 0, 0, 0, 0, -233, -233, -233, -233, -233, -233, -233, -233, -233, -233, -233, -233,
+        // State 289
+        0, -101, -101, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, 0, -101, -101, 0, -101, -101, -101, -101, -101, 0, -101, -101, -101, -101, 0, -101, -101, -101, -101, -101, 0, 0, 0, 0, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101,
+        // State 290
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 325, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 291
+        0, -260, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 292
+        0, -203, -203, -203, 0, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, 0, -203, -203, -203, -203, -203, 0, 0, 0, -203, 0, 0, -203, 0, 0, 0, 0, 0, -203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 293
+        0, -200, -200, -200, 0, -200, -200, -200, -200, -200, -200, -200, -200, -200, -200, -200, -200, -200, 0, -200, -200, -200, -200, -200, 0, 0, 0, -200, 0, 0, -200, 0, 0, 0, 0, 0, -200, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 294
+        0, -234, -234, 0, -234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -234, -234, -234, 0, 0, -234, 0, -234, -234, -234, -234, -234, 0, 0, -234, 0, 0, 0, 0, -234, 0, 0, 0, 0, 0, 0, 0, 0, -234, -234, -234, -234, -234, -234, -234, -234, -234, -234, -234, -234,
+        // State 295
+        0, -242, -242, 0, -242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -242, -242, -242, 0, 0, -242, 0, -242, -242, -242, -242, -242, 0, 0, -242, 0, 0, 0, 0, -242, 0, 0, 0, 0, 0, 0, 0, 0, -242, -242, -242, -242, -242, -242, -242, -242, -242, -242, -242, -242,
+        // State 296
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 297
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 298
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 328, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 299
+        0, -240, -240, 0, -240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -240, -240, -240, 0, 0, -240, 0, -240, -240, -240, -240, -240, 0, 0, -240, 0, 0, 0, 0, -240, 0, 0, 0, 0, 0, 0, 0, 0, -240, -240, -240, -240, -240, -240, -240, -240, -240, -240, -240, -240,
+        // State 300
+        -122, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -122, -122, -122, -122, 0, -122, -122, 0, -122, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 301
+        -215, 0, -215, 0, 0, 0, -215, -215, -215, -215, -215, -215, -215, 0, -215, -215, -215, -215, 0, -215, -215, -215, -215, 0, 0, 0, 0, 0, 0, 0, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 302
+        -82, 0, -82, 0, 0, 0, -82, -82, -82, -82, -82, -82, -82, 0, -82, -82, -82, -82, 0, -82, -82, -82, -82, 0, 0, 0, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 303
+        -81, 0, -81, 0, 0, 0, -81, -81, -81, -81, -81, -81, -81, 0, -81, -81, -81, -81, 0, -81, -81, -81, -81, 0, 0, 0, 0, 0, 0, 0, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 304
+        -222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 102, 101, 98, 0, 100, 97, 0, 95, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 305
+        -79, 0, -79, 0, 0, 0, -79, -79, -79, -79, -79, -79, -79, 0, -79, -79, -79, -79, 0, -79, -79, -79, -79, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 306
+        -77, 0, -77, 0, 103, 0, -77, -77, -77, -77, -77, -77, -77, 0, -77, -77, -77, -77, 0, -77, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 307
+        -36, 0, 0, 0, 0, 0, -36, 0, 106, -36, 0, 104, 105, 0, -36, -36, -36, -36, 0, -36, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 308
+        -78, 0, -78, 0, 0, 0, -78, -78, -78, -78, -78, -78, -78, 0, -78, -78, -78, -78, 0, -78, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 309
+        -282, 0, 108, 0, 0, 0, -282, 331, -282, -282, 330, -282, -282, 0, -282, -282, -282, -282, 0, -282, -282, 107, -282, 0, 0, 0, 0, 0, 0, 0, -282, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 310
+        -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 311
+        -80, 0, -80, 0, 0, 0, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, -80, -80, 0, -80, -80, -80, -80, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 312
+        -188, 0, 0, 0, 0, 0, -188, 0, -188, -188, 0, -188, -188, 0, -188, -188, -188, -188, 0, -188, -188, 0, -188, 0, 0, 0, 0, 0, 0, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 313
+        -91, 0, -91, 0, 0, 0, -91, -91, -91, -91, -91, -91, -91, 0, -91, -91, -91, -91, 0, -91, -91, -91, -91, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 314
+        -92, 0, -92, 0, 0, 0, -92, -92, -92, -92, -92, -92, -92, 0, -92, -92, -92, -92, 0, -92, -92, -92, -92, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 315
+        -93, 0, -93, 0, 0, 0, -93, -93, -93, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, -93, -93, -93, -93, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 316
+        -85, 0, -85, 0, 0, 0, -85, -85, -85, -85, -85, -85, -85, 0, -85, -85, -85, -85, 0, -85, -85, -85, -85, 0, 0, 0, 0, 0, 0, 0, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 317
+        -87, 0, -87, 0, 0, 0, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, -87, -87, 0, -87, -87, -87, -87, 0, 0, 0, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 318
+        -89, 0, -89, 0, 0, 0, -89, -89, -89, -89, -89, -89, -89, 0, -89, -89, -89, -89, 0, -89, -89, -89, -89, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 319
+        -83, 0, -83, 0, 0, 0, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, -83, -83, 0, -83, -83, -83, -83, 0, 0, 0, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 320
+        -86, 0, -86, 0, 0, 0, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, -86, 0, -86, -86, -86, -86, 0, 0, 0, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 321
+        -88, 0, -88, 0, 0, 0, -88, -88, -88, -88, -88, -88, -88, 0, -88, -88, -88, -88, 0, -88, -88, -88, -88, 0, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 322
+        -90, 0, -90, 0, 0, 0, -90, -90, -90, -90, -90, -90, -90, 0, -90, -90, -90, -90, 0, -90, -90, -90, -90, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 323
+        -84, 0, -84, 0, 0, 0, -84, -84, -84, -84, -84, -84, -84, 0, -84, -84, -84, -84, 0, -84, -84, -84, -84, 0, 0, 0, 0, 0, 0, 0, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 324
+        0, -241, -241, 0, -241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -241, -241, -241, 0, 0, -241, 0, -241, -241, -241, -241, -241, 0, 0, -241, 0, 0, 0, 0, -241, 0, 0, 0, 0, 0, 0, 0, 0, -241, -241, -241, -241, -241, -241, -241, -241, -241, -241, -241, -241,
+        // State 325
+        0, -202, -202, -202, 0, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, 0, -202, -202, -202, -202, -202, 0, 0, 0, -202, 0, 0, -202, 0, 0, 0, 0, 0, -202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 326
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 327
+        0, -239, -239, 0, -239, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -239, -239, -239, 0, 0, -239, 0, -239, -239, -239, -239, -239, 0, 0, -239, 0, 0, 0, 0, -239, 0, 0, 0, 0, 0, 0, 0, 0, -239, -239, -239, -239, -239, -239, -239, -239, -239, -239, -239, -239,
+        // State 328
+        0, -238, -238, 0, -238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -238, -238, -238, 0, 0, -238, 0, -238, -238, -238, -238, -238, 0, 0, -238, 0, 0, 0, 0, -238, 0, 0, 0, 0, 0, 0, 0, 0, -238, -238, -238, -238, -238, -238, -238, -238, -238, -238, -238, -238,
+        // State 329
+        -208, 0, -208, 0, 0, 0, -208, -208, -208, -208, -208, -208, -208, 0, -208, -208, -208, -208, 0, -208, -208, -208, -208, 0, 0, 0, 0, 0, 0, 0, -208, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 330
+        -207, 0, -207, 0, 0, 0, -207, -207, -207, -207, -207, -207, -207, 0, -207, -207, -207, -207, 0, -207, -207, -207, -207, 0, 0, 0, 0, 0, 0, 0, -207, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 331
+        -96, 0, -96, 0, 0, 0, -96, -96, -96, -96, -96, -96, -96, 0, -96, -96, -96, -96, 0, -96, -96, -96, -96, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 332
+        0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 333
+        -94, 0, -94, 0, 0, 0, -94, -94, -94, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, -94, -94, -94, -94, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 334
+        0, -243, -243, 0, -243, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -243, -243, -243, 0, 0, -243, 0, -243, -243, -243, -243, -243, 0, 0, -243, 0, 0, 0, 0, -243, 0, 0, 0, 0, 0, 0, 0, 0, -243, -243, -243, -243, -243, -243, -243, -243, -243, -243, -243, -243,
+        // State 335
+        0, -236, -236, 0, -236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -236, -236, -236, 0, 114, -236, 0, -236, -236, -236, -236, -236, 0, 0, -236, 0, 0, 0, 0, -236, 0, 0, 0, 0, 0, 0, 0, 0, -236, -236, -236, -236, -236, -236, -236, -236, -236, -236, -236, -236,
+        // State 336
+        -35, 0, 0, 0, 0, 0, -35, 0, 106, -35, 0, 104, 105, 0, -35, -35, -35, -35, 0, -35, -35, 0, -35, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 337
+        -34, 0, 0, 0, 0, 0, -34, 0, 106, -34, 0, 104, 105, 0, -34, -34, -34, -34, 0, -34, -34, 0, -34, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 338
+        -221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 102, 101, 98, 0, 100, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 339
+        -115, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -115, -115, -115, -115, 0, -115, -115, 0, -115, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 340
+        -120, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -120, -120, -120, -120, 0, -120, -120, 0, -120, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 341
+        -118, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -118, -118, -118, -118, 0, -118, -118, 0, -118, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 342
+        -121, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -121, -121, -121, -121, 0, -121, -121, 0, -121, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 343
+        -119, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -119, -119, -119, -119, 0, -119, -119, 0, -119, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 344
+        -117, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -117, -117, -117, -117, 0, -117, -117, 0, -117, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 345
+        -116, 0, 0, 0, 0, 0, 94, 0, 0, 93, 0, 0, 0, 0, -116, -116, -116, -116, 0, -116, -116, 0, -116, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 346
+        -72, 0, -72, 0, 0, 0, -72, -72, -72, -72, -72, -72, -72, 0, -72, -72, -72, -72, 0, -72, -72, -72, -72, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 347
+        -186, 0, 0, 0, 0, 0, -186, 0, -186, -186, 0, -186, -186, 0, -186, -186, -186, -186, 0, -186, -186, 0, -186, 0, 0, 0, 0, 0, 0, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 348
+        -187, 0, 0, 0, 0, 0, -187, 0, -187, -187, 0, -187, -187, 0, -187, -187, -187, -187, 0, -187, -187, 0, -187, 0, 0, 0, 0, 0, 0, 0, -187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 349
+        -185, 0, 0, 0, 0, 0, -185, 0, -185, -185, 0, -185, -185, 0, -185, -185, -185, -185, 0, -185, -185, 0, -185, 0, 0, 0, 0, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 350
+        -214, 0, -214, 0, 116, 0, -214, -214, -214, -214, -214, -214, -214, 0, -214, -214, -214, -214, 0, -214, -214, -214, -214, 0, 0, 0, 0, 0, 0, 0, -214, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 351
+        0, 0, 0, 359, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 352
+        0, 0, 0, 360, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 353
+        -98, 0, -98, 0, 0, 0, -98, -98, -98, -98, -98, -98, -98, 0, -98, -98, -98, -98, 0, -98, -98, -98, -98, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 354
+        -95, 0, -95, 0, 0, 0, -95, -95, -95, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, -95, -95, -95, -95, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 355
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 362, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 356
+        0, 0, 0, 0, 0, 364, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 357
+        -74, 0, -74, 0, 0, 0, -74, -74, -74, -74, -74, -74, -74, 0, -74, -74, -74, -74, 0, -74, -74, -74, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 358
+        -206, 0, -206, 0, 0, 0, -206, -206, -206, -206, -206, -206, -206, 0, -206, -206, -206, -206, 0, -206, -206, -206, -206, 0, 0, 0, 0, 0, 0, 0, -206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 359
+        -100, 0, -100, 0, 0, 0, -100, -100, -100, -100, -100, -100, -100, 0, -100, -100, -100, -100, 0, -100, -100, -100, -100, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 360
+        -97, 0, -97, 0, 0, 0, -97, -97, -97, -97, -97, -97, -97, 0, -97, -97, -97, -97, 0, -97, -97, -97, -97, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 361
+        0, -235, -235, 0, -235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -235, -235, -235, 0, 0, -235, 0, -235, -235, -235, -235, -235, 0, 0, -235, 0, 0, 0, 0, -235, 0, 0, 0, 0, 0, 0, 0, 0, -235, -235, -235, -235, -235, -235, -235, -235, -235, -235, -235, -235,
+        // State 362
+        0, -237, -237, 0, -237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -237, -237, -237, 0, 0, -237, 0, -237, -237, -237, -237, -237, 0, 0, -237, 0, 0, 0, 0, -237, 0, 0, 0, 0, 0, 0, 0, 0, -237, -237, -237, -237, -237, -237, -237, -237, -237, -237, -237, -237,
+        // State 363
+        -76, 0, -76, 0, 0, 0, -76, -76, -76, -76, -76, -76, -76, 0, -76, -76, -76, -76, 0, -76, -76, -76, -76, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 364
+        -73, 0, -73, 0, 0, 0, -73, -73, -73, -73, -73, -73, -73, 0, -73, -73, -73, -73, 0, -73, -73, -73, -73, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 365
+        -209, 0, -209, 0, 0, 0, -209, -209, -209, -209, -209, -209, -209, 0, -209, -209, -209, -209, 0, -209, -209, -209, -209, 0, 0, 0, 0, 0, 0, 0, -209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 366
+        -99, 0, -99, 0, 0, 0, -99, -99, -99, -99, -99, -99, -99, 0, -99, -99, -99, -99, 0, -99, -99, -99, -99, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 367
+        -75, 0, -75, 0, 0, 0, -75, -75, -75, -75, -75, -75, -75, 0, -75, -75, -75, -75, 0, -75, -75, -75, -75, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 368
+        0, 0, 0, 0, 0, 371, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 369
+        -211, 0, -211, 0, 0, 0, -211, -211, -211, -211, -211, -211, -211, 0, -211, -211, -211, -211, 0, -211, -211, -211, -211, 0, 0, 0, 0, 0, 0, 0, -211, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 370
+        -213, 0, -213, 0, 0, 0, -213, -213, -213, -213, -213, -213, -213, 0, -213, -213, -213, -213, 0, -213, -213, -213, -213, 0, 0, 0, 0, 0, 0, 0, -213, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 371
+        -210, 0, -210, 0, 0, 0, -210, -210, -210, -210, -210, -210, -210, 0, -210, -210, -210, -210, 0, -210, -210, -210, -210, 0, 0, 0, 0, 0, 0, 0, -210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 372
+        -212, 0, -212, 0, 0, 0, -212, -212, -212, -212, -212, -212, -212, 0, -212, -212, -212, -212, 0, -212, -212, -212, -212, 0, 0, 0, 0, 0, 0, 0, -212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 60 + integer]
+        __ACTION[(state as usize) * 64 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
-        -182,
+        -230,
         // State 1
-        -183,
+        0,
         // State 2
         0,
         // State 3
-        0,
+        -231,
         // State 4
         0,
         // State 5
@@ -857,37 +1046,37 @@ mod __parse__Start {
         // State 87
         0,
         // State 88
-        -134,
+        0,
         // State 89
-        -129,
+        0,
         // State 90
-        -132,
+        0,
         // State 91
-        -138,
+        0,
         // State 92
-        -133,
+        0,
         // State 93
-        -131,
+        0,
         // State 94
-        -236,
+        0,
         // State 95
-        -135,
+        0,
         // State 96
-        -130,
+        0,
         // State 97
         0,
         // State 98
-        -139,
+        0,
         // State 99
         0,
         // State 100
         0,
         // State 101
-        -122,
+        0,
         // State 102
         0,
         // State 103
-        -173,
+        0,
         // State 104
         0,
         // State 105
@@ -897,11 +1086,11 @@ mod __parse__Start {
         // State 107
         0,
         // State 108
-        -128,
+        0,
         // State 109
-        -153,
+        0,
         // State 110
-        -233,
+        0,
         // State 111
         0,
         // State 112
@@ -925,37 +1114,37 @@ mod __parse__Start {
         // State 121
         0,
         // State 122
-        0,
+        -173,
         // State 123
-        0,
+        -168,
         // State 124
-        0,
+        -167,
         // State 125
         0,
         // State 126
-        0,
+        -171,
         // State 127
-        0,
+        -225,
         // State 128
-        0,
+        -172,
         // State 129
-        0,
+        -170,
         // State 130
-        0,
+        -228,
         // State 131
-        0,
+        -287,
         // State 132
-        0,
+        -174,
         // State 133
-        0,
+        -169,
         // State 134
-        0,
+        -155,
         // State 135
         0,
         // State 136
         0,
         // State 137
-        0,
+        -229,
         // State 138
         0,
         // State 139
@@ -963,33 +1152,33 @@ mod __parse__Start {
         // State 140
         0,
         // State 141
-        0,
+        -216,
         // State 142
         0,
         // State 143
         0,
         // State 144
-        -232,
+        0,
         // State 145
         0,
         // State 146
         0,
         // State 147
-        0,
+        -162,
         // State 148
-        0,
+        -190,
         // State 149
-        0,
+        -284,
         // State 150
         0,
         // State 151
-        -98,
+        0,
         // State 152
         0,
         // State 153
         0,
         // State 154
-        -196,
+        0,
         // State 155
         0,
         // State 156
@@ -1059,9 +1248,9 @@ mod __parse__Start {
         // State 188
         0,
         // State 189
-        0,
+        -161,
         // State 190
-        0,
+        -283,
         // State 191
         0,
         // State 192
@@ -1077,13 +1266,13 @@ mod __parse__Start {
         // State 197
         0,
         // State 198
-        0,
+        -123,
         // State 199
         0,
         // State 200
         0,
         // State 201
-        0,
+        -247,
         // State 202
         0,
         // State 203
@@ -1093,7 +1282,7 @@ mod __parse__Start {
         // State 205
         0,
         // State 206
-        -116,
+        0,
         // State 207
         0,
         // State 208
@@ -1121,7 +1310,7 @@ mod __parse__Start {
         // State 219
         0,
         // State 220
-        -81,
+        0,
         // State 221
         0,
         // State 222
@@ -1191,7 +1380,7 @@ mod __parse__Start {
         // State 254
         0,
         // State 255
-        0,
+        -164,
         // State 256
         0,
         // State 257
@@ -1207,7 +1396,7 @@ mod __parse__Start {
         // State 262
         0,
         // State 263
-        0,
+        -130,
         // State 264
         0,
         // State 265
@@ -1215,7 +1404,7 @@ mod __parse__Start {
         // State 266
         0,
         // State 267
-        0,
+        -149,
         // State 268
         0,
         // State 269
@@ -1225,9 +1414,9 @@ mod __parse__Start {
         // State 271
         0,
         // State 272
-        0,
+        -166,
         // State 273
-        0,
+        -163,
         // State 274
         0,
         // State 275
@@ -1245,7 +1434,7 @@ mod __parse__Start {
         // State 281
         0,
         // State 282
-        0,
+        -165,
         // State 283
         0,
         // State 284
@@ -1258,192 +1447,402 @@ mod __parse__Start {
         0,
         // State 288
         0,
+        // State 289
+        -101,
+        // State 290
+        0,
+        // State 291
+        0,
+        // State 292
+        0,
+        // State 293
+        0,
+        // State 294
+        0,
+        // State 295
+        0,
+        // State 296
+        0,
+        // State 297
+        0,
+        // State 298
+        0,
+        // State 299
+        0,
+        // State 300
+        0,
+        // State 301
+        0,
+        // State 302
+        0,
+        // State 303
+        0,
+        // State 304
+        0,
+        // State 305
+        0,
+        // State 306
+        0,
+        // State 307
+        0,
+        // State 308
+        0,
+        // State 309
+        0,
+        // State 310
+        0,
+        // State 311
+        0,
+        // State 312
+        0,
+        // State 313
+        0,
+        // State 314
+        0,
+        // State 315
+        0,
+        // State 316
+        0,
+        // State 317
+        0,
+        // State 318
+        0,
+        // State 319
+        0,
+        // State 320
+        0,
+        // State 321
+        0,
+        // State 322
+        0,
+        // State 323
+        0,
+        // State 324
+        0,
+        // State 325
+        0,
+        // State 326
+        0,
+        // State 327
+        0,
+        // State 328
+        0,
+        // State 329
+        0,
+        // State 330
+        0,
+        // State 331
+        0,
+        // State 332
+        0,
+        // State 333
+        0,
+        // State 334
+        0,
+        // State 335
+        0,
+        // State 336
+        0,
+        // State 337
+        0,
+        // State 338
+        0,
+        // State 339
+        0,
+        // State 340
+        0,
+        // State 341
+        0,
+        // State 342
+        0,
+        // State 343
+        0,
+        // State 344
+        0,
+        // State 345
+        0,
+        // State 346
+        0,
+        // State 347
+        0,
+        // State 348
+        0,
+        // State 349
+        0,
+        // State 350
+        0,
+        // State 351
+        0,
+        // State 352
+        0,
+        // State 353
+        0,
+        // State 354
+        0,
+        // State 355
+        0,
+        // State 356
+        0,
+        // State 357
+        0,
+        // State 358
+        0,
+        // State 359
+        0,
+        // State 360
+        0,
+        // State 361
+        0,
+        // State 362
+        0,
+        // State 363
+        0,
+        // State 364
+        0,
+        // State 365
+        0,
+        // State 366
+        0,
+        // State 367
+        0,
+        // State 368
+        0,
+        // State 369
+        0,
+        // State 370
+        0,
+        // State 371
+        0,
+        // State 372
+        0,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => match state {
-                44 => 200,
-                56 => 216,
-                83 => 276,
-                84 => 279,
-                87 => 286,
-                _ => 174,
-            },
-            5 => 150,
-            11 => 155,
-            12 => match state {
-                23 => 159,
-                24 => 160,
-                25 => 161,
-                26 => 162,
-                27 => 163,
-                28 => 164,
-                _ => 111,
+            4 => match state {
+                58 => 257,
+                74 => 284,
+                108 => 352,
+                109 => 355,
+                110 => 356,
+                119 => 368,
+                _ => 224,
             },
+            7 => 196,
+            10 => 197,
             13 => match state {
-                71 => 264,
-                72 => 265,
-                73 => 266,
-                74 => 267,
-                75 => 268,
-                76 => 269,
-                _ => 228,
-            },
-            16 => 112,
-            17 => 229,
-            18 => match state {
-                64 => 253,
-                67 => 260,
-                85 => 283,
-                _ => 206,
+                79 => 296,
+                _ => 203,
             },
+            16 => 202,
             19 => match state {
-                62 | 68..=76 | 78..=80 => 230,
-                _ => 113,
+                32 => 208,
+                33 => 209,
+                34 => 210,
+                35 => 211,
+                36 => 212,
+                37 => 213,
+                38 => 214,
+                _ => 152,
             },
             20 => match state {
-                22 => 158,
-                _ => 114,
-            },
-            21 => match state {
-                70 => 263,
-                _ => 231,
+                95 => 339,
+                96 => 340,
+                97 => 341,
+                98 => 342,
+                99 => 343,
+                100 => 344,
+                101 => 345,
+                _ => 300,
             },
-            22 => 88,
-            23 => 142,
-            24 => 178,
+            23 => 153,
+            24 => 301,
             25 => match state {
-                30 => 44,
-                53 => 56,
-                57 => 59,
-                65 => 83,
-                77 => 84,
-                86 => 87,
-                10 => 115,
-                15 => 148,
-                35 => 173,
-                41 => 180,
-                45 => 204,
-                51 => 210,
-                54 => 215,
-                60 => 225,
-                61 => 226,
-                63 => 251,
-                66 => 257,
-                82 => 275,
-                _ => 36,
+                86 => 328,
+                91 => 335,
+                113 => 362,
+                _ => 267,
+            },
+            26 => match state {
+                82 | 92..=101 | 103..=105 => 302,
+                _ => 154,
+            },
+            27 => match state {
+                82 | 92..=101 | 103..=105 => 303,
+                _ => 155,
+            },
+            29 => match state {
+                31 => 207,
+                _ => 156,
             },
-            26 => 64,
             30 => match state {
-                40 => 179,
-                _ => 153,
+                94 => 338,
+                _ => 304,
             },
-            32 => 40,
+            31 => 122,
+            32 => 187,
+            33 => 247,
             34 => match state {
-                62 | 68..=76 | 78..=80 => 232,
-                _ => 116,
+                21 => 46,
+                40 => 58,
+                70 => 74,
+                75 => 78,
+                87 => 108,
+                89 => 109,
+                102 => 110,
+                115 => 119,
+                15 => 157,
+                22 => 194,
+                45 => 223,
+                54 => 249,
+                67 => 274,
+                71 | 73 | 83 | 116..=118 | 121 => 280,
+                80 => 297,
+                81 => 298,
+                84 => 326,
+                88 => 332,
+                107 => 351,
+                _ => 261,
             },
-            35 => 7,
-            36 => 89,
-            37 => match state {
-                37 => 177,
-                46 => 205,
-                _ => 16,
+            35 => 86,
+            36 => 123,
+            37 => 1,
+            38 => 183,
+            39 => match state {
+                48 => 228,
+                61 => 265,
+                _ => 23,
             },
-            42 => match state {
-                12 => 17,
-                13 | 43 | 50 => 18,
-                39 => 49,
-                3 => 100,
-                4 => 102,
-                5 => 103,
-                6 => 104,
-                7 => 105,
-                8 => 106,
-                9 => 107,
-                11 | 37 | 46 => 140,
-                29 | 52 | 58 => 165,
-                34 => 172,
-                62 | 68..=76 | 78..=80 => 233,
-                81 => 274,
-                _ => 117,
+            43 => match state {
+                53 => 248,
+                _ => 200,
             },
-            45 => 90,
-            46 => match state {
-                1 => 98,
-                _ => 91,
+            45 => 53,
+            47 => match state {
+                82 | 92..=101 | 103..=105 => 305,
+                _ => 158,
             },
-            48 => 1,
-            49 => match state {
-                49 => 208,
-                _ => 152,
+            48 => match state {
+                2 => 9,
+                _ => 8,
             },
-            51 => match state {
-                20 => 156,
-                21 => 157,
-                _ => 118,
+            49 => 124,
+            50 => match state {
+                50 => 246,
+                62 => 266,
+                _ => 24,
             },
-            52 => match state {
-                68 => 261,
-                69 => 262,
-                _ => 234,
+            55 => match state {
+                18 => 25,
+                19 | 56 | 65 => 26,
+                20 => 28,
+                52 => 64,
+                75 => 79,
+                0 | 3 => 125,
+                4..=5 => 138,
+                6 => 141,
+                7 => 142,
+                8 => 143,
+                9 => 144,
+                10 => 145,
+                11 => 146,
+                12 => 150,
+                13..=14 => 151,
+                16 | 48 | 61 => 184,
+                17 | 50 | 62 => 185,
+                39 | 68 | 76 => 215,
+                44 => 222,
+                57 | 85 => 253,
+                66 | 90 => 270,
+                82 | 92..=101 | 103..=105 => 306,
+                106 => 350,
+                _ => 159,
             },
-            53 => match state {
-                62 | 68..=76 | 78..=80 => 235,
-                _ => 119,
+            58 => 126,
+            59 => 127,
+            60 => match state {
+                5 => 140,
+                _ => 139,
             },
-            54 => 92,
-            55 => 141,
-            56 => 120,
-            57 => 236,
-            58 => 93,
-            59 => 2,
-            60 => 121,
-            61 => 237,
-            62 => 47,
-            63 => 94,
-            64 => 218,
-            65 => 223,
-            66 => 57,
-            67 => match state {
-                62 | 68..=76 | 78..=80 => 238,
-                _ => 122,
+            61 => match state {
+                64 => 268,
+                _ => 199,
             },
-            68 => 95,
-            69 => 143,
-            70 => match state {
-                43 => 198,
-                50 => 209,
-                _ => 19,
+            63 => match state {
+                29 => 205,
+                30 => 206,
+                _ => 160,
             },
-            71 => match state {
-                29 => 166,
-                _ => 211,
+            64 => match state {
+                92 => 336,
+                93 => 337,
+                _ => 307,
             },
-            72 => 167,
-            73 => match state {
-                58 => 221,
-                _ => 212,
+            65 => match state {
+                82 | 92..=101 | 103..=105 => 308,
+                _ => 161,
             },
+            66 => 128,
+            67 => 186,
+            68 => 162,
+            69 => 309,
+            70 => 129,
+            71 => 2,
+            72 => 163,
+            73 => 310,
             74 => match state {
-                48 => 207,
-                _ => 181,
+                47 => 227,
+                _ => 63,
             },
-            79 => match state {
-                31 => 169,
-                32 => 170,
-                33 => 171,
-                _ => 123,
+            75 => match state {
+                3 => 137,
+                _ => 130,
             },
-            80 => match state {
-                78 => 271,
-                79 => 272,
-                80 => 273,
-                _ => 239,
+            77 => 3,
+            78 => 131,
+            79 => 286,
+            80 => 287,
+            81 => 294,
+            82 => 75,
+            83 => match state {
+                82 | 92..=101 | 103..=105 => 311,
+                _ => 164,
             },
-            81 => match state {
-                57 => 219,
-                _ => 96,
+            84 => 132,
+            85 => 188,
+            86 => match state {
+                56 => 251,
+                65 => 269,
+                _ => 27,
+            },
+            87 => match state {
+                39 => 216,
+                _ => 275,
+            },
+            88 => 217,
+            89 => match state {
+                76 => 291,
+                _ => 276,
+            },
+            90 => match state {
+                55 => 250,
+                60 => 264,
+                _ => 229,
+            },
+            95 => match state {
+                41 => 219,
+                42 => 220,
+                43 => 221,
+                _ => 165,
+            },
+            96 => match state {
+                103 => 347,
+                104 => 348,
+                105 => 349,
+                _ => 312,
+            },
+            97 => match state {
+                75 => 288,
+                _ => 133,
             },
             _ => 0,
         }
@@ -1475,10 +1874,12 @@ mod __parse__Start {
         r###"DotDot"###,
         r###"Semi"###,
         r###"StrLiteral"###,
+        r###"CharLiteral"###,
         r###"If"###,
         r###"Then"###,
         r###"Else"###,
         r###"While"###,
+        r###"In"###,
         r###"IDENT"###,
         r###"NUM"###,
         r###"FLOAT"###,
@@ -1489,6 +1890,7 @@ mod __parse__Start {
         r###"Return"###,
         r###"Preprocessor"###,
         r###"Import"###,
+        r###"As"###,
         r###"Package"###,
         r###"Var"###,
         r###"Public"###,
@@ -1522,6 +1924,7 @@ mod __parse__Start {
         }).collect()
     }
     fn __expected_tokens_from_states<
+        '__0,
     >(
         __states: &[i16],
         _: core::marker::PhantomData<()>,
@@ -1535,12 +1938,13 @@ mod __parse__Start {
             }
         }).collect()
     }
-    struct __StateMachine<>
+    struct __StateMachine<'__0>
     where 
     {
+        errors: &'__0 mut Vec<ErrorRecovery<usize, Token, String>>,
         __phantom: core::marker::PhantomData<()>,
     }
-    impl<> __state_machine::ParserDefinition for __StateMachine<>
+    impl<'__0> __state_machine::ParserDefinition for __StateMachine<'__0>
     where 
     {
         type Location = usize;
@@ -1548,7 +1952,7 @@ mod __parse__Start {
         type Token = Token;
         type TokenIndex = usize;
         type Symbol = __Symbol<>;
-        type Success = Vec<Item>;
+        type Success = Vec<(Item, Span)>;
         type StateIndex = i16;
         type Action = i16;
         type ReduceIndex = i16;
@@ -1576,7 +1980,7 @@ mod __parse__Start {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 60 - 1)
+            __action(state, 64 - 1)
         }
 
         #[inline]
@@ -1603,7 +2007,7 @@ mod __parse__Start {
 
         #[inline]
         fn uses_error_recovery(&self) -> bool {
-            false
+            true
         }
 
         #[inline]
@@ -1611,7 +2015,7 @@ mod __parse__Start {
             &self,
             recovery: __state_machine::ErrorRecovery<Self>,
         ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
+            __Symbol::Variant1(recovery)
         }
 
         fn reduce(
@@ -1622,6 +2026,7 @@ mod __parse__Start {
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
+                self.errors,
                 action,
                 start_location,
                 states,
@@ -1667,41 +2072,44 @@ mod __parse__Start {
             Token::DotDot if true => Some(22),
             Token::Semi if true => Some(23),
             Token::StrLiteral(String) if true => Some(24),
-            Token::If if true => Some(25),
-            Token::Then if true => Some(26),
-            Token::Else if true => Some(27),
-            Token::While if true => Some(28),
-            Token::Ident(String) if true => Some(29),
-            Token::Num(i64) if true => Some(30),
-            Token::Float(f64) if true => Some(31),
-            Token::Bool(true) if true => Some(32),
-            Token::Bool(false) if true => Some(33),
-            Token::Comma if true => Some(34),
-            Token::Function if true => Some(35),
-            Token::Return if true => Some(36),
-            Token::Preprocessor if true => Some(37),
-            Token::Import if true => Some(38),
-            Token::Package if true => Some(39),
-            Token::Var if true => Some(40),
-            Token::Public if true => Some(41),
-            Token::Enum if true => Some(42),
-            Token::Struct if true => Some(43),
-            Token::TypeInt if true => Some(44),
-            Token::TypeFloat if true => Some(45),
-            Token::TypeBool if true => Some(46),
-            Token::TypeStr if true => Some(47),
-            Token::TypeUnit if true => Some(48),
-            Token::TypeI8 if true => Some(49),
-            Token::TypeU8 if true => Some(50),
-            Token::TypeI16 if true => Some(51),
-            Token::TypeU16 if true => Some(52),
-            Token::TypeI32 if true => Some(53),
-            Token::TypeU32 if true => Some(54),
-            Token::TypeI64 if true => Some(55),
-            Token::TypeU64 if true => Some(56),
-            Token::TypeF16 if true => Some(57),
-            Token::TypeF32 if true => Some(58),
-            Token::TypeF64 if true => Some(59),
+            Token::CharLiteral(char) if true => Some(25),
+            Token::If if true => Some(26),
+            Token::Then if true => Some(27),
+            Token::Else if true => Some(28),
+            Token::While if true => Some(29),
+            Token::In if true => Some(30),
+            Token::Ident(String) if true => Some(31),
+            Token::Num(i64) if true => Some(32),
+            Token::Float(f64) if true => Some(33),
+            Token::Bool(true) if true => Some(34),
+            Token::Bool(false) if true => Some(35),
+            Token::Comma if true => Some(36),
+            Token::Function if true => Some(37),
+            Token::Return if true => Some(38),
+            Token::Preprocessor if true => Some(39),
+            Token::Import if true => Some(40),
+            Token::As if true => Some(41),
+            Token::Package if true => Some(42),
+            Token::Var if true => Some(43),
+            Token::Public if true => Some(44),
+            Token::Enum if true => Some(45),
+            Token::Struct if true => Some(46),
+            Token::TypeInt if true => Some(47),
+            Token::TypeFloat if true => Some(48),
+            Token::TypeBool if true => Some(49),
+            Token::TypeStr if true => Some(50),
+            Token::TypeUnit if true => Some(51),
+            Token::TypeI8 if true => Some(52),
+            Token::TypeU8 if true => Some(53),
+            Token::TypeI16 if true => Some(54),
+            Token::TypeU16 if true => Some(55),
+            Token::TypeI32 if true => Some(56),
+            Token::TypeU32 if true => Some(57),
+            Token::TypeI64 if true => Some(58),
+            Token::TypeU64 if true => Some(59),
+            Token::TypeF16 if true => Some(60),
+            Token::TypeF32 if true => Some(61),
+            Token::TypeF64 if true => Some(62),
             _ => None,
         }
     }
@@ -1713,15 +2121,16 @@ mod __parse__Start {
     ) -> __Symbol<>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 => __Symbol::Variant0(__token),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 | 51 | 52 | 53 | 54 | 55 | 56 | 57 | 58 | 59 | 60 | 61 | 62 => __Symbol::Variant0(__token),
             _ => unreachable!(),
         }
     }
     fn __simulate_reduce<
+        '__0,
     >(
         __reduce_index: i16,
         _: core::marker::PhantomData<()>,
-    ) -> __state_machine::SimulatedReduce<__StateMachine<>>
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'__0>>
     {
         match __reduce_index {
             0 => {
@@ -1732,13 +2141,13 @@ mod __parse__Start {
             }
             1 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 1,
                 }
             }
             2 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 1,
                 }
             }
@@ -1750,25 +2159,25 @@ mod __parse__Start {
             }
             4 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 2,
+                    states_to_pop: 0,
+                    nonterminal_produced: 3,
                 }
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 3,
                 }
             }
             6 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 4,
                 }
             }
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 4,
                 }
             }
@@ -1780,25 +2189,25 @@ mod __parse__Start {
             }
             9 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 5,
+                    states_to_pop: 0,
+                    nonterminal_produced: 6,
                 }
             }
             10 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 6,
                 }
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 7,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 7,
                 }
             }
@@ -1810,25 +2219,25 @@ mod __parse__Start {
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 8,
+                    states_to_pop: 0,
+                    nonterminal_produced: 9,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 9,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 2,
                     nonterminal_produced: 10,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 10,
                 }
             }
@@ -1840,812 +2249,812 @@ mod __parse__Start {
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 11,
+                    states_to_pop: 0,
+                    nonterminal_produced: 12,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 12,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 12,
+                    states_to_pop: 2,
+                    nonterminal_produced: 13,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 12,
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 13,
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 13,
+                    states_to_pop: 0,
+                    nonterminal_produced: 15,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 15,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 14,
+                    states_to_pop: 2,
+                    nonterminal_produced: 16,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 14,
+                    states_to_pop: 0,
+                    nonterminal_produced: 17,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 19,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 19,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 20,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 20,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 20,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 0,
+                    nonterminal_produced: 21,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 21,
                 }
             }
             38 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 21,
                 }
             }
             39 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 22,
                 }
             }
             40 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 22,
                 }
             }
             41 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
                 }
             }
             42 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 5,
+                    nonterminal_produced: 23,
                 }
             }
             43 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 4,
+                    nonterminal_produced: 23,
                 }
             }
             44 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 6,
+                    nonterminal_produced: 23,
                 }
             }
             45 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 5,
+                    nonterminal_produced: 23,
                 }
             }
             46 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    states_to_pop: 4,
+                    nonterminal_produced: 23,
                 }
             }
             47 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 23,
                 }
             }
             48 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 23,
                 }
             }
             49 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 23,
                 }
             }
             50 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 16,
+                    nonterminal_produced: 23,
                 }
             }
             51 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             52 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 16,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             56 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             58 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
                 }
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 23,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 23,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 23,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 23,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    nonterminal_produced: 23,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 2,
+                    nonterminal_produced: 23,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 2,
+                    nonterminal_produced: 23,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 4,
+                    nonterminal_produced: 23,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 3,
+                    nonterminal_produced: 23,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 5,
+                    nonterminal_produced: 23,
                 }
             }
             70 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 4,
+                    nonterminal_produced: 23,
                 }
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 5,
+                    nonterminal_produced: 24,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 4,
+                    nonterminal_produced: 24,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 17,
+                    states_to_pop: 6,
+                    nonterminal_produced: 24,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 17,
+                    states_to_pop: 5,
+                    nonterminal_produced: 24,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             77 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             78 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 17,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 18,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 24,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 24,
                 }
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             85 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             86 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             87 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 20,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 24,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 21,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             91 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 21,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             92 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 21,
+                    states_to_pop: 1,
+                    nonterminal_produced: 24,
                 }
             }
             93 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 21,
+                    states_to_pop: 2,
+                    nonterminal_produced: 24,
                 }
             }
             94 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 24,
                 }
             }
             95 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 21,
+                    states_to_pop: 2,
+                    nonterminal_produced: 24,
                 }
             }
             96 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    states_to_pop: 4,
+                    nonterminal_produced: 24,
                 }
             }
             97 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 22,
+                    states_to_pop: 3,
+                    nonterminal_produced: 24,
                 }
             }
             98 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 23,
+                    states_to_pop: 5,
+                    nonterminal_produced: 24,
                 }
             }
             99 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 23,
+                    states_to_pop: 4,
+                    nonterminal_produced: 24,
                 }
             }
             100 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 24,
+                    states_to_pop: 3,
+                    nonterminal_produced: 25,
                 }
             }
             101 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 24,
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
                 }
             }
             102 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    nonterminal_produced: 26,
                 }
             }
             103 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 27,
                 }
             }
             104 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 28,
                 }
             }
             105 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 28,
                 }
             }
             106 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 29,
                 }
             }
             107 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 30,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             108 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 31,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             109 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 31,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             110 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 32,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             111 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 33,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             112 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 33,
+                    states_to_pop: 3,
+                    nonterminal_produced: 29,
                 }
             }
             113 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 34,
+                    nonterminal_produced: 29,
                 }
             }
             114 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 35,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             115 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
-                    nonterminal_produced: 36,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             116 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 37,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             117 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 38,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             118 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 39,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             119 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 40,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             120 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 41,
+                    states_to_pop: 3,
+                    nonterminal_produced: 30,
                 }
             }
             121 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 42,
+                    nonterminal_produced: 30,
                 }
             }
             122 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 43,
+                    states_to_pop: 6,
+                    nonterminal_produced: 31,
                 }
             }
             123 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 43,
+                    states_to_pop: 0,
+                    nonterminal_produced: 32,
                 }
             }
             124 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 43,
+                    nonterminal_produced: 32,
                 }
             }
             125 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 44,
+                    states_to_pop: 0,
+                    nonterminal_produced: 33,
                 }
             }
             126 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 44,
+                    states_to_pop: 2,
+                    nonterminal_produced: 33,
                 }
             }
             127 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 45,
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
                 }
             }
             128 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 35,
                 }
             }
             129 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    states_to_pop: 8,
+                    nonterminal_produced: 36,
                 }
             }
             130 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 37,
                 }
             }
             131 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    states_to_pop: 0,
+                    nonterminal_produced: 38,
                 }
             }
             132 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    states_to_pop: 2,
+                    nonterminal_produced: 38,
                 }
             }
             133 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    nonterminal_produced: 38,
                 }
             }
             134 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 46,
+                    states_to_pop: 3,
+                    nonterminal_produced: 38,
                 }
             }
             135 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 47,
+                    states_to_pop: 2,
+                    nonterminal_produced: 38,
                 }
             }
             136 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 47,
+                    states_to_pop: 3,
+                    nonterminal_produced: 39,
                 }
             }
             137 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 48,
+                    nonterminal_produced: 40,
                 }
             }
             138 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 48,
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
                 }
             }
             139 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 49,
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
                 }
             }
             140 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 2,
-                    nonterminal_produced: 49,
+                    nonterminal_produced: 43,
                 }
             }
             141 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 50,
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
                 }
             }
             142 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 50,
+                    states_to_pop: 0,
+                    nonterminal_produced: 44,
                 }
             }
             143 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 51,
+                    states_to_pop: 2,
+                    nonterminal_produced: 45,
                 }
             }
             144 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 51,
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
                 }
             }
             145 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 51,
+                    states_to_pop: 0,
+                    nonterminal_produced: 46,
                 }
             }
             146 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 51,
+                    nonterminal_produced: 47,
                 }
             }
             147 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 52,
+                    states_to_pop: 1,
+                    nonterminal_produced: 48,
                 }
             }
             148 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 52,
+                    states_to_pop: 8,
+                    nonterminal_produced: 49,
                 }
             }
             149 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 52,
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
                 }
             }
             150 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 52,
+                    nonterminal_produced: 51,
                 }
             }
             151 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 53,
+                    nonterminal_produced: 52,
                 }
             }
             152 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 54,
+                    states_to_pop: 1,
+                    nonterminal_produced: 53,
                 }
             }
             153 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 55,
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
                 }
             }
             154 => {
@@ -2656,13 +3065,13 @@ mod __parse__Start {
             }
             155 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 55,
+                    states_to_pop: 0,
+                    nonterminal_produced: 56,
                 }
             }
             156 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
+                    states_to_pop: 1,
                     nonterminal_produced: 56,
                 }
             }
@@ -2674,92 +3083,92 @@ mod __parse__Start {
             }
             158 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 56,
+                    states_to_pop: 4,
+                    nonterminal_produced: 57,
                 }
             }
             159 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 56,
+                    states_to_pop: 6,
+                    nonterminal_produced: 57,
                 }
             }
             160 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 56,
+                    states_to_pop: 5,
+                    nonterminal_produced: 58,
                 }
             }
             161 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 56,
+                    states_to_pop: 3,
+                    nonterminal_produced: 58,
                 }
             }
             162 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 56,
+                    states_to_pop: 8,
+                    nonterminal_produced: 58,
                 }
             }
             163 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 56,
+                    states_to_pop: 7,
+                    nonterminal_produced: 58,
                 }
             }
             164 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 57,
+                    states_to_pop: 9,
+                    nonterminal_produced: 58,
                 }
             }
             165 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 57,
+                    states_to_pop: 8,
+                    nonterminal_produced: 58,
                 }
             }
             166 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 57,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
             167 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 57,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
             168 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 57,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
             169 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
-                    nonterminal_produced: 57,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
             170 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 57,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
             171 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 57,
+                    nonterminal_produced: 59,
                 }
             }
             172 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 58,
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
                 }
             }
             173 => {
@@ -2770,8 +3179,8 @@ mod __parse__Start {
             }
             174 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 59,
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
                 }
             }
             175 => {
@@ -2782,302 +3191,302 @@ mod __parse__Start {
             }
             176 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 60,
+                    states_to_pop: 0,
+                    nonterminal_produced: 61,
                 }
             }
             177 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 2,
                     nonterminal_produced: 61,
                 }
             }
             178 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 61,
+                    states_to_pop: 0,
+                    nonterminal_produced: 62,
                 }
             }
             179 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 3,
                     nonterminal_produced: 62,
                 }
             }
             180 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 62,
+                    states_to_pop: 3,
+                    nonterminal_produced: 63,
                 }
             }
             181 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
+                    states_to_pop: 3,
                     nonterminal_produced: 63,
                 }
             }
             182 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 63,
                 }
             }
             183 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 64,
+                    nonterminal_produced: 63,
                 }
             }
             184 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 3,
                     nonterminal_produced: 64,
                 }
             }
             185 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
+                    states_to_pop: 3,
                     nonterminal_produced: 64,
                 }
             }
             186 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
+                    states_to_pop: 3,
                     nonterminal_produced: 64,
                 }
             }
             187 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 64,
                 }
             }
             188 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 64,
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
                 }
             }
             189 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 64,
+                    states_to_pop: 3,
+                    nonterminal_produced: 66,
                 }
             }
             190 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 65,
+                    states_to_pop: 0,
+                    nonterminal_produced: 67,
                 }
             }
             191 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 65,
+                    states_to_pop: 2,
+                    nonterminal_produced: 67,
                 }
             }
             192 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 66,
-                }
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
+                }
             }
             193 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 66,
+                    states_to_pop: 3,
+                    nonterminal_produced: 67,
                 }
             }
             194 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 67,
                 }
             }
             195 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
+                    states_to_pop: 4,
                     nonterminal_produced: 68,
                 }
             }
             196 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 69,
+                    states_to_pop: 2,
+                    nonterminal_produced: 68,
                 }
             }
             197 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 69,
+                    states_to_pop: 2,
+                    nonterminal_produced: 68,
                 }
             }
             198 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 69,
+                    states_to_pop: 5,
+                    nonterminal_produced: 68,
                 }
             }
             199 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 70,
+                    states_to_pop: 7,
+                    nonterminal_produced: 68,
                 }
             }
             200 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 70,
+                    states_to_pop: 6,
+                    nonterminal_produced: 68,
                 }
             }
             201 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 70,
+                    states_to_pop: 8,
+                    nonterminal_produced: 68,
                 }
             }
             202 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 70,
+                    states_to_pop: 7,
+                    nonterminal_produced: 68,
                 }
             }
             203 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 71,
+                    nonterminal_produced: 68,
                 }
             }
             204 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 72,
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
                 }
             }
             205 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 72,
+                    states_to_pop: 4,
+                    nonterminal_produced: 69,
                 }
             }
             206 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 72,
+                    states_to_pop: 2,
+                    nonterminal_produced: 69,
                 }
             }
             207 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 73,
+                    states_to_pop: 2,
+                    nonterminal_produced: 69,
                 }
             }
             208 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 73,
+                    states_to_pop: 5,
+                    nonterminal_produced: 69,
                 }
             }
             209 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 7,
+                    nonterminal_produced: 69,
                 }
             }
             210 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 6,
+                    nonterminal_produced: 69,
                 }
             }
             211 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 8,
+                    nonterminal_produced: 69,
                 }
             }
             212 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 7,
+                    nonterminal_produced: 69,
                 }
             }
             213 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 3,
+                    nonterminal_produced: 69,
                 }
             }
             214 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    nonterminal_produced: 69,
                 }
             }
             215 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 2,
+                    nonterminal_produced: 70,
                 }
             }
             216 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    nonterminal_produced: 71,
                 }
             }
             217 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 0,
+                    nonterminal_produced: 71,
                 }
             }
             218 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 3,
+                    nonterminal_produced: 72,
                 }
             }
             219 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    nonterminal_produced: 72,
                 }
             }
             220 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    states_to_pop: 3,
+                    nonterminal_produced: 73,
                 }
             }
             221 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    nonterminal_produced: 73,
                 }
             }
             222 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 74,
                 }
             }
             223 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 74,
                 }
             }
             224 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 74,
+                    nonterminal_produced: 75,
                 }
             }
             225 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 75,
+                    states_to_pop: 0,
+                    nonterminal_produced: 76,
                 }
             }
             226 => {
@@ -3094,845 +3503,1332 @@ mod __parse__Start {
             }
             228 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 78,
+                    states_to_pop: 2,
+                    nonterminal_produced: 77,
                 }
             }
             229 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 79,
+                    states_to_pop: 0,
+                    nonterminal_produced: 78,
                 }
             }
             230 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 80,
+                    nonterminal_produced: 78,
                 }
             }
             231 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 81,
+                    states_to_pop: 1,
+                    nonterminal_produced: 79,
                 }
             }
             232 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 81,
+                    states_to_pop: 1,
+                    nonterminal_produced: 80,
                 }
             }
             233 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 82,
+                    states_to_pop: 2,
+                    nonterminal_produced: 80,
                 }
             }
             234 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 82,
+                    states_to_pop: 6,
+                    nonterminal_produced: 80,
                 }
             }
-            235 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {__reduce_index}",)
-        }
-    }
-    pub struct StartParser {
-        _priv: (),
-    }
-
-    impl Default for StartParser { fn default() -> Self { Self::new() } }
-    impl StartParser {
-        pub fn new() -> StartParser {
-            StartParser {
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            __TOKEN: __ToTriple<>,
-            __TOKENS: IntoIterator<Item=__TOKEN>,
-        >(
-            &self,
-            __tokens0: __TOKENS,
-        ) -> Result<Vec<Item>, __lalrpop_util::ParseError<usize, Token, String>>
-        {
-            let __tokens = __tokens0.into_iter();
-            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
-            __state_machine::Parser::drive(
-                __StateMachine {
-                    __phantom: core::marker::PhantomData::<()>,
-                },
-                __tokens,
-            )
-        }
-    }
-    fn __accepts<
-    >(
-        __error_state: Option<i16>,
-        __states: &[i16],
-        __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<()>,
-    ) -> bool
-    {
-        let mut __states = __states.to_vec();
-        __states.extend(__error_state);
-        loop {
-            let mut __states_len = __states.len();
-            let __top = __states[__states_len - 1];
-            let __action = match __opt_integer {
-                None => __EOF_ACTION[__top as usize],
-                Some(__integer) => __action(__top, __integer),
-            };
-            if __action == 0 { return false; }
-            if __action > 0 { return true; }
-            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<()>) {
+            235 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop, nonterminal_produced
-                } => (states_to_pop, nonterminal_produced),
-                __state_machine::SimulatedReduce::Accept => return true,
-            };
-            __states_len -= __to_pop;
-            __states.truncate(__states_len);
-            let __top = __states[__states_len - 1];
-            let __next_state = __goto(__top, __nt);
-            __states.push(__next_state);
-        }
-    }
-    fn __reduce<
-    >(
-        __action: i16,
-        __lookahead_start: Option<&usize>,
-        __states: &mut alloc::vec::Vec<i16>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> Option<Result<Vec<Item>,__lalrpop_util::ParseError<usize, Token, String>>>
-    {
-        let (__pop_states, __nonterminal) = match __action {
-            0 => {
-                __reduce0(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
-            }
-            1 => {
-                __reduce1(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                    states_to_pop: 4,
+                    nonterminal_produced: 80,
+                }
             }
-            2 => {
-                __reduce2(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            236 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 80,
+                }
             }
-            3 => {
-                __reduce3(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            237 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 80,
+                }
             }
-            4 => {
-                __reduce4(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            238 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 80,
+                }
             }
-            5 => {
-                __reduce5(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            239 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 80,
+                }
             }
-            6 => {
-                __reduce6(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            240 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 80,
+                }
             }
-            7 => {
-                __reduce7(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            241 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 81,
+                }
             }
-            8 => {
-                __reduce8(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            242 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 81,
+                }
             }
-            9 => {
-                __reduce9(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            243 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 82,
+                }
             }
-            10 => {
-                __reduce10(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            244 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 82,
+                }
             }
-            11 => {
-                __reduce11(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            245 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 83,
+                }
             }
-            12 => {
-                __reduce12(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            246 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 84,
+                }
             }
-            13 => {
-                __reduce13(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            247 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 85,
+                }
             }
-            14 => {
-                __reduce14(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            248 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 85,
+                }
             }
-            15 => {
-                __reduce15(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            249 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 85,
+                }
             }
-            16 => {
-                __reduce16(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            250 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 86,
+                }
             }
-            17 => {
-                __reduce17(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            251 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 86,
+                }
             }
-            18 => {
-                __reduce18(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            252 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 86,
+                }
             }
-            19 => {
-                __reduce19(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            253 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 86,
+                }
             }
-            20 => {
-                __reduce20(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            254 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 87,
+                }
             }
-            21 => {
-                __reduce21(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            255 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 88,
+                }
             }
-            22 => {
-                __reduce22(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            256 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 88,
+                }
             }
-            23 => {
-                __reduce23(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            257 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 88,
+                }
             }
-            24 => {
-                __reduce24(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            258 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 89,
+                }
             }
-            25 => {
-                __reduce25(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            259 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 89,
+                }
             }
-            26 => {
-                __reduce26(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            260 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            27 => {
-                __reduce27(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            261 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            28 => {
-                __reduce28(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            262 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            29 => {
-                __reduce29(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            263 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            30 => {
-                __reduce30(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            264 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            31 => {
-                __reduce31(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            265 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            32 => {
-                __reduce32(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            266 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            33 => {
-                __reduce33(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            267 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            34 => {
-                __reduce34(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            268 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            35 => {
-                __reduce35(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            269 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            36 => {
-                __reduce36(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            270 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            37 => {
-                __reduce37(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            271 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            38 => {
-                __reduce38(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            272 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            39 => {
-                __reduce39(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            273 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            40 => {
-                __reduce40(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            274 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            41 => {
-                __reduce41(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            275 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 90,
+                }
             }
-            42 => {
-                __reduce42(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            276 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 91,
+                }
             }
-            43 => {
-                __reduce43(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            277 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 92,
+                }
             }
-            44 => {
-                __reduce44(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            278 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 93,
+                }
             }
-            45 => {
-                __reduce45(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            279 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 94,
+                }
             }
-            46 => {
-                __reduce46(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            280 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 95,
+                }
             }
-            47 => {
-                __reduce47(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            281 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 96,
+                }
             }
-            48 => {
-                __reduce48(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            282 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 97,
+                }
             }
-            49 => {
-                __reduce49(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            283 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 97,
+                }
             }
-            50 => {
-                __reduce50(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            284 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 98,
+                }
             }
-            51 => {
-                __reduce51(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            285 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 98,
+                }
             }
-            52 => {
-                __reduce52(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            286 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {__reduce_index}",)
+        }
+    }
+    pub struct StartParser {
+        _priv: (),
+    }
+
+    impl Default for StartParser { fn default() -> Self { Self::new() } }
+    impl StartParser {
+        pub fn new() -> StartParser {
+            StartParser {
+                _priv: (),
             }
-            53 => {
-                __reduce53(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            __TOKEN: __ToTriple<>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+            __tokens0: __TOKENS,
+        ) -> Result<Vec<(Item, Span)>, __lalrpop_util::ParseError<usize, Token, String>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    __phantom: core::marker::PhantomData::<()>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        '__0,
+    >(
+        __error_state: Option<i16>,
+        __states: &[i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<()>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.extend(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<()>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    fn __reduce<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> Option<Result<Vec<(Item, Span)>,__lalrpop_util::ParseError<usize, Token, String>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
-            54 => {
-                __reduce54(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            1 => {
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
-            55 => {
-                __reduce55(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            2 => {
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
-            56 => {
-                __reduce56(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            3 => {
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            4 => {
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            5 => {
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            6 => {
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            7 => {
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            8 => {
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            9 => {
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            10 => {
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            11 => {
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            12 => {
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            13 => {
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            14 => {
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            15 => {
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            16 => {
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            17 => {
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            18 => {
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            19 => {
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            20 => {
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            21 => {
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            22 => {
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            23 => {
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            24 => {
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            25 => {
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            26 => {
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            27 => {
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            28 => {
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            29 => {
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            30 => {
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            31 => {
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            32 => {
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            33 => {
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            34 => {
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            35 => {
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            36 => {
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            37 => {
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            38 => {
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            39 => {
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            43 => {
+                __reduce43(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            44 => {
+                __reduce44(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            45 => {
+                __reduce45(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            46 => {
+                __reduce46(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            47 => {
+                __reduce47(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            48 => {
+                __reduce48(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            49 => {
+                __reduce49(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            50 => {
+                __reduce50(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            51 => {
+                __reduce51(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            52 => {
+                __reduce52(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            53 => {
+                __reduce53(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            54 => {
+                __reduce54(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            55 => {
+                __reduce55(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            56 => {
+                __reduce56(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             57 => {
-                __reduce57(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce57(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             58 => {
-                __reduce58(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce58(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             59 => {
-                __reduce59(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce59(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             60 => {
-                __reduce60(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce60(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             61 => {
-                __reduce61(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce61(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             62 => {
-                __reduce62(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce62(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             63 => {
-                __reduce63(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce63(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             64 => {
-                __reduce64(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce64(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             65 => {
-                __reduce65(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce65(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             66 => {
-                __reduce66(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce66(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             67 => {
-                __reduce67(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce67(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             68 => {
-                __reduce68(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce68(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             69 => {
-                __reduce69(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce69(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             70 => {
-                __reduce70(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce70(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             71 => {
-                __reduce71(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce71(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             72 => {
-                __reduce72(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce72(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             73 => {
-                __reduce73(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce73(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             74 => {
-                __reduce74(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce74(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             75 => {
-                __reduce75(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce75(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             76 => {
-                __reduce76(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce76(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             77 => {
-                __reduce77(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce77(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             78 => {
-                __reduce78(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce78(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             79 => {
-                __reduce79(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce79(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             80 => {
-                __reduce80(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce80(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             81 => {
-                __reduce81(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce81(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             82 => {
-                __reduce82(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce82(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             83 => {
-                __reduce83(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce83(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             84 => {
-                __reduce84(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce84(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             85 => {
-                __reduce85(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce85(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             86 => {
-                __reduce86(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce86(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             87 => {
-                __reduce87(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce87(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             88 => {
-                __reduce88(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce88(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             89 => {
-                __reduce89(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce89(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             90 => {
-                __reduce90(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce90(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             91 => {
-                __reduce91(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce91(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             92 => {
-                __reduce92(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce92(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             93 => {
-                __reduce93(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce93(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             94 => {
-                __reduce94(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce94(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             95 => {
-                __reduce95(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce95(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             96 => {
-                __reduce96(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce96(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             97 => {
-                __reduce97(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce97(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             98 => {
-                __reduce98(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce98(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             99 => {
-                __reduce99(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce99(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             100 => {
-                __reduce100(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce100(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             101 => {
-                __reduce101(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce101(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             102 => {
-                __reduce102(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce102(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             103 => {
-                __reduce103(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce103(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             104 => {
-                __reduce104(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce104(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             105 => {
-                __reduce105(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce105(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             106 => {
-                __reduce106(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce106(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             107 => {
-                __reduce107(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce107(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             108 => {
-                __reduce108(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce108(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             109 => {
-                __reduce109(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce109(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             110 => {
-                __reduce110(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce110(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             111 => {
-                __reduce111(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce111(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             112 => {
-                __reduce112(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce112(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             113 => {
-                __reduce113(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce113(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             114 => {
-                __reduce114(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce114(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             115 => {
-                __reduce115(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce115(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             116 => {
-                __reduce116(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce116(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             117 => {
-                __reduce117(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce117(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             118 => {
-                __reduce118(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce118(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             119 => {
-                __reduce119(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce119(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             120 => {
-                __reduce120(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce120(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             121 => {
-                __reduce121(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce121(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             122 => {
-                __reduce122(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce122(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             123 => {
-                __reduce123(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce123(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             124 => {
-                __reduce124(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce124(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             125 => {
-                __reduce125(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce125(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             126 => {
-                __reduce126(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce126(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             127 => {
-                __reduce127(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce127(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             128 => {
-                __reduce128(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce128(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             129 => {
-                __reduce129(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce129(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             130 => {
-                __reduce130(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                // ExternKw = Ident => ActionFn(41);
+                let __sym0 = __pop_Variant2(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = match super::__action41::<>(errors, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+                (1, 37)
             }
             131 => {
-                __reduce131(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce131(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             132 => {
-                __reduce132(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce132(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             133 => {
-                __reduce133(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce133(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             134 => {
-                __reduce134(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce134(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             135 => {
-                __reduce135(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce135(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             136 => {
-                __reduce136(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce136(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             137 => {
-                __reduce137(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce137(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             138 => {
-                __reduce138(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce138(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             139 => {
-                __reduce139(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce139(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             140 => {
-                __reduce140(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce140(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             141 => {
-                __reduce141(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce141(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             142 => {
-                __reduce142(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce142(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             143 => {
-                __reduce143(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce143(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             144 => {
-                __reduce144(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce144(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             145 => {
-                __reduce145(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce145(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             146 => {
-                __reduce146(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce146(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             147 => {
-                __reduce147(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce147(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             148 => {
-                __reduce148(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce148(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             149 => {
-                __reduce149(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce149(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             150 => {
-                __reduce150(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce150(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             151 => {
-                __reduce151(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce151(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             152 => {
-                __reduce152(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce152(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             153 => {
-                __reduce153(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce153(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             154 => {
-                __reduce154(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce154(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             155 => {
-                __reduce155(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce155(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             156 => {
-                __reduce156(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce156(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             157 => {
-                __reduce157(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce157(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             158 => {
-                __reduce158(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce158(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             159 => {
-                __reduce159(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce159(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             160 => {
-                __reduce160(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce160(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             161 => {
-                __reduce161(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce161(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             162 => {
-                __reduce162(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce162(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             163 => {
-                __reduce163(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce163(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             164 => {
-                __reduce164(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce164(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             165 => {
-                __reduce165(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce165(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             166 => {
-                __reduce166(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce166(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             167 => {
-                __reduce167(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce167(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             168 => {
-                __reduce168(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce168(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             169 => {
-                __reduce169(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce169(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             170 => {
-                __reduce170(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce170(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             171 => {
-                __reduce171(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce171(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             172 => {
-                __reduce172(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce172(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             173 => {
-                __reduce173(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce173(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             174 => {
-                __reduce174(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce174(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             175 => {
-                __reduce175(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce175(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             176 => {
-                __reduce176(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce176(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             177 => {
-                __reduce177(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce177(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             178 => {
-                __reduce178(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce178(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             179 => {
-                __reduce179(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce179(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             180 => {
-                __reduce180(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce180(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             181 => {
-                __reduce181(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce181(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             182 => {
-                __reduce182(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce182(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             183 => {
-                __reduce183(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce183(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             184 => {
-                // Stmt = Expr, StmtTail => ActionFn(63);
-                assert!(__symbols.len() >= 2);
-                let __sym1 = __pop_Variant17(__symbols);
-                let __sym0 = __pop_Variant1(__symbols);
-                let __start = __sym0.0;
-                let __end = __sym1.2;
-                let __nt = match super::__action63::<>(__sym0, __sym1) {
-                    Ok(v) => v,
-                    Err(e) => return Some(Err(e)),
-                };
-                __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-                (2, 64)
+                __reduce184(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             185 => {
-                __reduce185(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce185(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             186 => {
-                __reduce186(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce186(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             187 => {
-                __reduce187(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce187(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             188 => {
-                __reduce188(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce188(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             189 => {
-                __reduce189(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce189(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             190 => {
-                __reduce190(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce190(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             191 => {
-                __reduce191(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce191(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             192 => {
-                __reduce192(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce192(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             193 => {
-                __reduce193(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce193(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             194 => {
-                __reduce194(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce194(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             195 => {
-                __reduce195(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce195(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             196 => {
-                __reduce196(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce196(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             197 => {
-                __reduce197(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce197(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             198 => {
-                __reduce198(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce198(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             199 => {
-                __reduce199(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce199(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             200 => {
-                __reduce200(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce200(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             201 => {
-                __reduce201(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce201(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             202 => {
-                __reduce202(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce202(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             203 => {
-                __reduce203(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce203(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             204 => {
-                __reduce204(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce204(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             205 => {
-                __reduce205(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce205(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             206 => {
-                __reduce206(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce206(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             207 => {
-                __reduce207(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce207(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             208 => {
-                __reduce208(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce208(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             209 => {
-                __reduce209(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce209(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             210 => {
-                __reduce210(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce210(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             211 => {
-                __reduce211(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce211(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             212 => {
-                __reduce212(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce212(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             213 => {
-                __reduce213(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce213(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             214 => {
-                __reduce214(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce214(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             215 => {
-                __reduce215(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce215(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             216 => {
-                __reduce216(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce216(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             217 => {
-                __reduce217(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce217(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             218 => {
-                __reduce218(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce218(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             219 => {
-                __reduce219(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce219(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             220 => {
-                __reduce220(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce220(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             221 => {
-                __reduce221(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce221(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             222 => {
-                __reduce222(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce222(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             223 => {
-                __reduce223(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce223(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             224 => {
-                __reduce224(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce224(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             225 => {
-                __reduce225(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce225(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             226 => {
-                __reduce226(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce226(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             227 => {
-                __reduce227(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce227(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             228 => {
-                __reduce228(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce228(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             229 => {
-                __reduce229(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce229(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             230 => {
-                __reduce230(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce230(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             231 => {
-                __reduce231(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce231(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             232 => {
-                __reduce232(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                __reduce232(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
             }
             233 => {
-                __reduce233(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                // StmtKind = Expr, StmtTail => ActionFn(74);
+                assert!(__symbols.len() >= 2);
+                let __sym1 = __pop_Variant27(__symbols);
+                let __sym0 = __pop_Variant4(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym1.2;
+                let __nt = match super::__action74::<>(errors, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+                (2, 80)
             }
             234 => {
-                __reduce234(__lookahead_start, __symbols, core::marker::PhantomData::<()>)
+                // StmtKind = Ident, (Comma <Ident>)+, Assign, Expr, (Comma <Expr>)+, Semi => ActionFn(75);
+                assert!(__symbols.len() >= 6);
+                let __sym5 = __pop_Variant0(__symbols);
+                let __sym4 = __pop_Variant5(__symbols);
+                let __sym3 = __pop_Variant4(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant10(__symbols);
+                let __sym0 = __pop_Variant2(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym5.2;
+                let __nt = match super::__action75::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+                (6, 80)
             }
             235 => {
+                __reduce235(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            236 => {
+                __reduce236(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            237 => {
+                __reduce237(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            238 => {
+                __reduce238(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            239 => {
+                __reduce239(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            240 => {
+                __reduce240(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            241 => {
+                __reduce241(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            242 => {
+                __reduce242(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            243 => {
+                __reduce243(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            244 => {
+                __reduce244(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            245 => {
+                __reduce245(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            246 => {
+                __reduce246(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            247 => {
+                __reduce247(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            248 => {
+                __reduce248(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            249 => {
+                __reduce249(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            250 => {
+                __reduce250(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            251 => {
+                __reduce251(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            252 => {
+                __reduce252(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            253 => {
+                __reduce253(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            254 => {
+                __reduce254(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            255 => {
+                __reduce255(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            256 => {
+                __reduce256(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            257 => {
+                __reduce257(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            258 => {
+                __reduce258(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            259 => {
+                __reduce259(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            260 => {
+                __reduce260(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            261 => {
+                __reduce261(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            262 => {
+                __reduce262(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            263 => {
+                __reduce263(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            264 => {
+                __reduce264(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            265 => {
+                __reduce265(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            266 => {
+                __reduce266(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            267 => {
+                __reduce267(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            268 => {
+                __reduce268(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            269 => {
+                __reduce269(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            270 => {
+                __reduce270(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            271 => {
+                __reduce271(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            272 => {
+                __reduce272(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            273 => {
+                __reduce273(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            274 => {
+                __reduce274(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            275 => {
+                __reduce275(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            276 => {
+                __reduce276(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            277 => {
+                __reduce277(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            278 => {
+                __reduce278(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            279 => {
+                __reduce279(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            280 => {
+                __reduce280(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            281 => {
+                __reduce281(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            282 => {
+                __reduce282(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            283 => {
+                __reduce283(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            284 => {
+                __reduce284(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            285 => {
+                __reduce285(errors, __lookahead_start, __symbols, core::marker::PhantomData::<()>)
+            }
+            286 => {
                 // __Start = Start => ActionFn(0);
-                let __sym0 = __pop_Variant30(__symbols);
+                let __sym0 = __pop_Variant40(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
-                let __nt = super::__action0::<>(__sym0);
+                let __nt = super::__action0::<>(errors, __sym0);
                 return Some(Ok(__nt));
             }
             _ => panic!("invalid action code {__action}")
@@ -3948,6697 +4844,13327 @@ mod __parse__Start {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant20<
+    fn __pop_Variant22<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, (), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant32<
+    fn __pop_Variant38<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, (String, Expr), usize)
+    ) -> (usize, (Item, Span), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant12<
+    fn __pop_Variant43<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Enum, usize)
+    ) -> (usize, (String, Expr), usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant43(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
+    fn __pop_Variant19<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Expr, usize)
+    ) -> (usize, Enum, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant3<
+    fn __pop_Variant4<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, FunctionParam, usize)
+    ) -> (usize, Expr, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant21<
+    fn __pop_Variant6<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Item, usize)
+    ) -> (usize, ExternParam, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant17<
+    fn __pop_Variant8<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Option<Expr>, usize)
+    ) -> (usize, FunctionParam, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant19<
+    fn __pop_Variant35<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Option<Type>, usize)
+    ) -> (usize, Import, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant26<
+    fn __pop_Variant21<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Stmt, usize)
+    ) -> (usize, Item, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant5<
+    fn __pop_Variant27<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, String, usize)
+    ) -> (usize, Option<Expr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant31<
+    fn __pop_Variant3<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Struct, usize)
+    ) -> (usize, Option<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant7<
+    fn __pop_Variant18<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, StructField, usize)
+    ) -> (usize, Option<Token>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant0<
+    fn __pop_Variant29<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Token, usize)
+    ) -> (usize, Option<Type>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant18<
+    fn __pop_Variant41<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Type, usize)
+    ) -> (usize, Stmt, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant41(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant38<
+    fn __pop_Variant34<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, VarDecl, usize)
+    ) -> (usize, StmtKind, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant38(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant33<
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, String, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant42<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Struct, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant42(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, StructField, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Token, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant28<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Type, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant49<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, VarDecl, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant49(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant40<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<(Item, Span)>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant40(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant44<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<(String, Expr)>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant44(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant9<
+    fn __pop_Variant14<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<Expr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant29<
+    fn __pop_Variant23<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<FunctionParam>, usize)
+    ) -> (usize, Vec<ExternParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant29(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant30<
+    fn __pop_Variant37<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Vec<Item>, usize)
+    ) -> (usize, Vec<FunctionParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant10<
+    fn __pop_Variant15<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<Stmt>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant13<
+    fn __pop_Variant20<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant28<
+    fn __pop_Variant36<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<StructField>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant28(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant39<
+    fn __pop_Variant50<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, Vec<VarDecl>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant50(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, __lalrpop_util::ErrorRecovery<usize, Token, String>, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant39<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, alloc::vec::Vec<(Item, Span)>, usize)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant39(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant2<
+    fn __pop_Variant5<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, alloc::vec::Vec<Expr>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
+    fn __pop_Variant7<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<FunctionParam>, usize)
+    ) -> (usize, alloc::vec::Vec<ExternParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant27<
+    fn __pop_Variant9<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, alloc::vec::Vec<Item>, usize)
+    ) -> (usize, alloc::vec::Vec<FunctionParam>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant27(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
+    fn __pop_Variant10<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, alloc::vec::Vec<String>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant8<
+    fn __pop_Variant12<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, alloc::vec::Vec<StructField>, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant11<
+    fn __pop_Variant16<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, bool, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant14<
+    fn __pop_Variant17<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, char, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, f16, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant15<
+    fn __pop_Variant25<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, f32, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant16<
+    fn __pop_Variant26<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, f64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant26(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant22<
+    fn __pop_Variant30<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, i16, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant30(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant23<
+    fn __pop_Variant31<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, i32, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant31(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant24<
+    fn __pop_Variant32<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, i64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant32(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant25<
+    fn __pop_Variant33<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, i8, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant33(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant34<
+    fn __pop_Variant45<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, u16, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant34(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant45(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant35<
+    fn __pop_Variant46<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, u32, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant35(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant46(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant36<
+    fn __pop_Variant47<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, u64, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant36(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant47(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant37<
+    fn __pop_Variant48<
     >(
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
     ) -> (usize, u8, usize)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant37(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant48(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __reduce0<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Expr>) = Comma, Expr => ActionFn(196);
+        // (As <Ident>) = As, Ident => ActionFn(239);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action196::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action239::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (2, 0)
     }
     fn __reduce1<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Expr>)* =  => ActionFn(194);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action194::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (0, 1)
+        // (As <Ident>)? = As, Ident => ActionFn(252);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action252::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (2, 1)
     }
     fn __reduce2<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Expr>)* = (Comma <Expr>)+ => ActionFn(195);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action195::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        // (As <Ident>)? =  => ActionFn(238);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action238::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (0, 1)
     }
     fn __reduce3<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Expr>)+ = Comma, Expr => ActionFn(222);
+        // (Comma <Expr>) = Comma, Expr => ActionFn(216);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action222::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        let __nt = super::__action216::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (2, 2)
     }
     fn __reduce4<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Expr>)+ = (Comma <Expr>)+, Comma, Expr => ActionFn(223);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action223::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 2)
+        // (Comma <Expr>)* =  => ActionFn(212);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action212::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (0, 3)
     }
     fn __reduce5<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <FunctionParamNode>) = Comma, FunctionParamNode => ActionFn(199);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (Comma <Expr>)* = (Comma <Expr>)+ => ActionFn(213);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action199::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 3)
+        let __end = __sym0.2;
+        let __nt = super::__action213::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 3)
     }
     fn __reduce6<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <FunctionParamNode>)* =  => ActionFn(197);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action197::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (0, 4)
+        // (Comma <Expr>)+ = Comma, Expr => ActionFn(255);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action255::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 4)
     }
     fn __reduce7<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <FunctionParamNode>)* = (Comma <FunctionParamNode>)+ => ActionFn(198);
-        let __sym0 = __pop_Variant4(__symbols);
+        // (Comma <Expr>)+ = (Comma <Expr>)+, Comma, Expr => ActionFn(256);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action198::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __end = __sym2.2;
+        let __nt = super::__action256::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 4)
     }
     fn __reduce8<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <FunctionParamNode>)+ = Comma, FunctionParamNode => ActionFn(236);
+        // (Comma <ExternParamNode>) = Comma, ExternParamNode => ActionFn(221);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action236::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        let __nt = super::__action221::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
         (2, 5)
     }
     fn __reduce9<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <FunctionParamNode>)+ = (Comma <FunctionParamNode>)+, Comma, FunctionParamNode => ActionFn(237);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action237::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (3, 5)
+        // (Comma <ExternParamNode>)* =  => ActionFn(219);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action219::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 6)
     }
     fn __reduce10<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Ident>) = Comma, Ident => ActionFn(209);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (Comma <ExternParamNode>)* = (Comma <ExternParamNode>)+ => ActionFn(220);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action209::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 6)
+        let __end = __sym0.2;
+        let __nt = super::__action220::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 6)
     }
     fn __reduce11<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Ident>)* =  => ActionFn(207);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action207::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (0, 7)
+        // (Comma <ExternParamNode>)+ = Comma, ExternParamNode => ActionFn(269);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action269::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 7)
     }
     fn __reduce12<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Ident>)* = (Comma <Ident>)+ => ActionFn(208);
-        let __sym0 = __pop_Variant6(__symbols);
+        // (Comma <ExternParamNode>)+ = (Comma <ExternParamNode>)+, Comma, ExternParamNode => ActionFn(270);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action208::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        let __end = __sym2.2;
+        let __nt = super::__action270::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 7)
     }
     fn __reduce13<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Ident>)+ = Comma, Ident => ActionFn(240);
+        // (Comma <FunctionParamNode>) = Comma, FunctionParamNode => ActionFn(224);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action240::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        let __nt = super::__action224::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
         (2, 8)
     }
     fn __reduce14<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <Ident>)+ = (Comma <Ident>)+, Comma, Ident => ActionFn(241);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant6(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action241::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (3, 8)
+        // (Comma <FunctionParamNode>)* =  => ActionFn(222);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action222::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 9)
     }
     fn __reduce15<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <StructFieldNode>) = Comma, StructFieldNode => ActionFn(206);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // (Comma <FunctionParamNode>)* = (Comma <FunctionParamNode>)+ => ActionFn(223);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action206::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 9)
+        let __end = __sym0.2;
+        let __nt = super::__action223::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 9)
     }
     fn __reduce16<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <StructFieldNode>)* =  => ActionFn(204);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action204::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (0, 10)
+        // (Comma <FunctionParamNode>)+ = Comma, FunctionParamNode => ActionFn(273);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action273::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 10)
     }
     fn __reduce17<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <StructFieldNode>)* = (Comma <StructFieldNode>)+ => ActionFn(205);
-        let __sym0 = __pop_Variant8(__symbols);
+        // (Comma <FunctionParamNode>)+ = (Comma <FunctionParamNode>)+, Comma, FunctionParamNode => ActionFn(274);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action205::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (1, 10)
+        let __end = __sym2.2;
+        let __nt = super::__action274::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 10)
     }
     fn __reduce18<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <StructFieldNode>)+ = Comma, StructFieldNode => ActionFn(244);
+        // (Comma <Ident>) = Comma, Ident => ActionFn(236);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action244::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        let __nt = super::__action236::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (2, 11)
     }
     fn __reduce19<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // (Comma <StructFieldNode>)+ = (Comma <StructFieldNode>)+, Comma, StructFieldNode => ActionFn(245);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant7(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant8(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action245::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
-        (3, 11)
+        // (Comma <Ident>)* =  => ActionFn(234);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action234::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 12)
     }
     fn __reduce20<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AddAndMinus = AddAndMinus, Plus, MulAndDivAndMod => ActionFn(103);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (Comma <Ident>)* = (Comma <Ident>)+ => ActionFn(235);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action103::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __end = __sym0.2;
+        let __nt = super::__action235::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 12)
     }
     fn __reduce21<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AddAndMinus = AddAndMinus, Minus, MulAndDivAndMod => ActionFn(104);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (Comma <Ident>)+ = Comma, Ident => ActionFn(277);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action104::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 12)
+        let __end = __sym1.2;
+        let __nt = super::__action277::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 13)
     }
     fn __reduce22<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AddAndMinus = MulAndDivAndMod => ActionFn(105);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (Comma <Ident>)+ = (Comma <Ident>)+, Comma, Ident => ActionFn(278);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action105::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 12)
+        let __end = __sym2.2;
+        let __nt = super::__action278::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
     }
     fn __reduce23<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AddAndMinusNoStruct = AddAndMinusNoStruct, Plus, MulAndDivAndModNoStruct => ActionFn(151);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (Comma <StructFieldNode>) = Comma, StructFieldNode => ActionFn(231);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action151::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 13)
+        let __end = __sym1.2;
+        let __nt = super::__action231::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 14)
     }
     fn __reduce24<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AddAndMinusNoStruct = AddAndMinusNoStruct, Minus, MulAndDivAndModNoStruct => ActionFn(152);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action152::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 13)
+        // (Comma <StructFieldNode>)* =  => ActionFn(229);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action229::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 15)
     }
     fn __reduce25<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AddAndMinusNoStruct = MulAndDivAndModNoStruct => ActionFn(153);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (Comma <StructFieldNode>)* = (Comma <StructFieldNode>)+ => ActionFn(230);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action153::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 13)
+        let __nt = super::__action230::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 15)
     }
     fn __reduce26<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArgList =  => ActionFn(54);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action54::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (0, 14)
+        // (Comma <StructFieldNode>)+ = Comma, StructFieldNode => ActionFn(283);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action283::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (2, 16)
     }
     fn __reduce27<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArgList = Expr => ActionFn(55);
-        let __sym0 = __pop_Variant1(__symbols);
+        // (Comma <StructFieldNode>)+ = (Comma <StructFieldNode>)+, Comma, StructFieldNode => ActionFn(284);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant11(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action55::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 14)
+        let __end = __sym2.2;
+        let __nt = super::__action284::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (3, 16)
     }
     fn __reduce28<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArgList = Expr, Comma, ArgListTail => ActionFn(56);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action56::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (3, 14)
+        // @L =  => ActionFn(241);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action241::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 17)
     }
     fn __reduce29<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArgListTail = Expr => ActionFn(57);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action57::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (1, 15)
+        // @R =  => ActionFn(240);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action240::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 18)
     }
     fn __reduce30<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ArgListTail = Expr, Comma, ArgListTail => ActionFn(58);
+        // AddAndMinus = AddAndMinus, Plus, MulAndDivAndMod => ActionFn(337);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action58::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
-        (3, 15)
+        let __nt = super::__action337::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 19)
     }
     fn __reduce31<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Ident, LParen, RParen => ActionFn(118);
+        // AddAndMinus = AddAndMinus, Minus, MulAndDivAndMod => ActionFn(338);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action118::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 16)
+        let __nt = super::__action338::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 19)
     }
     fn __reduce32<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Ident, LParen, Expr, RParen => ActionFn(224);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // AddAndMinus = MulAndDivAndMod => ActionFn(120);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action224::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action120::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 19)
     }
     fn __reduce33<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(225);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
+        // AddAndMinusNoStruct = AddAndMinusNoStruct, Plus, MulAndDivAndModNoStruct => ActionFn(339);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action225::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 16)
+        let __end = __sym2.2;
+        let __nt = super::__action339::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 20)
     }
     fn __reduce34<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Ident, LBrace, StructInitFields, RBrace => ActionFn(120);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant33(__symbols);
+        // AddAndMinusNoStruct = AddAndMinusNoStruct, Minus, MulAndDivAndModNoStruct => ActionFn(340);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action120::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 16)
+        let __end = __sym2.2;
+        let __nt = super::__action340::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 20)
     }
     fn __reduce35<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Ident => ActionFn(121);
-        let __sym0 = __pop_Variant5(__symbols);
+        // AddAndMinusNoStruct = MulAndDivAndModNoStruct => ActionFn(170);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action121::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action170::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 20)
     }
     fn __reduce36<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Num => ActionFn(122);
-        let __sym0 = __pop_Variant24(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action122::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        // ArgList =  => ActionFn(64);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action64::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (0, 21)
     }
     fn __reduce37<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = Float => ActionFn(123);
-        let __sym0 = __pop_Variant16(__symbols);
+        // ArgList = Expr => ActionFn(65);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action123::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action65::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 21)
     }
     fn __reduce38<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = StringLiteral => ActionFn(124);
-        let __sym0 = __pop_Variant5(__symbols);
+        // ArgList = Expr, Comma, ArgListTail => ActionFn(66);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action124::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2;
+        let __nt = super::__action66::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 21)
     }
     fn __reduce39<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = BooleanLiteral => ActionFn(125);
-        let __sym0 = __pop_Variant11(__symbols);
+        // ArgListTail = Expr => ActionFn(67);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action125::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action67::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 22)
     }
     fn __reduce40<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeI8 => ActionFn(126);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ArgListTail = Expr, Comma, ArgListTail => ActionFn(68);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant14(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action126::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2;
+        let __nt = super::__action68::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (3, 22)
     }
     fn __reduce41<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeU8 => ActionFn(127);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident, LParen, RParen => ActionFn(341);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action127::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym2.2;
+        let __nt = super::__action341::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 23)
     }
     fn __reduce42<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeI16 => ActionFn(128);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident, LParen, Expr, Comma, RParen => ActionFn(387);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action128::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym4.2;
+        let __nt = super::__action387::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 23)
     }
     fn __reduce43<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeU16 => ActionFn(129);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident, LParen, Expr, RParen => ActionFn(388);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action129::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym3.2;
+        let __nt = super::__action388::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 23)
     }
     fn __reduce44<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeI32 => ActionFn(130);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident, LParen, Expr, (Comma <Expr>)+, Comma, RParen => ActionFn(389);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action130::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym5.2;
+        let __nt = super::__action389::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 23)
     }
     fn __reduce45<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeU32 => ActionFn(131);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(390);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action131::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym4.2;
+        let __nt = super::__action390::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 23)
     }
     fn __reduce46<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeI64 => ActionFn(132);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident, LBrace, StructInitFields, RBrace => ActionFn(135);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant44(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action132::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __end = __sym3.2;
+        let __nt = super::__action135::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 23)
     }
     fn __reduce47<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeU64 => ActionFn(133);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Ident => ActionFn(136);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action133::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action136::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce48<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeF16 => ActionFn(134);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Num => ActionFn(137);
+        let __sym0 = __pop_Variant32(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action134::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action137::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce49<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeF32 => ActionFn(135);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = Float => ActionFn(138);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action135::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action138::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce50<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = TypeF64 => ActionFn(136);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = StringLiteral => ActionFn(139);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action136::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 16)
+        let __nt = super::__action139::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce51<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = LParen, RParen => ActionFn(137);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = CharacterLiteral => ActionFn(140);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action137::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action140::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce52<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = LParen, Expr, RParen => ActionFn(138);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Atom = BooleanLiteral => ActionFn(141);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action138::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action141::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce53<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = LBracket, RBracket => ActionFn(139);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Atom = TypeI8 => ActionFn(142);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action139::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action142::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce54<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = LBracket, Expr, RBracket => ActionFn(226);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Atom = TypeU8 => ActionFn(143);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action226::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action143::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce55<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Atom = LBracket, Expr, (Comma <Expr>)+, RBracket => ActionFn(227);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Atom = TypeI16 => ActionFn(144);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action227::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 16)
+        let __end = __sym0.2;
+        let __nt = super::__action144::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce56<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = Ident, LParen, RParen => ActionFn(166);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Atom = TypeU16 => ActionFn(145);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action166::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action145::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce57<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = Ident, LParen, Expr, RParen => ActionFn(228);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Atom = TypeI32 => ActionFn(146);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action228::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action146::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce58<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(229);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Atom = TypeU32 => ActionFn(147);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action229::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action147::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce59<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = Ident => ActionFn(168);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Atom = TypeI64 => ActionFn(148);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action168::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action148::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce60<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = Num => ActionFn(169);
-        let __sym0 = __pop_Variant24(__symbols);
+        // Atom = TypeU64 => ActionFn(149);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action169::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action149::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce61<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = Float => ActionFn(170);
-        let __sym0 = __pop_Variant16(__symbols);
+        // Atom = TypeF16 => ActionFn(150);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action170::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action150::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce62<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = StringLiteral => ActionFn(171);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Atom = TypeF32 => ActionFn(151);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action171::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action151::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce63<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = BooleanLiteral => ActionFn(172);
-        let __sym0 = __pop_Variant11(__symbols);
+        // Atom = TypeF64 => ActionFn(152);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action172::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __nt = super::__action152::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 23)
     }
     fn __reduce64<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeI8 => ActionFn(173);
+        // Atom = LParen, RParen => ActionFn(153);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action173::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym1.2;
+        let __nt = super::__action153::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 23)
     }
     fn __reduce65<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeU8 => ActionFn(174);
+        // Atom = LParen, Expr, RParen => ActionFn(154);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action174::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym2.2;
+        let __nt = super::__action154::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 23)
     }
     fn __reduce66<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeI16 => ActionFn(175);
+        // Atom = LBracket, RBracket => ActionFn(155);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action175::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym1.2;
+        let __nt = super::__action155::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 23)
     }
     fn __reduce67<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeU16 => ActionFn(176);
+        // Atom = LBracket, Expr, Comma, RBracket => ActionFn(391);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action176::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym3.2;
+        let __nt = super::__action391::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 23)
     }
     fn __reduce68<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeI32 => ActionFn(177);
+        // Atom = LBracket, Expr, RBracket => ActionFn(392);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action177::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym2.2;
+        let __nt = super::__action392::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 23)
     }
     fn __reduce69<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeU32 => ActionFn(178);
+        // Atom = LBracket, Expr, (Comma <Expr>)+, Comma, RBracket => ActionFn(393);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action178::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym4.2;
+        let __nt = super::__action393::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 23)
     }
     fn __reduce70<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeI64 => ActionFn(179);
+        // Atom = LBracket, Expr, (Comma <Expr>)+, RBracket => ActionFn(394);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action179::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym3.2;
+        let __nt = super::__action394::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 23)
     }
     fn __reduce71<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeU64 => ActionFn(180);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Ident, LParen, RParen => ActionFn(344);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action180::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym2.2;
+        let __nt = super::__action344::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 24)
     }
     fn __reduce72<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeF16 => ActionFn(181);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Ident, LParen, Expr, Comma, RParen => ActionFn(395);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action181::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym4.2;
+        let __nt = super::__action395::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 24)
     }
     fn __reduce73<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeF32 => ActionFn(182);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Ident, LParen, Expr, RParen => ActionFn(396);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action182::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym3.2;
+        let __nt = super::__action396::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 24)
     }
     fn __reduce74<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = TypeF64 => ActionFn(183);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Ident, LParen, Expr, (Comma <Expr>)+, Comma, RParen => ActionFn(397);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action183::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 17)
+        let __end = __sym5.2;
+        let __nt = super::__action397::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 24)
     }
     fn __reduce75<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = LParen, RParen => ActionFn(184);
-        assert!(__symbols.len() >= 2);
+        // AtomNoStruct = Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(398);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action184::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 17)
+        let __end = __sym4.2;
+        let __nt = super::__action398::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 24)
     }
     fn __reduce76<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = LParen, Expr, RParen => ActionFn(185);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Ident => ActionFn(185);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action185::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action185::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce77<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = LBracket, RBracket => ActionFn(186);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Num => ActionFn(186);
+        let __sym0 = __pop_Variant32(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action186::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action186::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce78<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = LBracket, Expr, RBracket => ActionFn(230);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = Float => ActionFn(187);
+        let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action230::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action187::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce79<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // AtomNoStruct = LBracket, Expr, (Comma <Expr>)+, RBracket => ActionFn(231);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = StringLiteral => ActionFn(188);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action231::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 17)
+        let __end = __sym0.2;
+        let __nt = super::__action188::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce80<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Block = LBrace, Stmts, RBrace => ActionFn(59);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant10(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = CharacterLiteral => ActionFn(189);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action59::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (3, 18)
+        let __end = __sym0.2;
+        let __nt = super::__action189::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce81<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BooleanLiteral = TRUE => ActionFn(80);
-        let __sym0 = __pop_Variant0(__symbols);
+        // AtomNoStruct = BooleanLiteral => ActionFn(190);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action80::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 19)
+        let __nt = super::__action190::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce82<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // BooleanLiteral = FALSE => ActionFn(81);
+        // AtomNoStruct = TypeI8 => ActionFn(191);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action81::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (1, 19)
+        let __nt = super::__action191::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce83<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = Comparison, EqEq, AddAndMinus => ActionFn(96);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeU8 => ActionFn(192);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action96::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action192::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce84<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = Comparison, Neq, AddAndMinus => ActionFn(97);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeI16 => ActionFn(193);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action97::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action193::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce85<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = Comparison, Lt, AddAndMinus => ActionFn(98);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeU16 => ActionFn(194);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action98::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action194::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce86<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = Comparison, Gt, AddAndMinus => ActionFn(99);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeI32 => ActionFn(195);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action99::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action195::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce87<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = Comparison, Le, AddAndMinus => ActionFn(100);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeU32 => ActionFn(196);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action100::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action196::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce88<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = Comparison, Ge, AddAndMinus => ActionFn(101);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeI64 => ActionFn(197);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action101::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 20)
+        let __end = __sym0.2;
+        let __nt = super::__action197::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce89<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Comparison = AddAndMinus => ActionFn(102);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeU64 => ActionFn(198);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action102::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 20)
+        let __nt = super::__action198::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce90<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = ComparisonNoStruct, EqEq, AddAndMinusNoStruct => ActionFn(144);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeF16 => ActionFn(199);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action144::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 21)
+        let __end = __sym0.2;
+        let __nt = super::__action199::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce91<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = ComparisonNoStruct, Neq, AddAndMinusNoStruct => ActionFn(145);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeF32 => ActionFn(200);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action145::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 21)
+        let __end = __sym0.2;
+        let __nt = super::__action200::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce92<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = ComparisonNoStruct, Lt, AddAndMinusNoStruct => ActionFn(146);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = TypeF64 => ActionFn(201);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action146::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 21)
+        let __end = __sym0.2;
+        let __nt = super::__action201::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 24)
     }
     fn __reduce93<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = ComparisonNoStruct, Gt, AddAndMinusNoStruct => ActionFn(147);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        // AtomNoStruct = LParen, RParen => ActionFn(202);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action147::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 21)
+        let __end = __sym1.2;
+        let __nt = super::__action202::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 24)
     }
     fn __reduce94<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = ComparisonNoStruct, Le, AddAndMinusNoStruct => ActionFn(148);
+        // AtomNoStruct = LParen, Expr, RParen => ActionFn(203);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action148::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 21)
+        let __nt = super::__action203::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 24)
     }
     fn __reduce95<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = ComparisonNoStruct, Ge, AddAndMinusNoStruct => ActionFn(149);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        // AtomNoStruct = LBracket, RBracket => ActionFn(204);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action149::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 21)
+        let __end = __sym1.2;
+        let __nt = super::__action204::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 24)
     }
     fn __reduce96<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ComparisonNoStruct = AddAndMinusNoStruct => ActionFn(150);
-        let __sym0 = __pop_Variant1(__symbols);
+        // AtomNoStruct = LBracket, Expr, Comma, RBracket => ActionFn(399);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action150::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 21)
+        let __end = __sym3.2;
+        let __nt = super::__action399::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 24)
     }
     fn __reduce97<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // EnumDef = PublicKw, Enum, Ident, LBrace, EnumVariantList, RBrace => ActionFn(12);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant13(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        // AtomNoStruct = LBracket, Expr, RBracket => ActionFn(400);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action12::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
-        (6, 22)
+        let __end = __sym2.2;
+        let __nt = super::__action400::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 24)
     }
     fn __reduce98<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // EnumVariantList =  => ActionFn(19);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action19::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 23)
+        // AtomNoStruct = LBracket, Expr, (Comma <Expr>)+, Comma, RBracket => ActionFn(401);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym4.2;
+        let __nt = super::__action401::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 24)
     }
     fn __reduce99<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // EnumVariantList = Ident, MoreEnumVariants => ActionFn(20);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // AtomNoStruct = LBracket, Expr, (Comma <Expr>)+, RBracket => ActionFn(402);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action20::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 23)
+        let __end = __sym3.2;
+        let __nt = super::__action402::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 24)
     }
     fn __reduce100<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // EnumVariantTail =  => ActionFn(23);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action23::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 24)
+        // Block = LBrace, Stmts, RBrace => ActionFn(69);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action69::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (3, 25)
     }
     fn __reduce101<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // EnumVariantTail = Ident, MoreEnumVariants => ActionFn(24);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // BooleanLiteral = TRUE => ActionFn(94);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action24::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 24)
+        let __end = __sym0.2;
+        let __nt = super::__action94::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 26)
     }
     fn __reduce102<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Expr = RangeExpr => ActionFn(93);
-        let __sym0 = __pop_Variant1(__symbols);
+        // BooleanLiteral = FALSE => ActionFn(95);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action93::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 25)
+        let __nt = super::__action95::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 26)
     }
     fn __reduce103<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ExprNoStruct = RangeExprNoStruct => ActionFn(141);
-        let __sym0 = __pop_Variant1(__symbols);
+        // CharacterLiteral = CharLiteral => ActionFn(93);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action141::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 26)
+        let __nt = super::__action93::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 27)
     }
     fn __reduce104<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // F16Literal = TypeF16 => ActionFn(90);
+        // Comma? = Comma => ActionFn(232);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action90::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
-        (1, 27)
+        let __nt = super::__action232::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 28)
     }
     fn __reduce105<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // F32Literal = TypeF32 => ActionFn(91);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action91::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
-        (1, 28)
+        // Comma? =  => ActionFn(233);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action233::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 28)
     }
     fn __reduce106<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // F64Literal = TypeF64 => ActionFn(92);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comparison = Comparison, EqEq, AddAndMinus => ActionFn(347);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action92::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 29)
+        let __end = __sym2.2;
+        let __nt = super::__action347::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce107<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FieldDefault = Assign, Expr => ActionFn(28);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comparison = Comparison, Neq, AddAndMinus => ActionFn(348);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action28::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 30)
+        let __end = __sym2.2;
+        let __nt = super::__action348::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce108<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FieldDefault? = FieldDefault => ActionFn(200);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Comparison = Comparison, Lt, AddAndMinus => ActionFn(349);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action200::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (1, 31)
+        let __end = __sym2.2;
+        let __nt = super::__action349::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce109<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FieldDefault? =  => ActionFn(201);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action201::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (0, 31)
+        // Comparison = Comparison, Gt, AddAndMinus => ActionFn(350);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action350::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce110<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FieldType = GtGt, Type => ActionFn(27);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant18(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comparison = Comparison, Le, AddAndMinus => ActionFn(351);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action27::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (2, 32)
+        let __end = __sym2.2;
+        let __nt = super::__action351::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce111<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FieldType? = FieldType => ActionFn(202);
-        let __sym0 = __pop_Variant18(__symbols);
+        // Comparison = Comparison, Ge, AddAndMinus => ActionFn(352);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action202::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (1, 33)
+        let __end = __sym2.2;
+        let __nt = super::__action352::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce112<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FieldType? =  => ActionFn(203);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action203::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 33)
+        // Comparison = Comparison, In, AddAndMinus => ActionFn(353);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action353::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 29)
     }
     fn __reduce113<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Float = FLOAT => ActionFn(78);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Comparison = AddAndMinus => ActionFn(117);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action78::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
-        (1, 34)
+        let __nt = super::__action117::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 29)
     }
     fn __reduce114<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FnKw = Function => ActionFn(29);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ComparisonNoStruct = ComparisonNoStruct, EqEq, AddAndMinusNoStruct => ActionFn(354);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action29::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
-        (1, 35)
+        let __end = __sym2.2;
+        let __nt = super::__action354::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce115<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FunctionDef = PublicKw, FnKw, Ident, LParen, ParamList, RParen, ReturnType, Block => ActionFn(32);
-        assert!(__symbols.len() >= 8);
-        let __sym7 = __pop_Variant10(__symbols);
-        let __sym6 = __pop_Variant19(__symbols);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant29(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant20(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        // ComparisonNoStruct = ComparisonNoStruct, Neq, AddAndMinusNoStruct => ActionFn(355);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym7.2;
-        let __nt = super::__action32::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (8, 36)
+        let __end = __sym2.2;
+        let __nt = super::__action355::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce116<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // FunctionParamNode = Ident => ActionFn(35);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action35::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 37)
+        // ComparisonNoStruct = ComparisonNoStruct, Lt, AddAndMinusNoStruct => ActionFn(356);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action356::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce117<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // I16Literal = TypeI16 => ActionFn(84);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ComparisonNoStruct = ComparisonNoStruct, Gt, AddAndMinusNoStruct => ActionFn(357);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action84::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
-        (1, 38)
+        let __end = __sym2.2;
+        let __nt = super::__action357::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce118<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // I32Literal = TypeI32 => ActionFn(86);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ComparisonNoStruct = ComparisonNoStruct, Le, AddAndMinusNoStruct => ActionFn(358);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action86::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
-        (1, 39)
+        let __end = __sym2.2;
+        let __nt = super::__action358::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce119<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // I64Literal = TypeI64 => ActionFn(88);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ComparisonNoStruct = ComparisonNoStruct, Ge, AddAndMinusNoStruct => ActionFn(359);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action88::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 40)
+        let __end = __sym2.2;
+        let __nt = super::__action359::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce120<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // I8Literal = TypeI8 => ActionFn(82);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ComparisonNoStruct = ComparisonNoStruct, In, AddAndMinusNoStruct => ActionFn(360);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action82::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
-        (1, 41)
+        let __end = __sym2.2;
+        let __nt = super::__action360::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 30)
     }
     fn __reduce121<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Ident = IDENT => ActionFn(76);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ComparisonNoStruct = AddAndMinusNoStruct => ActionFn(167);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action76::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (1, 42)
+        let __nt = super::__action167::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 30)
     }
     fn __reduce122<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IdentList =  => ActionFn(13);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action13::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 43)
+        // EnumDef = PublicKw, Enum, Ident, LBrace, EnumVariantList, RBrace => ActionFn(17);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action17::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (6, 31)
     }
     fn __reduce123<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IdentList = Ident => ActionFn(242);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action242::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (1, 43)
+        // EnumVariantList =  => ActionFn(24);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action24::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 32)
     }
     fn __reduce124<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IdentList = Ident, (Comma <Ident>)+ => ActionFn(243);
+        // EnumVariantList = Ident, MoreEnumVariants => ActionFn(25);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant6(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action243::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 43)
+        let __nt = super::__action25::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 32)
     }
     fn __reduce125<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IfStmt = If, Expr, Then, Block => ActionFn(68);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action68::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (4, 44)
+        // EnumVariantTail =  => ActionFn(28);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action28::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 33)
     }
     fn __reduce126<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // IfStmt = If, Expr, Then, Block, Else, Block => ActionFn(69);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant10(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // EnumVariantTail = Ident, MoreEnumVariants => ActionFn(29);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action69::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (6, 44)
+        let __end = __sym1.2;
+        let __nt = super::__action29::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 33)
     }
     fn __reduce127<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ImportDirective = Import, Ident, Semi => ActionFn(10);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Expr = RangeExpr => ActionFn(107);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action10::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 45)
+        let __end = __sym0.2;
+        let __nt = super::__action107::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 34)
     }
     fn __reduce128<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode = FunctionDef => ActionFn(2);
-        let __sym0 = __pop_Variant21(__symbols);
+        // ExprNoStruct = RangeExprNoStruct => ActionFn(157);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action2::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
+        let __nt = super::__action157::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 35)
     }
     fn __reduce129<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode = VarDecl => ActionFn(3);
-        let __sym0 = __pop_Variant38(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action3::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
-    }
-    fn __reduce130<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // ItemNode = PreprocessorDirective => ActionFn(4);
-        let __sym0 = __pop_Variant5(__symbols);
+        // ExternFnDecl = ExternKw, FnKw, Ident, LParen, ExternParamList, RParen, ReturnType, Semi => ActionFn(361);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant29(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action4::<>(__sym0);
+        let __end = __sym7.2;
+        let __nt = super::__action361::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
+        (8, 36)
     }
     fn __reduce131<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode = ImportDirective => ActionFn(5);
-        let __sym0 = __pop_Variant5(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action5::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
+        // ExternParamList =  => ActionFn(43);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action43::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 38)
     }
     fn __reduce132<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode = PackageDirective => ActionFn(6);
-        let __sym0 = __pop_Variant5(__symbols);
+        // ExternParamList = ExternParamNode, Comma => ActionFn(403);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action6::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
+        let __end = __sym1.2;
+        let __nt = super::__action403::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 38)
     }
     fn __reduce133<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode = EnumDef => ActionFn(7);
-        let __sym0 = __pop_Variant12(__symbols);
+        // ExternParamList = ExternParamNode => ActionFn(404);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action7::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
+        let __nt = super::__action404::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 38)
     }
     fn __reduce134<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode = StructDef => ActionFn(8);
-        let __sym0 = __pop_Variant31(__symbols);
+        // ExternParamList = ExternParamNode, (Comma <ExternParamNode>)+, Comma => ActionFn(405);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action8::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
-        (1, 46)
+        let __end = __sym2.2;
+        let __nt = super::__action405::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (3, 38)
     }
     fn __reduce135<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode* =  => ActionFn(210);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action210::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (0, 47)
+        // ExternParamList = ExternParamNode, (Comma <ExternParamNode>)+ => ActionFn(406);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant6(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action406::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 38)
     }
     fn __reduce136<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode* = ItemNode+ => ActionFn(211);
-        let __sym0 = __pop_Variant27(__symbols);
+        // ExternParamNode = Ident, GtGt, Type => ActionFn(362);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant28(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action211::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 47)
+        let __end = __sym2.2;
+        let __nt = super::__action362::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (3, 39)
     }
     fn __reduce137<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode+ = ItemNode => ActionFn(212);
-        let __sym0 = __pop_Variant21(__symbols);
+        // F16Literal = TypeF16 => ActionFn(104);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action212::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (1, 48)
+        let __nt = super::__action104::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (1, 40)
     }
     fn __reduce138<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ItemNode+ = ItemNode+, ItemNode => ActionFn(213);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant21(__symbols);
-        let __sym0 = __pop_Variant27(__symbols);
+        // F32Literal = TypeF32 => ActionFn(105);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action213::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
-        (2, 48)
+        let __end = __sym0.2;
+        let __nt = super::__action105::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 41)
     }
     fn __reduce139<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MoreEnumVariants =  => ActionFn(21);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action21::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (0, 49)
+        // F64Literal = TypeF64 => ActionFn(106);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action106::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 42)
     }
     fn __reduce140<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MoreEnumVariants = Comma, EnumVariantTail => ActionFn(22);
+        // FieldDefault = Assign, Expr => ActionFn(33);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant13(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action22::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
-        (2, 49)
+        let __nt = super::__action33::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 43)
     }
     fn __reduce141<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MoreStructFields =  => ActionFn(25);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action25::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (0, 50)
+        // FieldDefault? = FieldDefault => ActionFn(225);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action225::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 44)
     }
     fn __reduce142<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MoreStructFields = Comma, StructFieldNode, MoreStructFields => ActionFn(26);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant28(__symbols);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action26::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (3, 50)
+        // FieldDefault? =  => ActionFn(226);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action226::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (0, 44)
     }
     fn __reduce143<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndMod = MulAndDivAndMod, Star, Unary => ActionFn(106);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FieldType = GtGt, Type => ActionFn(32);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action106::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 51)
+        let __end = __sym1.2;
+        let __nt = super::__action32::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (2, 45)
     }
     fn __reduce144<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndMod = MulAndDivAndMod, Div, Unary => ActionFn(107);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FieldType? = FieldType => ActionFn(227);
+        let __sym0 = __pop_Variant28(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action107::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 51)
+        let __end = __sym0.2;
+        let __nt = super::__action227::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (1, 46)
     }
     fn __reduce145<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndMod = MulAndDivAndMod, Mod, Unary => ActionFn(108);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action108::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 51)
+        // FieldType? =  => ActionFn(228);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action228::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (0, 46)
     }
     fn __reduce146<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndMod = Unary => ActionFn(109);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Float = FLOAT => ActionFn(91);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action109::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 51)
+        let __nt = super::__action91::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        (1, 47)
     }
     fn __reduce147<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndModNoStruct = MulAndDivAndModNoStruct, Star, UnaryNoStruct => ActionFn(154);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FnKw = Function => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action154::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 52)
+        let __end = __sym0.2;
+        let __nt = super::__action34::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (1, 48)
     }
     fn __reduce148<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndModNoStruct = MulAndDivAndModNoStruct, Div, UnaryNoStruct => ActionFn(155);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FunctionDef = PublicKw, FnKw, Ident, LParen, ParamList, RParen, ReturnType, Block => ActionFn(363);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant15(__symbols);
+        let __sym6 = __pop_Variant29(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant37(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action155::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 52)
+        let __end = __sym7.2;
+        let __nt = super::__action363::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (8, 49)
     }
     fn __reduce149<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndModNoStruct = MulAndDivAndModNoStruct, Mod, UnaryNoStruct => ActionFn(156);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // FunctionParamNode = Ident => ActionFn(364);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action156::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 52)
+        let __end = __sym0.2;
+        let __nt = super::__action364::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 50)
     }
     fn __reduce150<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // MulAndDivAndModNoStruct = UnaryNoStruct => ActionFn(157);
-        let __sym0 = __pop_Variant1(__symbols);
+        // I16Literal = TypeI16 => ActionFn(98);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action157::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 52)
+        let __nt = super::__action98::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
+        (1, 51)
     }
     fn __reduce151<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Num = NUM => ActionFn(77);
+        // I32Literal = TypeI32 => ActionFn(100);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action77::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
-        (1, 53)
+        let __nt = super::__action100::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
+        (1, 52)
     }
     fn __reduce152<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PackageDirective = Package, Ident, Semi => ActionFn(11);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
+        // I64Literal = TypeI64 => ActionFn(102);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action11::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 54)
+        let __end = __sym0.2;
+        let __nt = super::__action102::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
+        (1, 53)
     }
     fn __reduce153<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ParamList =  => ActionFn(33);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action33::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (0, 55)
+        // I8Literal = TypeI8 => ActionFn(96);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action96::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
+        (1, 54)
     }
     fn __reduce154<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ParamList = FunctionParamNode => ActionFn(238);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Ident = IDENT => ActionFn(89);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action238::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        let __nt = super::__action89::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (1, 55)
     }
     fn __reduce155<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ParamList = FunctionParamNode, (Comma <FunctionParamNode>)+ => ActionFn(239);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant4(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action239::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
-        (2, 55)
+        // IdentList =  => ActionFn(18);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action18::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 56)
     }
     fn __reduce156<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, LBracket, Expr, RBracket => ActionFn(111);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // IdentList = Ident => ActionFn(279);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action111::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 56)
+        let __end = __sym0.2;
+        let __nt = super::__action279::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 56)
     }
     fn __reduce157<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, PlusPlus => ActionFn(112);
+        // IdentList = Ident, (Comma <Ident>)+ => ActionFn(280);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action112::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action280::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (2, 56)
     }
     fn __reduce158<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, MinusMinus => ActionFn(113);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // IfStmt = If, Expr, Then, Block => ActionFn(81);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action113::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 56)
+        let __end = __sym3.2;
+        let __nt = super::__action81::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (4, 57)
     }
     fn __reduce159<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, Dot, Ident, LParen, RParen => ActionFn(114);
-        assert!(__symbols.len() >= 5);
+        // IfStmt = If, Expr, Then, Block, Else, Block => ActionFn(82);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant15(__symbols);
         let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action114::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 56)
+        let __end = __sym5.2;
+        let __nt = super::__action82::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (6, 57)
     }
     fn __reduce160<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, Dot, Ident, LParen, Expr, RParen => ActionFn(232);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ImportDirective = Import, ModulePath, As, Ident, Semi => ActionFn(365);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant2(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action232::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 56)
+        let __end = __sym4.2;
+        let __nt = super::__action365::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (5, 58)
     }
     fn __reduce161<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, Dot, Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(233);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant2(__symbols);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ImportDirective = Import, ModulePath, Semi => ActionFn(366);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action233::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 56)
+        let __end = __sym2.2;
+        let __nt = super::__action366::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (3, 58)
     }
     fn __reduce162<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Postfix, Dot, Ident => ActionFn(116);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ImportDirective = Import, ModulePath, Dot, LBrace, Ident, Comma, RBrace, Semi => ActionFn(407);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action116::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 56)
+        let __end = __sym7.2;
+        let __nt = super::__action407::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (8, 58)
     }
     fn __reduce163<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Postfix = Atom => ActionFn(117);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ImportDirective = Import, ModulePath, Dot, LBrace, Ident, RBrace, Semi => ActionFn(408);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action117::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 56)
+        let __end = __sym6.2;
+        let __nt = super::__action408::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (7, 58)
     }
     fn __reduce164<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, LBracket, Expr, RBracket => ActionFn(159);
-        assert!(__symbols.len() >= 4);
+        // ImportDirective = Import, ModulePath, Dot, LBrace, Ident, (Comma <Ident>)+, Comma, RBrace, Semi => ActionFn(409);
+        assert!(__symbols.len() >= 9);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant10(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action159::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (4, 57)
+        let __end = __sym8.2;
+        let __nt = super::__action409::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (9, 58)
     }
     fn __reduce165<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, PlusPlus => ActionFn(160);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ImportDirective = Import, ModulePath, Dot, LBrace, Ident, (Comma <Ident>)+, RBrace, Semi => ActionFn(410);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant10(__symbols);
+        let __sym4 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action160::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 57)
+        let __end = __sym7.2;
+        let __nt = super::__action410::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        (8, 58)
     }
     fn __reduce166<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, MinusMinus => ActionFn(161);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ItemNode = FunctionDef => ActionFn(3);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action161::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 57)
+        let __end = __sym0.2;
+        let __nt = super::__action3::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce167<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, RParen => ActionFn(162);
-        assert!(__symbols.len() >= 5);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ItemNode = ExternFnDecl => ActionFn(4);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action162::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (5, 57)
+        let __end = __sym0.2;
+        let __nt = super::__action4::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce168<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, Expr, RParen => ActionFn(234);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ItemNode = VarDecl => ActionFn(5);
+        let __sym0 = __pop_Variant49(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action234::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (6, 57)
+        let __end = __sym0.2;
+        let __nt = super::__action5::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce169<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(235);
-        assert!(__symbols.len() >= 7);
-        let __sym6 = __pop_Variant0(__symbols);
-        let __sym5 = __pop_Variant2(__symbols);
-        let __sym4 = __pop_Variant1(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ItemNode = PreprocessorDirective => ActionFn(6);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym6.2;
-        let __nt = super::__action235::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (7, 57)
+        let __end = __sym0.2;
+        let __nt = super::__action6::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce170<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = PostfixNoStruct, Dot, Ident => ActionFn(164);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ItemNode = ImportDirective => ActionFn(7);
+        let __sym0 = __pop_Variant35(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action164::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 57)
+        let __end = __sym0.2;
+        let __nt = super::__action7::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce171<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PostfixNoStruct = AtomNoStruct => ActionFn(165);
-        let __sym0 = __pop_Variant1(__symbols);
+        // ItemNode = PackageDirective => ActionFn(8);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action165::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 57)
+        let __nt = super::__action8::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce172<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PreprocessorDirective = Preprocessor, Ident => ActionFn(9);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant5(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ItemNode = EnumDef => ActionFn(9);
+        let __sym0 = __pop_Variant19(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action9::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (2, 58)
+        let __end = __sym0.2;
+        let __nt = super::__action9::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 59)
     }
     fn __reduce173<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PublicKw = Public => ActionFn(30);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ItemNode = StructDef => ActionFn(10);
+        let __sym0 = __pop_Variant42(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action30::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        let __nt = super::__action10::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 59)
     }
     fn __reduce174<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // PublicKw =  => ActionFn(31);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action31::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
-        (0, 59)
+        // ModulePath = Ident => ActionFn(15);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action15::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 60)
     }
     fn __reduce175<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RangeExpr = Comparison, DotDot, Comparison => ActionFn(94);
+        // ModulePath = ModulePath, Dot, Ident => ActionFn(16);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action94::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action16::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
         (3, 60)
     }
     fn __reduce176<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RangeExpr = Comparison => ActionFn(95);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action95::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 60)
+        // MoreEnumVariants =  => ActionFn(26);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action26::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (0, 61)
     }
     fn __reduce177<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RangeExprNoStruct = ComparisonNoStruct, DotDot, ComparisonNoStruct => ActionFn(142);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // MoreEnumVariants = Comma, EnumVariantTail => ActionFn(27);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action142::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 61)
+        let __end = __sym1.2;
+        let __nt = super::__action27::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 61)
     }
     fn __reduce178<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // RangeExprNoStruct = ComparisonNoStruct => ActionFn(143);
-        let __sym0 = __pop_Variant1(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action143::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 61)
+        // MoreStructFields =  => ActionFn(30);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action30::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 62)
     }
     fn __reduce179<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ReturnType = GtGt, Type => ActionFn(52);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant18(__symbols);
+        // MoreStructFields = Comma, StructFieldNode, MoreStructFields => ActionFn(31);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant36(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action52::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (2, 62)
+        let __end = __sym2.2;
+        let __nt = super::__action31::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (3, 62)
     }
     fn __reduce180<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // ReturnType =  => ActionFn(53);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action53::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
-        (0, 62)
+        // MulAndDivAndMod = MulAndDivAndMod, Star, Unary => ActionFn(369);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action369::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 63)
     }
     fn __reduce181<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Start =  => ActionFn(256);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action256::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (0, 63)
+        // MulAndDivAndMod = MulAndDivAndMod, Div, Unary => ActionFn(370);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action370::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 63)
     }
     fn __reduce182<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Start = ItemNode+ => ActionFn(257);
-        let __sym0 = __pop_Variant27(__symbols);
+        // MulAndDivAndMod = MulAndDivAndMod, Mod, Unary => ActionFn(371);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action257::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant30(__nt), __end));
-        (1, 63)
+        let __end = __sym2.2;
+        let __nt = super::__action371::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 63)
     }
     fn __reduce183<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmt = VarDecl => ActionFn(62);
-        let __sym0 = __pop_Variant38(__symbols);
+        // MulAndDivAndMod = Unary => ActionFn(124);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action62::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (1, 64)
-    }
-    fn __reduce185<
-    >(
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: core::marker::PhantomData<()>,
-    ) -> (usize, usize)
-    {
-        // Stmt = If, Expr, Then, Block => ActionFn(254);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action254::<>(__sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (4, 64)
+        let __nt = super::__action124::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 63)
     }
-    fn __reduce186<
+    fn __reduce184<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmt = If, Expr, Then, Block, Else, Block => ActionFn(255);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant10(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant10(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // MulAndDivAndModNoStruct = MulAndDivAndModNoStruct, Star, UnaryNoStruct => ActionFn(372);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action255::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (6, 64)
+        let __end = __sym2.2;
+        let __nt = super::__action372::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 64)
     }
-    fn __reduce187<
+    fn __reduce185<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmt = While, ExprNoStruct, Block => ActionFn(65);
+        // MulAndDivAndModNoStruct = MulAndDivAndModNoStruct, Div, UnaryNoStruct => ActionFn(373);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant10(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action65::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        let __nt = super::__action373::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (3, 64)
     }
-    fn __reduce188<
+    fn __reduce186<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmt = Return, Expr, Semi => ActionFn(66);
+        // MulAndDivAndModNoStruct = MulAndDivAndModNoStruct, Mod, UnaryNoStruct => ActionFn(374);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action66::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
+        let __nt = super::__action374::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
         (3, 64)
     }
-    fn __reduce189<
+    fn __reduce187<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmt = Return, Semi => ActionFn(67);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // MulAndDivAndModNoStruct = UnaryNoStruct => ActionFn(174);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action67::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant26(__nt), __end));
-        (2, 64)
+        let __end = __sym0.2;
+        let __nt = super::__action174::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 64)
     }
-    fn __reduce190<
+    fn __reduce188<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StmtTail = Semi => ActionFn(70);
+        // Num = NUM => ActionFn(90);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action70::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        let __nt = super::__action90::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
         (1, 65)
     }
-    fn __reduce191<
+    fn __reduce189<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StmtTail = Assign, Expr, Semi => ActionFn(71);
+        // PackageDirective = Package, ModulePath, Semi => ActionFn(14);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action71::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
-        (3, 65)
+        let __nt = super::__action14::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (3, 66)
     }
-    fn __reduce192<
+    fn __reduce190<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmts =  => ActionFn(60);
+        // ParamList =  => ActionFn(38);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
         let __end = __start;
-        let __nt = super::__action60::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (0, 66)
+        let __nt = super::__action38::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (0, 67)
     }
-    fn __reduce193<
+    fn __reduce191<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Stmts = Stmts, Stmt => ActionFn(61);
+        // ParamList = FunctionParamNode, Comma => ActionFn(411);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant26(__symbols);
-        let __sym0 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action61::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
-        (2, 66)
+        let __nt = super::__action411::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (2, 67)
     }
-    fn __reduce194<
+    fn __reduce192<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StringLiteral = StrLiteral => ActionFn(79);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ParamList = FunctionParamNode => ActionFn(412);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action79::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        let __nt = super::__action412::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
         (1, 67)
     }
+    fn __reduce193<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // ParamList = FunctionParamNode, (Comma <FunctionParamNode>)+, Comma => ActionFn(413);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action413::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (3, 67)
+    }
+    fn __reduce194<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // ParamList = FunctionParamNode, (Comma <FunctionParamNode>)+ => ActionFn(414);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action414::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        (2, 67)
+    }
     fn __reduce195<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructDef = PublicKw, Struct, Ident, LBrace, StructFieldList, RBrace => ActionFn(15);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant0(__symbols);
-        let __sym4 = __pop_Variant28(__symbols);
+        // Postfix = Postfix, LBracket, Expr, RBracket => ActionFn(375);
+        assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action15::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant31(__nt), __end));
-        (6, 68)
+        let __end = __sym3.2;
+        let __nt = super::__action375::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 68)
     }
     fn __reduce196<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldList =  => ActionFn(16);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action16::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (0, 69)
+        // Postfix = Postfix, PlusPlus => ActionFn(127);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action127::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 68)
     }
     fn __reduce197<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldList = StructFieldNode => ActionFn(246);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Postfix = Postfix, MinusMinus => ActionFn(128);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action246::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (1, 69)
+        let __end = __sym1.2;
+        let __nt = super::__action128::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 68)
     }
     fn __reduce198<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldList = StructFieldNode, (Comma <StructFieldNode>)+ => ActionFn(247);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant8(__symbols);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Postfix = Postfix, Dot, Ident, LParen, RParen => ActionFn(376);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action247::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
-        (2, 69)
+        let __end = __sym4.2;
+        let __nt = super::__action376::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 68)
     }
     fn __reduce199<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldNode = Ident, FieldType, FieldDefault => ActionFn(250);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
-        let __sym1 = __pop_Variant18(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Postfix = Postfix, Dot, Ident, LParen, Expr, Comma, RParen => ActionFn(415);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action250::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (3, 70)
+        let __end = __sym6.2;
+        let __nt = super::__action415::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 68)
     }
     fn __reduce200<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldNode = Ident, FieldDefault => ActionFn(251);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Postfix = Postfix, Dot, Ident, LParen, Expr, RParen => ActionFn(416);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action251::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 70)
+        let __end = __sym5.2;
+        let __nt = super::__action416::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 68)
     }
     fn __reduce201<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldNode = Ident, FieldType => ActionFn(252);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant18(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Postfix = Postfix, Dot, Ident, LParen, Expr, (Comma <Expr>)+, Comma, RParen => ActionFn(417);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action252::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (2, 70)
+        let __end = __sym7.2;
+        let __nt = super::__action417::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (8, 68)
     }
     fn __reduce202<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructFieldNode = Ident => ActionFn(253);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Postfix = Postfix, Dot, Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(418);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action253::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 70)
+        let __end = __sym6.2;
+        let __nt = super::__action418::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 68)
     }
     fn __reduce203<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructInitField = Ident, Assign, Expr => ActionFn(193);
+        // Postfix = Postfix, Dot, Ident => ActionFn(131);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action193::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant32(__nt), __end));
-        (3, 71)
+        let __nt = super::__action131::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 68)
     }
     fn __reduce204<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructInitFields =  => ActionFn(188);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action188::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (0, 72)
+        // Postfix = Atom => ActionFn(132);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action132::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 68)
     }
     fn __reduce205<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructInitFields = StructInitField => ActionFn(189);
-        let __sym0 = __pop_Variant32(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, LBracket, Expr, RBracket => ActionFn(379);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action189::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 72)
+        let __end = __sym3.2;
+        let __nt = super::__action379::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (4, 69)
     }
     fn __reduce206<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructInitFields = StructInitField, Comma, StructInitFieldsTail => ActionFn(190);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant33(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, PlusPlus => ActionFn(177);
+        assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant32(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action190::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (3, 72)
+        let __end = __sym1.2;
+        let __nt = super::__action177::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 69)
     }
     fn __reduce207<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructInitFieldsTail = StructInitField => ActionFn(191);
-        let __sym0 = __pop_Variant32(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, MinusMinus => ActionFn(178);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action191::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (1, 73)
+        let __end = __sym1.2;
+        let __nt = super::__action178::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 69)
     }
     fn __reduce208<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // StructInitFieldsTail = StructInitField, Comma, StructInitFieldsTail => ActionFn(192);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant33(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, RParen => ActionFn(380);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant32(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action192::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant33(__nt), __end));
-        (3, 73)
+        let __end = __sym4.2;
+        let __nt = super::__action380::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (5, 69)
     }
     fn __reduce209<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeInt => ActionFn(36);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, Expr, Comma, RParen => ActionFn(419);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action36::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym6.2;
+        let __nt = super::__action419::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 69)
     }
     fn __reduce210<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeFloat => ActionFn(37);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, Expr, RParen => ActionFn(420);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action37::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym5.2;
+        let __nt = super::__action420::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (6, 69)
     }
     fn __reduce211<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeBool => ActionFn(38);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, Expr, (Comma <Expr>)+, Comma, RParen => ActionFn(421);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action38::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym7.2;
+        let __nt = super::__action421::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (8, 69)
     }
     fn __reduce212<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeStr => ActionFn(39);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, Dot, Ident, LParen, Expr, (Comma <Expr>)+, RParen => ActionFn(422);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action39::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym6.2;
+        let __nt = super::__action422::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (7, 69)
     }
     fn __reduce213<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeUnit => ActionFn(40);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PostfixNoStruct = PostfixNoStruct, Dot, Ident => ActionFn(181);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action40::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym2.2;
+        let __nt = super::__action181::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 69)
     }
     fn __reduce214<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeI8 => ActionFn(41);
-        let __sym0 = __pop_Variant0(__symbols);
+        // PostfixNoStruct = AtomNoStruct => ActionFn(182);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action41::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __nt = super::__action182::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 69)
     }
     fn __reduce215<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeU8 => ActionFn(42);
+        // PreprocessorDirective = Preprocessor, Ident => ActionFn(11);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant2(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action42::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym1.2;
+        let __nt = super::__action11::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (2, 70)
     }
     fn __reduce216<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeI16 => ActionFn(43);
+        // PublicKw = Public => ActionFn(35);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action43::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __nt = super::__action35::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (1, 71)
     }
     fn __reduce217<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeU16 => ActionFn(44);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action44::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        // PublicKw =  => ActionFn(36);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action36::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (0, 71)
     }
     fn __reduce218<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeI32 => ActionFn(45);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RangeExpr = Comparison, DotDot, Comparison => ActionFn(108);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action45::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym2.2;
+        let __nt = super::__action108::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 72)
     }
     fn __reduce219<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeU32 => ActionFn(46);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RangeExpr = Comparison => ActionFn(109);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action46::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __nt = super::__action109::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 72)
     }
     fn __reduce220<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeI64 => ActionFn(47);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RangeExprNoStruct = ComparisonNoStruct, DotDot, ComparisonNoStruct => ActionFn(158);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action47::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym2.2;
+        let __nt = super::__action158::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (3, 73)
     }
     fn __reduce221<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeU64 => ActionFn(48);
-        let __sym0 = __pop_Variant0(__symbols);
+        // RangeExprNoStruct = ComparisonNoStruct => ActionFn(159);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action48::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __nt = super::__action159::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 73)
     }
     fn __reduce222<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeF16 => ActionFn(49);
+        // ReturnType = GtGt, Type => ActionFn(62);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action49::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __end = __sym1.2;
+        let __nt = super::__action62::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (2, 74)
     }
     fn __reduce223<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeF32 => ActionFn(50);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action50::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        // ReturnType =  => ActionFn(63);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action63::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant29(__nt), __end));
+        (0, 74)
     }
     fn __reduce224<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Type = TypeF64 => ActionFn(51);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SpannedItem = ItemNode => ActionFn(383);
+        let __sym0 = __pop_Variant21(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action51::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
-        (1, 74)
+        let __nt = super::__action383::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
+        (1, 75)
     }
     fn __reduce225<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // U16Literal = TypeU16 => ActionFn(85);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action85::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
-        (1, 75)
+        // SpannedItem* =  => ActionFn(242);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action242::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (0, 76)
     }
     fn __reduce226<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // U32Literal = TypeU32 => ActionFn(87);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SpannedItem* = SpannedItem+ => ActionFn(243);
+        let __sym0 = __pop_Variant39(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action87::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant35(__nt), __end));
+        let __nt = super::__action243::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
         (1, 76)
     }
     fn __reduce227<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // U64Literal = TypeU64 => ActionFn(89);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SpannedItem+ = SpannedItem => ActionFn(244);
+        let __sym0 = __pop_Variant38(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action89::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        let __nt = super::__action244::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
         (1, 77)
     }
     fn __reduce228<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // U8Literal = TypeU8 => ActionFn(83);
-        let __sym0 = __pop_Variant0(__symbols);
+        // SpannedItem+ = SpannedItem+, SpannedItem => ActionFn(245);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant38(__symbols);
+        let __sym0 = __pop_Variant39(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action245::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
+        (2, 77)
+    }
+    fn __reduce229<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Start =  => ActionFn(431);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action431::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
+        (0, 78)
+    }
+    fn __reduce230<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Start = SpannedItem+ => ActionFn(432);
+        let __sym0 = __pop_Variant39(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action83::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant37(__nt), __end));
+        let __nt = super::__action432::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant40(__nt), __end));
         (1, 78)
     }
-    fn __reduce229<
+    fn __reduce231<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // Unary = Postfix => ActionFn(110);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = StmtKind => ActionFn(384);
+        let __sym0 = __pop_Variant34(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action110::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action384::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant41(__nt), __end));
         (1, 79)
     }
-    fn __reduce230<
+    fn __reduce232<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // UnaryNoStruct = PostfixNoStruct => ActionFn(158);
-        let __sym0 = __pop_Variant1(__symbols);
+        // StmtKind = VarDecl => ActionFn(73);
+        let __sym0 = __pop_Variant49(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action158::<>(__sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        let __nt = super::__action73::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
         (1, 80)
     }
-    fn __reduce231<
+    fn __reduce235<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // VarDecl = Var, Ident, Assign, Expr, Semi => ActionFn(74);
-        assert!(__symbols.len() >= 5);
+        // StmtKind = If, Expr, Then, Block => ActionFn(429);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action429::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (4, 80)
+    }
+    fn __reduce236<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StmtKind = If, Expr, Then, Block, Else, Block => ActionFn(430);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant15(__symbols);
         let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant1(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym4.2;
-        let __nt = super::__action74::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (5, 81)
+        let __end = __sym5.2;
+        let __nt = super::__action430::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (6, 80)
     }
-    fn __reduce232<
+    fn __reduce237<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // VarDecl = Var, Ident, Semi => ActionFn(75);
+        // StmtKind = While, ExprNoStruct, Block => ActionFn(77);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action75::<>(__sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant38(__nt), __end));
-        (3, 81)
+        let __nt = super::__action77::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (3, 80)
     }
-    fn __reduce233<
+    fn __reduce238<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // VarDecls =  => ActionFn(72);
-        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
-        let __end = __start;
-        let __nt = super::__action72::<>(&__start, &__end);
-        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (0, 82)
+        // StmtKind = Return, Expr, Semi => ActionFn(78);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action78::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (3, 80)
     }
-    fn __reduce234<
+    fn __reduce239<
     >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
         __lookahead_start: Option<&usize>,
         __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
         _: core::marker::PhantomData<()>,
     ) -> (usize, usize)
     {
-        // VarDecls = VarDecl, VarDecls => ActionFn(73);
+        // StmtKind = Return, Semi => ActionFn(79);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant39(__symbols);
-        let __sym0 = __pop_Variant38(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action73::<>(__sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant39(__nt), __end));
-        (2, 82)
+        let __nt = super::__action79::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (2, 80)
     }
+    fn __reduce240<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StmtKind = error, Semi => ActionFn(80);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action80::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant34(__nt), __end));
+        (2, 80)
+    }
+    fn __reduce241<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StmtTail = Semi => ActionFn(83);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action83::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (1, 81)
+    }
+    fn __reduce242<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StmtTail = Assign, Expr, Semi => ActionFn(84);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action84::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant27(__nt), __end));
+        (3, 81)
+    }
+    fn __reduce243<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Stmts =  => ActionFn(70);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action70::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 82)
+    }
+    fn __reduce244<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Stmts = Stmts, Stmt => ActionFn(71);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant41(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action71::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (2, 82)
+    }
+    fn __reduce245<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StringLiteral = StrLiteral => ActionFn(92);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action92::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 83)
+    }
+    fn __reduce246<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructDef = PublicKw, Struct, Ident, LBrace, StructFieldList, RBrace => ActionFn(20);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant36(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action20::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant42(__nt), __end));
+        (6, 84)
+    }
+    fn __reduce247<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldList =  => ActionFn(21);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action21::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (0, 85)
+    }
+    fn __reduce248<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldList = StructFieldNode => ActionFn(285);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action285::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (1, 85)
+    }
+    fn __reduce249<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldList = StructFieldNode, (Comma <StructFieldNode>)+ => ActionFn(286);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action286::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant36(__nt), __end));
+        (2, 85)
+    }
+    fn __reduce250<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldNode = Ident, FieldType, FieldDefault => ActionFn(425);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action425::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 86)
+    }
+    fn __reduce251<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldNode = Ident, FieldDefault => ActionFn(426);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action426::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 86)
+    }
+    fn __reduce252<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldNode = Ident, FieldType => ActionFn(427);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant28(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action427::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 86)
+    }
+    fn __reduce253<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructFieldNode = Ident => ActionFn(428);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action428::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 86)
+    }
+    fn __reduce254<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructInitField = Ident, Assign, Expr => ActionFn(211);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action211::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant43(__nt), __end));
+        (3, 87)
+    }
+    fn __reduce255<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructInitFields =  => ActionFn(206);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action206::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (0, 88)
+    }
+    fn __reduce256<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructInitFields = StructInitField => ActionFn(207);
+        let __sym0 = __pop_Variant43(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action207::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 88)
+    }
+    fn __reduce257<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructInitFields = StructInitField, Comma, StructInitFieldsTail => ActionFn(208);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant44(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant43(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action208::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (3, 88)
+    }
+    fn __reduce258<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructInitFieldsTail = StructInitField => ActionFn(209);
+        let __sym0 = __pop_Variant43(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action209::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (1, 89)
+    }
+    fn __reduce259<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // StructInitFieldsTail = StructInitField, Comma, StructInitFieldsTail => ActionFn(210);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant44(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant43(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action210::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant44(__nt), __end));
+        (3, 89)
+    }
+    fn __reduce260<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeInt => ActionFn(46);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action46::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce261<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeFloat => ActionFn(47);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action47::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce262<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeBool => ActionFn(48);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action48::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce263<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeStr => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action49::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce264<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeUnit => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce265<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeI8 => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action51::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce266<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeU8 => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action52::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce267<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeI16 => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action53::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce268<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeU16 => ActionFn(54);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action54::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce269<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeI32 => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action55::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce270<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeU32 => ActionFn(56);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action56::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce271<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeI64 => ActionFn(57);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action57::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce272<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeU64 => ActionFn(58);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action58::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce273<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeF16 => ActionFn(59);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action59::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce274<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeF32 => ActionFn(60);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action60::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce275<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Type = TypeF64 => ActionFn(61);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action61::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant28(__nt), __end));
+        (1, 90)
+    }
+    fn __reduce276<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // U16Literal = TypeU16 => ActionFn(99);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action99::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant45(__nt), __end));
+        (1, 91)
+    }
+    fn __reduce277<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // U32Literal = TypeU32 => ActionFn(101);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action101::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant46(__nt), __end));
+        (1, 92)
+    }
+    fn __reduce278<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // U64Literal = TypeU64 => ActionFn(103);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action103::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant47(__nt), __end));
+        (1, 93)
+    }
+    fn __reduce279<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // U8Literal = TypeU8 => ActionFn(97);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action97::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant48(__nt), __end));
+        (1, 94)
+    }
+    fn __reduce280<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // Unary = Postfix => ActionFn(125);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action125::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 95)
+    }
+    fn __reduce281<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // UnaryNoStruct = PostfixNoStruct => ActionFn(175);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action175::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 96)
+    }
+    fn __reduce282<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // VarDecl = Var, Ident, Assign, Expr, Semi => ActionFn(385);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym4.2;
+        let __nt = super::__action385::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (5, 97)
+    }
+    fn __reduce283<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // VarDecl = Var, Ident, Semi => ActionFn(386);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action386::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant49(__nt), __end));
+        (3, 97)
+    }
+    fn __reduce284<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // VarDecls =  => ActionFn(85);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action85::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
+        (0, 98)
+    }
+    fn __reduce285<
+    >(
+        errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+        __lookahead_start: Option<&usize>,
+        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: core::marker::PhantomData<()>,
+    ) -> (usize, usize)
+    {
+        // VarDecls = VarDecl, VarDecls => ActionFn(86);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant50(__symbols);
+        let __sym0 = __pop_Variant49(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action86::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant50(__nt), __end));
+        (2, 98)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__Start::StartParser;
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action0<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Vec<(Item, Span)>, usize),
+) -> Vec<(Item, Span)>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action1<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, items, _): (usize, alloc::vec::Vec<(Item, Span)>, usize),
+) -> Vec<(Item, Span)>
+{
+    items
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action2<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, item, _): (usize, Item, usize),
+    (_, end, _): (usize, usize, usize),
+) -> (Item, Span)
+{
+    (item, Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action3<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Item, usize),
+) -> Item
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action4<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Item, usize),
+) -> Item
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action5<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, VarDecl, usize),
+) -> Item
+{
+    Item::VarItem(v)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action6<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, p, _): (usize, String, usize),
+) -> Item
+{
+    Item::Preprocessor(p)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action7<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Import, usize),
+) -> Item
+{
+    Item::Import(i)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action8<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, p, _): (usize, String, usize),
+) -> Item
+{
+    Item::Package(p)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action9<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Enum, usize),
+) -> Item
+{
+    Item::EnumItem(e)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action10<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, s, _): (usize, Struct, usize),
+) -> Item
+{
+    Item::StructItem(s)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action11<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, id, _): (usize, String, usize),
+) -> String
+{
+    id
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action12<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, module, _): (usize, String, usize),
+    (_, alias, _): (usize, Option<String>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Import
+{
+    Import {
+        module,
+        alias,
+        names: vec![],
+        span: Span { start, end },
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action13<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, module, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, first, _): (usize, String, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Import
+{
+    {
+        let mut names = vec![first];
+        names.extend(rest);
+        Import { module, alias: None, names, span: Span { start, end } }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action14<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, path, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+) -> String
+{
+    path
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action15<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, id, _): (usize, String, usize),
+) -> String
+{
+    id
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action16<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, path, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, id, _): (usize, String, usize),
+) -> String
+{
+    format!("{}.{}", path, id)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action17<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, is_pub, _): (usize, bool, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, variants, _): (usize, Vec<String>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Enum
+{
+    {
+        Enum {
+            ident: name,
+            variants,
+            is_public: is_pub,
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action18<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action19<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, String, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<String>, usize),
+) -> Vec<String>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action20<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, is_pub, _): (usize, bool, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, fields, _): (usize, Vec<StructField>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Struct
+{
+    {
+        Struct {
+            ident: name,
+            fields,
+            is_public: is_pub,
+            _methods: vec![],
+        }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action21<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<StructField>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action22<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, StructField, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<StructField>, usize),
+) -> Vec<StructField>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action23<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, id, _): (usize, String, usize),
+    (_, ty, _): (usize, Option<Type>, usize),
+    (_, def, _): (usize, Option<Expr>, usize),
+) -> StructField
+{
+    StructField {
+        ident: id,
+        ty,
+        default_value: def,
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action24<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action25<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, String, usize),
+    (_, rest, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action26<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action27<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, tail, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    tail
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action28<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action29<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, String, usize),
+    (_, rest, _): (usize, Vec<String>, usize),
+) -> Vec<String>
+{
+    {
+        let mut v = vec![v];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action30<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<StructField>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action31<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, p, _): (usize, StructField, usize),
+    (_, mut rest, _): (usize, Vec<StructField>, usize),
+) -> Vec<StructField>
+{
+    {
+        let mut v = vec![p];
+        v.append(&mut rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action32<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, t, _): (usize, Type, usize),
+) -> Type
+{
+    t
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action33<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+) -> Expr
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action34<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+)
+{
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action35<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> bool
+{
+    true
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action36<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> bool
+{
+    false
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action37<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, is_pub, _): (usize, bool, usize),
+    (_, _, _): (usize, (), usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, params, _): (usize, Vec<FunctionParam>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+    (_, ret, _): (usize, Option<Type>, usize),
+    (_, body, _): (usize, Vec<Stmt>, usize),
+) -> Item
+{
+    {
+        Item::FunctionItem(Function {
+            ident: name,
+            params,
+            ret_ty: ret,
+            blk: body,
+            is_public: is_pub,
+            span: Span { start, end },
+        })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action38<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<FunctionParam>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action39<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, FunctionParam, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+) -> Vec<FunctionParam>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action40<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, end, _): (usize, usize, usize),
+) -> FunctionParam
+{
+    FunctionParam { ident: id, span: Span { start, end } }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action41<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, id, _): (usize, String, usize),
+) -> Result<(),__lalrpop_util::ParseError<usize,Token,String>>
+{
+    if id == "extern" {
+        Ok(())
+    } else {
+        Err(ParseError::User { error: format!("expected an item, found `{}`", id) })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action42<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, (), usize),
+    (_, _, _): (usize, (), usize),
+    (_, name, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, params, _): (usize, Vec<ExternParam>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+    (_, ret, _): (usize, Option<Type>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Item
+{
+    {
+        Item::ExternFnItem(ExternFunction {
+            ident: name,
+            params,
+            ret_ty: ret,
+            span: Span { start, end },
+        })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action43<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<ExternParam>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action44<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, ExternParam, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<ExternParam>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+) -> Vec<ExternParam>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action45<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, ty, _): (usize, Type, usize),
+    (_, end, _): (usize, usize, usize),
+) -> ExternParam
+{
+    ExternParam { ident: id, ty, span: Span { start, end } }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action46<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action47<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::Float
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action48<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::Bool
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action49<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::Str
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action50<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::Unit
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action51<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeI8
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action52<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeU8
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action53<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeI16
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action54<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeU16
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action55<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeI32
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action56<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeU32
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action57<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeI64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action58<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeU64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action59<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeF16
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action60<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeF32
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action61<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Type
+{
+    Type::TypeF64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action62<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, t, _): (usize, Type, usize),
+) -> Option<Type>
+{
+    Some(t)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action63<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<Type>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action64<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Expr>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action65<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+) -> Vec<Expr>
+{
+    vec![e]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action66<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, rest, _): (usize, Vec<Expr>, usize),
+) -> Vec<Expr>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action67<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+) -> Vec<Expr>
+{
+    vec![e]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action68<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, rest, _): (usize, Vec<Expr>, usize),
+) -> Vec<Expr>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action69<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, stmts, _): (usize, Vec<Stmt>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Vec<Stmt>
+{
+    stmts
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action70<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<Stmt>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action71<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, mut stmts, _): (usize, Vec<Stmt>, usize),
+    (_, s, _): (usize, Stmt, usize),
+) -> Vec<Stmt>
+{
+    { stmts.push(s); stmts }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action72<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, kind, _): (usize, StmtKind, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Stmt
+{
+    Stmt { kind, span: Span { start, end } }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action73<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, VarDecl, usize),
+) -> StmtKind
+{
+    StmtKind::Var(v)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action74<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+    (_, tail, _): (usize, Option<Expr>, usize),
+) -> Result<StmtKind,__lalrpop_util::ParseError<usize,Token,String>>
+{
+    {
+        match tail {
+            None => Ok(StmtKind::Expr(e)),
+            Some(val) => {
+                if let Expr::Var(id) = e {
+                    Ok(StmtKind::Assign(AssignStmt { name: id, expr: val }))
+                } else {
+                    Err(ParseError::User { error: "Invalid assignment target".to_string() })
+                }
+            }
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action75<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, String, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, first_e, _): (usize, Expr, usize),
+    (_, rest_e, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Result<StmtKind,__lalrpop_util::ParseError<usize,Token,String>>
+{
+    {
+        let mut names = vec![first];
+        names.extend(rest);
+        let mut exprs = vec![first_e];
+        exprs.extend(rest_e);
+
+        if names.len() != exprs.len() {
+            return Err(ParseError::User {
+                error: format!(
+                    "Assignment count mismatch: {} targets but {} values",
+                    names.len(),
+                    exprs.len()
+                ),
+            });
+        }
+
+        Ok(StmtKind::MultiAssign(
+            names
+                .into_iter()
+                .zip(exprs)
+                .map(|(name, expr)| AssignStmt { name, expr })
+                .collect(),
+        ))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action76<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, StmtKind, usize),
+) -> StmtKind
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action77<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, c, _): (usize, Expr, usize),
+    (_, body, _): (usize, Vec<Stmt>, usize),
+) -> StmtKind
+{
+    StmtKind::While {
+            cond: c,
+            body: body,
+        }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action78<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> StmtKind
+{
+    StmtKind::Return(Some(e))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action79<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+    (_, __1, _): (usize, Token, usize),
+) -> StmtKind
+{
+    StmtKind::Return(None)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action80<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, __lalrpop_util::ErrorRecovery<usize, Token, String>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> StmtKind
+{
+    {
+        errors.push(e);
+        StmtKind::Expr(Expr::Unit())
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action81<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, c, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, then, _): (usize, Vec<Stmt>, usize),
+) -> StmtKind
+{
+    StmtKind::If {
+        cond: c,
+        then_blk: then,
+        else_blk: None,
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action82<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, c, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, then, _): (usize, Vec<Stmt>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, else_blk, _): (usize, Vec<Stmt>, usize),
+) -> StmtKind
+{
+    StmtKind::If {
+        cond: c,
+        then_blk: then,
+        else_blk: Some(else_blk),
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action83<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Option<Expr>
+{
+    None
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action84<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Option<Expr>
+{
+    Some(e)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action85<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<VarDecl>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action86<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, VarDecl, usize),
+    (_, mut rest, _): (usize, Vec<VarDecl>, usize),
+) -> Vec<VarDecl>
+{
+    { rest.insert(0, v); rest }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action87<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, end, _): (usize, usize, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> VarDecl
+{
+    VarDecl { ident: id, expr: Some(e), ident_span: Span { start, end } }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action88<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, end, _): (usize, usize, usize),
+    (_, _, _): (usize, Token, usize),
+) -> VarDecl
+{
+    VarDecl { ident: id, expr: None, ident_span: Span { start, end } }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action89<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> String
+{
+    {
+    match tok { Token::Ident(s) => s, _ => unreachable!() }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action90<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> i64
+{
+    {
+    match tok { Token::Num(n) => n, _ => unreachable!() }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action91<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> f64
+{
+    {
+    match tok { Token::Float(f) => f, _ => unreachable!() }
+}
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action92<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, s, _): (usize, Token, usize),
+) -> String
+{
+    {
+        match s {
+            Token::StrLiteral(str) => str,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action93<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, c, _): (usize, Token, usize),
+) -> char
+{
+    {
+        match c {
+            Token::CharLiteral(c) => c,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action94<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> bool
+{
+    true
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action95<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> bool
+{
+    false
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action96<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> i8
+{
+    {
+        match tok {
+            Token::TypeI8 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action97<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> u8
+{
+    {
+        match tok {
+            Token::TypeU8 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action98<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> i16
+{
+    {
+        match tok {
+            Token::TypeI16 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action99<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> u16
+{
+    {
+        match tok {
+            Token::TypeU16 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action100<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> i32
+{
+    {
+        match tok {
+            Token::TypeI32 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action101<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> u32
+{
+    {
+        match tok {
+            Token::TypeU32 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action102<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> i64
+{
+    {
+        match tok {
+            Token::TypeI64 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action103<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> u64
+{
+    {
+        match tok {
+            Token::TypeU64 => 0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action104<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> f16
+{
+    {
+        match tok {
+            Token::TypeF16 => f16::from_f64(0.0), // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action105<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> f32
+{
+    {
+        match tok {
+            Token::TypeF32 => 0.0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action106<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, tok, _): (usize, Token, usize),
+) -> f64
+{
+    {
+        match tok {
+            Token::TypeF64 => 0.0, // Placeholder value
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action107<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Expr, usize),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action108<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+) -> Expr
+{
+    Expr::Range(Box::new(l), Box::new(r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action109<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+) -> Expr
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action110<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Eq(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action111<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Neq(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action112<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Lt(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action113<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Gt(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action114<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Le(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action115<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Ge(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action116<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::In(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action117<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, a, _): (usize, Expr, usize),
+) -> Expr
+{
+    a
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action118<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Add(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action119<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Minus(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action120<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, m, _): (usize, Expr, usize),
+) -> Expr
+{
+    m
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action121<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Mul(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action122<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Div(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action123<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Mod(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action124<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Expr, usize),
+) -> Expr
+{
+    u
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action125<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, p, _): (usize, Expr, usize),
+) -> Expr
+{
+    p
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action126<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, base, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, index, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Index(Box::new(base), Box::new(index), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action127<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::Increment(Box::new(e))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action128<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::Decrement(Box::new(e))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action129<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, base, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, field, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    {
+        if let Expr::Var(mod_name) = base {
+            Expr::ModuleAccess(mod_name, field, vec![])
+        } else {
+            Expr::Call(field, vec![], None, Span { start, end })
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action130<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, base, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, field, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, first, _): (usize, Expr, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    {
+        let mut args = vec![first];
+        args.extend(rest);
+        if let Expr::Var(mod_name) = base {
+            Expr::ModuleAccess(mod_name, field, args)
+        } else {
+            Expr::Call(field, args, None, Span { start, end })
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action131<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, base, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, field, _): (usize, String, usize),
+) -> Expr
+{
+    {
+        if let Expr::Var(val) = base {
+            Expr::FieldAccess(Box::new(Expr::Var(val)), field)
+        } else {
+            Expr::FieldAccess(Box::new(base), field)
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action132<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Expr, usize),
+) -> Expr
+{
+    f
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action133<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Call(id, vec![], None, Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action134<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, first, _): (usize, Expr, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    {
+        let mut args = vec![first];
+        args.extend(rest);
+        Expr::Call(id, args, None, Span { start, end })
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action135<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, fields, _): (usize, Vec<(String, Expr)>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
+{
+    {
+        Expr::StructInit(id, fields)
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action136<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, id, _): (usize, String, usize),
+) -> Expr
+{
+    Expr::Var(id)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action137<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, n, _): (usize, i64, usize),
+) -> Expr
+{
+    Expr::Number(n)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action138<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, f64, usize),
+) -> Expr
+{
+    Expr::Float(f)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action139<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, s, _): (usize, String, usize),
+) -> Expr
+{
+    Expr::Str(s)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action140<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, c, _): (usize, char, usize),
+) -> Expr
+{
+    Expr::Char(c)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action141<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, b, _): (usize, bool, usize),
+) -> Expr
+{
+    Expr::Bool(b)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action142<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeI8
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action143<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeU8
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action144<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeI16
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action145<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeU16
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action146<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeI32
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action147<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeU32
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action148<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeI64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action149<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeU64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action150<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeF16
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action151<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeF32
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action152<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::TypeF64
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action153<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+    (_, __1, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::Unit()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action154<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action155<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+    (_, __1, _): (usize, Token, usize),
+) -> Expr
+{
+    Expr::List(vec![])
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action156<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, first, _): (usize, Expr, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        Expr::List(v)
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action157<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Expr, usize),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action158<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+) -> Expr
+{
+    Expr::Range(Box::new(l), Box::new(r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action159<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+) -> Expr
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action160<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Eq(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action161<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Neq(Box::new(l), Box::new(r), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action162<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Lt(Box::new(l), Box::new(r), Span { start, end })
 }
-#[allow(unused_imports)]
-pub use self::__parse__Start::StartParser;
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action0<
+fn __action163<
 >(
-    (_, __0, _): (usize, Vec<Item>, usize),
-) -> Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    __0
+    Expr::Gt(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action1<
+fn __action164<
 >(
-    (_, items, _): (usize, alloc::vec::Vec<Item>, usize),
-) -> Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    items
+    Expr::Le(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action2<
+fn __action165<
 >(
-    (_, __0, _): (usize, Item, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    __0
+    Expr::Ge(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action3<
+fn __action166<
 >(
-    (_, v, _): (usize, VarDecl, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    Item::VarItem(v)
+    Expr::In(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action4<
+fn __action167<
 >(
-    (_, p, _): (usize, String, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, a, _): (usize, Expr, usize),
+) -> Expr
 {
-    Item::Preprocessor(p)
+    a
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action5<
+fn __action168<
 >(
-    (_, i, _): (usize, String, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    Item::Import(i)
+    Expr::Add(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action6<
+fn __action169<
 >(
-    (_, p, _): (usize, String, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    Item::Package(p)
+    Expr::Minus(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action7<
+fn __action170<
 >(
-    (_, e, _): (usize, Enum, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, m, _): (usize, Expr, usize),
+) -> Expr
 {
-    Item::EnumItem(e)
+    m
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action8<
+fn __action171<
 >(
-    (_, s, _): (usize, Struct, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    Item::StructItem(s)
+    Expr::Mul(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action9<
+fn __action172<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
     (_, _, _): (usize, Token, usize),
-    (_, id, _): (usize, String, usize),
-) -> String
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    id
+    Expr::Div(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action10<
+fn __action173<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, l, _): (usize, Expr, usize),
     (_, _, _): (usize, Token, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-) -> String
+    (_, r, _): (usize, Expr, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    id
+    Expr::Mod(Box::new(l), Box::new(r), Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action11<
+fn __action174<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-) -> String
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Expr, usize),
+) -> Expr
 {
-    id
+    u
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action12<
+fn __action175<
 >(
-    (_, is_pub, _): (usize, bool, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, p, _): (usize, Expr, usize),
+) -> Expr
+{
+    p
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action176<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, base, _): (usize, Expr, usize),
     (_, _, _): (usize, Token, usize),
-    (_, name, _): (usize, String, usize),
+    (_, index, _): (usize, Expr, usize),
     (_, _, _): (usize, Token, usize),
-    (_, variants, _): (usize, Vec<String>, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
+{
+    Expr::Index(Box::new(base), Box::new(index), Span { start, end })
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action177<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
     (_, _, _): (usize, Token, usize),
-) -> Enum
+) -> Expr
 {
-    {
-        Enum {
-            ident: name,
-            variants,
-            is_public: is_pub,
-        }
-    }
+    Expr::Increment(Box::new(e))
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action13<
+fn __action178<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
 {
-    vec![]
+    Expr::Decrement(Box::new(e))
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action14<
+fn __action179<
 >(
-    (_, first, _): (usize, String, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<String>, usize),
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, base, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, field, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
     {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
+        if let Expr::Var(mod_name) = base {
+            Expr::ModuleAccess(mod_name, field, vec![])
+        } else {
+            Expr::Call(field, vec![], None, Span { start, end })
+        }
     }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action15<
+fn __action180<
 >(
-    (_, is_pub, _): (usize, bool, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, base, _): (usize, Expr, usize),
     (_, _, _): (usize, Token, usize),
-    (_, name, _): (usize, String, usize),
+    (_, field, _): (usize, String, usize),
     (_, _, _): (usize, Token, usize),
-    (_, fields, _): (usize, Vec<StructField>, usize),
+    (_, first, _): (usize, Expr, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
     (_, _, _): (usize, Token, usize),
-) -> Struct
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
     {
-        Struct {
-            ident: name,
-            fields,
-            is_public: is_pub,
-            _methods: vec![],
+        let mut args = vec![first];
+        args.extend(rest);
+        if let Expr::Var(mod_name) = base {
+            Expr::ModuleAccess(mod_name, field, args)
+        } else {
+            Expr::Call(field, args, None, Span { start, end })
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action181<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, base, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, field, _): (usize, String, usize),
+) -> Expr
+{
+    {
+        if let Expr::Var(val) = base {
+            Expr::FieldAccess(Box::new(Expr::Var(val)), field)
+        } else {
+            Expr::FieldAccess(Box::new(base), field)
         }
+    }
 }
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action182<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Expr, usize),
+) -> Expr
+{
+    f
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action16<
+fn __action183<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
-    vec![]
+    Expr::Call(id, vec![], None, Span { start, end })
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action17<
+fn __action184<
 >(
-    (_, first, _): (usize, StructField, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<StructField>, usize),
-) -> Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, start, _): (usize, usize, usize),
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, first, _): (usize, Expr, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expr
 {
     {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
+        let mut args = vec![first];
+        args.extend(rest);
+        Expr::Call(id, args, None, Span { start, end })
     }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action18<
+fn __action185<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     (_, id, _): (usize, String, usize),
-    (_, ty, _): (usize, Option<Type>, usize),
-    (_, def, _): (usize, Option<Expr>, usize),
-) -> StructField
+) -> Expr
+{
+    Expr::Var(id)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action186<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, n, _): (usize, i64, usize),
+) -> Expr
 {
-    StructField {
-        ident: id,
-        ty,
-        default_value: def,
-    }
+    Expr::Number(n)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action19<
+fn __action187<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, f64, usize),
+) -> Expr
 {
-    vec![]
+    Expr::Float(f)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action20<
+fn __action188<
 >(
-    (_, first, _): (usize, String, usize),
-    (_, rest, _): (usize, Vec<String>, usize),
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, s, _): (usize, String, usize),
+) -> Expr
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
-    }
+    Expr::Str(s)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action21<
+fn __action189<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, c, _): (usize, char, usize),
+) -> Expr
 {
-    vec![]
+    Expr::Char(c)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action22<
+fn __action190<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, tail, _): (usize, Vec<String>, usize),
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, b, _): (usize, bool, usize),
+) -> Expr
 {
-    tail
+    Expr::Bool(b)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action23<
+fn __action191<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
 {
-    vec![]
+    Expr::TypeI8
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action24<
+fn __action192<
 >(
-    (_, v, _): (usize, String, usize),
-    (_, rest, _): (usize, Vec<String>, usize),
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
 {
-    {
-        let mut v = vec![v];
-        v.extend(rest);
-        v
-    }
+    Expr::TypeU8
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action25<
+fn __action193<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
 {
-    vec![]
+    Expr::TypeI16
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action26<
+fn __action194<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, p, _): (usize, StructField, usize),
-    (_, mut rest, _): (usize, Vec<StructField>, usize),
-) -> Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
 {
-    {
-        let mut v = vec![p];
-        v.append(&mut rest);
-        v
-    }
+    Expr::TypeU16
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action27<
+fn __action195<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, t, _): (usize, Type, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
 {
-    t
+    Expr::TypeI32
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action28<
+fn __action196<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
 ) -> Expr
 {
-    e
+    Expr::TypeU32
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action29<
+fn __action197<
 >(
-    (_, tok, _): (usize, Token, usize),
-)
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, i, _): (usize, Token, usize),
+) -> Expr
 {
+    Expr::TypeI64
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action30<
+fn __action198<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> bool
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, u, _): (usize, Token, usize),
+) -> Expr
 {
-    true
+    Expr::TypeU64
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action31<
+fn __action199<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> bool
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Token, usize),
+) -> Expr
 {
-    false
+    Expr::TypeF16
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action32<
+fn __action200<
 >(
-    (_, is_pub, _): (usize, bool, usize),
-    (_, _, _): (usize, (), usize),
-    (_, name, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, params, _): (usize, Vec<FunctionParam>, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, ret, _): (usize, Option<Type>, usize),
-    (_, body, _): (usize, Vec<Stmt>, usize),
-) -> Item
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Token, usize),
+) -> Expr
 {
-    {
-        Item::FunctionItem(Function {
-            ident: name,
-            params,
-            ret_ty: ret,
-            blk: body,
-            is_public: is_pub,
-        })
-    }
+    Expr::TypeF32
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action33<
+fn __action201<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<FunctionParam>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, Token, usize),
+) -> Expr
 {
-    vec![]
+    Expr::TypeF64
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action34<
+fn __action202<
 >(
-    (_, first, _): (usize, FunctionParam, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
-) -> Vec<FunctionParam>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+    (_, __1, _): (usize, Token, usize),
+) -> Expr
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
-    }
+    Expr::Unit()
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action35<
+fn __action203<
 >(
-    (_, id, _): (usize, String, usize),
-) -> FunctionParam
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
 {
-    FunctionParam { ident: id }
+    e
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action36<
+fn __action204<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     (_, __0, _): (usize, Token, usize),
-) -> Type
+    (_, __1, _): (usize, Token, usize),
+) -> Expr
 {
-    Type::Int
+    Expr::List(vec![])
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action37<
+fn __action205<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, first, _): (usize, Expr, usize),
+    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, _, _): (usize, Option<Token>, usize),
+    (_, _, _): (usize, Token, usize),
+) -> Expr
 {
-    Type::Float
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        Expr::List(v)
+    }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action38<
+fn __action206<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Vec<(String, Expr)>
 {
-    Type::Bool
+    vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action39<
+fn __action207<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, (String, Expr), usize),
+) -> Vec<(String, Expr)>
 {
-    Type::Str
+    vec![f]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action40<
+fn __action208<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, (String, Expr), usize),
+    (_, _, _): (usize, Token, usize),
+    (_, rest, _): (usize, Vec<(String, Expr)>, usize),
+) -> Vec<(String, Expr)>
 {
-    Type::Unit
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action41<
+fn __action209<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, f, _): (usize, (String, Expr), usize),
+) -> Vec<(String, Expr)>
 {
-    Type::TypeI8
+    vec![f]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action210<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, first, _): (usize, (String, Expr), usize),
+    (_, _, _): (usize, Token, usize),
+    (_, rest, _): (usize, Vec<(String, Expr)>, usize),
+) -> Vec<(String, Expr)>
+{
+    {
+        let mut v = vec![first];
+        v.extend(rest);
+        v
+    }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action42<
+fn __action211<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, id, _): (usize, String, usize),
+    (_, _, _): (usize, Token, usize),
+    (_, e, _): (usize, Expr, usize),
+) -> (String, Expr)
 {
-    Type::TypeU8
+    (id, e)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action43<
+fn __action212<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<Expr>
 {
-    Type::TypeI16
+    alloc::vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action44<
+fn __action213<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<Expr>, usize),
+) -> alloc::vec::Vec<Expr>
 {
-    Type::TypeU16
+    v
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action45<
+fn __action214<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Expr, usize),
+) -> alloc::vec::Vec<Expr>
 {
-    Type::TypeI32
+    alloc::vec![__0]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action46<
+fn __action215<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<Expr>, usize),
+    (_, e, _): (usize, Expr, usize),
+) -> alloc::vec::Vec<Expr>
 {
-    Type::TypeU32
+    { let mut v = v; v.push(e); v }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action47<
+fn __action216<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, __0, _): (usize, Expr, usize),
+) -> Expr
 {
-    Type::TypeI64
+    __0
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action48<
+fn __action217<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Type::TypeU64
+    alloc::vec![__0]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action49<
+fn __action218<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Type::TypeF16
+    { let mut v = v; v.push(e); v }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action50<
+fn __action219<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<ExternParam>
 {
-    Type::TypeF32
+    alloc::vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action51<
+fn __action220<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Type
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<ExternParam>, usize),
+) -> alloc::vec::Vec<ExternParam>
 {
-    Type::TypeF64
+    v
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action52<
+fn __action221<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     (_, _, _): (usize, Token, usize),
-    (_, t, _): (usize, Type, usize),
-) -> Option<Type>
+    (_, __0, _): (usize, ExternParam, usize),
+) -> ExternParam
 {
-    Some(t)
+    __0
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action53<
+fn __action222<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Option<Type>
+) -> alloc::vec::Vec<FunctionParam>
 {
-    None
+    alloc::vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action54<
+fn __action223<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    vec![]
+    v
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action55<
+fn __action224<
 >(
-    (_, e, _): (usize, Expr, usize),
-) -> Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, __0, _): (usize, FunctionParam, usize),
+) -> FunctionParam
 {
-    vec![e]
+    __0
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action56<
+fn __action225<
 >(
-    (_, first, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, rest, _): (usize, Vec<Expr>, usize),
-) -> Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Expr, usize),
+) -> Option<Expr>
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
-    }
+    Some(__0)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action57<
+fn __action226<
 >(
-    (_, e, _): (usize, Expr, usize),
-) -> Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<Expr>
 {
-    vec![e]
+    None
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action58<
+fn __action227<
 >(
-    (_, first, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, rest, _): (usize, Vec<Expr>, usize),
-) -> Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Type, usize),
+) -> Option<Type>
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
-    }
+    Some(__0)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action59<
+fn __action228<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, stmts, _): (usize, Vec<Stmt>, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Vec<Stmt>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<Type>
 {
-    stmts
+    None
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action60<
+fn __action229<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<Stmt>
+) -> alloc::vec::Vec<StructField>
 {
-    vec![]
+    alloc::vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action61<
+fn __action230<
 >(
-    (_, mut stmts, _): (usize, Vec<Stmt>, usize),
-    (_, s, _): (usize, Stmt, usize),
-) -> Vec<Stmt>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<StructField>, usize),
+) -> alloc::vec::Vec<StructField>
 {
-    { stmts.push(s); stmts }
+    v
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action62<
+fn __action231<
 >(
-    (_, v, _): (usize, VarDecl, usize),
-) -> Stmt
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, _, _): (usize, Token, usize),
+    (_, __0, _): (usize, StructField, usize),
+) -> StructField
 {
-    Stmt::Var(v)
+    __0
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action63<
+fn __action232<
 >(
-    (_, e, _): (usize, Expr, usize),
-    (_, tail, _): (usize, Option<Expr>, usize),
-) -> Result<Stmt,__lalrpop_util::ParseError<usize,Token,String>>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, Token, usize),
+) -> Option<Token>
 {
-    {
-        match tail {
-            None => Ok(Stmt::Expr(e)),
-            Some(val) => {
-                if let Expr::Var(id) = e {
-                    Ok(Stmt::Assign(AssignStmt { name: id, expr: val }))
-                } else {
-                    Err(ParseError::User { error: "Invalid assignment target".to_string() })
-                }
-            }
-        }
-    }
+    Some(__0)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action64<
+fn __action233<
 >(
-    (_, __0, _): (usize, Stmt, usize),
-) -> Stmt
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<Token>
 {
-    __0
+    None
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action65<
+fn __action234<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, c, _): (usize, Expr, usize),
-    (_, body, _): (usize, Vec<Stmt>, usize),
-) -> Stmt
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> alloc::vec::Vec<String>
 {
-    Stmt::While {
-            cond: c,
-            body: body,
-        }
+    alloc::vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action66<
+fn __action235<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
+) -> alloc::vec::Vec<String>
+{
+    v
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action236<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Stmt
+    (_, __0, _): (usize, String, usize),
+) -> String
 {
-    Stmt::Return(Some(e))
+    __0
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action67<
+fn __action237<
 >(
-    (_, __0, _): (usize, Token, usize),
-    (_, __1, _): (usize, Token, usize),
-) -> Stmt
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, String, usize),
+) -> Option<String>
 {
-    Stmt::Return(None)
+    Some(__0)
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action68<
+fn __action238<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, c, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, then, _): (usize, Vec<Stmt>, usize),
-) -> Stmt
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Option<String>
 {
-    Stmt::If {
-        cond: c,
-        then_blk: then,
-        else_blk: None,
-    }
+    None
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action69<
+fn __action239<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     (_, _, _): (usize, Token, usize),
-    (_, c, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, then, _): (usize, Vec<Stmt>, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, else_blk, _): (usize, Vec<Stmt>, usize),
-) -> Stmt
+    (_, __0, _): (usize, String, usize),
+) -> String
 {
-    Stmt::If {
-        cond: c,
-        then_blk: then,
-        else_blk: Some(else_blk),
-    }
+    __0
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action70<
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action240<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> Option<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    None
+    *__lookbehind
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action71<
+#[allow(unused_variables)]
+#[allow(clippy::needless_lifetimes)]
+fn __action241<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Option<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
 {
-    Some(e)
+    *__lookahead
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action72<
+fn __action242<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<VarDecl>
+) -> alloc::vec::Vec<(Item, Span)>
 {
-    vec![]
+    alloc::vec![]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action73<
+fn __action243<
 >(
-    (_, v, _): (usize, VarDecl, usize),
-    (_, mut rest, _): (usize, Vec<VarDecl>, usize),
-) -> Vec<VarDecl>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<(Item, Span)>, usize),
+) -> alloc::vec::Vec<(Item, Span)>
 {
-    { rest.insert(0, v); rest }
+    v
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action74<
+fn __action244<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-) -> VarDecl
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, (Item, Span), usize),
+) -> alloc::vec::Vec<(Item, Span)>
 {
-    VarDecl { ident: id, expr: Some(e) }
+    alloc::vec![__0]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action75<
+fn __action245<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-) -> VarDecl
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<(Item, Span)>, usize),
+    (_, e, _): (usize, (Item, Span), usize),
+) -> alloc::vec::Vec<(Item, Span)>
 {
-    VarDecl { ident: id, expr: None }
+    { let mut v = v; v.push(e); v }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action76<
+fn __action246<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> String
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, StructField, usize),
+) -> alloc::vec::Vec<StructField>
 {
-    {
-    match tok { Token::Ident(s) => s, _ => unreachable!() }
-}
+    alloc::vec![__0]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action77<
+fn __action247<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> i64
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<StructField>, usize),
+    (_, e, _): (usize, StructField, usize),
+) -> alloc::vec::Vec<StructField>
 {
-    {
-    match tok { Token::Num(n) => n, _ => unreachable!() }
-}
+    { let mut v = v; v.push(e); v }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action78<
+fn __action248<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> f64
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, FunctionParam, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    {
-    match tok { Token::Float(f) => f, _ => unreachable!() }
-}
+    alloc::vec![__0]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action79<
+fn __action249<
 >(
-    (_, s, _): (usize, Token, usize),
-) -> String
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
+    (_, e, _): (usize, FunctionParam, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    {
-        match s {
-            Token::StrLiteral(str) => str,
-            _ => unreachable!(),
-        }
-    }
+    { let mut v = v; v.push(e); v }
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action80<
+fn __action250<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> bool
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, __0, _): (usize, ExternParam, usize),
+) -> alloc::vec::Vec<ExternParam>
 {
-    true
+    alloc::vec![__0]
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action81<
+fn __action251<
 >(
-    (_, __0, _): (usize, Token, usize),
-) -> bool
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    (_, v, _): (usize, alloc::vec::Vec<ExternParam>, usize),
+    (_, e, _): (usize, ExternParam, usize),
+) -> alloc::vec::Vec<ExternParam>
 {
-    false
+    { let mut v = v; v.push(e); v }
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action82<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action252<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> i8
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+) -> Option<String>
 {
-    {
-        match tok {
-            Token::TypeI8 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action239(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action237(
+        errors,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action83<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action253<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> u8
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
+) -> Import
 {
-    {
-        match tok {
-            Token::TypeU8 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __3.0;
+    let __end0 = __4.2;
+    let __temp0 = __action252(
+        errors,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+        __6,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action84<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action254<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> i16
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, usize, usize),
+) -> Import
 {
-    {
-        match tok {
-            Token::TypeI16 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action238(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action85<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action255<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> u16
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+) -> alloc::vec::Vec<Expr>
 {
-    {
-        match tok {
-            Token::TypeU16 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action216(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        errors,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action86<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action256<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, alloc::vec::Vec<Expr>, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> alloc::vec::Vec<Expr>
+{
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action216(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action215(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action257<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> i32
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Expr, usize),
+    __4: (usize, Option<Token>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeI32 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __3.2;
+    let __end0 = __4.0;
+    let __temp0 = __action212(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action87<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action258<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> u32
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Expr, usize),
+    __4: (usize, alloc::vec::Vec<Expr>, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, usize, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeU32 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __4.0;
+    let __end0 = __4.2;
+    let __temp0 = __action213(
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action88<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action259<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> i64
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Option<Token>, usize),
+    __3: (usize, Token, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeI64 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action212(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action89<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action260<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> u64
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __3: (usize, Option<Token>, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeU64 => 0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action213(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action90<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action261<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> f16
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Expr, usize),
+    __4: (usize, Option<Token>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeF16 => f16::from_f64(0.0), // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __3.2;
+    let __end0 = __4.0;
+    let __temp0 = __action212(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action184(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action91<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action262<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> f32
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Expr, usize),
+    __4: (usize, alloc::vec::Vec<Expr>, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, usize, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeF32 => 0.0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __4.0;
+    let __end0 = __4.2;
+    let __temp0 = __action213(
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action184(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action92<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action263<
 >(
-    (_, tok, _): (usize, Token, usize),
-) -> f64
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Option<Token>, usize),
+    __3: (usize, Token, usize),
+) -> Expr
 {
-    {
-        match tok {
-            Token::TypeF64 => 0.0, // Placeholder value
-            _ => unreachable!(),
-        }
-    }
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action212(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action205(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action93<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action264<
 >(
-    (_, __0, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __3: (usize, Option<Token>, usize),
+    __4: (usize, Token, usize),
 ) -> Expr
 {
-    __0
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action213(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action205(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action94<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action265<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, String, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, Expr, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Range(Box::new(l), Box::new(r))
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action212(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action95<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action266<
 >(
-    (_, e, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, String, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, Expr, usize),
+    __6: (usize, alloc::vec::Vec<Expr>, usize),
+    __7: (usize, Option<Token>, usize),
+    __8: (usize, Token, usize),
+    __9: (usize, usize, usize),
 ) -> Expr
 {
-    e
+    let __start0 = __6.0;
+    let __end0 = __6.2;
+    let __temp0 = __action213(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+        __8,
+        __9,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action96<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action267<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, String, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, Expr, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Eq(Box::new(l), Box::new(r))
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action212(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action97<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action268<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, String, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, Expr, usize),
+    __6: (usize, alloc::vec::Vec<Expr>, usize),
+    __7: (usize, Option<Token>, usize),
+    __8: (usize, Token, usize),
+    __9: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Neq(Box::new(l), Box::new(r))
+    let __start0 = __6.0;
+    let __end0 = __6.2;
+    let __temp0 = __action213(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+        __8,
+        __9,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action98<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action269<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, ExternParam, usize),
+) -> alloc::vec::Vec<ExternParam>
 {
-    Expr::Lt(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action221(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action250(
+        errors,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action99<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action270<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, alloc::vec::Vec<ExternParam>, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, ExternParam, usize),
+) -> alloc::vec::Vec<ExternParam>
 {
-    Expr::Gt(Box::new(l), Box::new(r))
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action221(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action251(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action100<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action271<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, ExternParam, usize),
+    __1: (usize, Option<Token>, usize),
+) -> Vec<ExternParam>
 {
-    Expr::Le(Box::new(l), Box::new(r))
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action219(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action101<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action272<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, ExternParam, usize),
+    __1: (usize, alloc::vec::Vec<ExternParam>, usize),
+    __2: (usize, Option<Token>, usize),
+) -> Vec<ExternParam>
 {
-    Expr::Ge(Box::new(l), Box::new(r))
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action220(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        errors,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action102<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action273<
 >(
-    (_, a, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, FunctionParam, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    a
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action224(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action248(
+        errors,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action103<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action274<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, FunctionParam, usize),
+) -> alloc::vec::Vec<FunctionParam>
 {
-    Expr::Add(Box::new(l), Box::new(r))
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action224(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action249(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action104<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action275<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, FunctionParam, usize),
+    __1: (usize, Option<Token>, usize),
+) -> Vec<FunctionParam>
 {
-    Expr::Minus(Box::new(l), Box::new(r))
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action222(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action105<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action276<
 >(
-    (_, m, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, FunctionParam, usize),
+    __1: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    __2: (usize, Option<Token>, usize),
+) -> Vec<FunctionParam>
 {
-    m
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action223(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        errors,
+        __0,
+        __temp0,
+        __2,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action106<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action277<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Expr::Mul(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action236(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action217(
+        errors,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action107<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action278<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, alloc::vec::Vec<String>, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+) -> alloc::vec::Vec<String>
 {
-    Expr::Div(Box::new(l), Box::new(r))
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action236(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action218(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action108<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action279<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+) -> Vec<String>
 {
-    Expr::Mod(Box::new(l), Box::new(r))
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action234(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action109<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action280<
 >(
-    (_, u, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, alloc::vec::Vec<String>, usize),
+) -> Vec<String>
 {
-    u
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action235(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action110<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action281<
 >(
-    (_, p, _): (usize, Expr, usize),
-) -> Expr
-{
-    p
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, String, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, Token, usize),
+    __9: (usize, usize, usize),
+) -> Import
+{
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action234(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action111<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action282<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, index, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
-{
-    Expr::Index(Box::new(base), Box::new(index))
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, usize, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, String, usize),
+    __6: (usize, alloc::vec::Vec<String>, usize),
+    __7: (usize, Option<Token>, usize),
+    __8: (usize, Token, usize),
+    __9: (usize, Token, usize),
+    __10: (usize, usize, usize),
+) -> Import
+{
+    let __start0 = __6.0;
+    let __end0 = __6.2;
+    let __temp0 = __action235(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+        __8,
+        __9,
+        __10,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action112<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action283<
 >(
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, StructField, usize),
+) -> alloc::vec::Vec<StructField>
 {
-    Expr::Increment(Box::new(e))
+    let __start0 = __0.0;
+    let __end0 = __1.2;
+    let __temp0 = __action231(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action246(
+        errors,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action113<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action284<
 >(
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, alloc::vec::Vec<StructField>, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, StructField, usize),
+) -> alloc::vec::Vec<StructField>
 {
-    Expr::Decrement(Box::new(e))
+    let __start0 = __1.0;
+    let __end0 = __2.2;
+    let __temp0 = __action231(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action247(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action114<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action285<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, field, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, StructField, usize),
+) -> Vec<StructField>
 {
-    {
-        if let Expr::Var(mod_name) = base {
-            Expr::ModuleAccess(mod_name, field, vec![])
-        } else {
-            Expr::Call(field, vec![], None)
-        }
-    }
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action229(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action115<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action286<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, field, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, first, _): (usize, Expr, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, StructField, usize),
+    __1: (usize, alloc::vec::Vec<StructField>, usize),
+) -> Vec<StructField>
 {
-    {
-        let mut args = vec![first];
-        args.extend(rest);
-        if let Expr::Var(mod_name) = base {
-            Expr::ModuleAccess(mod_name, field, args)
-        } else {
-            Expr::Call(field, args, None)
-        }
-    }
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action230(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action116<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action287<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, field, _): (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    {
-        if let Expr::Var(val) = base {
-            Expr::FieldAccess(Box::new(Expr::Var(val)), field)
-        } else {
-            Expr::FieldAccess(Box::new(base), field)
-        }
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action118(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action117<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action288<
 >(
-    (_, f, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    f
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action119(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action118<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action289<
 >(
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Call(id, vec![], None)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action168(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action119<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action290<
 >(
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, first, _): (usize, Expr, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    {
-        let mut args = vec![first];
-        args.extend(rest);
-        Expr::Call(id, args, None)
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action169(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action120<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action291<
 >(
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, fields, _): (usize, Vec<(String, Expr)>, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    {
-        Expr::StructInit(id, fields)
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action133(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action121<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action292<
 >(
-    (_, id, _): (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Option<Token>, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Var(id)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action257(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action122<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action293<
 >(
-    (_, n, _): (usize, i64, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Option<Token>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Number(n)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action258(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action123<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action294<
 >(
-    (_, f, _): (usize, f64, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Float(f)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action183(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action124<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action295<
 >(
-    (_, s, _): (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Option<Token>, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Str(s)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action261(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action125<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action296<
 >(
-    (_, b, _): (usize, bool, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Option<Token>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Bool(b)
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action262(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action126<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action297<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeI8
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action110(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action127<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action298<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeU8
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action128<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action299<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeI16
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action112(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action129<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action300<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeU16
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action113(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action130<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action301<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeI32
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action114(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action131<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action302<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeU32
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action115(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action132<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action303<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeI64
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action116(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action133<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action304<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeU64
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action160(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action134<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action305<
 >(
-    (_, f, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeF16
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action161(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action135<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action306<
 >(
-    (_, f, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeF32
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action162(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action136<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action307<
 >(
-    (_, f, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::TypeF64
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action137<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action308<
 >(
-    (_, __0, _): (usize, Token, usize),
-    (_, __1, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Unit()
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action138<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action309<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    e
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action139<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action310<
 >(
-    (_, __0, _): (usize, Token, usize),
-    (_, __1, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::List(vec![])
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action166(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action140<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action311<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, first, _): (usize, Expr, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, (), usize),
+    __1: (usize, (), usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Vec<ExternParam>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
+    __7: (usize, Option<Type>, usize),
+    __8: (usize, Token, usize),
+) -> Item
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        Expr::List(v)
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action42(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action141<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action312<
 >(
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Type, usize),
+    __3: (usize, usize, usize),
+) -> ExternParam
 {
-    __0
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action142<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action313<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, bool, usize),
+    __1: (usize, (), usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Vec<FunctionParam>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, usize, usize),
+    __7: (usize, Option<Type>, usize),
+    __8: (usize, Vec<Stmt>, usize),
+) -> Item
 {
-    Expr::Range(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action37(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action143<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action314<
 >(
-    (_, e, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> FunctionParam
 {
-    e
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action40(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action144<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action315<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, String, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, usize, usize),
+) -> Import
 {
-    Expr::Eq(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action253(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action145<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action316<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, usize, usize),
+) -> Import
 {
-    Expr::Neq(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action254(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action146<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action317<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, usize, usize),
+) -> Import
 {
-    Expr::Lt(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action281(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action147<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action318<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, alloc::vec::Vec<String>, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, Token, usize),
+    __9: (usize, usize, usize),
+) -> Import
 {
-    Expr::Gt(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action282(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action148<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action319<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Le(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action121(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action149<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action320<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Ge(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action122(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action150<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action321<
 >(
-    (_, a, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    a
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action123(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action151<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action322<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Add(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action171(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action152<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action323<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Minus(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action172(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action153<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action324<
 >(
-    (_, m, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, usize, usize),
 ) -> Expr
 {
-    m
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action173(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action154<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action325<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Mul(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action155<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action326<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Div(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action129(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action156<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action327<
 >(
-    (_, l, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, r, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Mod(Box::new(l), Box::new(r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action265(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action157<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action328<
 >(
-    (_, u, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, usize, usize),
 ) -> Expr
 {
-    u
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action266(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action158<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action329<
 >(
-    (_, p, _): (usize, Expr, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, usize, usize),
 ) -> Expr
 {
-    p
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action176(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action159<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action330<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, index, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Index(Box::new(base), Box::new(index))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action179(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action160<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action331<
 >(
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Increment(Box::new(e))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action267(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action161<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action332<
 >(
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, usize, usize),
 ) -> Expr
 {
-    Expr::Decrement(Box::new(e))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action268(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action162<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action333<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, field, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Item, usize),
+    __1: (usize, usize, usize),
+) -> (Item, Span)
 {
-    {
-        if let Expr::Var(mod_name) = base {
-            Expr::ModuleAccess(mod_name, field, vec![])
-        } else {
-            Expr::Call(field, vec![], None)
-        }
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action2(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action163<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action334<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, field, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, first, _): (usize, Expr, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, StmtKind, usize),
+    __1: (usize, usize, usize),
+) -> Stmt
 {
-    {
-        let mut args = vec![first];
-        args.extend(rest);
-        if let Expr::Var(mod_name) = base {
-            Expr::ModuleAccess(mod_name, field, args)
-        } else {
-            Expr::Call(field, args, None)
-        }
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action164<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action335<
 >(
-    (_, base, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, field, _): (usize, String, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Token, usize),
+) -> VarDecl
 {
-    {
-        if let Expr::Var(val) = base {
-            Expr::FieldAccess(Box::new(Expr::Var(val)), field)
-        } else {
-            Expr::FieldAccess(Box::new(base), field)
-        }
-    }
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action165<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action336<
 >(
-    (_, f, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, Token, usize),
+) -> VarDecl
 {
-    f
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action241(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action88(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action166<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action337<
 >(
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::Call(id, vec![], None)
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action287(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action167<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action338<
 >(
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, first, _): (usize, Expr, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    {
-        let mut args = vec![first];
-        args.extend(rest);
-        Expr::Call(id, args, None)
-    }
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action288(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action168<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action339<
 >(
-    (_, id, _): (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::Var(id)
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action289(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action169<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action340<
 >(
-    (_, n, _): (usize, i64, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::Number(n)
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action290(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action170<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action341<
 >(
-    (_, f, _): (usize, f64, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Token, usize),
 ) -> Expr
 {
-    Expr::Float(f)
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action291(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action171<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action342<
 >(
-    (_, s, _): (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Option<Token>, usize),
+    __4: (usize, Token, usize),
 ) -> Expr
 {
-    Expr::Str(s)
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action292(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action172<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action343<
 >(
-    (_, b, _): (usize, bool, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Option<Token>, usize),
+    __5: (usize, Token, usize),
 ) -> Expr
 {
-    Expr::Bool(b)
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action293(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action173<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action344<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Token, usize),
 ) -> Expr
 {
-    Expr::TypeI8
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action294(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action174<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action345<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Option<Token>, usize),
+    __4: (usize, Token, usize),
 ) -> Expr
 {
-    Expr::TypeU8
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action295(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action175<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action346<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Option<Token>, usize),
+    __5: (usize, Token, usize),
 ) -> Expr
 {
-    Expr::TypeI16
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action296(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action176<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action347<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeU16
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action297(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action177<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action348<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeI32
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action298(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action178<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action349<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeU32
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action299(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action179<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action350<
 >(
-    (_, i, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeI64
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action300(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action180<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action351<
 >(
-    (_, u, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeU64
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action301(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action181<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action352<
 >(
-    (_, f, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeF16
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action302(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action182<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action353<
 >(
-    (_, f, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeF32
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action303(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action183<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action354<
 >(
-    (_, f, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::TypeF64
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action304(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action184<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action355<
 >(
-    (_, __0, _): (usize, Token, usize),
-    (_, __1, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::Unit()
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action305(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action185<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action356<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    e
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action306(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action186<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action357<
 >(
-    (_, __0, _): (usize, Token, usize),
-    (_, __1, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    Expr::List(vec![])
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action307(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action187<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action358<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, first, _): (usize, Expr, usize),
-    (_, rest, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, _, _): (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
 ) -> Expr
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        Expr::List(v)
-    }
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action308(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action188<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action359<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Vec<(String, Expr)>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    vec![]
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action309(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action189<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action360<
 >(
-    (_, f, _): (usize, (String, Expr), usize),
-) -> Vec<(String, Expr)>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    vec![f]
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action310(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action190<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action361<
 >(
-    (_, first, _): (usize, (String, Expr), usize),
-    (_, _, _): (usize, Token, usize),
-    (_, rest, _): (usize, Vec<(String, Expr)>, usize),
-) -> Vec<(String, Expr)>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, (), usize),
+    __1: (usize, (), usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Vec<ExternParam>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, Option<Type>, usize),
+    __7: (usize, Token, usize),
+) -> Item
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
-    }
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action311(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action191<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action362<
 >(
-    (_, f, _): (usize, (String, Expr), usize),
-) -> Vec<(String, Expr)>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Type, usize),
+) -> ExternParam
 {
-    vec![f]
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action312(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action192<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action363<
 >(
-    (_, first, _): (usize, (String, Expr), usize),
-    (_, _, _): (usize, Token, usize),
-    (_, rest, _): (usize, Vec<(String, Expr)>, usize),
-) -> Vec<(String, Expr)>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, bool, usize),
+    __1: (usize, (), usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Vec<FunctionParam>, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, Option<Type>, usize),
+    __7: (usize, Vec<Stmt>, usize),
+) -> Item
 {
-    {
-        let mut v = vec![first];
-        v.extend(rest);
-        v
-    }
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action313(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+        __7,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action193<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action364<
 >(
-    (_, id, _): (usize, String, usize),
-    (_, _, _): (usize, Token, usize),
-    (_, e, _): (usize, Expr, usize),
-) -> (String, Expr)
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+) -> FunctionParam
 {
-    (id, e)
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action314(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action194<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action365<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, String, usize),
+    __4: (usize, Token, usize),
+) -> Import
 {
-    alloc::vec![]
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action315(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action195<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action366<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<Expr>, usize),
-) -> alloc::vec::Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+) -> Import
 {
-    v
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action316(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action196<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action367<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, __0, _): (usize, Expr, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, Token, usize),
+) -> Import
 {
-    __0
+    let __start0 = __7.2;
+    let __end0 = __7.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action317(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action197<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action368<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<FunctionParam>
-{
-    alloc::vec![]
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, alloc::vec::Vec<String>, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, Token, usize),
+) -> Import
+{
+    let __start0 = __8.2;
+    let __end0 = __8.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action318(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action198<
->(
-    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
-) -> alloc::vec::Vec<FunctionParam>
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action369<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    v
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action319(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action199<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action370<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, __0, _): (usize, FunctionParam, usize),
-) -> FunctionParam
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    __0
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action320(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action200<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action371<
 >(
-    (_, __0, _): (usize, Expr, usize),
-) -> Option<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    Some(__0)
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action321(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action201<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action372<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Option<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    None
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action322(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action202<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action373<
 >(
-    (_, __0, _): (usize, Type, usize),
-) -> Option<Type>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    Some(__0)
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action323(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action203<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action374<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> Option<Type>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+) -> Expr
 {
-    None
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action324(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action204<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action375<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Token, usize),
+) -> Expr
 {
-    alloc::vec![]
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action325(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action205<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action376<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<StructField>, usize),
-) -> alloc::vec::Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    v
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action326(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action206<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action377<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, __0, _): (usize, StructField, usize),
-) -> StructField
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+) -> Expr
 {
-    __0
+    let __start0 = __6.2;
+    let __end0 = __6.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action327(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action207<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action378<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+) -> Expr
 {
-    alloc::vec![]
+    let __start0 = __7.2;
+    let __end0 = __7.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action328(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action208<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action379<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-) -> alloc::vec::Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Token, usize),
+) -> Expr
 {
-    v
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action329(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action209<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action380<
 >(
-    (_, _, _): (usize, Token, usize),
-    (_, __0, _): (usize, String, usize),
-) -> String
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    __0
+    let __start0 = __4.2;
+    let __end0 = __4.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action330(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action210<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action381<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> alloc::vec::Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Option<Token>, usize),
+    __6: (usize, Token, usize),
+) -> Expr
 {
-    alloc::vec![]
+    let __start0 = __6.2;
+    let __end0 = __6.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action331(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action211<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action382<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<Item>, usize),
-) -> alloc::vec::Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Option<Token>, usize),
+    __7: (usize, Token, usize),
+) -> Expr
 {
-    v
+    let __start0 = __7.2;
+    let __end0 = __7.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action332(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action212<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action383<
 >(
-    (_, __0, _): (usize, Item, usize),
-) -> alloc::vec::Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Item, usize),
+) -> (Item, Span)
 {
-    alloc::vec![__0]
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action333(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action213<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action384<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<Item>, usize),
-    (_, e, _): (usize, Item, usize),
-) -> alloc::vec::Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, StmtKind, usize),
+) -> Stmt
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action334(
+        errors,
+        __0,
+        __temp0,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action214<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action385<
 >(
-    (_, __0, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Expr, usize),
+    __4: (usize, Token, usize),
+) -> VarDecl
 {
-    alloc::vec![__0]
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action335(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action215<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action386<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<String>, usize),
-    (_, e, _): (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+) -> VarDecl
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action240(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action336(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action216<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action387<
 >(
-    (_, __0, _): (usize, StructField, usize),
-) -> alloc::vec::Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    alloc::vec![__0]
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action232(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action342(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action217<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action388<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<StructField>, usize),
-    (_, e, _): (usize, StructField, usize),
-) -> alloc::vec::Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, Token, usize),
+) -> Expr
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action342(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action218<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action389<
 >(
-    (_, __0, _): (usize, FunctionParam, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Token, usize),
+    __5: (usize, Token, usize),
+) -> Expr
 {
-    alloc::vec![__0]
+    let __start0 = __4.0;
+    let __end0 = __4.2;
+    let __temp0 = __action232(
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action343(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action219<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action390<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<FunctionParam>, usize),
-    (_, e, _): (usize, FunctionParam, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __3.2;
+    let __end0 = __4.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action343(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action220<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action391<
 >(
-    (_, __0, _): (usize, Expr, usize),
-) -> alloc::vec::Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+) -> Expr
 {
-    alloc::vec![__0]
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action232(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action259(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action221<
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action392<
 >(
-    (_, v, _): (usize, alloc::vec::Vec<Expr>, usize),
-    (_, e, _): (usize, Expr, usize),
-) -> alloc::vec::Vec<Expr>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
+) -> Expr
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action259(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action222<
+fn __action393<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, Token, usize),
     __1: (usize, Expr, usize),
-) -> alloc::vec::Vec<Expr>
+    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action196(
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action232(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action260(
+        errors,
         __0,
         __1,
+        __2,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action394<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __3: (usize, Token, usize),
+) -> Expr
+{
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action220(
+    __action260(
+        errors,
+        __0,
+        __1,
+        __2,
         __temp0,
+        __3,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action223<
+fn __action395<
 >(
-    __0: (usize, alloc::vec::Vec<Expr>, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
     __1: (usize, Token, usize),
     __2: (usize, Expr, usize),
-) -> alloc::vec::Vec<Expr>
+    __3: (usize, Token, usize),
+    __4: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action196(
-        __1,
-        __2,
+    let __start0 = __3.0;
+    let __end0 = __3.2;
+    let __temp0 = __action232(
+        errors,
+        __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action221(
+    __action345(
+        errors,
         __0,
+        __1,
+        __2,
         __temp0,
+        __4,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action224<
+fn __action396<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Token, usize),
     __2: (usize, Expr, usize),
@@ -10647,12 +18173,14 @@ fn __action224<
 {
     let __start0 = __2.2;
     let __end0 = __3.0;
-    let __temp0 = __action194(
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
+    __action345(
+        errors,
         __0,
         __1,
         __2,
@@ -10661,73 +18189,91 @@ fn __action224<
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action225<
+fn __action397<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Token, usize),
     __2: (usize, Expr, usize),
     __3: (usize, alloc::vec::Vec<Expr>, usize),
     __4: (usize, Token, usize),
+    __5: (usize, Token, usize),
 ) -> Expr
 {
-    let __start0 = __3.0;
-    let __end0 = __3.2;
-    let __temp0 = __action195(
-        __3,
+    let __start0 = __4.0;
+    let __end0 = __4.2;
+    let __temp0 = __action232(
+        errors,
+        __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
+    __action346(
+        errors,
         __0,
         __1,
         __2,
+        __3,
         __temp0,
-        __4,
+        __5,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action226<
+fn __action398<
 >(
-    __0: (usize, Token, usize),
-    __1: (usize, Expr, usize),
-    __2: (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, String, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, Expr, usize),
+    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, Token, usize),
 ) -> Expr
 {
-    let __start0 = __1.2;
-    let __end0 = __2.0;
-    let __temp0 = __action194(
+    let __start0 = __3.2;
+    let __end0 = __4.0;
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action140(
+    __action346(
+        errors,
         __0,
         __1,
-        __temp0,
         __2,
+        __3,
+        __temp0,
+        __4,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action227<
+fn __action399<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, Token, usize),
     __1: (usize, Expr, usize),
-    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __2: (usize, Token, usize),
     __3: (usize, Token, usize),
 ) -> Expr
 {
     let __start0 = __2.0;
     let __end0 = __2.2;
-    let __temp0 = __action195(
+    let __temp0 = __action232(
+        errors,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action140(
+    __action263(
+        errors,
         __0,
         __1,
         __temp0,
@@ -10735,50 +18281,56 @@ fn __action227<
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action228<
+fn __action400<
 >(
-    __0: (usize, String, usize),
-    __1: (usize, Token, usize),
-    __2: (usize, Expr, usize),
-    __3: (usize, Token, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, Token, usize),
 ) -> Expr
 {
-    let __start0 = __2.2;
-    let __end0 = __3.0;
-    let __temp0 = __action194(
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action167(
+    __action263(
+        errors,
         __0,
         __1,
-        __2,
         __temp0,
-        __3,
+        __2,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action229<
+fn __action401<
 >(
-    __0: (usize, String, usize),
-    __1: (usize, Token, usize),
-    __2: (usize, Expr, usize),
-    __3: (usize, alloc::vec::Vec<Expr>, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, Expr, usize),
+    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __3: (usize, Token, usize),
     __4: (usize, Token, usize),
 ) -> Expr
 {
     let __start0 = __3.0;
     let __end0 = __3.2;
-    let __temp0 = __action195(
+    let __temp0 = __action232(
+        errors,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action167(
+    __action264(
+        errors,
         __0,
         __1,
         __2,
@@ -10787,104 +18339,161 @@ fn __action229<
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action230<
+fn __action402<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, Token, usize),
     __1: (usize, Expr, usize),
-    __2: (usize, Token, usize),
+    __2: (usize, alloc::vec::Vec<Expr>, usize),
+    __3: (usize, Token, usize),
 ) -> Expr
 {
-    let __start0 = __1.2;
-    let __end0 = __2.0;
-    let __temp0 = __action194(
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action187(
+    __action264(
+        errors,
         __0,
         __1,
-        __temp0,
         __2,
+        __temp0,
+        __3,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action231<
+fn __action403<
 >(
-    __0: (usize, Token, usize),
-    __1: (usize, Expr, usize),
-    __2: (usize, alloc::vec::Vec<Expr>, usize),
-    __3: (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, ExternParam, usize),
+    __1: (usize, Token, usize),
+) -> Vec<ExternParam>
+{
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action232(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action271(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action404<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, ExternParam, usize),
+) -> Vec<ExternParam>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action271(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action405<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, ExternParam, usize),
+    __1: (usize, alloc::vec::Vec<ExternParam>, usize),
+    __2: (usize, Token, usize),
+) -> Vec<ExternParam>
 {
     let __start0 = __2.0;
     let __end0 = __2.2;
-    let __temp0 = __action195(
+    let __temp0 = __action232(
+        errors,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action187(
+    __action272(
+        errors,
         __0,
         __1,
         __temp0,
-        __3,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action232<
+fn __action406<
 >(
-    __0: (usize, Expr, usize),
-    __1: (usize, Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, Token, usize),
-    __4: (usize, Expr, usize),
-    __5: (usize, Token, usize),
-) -> Expr
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, ExternParam, usize),
+    __1: (usize, alloc::vec::Vec<ExternParam>, usize),
+) -> Vec<ExternParam>
 {
-    let __start0 = __4.2;
-    let __end0 = __5.0;
-    let __temp0 = __action194(
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action115(
+    __action272(
+        errors,
         __0,
         __1,
-        __2,
-        __3,
-        __4,
         __temp0,
-        __5,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action233<
+fn __action407<
 >(
-    __0: (usize, Expr, usize),
-    __1: (usize, Token, usize),
-    __2: (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
     __3: (usize, Token, usize),
-    __4: (usize, Expr, usize),
-    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __4: (usize, String, usize),
+    __5: (usize, Token, usize),
     __6: (usize, Token, usize),
-) -> Expr
+    __7: (usize, Token, usize),
+) -> Import
 {
     let __start0 = __5.0;
     let __end0 = __5.2;
-    let __temp0 = __action195(
+    let __temp0 = __action232(
+        errors,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action115(
+    __action367(
+        errors,
         __0,
         __1,
         __2,
@@ -10892,29 +18501,35 @@ fn __action233<
         __4,
         __temp0,
         __6,
+        __7,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action234<
+fn __action408<
 >(
-    __0: (usize, Expr, usize),
-    __1: (usize, Token, usize),
-    __2: (usize, String, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
     __3: (usize, Token, usize),
-    __4: (usize, Expr, usize),
+    __4: (usize, String, usize),
     __5: (usize, Token, usize),
-) -> Expr
+    __6: (usize, Token, usize),
+) -> Import
 {
     let __start0 = __4.2;
     let __end0 = __5.0;
-    let __temp0 = __action194(
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action163(
+    __action367(
+        errors,
         __0,
         __1,
         __2,
@@ -10922,289 +18537,472 @@ fn __action234<
         __4,
         __temp0,
         __5,
+        __6,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action235<
+fn __action409<
 >(
-    __0: (usize, Expr, usize),
-    __1: (usize, Token, usize),
-    __2: (usize, String, usize),
-    __3: (usize, Token, usize),
-    __4: (usize, Expr, usize),
-    __5: (usize, alloc::vec::Vec<Expr>, usize),
-    __6: (usize, Token, usize),
-) -> Expr
-{
-    let __start0 = __5.0;
-    let __end0 = __5.2;
-    let __temp0 = __action195(
-        __5,
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Token, usize),
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, alloc::vec::Vec<String>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, Token, usize),
+    __8: (usize, Token, usize),
+) -> Import
+{
+    let __start0 = __6.0;
+    let __end0 = __6.2;
+    let __temp0 = __action232(
+        errors,
+        __6,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action163(
+    __action368(
+        errors,
         __0,
         __1,
         __2,
         __3,
         __4,
+        __5,
         __temp0,
-        __6,
+        __7,
+        __8,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action236<
+fn __action410<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, Token, usize),
-    __1: (usize, FunctionParam, usize),
-) -> alloc::vec::Vec<FunctionParam>
+    __1: (usize, String, usize),
+    __2: (usize, Token, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, String, usize),
+    __5: (usize, alloc::vec::Vec<String>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, Token, usize),
+) -> Import
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action199(
-        __0,
-        __1,
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action218(
+    __action368(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
         __temp0,
+        __6,
+        __7,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action237<
+fn __action411<
 >(
-    __0: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, FunctionParam, usize),
     __1: (usize, Token, usize),
-    __2: (usize, FunctionParam, usize),
-) -> alloc::vec::Vec<FunctionParam>
+) -> Vec<FunctionParam>
 {
     let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action199(
+    let __end0 = __1.2;
+    let __temp0 = __action232(
+        errors,
         __1,
-        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action219(
+    __action275(
+        errors,
         __0,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action238<
+fn __action412<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, FunctionParam, usize),
 ) -> Vec<FunctionParam>
 {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action197(
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(
+    __action275(
+        errors,
         __0,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action239<
+fn __action413<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, FunctionParam, usize),
     __1: (usize, alloc::vec::Vec<FunctionParam>, usize),
+    __2: (usize, Token, usize),
 ) -> Vec<FunctionParam>
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action198(
-        __1,
+    let __start0 = __2.0;
+    let __end0 = __2.2;
+    let __temp0 = __action232(
+        errors,
+        __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action34(
+    __action276(
+        errors,
         __0,
+        __1,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action240<
+fn __action414<
 >(
-    __0: (usize, Token, usize),
-    __1: (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, FunctionParam, usize),
+    __1: (usize, alloc::vec::Vec<FunctionParam>, usize),
+) -> Vec<FunctionParam>
 {
-    let __start0 = __0.0;
+    let __start0 = __1.2;
     let __end0 = __1.2;
-    let __temp0 = __action209(
-        __0,
-        __1,
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action214(
+    __action276(
+        errors,
+        __0,
+        __1,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action241<
+fn __action415<
 >(
-    __0: (usize, alloc::vec::Vec<String>, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
     __1: (usize, Token, usize),
     __2: (usize, String, usize),
-) -> alloc::vec::Vec<String>
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action209(
-        __1,
-        __2,
+    let __start0 = __5.0;
+    let __end0 = __5.2;
+    let __temp0 = __action232(
+        errors,
+        __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action215(
+    __action377(
+        errors,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
+        __6,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action242<
+fn __action416<
 >(
-    __0: (usize, String, usize),
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action207(
+    let __start0 = __4.2;
+    let __end0 = __5.0;
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(
+    __action377(
+        errors,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
+        __5,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action243<
+fn __action417<
 >(
-    __0: (usize, String, usize),
-    __1: (usize, alloc::vec::Vec<String>, usize),
-) -> Vec<String>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action208(
-        __1,
+    let __start0 = __6.0;
+    let __end0 = __6.2;
+    let __temp0 = __action232(
+        errors,
+        __6,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(
+    __action378(
+        errors,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
         __temp0,
+        __7,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action244<
+fn __action418<
 >(
-    __0: (usize, Token, usize),
-    __1: (usize, StructField, usize),
-) -> alloc::vec::Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __0.0;
-    let __end0 = __1.2;
-    let __temp0 = __action206(
-        __0,
-        __1,
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action216(
+    __action378(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
         __temp0,
+        __6,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action245<
+fn __action419<
 >(
-    __0: (usize, alloc::vec::Vec<StructField>, usize),
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
     __1: (usize, Token, usize),
-    __2: (usize, StructField, usize),
-) -> alloc::vec::Vec<StructField>
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Token, usize),
+    __6: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __1.0;
-    let __end0 = __2.2;
-    let __temp0 = __action206(
-        __1,
-        __2,
+    let __start0 = __5.0;
+    let __end0 = __5.2;
+    let __temp0 = __action232(
+        errors,
+        __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action217(
+    __action381(
+        errors,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
+        __6,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action246<
+fn __action420<
 >(
-    __0: (usize, StructField, usize),
-) -> Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action204(
+    let __start0 = __4.2;
+    let __end0 = __5.0;
+    let __temp0 = __action233(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action381(
+        errors,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
+        __5,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action247<
+fn __action421<
 >(
-    __0: (usize, StructField, usize),
-    __1: (usize, alloc::vec::Vec<StructField>, usize),
-) -> Vec<StructField>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Token, usize),
+    __7: (usize, Token, usize),
+) -> Expr
 {
-    let __start0 = __1.0;
-    let __end0 = __1.2;
-    let __temp0 = __action205(
+    let __start0 = __6.0;
+    let __end0 = __6.2;
+    let __temp0 = __action232(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action382(
+        errors,
+        __0,
         __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action422<
+>(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, Expr, usize),
+    __1: (usize, Token, usize),
+    __2: (usize, String, usize),
+    __3: (usize, Token, usize),
+    __4: (usize, Expr, usize),
+    __5: (usize, alloc::vec::Vec<Expr>, usize),
+    __6: (usize, Token, usize),
+) -> Expr
+{
+    let __start0 = __5.2;
+    let __end0 = __6.0;
+    let __temp0 = __action233(
+        errors,
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action382(
+        errors,
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
         __temp0,
+        __6,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action248<
+fn __action423<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Option<Type>, usize),
     __2: (usize, Expr, usize),
@@ -11212,43 +19010,51 @@ fn __action248<
 {
     let __start0 = __2.0;
     let __end0 = __2.2;
-    let __temp0 = __action200(
+    let __temp0 = __action225(
+        errors,
         __2,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action23(
+        errors,
         __0,
         __1,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action249<
+fn __action424<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Option<Type>, usize),
 ) -> StructField
 {
     let __start0 = __1.2;
     let __end0 = __1.2;
-    let __temp0 = __action201(
+    let __temp0 = __action226(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action23(
+        errors,
         __0,
         __1,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action250<
+fn __action425<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Type, usize),
     __2: (usize, Expr, usize),
@@ -11256,118 +19062,139 @@ fn __action250<
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action202(
+    let __temp0 = __action227(
+        errors,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action248(
+    __action423(
+        errors,
         __0,
         __temp0,
         __2,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action251<
+fn __action426<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Expr, usize),
 ) -> StructField
 {
     let __start0 = __0.2;
     let __end0 = __1.0;
-    let __temp0 = __action203(
+    let __temp0 = __action228(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action248(
+    __action423(
+        errors,
         __0,
         __temp0,
         __1,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action252<
+fn __action427<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
     __1: (usize, Type, usize),
 ) -> StructField
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action202(
+    let __temp0 = __action227(
+        errors,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action249(
+    __action424(
+        errors,
         __0,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action253<
+fn __action428<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, String, usize),
 ) -> StructField
 {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action203(
+    let __temp0 = __action228(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action249(
+    __action424(
+        errors,
         __0,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action254<
+fn __action429<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, Token, usize),
     __1: (usize, Expr, usize),
     __2: (usize, Token, usize),
     __3: (usize, Vec<Stmt>, usize),
-) -> Stmt
+) -> StmtKind
 {
     let __start0 = __0.0;
     let __end0 = __3.2;
-    let __temp0 = __action68(
+    let __temp0 = __action81(
+        errors,
         __0,
         __1,
         __2,
         __3,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action64(
+    __action76(
+        errors,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action255<
+fn __action430<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __0: (usize, Token, usize),
     __1: (usize, Expr, usize),
     __2: (usize, Token, usize),
     __3: (usize, Vec<Stmt>, usize),
     __4: (usize, Token, usize),
     __5: (usize, Vec<Stmt>, usize),
-) -> Stmt
+) -> StmtKind
 {
     let __start0 = __0.0;
     let __end0 = __5.2;
-    let __temp0 = __action69(
+    let __temp0 = __action82(
+        errors,
         __0,
         __1,
         __2,
@@ -11376,45 +19203,54 @@ fn __action255<
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action64(
+    __action76(
+        errors,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action256<
+fn __action431<
 >(
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Vec<Item>
+) -> Vec<(Item, Span)>
 {
     let __start0 = *__lookbehind;
     let __end0 = *__lookahead;
-    let __temp0 = __action210(
+    let __temp0 = __action242(
+        errors,
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
     __action1(
+        errors,
         __temp0,
     )
 }
 
+#[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
     clippy::just_underscores_and_digits)]
-fn __action257<
+fn __action432<
 >(
-    __0: (usize, alloc::vec::Vec<Item>, usize),
-) -> Vec<Item>
+    errors: &mut Vec<ErrorRecovery<usize, Token, String>>,
+    __0: (usize, alloc::vec::Vec<(Item, Span)>, usize),
+) -> Vec<(Item, Span)>
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action211(
+    let __temp0 = __action243(
+        errors,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
     __action1(
+        errors,
         __temp0,
     )
 }
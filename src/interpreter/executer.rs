@@ -1,25 +1,41 @@
-// interpreter currently not support yet, for now this file set a allowed unused
+// The tree-walking interpreter behind `sprs repl`. It runs the AST the compiler gets, with the
+// values of the dynamic type system held as `Value`s. A panic of a compiled program is an `Err`
+// here, so a session goes on after it. Code that needs real machine code (`asm!`, the
+// `volatile_*!` macros, `extern fn`) is not supported.
 #![allow(unused)]
 
 use crate::{
     front::ast::{self},
     interpreter::runner::parse_only,
+    interpreter::type_helper::Type,
+    llvm::{compiler, file, math, string},
+    runtime::builtin,
+    runtime::runtime as rt,
 };
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 type Scope = HashMap<String, Value>;
 
+// Calls deeper than this stop with an error instead of overflowing the interpreter's stack.
+const MAX_CALL_DEPTH: usize = 2000;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Int(i64),
     Float(f64),
     Bool(bool),
     Str(String),
+    Char(char),
     Unit,
     Return(Box<Value>),
-    List(std::rc::Rc<std::cell::RefCell<Vec<Value>>>),
+    List(Rc<RefCell<Vec<Value>>>),
     Range(i64, i64),
-    StructInit(String, HashMap<String, Value>),
+    StructInit(String, Vec<(String, Value)>), // StructName, Fields in declaration order
+    Enum(String, String),                     // EnumName, Variant
+    SizedInt(Type, i64),                      // cast! to i8 ... u64; a u64 keeps its bits
+    SizedFloat(Type, f64),                    // cast! to fp16, fp32 or fp64
 
     // System types
     TypeI8,
@@ -36,9 +52,29 @@ pub enum Value {
     TypeF64,
 }
 
+impl Value {
+    // The name `type_of!` gives the value.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Int(_) => "Int",
+            Value::Float(_) => "Float",
+            Value::Bool(_) => "Bool",
+            Value::Str(_) => "Str",
+            Value::Char(_) => "Char",
+            Value::List(_) => "List",
+            Value::Range(_, _) => "Range",
+            Value::StructInit(_, _) => "Struct",
+            Value::Enum(_, _) => "Enum",
+            Value::SizedInt(ty, _) | Value::SizedFloat(ty, _) => sized_name(ty),
+            Value::Return(val) => val.type_name(),
+            _ => "Unit",
+        }
+    }
+}
+
 pub struct Module {
     pub name: String,
-    pub functions: HashMap<String, Callable<'static>>,
+    pub functions: HashMap<String, Rc<ast::Function>>,
     pub variables: HashMap<String, Value>,
 }
 impl Module {
@@ -50,13 +86,24 @@ impl Module {
         }
     }
 }
-pub struct RuntimeContext<'a> {
+
+// Everything a program or a repl session has defined so far.
+pub struct RuntimeContext {
     pub modules: HashMap<String, Module>,
     pub global_scope: Scope,
-    pub functions: HashMap<&'a str, Callable<'a>>,
+    pub functions: HashMap<String, Rc<ast::Function>>,
     pub program_data: ProgramSig,
+    pub src_path: String, // where `import` looks for modules
+    structs: HashMap<String, Rc<ast::Struct>>,
+    enums: HashMap<String, Vec<String>>,
+    packages: HashSet<String>,         // imported built-in packages
+    aliases: HashMap<String, String>,  // `import sensors as s;`: s -> sensors
+    imported: HashMap<String, String>, // `import math.{sin};`: sin -> math
+    current_module: Option<String>,    // the module of the running function
+    depth: usize,
+    files: Vec<Option<std::fs::File>>, // file.open handles, starting at 1
 }
-impl<'a> RuntimeContext<'a> {
+impl RuntimeContext {
     pub fn new() -> Self {
         RuntimeContext {
             modules: HashMap::new(),
@@ -65,22 +112,28 @@ impl<'a> RuntimeContext<'a> {
             program_data: ProgramSig {
                 runtime_os: OS::Windows, // Default OS
             },
+            src_path: "src".to_string(),
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            packages: HashSet::new(),
+            aliases: HashMap::new(),
+            imported: HashMap::new(),
+            current_module: None,
+            depth: 0,
+            files: Vec::new(),
         }
     }
 }
 
-pub enum Callable<'a> {
-    User(&'a ast::Function),
-    Builtin(NativeFunction),
-}
-
-type NativeFunction = fn(&[Value]) -> Result<Value, String>;
-
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Value::Int(n) => write!(f, "{}", n),
             Value::Float(n) => write!(f, "{}", n),
+            Value::SizedInt(Type::TypeU64, n) => write!(f, "{}", *n as u64),
+            Value::SizedInt(_, n) => write!(f, "{}", n),
+            Value::SizedFloat(Type::TypeF64, n) => write!(f, "{}", n),
+            Value::SizedFloat(_, n) => write!(f, "{}", *n as f32),
             Value::TypeI8 => write!(f, "i8"),
             Value::TypeU8 => write!(f, "u8"),
             Value::TypeI16 => write!(f, "i16"),
@@ -94,6 +147,7 @@ impl std::fmt::Display for Value {
             Value::TypeF64 => write!(f, "fp64"),
             Value::Bool(b) => write!(f, "{}", b),
             Value::Str(s) => write!(f, "{}", s),
+            Value::Char(c) => write!(f, "{}", c),
             Value::Unit => write!(f, "()"),
             Value::Return(val) => write!(f, "Return({})", val),
             Value::List(elements) => {
@@ -120,554 +174,1308 @@ impl std::fmt::Display for Value {
                 }
                 write!(f, " }}")
             }
+            Value::Enum(name, variant) => write!(f, "{}.{}", name, variant),
         }
     }
 }
 
-impl std::ops::Add for Value {
-    type Output = Value;
-
-    fn add(self, other: Value) -> Value {
-        match (self, other) {
-            (Value::Int(a), Value::Int(b)) => Value::Int(a + b),
-            _ => Value::Unit, // Simplified for demo purposes
-        }
-    }
+pub struct ProgramSig {
+    pub runtime_os: OS,
 }
 
-impl std::ops::Mul for Value {
-    type Output = Value;
-
-    fn mul(self, other: Value) -> Value {
-        match (self, other) {
-            (Value::Int(a), Value::Int(b)) => Value::Int(a * b),
-            _ => Value::Unit, // Simplified for demo purposes
-        }
-    }
+pub enum OS {
+    Windows,
+    Linux,
 }
 
-impl std::ops::Sub for Value {
-    type Output = Value;
-
-    fn sub(self, other: Value) -> Value {
-        match (self, other) {
-            (Value::Int(a), Value::Int(b)) => Value::Int(a - b),
-            _ => Value::Unit, // Simplified for demo purposes
-        }
-    }
+#[derive(Clone, Copy)]
+enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
 }
 
-impl std::ops::Div for Value {
-    type Output = Value;
-
-    fn div(self, other: Value) -> Value {
-        match (self, other) {
-            (Value::Int(a), Value::Int(b)) => Value::Int(a / b),
-            _ => Value::Unit, // Simplified for demo purposes
+impl ArithOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            ArithOp::Add => "+",
+            ArithOp::Sub => "-",
+            ArithOp::Mul => "*",
+            ArithOp::Div => "/",
+            ArithOp::Mod => "%",
         }
     }
 }
 
-pub struct ProgramSig {
-    pub runtime_os: OS,
+// The message of a runtime error, printed the way the compiled program prints its panics.
+fn panic(message: impl std::fmt::Display) -> String {
+    format!("Panic: {}", message)
 }
 
-pub enum OS {
-    Windows,
-    Linux,
+fn is_true(val: Value, what: &str) -> Result<bool, String> {
+    match val {
+        Value::Bool(b) => Ok(b),
+        _ => Err(panic(format!(
+            "TypeError: expected Bool in condition of '{}'",
+            what
+        ))),
+    }
 }
 
-fn entry_builtin_functions() -> HashMap<&'static str, Callable<'static>> {
-    let mut map = HashMap::new();
-    map.insert(
-        "println",
-        Callable::Builtin(crate::runtime::builtin::builtin_function_println),
-    );
-    map.insert(
-        "vec_push!",
-        Callable::Builtin(crate::runtime::builtin::builtin_function_push),
-    );
-    map
+// Runs `main` (or the item at `entry_idx`) of a parsed program.
+pub fn execute(
+    ctx: &mut RuntimeContext,
+    items: Vec<ast::Item>,
+    entry_idx: usize,
+) -> Result<Value, String> {
+    let entry = match &items.get(entry_idx) {
+        Some(ast::Item::FunctionItem(func)) => func.ident.clone(),
+        _ => return Err("Entry item is not a function".to_string()),
+    };
+    for item in items {
+        ctx.define(item)?;
+    }
+    let func = ctx.functions[&entry].clone();
+    ctx.call_function(&func, None, Vec::new())
 }
 
-fn load_modules(ctx: &mut RuntimeContext, module_name: &str) -> Result<(), String> {
-    if ctx.modules.contains_key(module_name) {
-        return Ok(());
+fn execute_preprocessor(pre: Vec<&String>, program_data: &mut ProgramSig) {
+    for directive in pre {
+        if directive.starts_with("Windows") {
+            program_data.runtime_os = OS::Windows;
+        } else if directive.starts_with("Linux") {
+            program_data.runtime_os = OS::Linux;
+        } else {
+            println!("Unknown preprocessor directive: {}", directive);
+        }
     }
+}
 
-    let path = format!("{}.sprs", module_name);
-    let source = std::fs::read_to_string(&path).map_err(|_| "file not found");
-    let source = match source {
-        Ok(s) => s,
-        Err(e) => return Err(format!("Error reading module {}: {}", module_name, e)),
-    };
-    let ast = parse_only(&source, &path)
-        .map_err(|e| format!("Error parsing module {}: {}", module_name, e));
-    let ast = match ast {
-        Ok(a) => a,
-        Err(e) => return Err(format!("Error parsing module {}: {}", module_name, e)),
-    };
-
-    let mut preprocessors = Vec::new();
-    for item in &ast {
+impl RuntimeContext {
+    // Adds a top-level item; a function or type of the same name is replaced.
+    pub fn define(&mut self, item: ast::Item) -> Result<(), String> {
         match item {
-            ast::Item::Import(sub_module) => {
-                load_modules(ctx, &sub_module).map_err(|e| format!("Error Load Module {}", e))?;
+            ast::Item::FunctionItem(func) => {
+                self.functions.insert(func.ident.clone(), Rc::new(func));
             }
-            ast::Item::Preprocessor(pre) => {
-                preprocessors.push(pre);
+            ast::Item::StructItem(strukt) => {
+                self.structs.insert(strukt.ident.clone(), Rc::new(strukt));
             }
-            ast::Item::Import(ident) => {
-                load_modules(ctx, ident)
-                    .map_err(|e| format!("Error loading module {}: {}", ident, e))?;
+            ast::Item::EnumItem(enm) => {
+                self.enums.insert(enm.ident, enm.variants);
             }
-            ast::Item::Package(name) => {
-                // Package declaration, can be used for namespacing if needed
-                println!("Loading package: {}", name);
+            ast::Item::Import(import) => self.import(import)?,
+            ast::Item::VarItem(var) => {
+                let val = match &var.expr {
+                    Some(expr) => self.evaluate(expr)?,
+                    None => Value::Unit,
+                };
+                self.global_scope.insert(var.ident, val);
+            }
+            ast::Item::Preprocessor(pre) => {
+                execute_preprocessor(vec![&pre], &mut self.program_data)
+            }
+            ast::Item::Package(_) => {}
+            ast::Item::ExternFnItem(func) => {
+                return Err(format!(
+                    "extern fn '{}' is linked into compiled programs and cannot be called by the interpreter",
+                    func.ident
+                ));
             }
-            _ => {}
         }
+        Ok(())
     }
 
-    execute_preprocessor(preprocessors, &mut ctx.program_data);
-
-    let mut module = Module::new(module_name);
-
-    ctx.modules.insert(module_name.to_string(), module);
-    Ok(())
-}
-
-pub fn execute(
-    ctx: &mut RuntimeContext,
-    items: &[ast::Item],
-    entry_idx: usize,
-) -> Result<(), String> {
-    let mut functions: HashMap<&str, Callable> = HashMap::new();
-    let mut preprocessors = Vec::new();
+    // Runs statements in the global scope, so the variables they declare stay defined.
+    pub fn run(&mut self, stmts: &[ast::Stmt]) -> Result<(), String> {
+        let mut scope = std::mem::take(&mut self.global_scope);
+        let result = self.execute_stmts(stmts, &mut scope, &mut Vec::new());
+        self.global_scope = scope;
+        result.map(|_| ())
+    }
 
-    let mut builtins = entry_builtin_functions();
-    functions.extend(builtins.drain());
+    pub fn evaluate(&mut self, expr: &ast::Expr) -> Result<Value, String> {
+        let mut scope = std::mem::take(&mut self.global_scope);
+        let result = self.evalute_expr(expr, &mut scope);
+        self.global_scope = scope;
+        result
+    }
 
-    for item in items {
-        match item {
-            ast::Item::Import(module_name) => {
-                load_modules(ctx, module_name)
-                    .map_err(|e| format!("Error loading module {}: {}", module_name, e))?;
-            }
-            ast::Item::FunctionItem(func) => {
-                functions.insert(func.ident.as_str(), Callable::User(func));
-            }
-            ast::Item::Preprocessor(pre) => {
-                preprocessors.push(pre);
-            }
-            _ => { /* Ignore other items for now */ }
+    fn import(&mut self, import: ast::Import) -> Result<(), String> {
+        let module_name = import.module;
+        if compiler::is_builtin_package(&self.src_path, &module_name) {
+            self.packages.insert(module_name.clone());
+        } else {
+            self.load_module(&module_name)?;
+        }
+        if let Some(alias) = import.alias {
+            self.aliases.insert(alias, module_name.clone());
+        }
+        for name in import.names {
+            self.imported.insert(name, module_name.clone());
         }
+        Ok(())
     }
 
-    execute_preprocessor(preprocessors, &mut ctx.program_data);
+    fn load_module(&mut self, module_name: &str) -> Result<(), String> {
+        if self.modules.contains_key(module_name) {
+            return Ok(());
+        }
+        let path = compiler::module_path(&self.src_path, module_name);
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Error reading module {}: {}", module_name, e))?;
+        let items = parse_only(&source, &path)?;
 
-    let entry_item = &items[entry_idx];
-    match entry_item {
-        ast::Item::FunctionItem(func) => {
-            match call_function(&Callable::User(func), &[], &functions) {
-                Ok(var) => {
-                    println!("Program finished with return value: {}", var);
-                    ()
+        // inserted first, so modules that import each other load once
+        self.modules
+            .insert(module_name.to_string(), Module::new(module_name));
+        let mut module = Module::new(module_name);
+        for item in items {
+            match item {
+                ast::Item::FunctionItem(func) => {
+                    module.functions.insert(func.ident.clone(), Rc::new(func));
+                }
+                ast::Item::VarItem(var) => {
+                    let val = match &var.expr {
+                        Some(expr) => self.evaluate(expr)?,
+                        None => Value::Unit,
+                    };
+                    module.variables.insert(var.ident, val);
                 }
-                Err(e) => return Err(format!("Error executing function {}: {}", func.ident, e)),
+                ast::Item::Package(_) | ast::Item::Preprocessor(_) => {}
+                other => self.define(other)?,
             }
         }
-        _ => return Err("Entry item is not a function".to_string()),
+        self.modules.insert(module_name.to_string(), module);
+        Ok(())
     }
 
-    println!("Executing {} items...", items.len());
-    Ok(())
-}
+    fn call_function(
+        &mut self,
+        func: &Rc<ast::Function>,
+        module: Option<String>,
+        args: Vec<Value>,
+    ) -> Result<Value, String> {
+        if args.len() != func.params.len() {
+            return Err(format!(
+                "Function '{}' expects {} arguments, got {}",
+                func.ident,
+                func.params.len(),
+                args.len()
+            ));
+        }
+        if self.depth >= MAX_CALL_DEPTH {
+            return Err(panic(format!(
+                "RecursionError: more than {} nested calls",
+                MAX_CALL_DEPTH
+            )));
+        }
 
-fn execute_preprocessor(pre: Vec<&String>, program_data: &mut ProgramSig) {
-    for directive in pre {
-        if directive.starts_with("Windows") {
-            program_data.runtime_os = OS::Windows;
-        } else if directive.starts_with("Linux") {
-            program_data.runtime_os = OS::Linux;
-        } else {
-            println!("Unknown preprocessor directive: {}", directive);
+        let mut scope: Scope = HashMap::new();
+        for (param, val) in func.params.iter().zip(args) {
+            scope.insert(param.ident.clone(), val);
         }
-    }
-}
 
-fn call_function(
-    func: &Callable,
-    arg_value: &[Value],
-    functions: &HashMap<&str, Callable>,
-) -> Result<Value, String> {
-    match func {
-        Callable::Builtin(builtin_fn) => builtin_fn(arg_value),
-        Callable::User(func) => {
-            let body = &func.blk;
-            let args = &func.params;
+        let caller_module = std::mem::replace(&mut self.current_module, module);
+        self.depth += 1;
+        let result = self.execute_stmts(&func.blk, &mut scope, &mut Vec::new());
+        self.depth -= 1;
+        self.current_module = caller_module;
 
-            let mut scope: Scope = HashMap::new();
+        match result? {
+            Value::Return(val) => Ok(*val),
+            _ => Ok(Value::Unit),
+        }
+    }
 
-            for (idx, param) in args.iter().enumerate() {
-                let val = arg_value.get(idx).cloned().unwrap_or(Value::Unit);
-                scope.insert(param.ident.clone(), val.clone());
-                println!("  Param {}: {} = {}", idx, param.ident, val);
-            }
+    // A nested block: the variables it declares go out of scope at its end.
+    fn execute_block(&mut self, stmts: &[ast::Stmt], scope: &mut Scope) -> Result<Value, String> {
+        let mut declared = Vec::new();
+        let result = self.execute_stmts(stmts, scope, &mut declared);
+        for (name, shadowed) in declared.into_iter().rev() {
+            match shadowed {
+                Some(val) => scope.insert(name, val),
+                None => scope.remove(&name),
+            };
+        }
+        result
+    }
 
-            let result = execute_block(body, functions, &mut scope)?;
-            match result {
-                Value::Return(val) => Ok(*val),
-                _ => Ok(result),
+    // Returns `Value::Return` when a `return` ran, else Unit. `declared` collects the names the
+    // statements declare, with the values they hide.
+    fn execute_stmts(
+        &mut self,
+        stmts: &[ast::Stmt],
+        scope: &mut Scope,
+        declared: &mut Vec<(String, Option<Value>)>,
+    ) -> Result<Value, String> {
+        for stmt in stmts {
+            match &stmt.kind {
+                ast::StmtKind::Var(var) => {
+                    let val = match &var.expr {
+                        Some(expr) => self.evalute_expr(expr, scope)?,
+                        None => Value::Unit,
+                    };
+                    let shadowed = scope.insert(var.ident.clone(), val);
+                    declared.push((var.ident.clone(), shadowed));
+                }
+                ast::StmtKind::Assign(assign_stmt) => {
+                    let val = self.evalute_expr(&assign_stmt.expr, scope)?;
+                    let target = scope
+                        .get_mut(&assign_stmt.name)
+                        .ok_or_else(|| format!("Undefined variable: {}", assign_stmt.name))?;
+                    *target = val;
+                }
+                ast::StmtKind::MultiAssign(assigns) => {
+                    // evaluate every value before assigning any target
+                    let mut values = Vec::new();
+                    for assign_stmt in assigns {
+                        values.push(self.evalute_expr(&assign_stmt.expr, scope)?);
+                    }
+                    for (assign_stmt, val) in assigns.iter().zip(values) {
+                        let target = scope
+                            .get_mut(&assign_stmt.name)
+                            .ok_or_else(|| format!("Undefined variable: {}", assign_stmt.name))?;
+                        *target = val;
+                    }
+                }
+                ast::StmtKind::Expr(expr) => {
+                    self.evalute_expr(expr, scope)?;
+                }
+                ast::StmtKind::If {
+                    cond,
+                    then_blk,
+                    else_blk,
+                } => {
+                    let cond = self.evalute_expr(cond, scope)?;
+                    let result = if is_true(cond, "if")? {
+                        self.execute_block(then_blk, scope)?
+                    } else if let Some(else_blk) = else_blk {
+                        self.execute_block(else_blk, scope)?
+                    } else {
+                        Value::Unit
+                    };
+                    if let Value::Return(_) = result {
+                        return Ok(result);
+                    }
+                }
+                ast::StmtKind::While { cond, body } => loop {
+                    let cond = self.evalute_expr(cond, scope)?;
+                    if !is_true(cond, "while")? {
+                        break;
+                    }
+                    let result = self.execute_block(body, scope)?;
+                    if let Value::Return(_) = result {
+                        return Ok(result);
+                    }
+                },
+                ast::StmtKind::Return(expr) => {
+                    let val = match expr {
+                        Some(expr) => self.evalute_expr(expr, scope)?,
+                        None => Value::Unit,
+                    };
+                    return Ok(Value::Return(Box::new(val)));
+                }
+                ast::StmtKind::EnumItem(enm) => {
+                    self.enums.insert(enm.ident.clone(), enm.variants.clone());
+                }
             }
         }
+        Ok(Value::Unit)
     }
-}
 
-fn execute_block(
-    stmts: &Vec<ast::Stmt>,
-    functions: &HashMap<&str, Callable>,
-    scope: &mut Scope,
-) -> Result<Value, String> {
-    for stmt in stmts {
-        match stmt {
-            ast::Stmt::Var(var) => {
-                let val = if let Some(expr) = &var.expr {
-                    println!(
-                        "  Evaluating variable declaration: {} = {:?}",
-                        var.ident, expr
-                    );
-                    match evalute_expr(expr, functions, scope) {
-                        Ok(v) => v,
-                        Err(e) => {
-                            return Err(format!("Error evaluating variable {}: {}", var.ident, e));
-                        }
-                    }
+    fn evalute_expr(&mut self, expr: &ast::Expr, scope: &mut Scope) -> Result<Value, String> {
+        match expr {
+            ast::Expr::Number(n) => Ok(Value::Int(*n)),
+            ast::Expr::Float(f) => Ok(Value::Float(*f)),
+            ast::Expr::TypeI8 => Ok(Value::TypeI8),
+            ast::Expr::TypeU8 => Ok(Value::TypeU8),
+            ast::Expr::TypeI16 => Ok(Value::TypeI16),
+            ast::Expr::TypeU16 => Ok(Value::TypeU16),
+            ast::Expr::TypeI32 => Ok(Value::TypeI32),
+            ast::Expr::TypeU32 => Ok(Value::TypeU32),
+            ast::Expr::TypeI64 => Ok(Value::TypeI64),
+            ast::Expr::TypeU64 => Ok(Value::TypeU64),
+            ast::Expr::TypeF16 => Ok(Value::TypeF16),
+            ast::Expr::TypeF32 => Ok(Value::TypeF32),
+            ast::Expr::TypeF64 => Ok(Value::TypeF64),
+            ast::Expr::Str(s) => Ok(Value::Str(s.clone())),
+            ast::Expr::Char(c) => Ok(Value::Char(*c)),
+            ast::Expr::Bool(b) => Ok(Value::Bool(*b)),
+            ast::Expr::Unit() => Ok(Value::Unit),
+            ast::Expr::Add(lhs, rhs, _) => self.arithmetic(ArithOp::Add, lhs, rhs, scope),
+            ast::Expr::Minus(lhs, rhs, _) => self.arithmetic(ArithOp::Sub, lhs, rhs, scope),
+            ast::Expr::Mul(lhs, rhs, _) => self.arithmetic(ArithOp::Mul, lhs, rhs, scope),
+            ast::Expr::Div(lhs, rhs, _) => self.arithmetic(ArithOp::Div, lhs, rhs, scope),
+            ast::Expr::Mod(lhs, rhs, _) => self.arithmetic(ArithOp::Mod, lhs, rhs, scope),
+            ast::Expr::Eq(lhs, rhs, _) => {
+                let left = self.evalute_expr(lhs, scope)?;
+                let right = self.evalute_expr(rhs, scope)?;
+                Ok(Value::Bool(left == right))
+            }
+            ast::Expr::Neq(lhs, rhs, _) => {
+                let left = self.evalute_expr(lhs, scope)?;
+                let right = self.evalute_expr(rhs, scope)?;
+                Ok(Value::Bool(left != right))
+            }
+            ast::Expr::Lt(lhs, rhs, _) => self.compare("<", lhs, rhs, scope, |o| o.is_lt()),
+            ast::Expr::Gt(lhs, rhs, _) => self.compare(">", lhs, rhs, scope, |o| o.is_gt()),
+            ast::Expr::Le(lhs, rhs, _) => self.compare("<=", lhs, rhs, scope, |o| o.is_le()),
+            ast::Expr::Ge(lhs, rhs, _) => self.compare(">=", lhs, rhs, scope, |o| o.is_ge()),
+            ast::Expr::If(cond, then_expr, else_expr) => {
+                let cond = self.evalute_expr(cond, scope)?;
+                if is_true(cond, "if")? {
+                    self.evalute_expr(then_expr, scope)
                 } else {
-                    println!("  Declaring variable {} with no initial value", var.ident);
-                    Value::Unit
-                };
-                scope.insert(var.ident.clone(), val.clone());
-                println!("  Declared variable {}: {}", val, var.ident);
-            }
-            ast::Stmt::Expr(expr) => {
-                println!("  Evaluating expression: {:?}", expr);
-                match evalute_expr(expr, functions, scope) {
-                    Ok(val) => println!("    Result: {}", val),
-                    Err(e) => return Err(format!("Error evaluating expression: {}", e)),
+                    self.evalute_expr(else_expr, scope)
                 }
             }
-            ast::Stmt::If {
-                cond,
-                then_blk,
-                else_blk,
-            } => {
-                println!("  Evaluating if condition: {:?}", cond);
-                match evalute_expr(cond, functions, scope) {
-                    Ok(val) => {
-                        let is_true = match val {
-                            Value::Bool(b) => b,
-                            Value::Int(n) => n != 0,
-                            _ => false,
-                        };
-
-                        if is_true {
-                            println!("    Condition is true, executing then block");
-                            let result = execute_block(then_blk, functions, scope)?;
-                            if let Value::Return(_) = result {
-                                return Ok(result);
-                            }
-                        } else if let Some(else_block) = else_blk {
-                            println!("    Condition is false, executing else block");
-                            let result = execute_block(else_block, functions, scope)?;
-                            if let Value::Return(_) = result {
-                                return Ok(result);
-                            }
-                        } else {
-                            println!("    Condition is false, no else block to execute");
-                        }
-                    }
-                    Err(e) => return Err(format!("Error evaluating if condition: {}", e)),
+            ast::Expr::Call(ident, args, _, _) => self.call(ident, args, scope),
+            ast::Expr::Var(ident) => scope
+                .get(ident)
+                .cloned()
+                .ok_or_else(|| format!("Undefined variable: {}", ident)),
+            ast::Expr::Increment(target) => self.step(target, 1, scope),
+            ast::Expr::Decrement(target) => self.step(target, -1, scope),
+            ast::Expr::List(elements) => {
+                let mut list_values = Vec::new();
+                for elem in elements {
+                    list_values.push(self.evalute_expr(elem, scope)?);
                 }
+                Ok(new_list(list_values))
             }
-            ast::Stmt::While { cond, body } => {
-                println!("  Entering while loop with condition: {:?}", cond);
-                loop {
-                    match evalute_expr(&cond, functions, scope) {
-                        Ok(val) => {
-                            let is_true = match val {
-                                Value::Bool(b) => b,
-                                Value::Int(n) => n != 0,
-                                _ => false,
-                            };
-
-                            if is_true {
-                                println!("    Condition is true, executing loop body");
-                                let result = execute_block(body, functions, scope)?;
-                                if let Value::Return(_) = result {
-                                    return Ok(result);
-                                }
-                            } else {
-                                println!("    Condition is false, exiting loop");
-                                break;
-                            }
-                        }
-                        Err(e) => return Err(format!("Error evaluating while condition: {}", e)),
+            ast::Expr::In(needle_expr, container_expr, _) => {
+                let needle = self.evalute_expr(needle_expr, scope)?;
+                let container = self.evalute_expr(container_expr, scope)?;
+                match (needle, container) {
+                    (needle, Value::List(elements)) => {
+                        Ok(Value::Bool(elements.borrow().contains(&needle)))
+                    }
+                    (Value::Str(sub), Value::Str(s)) => Ok(Value::Bool(s.contains(sub.as_str()))),
+                    (Value::Char(c), Value::Str(s)) => Ok(Value::Bool(s.contains(c))),
+                    (Value::Int(n), Value::Range(start, end)) => {
+                        Ok(Value::Bool(start <= n && n < end))
                     }
+                    _ => Err(panic("TypeError: unsupported operand types for 'in'")),
                 }
             }
-            ast::Stmt::Return(opt_expr) => {
-                if let Some(expr) = opt_expr {
-                    println!("  Evaluating return expression: {:?}", expr);
-                    match evalute_expr(expr, functions, scope) {
-                        Ok(val) => {
-                            println!("    Return value: {}", val);
-                            return Ok(Value::Return(Box::new(val)));
-                        }
-                        Err(e) => return Err(format!("Error evaluating return expression: {}", e)),
-                    }
-                } else {
-                    println!("  Return with no value");
-                    return Ok(Value::Return(Box::new(Value::Unit)));
+            ast::Expr::Range(start_expr, end_expr) => {
+                let start = self.evalute_expr(start_expr, scope)?;
+                let end = self.evalute_expr(end_expr, scope)?;
+                match (start, end) {
+                    (Value::Int(start), Value::Int(end)) => Ok(Value::Range(start, end)),
+                    _ => Err(panic("TypeError: range bounds must be Ints")),
                 }
             }
-            ast::Stmt::EnumItem(enm) => {
-                println!("  Enum declarations are not executed at runtime");
-            }
-            ast::Stmt::Assign(assign_stmt) => {
-                println!(
-                    "  Evaluating assignment: {} = {:?}",
-                    assign_stmt.name, assign_stmt.expr
-                );
-                match evalute_expr(&assign_stmt.expr, functions, scope) {
-                    Ok(val) => {
-                        scope.insert(assign_stmt.name.clone(), val.clone());
-                        println!("    Assigned variable {}: {}", assign_stmt.name, val);
+            ast::Expr::Index(collection_expr, index_expr, _) => {
+                let collection = self.evalute_expr(collection_expr, scope)?;
+                let index = self.evalute_expr(index_expr, scope)?;
+                index_value(collection, index)
+            }
+            ast::Expr::ModuleAccess(module_name, function_name, args) => {
+                let mut arg_values = Vec::new();
+                for arg in args {
+                    arg_values.push(self.evalute_expr(arg, scope)?);
+                }
+                self.call_module(module_name, function_name, arg_values)
+            }
+            ast::Expr::FieldAccess(base, field_name) => {
+                // `Animal.Dog`, unless a variable hides the enum
+                let enum_variants = match base.as_ref() {
+                    ast::Expr::Var(name) if !scope.contains_key(name) => {
+                        self.enums.get(name).map(|variants| (name, variants))
                     }
-                    Err(e) => {
-                        return Err(format!(
-                            "Error evaluating assignment for {}: {}",
-                            assign_stmt.name, e
-                        ));
+                    _ => None,
+                };
+                if let Some((name, variants)) = enum_variants {
+                    if !variants.contains(field_name) {
+                        return Err(format!("Enum '{}' has no variant '{}'", name, field_name));
                     }
+                    return Ok(Value::Enum(name.clone(), field_name.clone()));
+                }
+                match self.evalute_expr(base, scope)? {
+                    Value::StructInit(struct_name, fields) => fields
+                        .into_iter()
+                        .find(|(name, _)| name == field_name)
+                        .map(|(_, val)| val)
+                        .ok_or_else(|| {
+                            format!(
+                                "Field '{}' not found in struct '{}'",
+                                field_name, struct_name
+                            )
+                        }),
+                    other => Err(panic(format!(
+                        "TypeError: {} has no field '{}'",
+                        other.type_name(),
+                        field_name
+                    ))),
+                }
+            }
+            ast::Expr::StructInit(struct_name, field_exprs) => {
+                let struct_def = self
+                    .structs
+                    .get(struct_name)
+                    .cloned()
+                    .ok_or_else(|| format!("Undefined struct : {}", struct_name))?;
+                if let Some((name, _)) = field_exprs
+                    .iter()
+                    .find(|(name, _)| !struct_def.fields.iter().any(|f| f.ident == *name))
+                {
+                    return Err(format!(
+                        "Field '{}' not found in struct '{}'",
+                        name, struct_name
+                    ));
+                }
+                // fields left out of the literal take their default, else Int 0
+                let mut fields = Vec::new();
+                for field in &struct_def.fields {
+                    let given = field_exprs.iter().find(|(name, _)| *name == field.ident);
+                    let val = match (given, &field.default_value) {
+                        (Some((_, expr)), _) | (None, Some(expr)) => {
+                            self.evalute_expr(expr, scope)?
+                        }
+                        (None, None) => Value::Int(0),
+                    };
+                    fields.push((field.ident.clone(), val));
                 }
+                Ok(Value::StructInit(struct_name.clone(), fields))
             }
         }
     }
-    Ok(Value::Unit)
-}
 
-fn evalute_expr(
-    expr: &ast::Expr,
-    functions: &HashMap<&str, Callable>,
-    scope: &Scope,
-) -> Result<Value, String> {
-    match expr {
-        ast::Expr::Number(n) => Ok(Value::Int(*n)),
-        ast::Expr::Float(f) => Ok(Value::Float(*f)),
-        ast::Expr::TypeI8 => Ok(Value::TypeI8),
-        ast::Expr::TypeU8 => Ok(Value::TypeU8),
-        ast::Expr::TypeI16 => Ok(Value::TypeI16),
-        ast::Expr::TypeU16 => Ok(Value::TypeU16),
-        ast::Expr::TypeI32 => Ok(Value::TypeI32),
-        ast::Expr::TypeU32 => Ok(Value::TypeU32),
-        ast::Expr::TypeI64 => Ok(Value::TypeI64),
-        ast::Expr::TypeU64 => Ok(Value::TypeU64),
-        ast::Expr::TypeF16 => Ok(Value::TypeF16),
-        ast::Expr::TypeF32 => Ok(Value::TypeF32),
-        ast::Expr::TypeF64 => Ok(Value::TypeF64),
-        ast::Expr::Str(s) => Ok(Value::Str(s.clone())),
-        ast::Expr::Bool(b) => Ok(Value::Bool(*b)),
-        ast::Expr::Add(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-
-            match (left, right) {
-                (Value::Int(a), Value::Int(b)) => Ok(Value::Int(a + b)),
-                (Value::Str(a), Value::Str(b)) => Ok(Value::Str(a + &b)),
-                _ => Err("Type error in addition".to_string()),
-            }
-        }
-        ast::Expr::Mul(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            Ok(left * right)
-        }
-        ast::Expr::Minus(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            Ok(left - right)
-        }
-        ast::Expr::Div(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            Ok(left / right)
-        }
-        ast::Expr::Mod(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            match (left, right) {
-                (Value::Int(a), Value::Int(b)) => Ok(Value::Int(a % b)),
-                _ => Err("Type error in modulus operation".to_string()),
-            }
-        }
-        ast::Expr::Increment(expr) => {
-            if let ast::Expr::Var(ident) = &**expr {
-                if let Some(val) = scope.get(ident) {
-                    if let Value::Int(n) = val {
-                        let new_val = Value::Int(n + 1);
-                        println!("  Incrementing variable {}: {} -> {}", ident, n, n + 1);
-                        return Ok(new_val);
-                    }
+    fn arithmetic(
+        &mut self,
+        op: ArithOp,
+        lhs: &ast::Expr,
+        rhs: &ast::Expr,
+        scope: &mut Scope,
+    ) -> Result<Value, String> {
+        let left = self.evalute_expr(lhs, scope)?;
+        let right = self.evalute_expr(rhs, scope)?;
+        arithmetic(op, left, right)
+    }
+
+    fn compare(
+        &mut self,
+        symbol: &str,
+        lhs: &ast::Expr,
+        rhs: &ast::Expr,
+        scope: &mut Scope,
+        test: fn(std::cmp::Ordering) -> bool,
+    ) -> Result<Value, String> {
+        let left = self.evalute_expr(lhs, scope)?;
+        let right = self.evalute_expr(rhs, scope)?;
+        let ordering = match (&left, &right) {
+            (Value::Int(a), Value::Int(b)) => a.partial_cmp(b),
+            (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+            (Value::Char(a), Value::Char(b)) => a.partial_cmp(b),
+            (Value::Str(a), Value::Str(b)) => a.partial_cmp(b),
+            (Value::SizedInt(t1, a), Value::SizedInt(t2, b)) if t1 == t2 => {
+                decode(t1, *a).partial_cmp(&decode(t2, *b))
+            }
+            (Value::SizedFloat(t1, a), Value::SizedFloat(t2, b)) if t1 == t2 => a.partial_cmp(b),
+            _ => {
+                return Err(panic(format!(
+                    "TypeError: cannot compare {} and {} with '{}'",
+                    left.type_name(),
+                    right.type_name(),
+                    symbol
+                )));
+            }
+        };
+        Ok(Value::Bool(ordering.is_some_and(test)))
+    }
+
+    // `x++` and `x--`: the variable is updated and the expression is its new value.
+    fn step(&mut self, target: &ast::Expr, delta: i64, scope: &mut Scope) -> Result<Value, String> {
+        let ast::Expr::Var(ident) = target else {
+            return Err("Increment and decrement need a variable".to_string());
+        };
+        let val = scope
+            .get_mut(ident)
+            .ok_or_else(|| format!("Undefined variable: {}", ident))?;
+        *val = match &*val {
+            Value::Int(n) => Value::Int(n.wrapping_add(delta)),
+            Value::SizedInt(ty, n) => {
+                Value::SizedInt(ty.clone(), wrap_int(ty, decode(ty, *n) + delta as i128))
+            }
+            other => {
+                return Err(panic(format!(
+                    "TypeError: cannot increment or decrement a {}",
+                    other.type_name()
+                )));
+            }
+        };
+        Ok(val.clone())
+    }
+
+    fn call(
+        &mut self,
+        ident: &str,
+        args: &[ast::Expr],
+        scope: &mut Scope,
+    ) -> Result<Value, String> {
+        if ident.ends_with('!') {
+            return self.call_macro(ident, args, scope);
+        }
+
+        let mut arg_values = Vec::new();
+        for arg in args {
+            arg_values.push(self.evalute_expr(arg, scope)?);
+        }
+
+        // a function of a module calls the other functions of that module
+        let module_fn = self
+            .current_module
+            .as_ref()
+            .and_then(|name| self.modules.get(name))
+            .and_then(|module| module.functions.get(ident))
+            .cloned();
+        if let Some(func) = module_fn {
+            let module = self.current_module.clone();
+            return self.call_function(&func, module, arg_values);
+        }
+        if let Some(func) = self.functions.get(ident).cloned() {
+            return self.call_function(&func, None, arg_values);
+        }
+        if let Some(module_name) = self.imported.get(ident).cloned() {
+            return self.call_module(&module_name, ident, arg_values);
+        }
+        Err(format!("Undefined function: {}", ident))
+    }
+
+    fn call_module(
+        &mut self,
+        module_name: &str,
+        function_name: &str,
+        args: Vec<Value>,
+    ) -> Result<Value, String> {
+        let module_name = self
+            .aliases
+            .get(module_name)
+            .map(String::as_str)
+            .unwrap_or(module_name)
+            .to_string();
+        if self.packages.contains(&module_name) {
+            return call_package(self, &module_name, function_name, args);
+        }
+        let module = self
+            .modules
+            .get(&module_name)
+            .ok_or_else(|| format!("Module '{}' not found", module_name))?;
+        let func = module
+            .functions
+            .get(function_name)
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "Function '{}' not found in module '{}'",
+                    function_name, module_name
+                )
+            })?;
+        if !func.is_public && self.current_module.as_deref() != Some(module_name.as_str()) {
+            return Err(format!(
+                "Function '{}' is private to module '{}'",
+                function_name, module_name
+            ));
+        }
+        self.call_function(&func, Some(module_name), args)
+    }
+
+    fn call_macro(
+        &mut self,
+        ident: &str,
+        args: &[ast::Expr],
+        scope: &mut Scope,
+    ) -> Result<Value, String> {
+        // the type names of cast! and is! are not values
+        let type_arg = |index: usize| {
+            args.get(index).and_then(type_name_arg).ok_or_else(|| {
+                format!("{} argument {} must be a type identifier", ident, index + 1)
+            })
+        };
+        match ident {
+            "cast!" => {
+                expect_args(ident, args, 2)?;
+                let target = type_arg(1)?;
+                let val = self.evalute_expr(&args[0], scope)?;
+                return cast(val, target);
+            }
+            "is!" => {
+                expect_args(ident, args, 2)?;
+                let target = type_arg(1)?;
+                let val = self.evalute_expr(&args[0], scope)?;
+                return Ok(Value::Bool(val.type_name() == target));
+            }
+            "wrapping_add!" | "wrapping_sub!" | "wrapping_mul!" | "saturating_add!"
+            | "saturating_sub!" => {
+                expect_args(ident, args, 3)?;
+                let target = type_arg(2)?;
+                let a = self.evalute_expr(&args[0], scope)?;
+                let b = self.evalute_expr(&args[1], scope)?;
+                return int_arith_macro(ident, a, b, target);
+            }
+            "asm!" | "volatile_read!" | "volatile_write!" => {
+                return Err(format!(
+                    "{} needs compiled code and is not supported by the interpreter",
+                    ident
+                ));
+            }
+            _ => {}
+        }
+
+        let mut values = Vec::new();
+        for arg in args {
+            values.push(self.evalute_expr(arg, scope)?);
+        }
+        match ident {
+            "println!" => builtin::builtin_function_println(&values),
+            "list_push!" => builtin::builtin_function_push(&values),
+            "clone!" => {
+                expect_args(ident, args, 1)?;
+                Ok(deep_clone(&values[0]))
+            }
+            "type_of!" => {
+                expect_args(ident, args, 1)?;
+                Ok(Value::Str(values[0].type_name().to_string()))
+            }
+            "panic!" => Err(panic(values.first().cloned().unwrap_or(Value::Unit))),
+            "assert!" => {
+                if values.is_empty() || values.len() > 2 {
+                    return Err("assert! expects a condition and a message".to_string());
                 }
-                Err(format!("Variable {} not found or not an integer", ident))
-            } else {
-                Err("Increment operation requires a variable".to_string())
-            }
-        }
-        ast::Expr::Decrement(expr) => {
-            if let ast::Expr::Var(ident) = &**expr {
-                if let Some(val) = scope.get(ident) {
-                    if let Value::Int(n) = val {
-                        let new_val = Value::Int(n - 1);
-                        println!("  Decrementing variable {}: {} -> {}", ident, n, n - 1);
-                        return Ok(new_val);
+                let Value::Bool(holds) = values[0] else {
+                    return Err(panic("TypeError: assert! expects a Bool"));
+                };
+                if holds {
+                    return Ok(Value::Unit);
+                }
+                match values.get(1) {
+                    Some(message) => Err(panic(format!("AssertionError: {}", message))),
+                    None => Err(panic("AssertionError")),
+                }
+            }
+            "exit!" => {
+                expect_args(ident, args, 1)?;
+                let code = int_arg(ident, &values[0])?;
+                use std::io::Write;
+                let _ = std::io::stdout().flush();
+                std::process::exit(code as i32)
+            }
+            "now!" => Ok(Value::Int(rt::__clock_ms() as i64)),
+            "sleep!" => {
+                expect_args(ident, args, 1)?;
+                rt::__sleep_ms(int_arg(ident, &values[0])? as _);
+                Ok(Value::Unit)
+            }
+            "read_line!" => {
+                let mut line = String::new();
+                match std::io::stdin().read_line(&mut line) {
+                    Ok(0) | Err(_) => Ok(Value::Unit),
+                    Ok(_) => {
+                        let text = line.strip_suffix('\n').unwrap_or(&line);
+                        let text = text.strip_suffix('\r').unwrap_or(text);
+                        Ok(Value::Str(text.to_string()))
                     }
                 }
-                Err(format!("Variable {} not found or not an integer", ident))
-            } else {
-                Err("Decrement operation requires a variable".to_string())
             }
+            "rand!" => {
+                expect_args(ident, args, 2)?;
+                let lo = int_arg(ident, &values[0])?;
+                let hi = int_arg(ident, &values[1])?;
+                if hi <= lo {
+                    return Err(panic("ValueError: rand! range is empty"));
+                }
+                Ok(Value::Int(rt::__rand_range(lo as _, hi as _) as i64))
+            }
+            "rand_seed!" => {
+                expect_args(ident, args, 1)?;
+                rt::__rand_seed(int_arg(ident, &values[0])? as _);
+                Ok(Value::Unit)
+            }
+            "min!" | "max!" => {
+                expect_args(ident, args, 2)?;
+                let less = num_less(ident, &values[0], &values[1])?;
+                let pick_first = less == (ident == "min!");
+                Ok(values.swap_remove(if pick_first { 0 } else { 1 }))
+            }
+            "clamp!" => {
+                expect_args(ident, args, 3)?;
+                let (x, lo, hi) = (&values[0], &values[1], &values[2]);
+                if num_less(ident, x, lo)? {
+                    Ok(lo.clone())
+                } else if num_less(ident, hi, x)? {
+                    Ok(hi.clone())
+                } else {
+                    Ok(x.clone())
+                }
+            }
+            "abs!" => {
+                expect_args(ident, args, 1)?;
+                match &values[0] {
+                    Value::Int(n) => Ok(Value::Int(n.wrapping_abs())),
+                    Value::Float(f) => Ok(Value::Float(f.abs())),
+                    Value::SizedInt(ty, n) => Ok(Value::SizedInt(
+                        ty.clone(),
+                        wrap_int(ty, decode(ty, *n).abs()),
+                    )),
+                    Value::SizedFloat(ty, f) => Ok(Value::SizedFloat(ty.clone(), f.abs())),
+                    _ => Err(panic(format!("TypeError: {} expects numbers", ident))),
+                }
+            }
+            _ => Err(format!("Undefined function: {}", ident)),
         }
-        ast::Expr::Eq(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            Ok(Value::Bool(left == right))
+    }
+}
+
+fn expect_args(ident: &str, args: &[ast::Expr], count: usize) -> Result<(), String> {
+    if args.len() != count {
+        return Err(format!("{} expects {} arguments", ident, count));
+    }
+    Ok(())
+}
+
+fn int_arg(ident: &str, val: &Value) -> Result<i64, String> {
+    match val {
+        Value::Int(n) => Ok(*n),
+        Value::SizedInt(ty, n) => Ok(decode(ty, *n) as i64),
+        _ => Err(panic(format!("TypeError: {} expects integers", ident))),
+    }
+}
+
+// Integers compare as integers and a float on either side compares both as floats.
+fn num_less(ident: &str, a: &Value, b: &Value) -> Result<bool, String> {
+    let int = |val: &Value| match val {
+        Value::Int(n) => Some(*n as i128),
+        Value::SizedInt(ty, n) => Some(decode(ty, *n)),
+        _ => None,
+    };
+    let float = |val: &Value| match val {
+        Value::Float(f) | Value::SizedFloat(_, f) => Some(*f),
+        _ => int(val).map(|n| n as f64),
+    };
+    match (int(a), int(b)) {
+        (Some(a), Some(b)) => Ok(a < b),
+        _ => match (float(a), float(b)) {
+            (Some(a), Some(b)) => Ok(a < b),
+            _ => Err(panic(format!("TypeError: {} expects numbers", ident))),
+        },
+    }
+}
+
+fn new_list(values: Vec<Value>) -> Value {
+    Value::List(Rc::new(RefCell::new(values)))
+}
+
+// `clone!`: a list is copied with its elements, so pushing to the copy leaves the original.
+fn deep_clone(val: &Value) -> Value {
+    match val {
+        Value::List(elements) => new_list(elements.borrow().iter().map(deep_clone).collect()),
+        Value::StructInit(name, fields) => Value::StructInit(
+            name.clone(),
+            fields
+                .iter()
+                .map(|(field, val)| (field.clone(), deep_clone(val)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn arithmetic(op: ArithOp, left: Value, right: Value) -> Result<Value, String> {
+    let zero_division = || panic("ZeroDivisionError: division by zero");
+    match (op, left, right) {
+        (_, Value::Int(a), Value::Int(b)) => {
+            let result = match op {
+                ArithOp::Add => a.wrapping_add(b),
+                ArithOp::Sub => a.wrapping_sub(b),
+                ArithOp::Mul => a.wrapping_mul(b),
+                ArithOp::Div | ArithOp::Mod if b == 0 => return Err(zero_division()),
+                ArithOp::Div => a.wrapping_div(b),
+                ArithOp::Mod => a.wrapping_rem(b),
+            };
+            Ok(Value::Int(result))
         }
-        ast::Expr::Neq(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            Ok(Value::Bool(left != right))
+        (_, Value::Float(a), Value::Float(b)) => Ok(Value::Float(float_op(op, a, b))),
+        (_, Value::SizedInt(t1, a), Value::SizedInt(t2, b)) if t1 == t2 => {
+            let (a, b) = (decode(&t1, a), decode(&t2, b));
+            let result = match op {
+                ArithOp::Add => a + b,
+                ArithOp::Sub => a - b,
+                ArithOp::Mul => a.wrapping_mul(b),
+                ArithOp::Div | ArithOp::Mod if b == 0 => return Err(zero_division()),
+                ArithOp::Div => a / b,
+                ArithOp::Mod => a % b,
+            };
+            Ok(Value::SizedInt(t1.clone(), wrap_int(&t1, result)))
         }
-        ast::Expr::Lt(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            match (left, right) {
-                (Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a < b)),
-                _ => Err("Type error in less-than comparison".to_string()),
-            }
+        (_, Value::SizedFloat(t1, a), Value::SizedFloat(t2, b)) if t1 == t2 => Ok(
+            Value::SizedFloat(t1.clone(), round_float(&t1, float_op(op, a, b))),
+        ),
+        (ArithOp::Add, Value::Str(a), Value::Str(b)) => Ok(Value::Str(a + &b)),
+        (ArithOp::Add, Value::List(a), Value::List(b)) => {
+            let mut elements: Vec<Value> = a.borrow().iter().map(deep_clone).collect();
+            elements.extend(b.borrow().iter().map(deep_clone));
+            Ok(new_list(elements))
         }
-        ast::Expr::Gt(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            match (left, right) {
-                (Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a > b)),
-                _ => Err("Type error in greater-than comparison".to_string()),
+        (ArithOp::Mul, Value::Str(s), Value::Int(n)) => Ok(Value::Str(s.repeat(n.max(0) as usize))),
+        (ArithOp::Mul, Value::List(list), Value::Int(n)) => {
+            let list = list.borrow();
+            let mut elements = Vec::new();
+            for _ in 0..n.max(0) {
+                elements.extend(list.iter().map(deep_clone));
             }
+            Ok(new_list(elements))
         }
-        ast::Expr::Le(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            match (left, right) {
-                (Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a <= b)),
-                _ => Err("Type error in less-than-or-equal comparison".to_string()),
-            }
+        (op, left, right) => Err(panic(format!(
+            "TypeError: type miss match : '{}' {} '{}'",
+            left.type_name(),
+            op.symbol(),
+            right.type_name()
+        ))),
+    }
+}
+
+fn float_op(op: ArithOp, a: f64, b: f64) -> f64 {
+    match op {
+        ArithOp::Add => a + b,
+        ArithOp::Sub => a - b,
+        ArithOp::Mul => a * b,
+        ArithOp::Div => a / b,
+        ArithOp::Mod => a % b,
+    }
+}
+
+// Negative indices and bounds count from the end; a string counts characters.
+fn index_value(collection: Value, index: Value) -> Result<Value, String> {
+    match (collection, index) {
+        (Value::List(elements), Value::Range(start, end)) => {
+            let elements = elements.borrow();
+            let (from, to) = slice_bounds(start, end, elements.len())?;
+            Ok(new_list(
+                elements[from..to].iter().map(deep_clone).collect(),
+            ))
         }
-        ast::Expr::Ge(lhs, rhs) => {
-            let left = evalute_expr(lhs, functions, scope)?;
-            let right = evalute_expr(rhs, functions, scope)?;
-            match (left, right) {
-                (Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a >= b)),
-                _ => Err("Type error in greater-than-or-equal comparison".to_string()),
-            }
+        (Value::Str(s), Value::Range(start, end)) => {
+            let chars: Vec<char> = s.chars().collect();
+            let (from, to) = slice_bounds(start, end, chars.len())?;
+            Ok(Value::Str(chars[from..to].iter().collect()))
         }
-        ast::Expr::If(cond, then_expr, else_expr) => {
-            let condition = evalute_expr(cond, functions, scope)?;
-            if condition != Value::Int(0) {
-                evalute_expr(then_expr, functions, scope)
-            } else {
-                evalute_expr(else_expr, functions, scope)
-            }
+        (Value::List(elements), index) => {
+            let elements = elements.borrow();
+            let index = int_arg("indexing", &index)?;
+            Ok(elements[normalize_index(index, elements.len())?].clone())
         }
-        ast::Expr::Call(ident, args, _) => {
-            if let Some(func) = functions.get(ident.as_str()) {
-                let mut arg_values = Vec::new();
-                for arg in args {
-                    arg_values.push(evalute_expr(arg, functions, scope)?);
+        (Value::Str(s), index) => {
+            let chars: Vec<char> = s.chars().collect();
+            let index = int_arg("indexing", &index)?;
+            Ok(Value::Str(
+                chars[normalize_index(index, chars.len())?].to_string(),
+            ))
+        }
+        (other, _) => Err(panic(format!(
+            "TypeError: a {} cannot be indexed",
+            other.type_name()
+        ))),
+    }
+}
+
+fn normalize_index(index: i64, len: usize) -> Result<usize, String> {
+    let idx = if index < 0 { index + len as i64 } else { index };
+    if idx < 0 || idx as usize >= len {
+        return Err(panic(format!(
+            "Index out of bounds: {} (length {})",
+            index, len
+        )));
+    }
+    Ok(idx as usize)
+}
+
+fn slice_bounds(start: i64, end: i64, len: usize) -> Result<(usize, usize), String> {
+    let len = len as i64;
+    let from = if start < 0 { start + len } else { start };
+    let to = if end < 0 { end + len } else { end };
+    if from < 0 || to > len || from > to {
+        return Err(panic(format!(
+            "Slice out of bounds: {}..{} (length {})",
+            start, end, len
+        )));
+    }
+    Ok((from as usize, to as usize))
+}
+
+fn type_name_arg(expr: &ast::Expr) -> Option<&str> {
+    let name = match expr {
+        ast::Expr::Var(ident) => ident.as_str(),
+        ast::Expr::TypeI8 => "i8",
+        ast::Expr::TypeU8 => "u8",
+        ast::Expr::TypeI16 => "i16",
+        ast::Expr::TypeU16 => "u16",
+        ast::Expr::TypeI32 => "i32",
+        ast::Expr::TypeU32 => "u32",
+        ast::Expr::TypeI64 => "i64",
+        ast::Expr::TypeU64 => "u64",
+
+        ast::Expr::TypeF16 => "fp16",
+        ast::Expr::TypeF32 => "fp32",
+        ast::Expr::TypeF64 => "fp64",
+        _ => return None,
+    };
+    Some(name)
+}
+
+fn sized_type(name: &str) -> Option<Type> {
+    let ty = match name {
+        "i8" => Type::TypeI8,
+        "u8" => Type::TypeU8,
+        "i16" => Type::TypeI16,
+        "u16" => Type::TypeU16,
+        "i32" => Type::TypeI32,
+        "u32" => Type::TypeU32,
+        "i64" => Type::TypeI64,
+        "u64" => Type::TypeU64,
+        "fp16" => Type::TypeF16,
+        "fp32" => Type::TypeF32,
+        "fp64" => Type::TypeF64,
+        _ => return None,
+    };
+    Some(ty)
+}
+
+fn sized_name(ty: &Type) -> &'static str {
+    match ty {
+        Type::TypeI8 => "i8",
+        Type::TypeU8 => "u8",
+        Type::TypeI16 => "i16",
+        Type::TypeU16 => "u16",
+        Type::TypeI32 => "i32",
+        Type::TypeU32 => "u32",
+        Type::TypeI64 => "i64",
+        Type::TypeU64 => "u64",
+        Type::TypeF16 => "fp16",
+        Type::TypeF32 => "fp32",
+        _ => "fp64",
+    }
+}
+
+fn is_float_type(ty: &Type) -> bool {
+    matches!(ty, Type::TypeF16 | Type::TypeF32 | Type::TypeF64)
+}
+
+// The value of a sized integer; only a u64 needs its bits reinterpreted.
+fn decode(ty: &Type, bits: i64) -> i128 {
+    match ty {
+        Type::TypeU64 => bits as u64 as i128,
+        _ => bits as i128,
+    }
+}
+
+// Wraps around at the width of the type, as the compiled arithmetic does.
+fn wrap_int(ty: &Type, value: i128) -> i64 {
+    match ty {
+        Type::TypeI8 => value as i8 as i64,
+        Type::TypeU8 => value as u8 as i64,
+        Type::TypeI16 => value as i16 as i64,
+        Type::TypeU16 => value as u16 as i64,
+        Type::TypeI32 => value as i32 as i64,
+        Type::TypeU32 => value as u32 as i64,
+        Type::TypeU64 => value as u64 as i64,
+        _ => value as i64,
+    }
+}
+
+fn int_bounds(ty: &Type) -> (i128, i128) {
+    match ty {
+        Type::TypeI8 => (i8::MIN as i128, i8::MAX as i128),
+        Type::TypeU8 => (0, u8::MAX as i128),
+        Type::TypeI16 => (i16::MIN as i128, i16::MAX as i128),
+        Type::TypeU16 => (0, u16::MAX as i128),
+        Type::TypeI32 => (i32::MIN as i128, i32::MAX as i128),
+        Type::TypeU32 => (0, u32::MAX as i128),
+        Type::TypeU64 => (0, u64::MAX as i128),
+        _ => (i64::MIN as i128, i64::MAX as i128),
+    }
+}
+
+fn round_float(ty: &Type, value: f64) -> f64 {
+    match ty {
+        Type::TypeF16 => half::f16::from_f64(value).to_f64(),
+        Type::TypeF32 => value as f32 as f64,
+        _ => value,
+    }
+}
+
+fn cast(val: Value, target: &str) -> Result<Value, String> {
+    let failed = || {
+        panic(format!(
+            "ValueError: cast! could not convert the value to {}",
+            target
+        ))
+    };
+    let int = match &val {
+        Value::Int(n) => Some(*n as i128),
+        Value::SizedInt(ty, n) => Some(decode(ty, *n)),
+        _ => None,
+    };
+    let float = match &val {
+        Value::Float(f) | Value::SizedFloat(_, f) => Some(*f),
+        _ => None,
+    };
+    match target {
+        "Str" => match val {
+            Value::Str(_) => Ok(val),
+            Value::Int(_)
+            | Value::Float(_)
+            | Value::Bool(_)
+            | Value::Char(_)
+            | Value::SizedInt(_, _)
+            | Value::SizedFloat(_, _) => Ok(Value::Str(val.to_string())),
+            _ => Err(failed()),
+        },
+        "Int" => {
+            let value = match (&val, int, float) {
+                (Value::Str(s), _, _) => s.trim().parse::<i64>().ok(),
+                (Value::Bool(b), _, _) => Some(*b as i64),
+                (Value::Char(c), _, _) => Some(*c as i64),
+                (_, Some(n), _) => i64::try_from(n).ok(),
+                // truncated toward zero, NaN and values out of range fail
+                (_, _, Some(f))
+                    if f.is_finite() && f >= i64::MIN as f64 && f < -(i64::MIN as f64) =>
+                {
+                    Some(f as i64)
                 }
-                call_function(func, &arg_values, functions)
-            } else {
-                Err(format!("Function {} not found", ident))
-            }
-        }
-        ast::Expr::Var(ident) => {
-            if let Some(val) = scope.get(ident) {
-                println!("  Accessing variable {}: {}", val, ident);
-                Ok(val.clone())
-            } else {
-                Err(format!("Variable {} not found", ident))
-            }
-        }
-        ast::Expr::List(elements) => {
-            let mut list_values = Vec::new();
-            for elem in elements {
-                let val = evalute_expr(elem, functions, scope)?;
-                list_values.push(val.clone());
-                println!("  Added element to list: {}", val);
-            }
-            Ok(Value::List(std::rc::Rc::new(std::cell::RefCell::new(
-                list_values,
-            ))))
-        }
-        ast::Expr::Range(start_expr, end_expr) => {
-            let start_val = evalute_expr(start_expr, functions, scope)?;
-            let end_val = evalute_expr(end_expr, functions, scope)?;
-            if let (Value::Int(start), Value::Int(end)) = (start_val, end_val) {
-                Ok(Value::Range(start, end))
-            } else {
-                Err("Range bounds must be integers".to_string())
-            }
-        }
-        ast::Expr::Index(collection_expr, index_expr) => {
-            let collection_val = evalute_expr(collection_expr, functions, scope)?;
-            let index_val = evalute_expr(index_expr, functions, scope)?;
-            if let Value::List(ref elements_rc) = collection_val {
-                let elements = elements_rc.borrow();
-                if let Value::Int(index) = index_val {
-                    let idx = index as usize;
-                    if idx < elements.len() {
-                        Ok(elements[idx].clone())
-                    } else {
-                        Err("Index out of bounds".to_string())
+                _ => None,
+            };
+            value.map(Value::Int).ok_or_else(failed)
+        }
+        "Bool" => {
+            let value = match (&val, int, float) {
+                (Value::Str(s), _, _) => match s.as_str() {
+                    "true" => Some(true),
+                    "false" => Some(false),
+                    _ => None,
+                },
+                (Value::Bool(b), _, _) => Some(*b),
+                (_, Some(n), _) => Some(n != 0),
+                (_, _, Some(f)) => Some(f != 0.0),
+                _ => None,
+            };
+            value.map(Value::Bool).ok_or_else(failed)
+        }
+        "Char" => {
+            let value = match (&val, int) {
+                (Value::Char(c), _) => Some(*c),
+                (Value::Str(s), _) => {
+                    let mut chars = s.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => Some(c),
+                        _ => None,
                     }
-                } else {
-                    Err("Index must be an integer".to_string())
                 }
-            } else {
-                Err("Collection must be a list".to_string())
+                (_, Some(n)) => u32::try_from(n).ok().and_then(char::from_u32),
+                _ => None,
+            };
+            value.map(Value::Char).ok_or_else(failed)
+        }
+        _ => {
+            let ty =
+                sized_type(target).ok_or_else(|| format!("cast! to unknown type {}", target))?;
+            let number = match (int, float) {
+                (Some(n), _) => n as f64,
+                (_, Some(f)) => f,
+                _ => {
+                    return Err(panic(format!(
+                        "TypeError: cast! to {} expects a number, got {}",
+                        target,
+                        val.type_name()
+                    )));
+                }
+            };
+            if is_float_type(&ty) {
+                let value = round_float(&ty, number);
+                return Ok(Value::SizedFloat(ty, value));
             }
+            let value = match int {
+                Some(n) => n,
+                None => number as i128,
+            };
+            Ok(Value::SizedInt(ty.clone(), wrap_int(&ty, value)))
         }
-        ast::Expr::ModuleAccess(module_name, function_name, args) => {
-            // !TODO Implement module access
-            Err("Module access not implemented".to_string())
+    }
+}
+
+// wrapping_add!(a, b, u8) / saturating_add!(a, b, u8) and friends.
+fn int_arith_macro(ident: &str, a: Value, b: Value, target: &str) -> Result<Value, String> {
+    let ty = match sized_type(target) {
+        Some(ty) if !is_float_type(&ty) => ty,
+        _ => return Err(format!("{} needs an integer type, not {}", ident, target)),
+    };
+    let (a, b) = (int_arg(ident, &a)? as i128, int_arg(ident, &b)? as i128);
+    let a = decode(&ty, wrap_int(&ty, a));
+    let b = decode(&ty, wrap_int(&ty, b));
+    let result = match ident {
+        "wrapping_add!" | "saturating_add!" => a + b,
+        "wrapping_sub!" | "saturating_sub!" => a - b,
+        _ => a.wrapping_mul(b),
+    };
+    let value = if ident.starts_with("saturating") {
+        let (min, max) = int_bounds(&ty);
+        wrap_int(&ty, result.clamp(min, max))
+    } else {
+        wrap_int(&ty, result)
+    };
+    Ok(Value::SizedInt(ty, value))
+}
+
+// The built-in math, file and string packages.
+fn call_package(
+    ctx: &mut RuntimeContext,
+    package: &str,
+    function: &str,
+    args: Vec<Value>,
+) -> Result<Value, String> {
+    let arity = match package {
+        math::MODULE => math::arity(function).ok_or_else(|| math::unknown_function_error(function)),
+        file::MODULE => file::arity(function).ok_or_else(|| file::unknown_function_error(function)),
+        _ => string::arity(function).ok_or_else(|| string::unknown_function_error(function)),
+    }?;
+    if args.len() != arity {
+        return Err(format!(
+            "{}.{} expects {} arguments",
+            package, function, arity
+        ));
+    }
+    let name = format!("{}.{}", package, function);
+    match package {
+        math::MODULE => {
+            let mut numbers = Vec::new();
+            for arg in &args {
+                numbers.push(match arg {
+                    Value::Float(f) | Value::SizedFloat(_, f) => *f,
+                    Value::Int(n) => *n as f64,
+                    Value::SizedInt(ty, n) => decode(ty, *n) as f64,
+                    _ => return Err(panic(format!("TypeError: {} expects numbers", name))),
+                });
+            }
+            let x = numbers[0];
+            let result = match function {
+                "sqrt" => x.sqrt(),
+                "pow" => x.powf(numbers[1]),
+                "sin" => x.sin(),
+                "cos" => x.cos(),
+                "floor" => x.floor(),
+                _ => x.ceil(),
+            };
+            Ok(Value::Float(result))
         }
-        ast::Expr::FieldAccess(struct_expr, field_name) => {
-            let struct_val = evalute_expr(struct_expr, functions, scope)?;
-            // !TODO Implement field access for structs
-            Err(format!(
-                "Field access not implemented for field {}",
-                field_name
-            ))
+        file::MODULE => call_file(ctx, &name, function, args),
+        _ => {
+            let mut strs = Vec::new();
+            for arg in &args {
+                match arg {
+                    Value::Str(s) => strs.push(s.as_str()),
+                    _ => return Err(panic(format!("TypeError: {} expects a Str", name))),
+                }
+            }
+            let s = strs[0];
+            let result = match function {
+                "split" if strs[1].is_empty() => new_list(
+                    s.split_ascii_whitespace()
+                        .map(|part| Value::Str(part.to_string()))
+                        .collect(),
+                ),
+                "split" => new_list(
+                    s.split(strs[1])
+                        .map(|part| Value::Str(part.to_string()))
+                        .collect(),
+                ),
+                "trim" => Value::Str(s.trim_ascii().to_string()),
+                "to_upper" => Value::Str(s.to_ascii_uppercase()),
+                "to_lower" => Value::Str(s.to_ascii_lowercase()),
+                "contains" => Value::Bool(s.contains(strs[1])),
+                "find" => Value::Int(s.find(strs[1]).map_or(-1, |at| at as i64)),
+                _ => new_list(s.chars().map(Value::Char).collect()),
+            };
+            Ok(result)
         }
-        ast::Expr::Unit() => Ok(Value::Unit),
-        ast::Expr::StructInit(struct_name, fields) => {
-            let mut field_values = HashMap::new();
-            for (field_name, field_expr) in fields {
-                let val = evalute_expr(field_expr, functions, scope)?;
-                field_values.insert(field_name.clone(), val.clone());
-                println!("  Initialized field {}: {}", field_name, val);
+    }
+}
+
+fn call_file(
+    ctx: &mut RuntimeContext,
+    name: &str,
+    function: &str,
+    args: Vec<Value>,
+) -> Result<Value, String> {
+    use std::io::{Read, Write};
+
+    if function == "open" {
+        let (Value::Str(path), Value::Str(mode)) = (&args[0], &args[1]) else {
+            return Err(panic(format!("TypeError: {} expects a Str", name)));
+        };
+        let mut options = std::fs::OpenOptions::new();
+        match mode.as_str() {
+            "r" => options.read(true),
+            "w" => options.write(true).create(true).truncate(true),
+            "a" => options.append(true).create(true),
+            _ => {
+                return Err(panic(format!(
+                    "IOError: file.open mode must be \"r\", \"w\" or \"a\", not \"{}\"",
+                    mode
+                )));
+            }
+        };
+        let file = options
+            .open(path)
+            .map_err(|e| panic(format!("IOError: could not open {}: {}", path, e)))?;
+        let index = match ctx.files.iter().position(Option::is_none) {
+            Some(index) => {
+                ctx.files[index] = Some(file);
+                index
             }
-            Ok(Value::StructInit(struct_name.clone(), field_values))
+            None => {
+                ctx.files.push(Some(file));
+                ctx.files.len() - 1
+            }
+        };
+        return Ok(Value::Int(index as i64 + 1));
+    }
+
+    let handle = int_arg(name, &args[0])?;
+    let slot = usize::try_from(handle - 1)
+        .ok()
+        .and_then(|index| ctx.files.get_mut(index))
+        .filter(|slot| slot.is_some())
+        .ok_or_else(|| {
+            panic(format!(
+                "IOError: {} got a file that is not open ({})",
+                name, handle
+            ))
+        })?;
+    match function {
+        "read_all" => {
+            let mut text = String::new();
+            slot.as_mut()
+                .unwrap()
+                .read_to_string(&mut text)
+                .map_err(|e| panic(format!("IOError: could not read the file: {}", e)))?;
+            Ok(Value::Str(text))
+        }
+        "write" => {
+            let Value::Str(text) = &args[1] else {
+                return Err(panic(format!("TypeError: {} expects a Str", name)));
+            };
+            slot.as_mut()
+                .unwrap()
+                .write_all(text.as_bytes())
+                .map_err(|e| panic(format!("IOError: could not write the file: {}", e)))?;
+            Ok(Value::Unit)
+        }
+        _ => {
+            *slot = None;
+            Ok(Value::Unit)
         }
     }
 }
@@ -1,6 +1,8 @@
 // interpreter currently not support yet, for now this file set a allowed unused
 #![allow(unused)]
 
+use crate::front::ast::Span;
+use crate::front::diagnostics::Diagnostic;
 use crate::front::lexer;
 use crate::grammar;
 use crate::interpreter::executer;
@@ -31,13 +33,18 @@ pub fn debug_run(input: &str) -> () {
 pub fn parse_run(input: &str) -> Result<(), String> {
     // Parse the input
     let mut lex = lexer::Lexer::new(input);
-    let items = match grammar::StartParser::new().parse(&mut lex) {
-        Ok(items) => items,
+    let mut errors = Vec::new();
+    let items: Vec<_> = match grammar::StartParser::new().parse(&mut errors, &mut lex) {
+        Ok(items) => items.into_iter().map(|(item, _)| item).collect(),
         Err(e) => {
             eprintln!("Error parsing input: {:?}", e);
             return Err(format!("Error parsing input: {:?}", e));
         }
     };
+    if let Some(recovery) = errors.first() {
+        eprintln!("Error parsing input: {:?}", recovery.error);
+        return Err(format!("Error parsing input: {:?}", recovery.error));
+    }
 
     let sigs = sema_builder::collect_signatures(&items);
     if sigs.is_empty() {
@@ -63,10 +70,11 @@ pub fn parse_run(input: &str) -> Result<(), String> {
     };
 
     println!("Entry function: {}", entry.name);
+    let entry_ix = entry.ix;
 
     let mut ctx = executer::RuntimeContext::new();
 
-    match executer::execute(&mut ctx, &items, entry.ix) {
+    match executer::execute(&mut ctx, items, entry_ix) {
         Ok(_) => println!("Execution completed successfully."),
         Err(e) => {
             eprintln!("Error during execution: {}", e);
@@ -78,12 +86,45 @@ pub fn parse_run(input: &str) -> Result<(), String> {
 }
 
 pub fn parse_only(input: &str, file_path: &str) -> Result<Vec<crate::front::ast::Item>, String> {
-    let mut lex = lexer::Lexer::new(input);
-    match grammar::StartParser::new().parse(&mut lex) {
-        Ok(items) => Ok(items),
+    parse_module(input, file_path, lexer::Edition::default()).map_err(|diagnostics| {
+        diagnostics
+            .iter()
+            .map(|d| d.render(Some(input), false))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+// Parses a whole module, recovering at `;` so every syntax error is reported.
+pub fn parse_module(
+    input: &str,
+    file_path: &str,
+    edition: lexer::Edition,
+) -> Result<Vec<crate::front::ast::Item>, Vec<Diagnostic>> {
+    parse_module_spanned(input, file_path, edition)
+        .map(|items| items.into_iter().map(|(item, _)| item).collect())
+}
+
+// `parse_module`, keeping the span of every item.
+pub fn parse_module_spanned(
+    input: &str,
+    file_path: &str,
+    edition: lexer::Edition,
+) -> Result<Vec<(crate::front::ast::Item, Span)>, Vec<Diagnostic>> {
+    let mut lex = lexer::Lexer::with_edition(input, edition);
+    let mut errors = Vec::new();
+    let result = grammar::StartParser::new().parse(&mut errors, &mut lex);
+
+    let mut diagnostics: Vec<Diagnostic> = errors
+        .into_iter()
+        .map(|recovery| error_helper::parse_error_diagnostic(file_path, input, recovery.error))
+        .collect();
+    match result {
+        Ok(items) if diagnostics.is_empty() => Ok(items),
+        Ok(_) => Err(diagnostics),
         Err(e) => {
-            let error_message = error_helper::format_parse_error(input, file_path, e);
-            Err(error_message)
+            diagnostics.push(error_helper::parse_error_diagnostic(file_path, input, e));
+            Err(diagnostics)
         }
     }
 }
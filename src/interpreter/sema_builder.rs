@@ -47,6 +47,7 @@ pub fn collect_signatures(items: &[ast::Item]) -> Vec<ItemSig> {
             ast::Item::Package(_) => None,
             ast::Item::EnumItem(_) => None,
             ast::Item::StructItem(_) => None,
+            ast::Item::ExternFnItem(_) => None,
         })
         .collect();
 
@@ -91,12 +92,12 @@ pub fn collect_vardecls_in_block<'a>(
     out: &mut Vec<(&'a str, &'a ast::VarDecl)>,
 ) {
     for stmt in stmts {
-        match stmt {
-            ast::Stmt::Var(var) => {
+        match &stmt.kind {
+            ast::StmtKind::Var(var) => {
                 out.push((item_name, var));
             }
-            &ast::Stmt::Expr(_) => {}
-            ast::Stmt::If {
+            &ast::StmtKind::Expr(_) => {}
+            ast::StmtKind::If {
                 cond,
                 then_blk,
                 else_blk,
@@ -107,13 +108,14 @@ pub fn collect_vardecls_in_block<'a>(
                     collect_vardecls_in_block(else_blk, item_name, out);
                 }
             }
-            ast::Stmt::While { cond, body } => {
+            ast::StmtKind::While { cond, body } => {
                 _ = cond; // ignore condition
                 collect_vardecls_in_block(body, item_name, out);
             }
-            ast::Stmt::Return(_) => {}
-            ast::Stmt::EnumItem(_) => {}
-            &ast::Stmt::Assign(_) => {}
+            ast::StmtKind::Return(_) => {}
+            ast::StmtKind::EnumItem(_) => {}
+            &ast::StmtKind::Assign(_) => {}
+            &ast::StmtKind::MultiAssign(_) => {}
         }
     }
 }
@@ -133,14 +135,14 @@ pub fn build_var_table<'a>(items: &'a [ast::Item], sigs: &[ItemSig]) -> VarTable
 
 fn collect_varinfo_in_block<'a>(stmts: &'a [ast::Stmt], table: &mut Vec<VarInfo<'a>>) {
     for stmt in stmts {
-        match stmt {
-            ast::Stmt::Var(var) => table.push(VarInfo {
+        match &stmt.kind {
+            ast::StmtKind::Var(var) => table.push(VarInfo {
                 decl: var,
                 ty_hint: infer_type_hint(&var.expr.as_ref().unwrap_or(&ast::Expr::Number(0)), &[])
                     .unwrap_or(Type::Any),
             }),
-            ast::Stmt::Expr(_) => {}
-            ast::Stmt::If {
+            ast::StmtKind::Expr(_) => {}
+            ast::StmtKind::If {
                 cond: _,
                 then_blk,
                 else_blk,
@@ -150,23 +152,24 @@ fn collect_varinfo_in_block<'a>(stmts: &'a [ast::Stmt], table: &mut Vec<VarInfo<
                     collect_varinfo_in_block(else_blk, table);
                 }
             }
-            ast::Stmt::While { cond: _, body } => {
+            ast::StmtKind::While { cond: _, body } => {
                 collect_varinfo_in_block(body, table);
             }
-            ast::Stmt::Return(_) => {}
-            ast::Stmt::EnumItem(_) => {}
-            ast::Stmt::Assign(_) => {}
+            ast::StmtKind::Return(_) => {}
+            ast::StmtKind::EnumItem(_) => {}
+            ast::StmtKind::Assign(_) => {}
+            ast::StmtKind::MultiAssign(_) => {}
         }
     }
 }
 
 fn infer_return_type_from_block(stmts: &[ast::Stmt]) -> Type {
     for stmt in stmts {
-        match stmt {
-            ast::Stmt::Return(Some(expr)) => {
+        match &stmt.kind {
+            ast::StmtKind::Return(Some(expr)) => {
                 return infer_type_hint(&expr, &[]).unwrap_or(Type::Any);
             }
-            ast::Stmt::If {
+            ast::StmtKind::If {
                 then_blk, else_blk, ..
             } => {
                 let then_ty = infer_return_type_from_block(then_blk);
@@ -204,26 +207,28 @@ fn infer_type_hint(expr: &ast::Expr, sigs: &[ItemSig]) -> Option<Type> {
         TypeF64 => Some(Type::TypeF64),
         Bool(_) => Some(Type::Bool),
         Str(_) => Some(Type::Str),
+        Char(_) => Some(Type::Any),
         Var(_) => Some(Type::Any),
-        Add(left, right) | Mul(left, right) => {
+        Add(left, right, _) | Mul(left, right, _) => {
             match (infer_type_hint(left, sigs), infer_type_hint(right, sigs)) {
                 (Some(Type::Int), Some(Type::Int)) => Some(Type::Int),
                 _ => None,
             }
         }
-        Minus(left, right) | Div(left, right) | Mod(left, right) => {
+        Minus(left, right, _) | Div(left, right, _) | Mod(left, right, _) => {
             match (infer_type_hint(left, sigs), infer_type_hint(right, sigs)) {
                 (Some(Type::Int), Some(Type::Int)) => Some(Type::Int),
                 _ => None,
             }
         }
         Increment(expr) | Decrement(expr) => infer_type_hint(expr, sigs),
-        Eq(_, _) => Some(Type::Bool),
-        Neq(_, _) => Some(Type::Bool),
-        Lt(_, _) => Some(Type::Bool),
-        Gt(_, _) => Some(Type::Bool),
-        Le(_, _) => Some(Type::Bool),
-        Ge(_, _) => Some(Type::Bool),
+        Eq(_, _, _) => Some(Type::Bool),
+        Neq(_, _, _) => Some(Type::Bool),
+        Lt(_, _, _) => Some(Type::Bool),
+        Gt(_, _, _) => Some(Type::Bool),
+        Le(_, _, _) => Some(Type::Bool),
+        Ge(_, _, _) => Some(Type::Bool),
+        In(_, _, _) => Some(Type::Bool),
         If(_, then_expr, else_expr) => {
             let then_type = infer_type_hint(then_expr, sigs)?;
             let else_type = infer_type_hint(else_expr, sigs)?;
@@ -233,13 +238,13 @@ fn infer_type_hint(expr: &ast::Expr, sigs: &[ItemSig]) -> Option<Type> {
                 None
             }
         }
-        Call(ident, args, ret_ty) => sigs
+        Call(ident, args, ret_ty, _) => sigs
             .iter()
             .find(|sig| sig.name == *ident)
             .map(|sig| sig.ret_ty.clone()),
         List(_) => Some(Type::Any), // Currently, we treat all lists as Any type
         Range(_, _) => Some(Type::Any),
-        Index(_, _) => Some(Type::Any),
+        Index(_, _, _) => Some(Type::Any),
         ModuleAccess(_, _, _) => Some(Type::Any), // !TODO Implement module access type inference
         FieldAccess(_, _) => Some(Type::Any),     // !TODO Implement field access type inference
         Unit() => Some(Type::Unit),
@@ -0,0 +1,22 @@
+//! The Sprs compiler as a library, for Rust drivers that add their own builtins.
+//! `front` parses Sprs source into the AST and `llvm` compiles it: implement
+//! `llvm::plugin::MacroPlugin` and build with `llvm::llvm_executer::build_and_run_with_macros`,
+//! passing the `command_helper::BuildOptions` of the build. The `sprs` binary is built on the
+//! same modules.
+
+pub mod command_helper;
+pub mod front;
+pub mod llvm;
+
+// The command line of the `sprs` binary.
+#[doc(hidden)]
+pub mod cli;
+#[doc(hidden)]
+pub mod crash_report;
+#[doc(hidden)]
+pub mod repl;
+
+mod grammar;
+mod interpreter;
+mod runtime;
+mod vendor;
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use inkwell::module::Module;
+
+// Optimized artifacts of each module under <out_dir>/.sprs-cache, so a rebuild only runs the
+// LLVM passes and code generation for modules that changed. The key hashes the module's IR
+// before optimization, which covers its source and the declarations it imports from other
+// modules, together with the flags that change the generated code and the compiler build.
+pub struct BuildCache {
+    dir: PathBuf,
+    prune: bool, // drop the older entries of a module when storing a new one
+}
+
+impl BuildCache {
+    pub fn new(out_dir: &str) -> Self {
+        BuildCache {
+            dir: Path::new(out_dir).join(".sprs-cache"),
+            prune: true,
+        }
+    }
+
+    // The cache of a workspace, used by all of its members. Two members can have different
+    // modules of the same name (each has a `main`), so no entry is dropped for being older.
+    pub fn shared(dir: &str) -> Self {
+        BuildCache {
+            dir: PathBuf::from(dir),
+            prune: false,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // `flags` is everything besides the IR that goes into the object: target, -O, --debug, ...
+    pub fn key(module: &Module<'_>, flags: &str) -> u64 {
+        let mut hasher = Fnv1a::new();
+        hasher.write(module.print_to_string().to_string().as_bytes());
+        hasher.write(flags.as_bytes());
+        hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.write(&compiler_build().to_le_bytes());
+        hasher.finish()
+    }
+
+    fn entry(&self, name: &str, key: u64, extension: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}-{:016x}.{}", name, key, extension))
+    }
+
+    // Copies the cached `extensions` of the module to `<out_dir>/<name>.<ext>`; false when
+    // any of them is missing, and then the module has to be compiled.
+    pub fn restore(&self, name: &str, key: u64, extensions: &[&str], out_dir: &str) -> bool {
+        if extensions
+            .iter()
+            .any(|ext| !self.entry(name, key, ext).exists())
+        {
+            return false;
+        }
+        extensions.iter().all(|ext| {
+            std::fs::copy(
+                self.entry(name, key, ext),
+                format!("{}/{}.{}", out_dir, name, ext),
+            )
+            .is_ok()
+        })
+    }
+
+    // Keeps `<out_dir>/<name>.<ext>` for the next build and drops older entries of the module.
+    pub fn store(&self, name: &str, key: u64, extensions: &[&str], out_dir: &str) {
+        if std::fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        let current = format!("{}-{:016x}.", name, key);
+        let entries = std::fs::read_dir(&self.dir).ok().filter(|_| self.prune);
+        if let Some(entries) = entries {
+            for entry in entries.flatten() {
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                let Some(rest) = file_name.strip_prefix(&format!("{}-", name)) else {
+                    continue;
+                };
+                // `<16 hex digits>.<ext>`, so `drivers-uart-...` is not taken for `drivers`
+                let is_entry = rest.get(16..17) == Some(".")
+                    && rest[..16].chars().all(|c| c.is_ascii_hexdigit());
+                if is_entry && !file_name.starts_with(&current) {
+                    let _ = std::fs::remove_file(entry.path());
+                }
+            }
+        }
+        for ext in extensions {
+            let _ = std::fs::copy(
+                format!("{}/{}.{}", out_dir, name, ext),
+                self.entry(name, key, ext),
+            );
+        }
+    }
+}
+
+// Modification time of the sprs binary, so a rebuilt compiler (a dev build keeps its version)
+// does not reuse what an older one generated. 0 when it can't be read.
+fn compiler_build() -> u128 {
+    std::env::current_exe()
+        .and_then(|exe| exe.metadata())
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|since| since.as_nanos())
+        .unwrap_or(0)
+}
+
+// 64-bit FNV-1a. Keys are stored on disk, so the hash has to stay the same across Rust
+// releases, which `DefaultHasher` does not promise.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    // Each part is prefixed with its length, so ("ab", "c") and ("a", "bc") differ.
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
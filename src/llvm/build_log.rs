@@ -0,0 +1,53 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+// What a build prints besides its errors: `-q` nothing when it succeeds, `-v` also the time
+// of every phase (parse, codegen of each module, optimize, link) and the commands it runs.
+// Errors and warnings are printed at every level.
+
+#[derive(Clone, Copy, PartialEq, PartialOrd, Default, Debug)]
+pub enum Verbosity {
+    Quiet,
+    #[default]
+    Normal,
+    Verbose,
+}
+
+#[derive(Clone, Copy)]
+pub struct BuildLog {
+    verbosity: Verbosity,
+}
+
+impl BuildLog {
+    pub fn new(verbosity: Verbosity) -> Self {
+        BuildLog { verbosity }
+    }
+
+    // Progress and artifacts: "Generated: ...", "Linking...".
+    pub fn status(&self, message: impl Display) {
+        if self.verbosity >= Verbosity::Normal {
+            println!("{}", message);
+        }
+    }
+
+    // On stderr, so `sprs run` and `sprs test --examples` don't read it as program output.
+    pub fn warning(&self, message: impl Display) {
+        eprintln!("[Warning] {}", message);
+    }
+
+    // Only at `-v`.
+    pub fn verbose(&self, message: impl Display) {
+        if self.verbosity >= Verbosity::Verbose {
+            println!("{}", message);
+        }
+    }
+
+    // At `-v`, how long `phase` took since `started`.
+    pub fn timed(&self, phase: impl Display, started: Instant) {
+        self.verbose(format_args!(
+            "[{:>9.2} ms] {}",
+            started.elapsed().as_secs_f64() * 1000.0,
+            phase
+        ));
+    }
+}
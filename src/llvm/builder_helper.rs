@@ -3,16 +3,67 @@ use core::error;
 use inkwell::{
     AddressSpace,
     builder::Builder,
+    intrinsics::Intrinsic,
     module::Linkage,
-    values::{BasicValueEnum, FunctionValue, IntValue, PointerValue, ValueKind},
+    types::IntType,
+    values::{
+        BasicValueEnum, FloatValue, FunctionValue, GlobalValue, IntValue, PointerValue, ValueKind,
+    },
 };
 
 use crate::{
     front::ast,
-    llvm::compiler::{Compiler, StoreTag, StoreValue, Tag},
+    front::cst::TokenStream,
+    front::lexer::Token,
+    interpreter::type_helper::Type,
+    llvm::compiler::{Compiler, ExternSig, OS, StoreTag, StoreValue, Tag},
+    llvm::error_helper,
+    llvm::file,
+    llvm::math,
+    llvm::panic_table::PanicSite,
+    llvm::rodata,
+    llvm::string,
 };
 
+// Bytes of stack reserved for each non-escaping string concatenation.
+const STACK_STRING_BUFFER_SIZE: u64 = 256;
+
 // !support functions
+
+// f64 payloads fill a 64-bit data field. AVR rejects f64 before codegen, but the dynamic
+// float paths are still emitted there, so they widen/narrow through i64 to type-check.
+pub fn data_to_f64<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    data: IntValue<'ctx>,
+    name: &str,
+) -> FloatValue<'ctx> {
+    let bits = self_compiler
+        .builder
+        .build_int_z_extend(data, self_compiler.context.i64_type(), name)
+        .unwrap();
+    self_compiler
+        .builder
+        .build_bit_cast(bits, self_compiler.context.f64_type(), name)
+        .unwrap()
+        .into_float_value()
+}
+
+pub fn f64_to_data<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    value: FloatValue<'ctx>,
+    name: &str,
+) -> IntValue<'ctx> {
+    let bits = self_compiler
+        .builder
+        .build_bit_cast(value, self_compiler.context.i64_type(), name)
+        .unwrap()
+        .into_int_value();
+    self_compiler
+        .builder
+        .build_int_truncate(bits, self_compiler.data_type(), name)
+        .unwrap()
+}
+
 pub struct PanicErrorSettings {
     pub is_const: bool,
     pub is_global: bool,
@@ -23,31 +74,36 @@ pub fn create_panic_err<'ctx>(
     module: &inkwell::module::Module<'ctx>,
     settings: PanicErrorSettings,
 ) -> Result<(), String> {
-    let global = if let Some(existing) = self_compiler.string_constants.get(message) {
-        *existing
+    if self_compiler.options.panic_codes {
+        return create_panic_code(self_compiler, message, module);
+    }
+
+    let location = current_location(self_compiler, module);
+    let message = if location.is_empty() {
+        message.to_string()
+    } else {
+        format!("{} {}", message, location)
+    };
+    let message = message.as_str();
+
+    let str_ptr = if settings.is_const {
+        string_literal_ptr(self_compiler, message, module)?
     } else {
         let str_val = self_compiler.context.const_string(message.as_bytes(), true);
         let global = module.add_global(
             str_val.get_type(),
             Some(AddressSpace::default()),
-            &format!("panic_err_{}", self_compiler.string_constants.len()),
+            "panic_err",
         );
         global.set_initializer(&str_val);
-        if settings.is_const {
-            global.set_constant(true);
-        }
         if settings.is_global {
             global.set_linkage(Linkage::External);
         } else {
             global.set_linkage(Linkage::Internal);
         }
-        self_compiler
-            .string_constants
-            .insert(message.to_string(), global);
-        global
+        global.as_pointer_value()
     };
 
-    let str_ptr = global.as_pointer_value();
     let str_ptr_i8 = self_compiler.builder.build_bit_cast(
         str_ptr,
         self_compiler.context.ptr_type(AddressSpace::default()),
@@ -62,9 +118,160 @@ pub fn create_panic_err<'ctx>(
     Ok(())
 }
 
+// `fn panic_handler(msg)` in main.sprs: `main` registers a trampoline that wraps the runtime's
+// C string in a Str value and calls the handler, which then runs instead of the message print.
+pub fn install_panic_handler<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    handler: FunctionValue<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(), String> {
+    if handler.count_params() != 1 {
+        return Err(format!(
+            "panic_handler must take exactly one parameter (the message), found {}",
+            handler.count_params()
+        ));
+    }
+
+    let saved_block = self_compiler.builder.get_insert_block();
+    let ptr_type = self_compiler.context.ptr_type(AddressSpace::default());
+    let trampoline = module.add_function(
+        "__sprs_panic_handler",
+        self_compiler
+            .context
+            .void_type()
+            .fn_type(&[ptr_type.into()], false),
+        Some(Linkage::Private),
+    );
+    let entry = self_compiler
+        .context
+        .append_basic_block(trampoline, "entry");
+    self_compiler.builder.position_at_end(entry);
+
+    let message = trampoline.get_nth_param(0).unwrap().into_pointer_value();
+    // The Str points at the runtime's stack buffer: the heap may be what ran out, and dropping
+    // a Str doesn't free it. Arguments are pointers to runtime values, see compile_call_args
+    let message_ptr = create_entry_block_alloca(self_compiler, "panic_message_arg");
+    self_compiler.build_runtime_value_store(
+        message_ptr,
+        StoreTag::Int(Tag::String as u64),
+        StoreValue::Ptr(message),
+        "panic_message",
+    );
+    self_compiler
+        .builder
+        .build_call(handler, &[message_ptr.into()], "call_panic_handler")
+        .unwrap();
+    self_compiler.builder.build_return(None).unwrap();
+
+    if let Some(block) = saved_block {
+        self_compiler.builder.position_at_end(block);
+    }
+    let set_fn = self_compiler.get_runtime_fn(module, "__set_panic_handler");
+    self_compiler
+        .builder
+        .build_call(
+            set_fn,
+            &[trampoline.as_global_value().as_pointer_value().into()],
+            "",
+        )
+        .unwrap();
+    Ok(())
+}
+
+// `--panic-codes`: the message goes into the off-target panic table and only its 16-bit code
+// is passed to `__panic_code`. Sites with the same message and location share a code.
+fn create_panic_code<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    message: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(), String> {
+    let (location, function) = current_position(self_compiler, module);
+    let site = PanicSite {
+        message: message.to_string(),
+        location,
+        function,
+    };
+    let code = match self_compiler.panic_sites.iter().position(|s| *s == site) {
+        Some(idx) => idx + 1,
+        None => {
+            self_compiler.panic_sites.push(site);
+            self_compiler.panic_sites.len()
+        }
+    };
+    if code > u16::MAX as usize {
+        return Err("Too many panic sites for 16-bit panic codes".to_string());
+    }
+
+    let code_val = self_compiler
+        .context
+        .i16_type()
+        .const_int(code as u64, false);
+    let panic_fn = self_compiler.get_runtime_fn(module, "__panic_code");
+    self_compiler
+        .builder
+        .build_call(panic_fn, &[code_val.into()], "panic_call")
+        .unwrap();
+    Ok(())
+}
+
 fn create_entry_block_alloca<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
     name: &str,
+) -> PointerValue<'ctx> {
+    if let Some(slot) = next_temp_slot(self_compiler, name) {
+        return slot;
+    }
+    let value_type = self_compiler.runtime_value_type.into();
+    create_entry_block_alloca_of(self_compiler, value_type, name)
+}
+
+// In a spilled function a temporary takes the next slot of the frame's temp array instead of
+// an alloca of its own. The address is computed in the entry block, so it can be used anywhere
+// in the function like an alloca.
+fn next_temp_slot<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    name: &str,
+) -> Option<PointerValue<'ctx>> {
+    let current_block = self_compiler.builder.get_insert_block()?;
+    let frame = self_compiler.frame.as_mut()?;
+    // e.g. the panic handler trampoline, built while compiling `main`
+    if current_block.get_parent() != Some(frame.function) {
+        return None;
+    }
+    let index = frame.temp_next;
+    frame.temp_next += 1;
+    frame.temp_count = frame.temp_count.max(frame.temp_next);
+    let temps = frame.temps;
+
+    let builder = &self_compiler.builder;
+    match temps
+        .as_instruction()
+        .and_then(|i| i.get_next_instruction())
+    {
+        Some(after_temps) => builder.position_before(&after_temps),
+        None => builder.position_at_end(current_block),
+    }
+    let slot = unsafe {
+        builder
+            .build_in_bounds_gep(
+                self_compiler.runtime_value_type,
+                temps,
+                &[self_compiler
+                    .context
+                    .i32_type()
+                    .const_int(index as u64, false)],
+                &format!("{}_temp_slot", name),
+            )
+            .unwrap()
+    };
+    builder.position_at_end(current_block);
+    Some(slot)
+}
+
+fn create_entry_block_alloca_of<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    ty: inkwell::types::BasicTypeEnum<'ctx>,
+    name: &str,
 ) -> PointerValue<'ctx> {
     let builder = &self_compiler.builder;
     let current_block = builder.get_insert_block().unwrap();
@@ -77,16 +284,277 @@ fn create_entry_block_alloca<'ctx>(
     }
 
     let alloca = builder
-        .build_alloca(
-            self_compiler.runtime_value_type,
-            format!("{}_var_alloca", name).as_str(),
-        )
+        .build_alloca(ty, format!("{}_var_alloca", name).as_str())
         .unwrap();
 
     builder.position_at_end(current_block);
     alloca
 }
 
+#[derive(Clone, Copy)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+// Integer add/sub/mul. With `--overflow-checks` this goes through the
+// llvm.*.with.overflow intrinsics and panics instead of wrapping.
+pub fn build_int_arith<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    op: ArithOp,
+    l_val: IntValue<'ctx>,
+    r_val: IntValue<'ctx>,
+    is_signed: bool,
+    module: &inkwell::module::Module<'ctx>,
+    name: &str,
+) -> Result<IntValue<'ctx>, String> {
+    if !self_compiler.options.overflow_checks {
+        let result = match op {
+            ArithOp::Add => self_compiler.builder.build_int_add(l_val, r_val, name),
+            ArithOp::Sub => self_compiler.builder.build_int_sub(l_val, r_val, name),
+            ArithOp::Mul => self_compiler.builder.build_int_mul(l_val, r_val, name),
+        };
+        return Ok(result.unwrap());
+    }
+
+    let (intrinsic_name, op_str) = match (op, is_signed) {
+        (ArithOp::Add, true) => ("llvm.sadd.with.overflow", "+"),
+        (ArithOp::Add, false) => ("llvm.uadd.with.overflow", "+"),
+        (ArithOp::Sub, true) => ("llvm.ssub.with.overflow", "-"),
+        (ArithOp::Sub, false) => ("llvm.usub.with.overflow", "-"),
+        (ArithOp::Mul, true) => ("llvm.smul.with.overflow", "*"),
+        (ArithOp::Mul, false) => ("llvm.umul.with.overflow", "*"),
+    };
+
+    let intrinsic = Intrinsic::find(intrinsic_name)
+        .ok_or_else(|| format!("Intrinsic not found: {}", intrinsic_name))?;
+    let intrinsic_fn = intrinsic
+        .get_declaration(module, &[l_val.get_type().into()])
+        .ok_or_else(|| format!("Failed to declare intrinsic: {}", intrinsic_name))?;
+
+    let call_site = self_compiler
+        .builder
+        .build_call(
+            intrinsic_fn,
+            &[l_val.into(), r_val.into()],
+            &format!("{}_checked", name),
+        )
+        .unwrap();
+    let pair = match call_site.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_struct_value(),
+        ValueKind::Instruction(_) => {
+            return Err(format!("Expected basic value from {}", intrinsic_name));
+        }
+    };
+
+    let result = self_compiler
+        .builder
+        .build_extract_value(pair, 0, name)
+        .unwrap()
+        .into_int_value();
+    let overflowed = self_compiler
+        .builder
+        .build_extract_value(pair, 1, &format!("{}_overflowed", name))
+        .unwrap()
+        .into_int_value();
+
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let overflow_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_overflow_bb", name));
+    let cont_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_cont_bb", name));
+
+    self_compiler
+        .builder
+        .build_conditional_branch(overflowed, overflow_bb, cont_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(overflow_bb);
+    let error_message = format!("OverflowError: integer overflow in '{}'", op_str);
+    create_panic_err(
+        self_compiler,
+        &error_message,
+        module,
+        PanicErrorSettings {
+            is_const: true,
+            is_global: false,
+        },
+    )?;
+    self_compiler.builder.build_unreachable().unwrap();
+
+    self_compiler.builder.position_at_end(cont_bb);
+    Ok(result)
+}
+
+// `--overflow-checks` arithmetic on the data of two values tagged `tag`: a sized integer is
+// computed at its own width and signedness, so `cast!(1, u8) - cast!(2, u8)` overflows as a u8
+// does, and any other tag as a signed Int. Returns the result and its tag, the sized tag or Int.
+pub fn build_tagged_int_arith<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    op: ArithOp,
+    tag: IntValue<'ctx>,
+    l_val: IntValue<'ctx>,
+    r_val: IntValue<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+    name: &str,
+) -> Result<(IntValue<'ctx>, IntValue<'ctx>), String> {
+    let data_type = self_compiler.data_type();
+    let tag_type = self_compiler.tag_type();
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let int_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_int_bb", name));
+    let merge_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_merge_bb", name));
+
+    // wider than the data word does not occur: such tags are rejected on the target
+    let sized: Vec<(u64, u32, bool)> = [
+        (Tag::Int8 as u64, 8, true),
+        (Tag::Uint8 as u64, 8, false),
+        (Tag::Int16 as u64, 16, true),
+        (Tag::Uint16 as u64, 16, false),
+        (Tag::Int32 as u64, 32, true),
+        (Tag::Uint32 as u64, 32, false),
+        (Tag::Int64 as u64, 64, true),
+        (Tag::Uint64 as u64, 64, false),
+    ]
+    .into_iter()
+    .filter(|(_, bits, _)| *bits <= data_type.get_bit_width())
+    .collect();
+    let cases: Vec<_> = sized
+        .iter()
+        .map(|(sized_tag, bits, _)| {
+            let bb = self_compiler
+                .context
+                .append_basic_block(parent_fn, &format!("{}_{}_bb", name, bits));
+            (tag_type.const_int(*sized_tag, false), bb)
+        })
+        .collect();
+    self_compiler
+        .builder
+        .build_switch(tag, int_bb, &cases)
+        .unwrap();
+
+    let mut incoming = Vec::new();
+    for ((_, bits, signed), (tag_const, bb)) in sized.iter().zip(&cases) {
+        self_compiler.builder.position_at_end(*bb);
+        let result = if *bits == data_type.get_bit_width() {
+            build_int_arith(self_compiler, op, l_val, r_val, *signed, module, name)?
+        } else {
+            let int_type = self_compiler.context.custom_width_int_type(*bits);
+            let l_narrow = self_compiler
+                .builder
+                .build_int_truncate(l_val, int_type, "l_narrow")
+                .unwrap();
+            let r_narrow = self_compiler
+                .builder
+                .build_int_truncate(r_val, int_type, "r_narrow")
+                .unwrap();
+            let narrow =
+                build_int_arith(self_compiler, op, l_narrow, r_narrow, *signed, module, name)?;
+            if *signed {
+                self_compiler
+                    .builder
+                    .build_int_s_extend(narrow, data_type, "sized_ext")
+                    .unwrap()
+            } else {
+                self_compiler
+                    .builder
+                    .build_int_z_extend(narrow, data_type, "sized_ext")
+                    .unwrap()
+            }
+        };
+        incoming.push((
+            result,
+            *tag_const,
+            self_compiler.builder.get_insert_block().unwrap(),
+        ));
+        self_compiler
+            .builder
+            .build_unconditional_branch(merge_bb)
+            .unwrap();
+    }
+
+    self_compiler.builder.position_at_end(int_bb);
+    let result = build_int_arith(self_compiler, op, l_val, r_val, true, module, name)?;
+    incoming.push((
+        result,
+        tag_type.const_int(Tag::Integer as u64, false),
+        self_compiler.builder.get_insert_block().unwrap(),
+    ));
+    self_compiler
+        .builder
+        .build_unconditional_branch(merge_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(merge_bb);
+    let value_phi = self_compiler
+        .builder
+        .build_phi(data_type, &format!("{}_phi", name))
+        .unwrap();
+    let tag_phi = self_compiler
+        .builder
+        .build_phi(tag_type, &format!("{}_tag_phi", name))
+        .unwrap();
+    for (result, result_tag, bb) in &incoming {
+        value_phi.add_incoming(&[(result, *bb)]);
+        tag_phi.add_incoming(&[(result_tag, *bb)]);
+    }
+    Ok((
+        value_phi.as_basic_value().into_int_value(),
+        tag_phi.as_basic_value().into_int_value(),
+    ))
+}
+
+// Human readable location of the code currently being generated, used in panic messages.
+fn current_location<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+) -> String {
+    match current_position(self_compiler, module) {
+        (Some(position), Some(name)) => format!("(at {} in function '{}')", position, name),
+        (Some(position), None) => format!("(at {})", position),
+        (None, Some(name)) => format!("(in function '{}')", name),
+        (None, None) => String::new(),
+    }
+}
+
+// ("path:line:col", function name) of the code currently being generated.
+fn current_position<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+) -> (Option<String>, Option<String>) {
+    let func_name = self_compiler.function_signatures.map(|func| {
+        let name = func.get_name().to_string_lossy().into_owned();
+        if name == "_sprs_main" {
+            "main".to_string()
+        } else {
+            name
+        }
+    });
+    let position = self_compiler.current_span.and_then(|span| {
+        let module_name = module.get_name().to_str().ok()?;
+        let (path, source) = self_compiler.module_sources.get(module_name)?;
+        Some(error_helper::format_location(source, path, span.start))
+    });
+
+    (position, func_name)
+}
+
 pub enum TagOptionsInst {
     None,
     BoolAsI64,
@@ -109,7 +577,7 @@ pub fn create_list_from_expr<'ctx>(
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<PointerValue<'ctx>, String> {
     let len = elements.len();
-    let i64_type = self_compiler.context.i64_type();
+    let data_type = self_compiler.data_type();
 
     let list_new_fn = self_compiler.get_runtime_fn(module, "__list_new");
 
@@ -117,7 +585,7 @@ pub fn create_list_from_expr<'ctx>(
         .builder
         .build_call(
             list_new_fn,
-            &[i64_type.const_int(len as u64, false).into()],
+            &[data_type.const_int(len as u64, false).into()],
             "list_ptr",
         )
         .unwrap();
@@ -144,7 +612,7 @@ pub fn create_list_from_expr<'ctx>(
     Ok(list_ptr_val)
 }
 
-// A runtime move system for variables that hold heap data (strings, lists, ranges)
+// A runtime move system for variables that hold heap data (strings, lists, ranges, structs)
 // When passing such variables to functions, we need to "move" them by resetting their tag to Unit
 // If want to keep the data, can use "clone" macro.
 pub fn move_variable<'ctx>(
@@ -167,7 +635,7 @@ pub fn move_variable<'ctx>(
     let current_tag = self_compiler
         .builder
         .build_load(
-            self_compiler.context.i32_type(),
+            self_compiler.tag_type(),
             tag_ptr,
             &format!("{}_current_tag", name),
         )
@@ -175,17 +643,13 @@ pub fn move_variable<'ctx>(
         .into_int_value();
 
     let tag_string = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::String as u64, false);
-    let tag_list = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::List as u64, false);
-    let tag_range = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Range as u64, false);
+    let tag_list = self_compiler.tag_type().const_int(Tag::List as u64, false);
+    let tag_range = self_compiler.tag_type().const_int(Tag::Range as u64, false);
+    let tag_struct = self_compiler
+        .tag_type()
+        .const_int(Tag::Struct as u64, false);
     let is_string = self_compiler
         .builder
         .build_int_compare(
@@ -213,14 +677,27 @@ pub fn move_variable<'ctx>(
             &format!("{}_is_range", name),
         )
         .unwrap();
+    let is_struct = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::EQ,
+            current_tag,
+            tag_struct,
+            &format!("{}_is_struct", name),
+        )
+        .unwrap();
 
     let is_heap_1 = self_compiler
         .builder
         .build_or(is_string, is_list, &format!("{}_is_heap_1", name))
         .unwrap();
+    let is_heap_2 = self_compiler
+        .builder
+        .build_or(is_heap_1, is_range, &format!("{}_is_heap_2", name))
+        .unwrap();
     let should_move = self_compiler
         .builder
-        .build_or(is_heap_1, is_range, &format!("{}_should_move", name))
+        .build_or(is_heap_2, is_struct, &format!("{}_should_move", name))
         .unwrap();
 
     let parent_bb = self_compiler
@@ -245,10 +722,7 @@ pub fn move_variable<'ctx>(
         .builder
         .build_store(
             tag_ptr,
-            self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Unit as u64, false),
+            self_compiler.tag_type().const_int(Tag::Moved as u64, false),
         )
         .unwrap();
     self_compiler
@@ -263,7 +737,10 @@ pub fn var_load_at_init_variable<'ctx>(
     init_value: PointerValue<'ctx>,
     name: &str,
 ) -> PointerValue<'ctx> {
-    let ptr = create_entry_block_alloca(self_compiler, name);
+    let ptr = match next_frame_slot(self_compiler, name) {
+        Some(slot) => slot,
+        None => create_entry_block_alloca(self_compiler, name),
+    };
 
     let val = self_compiler
         .builder
@@ -277,40 +754,262 @@ pub fn var_load_at_init_variable<'ctx>(
     ptr
 }
 
-pub fn var_return_store<'ctx>(
+// Hands out the next slot of the spilled frame array, if the current function has one.
+pub fn next_frame_slot<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
-    value_enum: &BasicValueEnum<'ctx>,
     name: &str,
-) {
-    let var_ptr = value_enum.into_pointer_value();
+) -> Option<PointerValue<'ctx>> {
+    let frame = self_compiler.frame.as_mut()?;
+    if frame.next >= frame.capacity {
+        return None;
+    }
+    let (base, capacity, index) = (frame.base, frame.capacity, frame.next);
+    frame.next += 1;
 
-    self_compiler.tag_only_runtime_value_store(var_ptr, Tag::Unit as u64, name);
+    let frame_type = self_compiler.runtime_value_type.array_type(capacity);
+    let i32_type = self_compiler.context.i32_type();
+    let slot = unsafe {
+        self_compiler
+            .builder
+            .build_in_bounds_gep(
+                frame_type,
+                base,
+                &[
+                    i32_type.const_zero(),
+                    i32_type.const_int(index as u64, false),
+                ],
+                &format!("{}_frame_slot", name),
+            )
+            .unwrap()
+    };
+    Some(slot)
 }
 
-pub fn drop_var<'ctx>(
+// Panics with "use of moved value" when the variable was moved out before this read.
+pub fn check_not_moved<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
-    ptr: PointerValue<'ctx>,
-    drop_fn: FunctionValue<'_>,
+    var_ptr: PointerValue<'ctx>,
     name: &str,
-) {
-    self_compiler.build_sprs_value_call_func(ptr, drop_fn, name, &[], false);
-}
-
-pub fn create_dummy_for_no_return<'ctx>(self_compiler: &mut Compiler<'ctx>) {
-    let dummy = create_entry_block_alloca(self_compiler, "ret_dummy");
-    self_compiler.build_runtime_value_store(
-        dummy,
-        StoreTag::Int(Tag::Unit as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
-        "ret_dummy",
-    );
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(), String> {
+    // global initializers have no function to branch in
+    let Some(current_bb) = self_compiler.builder.get_insert_block() else {
+        return Ok(());
+    };
+    let Some(parent_fn) = current_bb.get_parent() else {
+        return Ok(());
+    };
 
-    let val = self_compiler
+    let tag_ptr = self_compiler
         .builder
-        .build_load(self_compiler.runtime_value_type, dummy, "ret_dummy_val")
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            var_ptr,
+            0,
+            &format!("{}_moved_tag_ptr", name),
+        )
         .unwrap();
-    self_compiler.builder.build_return(Some(&val)).unwrap();
-}
+    let tag = self_compiler
+        .builder
+        .build_load(
+            self_compiler.tag_type(),
+            tag_ptr,
+            &format!("{}_moved_tag", name),
+        )
+        .unwrap()
+        .into_int_value();
+    let is_moved = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::EQ,
+            tag,
+            self_compiler.tag_type().const_int(Tag::Moved as u64, false),
+            &format!("{}_is_moved", name),
+        )
+        .unwrap();
+
+    let moved_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_moved_bb", name));
+    let ok_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_not_moved_bb", name));
+    self_compiler
+        .builder
+        .build_conditional_branch(is_moved, moved_bb, ok_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(moved_bb);
+    let error_message = format!("use of moved value: {}", name);
+    create_panic_err(
+        self_compiler,
+        &error_message,
+        module,
+        PanicErrorSettings {
+            is_const: true,
+            is_global: false,
+        },
+    )?;
+    self_compiler.builder.build_unreachable().unwrap();
+
+    self_compiler.builder.position_at_end(ok_bb);
+    Ok(())
+}
+
+pub fn var_return_store<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    value_enum: &BasicValueEnum<'ctx>,
+    name: &str,
+) {
+    let var_ptr = value_enum.into_pointer_value();
+
+    self_compiler.tag_only_runtime_value_store(var_ptr, Tag::Unit as u64, name);
+}
+
+// The slot reads Unit afterwards, so dropping it a second time does nothing.
+pub fn drop_var<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    ptr: PointerValue<'ctx>,
+    drop_fn: FunctionValue<'_>,
+    name: &str,
+) {
+    self_compiler.build_sprs_value_call_func(ptr, drop_fn, name, &[], false);
+    self_compiler.tag_only_runtime_value_store(ptr, Tag::Unit as u64, name);
+}
+
+// a, b = b, a;
+// Every value is loaded before any target is written, so swaps need no temporary.
+pub fn create_multi_assign<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    assigns: &Vec<ast::AssignStmt>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(), String> {
+    let mut new_vals = Vec::new();
+    for assign_stmt in assigns {
+        let val_ptr = self_compiler
+            .compile_expr(&assign_stmt.expr, module)?
+            .into_pointer_value();
+        let new_val = self_compiler
+            .builder
+            .build_load(
+                self_compiler.runtime_value_type,
+                val_ptr,
+                &format!("{}_multi_assign_load", assign_stmt.name),
+            )
+            .unwrap();
+        new_vals.push(new_val);
+    }
+
+    // move the sources first so a target that is also a source is not dropped
+    for assign_stmt in assigns {
+        if let ast::Expr::Var(src_val_name) = &assign_stmt.expr {
+            let var_val = self_compiler.get_variables(src_val_name).map(|(v, _)| v);
+            if let Some(val) = var_val {
+                move_variable(self_compiler, &val, &assign_stmt.name);
+            }
+        }
+    }
+
+    let drop_fn = self_compiler.get_runtime_fn(module, "__drop");
+    for (assign_stmt, new_val) in assigns.iter().zip(new_vals) {
+        let (target_val, _) = self_compiler
+            .get_variables(&assign_stmt.name)
+            .ok_or_else(|| format!("Undefined variable: {}", &assign_stmt.name))?;
+        let target_ptr = target_val.into_pointer_value();
+
+        drop_var(self_compiler, target_ptr, drop_fn, &assign_stmt.name);
+
+        self_compiler
+            .builder
+            .build_store(target_ptr, new_val)
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+pub fn create_dummy_for_no_return<'ctx>(self_compiler: &mut Compiler<'ctx>) {
+    let dummy = create_entry_block_alloca(self_compiler, "ret_dummy");
+    self_compiler.build_runtime_value_store(
+        dummy,
+        StoreTag::Int(Tag::Unit as u64),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
+        "ret_dummy",
+    );
+
+    let val = self_compiler
+        .builder
+        .build_load(self_compiler.runtime_value_type, dummy, "ret_dummy_val")
+        .unwrap();
+    self_compiler.builder.build_return(Some(&val)).unwrap();
+}
+
+// Panics at runtime unless the condition value is tagged Bool.
+fn check_bool_condition<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    cond_ptr: PointerValue<'ctx>,
+    kind: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(), String> {
+    let cond_tag_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            cond_ptr,
+            0,
+            "cond_tag_ptr",
+        )
+        .unwrap();
+    let cond_tag = self_compiler
+        .builder
+        .build_load(self_compiler.tag_type(), cond_tag_ptr, "cond_tag")
+        .unwrap()
+        .into_int_value();
+    let is_bool = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::EQ,
+            cond_tag,
+            self_compiler
+                .tag_type()
+                .const_int(Tag::Boolean as u64, false),
+            "cond_is_bool",
+        )
+        .unwrap();
+
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let error_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_cond_type_error_bb", kind));
+    let ok_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, &format!("{}_cond_ok_bb", kind));
+    self_compiler
+        .builder
+        .build_conditional_branch(is_bool, ok_bb, error_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(error_bb);
+    let error_message = format!("TypeError: expected Bool in condition of '{}'", kind);
+    create_panic_err(
+        self_compiler,
+        &error_message,
+        module,
+        PanicErrorSettings {
+            is_const: true,
+            is_global: false,
+        },
+    )?;
+    self_compiler.builder.build_unreachable().unwrap();
+
+    self_compiler.builder.position_at_end(ok_bb);
+    Ok(())
+}
 
 pub fn create_if_condition<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
@@ -338,6 +1037,7 @@ pub fn create_if_condition<'ctx>(
 
     let cond_val = self_compiler.compile_expr(cond, module)?;
     let cond_ptr = cond_val.into_pointer_value();
+    check_bool_condition(self_compiler, cond_ptr, "if", module)?;
     let cond_data_ptr = self_compiler
         .builder
         .build_struct_gep(
@@ -349,14 +1049,10 @@ pub fn create_if_condition<'ctx>(
         .unwrap();
     let cond_loaded = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            cond_data_ptr,
-            "cond_loaded",
-        )
+        .build_load(self_compiler.data_type(), cond_data_ptr, "cond_loaded")
         .unwrap()
         .into_int_value();
-    let zero = self_compiler.context.i64_type().const_int(0, false);
+    let zero = self_compiler.data_type().const_int(0, false);
     let cond_bool = self_compiler
         .builder
         .build_int_compare(inkwell::IntPredicate::NE, cond_loaded, zero, "if_cond_bool")
@@ -423,6 +1119,7 @@ pub fn create_while_condition<'ctx>(
     self_compiler.builder.position_at_end(cond_bb);
     let cond_val = self_compiler.compile_expr(cond, module)?;
     let cond_ptr = cond_val.into_pointer_value();
+    check_bool_condition(self_compiler, cond_ptr, "while", module)?;
 
     let cond_data_ptr = self_compiler
         .builder
@@ -435,15 +1132,11 @@ pub fn create_while_condition<'ctx>(
         .unwrap();
     let cond_loaded = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            cond_data_ptr,
-            "cond_loaded",
-        )
+        .build_load(self_compiler.data_type(), cond_data_ptr, "cond_loaded")
         .unwrap()
         .into_int_value();
 
-    let zero = self_compiler.context.i64_type().const_int(0, false);
+    let zero = self_compiler.data_type().const_int(0, false);
     let cond_bool = self_compiler
         .builder
         .build_int_compare(
@@ -484,7 +1177,7 @@ pub fn create_integer<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Integer as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(*n as u64, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(*n as u64, false)),
         "int",
     );
 
@@ -507,33 +1200,138 @@ pub fn create_float<'ctx>(
     Ok(ptr.into())
 }
 
+// Global of a literal: `<prefix>.<len>.<128-bit FNV-1a of the literal>`. Returns the name and
+// the global when this module already has it. A global of that name holding other bytes is a
+// hash collision and the name gets a `.<n>` suffix; globals of other modules can't be compared,
+// hence the wide hash.
+fn find_literal_global<'ctx>(
+    module: &inkwell::module::Module<'ctx>,
+    prefix: &str,
+    str: &str,
+    contents: &[u8],
+) -> (String, Option<GlobalValue<'ctx>>) {
+    let mut hash: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+    for byte in str.bytes() {
+        hash ^= byte as u128;
+        hash = hash.wrapping_mul(0x0000_0000_0100_0000_0000_0000_0000_013b);
+    }
+    let base = format!("{}.{}.{:032x}", prefix, str.len(), hash);
+
+    let mut name = base.clone();
+    for n in 1.. {
+        let Some(global) = module.get_global(&name) else {
+            break;
+        };
+        let init = global.get_initializer().map(|init| init.into_array_value());
+        if init.as_ref().and_then(|init| init.as_const_string()) == Some(contents) {
+            return (name, Some(global));
+        }
+        name = format!("{}.{}", base, n);
+    }
+    (name, None)
+}
+
+// String literals are interned across the whole binary: the global is named after its
+// contents and emitted as linkonce_odr + unnamed_addr in its own comdat (where the target has
+// comdats), so the linker keeps a single copy even when several modules use the same literal.
+pub fn intern_string_global<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    str: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> GlobalValue<'ctx> {
+    let mut contents = str.as_bytes().to_vec();
+    contents.push(0);
+    let (name, existing) = find_literal_global(module, "__sprs_str", str, &contents);
+    if let Some(existing) = existing {
+        return existing;
+    }
+
+    let str_val = self_compiler.context.const_string(str.as_bytes(), true);
+    let global = module.add_global(str_val.get_type(), Some(AddressSpace::default()), &name);
+    global.set_initializer(&str_val);
+    global.set_constant(true);
+    global.set_linkage(Linkage::LinkOnceODR);
+    global.set_unnamed_addr(true);
+    if self_compiler.supports_comdat() {
+        global.set_comdat(module.get_or_insert_comdat(&name));
+    }
+    global
+}
+
+// Pointer to a NUL-terminated string literal. With `--compress-strings` long literals are
+// stored LZSS-packed and unpacked once at run time by `__str_unpack`.
+pub fn string_literal_ptr<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    str: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let packed = if self_compiler.options.compress_strings && str.len() >= rodata::COMPRESS_MIN_LEN
+    {
+        rodata::lzss_pack(str.as_bytes())
+    } else {
+        None
+    };
+    // The cache pointer costs a word of RAM per literal, so only pack when it still pays off.
+    let Some(packed) = packed.filter(|packed| packed.len() + 8 < str.len()) else {
+        return Ok(intern_string_global(self_compiler, str, module).as_pointer_value());
+    };
+
+    let (name, existing) = find_literal_global(module, "__sprs_zstr", str, &packed);
+    let ptr_type = self_compiler.context.ptr_type(AddressSpace::default());
+
+    let (data, cache) = match (existing, module.get_global(&format!("{}.cache", name))) {
+        (Some(data), Some(cache)) => (data, cache),
+        _ => {
+            let packed_val = self_compiler.context.const_string(&packed, false);
+            let data =
+                module.add_global(packed_val.get_type(), Some(AddressSpace::default()), &name);
+            data.set_initializer(&packed_val);
+            data.set_constant(true);
+            data.set_linkage(Linkage::Private);
+            data.set_unnamed_addr(true);
+
+            let cache = module.add_global(
+                ptr_type,
+                Some(AddressSpace::default()),
+                &format!("{}.cache", name),
+            );
+            cache.set_initializer(&ptr_type.const_null());
+            cache.set_linkage(Linkage::Private);
+            (data, cache)
+        }
+    };
+
+    let unpack_fn = self_compiler.get_runtime_fn(module, "__str_unpack");
+    let call_site = self_compiler
+        .builder
+        .build_call(
+            unpack_fn,
+            &[
+                data.as_pointer_value().into(),
+                cache.as_pointer_value().into(),
+            ],
+            "unpacked_str",
+        )
+        .unwrap();
+    match call_site.try_as_basic_value() {
+        ValueKind::Basic(val) => Ok(val.into_pointer_value()),
+        ValueKind::Instruction(_) => Err("Expected basic value from __str_unpack".to_string()),
+    }
+}
+
 pub fn create_string<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
     str: &String,
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
-    let global = if let Some(existing) = self_compiler.string_constants.get(str) {
-        *existing
-    } else {
-        let str_val = self_compiler.context.const_string(str.as_bytes(), true);
-        let global = module.add_global(
-            str_val.get_type(),
-            Some(AddressSpace::default()),
-            &format!("str_const_{}", self_compiler.string_constants.len()),
-        );
-        global.set_initializer(&str_val);
-        global.set_linkage(Linkage::Internal);
-        global.set_constant(true);
-        self_compiler.string_constants.insert(str.clone(), global);
-        global
-    };
+    let str_ptr = string_literal_ptr(self_compiler, str, module)?;
 
     let ptr = create_entry_block_alloca(self_compiler, "str_alloc");
 
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::String as u64),
-        StoreValue::Ptr(global.as_pointer_value()),
+        StoreValue::Ptr(str_ptr),
         "string",
     );
 
@@ -551,8 +1349,7 @@ pub fn create_bool<'ctx>(
         StoreTag::Int(Tag::Boolean as u64),
         StoreValue::Int(
             self_compiler
-                .context
-                .i64_type()
+                .data_type()
                 .const_int(if *boolean { 1 } else { 0 }, false),
         ),
         "bool",
@@ -561,6 +1358,22 @@ pub fn create_bool<'ctx>(
     Ok(ptr.into())
 }
 
+pub fn create_char<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    c: char,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let ptr = create_entry_block_alloca(self_compiler, "char_alloc");
+
+    self_compiler.build_runtime_value_store(
+        ptr,
+        StoreTag::Int(Tag::Char as u64),
+        StoreValue::Int(self_compiler.data_type().const_int(c as u64, false)),
+        "char",
+    );
+
+    Ok(ptr.into())
+}
+
 pub fn create_int8<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
@@ -569,7 +1382,7 @@ pub fn create_int8<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Int8 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "int8",
     );
 
@@ -584,7 +1397,7 @@ pub fn create_uint8<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Uint8 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "uint8",
     );
 
@@ -599,7 +1412,7 @@ pub fn create_int16<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Int16 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "int16",
     );
 
@@ -614,7 +1427,7 @@ pub fn create_uint16<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Uint16 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "uint16",
     );
 
@@ -629,7 +1442,7 @@ pub fn create_int32<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Int32 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "int32",
     );
 
@@ -644,7 +1457,7 @@ pub fn create_uint32<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Uint32 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "uint32",
     );
 
@@ -659,7 +1472,7 @@ pub fn create_int64<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Int64 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "int64",
     );
 
@@ -674,7 +1487,7 @@ pub fn create_uint64<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Uint64 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "uint64",
     );
 
@@ -689,7 +1502,7 @@ pub fn create_float16<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Float16 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "f16",
     );
 
@@ -704,7 +1517,7 @@ pub fn create_float32<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Float32 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "f32",
     );
 
@@ -719,7 +1532,7 @@ pub fn create_float64<'ctx>(
     self_compiler.build_runtime_value_store(
         ptr,
         StoreTag::Int(Tag::Float64 as u64),
-        StoreValue::Int(self_compiler.context.i64_type().const_int(0, false)),
+        StoreValue::Int(self_compiler.data_type().const_int(0, false)),
         "f64",
     );
 
@@ -733,12 +1546,28 @@ fn box_return_value<'ctx>(
 ) -> Result<BasicValueEnum<'ctx>, String> {
     let result_ptr = create_entry_block_alloca(self_compiler, "compile_expr_call_res_alloc");
 
-    if return_type.is_int_type() {
+    if return_type.is_int_type() && return_type.into_int_type().get_bit_width() == 1 {
+        let bool_val = self_compiler
+            .builder
+            .build_int_z_extend(
+                result_val.into_int_value(),
+                self_compiler.data_type(),
+                "bool_to_i64",
+            )
+            .unwrap();
+
+        self_compiler.build_runtime_value_store(
+            result_ptr,
+            StoreTag::Int(Tag::Boolean as u64),
+            StoreValue::Int(bool_val),
+            "res_boolean",
+        );
+    } else if return_type.is_int_type() {
         let int_val = result_val.into_int_value();
 
         let val_i64 = self_compiler
             .builder
-            .build_int_s_extend(int_val, self_compiler.context.i64_type(), "int_to_i64")
+            .build_int_s_extend(int_val, self_compiler.data_type(), "int_to_i64")
             .unwrap();
 
         self_compiler.build_runtime_value_store(
@@ -755,11 +1584,7 @@ fn box_return_value<'ctx>(
             .build_float_ext(float_val, self_compiler.context.f64_type(), "float_to_f64")
             .unwrap();
 
-        let data = self_compiler
-            .builder
-            .build_bit_cast(val_f64, self_compiler.context.i64_type(), "f64_to_i64")
-            .unwrap()
-            .into_int_value();
+        let data = f64_to_data(self_compiler, val_f64, "f64_to_i64");
 
         self_compiler.build_runtime_value_store(
             result_ptr,
@@ -776,7 +1601,7 @@ fn box_return_value<'ctx>(
         let ptr_val = result_val.into_pointer_value();
         let ptr_as_i64 = self_compiler
             .builder
-            .build_ptr_to_int(ptr_val, self_compiler.context.i64_type(), "ptr_to_i64")
+            .build_ptr_to_int(ptr_val, self_compiler.data_type(), "ptr_to_i64")
             .unwrap();
 
         self_compiler.build_runtime_value_store(
@@ -791,23 +1616,28 @@ fn box_return_value<'ctx>(
     Ok(result_ptr.into())
 }
 
-pub fn create_call_expr<'ctx>(
+// Arguments are evaluated left to right and copied into temporaries.
+// Moves out of variables happen only after every argument has been evaluated,
+// so `f(a, a)` sees the same value twice instead of a moved-out Unit.
+fn compile_call_args<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
-    ident: &str,
     args: &Vec<ast::Expr>,
     module: &inkwell::module::Module<'ctx>,
-) -> Result<BasicValueEnum<'ctx>, String> {
-    let func = module
-        .get_function(ident)
-        .or_else(|| {
-            self_compiler
-                .modules
-                .values()
-                .find_map(|m| m.get_function(ident))
-        })
-        .ok_or(format!("Undefined function: {}", ident))?;
+) -> Result<Vec<inkwell::values::BasicMetadataValueEnum<'ctx>>, String> {
     let mut compiled_args = Vec::with_capacity(args.len());
+    let mut moved_vars = Vec::new();
     for arg in args {
+        // `clone!(x)` on the last use of x: pass x itself and move it instead of copying
+        let arg = match arg {
+            ast::Expr::Call(_, clone_args, _, _)
+                if self_compiler
+                    .elided_clones
+                    .contains(&(arg as *const ast::Expr)) =>
+            {
+                &clone_args[0]
+            }
+            _ => arg,
+        };
         let arg_val = self_compiler.compile_expr(arg, module)?;
         let arg_ptr = arg_val.into_pointer_value();
 
@@ -822,13 +1652,33 @@ pub fn create_call_expr<'ctx>(
             .unwrap();
         let val_tag = self_compiler
             .builder
-            .build_load(self_compiler.context.i32_type(), val_tag_ptr, "val_tag")
+            .build_load(self_compiler.tag_type(), val_tag_ptr, "val_tag")
             .unwrap();
         let val_data = self_compiler
             .builder
-            .build_load(self_compiler.context.i64_type(), val_data_ptr, "val_data")
+            .build_load(self_compiler.data_type(), val_data_ptr, "val_data")
             .unwrap();
 
+        // `f(a, a)`: the first argument takes the value of a, the second gets a copy, so the
+        // callee does not drop the same heap data twice
+        let consumed = matches!(arg, ast::Expr::Var(name) if moved_vars.contains(&name));
+        if consumed {
+            let clone_fn = self_compiler.get_runtime_fn(module, "__clone");
+            let call_site = self_compiler
+                .builder
+                .build_call(clone_fn, &[val_tag.into(), val_data.into()], "arg_clone")
+                .unwrap();
+            let ValueKind::Basic(cloned) = call_site.try_as_basic_value() else {
+                return Err("__clone did not return a value".to_string());
+            };
+            self_compiler
+                .builder
+                .build_store(temp_arg_ptr, cloned)
+                .unwrap();
+            compiled_args.push(temp_arg_ptr.into());
+            continue;
+        }
+
         let temp_tag_ptr = self_compiler
             .builder
             .build_struct_gep(
@@ -858,123 +1708,53 @@ pub fn create_call_expr<'ctx>(
         compiled_args.push(temp_arg_ptr.into());
 
         if let ast::Expr::Var(name) = arg {
-            if let Some((var_ptr_enum, _)) = self_compiler.get_variables(name) {
-                let var_ptr = var_ptr_enum.into_pointer_value();
+            moved_vars.push(name);
+        }
+    }
 
-                let current_tag = val_tag.into_int_value();
+    for name in moved_vars {
+        if let Some((var_ptr_enum, _)) = self_compiler.get_variables(name) {
+            move_variable(self_compiler, &var_ptr_enum, name);
+        }
+    }
 
-                let tag_string = self_compiler
-                    .context
-                    .i32_type()
-                    .const_int(Tag::String as u64, false);
-                let tag_list = self_compiler
-                    .context
-                    .i32_type()
-                    .const_int(Tag::List as u64, false);
-                let tag_range = self_compiler
-                    .context
-                    .i32_type()
-                    .const_int(Tag::Range as u64, false);
-                let is_string = self_compiler
-                    .builder
-                    .build_int_compare(
-                        inkwell::IntPredicate::EQ,
-                        current_tag,
-                        tag_string,
-                        "compile_expr_is_string",
-                    )
-                    .unwrap();
-                let is_list = self_compiler
-                    .builder
-                    .build_int_compare(
-                        inkwell::IntPredicate::EQ,
-                        current_tag,
-                        tag_list,
-                        "compile_expr_is_list",
-                    )
-                    .unwrap();
-                let is_range = self_compiler
-                    .builder
-                    .build_int_compare(
-                        inkwell::IntPredicate::EQ,
-                        current_tag,
-                        tag_range,
-                        "compile_expr_is_range",
-                    )
-                    .unwrap();
+    Ok(compiled_args)
+}
 
-                let is_heap_1 = self_compiler
-                    .builder
-                    .build_or(is_string, is_list, "compile_expr_is_heap_1")
-                    .unwrap();
-                let should_move = self_compiler
-                    .builder
-                    .build_or(
-                        is_heap_1,
-                        self_compiler
-                            .builder
-                            .build_int_compare(
-                                inkwell::IntPredicate::EQ,
-                                is_heap_1,
-                                is_range,
-                                "is_heap_2",
-                            )
-                            .unwrap(),
-                        "should_move",
-                    )
-                    .unwrap();
+pub fn create_call_expr<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    ident: &str,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    // functions without `pub` are only callable inside their own module
+    let func = match module.get_function(ident) {
+        Some(func) => func,
+        None => {
+            let mut private_in = None;
+            let found = self_compiler.modules.iter().find_map(|(name, m)| {
+                let func = m.get_function(ident)?;
+                if func.get_linkage() == Linkage::Private {
+                    private_in = Some(name.clone());
+                    return None;
+                }
+                Some(func)
+            });
+            match (found, private_in) {
+                (Some(func), _) => func,
+                (None, Some(module_name)) => {
+                    return Err(private_function_error(ident, &module_name));
+                }
+                (None, None) => return Err(format!("Undefined function: {}", ident)),
+            }
+        }
+    };
+    let compiled_args = compile_call_args(self_compiler, args, module)?;
 
-                let parent_bb = self_compiler
-                    .builder
-                    .get_insert_block()
-                    .unwrap()
-                    .get_parent()
-                    .unwrap();
-                let move_bb = self_compiler
-                    .context
-                    .append_basic_block(parent_bb, "compile_expr_arg_move_bb");
-                let cont_bb = self_compiler
-                    .context
-                    .append_basic_block(parent_bb, "compile_expr_arg_cont_bb");
-
-                self_compiler
-                    .builder
-                    .build_conditional_branch(should_move, move_bb, cont_bb)
-                    .unwrap();
-
-                self_compiler.builder.position_at_end(move_bb);
-                let var_tag_ptr = self_compiler
-                    .builder
-                    .build_struct_gep(
-                        self_compiler.runtime_value_type,
-                        var_ptr,
-                        0,
-                        "compile_expr_var_tag_ptr",
-                    )
-                    .unwrap();
-                self_compiler
-                    .builder
-                    .build_store(
-                        var_tag_ptr,
-                        self_compiler
-                            .context
-                            .i32_type()
-                            .const_int(Tag::Unit as u64, false),
-                    )
-                    .unwrap();
-                self_compiler
-                    .builder
-                    .build_unconditional_branch(cont_bb)
-                    .unwrap();
-
-                self_compiler.builder.position_at_end(cont_bb);
-            }
-        }
-    }
-    let call_site = self_compiler
-        .builder
-        .build_call(func, &compiled_args, "compile_expr_call_tmp")
-        .unwrap();
+    let call_site = self_compiler
+        .builder
+        .build_call(func, &compiled_args, "compile_expr_call_tmp")
+        .unwrap();
 
     let return_type_opt = func.get_type().get_return_type();
     if return_type_opt.is_none() {
@@ -991,10 +1771,18 @@ pub fn create_call_expr<'ctx>(
     box_return_value(self_compiler, return_type, result_val)
 }
 
+pub fn private_function_error(function_name: &str, module_name: &str) -> String {
+    format!(
+        "Function '{}' in module '{}' is private; declare it with `pub fn` to call it from other modules",
+        function_name, module_name
+    )
+}
+
 pub fn create_add_expr<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
     lhs: &ast::Expr,
     rhs: &ast::Expr,
+    on_stack: bool,
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
     if let Ok(val) = create_add_expr_type_check(self_compiler, lhs, rhs, module) {
@@ -1014,7 +1802,7 @@ pub fn create_add_expr<'ctx>(
         .unwrap();
     let l_tag = self_compiler
         .builder
-        .build_load(self_compiler.context.i32_type(), l_tag_ptr, "l_tag")
+        .build_load(self_compiler.tag_type(), l_tag_ptr, "l_tag")
         .unwrap()
         .into_int_value();
 
@@ -1024,7 +1812,7 @@ pub fn create_add_expr<'ctx>(
         .unwrap();
     let r_tag = self_compiler
         .builder
-        .build_load(self_compiler.context.i32_type(), r_tag_ptr, "r_tag")
+        .build_load(self_compiler.tag_type(), r_tag_ptr, "r_tag")
         .unwrap()
         .into_int_value();
 
@@ -1038,6 +1826,9 @@ pub fn create_add_expr<'ctx>(
     // check if both are strings
     let check_string = create_add_expr_check_string(self_compiler, l_tag, r_tag)?;
 
+    // check if both are lists
+    let both_list = create_check_both_tag(self_compiler, l_tag, r_tag, Tag::List)?;
+
     // create branches
     let parent_fn = self_compiler
         .builder
@@ -1060,6 +1851,12 @@ pub fn create_add_expr<'ctx>(
     let string_bb = self_compiler
         .context
         .append_basic_block(parent_fn, "add_string_bb");
+    let check_list_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "add_check_list_bb");
+    let list_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "add_list_bb");
     let error_bb = self_compiler
         .context
         .append_basic_block(parent_fn, "add_error_bb");
@@ -1083,7 +1880,13 @@ pub fn create_add_expr<'ctx>(
     self_compiler.builder.position_at_end(check_string_bb);
     let _ = self_compiler
         .builder
-        .build_conditional_branch(check_string, string_bb, error_bb);
+        .build_conditional_branch(check_string, string_bb, check_list_bb);
+
+    // fourth check if can concatenate as lists
+    self_compiler.builder.position_at_end(check_list_bb);
+    let _ = self_compiler
+        .builder
+        .build_conditional_branch(both_list, list_bb, error_bb);
 
     // error branch
     self_compiler.builder.position_at_end(error_bb);
@@ -1107,7 +1910,8 @@ pub fn create_add_expr<'ctx>(
 
     self_compiler.builder.position_at_end(int_bb);
 
-    let int_res_ptr = create_add_expr_build_int_branch(self_compiler, l_ptr, r_ptr, l_tag)?;
+    let int_res_ptr = create_add_expr_build_int_branch(self_compiler, l_ptr, r_ptr, l_tag, module)?;
+    let int_end_bb = self_compiler.builder.get_insert_block().unwrap();
     let _ = self_compiler.builder.build_unconditional_branch(merge_bb);
 
     // float addition branch
@@ -1121,7 +1925,24 @@ pub fn create_add_expr<'ctx>(
 
     self_compiler.builder.position_at_end(string_bb);
 
-    let str_res_ptr = create_add_expr_build_string_branch(self_compiler, l_ptr, r_ptr, module)?;
+    let str_res_ptr =
+        create_add_expr_build_string_branch(self_compiler, l_ptr, r_ptr, on_stack, module)?;
+    let string_end_bb = self_compiler.builder.get_insert_block().unwrap();
+    let _ = self_compiler.builder.build_unconditional_branch(merge_bb);
+
+    // list concatenation branch
+
+    self_compiler.builder.position_at_end(list_bb);
+
+    let list_res_ptr = create_collection_runtime_call(
+        self_compiler,
+        "__list_concat",
+        l_ptr,
+        r_ptr,
+        true,
+        Tag::List,
+        module,
+    )?;
 
     // final merge branch
 
@@ -1137,9 +1958,10 @@ pub fn create_add_expr<'ctx>(
         )
         .unwrap();
     phi.add_incoming(&[
-        (&int_res_ptr, int_bb),
+        (&int_res_ptr, int_end_bb),
         (&float_res_ptr, float_end_bb),
-        (&str_res_ptr, string_bb),
+        (&str_res_ptr, string_end_bb),
+        (&list_res_ptr, list_bb),
     ]);
 
     Ok(phi.as_basic_value())
@@ -1211,40 +2033,21 @@ fn create_add_expr_check_int<'ctx>(
     r_tag: IntValue<'ctx>,
 ) -> Result<IntValue<'ctx>, String> {
     let int_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Integer as u64, false);
-    let int8_tag = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Int8 as u64, false);
-    let uint8_tag = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Uint8 as u64, false);
-    let int16_tag = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Int16 as u64, false);
+    let int8_tag = self_compiler.tag_type().const_int(Tag::Int8 as u64, false);
+    let uint8_tag = self_compiler.tag_type().const_int(Tag::Uint8 as u64, false);
+    let int16_tag = self_compiler.tag_type().const_int(Tag::Int16 as u64, false);
     let uint16_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Uint16 as u64, false);
-    let int32_tag = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Int32 as u64, false);
+    let int32_tag = self_compiler.tag_type().const_int(Tag::Int32 as u64, false);
     let uint32_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Uint32 as u64, false);
-    let int64_tag = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Int64 as u64, false);
+    let int64_tag = self_compiler.tag_type().const_int(Tag::Int64 as u64, false);
     let uint64_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Uint64 as u64, false);
     let tags_equal = self_compiler
         .builder
@@ -1336,8 +2139,7 @@ fn create_add_expr_check_string<'ctx>(
     r_tag: IntValue<'ctx>,
 ) -> Result<IntValue<'ctx>, String> {
     let string_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::String as u64, false);
     let is_l_string = self_compiler
         .builder
@@ -1356,26 +2158,126 @@ fn create_add_expr_check_string<'ctx>(
     Ok(both_string)
 }
 
+fn create_check_both_tag<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    l_tag: IntValue<'ctx>,
+    r_tag: IntValue<'ctx>,
+    tag: Tag,
+) -> Result<IntValue<'ctx>, String> {
+    let expected_tag = self_compiler.tag_type().const_int(tag as u64, false);
+    let is_l = self_compiler
+        .builder
+        .build_int_compare(inkwell::IntPredicate::EQ, l_tag, expected_tag, "is_l_tag")
+        .unwrap();
+    let is_r = self_compiler
+        .builder
+        .build_int_compare(inkwell::IntPredicate::EQ, r_tag, expected_tag, "is_r_tag")
+        .unwrap();
+
+    Ok(self_compiler
+        .builder
+        .build_and(is_l, is_r, "both_tag")
+        .unwrap())
+}
+
+// Calls a collection runtime function (`__list_concat`, `__list_repeat`, `__str_repeat`)
+// and boxes the returned pointer with `res_tag`.
+// The right operand is passed as a pointer when `r_is_ptr`, otherwise as its raw i64 data.
+fn create_collection_runtime_call<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    fn_name: &str,
+    l_ptr: PointerValue<'ctx>,
+    r_ptr: PointerValue<'ctx>,
+    r_is_ptr: bool,
+    res_tag: Tag,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let runtime_fn = self_compiler.get_runtime_fn(module, fn_name);
+    let ptr_type = self_compiler.context.ptr_type(AddressSpace::default());
+    let data_type = self_compiler.data_type();
+
+    let l_data_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            l_ptr,
+            1,
+            "l_list_data_ptr",
+        )
+        .unwrap();
+    let l_data = self_compiler
+        .builder
+        .build_load(data_type, l_data_ptr, "l_list_data")
+        .unwrap()
+        .into_int_value();
+    let l_list_ptr = self_compiler
+        .builder
+        .build_int_to_ptr(l_data, ptr_type, "l_list_ptr")
+        .unwrap();
+
+    let r_data_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            r_ptr,
+            1,
+            "r_list_data_ptr",
+        )
+        .unwrap();
+    let r_data = self_compiler
+        .builder
+        .build_load(data_type, r_data_ptr, "r_list_data")
+        .unwrap()
+        .into_int_value();
+    let r_arg: inkwell::values::BasicMetadataValueEnum = if r_is_ptr {
+        self_compiler
+            .builder
+            .build_int_to_ptr(r_data, ptr_type, "r_list_ptr")
+            .unwrap()
+            .into()
+    } else {
+        r_data.into()
+    };
+
+    let call = self_compiler
+        .builder
+        .build_call(
+            runtime_fn,
+            &[l_list_ptr.into(), r_arg],
+            "collection_op_call",
+        )
+        .unwrap();
+    let new_ptr = match call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_pointer_value(),
+        ValueKind::Instruction(_) => {
+            return Err(format!("Expected basic value from {}", fn_name));
+        }
+    };
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "collection_op_res_alloc");
+    self_compiler.build_runtime_value_store(
+        res_ptr,
+        StoreTag::Int(res_tag as u64),
+        StoreValue::Ptr(new_ptr),
+        "collection_op_res",
+    );
+    Ok(res_ptr)
+}
+
 fn create_add_expr_check_float<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
     l_tag: IntValue<'ctx>,
     r_tag: IntValue<'ctx>,
 ) -> Result<IntValue<'ctx>, String> {
-    let float_tag = self_compiler
-        .context
-        .i32_type()
-        .const_int(Tag::Float as u64, false);
+    let float_tag = self_compiler.tag_type().const_int(Tag::Float as u64, false);
     let float16_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Float16 as u64, false);
     let float32_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Float32 as u64, false);
     let float64_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Float64 as u64, false);
     let float_tags_equal = self_compiler
         .builder
@@ -1441,6 +2343,7 @@ fn create_add_expr_build_int_branch<'ctx>(
     l_ptr: PointerValue<'ctx>,
     r_ptr: PointerValue<'ctx>,
     l_tag: IntValue<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
 ) -> Result<PointerValue<'ctx>, String> {
     let l_int_data_ptr = self_compiler
         .builder
@@ -1448,11 +2351,7 @@ fn create_add_expr_build_int_branch<'ctx>(
         .unwrap();
     let l_int_val = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            l_int_data_ptr,
-            "l_int_val",
-        )
+        .build_load(self_compiler.data_type(), l_int_data_ptr, "l_int_val")
         .unwrap()
         .into_int_value();
 
@@ -1462,18 +2361,33 @@ fn create_add_expr_build_int_branch<'ctx>(
         .unwrap();
     let r_int_val = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            r_int_data_ptr,
-            "r_int_val",
-        )
+        .build_load(self_compiler.data_type(), r_int_data_ptr, "r_int_val")
         .unwrap()
         .into_int_value();
 
-    let int_sum = self_compiler
-        .builder
-        .build_int_add(l_int_val, r_int_val, "int_sum")
-        .unwrap();
+    // both tags are the same integer type here
+    let int_sum = if self_compiler.options.overflow_checks {
+        build_tagged_int_arith(
+            self_compiler,
+            ArithOp::Add,
+            l_tag,
+            l_int_val,
+            r_int_val,
+            module,
+            "int_sum",
+        )?
+        .0
+    } else {
+        build_int_arith(
+            self_compiler,
+            ArithOp::Add,
+            l_int_val,
+            r_int_val,
+            true,
+            module,
+            "int_sum",
+        )?
+    };
 
     let int_res_ptr = create_entry_block_alloca(self_compiler, "int_res_alloc");
     self_compiler.build_runtime_value_store(
@@ -1503,11 +2417,7 @@ fn create_add_expr_build_float_branch<'ctx>(
         .unwrap();
     let l_float_bits = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            l_float_data_ptr,
-            "l_float_bits",
-        )
+        .build_load(self_compiler.data_type(), l_float_data_ptr, "l_float_bits")
         .unwrap()
         .into_int_value();
 
@@ -1522,11 +2432,7 @@ fn create_add_expr_build_float_branch<'ctx>(
         .unwrap();
     let r_float_bits = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            r_float_data_ptr,
-            "r_float_bits",
-        )
+        .build_load(self_compiler.data_type(), r_float_data_ptr, "r_float_bits")
         .unwrap()
         .into_int_value();
 
@@ -1550,16 +2456,13 @@ fn create_add_expr_build_float_branch<'ctx>(
         .append_basic_block(parent, "add_merge_bb");
 
     let f16_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Float16 as u64, false);
     let f32_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Float32 as u64, false);
     let f64_tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Float64 as u64, false);
 
     let cases = vec![(f16_tag, bb_f16), (f32_tag, bb_f32), (f64_tag, bb_f64)];
@@ -1601,7 +2504,7 @@ fn create_add_expr_build_float_branch<'ctx>(
         .into_int_value();
     let res_f16_bits = self_compiler
         .builder
-        .build_int_s_extend(sum_i16, self_compiler.context.i64_type(), "f16_to_i64")
+        .build_int_s_extend(sum_i16, self_compiler.data_type(), "f16_to_i64")
         .unwrap();
 
     self_compiler
@@ -1640,7 +2543,7 @@ fn create_add_expr_build_float_branch<'ctx>(
         .into_int_value();
     let res_f32_bits = self_compiler
         .builder
-        .build_int_s_extend(sum_i32, self_compiler.context.i64_type(), "f32_to_i64")
+        .build_int_s_extend(sum_i32, self_compiler.data_type(), "f32_to_i64")
         .unwrap();
     self_compiler
         .builder
@@ -1649,34 +2552,14 @@ fn create_add_expr_build_float_branch<'ctx>(
 
     // Float64
     self_compiler.builder.position_at_end(bb_f64);
-    let l_f64 = self_compiler
-        .builder
-        .build_bit_cast(
-            l_float_bits,
-            self_compiler.context.f64_type(),
-            "l_float_val",
-        )
-        .unwrap()
-        .into_float_value();
-    let r_f64 = self_compiler
-        .builder
-        .build_bit_cast(
-            r_float_bits,
-            self_compiler.context.f64_type(),
-            "r_float_val",
-        )
-        .unwrap()
-        .into_float_value();
+    let l_f64 = data_to_f64(self_compiler, l_float_bits, "l_float_val");
+    let r_f64 = data_to_f64(self_compiler, r_float_bits, "r_float_val");
     let sum_f64 = self_compiler
         .builder
         .build_float_add(l_f64, r_f64, "f64_add")
         .unwrap();
 
-    let res_f64_bits = self_compiler
-        .builder
-        .build_bit_cast(sum_f64, self_compiler.context.i64_type(), "f64_to_i64_cast")
-        .unwrap()
-        .into_int_value();
+    let res_f64_bits = f64_to_data(self_compiler, sum_f64, "f64_to_i64_cast");
     self_compiler
         .builder
         .build_unconditional_branch(marge)
@@ -1687,7 +2570,7 @@ fn create_add_expr_build_float_branch<'ctx>(
     self_compiler.builder.position_at_end(marge);
     let phi = self_compiler
         .builder
-        .build_phi(self_compiler.context.i64_type(), "float_add_res_phi")
+        .build_phi(self_compiler.data_type(), "float_add_res_phi")
         .unwrap();
     phi.add_incoming(&[
         (&res_f16_bits, bb_f16),
@@ -1706,10 +2589,82 @@ fn create_add_expr_build_float_branch<'ctx>(
     Ok(float_res_ptr)
 }
 
+// Non-escaping concatenation result: use a fixed buffer in the entry block when the
+// string fits, and fall back to `__malloc` for longer ones.
+fn build_stack_string_alloc<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    alloc_size: IntValue<'ctx>,
+    malloc_fn: FunctionValue<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let buffer_type = self_compiler
+        .context
+        .i8_type()
+        .array_type(STACK_STRING_BUFFER_SIZE as u32);
+    let buffer = create_entry_block_alloca_of(self_compiler, buffer_type.into(), "str_buf");
+
+    let current_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let stack_bb = self_compiler
+        .context
+        .append_basic_block(current_fn, "str_stack");
+    let heap_bb = self_compiler
+        .context
+        .append_basic_block(current_fn, "str_heap");
+    let merge_bb = self_compiler
+        .context
+        .append_basic_block(current_fn, "str_alloc_merge");
+
+    let limit = self_compiler
+        .data_type()
+        .const_int(STACK_STRING_BUFFER_SIZE, false);
+    let fits = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::ULE,
+            alloc_size,
+            limit,
+            "str_fits_buf",
+        )
+        .unwrap();
+    let _ = self_compiler
+        .builder
+        .build_conditional_branch(fits, stack_bb, heap_bb);
+
+    self_compiler.builder.position_at_end(stack_bb);
+    let _ = self_compiler.builder.build_unconditional_branch(merge_bb);
+
+    self_compiler.builder.position_at_end(heap_bb);
+    let malloc_call = self_compiler
+        .builder
+        .build_call(malloc_fn, &[alloc_size.into()], "malloc_call")
+        .unwrap();
+    let heap_ptr = match malloc_call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_pointer_value(),
+        _ => return Err("Expected basic value from malloc".to_string()),
+    };
+    let _ = self_compiler.builder.build_unconditional_branch(merge_bb);
+
+    self_compiler.builder.position_at_end(merge_bb);
+    let phi = self_compiler
+        .builder
+        .build_phi(
+            self_compiler.context.ptr_type(AddressSpace::default()),
+            "str_alloc_ptr",
+        )
+        .unwrap();
+    phi.add_incoming(&[(&buffer, stack_bb), (&heap_ptr, heap_bb)]);
+    Ok(phi.as_basic_value().into_pointer_value())
+}
+
 fn create_add_expr_build_string_branch<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
     l_ptr: PointerValue<'ctx>,
     r_ptr: PointerValue<'ctx>,
+    on_stack: bool,
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<PointerValue<'ctx>, String> {
     let l_str_data_ptr = self_compiler
@@ -1718,11 +2673,7 @@ fn create_add_expr_build_string_branch<'ctx>(
         .unwrap();
     let l_str_ptr_int = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            l_str_data_ptr,
-            "l_str_ptr_int",
-        )
+        .build_load(self_compiler.data_type(), l_str_data_ptr, "l_str_ptr_int")
         .unwrap()
         .into_int_value();
     let l_str_ptr = self_compiler
@@ -1739,11 +2690,7 @@ fn create_add_expr_build_string_branch<'ctx>(
         .unwrap();
     let r_str_ptr_int = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            r_str_data_ptr,
-            "r_str_ptr_int",
-        )
+        .build_load(self_compiler.data_type(), r_str_data_ptr, "r_str_ptr_int")
         .unwrap()
         .into_int_value();
     let r_str_ptr = self_compiler
@@ -1782,20 +2729,24 @@ fn create_add_expr_build_string_branch<'ctx>(
         .builder
         .build_int_add(l_len_val, r_len_val, "total_str_len")
         .unwrap();
-    let one = self_compiler.context.i64_type().const_int(1, false); // for null terminator
+    let one = self_compiler.data_type().const_int(1, false); // for null terminator
     let alloc_size = self_compiler
         .builder
         .build_int_add(total_len, one, "alloc_size")
         .unwrap();
 
-    let malloc_call = self_compiler
-        .builder
-        .build_call(malloc_fn, &[alloc_size.into()], "malloc_call")
-        .unwrap();
+    let malloc_ptr = if on_stack {
+        build_stack_string_alloc(self_compiler, alloc_size, malloc_fn)?
+    } else {
+        let malloc_call = self_compiler
+            .builder
+            .build_call(malloc_fn, &[alloc_size.into()], "malloc_call")
+            .unwrap();
 
-    let malloc_ptr = match malloc_call.try_as_basic_value() {
-        ValueKind::Basic(val) => val.into_pointer_value(),
-        _ => return Err("Expected basic value from malloc".to_string()),
+        match malloc_call.try_as_basic_value() {
+            ValueKind::Basic(val) => val.into_pointer_value(),
+            _ => return Err("Expected basic value from malloc".to_string()),
+        }
     };
 
     self_compiler
@@ -1848,8 +2799,7 @@ fn create_add_expr_build_string_branch<'ctx>(
         .unwrap();
 
     let check_string = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::String as u64, false);
 
     self_compiler
@@ -1868,11 +2818,7 @@ fn create_add_expr_build_string_branch<'ctx>(
         .unwrap();
     let malloc_ptr_as_i64 = self_compiler
         .builder
-        .build_ptr_to_int(
-            malloc_ptr,
-            self_compiler.context.i64_type(),
-            "malloc_ptr_as_i64",
-        )
+        .build_ptr_to_int(malloc_ptr, self_compiler.data_type(), "malloc_ptr_as_i64")
         .unwrap();
     self_compiler
         .builder
@@ -1901,7 +2847,7 @@ fn create_int8_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -1911,7 +2857,7 @@ fn create_int8_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -1924,13 +2870,18 @@ fn create_int8_add_logic<'ctx>(
         .build_int_truncate(r_val_i64, self_compiler.context.i8_type(), "r_trunc_i8")
         .unwrap();
 
-    let res_i8 = self_compiler
-        .builder
-        .build_int_add(l_i8, r_i8, "i8_sum")
-        .unwrap();
+    let res_i8 = build_int_arith(
+        self_compiler,
+        ArithOp::Add,
+        l_i8,
+        r_i8,
+        true,
+        module,
+        "i8_sum",
+    )?;
     let res_i64 = self_compiler
         .builder
-        .build_int_s_extend(res_i8, self_compiler.context.i64_type(), "i8_sum_ext")
+        .build_int_s_extend(res_i8, self_compiler.data_type(), "i8_sum_ext")
         .unwrap();
     let res_ptr = create_entry_block_alloca(self_compiler, "int8_add_res_alloc");
 
@@ -1963,7 +2914,7 @@ fn create_uint8_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -1973,7 +2924,7 @@ fn create_uint8_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -1986,13 +2937,18 @@ fn create_uint8_add_logic<'ctx>(
         .build_int_truncate(r_val_i64, self_compiler.context.i8_type(), "r_trunc_u8")
         .unwrap();
 
-    let res_u8 = self_compiler
-        .builder
-        .build_int_add(l_u8, r_u8, "u8_sum")
-        .unwrap();
+    let res_u8 = build_int_arith(
+        self_compiler,
+        ArithOp::Add,
+        l_u8,
+        r_u8,
+        false,
+        module,
+        "u8_sum",
+    )?;
     let res_i64 = self_compiler
         .builder
-        .build_int_z_extend(res_u8, self_compiler.context.i64_type(), "u8_sum_ext")
+        .build_int_z_extend(res_u8, self_compiler.data_type(), "u8_sum_ext")
         .unwrap();
     let res_ptr = create_entry_block_alloca(self_compiler, "uint8_add_res_alloc");
 
@@ -2025,7 +2981,7 @@ fn create_int16_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(_self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2035,7 +2991,7 @@ fn create_int16_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(_self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2048,13 +3004,18 @@ fn create_int16_add_logic<'ctx>(
         .build_int_truncate(r_val_i64, _self_compiler.context.i16_type(), "r_trunc_i16")
         .unwrap();
 
-    let res_i16 = _self_compiler
-        .builder
-        .build_int_add(l_i16, r_i16, "i16_sum")
-        .unwrap();
+    let res_i16 = build_int_arith(
+        _self_compiler,
+        ArithOp::Add,
+        l_i16,
+        r_i16,
+        true,
+        _module,
+        "i16_sum",
+    )?;
     let res_i64 = _self_compiler
         .builder
-        .build_int_s_extend(res_i16, _self_compiler.context.i64_type(), "i16_sum_ext")
+        .build_int_s_extend(res_i16, _self_compiler.data_type(), "i16_sum_ext")
         .unwrap();
     let res_ptr = create_entry_block_alloca(_self_compiler, "int16_add_res_alloc");
     _self_compiler.build_runtime_value_store(
@@ -2086,7 +3047,7 @@ fn create_uint16_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(_self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2096,7 +3057,7 @@ fn create_uint16_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(_self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2109,13 +3070,18 @@ fn create_uint16_add_logic<'ctx>(
         .build_int_truncate(r_val_i64, _self_compiler.context.i16_type(), "r_trunc_u16")
         .unwrap();
 
-    let res_u16 = _self_compiler
-        .builder
-        .build_int_add(l_u16, r_u16, "u16_sum")
-        .unwrap();
+    let res_u16 = build_int_arith(
+        _self_compiler,
+        ArithOp::Add,
+        l_u16,
+        r_u16,
+        false,
+        _module,
+        "u16_sum",
+    )?;
     let res_i64 = _self_compiler
         .builder
-        .build_int_z_extend(res_u16, _self_compiler.context.i64_type(), "u16_sum_ext")
+        .build_int_z_extend(res_u16, _self_compiler.data_type(), "u16_sum_ext")
         .unwrap();
     let res_ptr = create_entry_block_alloca(_self_compiler, "uint16_add_res_alloc");
     _self_compiler.build_runtime_value_store(
@@ -2147,7 +3113,7 @@ fn create_int32_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(_self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2157,7 +3123,7 @@ fn create_int32_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(_self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2170,13 +3136,18 @@ fn create_int32_add_logic<'ctx>(
         .build_int_truncate(r_val_i64, _self_compiler.context.i32_type(), "r_trunc_i32")
         .unwrap();
 
-    let res_i32 = _self_compiler
-        .builder
-        .build_int_add(l_i32, r_i32, "i32_sum")
-        .unwrap();
+    let res_i32 = build_int_arith(
+        _self_compiler,
+        ArithOp::Add,
+        l_i32,
+        r_i32,
+        true,
+        _module,
+        "i32_sum",
+    )?;
     let res_i64 = _self_compiler
         .builder
-        .build_int_s_extend(res_i32, _self_compiler.context.i64_type(), "i32_sum_ext")
+        .build_int_s_extend(res_i32, _self_compiler.data_type(), "i32_sum_ext")
         .unwrap();
     let res_ptr = create_entry_block_alloca(_self_compiler, "int32_add_res_alloc");
     _self_compiler.build_runtime_value_store(
@@ -2208,7 +3179,7 @@ fn create_uint32_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(_self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2218,7 +3189,7 @@ fn create_uint32_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(_self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2231,13 +3202,18 @@ fn create_uint32_add_logic<'ctx>(
         .build_int_truncate(r_val_i64, _self_compiler.context.i32_type(), "r_trunc_u32")
         .unwrap();
 
-    let res_u32 = _self_compiler
-        .builder
-        .build_int_add(l_u32, r_u32, "u32_sum")
-        .unwrap();
+    let res_u32 = build_int_arith(
+        _self_compiler,
+        ArithOp::Add,
+        l_u32,
+        r_u32,
+        false,
+        _module,
+        "u32_sum",
+    )?;
     let res_i64 = _self_compiler
         .builder
-        .build_int_z_extend(res_u32, _self_compiler.context.i64_type(), "u32_sum_ext")
+        .build_int_z_extend(res_u32, _self_compiler.data_type(), "u32_sum_ext")
         .unwrap();
     let res_ptr = create_entry_block_alloca(_self_compiler, "uint32_add_res_alloc");
     _self_compiler.build_runtime_value_store(
@@ -2269,7 +3245,7 @@ fn create_int64_add_logic<'ctx>(
         .unwrap();
     let l_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val")
         .unwrap()
         .into_int_value();
 
@@ -2279,14 +3255,19 @@ fn create_int64_add_logic<'ctx>(
         .unwrap();
     let r_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val")
         .unwrap()
         .into_int_value();
 
-    let res_val = self_compiler
-        .builder
-        .build_int_add(l_val, r_val, "i64_sum")
-        .unwrap();
+    let res_val = build_int_arith(
+        self_compiler,
+        ArithOp::Add,
+        l_val,
+        r_val,
+        true,
+        module,
+        "i64_sum",
+    )?;
 
     let res_ptr = create_entry_block_alloca(self_compiler, "int64_add_res_alloc");
 
@@ -2319,7 +3300,7 @@ fn create_uint64_add_logic<'ctx>(
         .unwrap();
     let l_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val")
         .unwrap()
         .into_int_value();
 
@@ -2329,14 +3310,19 @@ fn create_uint64_add_logic<'ctx>(
         .unwrap();
     let r_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val")
         .unwrap()
         .into_int_value();
 
-    let res_val = self_compiler
-        .builder
-        .build_int_add(l_val, r_val, "u64_sum")
-        .unwrap();
+    let res_val = build_int_arith(
+        self_compiler,
+        ArithOp::Add,
+        l_val,
+        r_val,
+        false,
+        module,
+        "u64_sum",
+    )?;
 
     let res_ptr = create_entry_block_alloca(self_compiler, "uint64_add_res_alloc");
 
@@ -2368,7 +3354,7 @@ fn create_float16_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(_self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2378,7 +3364,7 @@ fn create_float16_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = _self_compiler
         .builder
-        .build_load(_self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(_self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2413,7 +3399,7 @@ fn create_float16_add_logic<'ctx>(
         .into_int_value();
     let res_i64 = _self_compiler
         .builder
-        .build_int_s_extend(res_i16, _self_compiler.context.i64_type(), "f16_sum_to_i64")
+        .build_int_s_extend(res_i16, _self_compiler.data_type(), "f16_sum_to_i64")
         .unwrap();
     let res_ptr = create_entry_block_alloca(_self_compiler, "float16_add_res_alloc");
     _self_compiler.build_runtime_value_store(
@@ -2445,7 +3431,7 @@ fn create_float32_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2455,7 +3441,7 @@ fn create_float32_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2493,7 +3479,7 @@ fn create_float32_add_logic<'ctx>(
         .into_int_value();
     let res_i64 = self_compiler
         .builder
-        .build_int_z_extend(res_i32, self_compiler.context.i64_type(), "f32_sum_to_i64")
+        .build_int_z_extend(res_i32, self_compiler.data_type(), "f32_sum_to_i64")
         .unwrap();
     let res_ptr = create_entry_block_alloca(self_compiler, "float32_add_res_alloc");
     self_compiler.build_runtime_value_store(
@@ -2525,7 +3511,7 @@ fn create_float64_add_logic<'ctx>(
         .unwrap();
     let l_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val_i64")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val_i64")
         .unwrap()
         .into_int_value();
 
@@ -2535,30 +3521,18 @@ fn create_float64_add_logic<'ctx>(
         .unwrap();
     let r_val_i64 = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val_i64")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val_i64")
         .unwrap()
         .into_int_value();
 
-    let l_f64 = self_compiler
-        .builder
-        .build_bit_cast(l_val_i64, self_compiler.context.f64_type(), "l_i64_to_f64")
-        .unwrap()
-        .into_float_value();
-    let r_f64 = self_compiler
-        .builder
-        .build_bit_cast(r_val_i64, self_compiler.context.f64_type(), "r_i64_to_f64")
-        .unwrap()
-        .into_float_value();
+    let l_f64 = data_to_f64(self_compiler, l_val_i64, "l_i64_to_f64");
+    let r_f64 = data_to_f64(self_compiler, r_val_i64, "r_i64_to_f64");
 
     let res_f64 = self_compiler
         .builder
         .build_float_add(l_f64, r_f64, "f64_sum")
         .unwrap();
-    let res_i64 = self_compiler
-        .builder
-        .build_bit_cast(res_f64, self_compiler.context.i64_type(), "f64_sum_to_i64")
-        .unwrap()
-        .into_int_value();
+    let res_i64 = f64_to_data(self_compiler, res_f64, "f64_sum_to_i64");
 
     let res_ptr = create_entry_block_alloca(self_compiler, "float64_add_res_alloc");
     self_compiler.build_runtime_value_store(
@@ -2665,15 +3639,95 @@ where
         .compile_expr(rhs, module)?
         .into_pointer_value();
 
-    let l_data_ptr = self_compiler
-        .builder
-        .build_struct_gep(self_compiler.runtime_value_type, l_ptr, 1, "l_data_ptr")
-        .unwrap();
-    let l_val = self_compiler
-        .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val")
-        .unwrap()
-        .into_int_value();
+    // `list * n` and `"str" * n` repeat the left operand
+    let repeat = if let IntBinOp::Mul = op {
+        let l_tag_ptr = self_compiler
+            .builder
+            .build_struct_gep(self_compiler.runtime_value_type, l_ptr, 0, "l_tag_ptr")
+            .unwrap();
+        let l_tag = self_compiler
+            .builder
+            .build_load(self_compiler.tag_type(), l_tag_ptr, "l_tag")
+            .unwrap()
+            .into_int_value();
+
+        let parent_fn = self_compiler
+            .builder
+            .get_insert_block()
+            .unwrap()
+            .get_parent()
+            .unwrap();
+        let list_bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, "mul_list_bb");
+        let str_bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, "mul_str_bb");
+        let int_bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, "mul_int_bb");
+        let merge_bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, "mul_merge_bb");
+
+        let tag_type = self_compiler.tag_type();
+        self_compiler
+            .builder
+            .build_switch(
+                l_tag,
+                int_bb,
+                &[
+                    (tag_type.const_int(Tag::List as u64, false), list_bb),
+                    (tag_type.const_int(Tag::String as u64, false), str_bb),
+                ],
+            )
+            .unwrap();
+
+        self_compiler.builder.position_at_end(list_bb);
+        let list_res_ptr = create_collection_runtime_call(
+            self_compiler,
+            "__list_repeat",
+            l_ptr,
+            r_ptr,
+            false,
+            Tag::List,
+            module,
+        )?;
+        self_compiler
+            .builder
+            .build_unconditional_branch(merge_bb)
+            .unwrap();
+
+        self_compiler.builder.position_at_end(str_bb);
+        let str_res_ptr = create_collection_runtime_call(
+            self_compiler,
+            "__str_repeat",
+            l_ptr,
+            r_ptr,
+            false,
+            Tag::String,
+            module,
+        )?;
+        self_compiler
+            .builder
+            .build_unconditional_branch(merge_bb)
+            .unwrap();
+
+        self_compiler.builder.position_at_end(int_bb);
+        Some((list_res_ptr, list_bb, str_res_ptr, str_bb, merge_bb))
+    } else {
+        None
+    };
+
+    let l_data_ptr = self_compiler
+        .builder
+        .build_struct_gep(self_compiler.runtime_value_type, l_ptr, 1, "l_data_ptr")
+        .unwrap();
+    let l_val = self_compiler
+        .builder
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val")
+        .unwrap()
+        .into_int_value();
 
     let r_data_ptr = self_compiler
         .builder
@@ -2681,30 +3735,79 @@ where
         .unwrap();
     let r_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val")
         .unwrap()
         .into_int_value();
 
-    let result = op_fn(
-        &self_compiler.builder,
-        l_val,
-        r_val,
-        match op {
-            IntBinOp::Sub => "difference",
-            IntBinOp::Mul => "product",
-            IntBinOp::Div => "quotient",
-            IntBinOp::Mod => "remainder",
-        },
-    )?;
+    let checked = match op {
+        IntBinOp::Sub => Some((ArithOp::Sub, "difference")),
+        IntBinOp::Mul => Some((ArithOp::Mul, "product")),
+        IntBinOp::Div | IntBinOp::Mod => None,
+    }
+    .filter(|_| self_compiler.options.overflow_checks);
+    let (result, result_tag) = match checked {
+        Some((arith_op, name)) => {
+            let l_tag_ptr = self_compiler
+                .builder
+                .build_struct_gep(self_compiler.runtime_value_type, l_ptr, 0, "l_tag_ptr")
+                .unwrap();
+            let l_tag = self_compiler
+                .builder
+                .build_load(self_compiler.tag_type(), l_tag_ptr, "l_tag")
+                .unwrap()
+                .into_int_value();
+            let (result, tag) =
+                build_tagged_int_arith(self_compiler, arith_op, l_tag, l_val, r_val, module, name)?;
+            (result, StoreTag::Dynamic(tag))
+        }
+        None => {
+            let result = op_fn(
+                &self_compiler.builder,
+                l_val,
+                r_val,
+                match op {
+                    IntBinOp::Sub => "difference",
+                    IntBinOp::Mul => "product",
+                    IntBinOp::Div => "quotient",
+                    IntBinOp::Mod => "remainder",
+                },
+            )?;
+            (result, StoreTag::Int(Tag::Integer as u64))
+        }
+    };
 
     let res_ptr = create_entry_block_alloca(self_compiler, "res_alloc");
 
     self_compiler.build_runtime_value_store(
         res_ptr,
-        StoreTag::Int(Tag::Integer as u64),
+        result_tag,
         StoreValue::Int(result),
         "int_bin_op_res",
     );
+
+    if let Some((list_res_ptr, list_bb, str_res_ptr, str_bb, merge_bb)) = repeat {
+        let int_end_bb = self_compiler.builder.get_insert_block().unwrap();
+        self_compiler
+            .builder
+            .build_unconditional_branch(merge_bb)
+            .unwrap();
+
+        self_compiler.builder.position_at_end(merge_bb);
+        let phi = self_compiler
+            .builder
+            .build_phi(
+                self_compiler.context.ptr_type(AddressSpace::default()),
+                "mul_res_phi",
+            )
+            .unwrap();
+        phi.add_incoming(&[
+            (&list_res_ptr, list_bb),
+            (&str_res_ptr, str_bb),
+            (&res_ptr, int_end_bb),
+        ]);
+        return Ok(phi.as_basic_value());
+    }
+
     Ok(res_ptr.into())
 }
 
@@ -2740,36 +3843,43 @@ pub fn create_increment_or_decrement<'ctx>(
     let val = self_compiler
         .builder
         .build_load(
-            self_compiler.context.i64_type(),
+            self_compiler.data_type(),
             data_ptr,
             format!("{}_val", mode_str).as_str(),
         )
         .unwrap()
         .into_int_value();
 
-    let one = self_compiler.context.i64_type().const_int(1, false);
-    match mode {
-        UpDown::Up => {
-            let incremented = self_compiler
-                .builder
-                .build_int_add(val, one, "incremented")
-                .unwrap();
-            self_compiler
-                .builder
-                .build_store(data_ptr, incremented)
-                .unwrap();
-        }
-        UpDown::Down => {
-            let decremented = self_compiler
-                .builder
-                .build_int_sub(val, one, "decremented")
-                .unwrap();
-            self_compiler
-                .builder
-                .build_store(data_ptr, decremented)
-                .unwrap();
-        }
-    }
+    let one = self_compiler.data_type().const_int(1, false);
+    let (op, name) = match mode {
+        UpDown::Up => (ArithOp::Add, "incremented"),
+        UpDown::Down => (ArithOp::Sub, "decremented"),
+    };
+    // the variable keeps its tag, a sized integer is checked at its own width
+    let result = if self_compiler.options.overflow_checks {
+        let tag_ptr = self_compiler
+            .builder
+            .build_struct_gep(
+                self_compiler.runtime_value_type,
+                val_ptr,
+                0,
+                format!("{}_tag_ptr", mode_str).as_str(),
+            )
+            .unwrap();
+        let tag = self_compiler
+            .builder
+            .build_load(
+                self_compiler.tag_type(),
+                tag_ptr,
+                format!("{}_tag", mode_str).as_str(),
+            )
+            .unwrap()
+            .into_int_value();
+        build_tagged_int_arith(self_compiler, op, tag, val, one, module, name)?.0
+    } else {
+        build_int_arith(self_compiler, op, val, one, true, module, name)?
+    };
+    self_compiler.builder.build_store(data_ptr, result).unwrap();
 
     Ok(val_ptr.into())
 }
@@ -2808,7 +3918,7 @@ where
         .unwrap();
     let l_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val")
         .unwrap()
         .into_int_value();
 
@@ -2818,7 +3928,7 @@ where
         .unwrap();
     let r_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val")
         .unwrap()
         .into_int_value();
 
@@ -2880,7 +3990,7 @@ where
         .unwrap();
     let l_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), l_data_ptr, "l_val")
+        .build_load(self_compiler.data_type(), l_data_ptr, "l_val")
         .unwrap()
         .into_int_value();
 
@@ -2890,7 +4000,7 @@ where
         .unwrap();
     let r_val = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), r_data_ptr, "r_val")
+        .build_load(self_compiler.data_type(), r_data_ptr, "r_val")
         .unwrap()
         .into_int_value();
 
@@ -2943,6 +4053,7 @@ pub fn create_if_expr<'ctx>(
 
     let cond_val = self_compiler.compile_expr(cond, module)?;
     let cond_ptr = cond_val.into_pointer_value();
+    check_bool_condition(self_compiler, cond_ptr, "if", module)?;
     let cond_data_ptr = self_compiler
         .builder
         .build_struct_gep(
@@ -2954,14 +4065,10 @@ pub fn create_if_expr<'ctx>(
         .unwrap();
     let cond_loaded = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            cond_data_ptr,
-            "cond_loaded",
-        )
+        .build_load(self_compiler.data_type(), cond_data_ptr, "cond_loaded")
         .unwrap()
         .into_int_value();
-    let zero = self_compiler.context.i64_type().const_int(0, false);
+    let zero = self_compiler.data_type().const_int(0, false);
     let cond_bool = self_compiler
         .builder
         .build_int_compare(inkwell::IntPredicate::NE, cond_loaded, zero, "if_cond_bool")
@@ -3028,7 +4135,7 @@ pub fn create_list<'ctx>(
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
     let list_ptr = self_compiler.build_list_from_exprs(elements, module)?;
-    let i64_type = self_compiler.context.i64_type();
+    let data_type = self_compiler.data_type();
 
     let res_ptr = create_entry_block_alloca(self_compiler, "list_res_alloc");
     let res_tag_ptr = self_compiler
@@ -3039,10 +4146,7 @@ pub fn create_list<'ctx>(
         .builder
         .build_store(
             res_tag_ptr,
-            self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::List as u64, false),
+            self_compiler.tag_type().const_int(Tag::List as u64, false),
         )
         .unwrap();
 
@@ -3052,7 +4156,7 @@ pub fn create_list<'ctx>(
         .unwrap();
     let list_ptr_as_int = self_compiler
         .builder
-        .build_ptr_to_int(list_ptr, i64_type, "list_ptr_as_int")
+        .build_ptr_to_int(list_ptr, data_type, "list_ptr_as_int")
         .unwrap();
     self_compiler
         .builder
@@ -3068,6 +4172,10 @@ pub fn create_index<'ctx>(
     index_expr: &ast::Expr,
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
+    if let ast::Expr::Range(start_expr, end_expr) = index_expr {
+        return create_slice(self_compiler, collection_expr, start_expr, end_expr, module);
+    }
+
     let get_fn = self_compiler.get_runtime_fn(module, "__list_get");
 
     let collection_var_ptr = self_compiler
@@ -3085,11 +4193,7 @@ pub fn create_index<'ctx>(
         .unwrap();
     let list_ptr_int = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            list_data_ptr,
-            "list_ptr_int",
-        )
+        .build_load(self_compiler.data_type(), list_data_ptr, "list_ptr_int")
         .unwrap()
         .into_int_value();
 
@@ -3116,15 +4220,83 @@ pub fn create_index<'ctx>(
         )
         .unwrap();
     let index_int = self_compiler
+        .builder
+        .build_load(self_compiler.data_type(), index_data_ptr, "index_int")
+        .unwrap()
+        .into_int_value();
+
+    // Strings are indexed by character and yield a one character string.
+    let collection_tag_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            collection_var_ptr,
+            0,
+            "collection_tag_ptr",
+        )
+        .unwrap();
+    let collection_tag = self_compiler
         .builder
         .build_load(
-            self_compiler.context.i64_type(),
-            index_data_ptr,
-            "index_int",
+            self_compiler.tag_type(),
+            collection_tag_ptr,
+            "collection_tag",
         )
         .unwrap()
         .into_int_value();
+    let is_string = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::EQ,
+            collection_tag,
+            self_compiler
+                .tag_type()
+                .const_int(Tag::String as u64, false),
+            "is_string",
+        )
+        .unwrap();
+
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let str_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "index_str_bb");
+    let list_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "index_list_bb");
+    let merge_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "index_merge_bb");
+
+    self_compiler
+        .builder
+        .build_conditional_branch(is_string, str_bb, list_bb)
+        .unwrap();
+
+    // string branch
+    self_compiler.builder.position_at_end(str_bb);
+    let str_get_fn = self_compiler.get_runtime_fn(module, "__str_get");
+    let str_res_ptr = create_entry_block_alloca(self_compiler, "str_get_res");
+    self_compiler
+        .builder
+        .build_call(
+            str_get_fn,
+            &[list_ptr.into(), index_int.into(), str_res_ptr.into()],
+            "",
+        )
+        .unwrap();
+    let str_val: BasicValueEnum = str_res_ptr.into();
+    self_compiler
+        .builder
+        .build_unconditional_branch(merge_bb)
+        .unwrap();
 
+    // list branch
+    self_compiler.builder.position_at_end(list_bb);
     let get_call = self_compiler
         .builder
         .build_call(
@@ -3133,11 +4305,200 @@ pub fn create_index<'ctx>(
             "list_get_call",
         )
         .unwrap();
+    let list_val = match get_call.try_as_basic_value() {
+        ValueKind::Basic(val) => val,
+        ValueKind::Instruction(_) => {
+            return Err("Expected basic value from __list_get".to_string());
+        }
+    };
+    self_compiler
+        .builder
+        .build_unconditional_branch(merge_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(merge_bb);
+    let phi = self_compiler
+        .builder
+        .build_phi(
+            self_compiler.context.ptr_type(AddressSpace::default()),
+            "index_result",
+        )
+        .unwrap();
+    phi.add_incoming(&[(&str_val, str_bb), (&list_val, list_bb)]);
 
-    match get_call.try_as_basic_value() {
-        ValueKind::Basic(val) => Ok(val),
-        ValueKind::Instruction(_) => Err("Expected basic value from __list_get".to_string()),
+    Ok(phi.as_basic_value())
+}
+
+// `s[start..end]` and `xs[start..end]`: a substring or a new list, bounds checked by the
+// runtime.
+fn create_slice<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    collection_expr: &ast::Expr,
+    start_expr: &ast::Expr,
+    end_expr: &ast::Expr,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let (tag, data) = load_operand(self_compiler, collection_expr, module)?;
+    let is_sliceable = tag_in(self_compiler, tag, &[Tag::String, Tag::List]);
+    panic_unless(
+        self_compiler,
+        is_sliceable,
+        "TypeError: only a Str or a List can be sliced",
+        module,
+    )?;
+    let is_str = tag_in(self_compiler, tag, &[Tag::String]);
+    let collection = self_compiler
+        .builder
+        .build_int_to_ptr(
+            data,
+            self_compiler.context.ptr_type(AddressSpace::default()),
+            "slice_collection",
+        )
+        .unwrap();
+    let start = load_int_operand(self_compiler, start_expr, "a slice", module)?;
+    let end = load_int_operand(self_compiler, end_expr, "a slice", module)?;
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "slice_res_alloc");
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let str_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "slice_str_bb");
+    let list_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "slice_list_bb");
+    let merge_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "slice_merge_bb");
+    self_compiler
+        .builder
+        .build_conditional_branch(is_str, str_bb, list_bb)
+        .unwrap();
+
+    for (block, slice_fn, result_tag) in [
+        (str_bb, "__str_slice", Tag::String),
+        (list_bb, "__list_slice", Tag::List),
+    ] {
+        self_compiler.builder.position_at_end(block);
+        let slice_fn = self_compiler.get_runtime_fn(module, slice_fn);
+        let call = self_compiler
+            .builder
+            .build_call(
+                slice_fn,
+                &[collection.into(), start.into(), end.into()],
+                "slice_call",
+            )
+            .unwrap();
+        let slice = match call.try_as_basic_value() {
+            ValueKind::Basic(val) => val.into_pointer_value(),
+            ValueKind::Instruction(_) => {
+                return Err("Expected basic value from the slice call".to_string());
+            }
+        };
+        let slice_data = self_compiler
+            .builder
+            .build_ptr_to_int(slice, self_compiler.data_type(), "slice_data")
+            .unwrap();
+        self_compiler.build_runtime_value_store(
+            result_ptr,
+            StoreTag::Int(result_tag as u64),
+            StoreValue::Int(slice_data),
+            "slice_res",
+        );
+        self_compiler
+            .builder
+            .build_unconditional_branch(merge_bb)
+            .unwrap();
     }
+
+    self_compiler.builder.position_at_end(merge_bb);
+    Ok(result_ptr.into())
+}
+
+pub fn create_in_expr<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    needle_expr: &ast::Expr,
+    container_expr: &ast::Expr,
+    on_stack: bool,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let (needle_tag, needle_data) = load_operand(self_compiler, needle_expr, module)?;
+    let mut args: Vec<inkwell::values::BasicMetadataValueEnum> =
+        vec![needle_tag.into(), needle_data.into()];
+
+    let contains_fn = match container_expr {
+        // a list literal that is only searched is built in an array in the entry block
+        ast::Expr::List(elements) if on_stack => {
+            let array_type = self_compiler
+                .runtime_value_type
+                .array_type(elements.len() as u32);
+            let items = create_entry_block_alloca_of(self_compiler, array_type.into(), "in_items");
+            let i32_type = self_compiler.context.i32_type();
+            for (index, elem) in elements.iter().enumerate() {
+                let val_ptr = self_compiler
+                    .compile_expr(elem, module)?
+                    .into_pointer_value();
+                let val = self_compiler
+                    .builder
+                    .build_load(self_compiler.runtime_value_type, val_ptr, "in_item")
+                    .unwrap();
+                let slot = unsafe {
+                    self_compiler
+                        .builder
+                        .build_in_bounds_gep(
+                            array_type,
+                            items,
+                            &[
+                                i32_type.const_zero(),
+                                i32_type.const_int(index as u64, false),
+                            ],
+                            "in_item_slot",
+                        )
+                        .unwrap()
+                };
+                self_compiler.builder.build_store(slot, val).unwrap();
+            }
+            args.push(items.into());
+            args.push(
+                self_compiler
+                    .data_type()
+                    .const_int(elements.len() as u64, false)
+                    .into(),
+            );
+            self_compiler.get_runtime_fn(module, "__contains_values")
+        }
+        _ => {
+            let (container_tag, container_data) =
+                load_operand(self_compiler, container_expr, module)?;
+            args.push(container_tag.into());
+            args.push(container_data.into());
+            self_compiler.get_runtime_fn(module, "__contains")
+        }
+    };
+
+    let contains_call = self_compiler
+        .builder
+        .build_call(contains_fn, &args, "contains_call")
+        .unwrap();
+    let found = match contains_call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_int_value(),
+        ValueKind::Instruction(_) => {
+            return Err("Expected basic value from __contains".to_string());
+        }
+    };
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "in_res_alloc");
+    self_compiler.build_runtime_value_store(
+        res_ptr,
+        StoreTag::Int(Tag::Boolean as u64),
+        StoreValue::Int(found),
+        "in_res",
+    );
+    Ok(res_ptr.into())
 }
 
 pub fn create_range<'ctx>(
@@ -3161,11 +4522,7 @@ pub fn create_range<'ctx>(
         .unwrap();
     let start_int = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            start_data_ptr,
-            "start_int",
-        )
+        .build_load(self_compiler.data_type(), start_data_ptr, "start_int")
         .unwrap()
         .into_int_value();
 
@@ -3183,7 +4540,7 @@ pub fn create_range<'ctx>(
         .unwrap();
     let end_int = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), end_data_ptr, "end_int")
+        .build_load(self_compiler.data_type(), end_data_ptr, "end_int")
         .unwrap()
         .into_int_value();
 
@@ -3208,10 +4565,7 @@ pub fn create_range<'ctx>(
         .builder
         .build_store(
             res_tag_ptr,
-            self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Range as u64, false),
+            self_compiler.tag_type().const_int(Tag::Range as u64, false),
         )
         .unwrap();
 
@@ -3221,11 +4575,7 @@ pub fn create_range<'ctx>(
         .unwrap();
     let range_ptr_as_int = self_compiler
         .builder
-        .build_ptr_to_int(
-            range_ptr,
-            self_compiler.context.i64_type(),
-            "range_ptr_as_int",
-        )
+        .build_ptr_to_int(range_ptr, self_compiler.data_type(), "range_ptr_as_int")
         .unwrap();
     self_compiler
         .builder
@@ -3241,6 +4591,26 @@ pub fn create_module_access<'ctx>(
     args: &Vec<ast::Expr>,
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
+    // `import sensors as s;` makes `s.read()` refer to sensors
+    let module_name = module
+        .get_name()
+        .to_str()
+        .ok()
+        .and_then(|name| self_compiler.module_aliases.get(name))
+        .and_then(|aliases| aliases.get(module_name))
+        .cloned()
+        .unwrap_or_else(|| module_name.to_string());
+    let module_name = module_name.as_str();
+    if self_compiler.is_builtin_module(module_name) {
+        if module_name == file::MODULE {
+            return create_file_call(self_compiler, function_name, args, module);
+        }
+        if module_name == string::MODULE {
+            return create_string_call(self_compiler, function_name, args, module);
+        }
+        return create_math_call(self_compiler, function_name, args, module);
+    }
+
     let target_module = self_compiler
         .modules
         .get(module_name)
@@ -3252,6 +4622,11 @@ pub fn create_module_access<'ctx>(
             function_name, module_name
         )
     })?;
+    if target_func.get_linkage() == Linkage::Private
+        && module.get_name() != target_module.get_name()
+    {
+        return Err(private_function_error(function_name, module_name));
+    }
 
     let func_in_current_module = if let Some(func) = module.get_function(&function_name) {
         func
@@ -3259,11 +4634,7 @@ pub fn create_module_access<'ctx>(
         module.add_function(&function_name, target_func.get_type(), None)
     };
 
-    let mut compiled_args = Vec::with_capacity(args.len());
-    for arg_expr in args {
-        let arg_val = self_compiler.compile_expr(arg_expr, module)?.into();
-        compiled_args.push(arg_val);
-    }
+    let compiled_args = compile_call_args(self_compiler, args, module)?;
 
     let call_site = self_compiler
         .builder
@@ -3309,11 +4680,7 @@ pub fn create_field_access<'ctx>(
 
     let heap_ptr_int = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            struct_data_ptr,
-            "heap_ptr_int",
-        )
+        .build_load(self_compiler.data_type(), struct_data_ptr, "heap_ptr_int")
         .unwrap()
         .into_int_value();
 
@@ -3331,7 +4698,7 @@ pub fn create_field_access<'ctx>(
         .get(struct_name)
         .ok_or_else(|| format!("Undefined struct : {}", struct_name))?;
     let llvm_type = struct_def.llvm_type;
-    let field_def = &struct_def.fields[field_index as usize];
+    let field_def = &struct_def.fields[field_index as usize - 1];
 
     let struct_ptr_typed = self_compiler
         .builder
@@ -3355,7 +4722,7 @@ pub fn create_field_access<'ctx>(
                 | crate::interpreter::type_helper::Type::TypeU64 => {
                     let val = self_compiler
                         .builder
-                        .build_load(self_compiler.context.i64_type(), field_ptr, "field_val")
+                        .build_load(self_compiler.data_type(), field_ptr, "field_val")
                         .unwrap()
                         .into_int_value();
 
@@ -3381,11 +4748,7 @@ pub fn create_field_access<'ctx>(
                         .into_pointer_value();
                     let var_int = self_compiler
                         .builder
-                        .build_ptr_to_int(
-                            val,
-                            self_compiler.context.i64_type(),
-                            "str_field_ptr_as_int",
-                        )
+                        .build_ptr_to_int(val, self_compiler.data_type(), "str_field_ptr_as_int")
                         .unwrap();
                     let res_ptr =
                         create_entry_block_alloca(self_compiler, "str_field_access_res_alloc");
@@ -3402,19 +4765,200 @@ pub fn create_field_access<'ctx>(
         }
     }
 
+    // the struct drops its fields with itself, so a read hands out a copy of heap values
     let field_val = self_compiler
         .builder
         .build_load(self_compiler.runtime_value_type, field_ptr, "field_val")
+        .unwrap()
+        .into_struct_value();
+    let field_tag = self_compiler
+        .builder
+        .build_extract_value(field_val, 0, "field_tag")
         .unwrap();
-
-    let res_ptr = create_entry_block_alloca(self_compiler, "field_access_res_alloc");
-
-    self_compiler
+    let field_data = self_compiler
         .builder
-        .build_store(res_ptr, field_val)
+        .build_extract_value(field_val, 1, "field_data")
         .unwrap();
-
-    Ok(res_ptr.into())
+    let clone_fn = self_compiler.get_runtime_fn(module, "__clone");
+    let call_site = self_compiler
+        .builder
+        .build_call(
+            clone_fn,
+            &[field_tag.into(), field_data.into()],
+            "field_clone",
+        )
+        .unwrap();
+    let ValueKind::Basic(cloned) = call_site.try_as_basic_value() else {
+        return Err("Expected basic value from clone function".to_string());
+    };
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "field_access_res_alloc");
+
+    self_compiler.builder.build_store(res_ptr, cloned).unwrap();
+
+    Ok(res_ptr.into())
+}
+
+// `math.sqrt(x)` and the other functions of the built-in math package (see math.rs).
+fn create_math_call<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    function_name: &str,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let arity =
+        math::arity(function_name).ok_or_else(|| math::unknown_function_error(function_name))?;
+    let qualified_name = format!("{}.{}", math::MODULE, function_name);
+    if args.len() != arity {
+        return Err(format!("{} expects {} arguments", qualified_name, arity));
+    }
+
+    let mut f64_args: Vec<inkwell::values::BasicMetadataValueEnum> = Vec::new();
+    for arg in args {
+        let (tag, data) = load_numeric_operand(self_compiler, arg, &qualified_name, module)?;
+        f64_args.push(numeric_to_f64(self_compiler, tag, data).into());
+    }
+    let runtime_fn = self_compiler.get_runtime_fn(module, &format!("__math_{}", function_name));
+    let call = self_compiler
+        .builder
+        .build_call(runtime_fn, &f64_args, "math_call")
+        .unwrap();
+    let result = match call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_float_value(),
+        ValueKind::Instruction(_) => {
+            return Err(format!("{} did not return a value", qualified_name));
+        }
+    };
+
+    let data = f64_to_data(self_compiler, result, "math_res_data");
+    let result_ptr = create_entry_block_alloca(self_compiler, "math_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(Tag::Float as u64),
+        StoreValue::Int(data),
+        "math_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// `file.open(path, mode)` and the other functions of the built-in file package (see file.rs).
+fn create_file_call<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    function_name: &str,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let arity =
+        file::arity(function_name).ok_or_else(|| file::unknown_function_error(function_name))?;
+    let qualified_name = format!("{}.{}", file::MODULE, function_name);
+    if args.len() != arity {
+        return Err(format!("{} expects {} arguments", qualified_name, arity));
+    }
+
+    // open(path: Str, mode: Str), the others take the handle first and write's text second
+    let mut call_args: Vec<inkwell::values::BasicMetadataValueEnum> = Vec::new();
+    for (i, arg) in args.iter().enumerate() {
+        if function_name == "open" || i == 1 {
+            let text = load_str_operand(self_compiler, arg, &qualified_name, module)?;
+            call_args.push(text.into());
+        } else {
+            let handle = load_int_operand(self_compiler, arg, &qualified_name, module)?;
+            call_args.push(handle.into());
+        }
+    }
+    let runtime_fn = self_compiler.get_runtime_fn(module, &format!("__file_{}", function_name));
+    let call = self_compiler
+        .builder
+        .build_call(runtime_fn, &call_args, "file_call")
+        .unwrap();
+
+    let (tag, data) = match (function_name, call.try_as_basic_value()) {
+        ("open", ValueKind::Basic(handle)) => (Tag::Integer, handle.into_int_value()),
+        ("read_all", ValueKind::Basic(text)) => {
+            let data = self_compiler
+                .builder
+                .build_ptr_to_int(
+                    text.into_pointer_value(),
+                    self_compiler.data_type(),
+                    "file_text_data",
+                )
+                .unwrap();
+            (Tag::String, data)
+        }
+        ("open" | "read_all", ValueKind::Instruction(_)) => {
+            return Err(format!("{} did not return a value", qualified_name));
+        }
+        _ => return create_unit(self_compiler),
+    };
+    let result_ptr = create_entry_block_alloca(self_compiler, "file_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(tag as u64),
+        StoreValue::Int(data),
+        "file_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// `string.split(s, sep)` and the other functions of the built-in string package (see string.rs).
+fn create_string_call<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    function_name: &str,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let arity = string::arity(function_name)
+        .ok_or_else(|| string::unknown_function_error(function_name))?;
+    let qualified_name = format!("{}.{}", string::MODULE, function_name);
+    if args.len() != arity {
+        return Err(format!("{} expects {} arguments", qualified_name, arity));
+    }
+
+    let mut call_args: Vec<inkwell::values::BasicMetadataValueEnum> = Vec::new();
+    for arg in args {
+        let text = load_str_operand(self_compiler, arg, &qualified_name, module)?;
+        call_args.push(text.into());
+    }
+    let runtime_fn = self_compiler.get_runtime_fn(module, &format!("__str_{}", function_name));
+    let call = self_compiler
+        .builder
+        .build_call(runtime_fn, &call_args, "string_call")
+        .unwrap();
+    let result = match call.try_as_basic_value() {
+        ValueKind::Basic(val) => val,
+        ValueKind::Instruction(_) => {
+            return Err(format!("{} did not return a value", qualified_name));
+        }
+    };
+
+    let (tag, data) = match function_name {
+        "contains" => (Tag::Boolean, result.into_int_value()),
+        "find" => (Tag::Integer, result.into_int_value()),
+        _ => {
+            let tag = if let "split" | "chars" = function_name {
+                Tag::List
+            } else {
+                Tag::String
+            };
+            let data = self_compiler
+                .builder
+                .build_ptr_to_int(
+                    result.into_pointer_value(),
+                    self_compiler.data_type(),
+                    "string_res_data",
+                )
+                .unwrap();
+            (tag, data)
+        }
+    };
+    let result_ptr = create_entry_block_alloca(self_compiler, "string_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(tag as u64),
+        StoreValue::Int(data),
+        "string_res",
+    );
+    Ok(result_ptr.into())
 }
 
 pub fn create_unit<'ctx>(
@@ -3440,10 +4984,27 @@ pub fn create_struct_init<'ctx>(
     let field_indices = struct_def.field_indices.clone();
     let def_fields = struct_def.fields.clone();
 
-    let struct_ptr = self_compiler
+    let struct_ptr = build_runtime_malloc(
+        self_compiler,
+        llvm_type,
+        &format!("{}_struct_alloc", struct_name),
+        module,
+    )?;
+    // fields left out of the literal are Int 0, which dropping the struct skips
+    self_compiler
         .builder
-        .build_malloc(llvm_type, &format!("{}_struct_alloc", struct_name))
-        .map_err(|e| e.to_string())?;
+        .build_store(struct_ptr, llvm_type.const_zero())
+        .unwrap();
+    track_struct_alloc(self_compiler, struct_ptr, llvm_type, module);
+    let vtable = struct_vtable(self_compiler, struct_name, module)?;
+    let vtable_ptr = self_compiler
+        .builder
+        .build_struct_gep(llvm_type, struct_ptr, 0, "vtable_ptr")
+        .unwrap();
+    self_compiler
+        .builder
+        .build_store(vtable_ptr, vtable)
+        .unwrap();
 
     for (field_name, field_expr) in field_exprs {
         let index = field_indices.get(field_name).ok_or_else(|| {
@@ -3488,7 +5049,7 @@ pub fn create_struct_init<'ctx>(
                             .unwrap();
                         let int_val = self_compiler
                             .builder
-                            .build_load(self_compiler.context.i64_type(), data_ptr, "int_field_val")
+                            .build_load(self_compiler.data_type(), data_ptr, "int_field_val")
                             .unwrap()
                             .into_int_value();
                         self_compiler
@@ -3510,11 +5071,7 @@ pub fn create_struct_init<'ctx>(
                             .unwrap();
                         let str_ptr_int = self_compiler
                             .builder
-                            .build_load(
-                                self_compiler.context.i64_type(),
-                                data_ptr,
-                                "str_field_ptr_int",
-                            )
+                            .build_load(self_compiler.data_type(), data_ptr, "str_field_ptr_int")
                             .unwrap()
                             .into_int_value();
                         let str_ptr = self_compiler
@@ -3552,16 +5109,19 @@ pub fn create_struct_init<'ctx>(
             .builder
             .build_store(field_ptr, val_to_store)
             .unwrap();
+
+        // the struct owns the field now and drops it with itself
+        if let ast::Expr::Var(src_name) = field_expr {
+            if let Some((src_val, _)) = self_compiler.get_variables(src_name) {
+                move_variable(self_compiler, &src_val, src_name);
+            }
+        }
     }
 
-    let allloca = self_compiler
-        .builder
-        .build_alloca(self_compiler.runtime_value_type, "struct_init_res_alloc")
-        .unwrap();
+    let allloca = create_entry_block_alloca(self_compiler, "struct_init_res_alloc");
 
     let tag = self_compiler
-        .context
-        .i32_type()
+        .tag_type()
         .const_int(Tag::Struct as u64, false);
     let tag_ptr = self_compiler
         .builder
@@ -3571,11 +5131,7 @@ pub fn create_struct_init<'ctx>(
 
     let data_int = self_compiler
         .builder
-        .build_ptr_to_int(
-            struct_ptr,
-            self_compiler.context.i64_type(),
-            "struct_ptr_as_int",
-        )
+        .build_ptr_to_int(struct_ptr, self_compiler.data_type(), "struct_ptr_as_int")
         .unwrap();
     let data_ptr = self_compiler
         .builder
@@ -3589,6 +5145,247 @@ pub fn create_struct_init<'ctx>(
     Ok(allloca.into())
 }
 
+// Every struct allocation starts with a pointer to `<Struct>.vtable`, a `{ drop, clone }` pair
+// generated from the definition. `__drop` and `__clone` only see the Struct tag, so they go
+// through it to free or copy the fields, nested structs included.
+pub fn struct_vtable<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    struct_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let name = format!("{}.vtable", struct_name);
+    if let Some(existing) = module.get_global(&name) {
+        return Ok(existing.as_pointer_value());
+    }
+
+    let saved_block = self_compiler.builder.get_insert_block();
+    // the generated functions have no source lines of their own
+    let saved_location = self_compiler.builder.get_current_debug_location();
+    self_compiler.builder.unset_current_debug_location();
+    let drop_fn = build_struct_drop_fn(self_compiler, struct_name, module)?;
+    let clone_fn = build_struct_clone_fn(self_compiler, struct_name, module)?;
+    if let Some(block) = saved_block {
+        self_compiler.builder.position_at_end(block);
+    }
+    if let Some(location) = saved_location {
+        self_compiler.builder.set_current_debug_location(location);
+    }
+
+    let vtable_val = self_compiler.context.const_struct(
+        &[
+            drop_fn.as_global_value().as_pointer_value().into(),
+            clone_fn.as_global_value().as_pointer_value().into(),
+        ],
+        false,
+    );
+    let global = module.add_global(vtable_val.get_type(), Some(AddressSpace::default()), &name);
+    global.set_initializer(&vtable_val);
+    global.set_constant(true);
+    global.set_linkage(Linkage::Private);
+    Ok(global.as_pointer_value())
+}
+
+// Structs and enum infos are allocated and freed through the runtime's `__malloc`/`__free`, so
+// they come from the `[runtime] allocator` of sprs.toml like everything else on the heap.
+pub fn build_runtime_malloc<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    llvm_type: inkwell::types::StructType<'ctx>,
+    name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let size = type_size(self_compiler, llvm_type);
+    let malloc_fn = self_compiler.get_runtime_fn(module, "__malloc");
+    let call = self_compiler
+        .builder
+        .build_call(malloc_fn, &[size.into()], name)
+        .unwrap();
+    match call.try_as_basic_value() {
+        ValueKind::Basic(val) => Ok(val.into_pointer_value()),
+        ValueKind::Instruction(_) => Err("Expected basic value from __malloc".to_string()),
+    }
+}
+
+fn build_runtime_free<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    ptr: PointerValue<'ctx>,
+    llvm_type: inkwell::types::StructType<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+) {
+    let size = type_size(self_compiler, llvm_type);
+    let free_fn = self_compiler.get_runtime_fn(module, "__free");
+    self_compiler
+        .builder
+        .build_call(free_fn, &[ptr.into(), size.into()], "")
+        .unwrap();
+}
+
+fn type_size<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    llvm_type: inkwell::types::StructType<'ctx>,
+) -> IntValue<'ctx> {
+    self_compiler
+        .builder
+        .build_int_cast(
+            llvm_type.size_of().unwrap(),
+            self_compiler.data_type(),
+            "struct_size",
+        )
+        .unwrap()
+}
+
+// `--debug-alloc`: `__malloc` records a string buffer, so the runtime is told that it is a
+// struct (`__drop` forgets it again).
+fn track_struct_alloc<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    struct_ptr: PointerValue<'ctx>,
+    llvm_type: inkwell::types::StructType<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+) {
+    if !self_compiler.options.debug_alloc {
+        return;
+    }
+    let size = type_size(self_compiler, llvm_type);
+    let tag = self_compiler
+        .tag_type()
+        .const_int(Tag::Struct as u64, false);
+    let track_fn = self_compiler.get_runtime_fn(module, "__alloc_track");
+    self_compiler
+        .builder
+        .build_call(track_fn, &[struct_ptr.into(), size.into(), tag.into()], "")
+        .unwrap();
+}
+
+// The llvm indices of the fields stored as runtime values, which may own heap data.
+fn struct_value_fields<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    struct_name: &str,
+) -> Result<(inkwell::types::StructType<'ctx>, Vec<u32>), String> {
+    let llvm_type = self_compiler
+        .struct_defs
+        .get(struct_name)
+        .ok_or_else(|| format!("Undefined struct : {}", struct_name))?
+        .llvm_type;
+    let value_type: inkwell::types::BasicTypeEnum = self_compiler.runtime_value_type.into();
+    let indices = (1..llvm_type.count_fields())
+        .filter(|i| llvm_type.get_field_type_at_index(*i) == Some(value_type))
+        .collect();
+    Ok((llvm_type, indices))
+}
+
+// void <Struct>.drop(ptr): drops every field, then frees the allocation.
+fn build_struct_drop_fn<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    struct_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<FunctionValue<'ctx>, String> {
+    let (llvm_type, value_fields) = struct_value_fields(self_compiler, struct_name)?;
+    let ptr_type = self_compiler.context.ptr_type(AddressSpace::default());
+    let fn_type = self_compiler
+        .context
+        .void_type()
+        .fn_type(&[ptr_type.into()], false);
+    let drop_fn = module.add_function(
+        &format!("{}.drop", struct_name),
+        fn_type,
+        Some(Linkage::Private),
+    );
+    let entry = self_compiler.context.append_basic_block(drop_fn, "entry");
+    self_compiler.builder.position_at_end(entry);
+
+    let struct_ptr = drop_fn.get_nth_param(0).unwrap().into_pointer_value();
+    let runtime_drop = self_compiler.get_runtime_fn(module, "__drop");
+    for index in value_fields {
+        let field_ptr = self_compiler
+            .builder
+            .build_struct_gep(llvm_type, struct_ptr, index, "field_ptr")
+            .unwrap();
+        self_compiler.build_sprs_value_call_func(field_ptr, runtime_drop, "field", &[], false);
+    }
+    build_runtime_free(self_compiler, struct_ptr, llvm_type, module);
+    self_compiler.builder.build_return(None).unwrap();
+    Ok(drop_fn)
+}
+
+// ptr <Struct>.clone(ptr): a new allocation with every field cloned.
+fn build_struct_clone_fn<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    struct_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<FunctionValue<'ctx>, String> {
+    let (llvm_type, value_fields) = struct_value_fields(self_compiler, struct_name)?;
+    let ptr_type = self_compiler.context.ptr_type(AddressSpace::default());
+    let fn_type = ptr_type.fn_type(&[ptr_type.into()], false);
+    let clone_fn = module.add_function(
+        &format!("{}.clone", struct_name),
+        fn_type,
+        Some(Linkage::Private),
+    );
+    let entry = self_compiler.context.append_basic_block(clone_fn, "entry");
+    self_compiler.builder.position_at_end(entry);
+
+    let src_ptr = clone_fn.get_nth_param(0).unwrap().into_pointer_value();
+    let new_ptr = build_runtime_malloc(
+        self_compiler,
+        llvm_type,
+        &format!("{}_clone_alloc", struct_name),
+        module,
+    )?;
+    track_struct_alloc(self_compiler, new_ptr, llvm_type, module);
+    // the vtable and the scalar fields are plain copies
+    let src_val = self_compiler
+        .builder
+        .build_load(llvm_type, src_ptr, "src_struct")
+        .unwrap();
+    self_compiler.builder.build_store(new_ptr, src_val).unwrap();
+
+    let runtime_clone = self_compiler.get_runtime_fn(module, "__clone");
+    for index in value_fields {
+        let src_field = self_compiler
+            .builder
+            .build_struct_gep(llvm_type, src_ptr, index, "src_field_ptr")
+            .unwrap();
+        let tag = self_compiler
+            .builder
+            .build_load(
+                self_compiler.tag_type(),
+                self_compiler
+                    .builder
+                    .build_struct_gep(self_compiler.runtime_value_type, src_field, 0, "tag_ptr")
+                    .unwrap(),
+                "field_tag",
+            )
+            .unwrap();
+        let data = self_compiler
+            .builder
+            .build_load(
+                self_compiler.data_type(),
+                self_compiler
+                    .builder
+                    .build_struct_gep(self_compiler.runtime_value_type, src_field, 1, "data_ptr")
+                    .unwrap(),
+                "field_data",
+            )
+            .unwrap();
+        let cloned = self_compiler
+            .builder
+            .build_call(runtime_clone, &[tag.into(), data.into()], "field_clone")
+            .unwrap();
+        let ValueKind::Basic(cloned) = cloned.try_as_basic_value() else {
+            return Err("Expected basic value from clone function".to_string());
+        };
+        let new_field = self_compiler
+            .builder
+            .build_struct_gep(llvm_type, new_ptr, index, "new_field_ptr")
+            .unwrap();
+        self_compiler
+            .builder
+            .build_store(new_field, cloned)
+            .unwrap();
+    }
+    self_compiler.builder.build_return(Some(&new_ptr)).unwrap();
+    Ok(clone_fn)
+}
+
 // !Define builtin macro handlers
 
 pub fn call_builtin_macro_println<'ctx>(
@@ -3637,11 +5434,7 @@ pub fn call_builtin_macro_list_push<'ctx>(
         .unwrap();
     let list_vec_int = self_compiler
         .builder
-        .build_load(
-            self_compiler.context.i64_type(),
-            list_data_ptr,
-            "list_vec_int",
-        )
+        .build_load(self_compiler.data_type(), list_data_ptr, "list_vec_int")
         .unwrap()
         .into_int_value();
     let list_vec_ptr = self_compiler
@@ -3659,7 +5452,7 @@ pub fn call_builtin_macro_list_push<'ctx>(
         .unwrap();
     let val_tag = self_compiler
         .builder
-        .build_load(self_compiler.context.i32_type(), target_ptr, "val_tag")
+        .build_load(self_compiler.tag_type(), target_ptr, "val_tag")
         .unwrap()
         .into_int_value();
 
@@ -3669,7 +5462,7 @@ pub fn call_builtin_macro_list_push<'ctx>(
         .unwrap();
     let val_data = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), data_ptr, "val_data")
+        .build_load(self_compiler.data_type(), data_ptr, "val_data")
         .unwrap()
         .into_int_value();
 
@@ -3712,7 +5505,7 @@ pub fn call_builtin_macro_clone<'ctx>(
         .unwrap();
     let tag = self_compiler
         .builder
-        .build_load(self_compiler.context.i32_type(), tag_ptr, "clone_arg_tag")
+        .build_load(self_compiler.tag_type(), tag_ptr, "clone_arg_tag")
         .unwrap()
         .into_int_value();
 
@@ -3727,7 +5520,7 @@ pub fn call_builtin_macro_clone<'ctx>(
         .unwrap();
     let data = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), data_ptr, "clone_arg_data")
+        .build_load(self_compiler.data_type(), data_ptr, "clone_arg_data")
         .unwrap()
         .into_int_value();
 
@@ -3751,41 +5544,264 @@ pub fn call_builtin_macro_clone<'ctx>(
     return Ok(result_ptr.into());
 }
 
-pub fn call_builtin_macro_cast<'ctx>(
+// `assert!(cond, "message")`: panics with the message, the condition as written and its
+// location when `cond` is false. With `--release` nothing is generated, not even `cond`.
+pub fn call_builtin_macro_assert<'ctx>(
     self_compiler: &mut Compiler<'ctx>,
     args: &Vec<ast::Expr>,
     module: &inkwell::module::Module<'ctx>,
 ) -> Result<BasicValueEnum<'ctx>, String> {
-    if args.len() != 2 {
-        return Err("cast! expects 2 arguments".to_string());
-    }
+    let message = match args.as_slice() {
+        [_, ast::Expr::Str(message)] => message.clone(),
+        _ => return Err("assert! expects a condition and a message string".to_string()),
+    };
 
-    let value_ptr = self_compiler
-        .compile_expr(&args[0], module)?
-        .into_pointer_value();
-    let target_type_expr = &args[1];
+    if !self_compiler.options.release {
+        let cond_ptr = self_compiler
+            .compile_expr(&args[0], module)?
+            .into_pointer_value();
+        check_bool_condition(self_compiler, cond_ptr, "assert", module)?;
+        let cond_data_ptr = self_compiler
+            .builder
+            .build_struct_gep(
+                self_compiler.runtime_value_type,
+                cond_ptr,
+                1,
+                "assert_data_ptr",
+            )
+            .unwrap();
+        let cond_loaded = self_compiler
+            .builder
+            .build_load(self_compiler.data_type(), cond_data_ptr, "assert_loaded")
+            .unwrap()
+            .into_int_value();
+        let zero = self_compiler.data_type().const_int(0, false);
+        let holds = self_compiler
+            .builder
+            .build_int_compare(inkwell::IntPredicate::NE, cond_loaded, zero, "assert_holds")
+            .unwrap();
 
-    let target_type = match target_type_expr {
-        ast::Expr::Var(ident) => ident.as_str(),
-        ast::Expr::TypeI8 => "i8",
-        ast::Expr::TypeU8 => "u8",
-        ast::Expr::TypeI16 => "i16",
-        ast::Expr::TypeU16 => "u16",
-        ast::Expr::TypeI32 => "i32",
-        ast::Expr::TypeU32 => "u32",
-        ast::Expr::TypeI64 => "i64",
-        ast::Expr::TypeU64 => "u64",
+        let parent_fn = self_compiler
+            .builder
+            .get_insert_block()
+            .unwrap()
+            .get_parent()
+            .unwrap();
+        let fail_bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, "assert_fail_bb");
+        let ok_bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, "assert_ok_bb");
+        self_compiler
+            .builder
+            .build_conditional_branch(holds, ok_bb, fail_bb)
+            .unwrap();
 
-        ast::Expr::TypeF16 => "fp16",
-        ast::Expr::TypeF32 => "fp32",
-        ast::Expr::TypeF64 => "fp64",
-        _ => {
-            return Err(format!(
-                "cast! second argument must be a type identifier : {:?}",
-                target_type_expr
-            ));
-        }
-    };
+        self_compiler.builder.position_at_end(fail_bb);
+        let error_message = match assert_condition_source(self_compiler, module) {
+            Some(cond) => format!("AssertionError: {} (`{}` is false)", message, cond),
+            None => format!("AssertionError: {}", message),
+        };
+        create_panic_err(
+            self_compiler,
+            &error_message,
+            module,
+            PanicErrorSettings {
+                is_const: true,
+                is_global: false,
+            },
+        )?;
+        self_compiler.builder.build_unreachable().unwrap();
+
+        self_compiler.builder.position_at_end(ok_bb);
+    }
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "assert_res_alloc");
+    self_compiler.tag_only_runtime_value_store(res_ptr, Tag::Unit as u64, "unit_res");
+    Ok(res_ptr.into())
+}
+
+// `panic!("message")`: aborts through `__panic` with the message and the location.
+pub fn call_builtin_macro_panic<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let message = match args.as_slice() {
+        [ast::Expr::Str(message)] => message.clone(),
+        _ => return Err("panic! expects a message string".to_string()),
+    };
+    create_panic_err(
+        self_compiler,
+        &message,
+        module,
+        PanicErrorSettings {
+            is_const: true,
+            is_global: false,
+        },
+    )?;
+    self_compiler.builder.build_unreachable().unwrap();
+
+    // `panic!` can sit inside an expression; what follows it goes into a block nothing jumps to
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let after_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "after_panic_bb");
+    self_compiler.builder.position_at_end(after_bb);
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "panic_res_alloc");
+    self_compiler.tag_only_runtime_value_store(res_ptr, Tag::Unit as u64, "unit_res");
+    Ok(res_ptr.into())
+}
+
+// exit!(code): ends the program with an exit status, like `return code;` from main.
+pub fn call_builtin_macro_exit<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let [code] = args.as_slice() else {
+        return Err("exit! expects an exit code".to_string());
+    };
+    let code = load_int_operand(self_compiler, code, "exit!", module)?;
+    let exit_fn = self_compiler.get_runtime_fn(module, "__exit");
+    self_compiler
+        .builder
+        .build_call(exit_fn, &[code.into()], "exit_call")
+        .unwrap();
+    self_compiler.builder.build_unreachable().unwrap();
+
+    // like panic!, what follows goes into a block nothing jumps to
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let after_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "after_exit_bb");
+    self_compiler.builder.position_at_end(after_bb);
+
+    create_unit(self_compiler)
+}
+
+// The exit status for what `main` returned: an integer is the status, anything else is 0.
+pub fn build_exit_status<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    value: BasicValueEnum<'ctx>,
+) -> IntValue<'ctx> {
+    let i32_type = self_compiler.context.i32_type();
+    match value {
+        // `fn main() -> i8`, but not `-> bool`
+        BasicValueEnum::IntValue(code) if code.get_type().get_bit_width() > 1 => self_compiler
+            .builder
+            .build_int_cast_sign_flag(code, i32_type, true, "exit_status")
+            .unwrap(),
+        BasicValueEnum::StructValue(result) => {
+            let tag = self_compiler
+                .builder
+                .build_extract_value(result, 0, "main_res_tag")
+                .unwrap()
+                .into_int_value();
+            let data = self_compiler
+                .builder
+                .build_extract_value(result, 1, "main_res_data")
+                .unwrap()
+                .into_int_value();
+            let is_int = tag_in(
+                self_compiler,
+                tag,
+                &[
+                    Tag::Integer,
+                    Tag::Int8,
+                    Tag::Uint8,
+                    Tag::Int16,
+                    Tag::Uint16,
+                    Tag::Int32,
+                    Tag::Uint32,
+                    Tag::Int64,
+                    Tag::Uint64,
+                ],
+            );
+            let code = self_compiler
+                .builder
+                .build_int_truncate_or_bit_cast(data, i32_type, "main_res_code")
+                .unwrap();
+            self_compiler
+                .builder
+                .build_select(is_int, code, i32_type.const_zero(), "exit_status")
+                .unwrap()
+                .into_int_value()
+        }
+        _ => i32_type.const_zero(),
+    }
+}
+
+// The first argument of the `assert!` in the current statement, as written in the source.
+fn assert_condition_source<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Option<String> {
+    let span = self_compiler.current_span?;
+    let (_, source) = self_compiler
+        .module_sources
+        .get(module.get_name().to_str().ok()?)?;
+    let text = source.get(span.start..span.end)?;
+    let stream = TokenStream::lex(text);
+    let tokens = &stream.tokens;
+    let open = tokens.windows(2).position(|w| {
+        matches!(&w[0].token, Token::Ident(name) if name == "assert!")
+            && w[1].token == Token::LParen
+    })? + 1;
+
+    let mut depth = 0;
+    for (idx, token) in tokens.iter().enumerate().skip(open + 1) {
+        match token.token {
+            Token::LParen | Token::LBracket | Token::LBrace => depth += 1,
+            Token::RParen | Token::RBracket | Token::RBrace if depth > 0 => depth -= 1,
+            Token::Comma | Token::RParen if depth == 0 => {
+                if idx == open + 1 {
+                    return None;
+                }
+                let (first, last) = (&tokens[open + 1], &tokens[idx - 1]);
+                return Some(text[first.span.start..last.span.end].to_string());
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+pub fn call_builtin_macro_cast<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 2 {
+        return Err("cast! expects 2 arguments".to_string());
+    }
+
+    let value_ptr = self_compiler
+        .compile_expr(&args[0], module)?
+        .into_pointer_value();
+    let target_type_expr = &args[1];
+
+    let Some(target_type) = type_name_arg(target_type_expr) else {
+        return Err(format!(
+            "cast! second argument must be a type identifier : {:?}",
+            target_type_expr
+        ));
+    };
+    if let "Str" | "Int" | "Bool" | "Char" = target_type {
+        return create_runtime_cast(self_compiler, value_ptr, target_type, module);
+    }
 
     let tag_ptr = self_compiler
         .builder
@@ -3800,7 +5816,7 @@ pub fn call_builtin_macro_cast<'ctx>(
     // Load the current tag (not used here but could be useful for type checking)
     let current_tag = self_compiler
         .builder
-        .build_load(self_compiler.context.i32_type(), tag_ptr, "cast_arg_tag")
+        .build_load(self_compiler.tag_type(), tag_ptr, "cast_arg_tag")
         .unwrap()
         .into_int_value();
 
@@ -3815,7 +5831,7 @@ pub fn call_builtin_macro_cast<'ctx>(
         .unwrap();
     let data = self_compiler
         .builder
-        .build_load(self_compiler.context.i64_type(), data_ptr, "cast_arg_data")
+        .build_load(self_compiler.data_type(), data_ptr, "cast_arg_data")
         .unwrap()
         .into_int_value();
 
@@ -3845,13 +5861,13 @@ pub fn call_builtin_macro_cast<'ctx>(
         .context
         .append_basic_block(parent, "cast_merge_bb");
 
-    let i32_type = self_compiler.context.i32_type();
+    let tag_type = self_compiler.tag_type();
     let cases = vec![
-        (i32_type.const_int(Tag::Integer as u64, false), bb_int),
-        (i32_type.const_int(Tag::Float as u64, false), bb_float),
-        (i32_type.const_int(Tag::Float16 as u64, false), bb_f16),
-        (i32_type.const_int(Tag::Float32 as u64, false), bb_f32),
-        (i32_type.const_int(Tag::Float64 as u64, false), bb_f64),
+        (tag_type.const_int(Tag::Integer as u64, false), bb_int),
+        (tag_type.const_int(Tag::Float as u64, false), bb_float),
+        (tag_type.const_int(Tag::Float16 as u64, false), bb_f16),
+        (tag_type.const_int(Tag::Float32 as u64, false), bb_f32),
+        (tag_type.const_int(Tag::Float64 as u64, false), bb_f64),
     ];
 
     self_compiler
@@ -3872,11 +5888,7 @@ pub fn call_builtin_macro_cast<'ctx>(
 
     // Float -> f64
     self_compiler.builder.position_at_end(bb_float);
-    let float_to_f64 = self_compiler
-        .builder
-        .build_bit_cast(data, self_compiler.context.f64_type(), "float_to_f64")
-        .unwrap()
-        .into_float_value();
+    let float_to_f64 = data_to_f64(self_compiler, data, "float_to_f64");
     self_compiler
         .builder
         .build_unconditional_branch(marge)
@@ -3933,11 +5945,7 @@ pub fn call_builtin_macro_cast<'ctx>(
 
     // Float64 -> f64
     self_compiler.builder.position_at_end(bb_f64);
-    let val_f64 = self_compiler
-        .builder
-        .build_bit_cast(data, self_compiler.context.f64_type(), "f64_to_f64")
-        .unwrap()
-        .into_float_value();
+    let val_f64 = data_to_f64(self_compiler, data, "f64_to_f64");
     self_compiler
         .builder
         .build_unconditional_branch(marge)
@@ -3960,10 +5968,7 @@ pub fn call_builtin_macro_cast<'ctx>(
 
     let (new_tag, new_data) = match target_type {
         "i8" => {
-            let new_tag = self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Int8 as u64, false);
+            let new_tag = self_compiler.tag_type().const_int(Tag::Int8 as u64, false);
 
             let new_data = self_compiler
                 .builder
@@ -3971,19 +5976,12 @@ pub fn call_builtin_macro_cast<'ctx>(
                 .unwrap();
             let new_data_ext = self_compiler
                 .builder
-                .build_int_s_extend(
-                    new_data,
-                    self_compiler.context.i64_type(),
-                    "cast_to_int8_ext",
-                )
+                .build_int_s_extend(new_data, self_compiler.data_type(), "cast_to_int8_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
         "u8" => {
-            let new_tag = self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Uint8 as u64, false);
+            let new_tag = self_compiler.tag_type().const_int(Tag::Uint8 as u64, false);
 
             let new_data = self_compiler
                 .builder
@@ -3991,19 +5989,12 @@ pub fn call_builtin_macro_cast<'ctx>(
                 .unwrap();
             let new_data_ext = self_compiler
                 .builder
-                .build_int_z_extend(
-                    new_data,
-                    self_compiler.context.i64_type(),
-                    "cast_to_uint8_ext",
-                )
+                .build_int_z_extend(new_data, self_compiler.data_type(), "cast_to_uint8_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
         "i16" => {
-            let new_tag = self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Int16 as u64, false);
+            let new_tag = self_compiler.tag_type().const_int(Tag::Int16 as u64, false);
 
             let new_data = self_compiler
                 .builder
@@ -4011,18 +6002,13 @@ pub fn call_builtin_macro_cast<'ctx>(
                 .unwrap();
             let new_data_ext = self_compiler
                 .builder
-                .build_int_s_extend(
-                    new_data,
-                    self_compiler.context.i64_type(),
-                    "cast_to_int16_ext",
-                )
+                .build_int_s_extend(new_data, self_compiler.data_type(), "cast_to_int16_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
         "u16" => {
             let new_tag = self_compiler
-                .context
-                .i32_type()
+                .tag_type()
                 .const_int(Tag::Uint16 as u64, false);
 
             let new_data = self_compiler
@@ -4031,19 +6017,12 @@ pub fn call_builtin_macro_cast<'ctx>(
                 .unwrap();
             let new_data_ext = self_compiler
                 .builder
-                .build_int_z_extend(
-                    new_data,
-                    self_compiler.context.i64_type(),
-                    "cast_to_uint16_ext",
-                )
+                .build_int_z_extend(new_data, self_compiler.data_type(), "cast_to_uint16_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
         "i32" => {
-            let new_tag = self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Int32 as u64, false);
+            let new_tag = self_compiler.tag_type().const_int(Tag::Int32 as u64, false);
 
             let new_data = self_compiler
                 .builder
@@ -4051,18 +6030,13 @@ pub fn call_builtin_macro_cast<'ctx>(
                 .unwrap();
             let new_data_ext = self_compiler
                 .builder
-                .build_int_s_extend(
-                    new_data,
-                    self_compiler.context.i64_type(),
-                    "cast_to_int32_ext",
-                )
+                .build_int_s_extend(new_data, self_compiler.data_type(), "cast_to_int32_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
         "u32" => {
             let new_tag = self_compiler
-                .context
-                .i32_type()
+                .tag_type()
                 .const_int(Tag::Uint32 as u64, false);
 
             let new_data = self_compiler
@@ -4071,33 +6045,24 @@ pub fn call_builtin_macro_cast<'ctx>(
                 .unwrap();
             let new_data_ext = self_compiler
                 .builder
-                .build_int_z_extend(
-                    new_data,
-                    self_compiler.context.i64_type(),
-                    "cast_to_uint32_ext",
-                )
+                .build_int_z_extend(new_data, self_compiler.data_type(), "cast_to_uint32_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
         "i64" => {
-            let new_tag = self_compiler
-                .context
-                .i32_type()
-                .const_int(Tag::Int64 as u64, false);
+            let new_tag = self_compiler.tag_type().const_int(Tag::Int64 as u64, false);
             (new_tag, data)
         }
         "u64" => {
             let new_tag = self_compiler
-                .context
-                .i32_type()
+                .tag_type()
                 .const_int(Tag::Uint64 as u64, false);
             (new_tag, data)
         }
 
         "fp16" => {
             let new_tag = self_compiler
-                .context
-                .i32_type()
+                .tag_type()
                 .const_int(Tag::Float16 as u64, false);
 
             // f64 -> f16
@@ -4119,19 +6084,14 @@ pub fn call_builtin_macro_cast<'ctx>(
 
             let new_data_ext = self_compiler
                 .builder
-                .build_int_z_extend(
-                    new_data_i16,
-                    self_compiler.context.i64_type(),
-                    "cast_to_fp16_ext",
-                )
+                .build_int_z_extend(new_data_i16, self_compiler.data_type(), "cast_to_fp16_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
 
         "fp32" => {
             let new_tag = self_compiler
-                .context
-                .i32_type()
+                .tag_type()
                 .const_int(Tag::Float32 as u64, false);
 
             // f64 -> f32
@@ -4153,30 +6113,17 @@ pub fn call_builtin_macro_cast<'ctx>(
 
             let new_data_ext = self_compiler
                 .builder
-                .build_int_z_extend(
-                    new_data_i32,
-                    self_compiler.context.i64_type(),
-                    "cast_to_fp32_ext",
-                )
+                .build_int_z_extend(new_data_i32, self_compiler.data_type(), "cast_to_fp32_ext")
                 .unwrap();
             (new_tag, new_data_ext)
         }
 
         "fp64" => {
             let new_tag = self_compiler
-                .context
-                .i32_type()
+                .tag_type()
                 .const_int(Tag::Float64 as u64, false);
 
-            let new_data = self_compiler
-                .builder
-                .build_bit_cast(
-                    normalized_f64,
-                    self_compiler.context.i64_type(),
-                    "cast_to_fp64_ext",
-                )
-                .unwrap()
-                .into_int_value();
+            let new_data = f64_to_data(self_compiler, normalized_f64, "cast_to_fp64_ext");
             (new_tag, new_data)
         }
         _ => {
@@ -4196,3 +6143,1428 @@ pub fn call_builtin_macro_cast<'ctx>(
     );
     return Ok(result_ptr.into());
 }
+
+// `cast!(x, Str)`, `cast!(x, Int)`, `cast!(x, Bool)` and `cast!(x, Char)` convert in the
+// runtime (`__to_string`, `__to_int`, `__to_bool`, `__to_char`); a value that does not convert, such as
+// `cast!("12a", Int)`, panics here.
+fn create_runtime_cast<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    value_ptr: PointerValue<'ctx>,
+    target_type: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let tag_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            value_ptr,
+            0,
+            "cast_arg_tag_ptr",
+        )
+        .unwrap();
+    let tag = self_compiler
+        .builder
+        .build_load(self_compiler.tag_type(), tag_ptr, "cast_arg_tag")
+        .unwrap()
+        .into_int_value();
+    let data_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            value_ptr,
+            1,
+            "cast_arg_data_ptr",
+        )
+        .unwrap();
+    let data = self_compiler
+        .builder
+        .build_load(self_compiler.data_type(), data_ptr, "cast_arg_data")
+        .unwrap()
+        .into_int_value();
+
+    let (runtime_fn, res_tag) = match target_type {
+        "Str" => ("__to_string", Tag::String),
+        "Int" => ("__to_int", Tag::Integer),
+        "Char" => ("__to_char", Tag::Char),
+        _ => ("__to_bool", Tag::Boolean),
+    };
+    let runtime_fn = self_compiler.get_runtime_fn(module, runtime_fn);
+    let (converted, new_data) = if target_type == "Str" {
+        let call = self_compiler
+            .builder
+            .build_call(runtime_fn, &[tag.into(), data.into()], "cast_to_str_call")
+            .unwrap();
+        let str_ptr = match call.try_as_basic_value() {
+            ValueKind::Basic(val) => val.into_pointer_value(),
+            ValueKind::Instruction(_) => {
+                return Err("__to_string did not return a value".to_string());
+            }
+        };
+        let converted = self_compiler
+            .builder
+            .build_is_not_null(str_ptr, "cast_str_ok")
+            .unwrap();
+        let new_data = self_compiler
+            .builder
+            .build_ptr_to_int(str_ptr, self_compiler.data_type(), "cast_str_data")
+            .unwrap();
+        (converted, new_data)
+    } else {
+        let data_type = self_compiler.data_type().into();
+        let out_ptr = create_entry_block_alloca_of(self_compiler, data_type, "cast_out");
+        let call = self_compiler
+            .builder
+            .build_call(
+                runtime_fn,
+                &[tag.into(), data.into(), out_ptr.into()],
+                "cast_runtime_call",
+            )
+            .unwrap();
+        let ok = match call.try_as_basic_value() {
+            ValueKind::Basic(val) => val.into_int_value(),
+            ValueKind::Instruction(_) => {
+                return Err(format!("cast! to {} did not return a value", target_type));
+            }
+        };
+        let converted = self_compiler
+            .builder
+            .build_int_compare(
+                inkwell::IntPredicate::NE,
+                ok,
+                self_compiler.data_type().const_int(0, false),
+                "cast_ok",
+            )
+            .unwrap();
+        let new_data = self_compiler
+            .builder
+            .build_load(self_compiler.data_type(), out_ptr, "cast_out_data")
+            .unwrap()
+            .into_int_value();
+        (converted, new_data)
+    };
+
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let fail_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "cast_fail_bb");
+    let ok_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "cast_ok_bb");
+    self_compiler
+        .builder
+        .build_conditional_branch(converted, ok_bb, fail_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(fail_bb);
+    create_panic_err(
+        self_compiler,
+        &format!(
+            "ValueError: cast! could not convert the value to {}",
+            target_type
+        ),
+        module,
+        PanicErrorSettings {
+            is_const: true,
+            is_global: false,
+        },
+    )?;
+    self_compiler.builder.build_unreachable().unwrap();
+
+    self_compiler.builder.position_at_end(ok_bb);
+    let result_ptr = create_entry_block_alloca(self_compiler, "cast_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(res_tag as u64),
+        StoreValue::Int(new_data),
+        "cast_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// `type_of!(x)`: the name of the runtime tag of `x` as a Str, e.g. "Int", "List" or "u8".
+pub fn call_builtin_macro_type_of<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 1 {
+        return Err("type_of! expects 1 argument".to_string());
+    }
+
+    let value_ptr = self_compiler
+        .compile_expr(&args[0], module)?
+        .into_pointer_value();
+    let tag_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            value_ptr,
+            0,
+            "type_of_tag_ptr",
+        )
+        .unwrap();
+    let tag = self_compiler
+        .builder
+        .build_load(self_compiler.tag_type(), tag_ptr, "type_of_tag")
+        .unwrap()
+        .into_int_value();
+
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let unknown_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "type_of_unknown_bb");
+    let merge_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "type_of_merge_bb");
+
+    let mut cases = Vec::new();
+    let mut incoming = Vec::new();
+    for (tag_value, name) in runtime_type_names() {
+        let bb = self_compiler
+            .context
+            .append_basic_block(parent_fn, &format!("type_of_{}_bb", name));
+        cases.push((
+            self_compiler.tag_type().const_int(tag_value as u64, false),
+            bb,
+        ));
+        incoming.push((name, bb));
+    }
+    incoming.push(("Unknown", unknown_bb));
+    self_compiler
+        .builder
+        .build_switch(tag, unknown_bb, &cases)
+        .unwrap();
+
+    let mut phi_incoming = Vec::new();
+    for (name, bb) in incoming {
+        self_compiler.builder.position_at_end(bb);
+        let name_ptr = string_literal_ptr(self_compiler, name, module)?;
+        self_compiler
+            .builder
+            .build_unconditional_branch(merge_bb)
+            .unwrap();
+        phi_incoming.push((name_ptr, bb));
+    }
+
+    self_compiler.builder.position_at_end(merge_bb);
+    let phi = self_compiler
+        .builder
+        .build_phi(
+            self_compiler.context.ptr_type(AddressSpace::default()),
+            "type_of_name",
+        )
+        .unwrap();
+    for (name_ptr, bb) in &phi_incoming {
+        phi.add_incoming(&[(name_ptr, *bb)]);
+    }
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "type_of_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(Tag::String as u64),
+        StoreValue::Ptr(phi.as_basic_value().into_pointer_value()),
+        "type_of_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// `is!(x, Str)`: whether the runtime tag of `x` is the named type, as a Bool.
+// A single tag comparison, so it never panics whatever `x` holds.
+pub fn call_builtin_macro_is<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 2 {
+        return Err("is! expects 2 arguments".to_string());
+    }
+    let Some(type_name) = type_name_arg(&args[1]) else {
+        return Err(format!(
+            "is! second argument must be a type identifier : {:?}",
+            args[1]
+        ));
+    };
+    let Some((tag_value, _)) = runtime_type_names()
+        .into_iter()
+        .find(|(_, name)| *name == type_name)
+    else {
+        let names: Vec<&str> = runtime_type_names().iter().map(|(_, name)| *name).collect();
+        return Err(format!(
+            "is! does not know the type '{}' (expected one of {})",
+            type_name,
+            names.join(", ")
+        ));
+    };
+
+    let value_ptr = self_compiler
+        .compile_expr(&args[0], module)?
+        .into_pointer_value();
+    let tag_ptr = self_compiler
+        .builder
+        .build_struct_gep(self_compiler.runtime_value_type, value_ptr, 0, "is_tag_ptr")
+        .unwrap();
+    let tag = self_compiler
+        .builder
+        .build_load(self_compiler.tag_type(), tag_ptr, "is_tag")
+        .unwrap()
+        .into_int_value();
+    let matches = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::EQ,
+            tag,
+            self_compiler.tag_type().const_int(tag_value as u64, false),
+            "is_match",
+        )
+        .unwrap();
+    let data = self_compiler
+        .builder
+        .build_int_z_extend(matches, self_compiler.data_type(), "is_data")
+        .unwrap();
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "is_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(Tag::Boolean as u64),
+        StoreValue::Int(data),
+        "is_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// The names `type_of!` returns and `is!` accepts, one per runtime tag.
+fn runtime_type_names() -> [(Tag, &'static str); 22] {
+    [
+        (Tag::Integer, "Int"),
+        (Tag::Float, "Float"),
+        (Tag::String, "Str"),
+        (Tag::Boolean, "Bool"),
+        (Tag::List, "List"),
+        (Tag::Range, "Range"),
+        (Tag::Unit, "Unit"),
+        (Tag::Enum, "Enum"),
+        (Tag::Struct, "Struct"),
+        (Tag::Moved, "Moved"),
+        (Tag::Char, "Char"),
+        (Tag::Int8, "i8"),
+        (Tag::Uint8, "u8"),
+        (Tag::Int16, "i16"),
+        (Tag::Uint16, "u16"),
+        (Tag::Int32, "i32"),
+        (Tag::Uint32, "u32"),
+        (Tag::Int64, "i64"),
+        (Tag::Uint64, "u64"),
+        (Tag::Float16, "fp16"),
+        (Tag::Float32, "fp32"),
+        (Tag::Float64, "fp64"),
+    ]
+}
+
+// The type argument of `cast!` and `is!`: a name like `Str` or a sized type keyword like `u8`.
+fn type_name_arg(expr: &ast::Expr) -> Option<&str> {
+    let name = match expr {
+        ast::Expr::Var(ident) => ident.as_str(),
+        ast::Expr::TypeI8 => "i8",
+        ast::Expr::TypeU8 => "u8",
+        ast::Expr::TypeI16 => "i16",
+        ast::Expr::TypeU16 => "u16",
+        ast::Expr::TypeI32 => "i32",
+        ast::Expr::TypeU32 => "u32",
+        ast::Expr::TypeI64 => "i64",
+        ast::Expr::TypeU64 => "u64",
+
+        ast::Expr::TypeF16 => "fp16",
+        ast::Expr::TypeF32 => "fp32",
+        ast::Expr::TypeF64 => "fp64",
+        _ => return None,
+    };
+    Some(name)
+}
+
+pub enum IntMacroMode {
+    Wrapping,
+    Saturating,
+}
+
+// wrapping_add!(a, b, u8) / saturating_add!(a, b, u8) and friends.
+// Operands are truncated to the target width before the operation.
+pub fn call_builtin_macro_int_arith<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    macro_name: &str,
+    op: ArithOp,
+    mode: IntMacroMode,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 3 {
+        return Err(format!("{} expects 3 arguments", macro_name));
+    }
+
+    let Some((int_type, tag, is_signed)) = sized_int_arg(self_compiler, &args[2]) else {
+        return Err(format!(
+            "{} third argument must be a sized integer type : {:?}",
+            macro_name, args[2]
+        ));
+    };
+
+    let mut operands = Vec::new();
+    for (i, arg) in args[..2].iter().enumerate() {
+        let value_ptr = self_compiler
+            .compile_expr(arg, module)?
+            .into_pointer_value();
+        let data_ptr = self_compiler
+            .builder
+            .build_struct_gep(
+                self_compiler.runtime_value_type,
+                value_ptr,
+                1,
+                &format!("int_macro_arg{}_data_ptr", i),
+            )
+            .unwrap();
+        let data = self_compiler
+            .builder
+            .build_load(
+                self_compiler.data_type(),
+                data_ptr,
+                &format!("int_macro_arg{}_data", i),
+            )
+            .unwrap()
+            .into_int_value();
+        let narrowed = if int_type.get_bit_width() < 64 {
+            self_compiler
+                .builder
+                .build_int_truncate(data, int_type, &format!("int_macro_arg{}_trunc", i))
+                .unwrap()
+        } else {
+            data
+        };
+        operands.push(narrowed);
+    }
+    let (l_val, r_val) = (operands[0], operands[1]);
+
+    let result = match mode {
+        IntMacroMode::Wrapping => match op {
+            ArithOp::Add => self_compiler
+                .builder
+                .build_int_add(l_val, r_val, "wrapping_add"),
+            ArithOp::Sub => self_compiler
+                .builder
+                .build_int_sub(l_val, r_val, "wrapping_sub"),
+            ArithOp::Mul => self_compiler
+                .builder
+                .build_int_mul(l_val, r_val, "wrapping_mul"),
+        }
+        .unwrap(),
+        IntMacroMode::Saturating => {
+            let intrinsic_name = match (op, is_signed) {
+                (ArithOp::Add, true) => "llvm.sadd.sat",
+                (ArithOp::Add, false) => "llvm.uadd.sat",
+                (ArithOp::Sub, true) => "llvm.ssub.sat",
+                (ArithOp::Sub, false) => "llvm.usub.sat",
+                (ArithOp::Mul, _) => {
+                    return Err(format!("{} is not supported", macro_name));
+                }
+            };
+            let intrinsic = Intrinsic::find(intrinsic_name)
+                .ok_or_else(|| format!("Intrinsic not found: {}", intrinsic_name))?;
+            let intrinsic_fn = intrinsic
+                .get_declaration(module, &[int_type.into()])
+                .ok_or_else(|| format!("Failed to declare intrinsic: {}", intrinsic_name))?;
+            let call_site = self_compiler
+                .builder
+                .build_call(intrinsic_fn, &[l_val.into(), r_val.into()], "saturating")
+                .unwrap();
+            match call_site.try_as_basic_value() {
+                ValueKind::Basic(val) => val.into_int_value(),
+                ValueKind::Instruction(_) => {
+                    return Err(format!("Expected basic value from {}", intrinsic_name));
+                }
+            }
+        }
+    };
+
+    let result_ext = if int_type.get_bit_width() < 64 {
+        if is_signed {
+            self_compiler
+                .builder
+                .build_int_s_extend(result, self_compiler.data_type(), "int_macro_ext")
+                .unwrap()
+        } else {
+            self_compiler
+                .builder
+                .build_int_z_extend(result, self_compiler.data_type(), "int_macro_ext")
+                .unwrap()
+        }
+    } else {
+        result
+    };
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "int_macro_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(tag as u64),
+        StoreValue::Int(result_ext),
+        "int_macro_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// A sized integer type argument (`u8`, `i32`, ...): its LLVM type, tag and signedness.
+fn sized_int_arg<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    expr: &ast::Expr,
+) -> Option<(IntType<'ctx>, Tag, bool)> {
+    let context = self_compiler.context;
+    let sized = match expr {
+        ast::Expr::TypeI8 => (context.i8_type(), Tag::Int8, true),
+        ast::Expr::TypeU8 => (context.i8_type(), Tag::Uint8, false),
+        ast::Expr::TypeI16 => (context.i16_type(), Tag::Int16, true),
+        ast::Expr::TypeU16 => (context.i16_type(), Tag::Uint16, false),
+        ast::Expr::TypeI32 => (context.i32_type(), Tag::Int32, true),
+        ast::Expr::TypeU32 => (context.i32_type(), Tag::Uint32, false),
+        ast::Expr::TypeI64 => (context.i64_type(), Tag::Int64, true),
+        ast::Expr::TypeU64 => (context.i64_type(), Tag::Uint64, false),
+        _ => return None,
+    };
+    Some(sized)
+}
+
+// The integer address of volatile_read!/volatile_write! as a pointer.
+fn volatile_address<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    arg: &ast::Expr,
+    macro_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let addr = load_int_operand(self_compiler, arg, macro_name, module)?;
+    Ok(self_compiler
+        .builder
+        .build_int_to_ptr(
+            addr,
+            self_compiler.context.ptr_type(AddressSpace::default()),
+            "volatile_addr",
+        )
+        .unwrap())
+}
+
+// volatile_read!(addr, u32): a volatile load of a memory-mapped register at an integer
+// address. LLVM never merges, reorders or drops it, even when the value looks unchanged.
+pub fn call_builtin_macro_volatile_read<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 2 {
+        return Err("volatile_read! expects 2 arguments".to_string());
+    }
+    let Some((int_type, tag, is_signed)) = sized_int_arg(self_compiler, &args[1]) else {
+        return Err(format!(
+            "volatile_read! second argument must be a sized integer type : {:?}",
+            args[1]
+        ));
+    };
+    let ptr = volatile_address(self_compiler, &args[0], "volatile_read!", module)?;
+
+    let value = self_compiler
+        .builder
+        .build_load(int_type, ptr, "volatile_load")
+        .unwrap()
+        .into_int_value();
+    value
+        .as_instruction()
+        .unwrap()
+        .set_volatile(true)
+        .map_err(|e| e.to_string())?;
+
+    let data_type = self_compiler.data_type();
+    let data = if int_type.get_bit_width() < data_type.get_bit_width() {
+        if is_signed {
+            self_compiler
+                .builder
+                .build_int_s_extend(value, data_type, "volatile_ext")
+                .unwrap()
+        } else {
+            self_compiler
+                .builder
+                .build_int_z_extend(value, data_type, "volatile_ext")
+                .unwrap()
+        }
+    } else {
+        value
+    };
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "volatile_read_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(tag as u64),
+        StoreValue::Int(data),
+        "volatile_read_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// volatile_write!(addr, value, u32): a volatile store of the low bits of an integer value.
+pub fn call_builtin_macro_volatile_write<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 3 {
+        return Err("volatile_write! expects 3 arguments".to_string());
+    }
+    let Some((int_type, _, _)) = sized_int_arg(self_compiler, &args[2]) else {
+        return Err(format!(
+            "volatile_write! third argument must be a sized integer type : {:?}",
+            args[2]
+        ));
+    };
+    let ptr = volatile_address(self_compiler, &args[0], "volatile_write!", module)?;
+    let value = load_int_operand(self_compiler, &args[1], "volatile_write!", module)?;
+
+    let value = if int_type.get_bit_width() < value.get_type().get_bit_width() {
+        self_compiler
+            .builder
+            .build_int_truncate(value, int_type, "volatile_trunc")
+            .unwrap()
+    } else {
+        value
+    };
+    let store = self_compiler.builder.build_store(ptr, value).unwrap();
+    store.set_volatile(true).map_err(|e| e.to_string())?;
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "volatile_write_res_alloc");
+    self_compiler.tag_only_runtime_value_store(res_ptr, Tag::Unit as u64, "unit_res");
+    Ok(res_ptr.into())
+}
+
+// Width of the general-purpose registers that asm! operands are passed in.
+fn asm_register_type<'ctx>(self_compiler: &Compiler<'ctx>) -> IntType<'ctx> {
+    match self_compiler.target_os {
+        OS::RiscV => self_compiler.context.i32_type(),
+        OS::Avr => self_compiler.context.i16_type(),
+        _ => self_compiler.context.i64_type(),
+    }
+}
+
+// asm!("wfi"), asm!("csrr $0, mcause", "=r") or asm!("csrw mtvec, $0", "r", handler):
+// LLVM inline assembly with its constraint string as written. An output (`=r` first)
+// becomes the Int result, every other non-clobber constraint takes one integer argument.
+// It is always treated as having side effects, so it is never removed or hoisted.
+pub fn call_builtin_macro_asm<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let (template, constraints, operands) = match args.as_slice() {
+        [ast::Expr::Str(template)] => (template.clone(), String::new(), &args[1..]),
+        [
+            ast::Expr::Str(template),
+            ast::Expr::Str(constraints),
+            operands @ ..,
+        ] => (template.clone(), constraints.clone(), operands),
+        _ => {
+            return Err(
+                "asm! expects an assembly string, then an optional constraint string and its operands"
+                    .to_string(),
+            );
+        }
+    };
+
+    let parts: Vec<&str> = constraints
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .collect();
+    let outputs = parts.iter().filter(|c| c.starts_with('=')).count();
+    let inputs = parts
+        .iter()
+        .filter(|c| !c.starts_with('=') && !c.starts_with('~'))
+        .count();
+    if outputs > 1 {
+        return Err("asm! supports at most one output constraint".to_string());
+    }
+    if inputs != operands.len() {
+        return Err(format!(
+            "asm! constraints \"{}\" take {} operand(s), found {}",
+            constraints,
+            inputs,
+            operands.len()
+        ));
+    }
+
+    let reg_type = asm_register_type(self_compiler);
+    let mut values = Vec::new();
+    for operand in operands {
+        let data = load_int_operand(self_compiler, operand, "asm!", module)?;
+        let value = if reg_type.get_bit_width() < data.get_type().get_bit_width() {
+            self_compiler
+                .builder
+                .build_int_truncate(data, reg_type, "asm_operand")
+                .unwrap()
+        } else {
+            data
+        };
+        values.push(value.into());
+    }
+
+    let param_types = vec![reg_type.into(); values.len()];
+    let fn_type = if outputs == 1 {
+        reg_type.fn_type(&param_types, false)
+    } else {
+        self_compiler
+            .context
+            .void_type()
+            .fn_type(&param_types, false)
+    };
+    let asm = self_compiler.context.create_inline_asm(
+        fn_type,
+        template,
+        constraints,
+        true,
+        false,
+        None,
+        false,
+    );
+    let call = self_compiler
+        .builder
+        .build_indirect_call(fn_type, asm, &values, "asm")
+        .unwrap();
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "asm_res_alloc");
+    match call.try_as_basic_value() {
+        ValueKind::Basic(val) => {
+            let data = self_compiler
+                .builder
+                .build_int_s_extend_or_bit_cast(
+                    val.into_int_value(),
+                    self_compiler.data_type(),
+                    "asm_res",
+                )
+                .unwrap();
+            self_compiler.build_runtime_value_store(
+                result_ptr,
+                StoreTag::Int(Tag::Integer as u64),
+                StoreValue::Int(data),
+                "asm_res",
+            );
+        }
+        ValueKind::Instruction(_) => {
+            self_compiler.tag_only_runtime_value_store(result_ptr, Tag::Unit as u64, "unit_res");
+        }
+    }
+    Ok(result_ptr.into())
+}
+
+// Calls an `extern fn`: each runtime value is unboxed to the declared C type and the
+// C result is boxed again.
+pub fn create_extern_call<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    name: &str,
+    sig: &ExternSig,
+    args: &[ast::Expr],
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != sig.params.len() {
+        return Err(format!(
+            "extern fn '{}' expects {} argument(s), found {}",
+            name,
+            sig.params.len(),
+            args.len()
+        ));
+    }
+    let function = module
+        .get_function(name)
+        .ok_or_else(|| format!("extern fn '{}' is not declared", name))?;
+
+    let context = self_compiler.context;
+    let mut values = Vec::new();
+    for (arg, ty) in args.iter().zip(&sig.params) {
+        let value: BasicValueEnum = match ty {
+            Type::Str => load_str_operand(self_compiler, arg, name, module)?.into(),
+            Type::Bool => {
+                let (tag, data) = load_operand(self_compiler, arg, module)?;
+                let is_bool = tag_in(self_compiler, tag, &[Tag::Boolean]);
+                let message = format!("TypeError: {} expects a Bool", name);
+                panic_unless(self_compiler, is_bool, &message, module)?;
+                self_compiler
+                    .builder
+                    .build_int_truncate(data, context.bool_type(), "extern_bool")
+                    .unwrap()
+                    .into()
+            }
+            Type::Float | Type::TypeF64 | Type::TypeF32 | Type::TypeF16 => {
+                let (tag, data) = load_numeric_operand(self_compiler, arg, name, module)?;
+                let value = numeric_to_f64(self_compiler, tag, data);
+                let float_type = match ty {
+                    Type::TypeF32 => context.f32_type(),
+                    Type::TypeF16 => context.f16_type(),
+                    _ => context.f64_type(),
+                };
+                self_compiler
+                    .builder
+                    .build_float_cast(value, float_type, "extern_float")
+                    .unwrap()
+                    .into()
+            }
+            _ => {
+                let data = load_int_operand(self_compiler, arg, name, module)?;
+                let int_type = match ty {
+                    Type::TypeI8 | Type::TypeU8 => context.i8_type(),
+                    Type::TypeI16 | Type::TypeU16 => context.i16_type(),
+                    Type::TypeI32 | Type::TypeU32 => context.i32_type(),
+                    _ => self_compiler.data_type(),
+                };
+                self_compiler
+                    .builder
+                    .build_int_truncate_or_bit_cast(data, int_type, "extern_int")
+                    .unwrap()
+                    .into()
+            }
+        };
+        values.push(value.into());
+    }
+
+    let call = self_compiler
+        .builder
+        .build_call(function, &values, "extern_call")
+        .unwrap();
+    match call.try_as_basic_value() {
+        ValueKind::Basic(val) => box_return_value(self_compiler, val.get_type(), val),
+        ValueKind::Instruction(_) => create_unit(self_compiler),
+    }
+}
+
+pub enum NumericMacro {
+    Min,
+    Max,
+    Abs,
+    Clamp,
+}
+
+// min!(a, b), max!(a, b), abs!(x) and clamp!(x, lo, hi) on any numeric values.
+// Integers compare as integers (unsigned when both are u8..u64); with a float on either side
+// both compare as f64. The result is one of the operands and keeps its type.
+pub fn call_builtin_macro_numeric<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    macro_name: &str,
+    op: NumericMacro,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let arity = match op {
+        NumericMacro::Abs => 1,
+        NumericMacro::Min | NumericMacro::Max => 2,
+        NumericMacro::Clamp => 3,
+    };
+    if args.len() != arity {
+        return Err(format!("{} expects {} arguments", macro_name, arity));
+    }
+
+    let mut operands = Vec::new();
+    for arg in args {
+        operands.push(load_numeric_operand(
+            self_compiler,
+            arg,
+            macro_name,
+            module,
+        )?);
+    }
+
+    let (tag, data) = match op {
+        NumericMacro::Min => {
+            let (a, b) = (operands[0], operands[1]);
+            let b_less = numeric_less(self_compiler, b, a);
+            select_operand(self_compiler, b_less, b, a)
+        }
+        NumericMacro::Max => {
+            let (a, b) = (operands[0], operands[1]);
+            let a_less = numeric_less(self_compiler, a, b);
+            select_operand(self_compiler, a_less, b, a)
+        }
+        NumericMacro::Clamp => {
+            let (x, lo, hi) = (operands[0], operands[1], operands[2]);
+            let below = numeric_less(self_compiler, x, lo);
+            let low = select_operand(self_compiler, below, lo, x);
+            let above = numeric_less(self_compiler, hi, low);
+            select_operand(self_compiler, above, hi, low)
+        }
+        NumericMacro::Abs => numeric_abs(self_compiler, operands[0]),
+    };
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "numeric_macro_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Dynamic(tag),
+        StoreValue::Int(data),
+        "numeric_macro_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// Compiles `arg` and loads its tag and data, panicking with a TypeError when it is not a number.
+fn load_numeric_operand<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    arg: &ast::Expr,
+    macro_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(IntValue<'ctx>, IntValue<'ctx>), String> {
+    let (tag, data) = load_operand(self_compiler, arg, module)?;
+
+    let tag_type = self_compiler.tag_type();
+    let is_dynamic_number = tag_in(self_compiler, tag, &[Tag::Integer, Tag::Float]);
+    let is_sized_lo = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::UGE,
+            tag,
+            tag_type.const_int(Tag::Int8 as u64, false),
+            "numeric_sized_lo",
+        )
+        .unwrap();
+    let is_sized_hi = self_compiler
+        .builder
+        .build_int_compare(
+            inkwell::IntPredicate::ULE,
+            tag,
+            tag_type.const_int(Tag::Float64 as u64, false),
+            "numeric_sized_hi",
+        )
+        .unwrap();
+    let is_sized = self_compiler
+        .builder
+        .build_and(is_sized_lo, is_sized_hi, "numeric_sized")
+        .unwrap();
+    let is_number = self_compiler
+        .builder
+        .build_or(is_dynamic_number, is_sized, "numeric_ok")
+        .unwrap();
+
+    let message = format!("TypeError: {} expects numbers", macro_name);
+    panic_unless(self_compiler, is_number, &message, module)?;
+    Ok((tag, data))
+}
+
+// Like `load_numeric_operand`, for Int and the sized integer types.
+fn load_int_operand<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    arg: &ast::Expr,
+    macro_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<IntValue<'ctx>, String> {
+    let (tag, data) = load_operand(self_compiler, arg, module)?;
+    let is_int = tag_in(
+        self_compiler,
+        tag,
+        &[
+            Tag::Integer,
+            Tag::Int8,
+            Tag::Uint8,
+            Tag::Int16,
+            Tag::Uint16,
+            Tag::Int32,
+            Tag::Uint32,
+            Tag::Int64,
+            Tag::Uint64,
+        ],
+    );
+    let message = format!("TypeError: {} expects integers", macro_name);
+    panic_unless(self_compiler, is_int, &message, module)?;
+    Ok(data)
+}
+
+// A Str argument as the pointer to its text.
+fn load_str_operand<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    arg: &ast::Expr,
+    macro_name: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<PointerValue<'ctx>, String> {
+    let (tag, data) = load_operand(self_compiler, arg, module)?;
+    let is_str = tag_in(self_compiler, tag, &[Tag::String]);
+    let message = format!("TypeError: {} expects a Str", macro_name);
+    panic_unless(self_compiler, is_str, &message, module)?;
+    let text = self_compiler
+        .builder
+        .build_int_to_ptr(
+            data,
+            self_compiler.context.ptr_type(AddressSpace::default()),
+            "operand_str",
+        )
+        .unwrap();
+    Ok(text)
+}
+
+fn load_operand<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    arg: &ast::Expr,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(IntValue<'ctx>, IntValue<'ctx>), String> {
+    let value_ptr = self_compiler
+        .compile_expr(arg, module)?
+        .into_pointer_value();
+    let tag_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            value_ptr,
+            0,
+            "operand_tag_ptr",
+        )
+        .unwrap();
+    let tag = self_compiler
+        .builder
+        .build_load(self_compiler.tag_type(), tag_ptr, "operand_tag")
+        .unwrap()
+        .into_int_value();
+    let data_ptr = self_compiler
+        .builder
+        .build_struct_gep(
+            self_compiler.runtime_value_type,
+            value_ptr,
+            1,
+            "operand_data_ptr",
+        )
+        .unwrap();
+    let data = self_compiler
+        .builder
+        .build_load(self_compiler.data_type(), data_ptr, "operand_data")
+        .unwrap()
+        .into_int_value();
+    Ok((tag, data))
+}
+
+// Panics with `message` (and the location) when `ok` is false, then continues after the check.
+fn panic_unless<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    ok: IntValue<'ctx>,
+    message: &str,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<(), String> {
+    let parent_fn = self_compiler
+        .builder
+        .get_insert_block()
+        .unwrap()
+        .get_parent()
+        .unwrap();
+    let error_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "check_error_bb");
+    let ok_bb = self_compiler
+        .context
+        .append_basic_block(parent_fn, "check_ok_bb");
+    self_compiler
+        .builder
+        .build_conditional_branch(ok, ok_bb, error_bb)
+        .unwrap();
+
+    self_compiler.builder.position_at_end(error_bb);
+    create_panic_err(
+        self_compiler,
+        message,
+        module,
+        PanicErrorSettings {
+            is_const: true,
+            is_global: false,
+        },
+    )?;
+    self_compiler.builder.build_unreachable().unwrap();
+
+    self_compiler.builder.position_at_end(ok_bb);
+    Ok(())
+}
+
+// Whether `tag` is one of `tags`.
+fn tag_in<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    tag: IntValue<'ctx>,
+    tags: &[Tag],
+) -> IntValue<'ctx> {
+    let mut result = self_compiler.context.bool_type().const_zero();
+    for t in tags {
+        let is_tag = self_compiler
+            .builder
+            .build_int_compare(
+                inkwell::IntPredicate::EQ,
+                tag,
+                self_compiler.tag_type().const_int(*t as u64, false),
+                "is_tag",
+            )
+            .unwrap();
+        result = self_compiler
+            .builder
+            .build_or(result, is_tag, "tag_in")
+            .unwrap();
+    }
+    result
+}
+
+const FLOAT_TAGS: [Tag; 4] = [Tag::Float, Tag::Float16, Tag::Float32, Tag::Float64];
+const UNSIGNED_TAGS: [Tag; 4] = [Tag::Uint8, Tag::Uint16, Tag::Uint32, Tag::Uint64];
+
+// `a < b` for two numeric operands.
+fn numeric_less<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    (a_tag, a_data): (IntValue<'ctx>, IntValue<'ctx>),
+    (b_tag, b_data): (IntValue<'ctx>, IntValue<'ctx>),
+) -> IntValue<'ctx> {
+    let builder = &self_compiler.builder;
+    let a_float = tag_in(self_compiler, a_tag, &FLOAT_TAGS);
+    let b_float = tag_in(self_compiler, b_tag, &FLOAT_TAGS);
+    let any_float = builder.build_or(a_float, b_float, "any_float").unwrap();
+    let a_f64 = numeric_to_f64(self_compiler, a_tag, a_data);
+    let b_f64 = numeric_to_f64(self_compiler, b_tag, b_data);
+    let float_less = builder
+        .build_float_compare(inkwell::FloatPredicate::OLT, a_f64, b_f64, "float_less")
+        .unwrap();
+
+    let a_unsigned = tag_in(self_compiler, a_tag, &UNSIGNED_TAGS);
+    let b_unsigned = tag_in(self_compiler, b_tag, &UNSIGNED_TAGS);
+    let both_unsigned = builder
+        .build_and(a_unsigned, b_unsigned, "both_unsigned")
+        .unwrap();
+    let unsigned_less = builder
+        .build_int_compare(inkwell::IntPredicate::ULT, a_data, b_data, "unsigned_less")
+        .unwrap();
+    let signed_less = builder
+        .build_int_compare(inkwell::IntPredicate::SLT, a_data, b_data, "signed_less")
+        .unwrap();
+    let int_less = builder
+        .build_select(both_unsigned, unsigned_less, signed_less, "int_less")
+        .unwrap()
+        .into_int_value();
+
+    builder
+        .build_select(any_float, float_less, int_less, "numeric_less")
+        .unwrap()
+        .into_int_value()
+}
+
+// A numeric payload as f64: floats are widened, integers converted.
+fn numeric_to_f64<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    tag: IntValue<'ctx>,
+    data: IntValue<'ctx>,
+) -> FloatValue<'ctx> {
+    let builder = &self_compiler.builder;
+    let context = self_compiler.context;
+    let f64_type = context.f64_type();
+
+    let as_f64 = data_to_f64(self_compiler, data, "num_f64");
+    let f32_bits = builder
+        .build_int_truncate(data, context.i32_type(), "num_f32_bits")
+        .unwrap();
+    let f32_val = builder
+        .build_bit_cast(f32_bits, context.f32_type(), "num_f32")
+        .unwrap()
+        .into_float_value();
+    let f32_ext = builder
+        .build_float_ext(f32_val, f64_type, "num_f32_ext")
+        .unwrap();
+    let f16_bits = builder
+        .build_int_truncate(data, context.i16_type(), "num_f16_bits")
+        .unwrap();
+    let f16_val = builder
+        .build_bit_cast(f16_bits, context.f16_type(), "num_f16")
+        .unwrap()
+        .into_float_value();
+    let f16_ext = builder
+        .build_float_ext(f16_val, f64_type, "num_f16_ext")
+        .unwrap();
+    let int_val = builder
+        .build_signed_int_to_float(data, f64_type, "num_int_f64")
+        .unwrap();
+
+    let is_f64 = tag_in(self_compiler, tag, &[Tag::Float, Tag::Float64]);
+    let is_f32 = tag_in(self_compiler, tag, &[Tag::Float32]);
+    let is_f16 = tag_in(self_compiler, tag, &[Tag::Float16]);
+    let value = builder
+        .build_select(is_f64, as_f64, int_val, "num_sel_f64")
+        .unwrap();
+    let value = builder
+        .build_select(is_f32, f32_ext.into(), value, "num_sel_f32")
+        .unwrap();
+    builder
+        .build_select(is_f16, f16_ext.into(), value, "num_sel_f16")
+        .unwrap()
+        .into_float_value()
+}
+
+fn select_operand<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    cond: IntValue<'ctx>,
+    (then_tag, then_data): (IntValue<'ctx>, IntValue<'ctx>),
+    (else_tag, else_data): (IntValue<'ctx>, IntValue<'ctx>),
+) -> (IntValue<'ctx>, IntValue<'ctx>) {
+    let tag = self_compiler
+        .builder
+        .build_select(cond, then_tag, else_tag, "numeric_sel_tag")
+        .unwrap()
+        .into_int_value();
+    let data = self_compiler
+        .builder
+        .build_select(cond, then_data, else_data, "numeric_sel_data")
+        .unwrap()
+        .into_int_value();
+    (tag, data)
+}
+
+// Floats drop the sign bit of their width, signed integers are negated when negative
+// (wrapping, so abs!(cast!(-128, i8)) stays -128) and unsigned ones are returned as they are.
+fn numeric_abs<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    (tag, data): (IntValue<'ctx>, IntValue<'ctx>),
+) -> (IntValue<'ctx>, IntValue<'ctx>) {
+    let builder = &self_compiler.builder;
+    let data_type = self_compiler.data_type();
+
+    let sign_mask = |bit: u32| data_type.const_int(!(1u64 << bit), false);
+    let is_f32 = tag_in(self_compiler, tag, &[Tag::Float32]);
+    let is_f16 = tag_in(self_compiler, tag, &[Tag::Float16]);
+    let mask = builder
+        .build_select(is_f32, sign_mask(31), sign_mask(63), "abs_mask_f32")
+        .unwrap()
+        .into_int_value();
+    let mask = builder
+        .build_select(is_f16, sign_mask(15), mask, "abs_mask_f16")
+        .unwrap()
+        .into_int_value();
+    let float_abs = builder.build_and(data, mask, "float_abs").unwrap();
+
+    let neg = builder.build_int_neg(data, "int_neg").unwrap();
+    let neg = wrap_to_tag_width(self_compiler, tag, neg);
+    let is_negative = builder
+        .build_int_compare(
+            inkwell::IntPredicate::SLT,
+            data,
+            data_type.const_zero(),
+            "is_negative",
+        )
+        .unwrap();
+    let is_unsigned = tag_in(self_compiler, tag, &UNSIGNED_TAGS);
+    let is_signed = builder.build_not(is_unsigned, "is_signed").unwrap();
+    let negate = builder
+        .build_and(is_negative, is_signed, "abs_negate")
+        .unwrap();
+    let int_abs = builder
+        .build_select(negate, neg, data, "int_abs")
+        .unwrap()
+        .into_int_value();
+
+    let is_float = tag_in(self_compiler, tag, &FLOAT_TAGS);
+    let data = builder
+        .build_select(is_float, float_abs, int_abs, "abs_data")
+        .unwrap()
+        .into_int_value();
+    (tag, data)
+}
+
+// Sign-extends `value` from the width of a sized signed integer tag, so a wrapped
+// result is stored the way the other i8/i16/i32 values are.
+fn wrap_to_tag_width<'ctx>(
+    self_compiler: &Compiler<'ctx>,
+    tag: IntValue<'ctx>,
+    value: IntValue<'ctx>,
+) -> IntValue<'ctx> {
+    let builder = &self_compiler.builder;
+    let context = self_compiler.context;
+    let data_type = self_compiler.data_type();
+    let mut result = value;
+    for (sized_tag, int_type) in [
+        (Tag::Int8, context.i8_type()),
+        (Tag::Int16, context.i16_type()),
+        (Tag::Int32, context.i32_type()),
+    ] {
+        if int_type.get_bit_width() >= data_type.get_bit_width() {
+            continue;
+        }
+        let narrowed = builder
+            .build_int_truncate(value, int_type, "wrap_trunc")
+            .unwrap();
+        let widened = builder
+            .build_int_s_extend(narrowed, data_type, "wrap_ext")
+            .unwrap();
+        let is_tag = tag_in(self_compiler, tag, &[sized_tag]);
+        result = builder
+            .build_select(is_tag, widened, result, "wrap_sel")
+            .unwrap()
+            .into_int_value();
+    }
+    result
+}
+
+// rand!(lo, hi): a random Int in `lo..hi` (hi excluded) from the runtime's xorshift generator.
+pub fn call_builtin_macro_rand<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 2 {
+        return Err("rand! expects 2 arguments".to_string());
+    }
+    let lo = load_int_operand(self_compiler, &args[0], "rand!", module)?;
+    let hi = load_int_operand(self_compiler, &args[1], "rand!", module)?;
+    let non_empty = self_compiler
+        .builder
+        .build_int_compare(inkwell::IntPredicate::SLT, lo, hi, "rand_non_empty")
+        .unwrap();
+    panic_unless(
+        self_compiler,
+        non_empty,
+        "ValueError: rand! range is empty",
+        module,
+    )?;
+
+    let runtime_fn = self_compiler.get_runtime_fn(module, "__rand_range");
+    let call = self_compiler
+        .builder
+        .build_call(runtime_fn, &[lo.into(), hi.into()], "rand_call")
+        .unwrap();
+    let value = match call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_int_value(),
+        ValueKind::Instruction(_) => {
+            return Err("__rand_range did not return a value".to_string());
+        }
+    };
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "rand_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(Tag::Integer as u64),
+        StoreValue::Int(value),
+        "rand_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// rand_seed!(n): restarts the sequence of rand! from `n`, e.g. a timer or ADC reading.
+pub fn call_builtin_macro_rand_seed<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if args.len() != 1 {
+        return Err("rand_seed! expects 1 argument".to_string());
+    }
+    let seed = load_int_operand(self_compiler, &args[0], "rand_seed!", module)?;
+    let runtime_fn = self_compiler.get_runtime_fn(module, "__rand_seed");
+    self_compiler
+        .builder
+        .build_call(runtime_fn, &[seed.into()], "rand_seed_call")
+        .unwrap();
+
+    let res_ptr = create_entry_block_alloca(self_compiler, "rand_seed_res_alloc");
+    self_compiler.tag_only_runtime_value_store(res_ptr, Tag::Unit as u64, "unit_res");
+    Ok(res_ptr.into())
+}
+
+// now!(): milliseconds from a monotonic clock, for measuring time between two readings.
+pub fn call_builtin_macro_now<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if !args.is_empty() {
+        return Err("now! expects no arguments".to_string());
+    }
+    let clock_fn = self_compiler.get_runtime_fn(module, "__clock_ms");
+    let call = self_compiler
+        .builder
+        .build_call(clock_fn, &[], "now_call")
+        .unwrap();
+    let ms = match call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_int_value(),
+        ValueKind::Instruction(_) => {
+            return Err("__clock_ms did not return a value".to_string());
+        }
+    };
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "now_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Int(Tag::Integer as u64),
+        StoreValue::Int(ms),
+        "now_res",
+    );
+    Ok(result_ptr.into())
+}
+
+// sleep!(ms): waits for `ms` milliseconds.
+pub fn call_builtin_macro_sleep<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    let [ms] = args.as_slice() else {
+        return Err("sleep! expects the time in milliseconds".to_string());
+    };
+    let ms = load_int_operand(self_compiler, ms, "sleep!", module)?;
+    let sleep_fn = self_compiler.get_runtime_fn(module, "__sleep_ms");
+    self_compiler
+        .builder
+        .build_call(sleep_fn, &[ms.into()], "sleep_call")
+        .unwrap();
+    create_unit(self_compiler)
+}
+
+// read_line!(): the next line of stdin as a Str without the line ending, or Unit at the end
+// of input, so `while is!(line, Str)` reads until the input is closed.
+pub fn call_builtin_macro_read_line<'ctx>(
+    self_compiler: &mut Compiler<'ctx>,
+    args: &Vec<ast::Expr>,
+    module: &inkwell::module::Module<'ctx>,
+) -> Result<BasicValueEnum<'ctx>, String> {
+    if !args.is_empty() {
+        return Err("read_line! expects no arguments".to_string());
+    }
+
+    let runtime_fn = self_compiler.get_runtime_fn(module, "__read_line");
+    let call = self_compiler
+        .builder
+        .build_call(runtime_fn, &[], "read_line_call")
+        .unwrap();
+    let line_ptr = match call.try_as_basic_value() {
+        ValueKind::Basic(val) => val.into_pointer_value(),
+        ValueKind::Instruction(_) => {
+            return Err("__read_line did not return a value".to_string());
+        }
+    };
+
+    let at_eof = self_compiler
+        .builder
+        .build_is_null(line_ptr, "read_line_eof")
+        .unwrap();
+    let tag_type = self_compiler.tag_type();
+    let tag = self_compiler
+        .builder
+        .build_select(
+            at_eof,
+            tag_type.const_int(Tag::Unit as u64, false),
+            tag_type.const_int(Tag::String as u64, false),
+            "read_line_tag",
+        )
+        .unwrap()
+        .into_int_value();
+    let data = self_compiler
+        .builder
+        .build_ptr_to_int(line_ptr, self_compiler.data_type(), "read_line_data")
+        .unwrap();
+
+    let result_ptr = create_entry_block_alloca(self_compiler, "read_line_res_alloc");
+    self_compiler.build_runtime_value_store(
+        result_ptr,
+        StoreTag::Dynamic(tag),
+        StoreValue::Int(data),
+        "read_line_res",
+    );
+    Ok(result_ptr.into())
+}
@@ -0,0 +1,156 @@
+// C header for code that talks to Sprs programs through FFI.
+// Keep in sync with `Tag` in compiler.rs / runtime.rs and the runtime function signatures.
+const C_HEADER: &str = r#"/* Generated by `sprs build --emit-header`. Do not edit. */
+#ifndef SPRS_H
+#define SPRS_H
+
+#include <stdint.h>
+
+/* Value layout: { int32_t tag, uint64_t data }, or { uint8_t tag, uint32_t data }
+ * on the experimental AVR target. sprs_word_t is the width of Int, indices and sizes. */
+#ifdef __AVR__
+typedef uint8_t sprs_tag_t;
+typedef uint32_t sprs_data_t;
+typedef int32_t sprs_word_t;
+#else
+typedef int32_t sprs_tag_t;
+typedef uint64_t sprs_data_t;
+typedef int64_t sprs_word_t;
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Value tags */
+typedef enum SprsTag {
+    SPRS_TAG_INTEGER = 0,
+    SPRS_TAG_FLOAT = 1,
+    SPRS_TAG_STRING = 2,
+    SPRS_TAG_BOOLEAN = 3,
+    SPRS_TAG_LIST = 4,
+    SPRS_TAG_RANGE = 5,
+    SPRS_TAG_UNIT = 6,
+    SPRS_TAG_ENUM = 7,
+    SPRS_TAG_STRUCT = 8,
+    SPRS_TAG_MOVED = 9,
+    SPRS_TAG_CHAR = 10,
+
+    SPRS_TAG_INT8 = 100,
+    SPRS_TAG_UINT8 = 101,
+    SPRS_TAG_INT16 = 102,
+    SPRS_TAG_UINT16 = 103,
+    SPRS_TAG_INT32 = 104,
+    SPRS_TAG_UINT32 = 105,
+    SPRS_TAG_INT64 = 106,
+    SPRS_TAG_UINT64 = 107,
+    SPRS_TAG_FLOAT16 = 108,
+    SPRS_TAG_FLOAT32 = 109,
+    SPRS_TAG_FLOAT64 = 110
+} SprsTag;
+
+/* Every Sprs value: a tag plus the payload.
+ * Numbers and booleans are stored inline (floats as their bit pattern),
+ * strings are `char *`, lists and ranges are pointers to the handles below. */
+typedef struct SprsValue {
+    sprs_tag_t tag;
+    sprs_data_t data;
+} SprsValue;
+
+/* Opaque list handle, owned by the runtime */
+typedef struct SprsList SprsList;
+
+typedef struct SprsRange {
+    sprs_word_t start;
+    sprs_word_t end;
+} SprsRange;
+
+/* A struct value points at its fields, which follow a pointer to this table
+ * of functions generated for the struct definition. */
+typedef struct SprsStructVtable {
+    void (*drop)(void *self);
+    void *(*clone)(void *self);
+} SprsStructVtable;
+
+/* Runtime functions */
+SprsList *__list_new(sprs_word_t capacity);
+void __list_push(SprsList *list, sprs_tag_t tag, sprs_data_t data);
+SprsValue *__list_get(SprsList *list, sprs_word_t index);
+SprsList *__list_concat(SprsList *l, SprsList *r);
+SprsList *__list_slice(SprsList *list, sprs_word_t start, sprs_word_t end);
+SprsList *__list_repeat(SprsList *list, sprs_word_t times);
+void __str_get(const char *s, sprs_word_t index, SprsValue *out);
+char *__str_repeat(const char *s, sprs_word_t times);
+char *__str_slice(const char *s, sprs_word_t start, sprs_word_t end);
+sprs_word_t __strlen(const char *s);
+char *__to_string(sprs_tag_t tag, sprs_data_t data);
+sprs_word_t __to_int(sprs_tag_t tag, sprs_data_t data, sprs_word_t *out);
+sprs_word_t __to_bool(sprs_tag_t tag, sprs_data_t data, sprs_word_t *out);
+sprs_word_t __to_char(sprs_tag_t tag, sprs_data_t data, sprs_word_t *out);
+void __exit(sprs_word_t code);
+char *__read_line(void);
+sprs_word_t __clock_ms(void);
+void __sleep_ms(sprs_word_t ms);
+void __rand_seed(sprs_word_t seed);
+sprs_word_t __rand_range(sprs_word_t lo, sprs_word_t hi);
+sprs_word_t __contains(sprs_tag_t needle_tag, sprs_data_t needle_data, sprs_tag_t container_tag, sprs_data_t container_data);
+sprs_word_t __contains_values(sprs_tag_t needle_tag, sprs_data_t needle_data, const SprsValue *items, sprs_word_t len);
+SprsRange *__range_new(sprs_word_t start, sprs_word_t end);
+void __println(SprsList *args);
+char *__malloc(sprs_word_t size);
+void __free(void *ptr, sprs_word_t size);
+void __alloc_track(void *ptr, sprs_word_t size, sprs_tag_t tag);
+void __alloc_report(void);
+void __drop(sprs_tag_t tag, sprs_data_t data);
+SprsValue __clone(sprs_tag_t tag, sprs_data_t data);
+void __panic(const char *message);
+void __panic_code(uint16_t code);
+void __set_panic_handler(void (*handler)(const char *message));
+char *__str_unpack(const uint8_t *packed, char **cache);
+
+/* Built-in `math` package */
+double __math_sqrt(double x);
+double __math_pow(double x, double y);
+double __math_sin(double x);
+double __math_cos(double x);
+double __math_floor(double x);
+double __math_ceil(double x);
+
+/* Built-in `file` package; handles come from __file_open */
+sprs_word_t __file_open(const char *path, const char *mode);
+char *__file_read_all(sprs_word_t handle);
+void __file_write(sprs_word_t handle, const char *text);
+void __file_close(sprs_word_t handle);
+
+/* Built-in `string` package */
+SprsList *__str_split(const char *s, const char *sep);
+char *__str_trim(const char *s);
+char *__str_to_upper(const char *s);
+char *__str_to_lower(const char *s);
+sprs_word_t __str_contains(const char *s, const char *needle);
+sprs_word_t __str_find(const char *s, const char *needle);
+SprsList *__str_chars(const char *s);
+
+/* Freestanding runtime (bare metal, `--no-std`). The board support code provides the
+ * sprs_* functions; startup code may call __sprs_heap_init before main to replace
+ * the static heap of the bump and pool allocators with its own RAM region. */
+void sprs_write(const uint8_t *ptr, uintptr_t len);
+uint32_t sprs_clock_ms(void);
+void sprs_sleep_ms(uint32_t ms);
+void __sprs_heap_init(void *ptr, uintptr_t len);
+
+/* Test harness used by `sprs test` */
+void __test_begin(const char *name);
+void __test_pass(const char *name);
+void __test_done(sprs_word_t total);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SPRS_H */
+"#;
+
+pub fn generate_c_header() -> &'static str {
+    C_HEADER
+}
@@ -1,21 +1,36 @@
 use crate::command_helper;
+use crate::command_helper::BuildOptions;
 use crate::front::ast;
-use crate::interpreter::runner::parse_only;
+use crate::front::diagnostics::{Diagnostic, Diagnostics};
+use crate::front::error_codes;
+use crate::front::lexer::Edition;
+use crate::front::preprocess::{self, Symbols};
+use crate::interpreter::runner::parse_module;
 use crate::interpreter::type_helper;
 use crate::interpreter::type_helper::Type;
 use crate::llvm;
+use crate::llvm::build_log::BuildLog;
 use crate::llvm::builder_helper;
+use crate::llvm::builder_helper::ArithOp;
 use crate::llvm::builder_helper::Comparison;
 use crate::llvm::builder_helper::EqNeq;
+use crate::llvm::builder_helper::IntMacroMode;
+use crate::llvm::builder_helper::NumericMacro;
 use crate::llvm::builder_helper::TagOptionsInst;
 use crate::llvm::builder_helper::UpDown;
+use crate::llvm::debug_info::DebugInfo;
+use crate::llvm::file;
+use crate::llvm::math;
+use crate::llvm::panic_table::PanicSite;
+use crate::llvm::plugin::{BUILTIN_MACROS, MacroPlugin};
+use crate::llvm::string;
 use inkwell::AddressSpace;
 use inkwell::builder::Builder;
 use inkwell::context::Context;
 use inkwell::module::Linkage;
 use inkwell::module::Module;
 use inkwell::types::BasicTypeEnum;
-use inkwell::types::{BasicMetadataTypeEnum, StructType};
+use inkwell::types::{BasicMetadataTypeEnum, BasicType, IntType, StructType};
 use inkwell::values::FloatValue;
 use inkwell::values::IntValue;
 use inkwell::values::{BasicValueEnum, FunctionValue, PointerValue, ValueKind};
@@ -25,7 +40,29 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::f32::consts::E;
 use std::ptr;
+use std::rc::Rc;
 use std::result;
+use std::time::Instant;
+
+// Functions with more locals than this get a single frame array instead of one alloca each.
+pub const MAX_FUNCTION_LOCALS: usize = 1024;
+
+// Parameter types of an `extern fn`; its result is boxed from the LLVM return type.
+#[derive(Clone)]
+pub struct ExternSig {
+    pub params: Vec<Type>,
+}
+
+// Indexed local slots used when a function goes over MAX_FUNCTION_LOCALS.
+pub struct FrameSlots<'ctx> {
+    pub function: FunctionValue<'ctx>,
+    pub base: PointerValue<'ctx>, // [capacity x runtime_value]: parameters and `var`s
+    pub capacity: u32,
+    pub next: u32,
+    pub temps: PointerValue<'ctx>, // [temp_count x runtime_value], sized when the function is done
+    pub temp_next: u32,            // a statement's temporaries start where the statement starts
+    pub temp_count: u32,
+}
 
 pub struct StructDef<'ctx> {
     pub fields: Vec<ast::StructField>,
@@ -41,11 +78,29 @@ pub struct Compiler<'ctx> {
     pub function_signatures: Option<FunctionValue<'ctx>>,
     pub runtime_value_type: StructType<'ctx>,
     pub target_os: OS,
-    pub string_constants: HashMap<String, inkwell::values::GlobalValue<'ctx>>,
     pub malloc_type: inkwell::types::FunctionType<'ctx>,
     pub source_path: String,
+    pub dependencies: HashMap<String, String>, // `[dependencies]` name -> its src_dir
     pub struct_defs: HashMap<String, StructDef<'ctx>>, // struct name -> struct definition
     pub enum_names: HashSet<String>,
+    pub options: BuildOptions,
+    // (llvm module name, function name) -> declared return type
+    pub fn_return_types: HashMap<(String, String), Type>,
+    pub declared_modules: HashMap<String, String>, // module file name -> llvm module name
+    pub module_aliases: HashMap<String, HashMap<String, String>>, // llvm module -> alias -> module
+    pub imported_names: HashMap<String, HashMap<String, String>>, // llvm module -> fn -> module
+    pub extern_fns: HashMap<String, HashMap<String, ExternSig>>, // llvm module -> `extern fn`s
+    pub frame: Option<FrameSlots<'ctx>>,           // set while compiling a spilled function
+    pub elided_clones: HashSet<*const ast::Expr>,  // clone! args passed without copying
+    pub stack_values: HashSet<*const ast::Expr>,   // string concats and lists that don't escape
+    pub module_sources: HashMap<String, (String, String)>, // llvm module name -> (path, source)
+    pub current_span: Option<ast::Span>,           // error location: the statement or expression
+    pub debug_info: Option<DebugInfo<'ctx>>,       // `--debug`: DWARF of the module being compiled
+    pub diagnostics: Diagnostics,
+    pub test_names: Vec<String>, // `test_*` functions run by the test entry point, in order
+    pub panic_sites: Vec<PanicSite>, // `--panic-codes`: code N is panic_sites[N - 1]
+    pub macros: HashMap<String, Rc<dyn MacroPlugin>>, // plugin macros by name, e.g. "can_frame!"
+    pub symbols: Symbols,        // `#define`/`-D` symbols for `#if`, carried over to later modules
 }
 
 pub enum StoreTag<'ctx> {
@@ -62,6 +117,30 @@ pub enum StoreValue<'ctx> {
 
 // Support builder_helper.rs for LLVM instuctions of execution.
 impl<'ctx> Compiler<'ctx> {
+    // Tag field of a runtime value: i32, or i8 on AVR
+    pub fn tag_type(&self) -> IntType<'ctx> {
+        if self.target_os == OS::Avr {
+            self.context.i8_type()
+        } else {
+            self.context.i32_type()
+        }
+    }
+
+    // Data field of a runtime value and the width of Int: i64, or i32 on AVR
+    pub fn data_type(&self) -> IntType<'ctx> {
+        if self.target_os == OS::Avr {
+            self.context.i32_type()
+        } else {
+            self.context.i64_type()
+        }
+    }
+
+    // Mach-O has no comdat groups; linkonce_odr globals are coalesced by name there
+    pub fn supports_comdat(&self) -> bool {
+        let triple = llvm::llvm_executer::target_triple(self.target_os);
+        !triple.as_str().to_string_lossy().contains("-apple-")
+    }
+
     // Default options is i64 integer store
     pub fn build_runtime_value_store(
         &self,
@@ -71,7 +150,7 @@ impl<'ctx> Compiler<'ctx> {
         name: &str,
     ) {
         let tag_val = match tag {
-            StoreTag::Int(t) => self.context.i32_type().const_int(t, false),
+            StoreTag::Int(t) => self.tag_type().const_int(t, false),
             StoreTag::Dynamic(t) => t,
         };
 
@@ -98,14 +177,14 @@ impl<'ctx> Compiler<'ctx> {
 
         let data_val = match value {
             StoreValue::Int(v) => v,
-            StoreValue::Float(f) => self.context.i64_type().const_int(f.to_bits(), false),
+            StoreValue::Float(f) => self.data_type().const_int(f.to_bits(), false),
             StoreValue::Ptr(p) => self
                 .builder
-                .build_ptr_to_int(p, self.context.i64_type(), "ptr_to_int")
+                .build_ptr_to_int(p, self.data_type(), "ptr_to_int")
                 .unwrap(),
             StoreValue::Bool(b) => self
                 .builder
-                .build_int_z_extend(b, self.context.i64_type(), name)
+                .build_int_z_extend(b, self.data_type(), name)
                 .unwrap(),
         };
 
@@ -117,7 +196,7 @@ impl<'ctx> Compiler<'ctx> {
         tag: u64,
         name: &str,
     ) {
-        let tag_val = self.context.i32_type().const_int(tag, false);
+        let tag_val = self.tag_type().const_int(tag, false);
 
         let tag_ptr = self
             .builder
@@ -149,7 +228,7 @@ impl<'ctx> Compiler<'ctx> {
             .unwrap();
         let tag = self
             .builder
-            .build_load(self.context.i32_type(), tag_ptr, &format!("{}_tag", name))
+            .build_load(self.tag_type(), tag_ptr, &format!("{}_tag", name))
             .unwrap()
             .into_int_value();
 
@@ -164,7 +243,7 @@ impl<'ctx> Compiler<'ctx> {
             .unwrap();
         let data = self
             .builder
-            .build_load(self.context.i64_type(), data_ptr, &format!("{}_data", name))
+            .build_load(self.data_type(), data_ptr, &format!("{}_data", name))
             .unwrap()
             .into_int_value();
 
@@ -193,13 +272,36 @@ impl<'ctx> Compiler<'ctx> {
     }
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum OS {
     Unknown, // default triple
     Windows,
     Linux,
+    Wasm,  // wasm32-wasip1
+    RiscV, // rv32imac bare metal
+    Avr,   // 8-bit AVR (experimental), values are { i8 tag, i32 data }
+}
+
+impl OS {
+    // Target selected with `--target <triple>`.
+    pub fn from_triple(triple: &str) -> Option<OS> {
+        if triple.starts_with("riscv32") {
+            Some(OS::RiscV)
+        } else if triple.starts_with("avr") {
+            Some(OS::Avr)
+        } else if triple.starts_with("wasm32") {
+            Some(OS::Wasm)
+        } else if triple.contains("windows") {
+            Some(OS::Windows)
+        } else if triple.contains("linux") {
+            Some(OS::Linux)
+        } else {
+            None
+        }
+    }
 }
 
+#[derive(Clone, Copy)]
 pub enum Tag {
     // Dynamic value tags
     Integer = 0, // i64
@@ -211,6 +313,8 @@ pub enum Tag {
     Unit = 6,
     Enum = 7,
     Struct = 8,
+    Moved = 9, // left behind by a move, reading it panics
+    Char = 10, // a Unicode scalar value
 
     // System types
     Int8 = 100,
@@ -229,6 +333,9 @@ pub enum Tag {
 
 const WINDOWS_STR: &str = "Windows";
 const LINUX_STR: &str = "Linux";
+const WASM_STR: &str = "Wasm";
+const RISCV_STR: &str = "RiscV";
+const AVR_STR: &str = "Avr";
 
 pub struct Scope<'ctx> {
     pub variables: HashMap<String, (BasicValueEnum<'ctx>, Type)>,
@@ -245,7 +352,12 @@ impl<'ctx> Scope<'ctx> {
 }
 
 impl<'ctx> Compiler<'ctx> {
-    pub fn new(context: &'ctx Context, builder: Builder<'ctx>, source_path: String) -> Self {
+    pub fn new(
+        context: &'ctx Context,
+        builder: Builder<'ctx>,
+        source_path: String,
+        options: BuildOptions,
+    ) -> Self {
         let runtime_value_type = context.struct_type(
             &[context.i32_type().into(), context.i64_type().into()],
             false,
@@ -259,6 +371,8 @@ impl<'ctx> Compiler<'ctx> {
         let mut scopes = Vec::new();
         scopes.push(Scope::new());
 
+        let symbols = Symbols::new(&options.defines);
+
         Compiler {
             context,
             modules: HashMap::new(),
@@ -267,14 +381,43 @@ impl<'ctx> Compiler<'ctx> {
             function_signatures: None,
             runtime_value_type,
             target_os: OS::Unknown,
-            string_constants: HashMap::new(),
             malloc_type,
             source_path,
+            dependencies: HashMap::new(),
             struct_defs: HashMap::new(),
             enum_names: HashSet::new(),
+            options,
+            fn_return_types: HashMap::new(),
+            declared_modules: HashMap::new(),
+            module_aliases: HashMap::new(),
+            imported_names: HashMap::new(),
+            extern_fns: HashMap::new(),
+            frame: None,
+            elided_clones: HashSet::new(),
+            stack_values: HashSet::new(),
+            module_sources: HashMap::new(),
+            current_span: None,
+            debug_info: None,
+            diagnostics: Diagnostics::new(),
+            test_names: Vec::new(),
+            panic_sites: Vec::new(),
+            macros: HashMap::new(),
+            symbols,
         }
     }
 
+    pub fn register_macro(&mut self, plugin: Rc<dyn MacroPlugin>) -> Result<(), String> {
+        let name = plugin.name().to_string();
+        if !name.ends_with('!') {
+            return Err(format!("Macro name must end with '!': {}", name));
+        }
+        if BUILTIN_MACROS.contains(&name.as_str()) || self.macros.contains_key(&name) {
+            return Err(format!("Macro {} is already defined", name));
+        }
+        self.macros.insert(name, plugin);
+        Ok(())
+    }
+
     fn enter_scope(&mut self) {
         self.scopes.push(Scope::new());
     }
@@ -345,19 +488,21 @@ impl<'ctx> Compiler<'ctx> {
 
     pub fn register_struct(&mut self, name: String, fields: Vec<ast::StructField>) {
         let mut field_indices = HashMap::new();
-        let mut llvm_field_types: Vec<BasicTypeEnum> = Vec::new();
+        // index 0 points at the struct's vtable (see builder_helper::struct_vtable)
+        let mut llvm_field_types: Vec<BasicTypeEnum> =
+            vec![self.context.ptr_type(AddressSpace::default()).into()];
         for (i, field) in fields.iter().enumerate() {
-            field_indices.insert(field.ident.clone(), i as u32);
+            field_indices.insert(field.ident.clone(), i as u32 + 1);
 
             let llvm_ty = if let Some(ty) = &field.ty {
                 match ty {
                     Type::Any => self.runtime_value_type.into(),
-                    Type::Int => self.context.i64_type().into(),
+                    Type::Int => self.data_type().into(),
                     Type::Str => self.context.ptr_type(AddressSpace::default()).into(),
                     Type::Float => self.context.f64_type().into(),
                     Type::Bool => self.context.bool_type().into(),
                     Type::Unit => self.runtime_value_type.into(),
-                    Type::Enum => self.context.i64_type().into(),
+                    Type::Enum => self.data_type().into(),
                     Type::Struct(_) => self.runtime_value_type.into(),
 
                     Type::TypeI8 => self.context.i8_type().into(),
@@ -416,47 +561,144 @@ impl<'ctx> Compiler<'ctx> {
             return func;
         }
 
-        let i64_type = self.context.i64_type();
-        let i32_type = self.context.i32_type();
+        let data_type = self.data_type();
+        let tag_type = self.tag_type();
         let i8_ptr_type = self.context.ptr_type(inkwell::AddressSpace::default());
         let void_type = self.context.void_type();
 
         let fn_type = match name {
-            "__list_new" => i8_ptr_type.fn_type(&[i64_type.into()], false),
+            "__list_new" => i8_ptr_type.fn_type(&[data_type.into()], false),
             "__list_push" => void_type.fn_type(
                 &[
                     i8_ptr_type.into(), // list ptr
-                    i32_type.into(),    // value tag
-                    i64_type.into(),    // value data
+                    tag_type.into(),    // value tag
+                    data_type.into(),   // value data
                 ],
                 false,
             ),
             "__list_get" => i8_ptr_type.fn_type(
                 &[
                     i8_ptr_type.into(), // list ptr
-                    i64_type.into(),    // index
+                    data_type.into(),   // index
+                ],
+                false,
+            ),
+            "__list_concat" => i8_ptr_type.fn_type(
+                &[
+                    i8_ptr_type.into(), // left list ptr
+                    i8_ptr_type.into(), // right list ptr
+                ],
+                false,
+            ),
+            "__list_repeat" => i8_ptr_type.fn_type(
+                &[
+                    i8_ptr_type.into(), // list ptr
+                    data_type.into(),   // times
+                ],
+                false,
+            ),
+            "__contains" => data_type.fn_type(
+                &[
+                    tag_type.into(),  // needle tag
+                    data_type.into(), // needle data
+                    tag_type.into(),  // container tag
+                    data_type.into(), // container data
+                ],
+                false,
+            ),
+            "__contains_values" => data_type.fn_type(
+                &[
+                    tag_type.into(),    // needle tag
+                    data_type.into(),   // needle data
+                    i8_ptr_type.into(), // SprsValue array
+                    data_type.into(),   // len
+                ],
+                false,
+            ),
+            "__str_repeat" => i8_ptr_type.fn_type(
+                &[
+                    i8_ptr_type.into(), // string ptr
+                    data_type.into(),   // times
+                ],
+                false,
+            ),
+            "__str_get" => void_type.fn_type(
+                &[
+                    i8_ptr_type.into(), // string ptr
+                    data_type.into(),   // index
+                    i8_ptr_type.into(), // out: result value
                 ],
                 false,
             ),
             "__range_new" => i8_ptr_type.fn_type(
                 &[
-                    i64_type.into(), // start
-                    i64_type.into(), // end
+                    data_type.into(), // start
+                    data_type.into(), // end
                 ],
                 false,
             ),
             "__println" => void_type.fn_type(&[i8_ptr_type.into()], false),
-            "__strlen" => i64_type.fn_type(&[i8_ptr_type.into()], false),
-            "__malloc" => i8_ptr_type.fn_type(&[i64_type.into()], false),
-            "__drop" => void_type.fn_type(&[i32_type.into(), i64_type.into()], false),
+            "__strlen" => data_type.fn_type(&[i8_ptr_type.into()], false),
+            "__str_slice" | "__list_slice" => i8_ptr_type.fn_type(
+                &[i8_ptr_type.into(), data_type.into(), data_type.into()],
+                false,
+            ),
+            "__math_pow" => {
+                let f64_type = self.context.f64_type();
+                f64_type.fn_type(&[f64_type.into(), f64_type.into()], false)
+            }
+            "__math_sqrt" | "__math_sin" | "__math_cos" | "__math_floor" | "__math_ceil" => {
+                let f64_type = self.context.f64_type();
+                f64_type.fn_type(&[f64_type.into()], false)
+            }
+            "__exit" => void_type.fn_type(&[data_type.into()], false),
+            "__str_split" => i8_ptr_type.fn_type(&[i8_ptr_type.into(), i8_ptr_type.into()], false),
+            "__str_chars" => i8_ptr_type.fn_type(&[i8_ptr_type.into()], false),
+            "__str_trim" | "__str_to_upper" | "__str_to_lower" => {
+                i8_ptr_type.fn_type(&[i8_ptr_type.into()], false)
+            }
+            "__str_contains" | "__str_find" => {
+                data_type.fn_type(&[i8_ptr_type.into(), i8_ptr_type.into()], false)
+            }
+            "__clock_ms" => data_type.fn_type(&[], false),
+            "__sleep_ms" => void_type.fn_type(&[data_type.into()], false),
+            "__read_line" => i8_ptr_type.fn_type(&[], false),
+            "__file_open" => data_type.fn_type(&[i8_ptr_type.into(), i8_ptr_type.into()], false),
+            "__file_read_all" => i8_ptr_type.fn_type(&[data_type.into()], false),
+            "__file_write" => void_type.fn_type(&[data_type.into(), i8_ptr_type.into()], false),
+            "__file_close" => void_type.fn_type(&[data_type.into()], false),
+            "__rand_seed" => void_type.fn_type(&[data_type.into()], false),
+            "__rand_range" => data_type.fn_type(&[data_type.into(), data_type.into()], false),
+            "__to_string" => i8_ptr_type.fn_type(&[tag_type.into(), data_type.into()], false),
+            "__to_int" | "__to_bool" | "__to_char" => data_type.fn_type(
+                &[
+                    tag_type.into(),    // value tag
+                    data_type.into(),   // value data
+                    i8_ptr_type.into(), // out
+                ],
+                false,
+            ),
+            "__malloc" => i8_ptr_type.fn_type(&[data_type.into()], false),
+            "__free" => void_type.fn_type(&[i8_ptr_type.into(), data_type.into()], false),
+            "__alloc_track" => void_type.fn_type(
+                &[i8_ptr_type.into(), data_type.into(), tag_type.into()],
+                false,
+            ),
+            "__alloc_report" => void_type.fn_type(&[], false),
+            "__set_panic_handler" => void_type.fn_type(&[i8_ptr_type.into()], false),
+            "__drop" => void_type.fn_type(&[tag_type.into(), data_type.into()], false),
             "__clone" => self.runtime_value_type.fn_type(
                 &[
-                    i32_type.into(), // value tag
-                    i64_type.into(), // value data
+                    tag_type.into(),  // value tag
+                    data_type.into(), // value data
                 ],
                 false,
             ),
             "__panic" => void_type.fn_type(&[i8_ptr_type.into()], false),
+            "__panic_code" => void_type.fn_type(&[self.context.i16_type().into()], false),
+            "__str_unpack" => i8_ptr_type.fn_type(&[i8_ptr_type.into(), i8_ptr_type.into()], false),
+            "__test_begin" | "__test_pass" => void_type.fn_type(&[i8_ptr_type.into()], false),
+            "__test_done" => void_type.fn_type(&[data_type.into()], false),
             _ => panic!("Unknown runtime function: {}", name),
         };
 
@@ -468,11 +710,68 @@ impl<'ctx> Compiler<'ctx> {
         module_name: &str,
         main_path: Option<&String>,
     ) -> Result<(), String> {
-        if self.modules.contains_key(module_name) {
+        // Phase 1: parse every reachable module and declare all of their prototypes.
+        // Phase 2: compile the bodies. Splitting the two lets modules that import
+        // each other call each other's functions.
+        // Errors are collected in `self.diagnostics` so one run reports as many as possible.
+        let entry_path = main_path
+            .cloned()
+            .unwrap_or_else(|| module_path(&self.source_path, module_name));
+        self.symbols = project_symbols(
+            &entry_path,
+            &self.options.defines,
+            self.options.target,
+            self.options.edition,
+        );
+
+        let log = BuildLog::new(self.options.verbosity);
+        let started = Instant::now();
+        let mut pending = Vec::new();
+        if let Err(e) = self.declare_module(module_name, main_path, &mut pending) {
+            self.diagnostics.push(Diagnostic::error(e));
+        }
+        log.timed(format_args!("parse {} module(s)", pending.len()), started);
+
+        for (llvm_module_name, items) in pending {
+            let started = Instant::now();
+            let name = llvm_module_name.clone();
+            if let Err(e) = self.compile_module_bodies(llvm_module_name, &items) {
+                self.diagnostics.push(Diagnostic::error(e));
+            }
+            log.timed(format_args!("codegen {}", name), started);
+        }
+
+        let warnings = self.diagnostics.warning_count();
+        match self.diagnostics.error_count() {
+            0 if self.options.deny_warnings && warnings > 0 => Err(format!(
+                "could not compile due to {} warning(s) (--deny-warnings)",
+                warnings
+            )),
+            0 => Ok(()),
+            1 => Err("could not compile due to the previous error".to_string()),
+            n => Err(format!("could not compile due to {} previous errors", n)),
+        }
+    }
+
+    fn declare_module(
+        &mut self,
+        module_name: &str,
+        main_path: Option<&String>,
+        pending: &mut Vec<(String, Vec<ast::Item>)>,
+    ) -> Result<(), String> {
+        // inserted before the imports are followed, so import cycles terminate
+        if self.declared_modules.contains_key(module_name) {
             return Ok(());
         }
+        self.declared_modules
+            .insert(module_name.to_string(), module_name.to_string());
 
-        let mut path = format!("{}/{}.sprs", self.source_path, module_name);
+        if main_path.is_none() && is_builtin_package(&self.source_path, module_name) {
+            return Ok(());
+        }
+
+        let mut path = dependency_module_path(&self.dependencies, module_name)
+            .unwrap_or_else(|| module_path(&self.source_path, module_name));
 
         if let Some(main_path) = main_path {
             if module_name == "main" {
@@ -483,9 +782,37 @@ impl<'ctx> Compiler<'ctx> {
         let source = std::fs::read_to_string(&path)
             .map_err(|e| format!("Failed to read module file {}: {}", path, e))?;
 
-        let items = parse_only(&source, &path)?;
+        self.diagnostics.add_source(&path, &source);
+
+        // a module that does not parse is skipped, the other modules are still checked
+        let text = match preprocess::preprocess(&source, &path, &mut self.symbols) {
+            Ok(text) => text,
+            Err(diagnostics) => {
+                self.diagnostics.extend(diagnostics);
+                return Ok(());
+            }
+        };
+        let mut items = match parse_module(&text, &path, self.options.edition) {
+            Ok(items) => items,
+            Err(diagnostics) => {
+                self.diagnostics.extend(diagnostics);
+                return Ok(());
+            }
+        };
+        self.qualify_dependency_imports(module_name, &mut items);
+        llvm::plugin::expand_macros(&mut items, &self.macros)?;
 
         self.process_preprocessors(&items);
+        self.diagnostics
+            .extend(llvm::lint::lint_module(&items, &path));
+
+        if self.target_os == OS::Avr {
+            let unsupported = llvm::target_check::check_avr(&items, &path);
+            if !unsupported.is_empty() {
+                self.diagnostics.extend(unsupported);
+                return Ok(());
+            }
+        }
 
         let llvm_module_name = items
             .iter()
@@ -494,35 +821,197 @@ impl<'ctx> Compiler<'ctx> {
                 _ => None,
             })
             .unwrap_or_else(|| module_name.to_string());
+        self.declared_modules
+            .insert(module_name.to_string(), llvm_module_name.clone());
 
         let module = self.context.create_module(&llvm_module_name);
 
         self.inject_runtime_constants(&module);
 
-        // First, load and compile all imports
-        for item in &items {
-            if let ast::Item::Import(import_name) = item {
-                self.load_and_compile_module(import_name, None)?;
-            }
-        }
-
         self.builder.clear_insertion_position();
 
-        // Declare all function prototypes
+        // Declare all function prototypes, so definition order inside a module never matters
+        let mut defined: HashMap<&str, ast::Span> = HashMap::new();
         for item in &items {
             match item {
                 ast::Item::FunctionItem(func) => {
-                    self.declare_fn_prototype(func, &module);
+                    if let Some(first) = defined.get(func.ident.as_str()) {
+                        self.diagnostics.push(
+                            Diagnostic::error(format!(
+                                "function '{}' is defined more than once",
+                                func.ident
+                            ))
+                            .with_code(error_codes::DUPLICATE_FUNCTION)
+                            .in_file(&path)
+                            .with_primary(func.span, "redefined here")
+                            .with_label(*first, "first defined here"),
+                        );
+                        continue;
+                    }
+                    defined.insert(&func.ident, func.span);
+
+                    if let Err(e) = self.declare_fn_prototype(func, &module) {
+                        self.diagnostics.push(
+                            Diagnostic::error(e)
+                                .in_file(&path)
+                                .with_primary(func.span, ""),
+                        );
+                    }
+                }
+                ast::Item::ExternFnItem(ext) => {
+                    if let Some(first) = defined.get(ext.ident.as_str()) {
+                        self.diagnostics.push(
+                            Diagnostic::error(format!(
+                                "function '{}' is defined more than once",
+                                ext.ident
+                            ))
+                            .with_code(error_codes::DUPLICATE_FUNCTION)
+                            .in_file(&path)
+                            .with_primary(ext.span, "redefined here")
+                            .with_label(*first, "first defined here"),
+                        );
+                        continue;
+                    }
+                    defined.insert(&ext.ident, ext.span);
+
+                    if let Err(e) = self.declare_extern_fn(ext, &module, &llvm_module_name) {
+                        self.diagnostics.push(
+                            Diagnostic::error(e)
+                                .in_file(&path)
+                                .with_primary(ext.span, ""),
+                        );
+                    }
                 }
                 _ => {}
             }
         }
 
+        self.modules.insert(llvm_module_name.clone(), module);
+        self.module_sources
+            .insert(llvm_module_name.clone(), (path, source));
+
+        for item in &items {
+            if let ast::Item::Import(import) = item {
+                self.declare_module(&import.module, None, pending)?;
+                self.register_import(&llvm_module_name, import);
+            }
+        }
+
+        // imports are pushed first, so their enums and structs are registered before use
+        pending.push((llvm_module_name, items));
+        Ok(())
+    }
+
+    // A dependency imports its own modules by their names in its src_dir: `import regs;` in
+    // `mylib.uart` is `mylib.regs`.
+    fn qualify_dependency_imports(&self, module_name: &str, items: &mut [ast::Item]) {
+        let Some((dependency, _)) = module_name.split_once('.') else {
+            return;
+        };
+        let Some(root) = self.dependencies.get(dependency) else {
+            return;
+        };
+        for item in items {
+            if let ast::Item::Import(import) = item {
+                if !is_builtin_package(root, &import.module) {
+                    import.module = format!("{}.{}", dependency, import.module);
+                }
+            }
+        }
+    }
+
+    // Records `as` aliases and selected names of an import for the importing module.
+    fn register_import(&mut self, llvm_module_name: &str, import: &ast::Import) {
+        let target = self
+            .declared_modules
+            .get(&import.module)
+            .cloned()
+            .unwrap_or_else(|| import.module.clone());
+
+        // `import drivers.uart;` is used as `uart.init()`
+        let alias = import.alias.clone().or_else(|| {
+            import
+                .module
+                .rsplit_once('.')
+                .map(|(_, last)| last.to_string())
+        });
+        if let Some(alias) = alias {
+            self.module_aliases
+                .entry(llvm_module_name.to_string())
+                .or_default()
+                .insert(alias, target.clone());
+        }
+
+        for name in &import.names {
+            // an import cycle can reach a module whose prototypes are not declared yet
+            let func = self.modules.get(&target).map(|m| m.get_function(name));
+            let problem = match func {
+                None if self.is_builtin_module(&target) => builtin_function_problem(&target, name),
+                Some(None) => Some(format!(
+                    "module '{}' has no function '{}'",
+                    import.module, name
+                )),
+                Some(Some(func)) if func.get_linkage() == Linkage::Private => {
+                    Some(builder_helper::private_function_error(name, &import.module))
+                }
+                _ => None,
+            };
+            if let Some(problem) = problem {
+                let path = self
+                    .module_sources
+                    .get(llvm_module_name)
+                    .map(|(path, _)| path.clone())
+                    .unwrap_or_default();
+                self.diagnostics.push(
+                    Diagnostic::error(problem)
+                        .with_code(error_codes::BAD_IMPORT)
+                        .in_file(&path)
+                        .with_primary(import.span, "imported here"),
+                );
+                continue;
+            }
+            self.imported_names
+                .entry(llvm_module_name.to_string())
+                .or_default()
+                .insert(name.clone(), target.clone());
+        }
+    }
+
+    // A built-in package when the project has no module of that name of its own.
+    pub fn is_builtin_module(&self, module_name: &str) -> bool {
+        !self.module_sources.contains_key(module_name)
+            && is_builtin_package(&self.source_path, module_name)
+    }
+
+    fn compile_module_bodies(
+        &mut self,
+        llvm_module_name: String,
+        items: &Vec<ast::Item>,
+    ) -> Result<(), String> {
+        // taken out of the map while its bodies are compiled, other modules stay reachable
+        let module = self
+            .modules
+            .remove(&llvm_module_name)
+            .ok_or_else(|| format!("Module '{}' not declared", llvm_module_name))?;
+
+        if self.options.debug {
+            if let Some((path, source)) = self.module_sources.get(&llvm_module_name) {
+                self.debug_info = Some(DebugInfo::new(
+                    self.context,
+                    &module,
+                    path,
+                    source,
+                    self.target_os,
+                    self.options.effective_opt_level() > 0,
+                ));
+            }
+        }
+
         let mut private_enum_variants: Vec<String> = Vec::new();
         let mut private_struct_fields: Vec<String> = Vec::new();
 
         // get enums and structs first
-        for item in &items {
+        for item in items {
             match item {
                 ast::Item::StructItem(items) => {
                     self.register_struct(items.ident.clone(), items.fields.clone());
@@ -548,16 +1037,37 @@ impl<'ctx> Compiler<'ctx> {
             }
         }
 
-        // Now compile all functions
-        for item in &items {
+        let path = self
+            .module_sources
+            .get(&llvm_module_name)
+            .map(|(path, _)| path.clone())
+            .unwrap_or_default();
+
+        // Now compile all functions; a failing one is reported and the rest are still compiled
+        for item in items {
             match item {
                 ast::Item::FunctionItem(func) => {
-                    self.compile_fn(func, &module)?;
+                    let scope_depth = self.scopes.len();
+                    let result = self.compile_fn(func, &module);
+                    let span = self.current_span.take();
+                    if let Some(debug_info) = &mut self.debug_info {
+                        debug_info.leave_function(&self.builder);
+                    }
+                    if let Err(e) = result {
+                        self.scopes.truncate(scope_depth);
+                        self.diagnostics.push(
+                            Diagnostic::error(e)
+                                .in_file(&path)
+                                .with_primary(span.unwrap_or(func.span), ""),
+                        );
+                    }
                 }
                 _ => {}
             }
         }
-        if llvm_module_name == "main" {
+        if llvm_module_name == "main" && self.options.test {
+            self.build_test_main(&module, items);
+        } else if llvm_module_name == "main" {
             if let Some(sprs_main_fn) = module.get_function("_sprs_main") {
                 let i32_type = self.context.i32_type();
                 let main_type = i32_type.fn_type(&[], false);
@@ -566,16 +1076,31 @@ impl<'ctx> Compiler<'ctx> {
                 let entry = self.context.append_basic_block(c_main, "entry");
                 self.builder.position_at_end(entry);
 
-                self.builder
+                if let Some(handler) = module.get_function("panic_handler") {
+                    builder_helper::install_panic_handler(self, handler, &module)?;
+                }
+
+                let call = self
+                    .builder
                     .build_call(sprs_main_fn, &[], "call_sprs_main")
                     .unwrap();
 
-                self.builder
-                    .build_return(Some(&i32_type.const_int(0, false)))
-                    .unwrap();
+                // `return 1;` from main is the exit status
+                let status = match call.try_as_basic_value() {
+                    ValueKind::Basic(value) => builder_helper::build_exit_status(self, value),
+                    ValueKind::Instruction(_) => i32_type.const_zero(),
+                };
+                if self.options.debug_alloc {
+                    let report_fn = self.get_runtime_fn(&module, "__alloc_report");
+                    self.builder.build_call(report_fn, &[], "").unwrap();
+                }
+                self.builder.build_return(Some(&status)).unwrap();
             }
         }
 
+        if let Some(debug_info) = self.debug_info.take() {
+            debug_info.finalize();
+        }
         self.modules.insert(llvm_module_name, module);
 
         for private_field in private_struct_fields {
@@ -589,16 +1114,69 @@ impl<'ctx> Compiler<'ctx> {
         Ok(())
     }
 
-    fn process_preprocessors(&mut self, items: &Vec<ast::Item>) {
+    // Entry point of a `sprs test` build: runs every `test_*` function without parameters
+    // and reports each one through the runtime's test protocol. A failing test panics,
+    // which ends the run with a FAIL line.
+    fn build_test_main(&mut self, module: &Module<'ctx>, items: &Vec<ast::Item>) {
+        let i32_type = self.context.i32_type();
+        let c_main = module.add_function("main", i32_type.fn_type(&[], false), None);
+        let entry = self.context.append_basic_block(c_main, "entry");
+        self.builder.position_at_end(entry);
+
+        let begin_fn = self.get_runtime_fn(module, "__test_begin");
+        let pass_fn = self.get_runtime_fn(module, "__test_pass");
+        let done_fn = self.get_runtime_fn(module, "__test_done");
+
         for item in items {
-            if let ast::Item::Preprocessor(pre) = item {
-                if pre.starts_with("Windows") {
-                    self.target_os = OS::Windows;
-                } else if pre.starts_with("Linux") {
-                    self.target_os = OS::Linux;
-                }
+            let ast::Item::FunctionItem(func) = item else {
+                continue;
+            };
+            if !func.ident.starts_with("test_") || !func.params.is_empty() {
+                continue;
             }
+            let Some(test_fn) = module.get_function(&func.ident) else {
+                continue;
+            };
+
+            let name =
+                builder_helper::intern_string_global(self, &func.ident, module).as_pointer_value();
+            self.builder
+                .build_call(begin_fn, &[name.into()], "test_begin")
+                .unwrap();
+            self.builder.build_call(test_fn, &[], "run_test").unwrap();
+            self.builder
+                .build_call(pass_fn, &[name.into()], "test_pass")
+                .unwrap();
+            self.test_names.push(func.ident.clone());
         }
+
+        let total = self
+            .data_type()
+            .const_int(self.test_names.len() as u64, false);
+        self.builder
+            .build_call(done_fn, &[total.into()], "test_done")
+            .unwrap();
+        self.builder
+            .build_return(Some(&i32_type.const_int(0, false)))
+            .unwrap();
+    }
+
+    fn process_preprocessors(&mut self, items: &Vec<ast::Item>) {
+        if let Some(target) = target_from_defines(items) {
+            self.target_os = target;
+        }
+        if let Some(target) = self.options.target {
+            self.target_os = target;
+        }
+
+        // the value layout follows the target
+        self.runtime_value_type = self
+            .context
+            .struct_type(&[self.tag_type().into(), self.data_type().into()], false);
+        self.malloc_type = self
+            .context
+            .ptr_type(AddressSpace::default())
+            .fn_type(&[self.data_type().into()], false);
     }
 
     fn register_enum(&mut self, enm: &ast::Enum, module: &Module<'ctx>, is_global: bool) {
@@ -612,8 +1190,8 @@ impl<'ctx> Compiler<'ctx> {
         let i8_ptr_type = self.context.ptr_type(AddressSpace::default());
         let enum_info_type = self.context.struct_type(
             &[
-                i8_ptr_type.into(),             // name
-                self.context.i64_type().into(), // variant_index
+                i8_ptr_type.into(),      // name
+                self.data_type().into(), // variant_index
             ],
             false,
         );
@@ -621,7 +1199,7 @@ impl<'ctx> Compiler<'ctx> {
         for (idx, variant) in enm.variants.iter().enumerate() {
             let full_name = format!("{}.{}", enm.ident, variant);
 
-            let enum_tag = self.context.i32_type().const_int(Tag::Enum as u64, false);
+            let enum_tag = self.tag_type().const_int(Tag::Enum as u64, false);
 
             let ptr = if !is_global {
                 let current_block = self.builder.get_insert_block().unwrap();
@@ -639,10 +1217,13 @@ impl<'ctx> Compiler<'ctx> {
                     .build_global_string_ptr(&full_name, &format!("enum_name_{}", full_name))
                     .unwrap();
 
-                let enum_info_ptr = self
-                    .builder
-                    .build_malloc(enum_info_type, &format!("enum_info_{}", full_name))
-                    .unwrap();
+                let enum_info_ptr = builder_helper::build_runtime_malloc(
+                    self,
+                    enum_info_type,
+                    &format!("enum_info_{}", full_name),
+                    module,
+                )
+                .unwrap();
 
                 let name_gep = self
                     .builder
@@ -656,12 +1237,12 @@ impl<'ctx> Compiler<'ctx> {
                     .builder
                     .build_struct_gep(enum_info_type, enum_info_ptr, 1, "variant_index_ptr")
                     .unwrap();
-                let idx_val = self.context.i64_type().const_int(idx as u64, false);
+                let idx_val = self.data_type().const_int(idx as u64, false);
                 self.builder.build_store(idx_gep, idx_val).unwrap();
 
                 let enum_info_int = self
                     .builder
-                    .build_ptr_to_int(enum_info_ptr, self.context.i64_type(), "enum_info_as_int")
+                    .build_ptr_to_int(enum_info_ptr, self.data_type(), "enum_info_as_int")
                     .unwrap();
 
                 let alloca = self
@@ -702,7 +1283,7 @@ impl<'ctx> Compiler<'ctx> {
                         .const_gep(self.context.i8_type(), &[zero, zero])
                 };
 
-                let idx_val = self.context.i64_type().const_int(idx as u64, false);
+                let idx_val = self.data_type().const_int(idx as u64, false);
                 let enum_info_const =
                     enum_info_type.const_named_struct(&[name_ptr.into(), idx_val.into()]);
 
@@ -718,7 +1299,7 @@ impl<'ctx> Compiler<'ctx> {
                 global_enum_info.set_linkage(Linkage::Internal);
 
                 let enum_info_ptr = global_enum_info.as_pointer_value();
-                let enum_info_int = enum_info_ptr.const_to_int(self.context.i64_type());
+                let enum_info_int = enum_info_ptr.const_to_int(self.data_type());
 
                 let global = module.add_global(
                     self.runtime_value_type,
@@ -742,6 +1323,9 @@ impl<'ctx> Compiler<'ctx> {
             OS::Unknown => "Unknown",
             OS::Windows => WINDOWS_STR,
             OS::Linux => LINUX_STR,
+            OS::Wasm => WASM_STR,
+            OS::RiscV => RISCV_STR,
+            OS::Avr => AVR_STR,
         };
         let os_str_val = self.context.const_string(os_str.as_bytes(), true);
 
@@ -755,7 +1339,70 @@ impl<'ctx> Compiler<'ctx> {
         global.set_constant(true);
     }
 
-    fn declare_fn_prototype(&self, func: &ast::Function, module: &Module<'ctx>) {
+    // The C type an `extern fn` parameter or result of type `ty` is passed as.
+    fn extern_llvm_type(&self, ty: &Type) -> Option<BasicTypeEnum<'ctx>> {
+        let context = self.context;
+        let llvm_type = match ty {
+            Type::Int => self.data_type().into(),
+            Type::Float | Type::TypeF64 => context.f64_type().into(),
+            Type::Bool => context.bool_type().into(),
+            Type::Str => context.ptr_type(AddressSpace::default()).into(),
+            Type::TypeI8 | Type::TypeU8 => context.i8_type().into(),
+            Type::TypeI16 | Type::TypeU16 => context.i16_type().into(),
+            Type::TypeI32 | Type::TypeU32 => context.i32_type().into(),
+            Type::TypeI64 | Type::TypeU64 => context.i64_type().into(),
+            Type::TypeF16 => context.f16_type().into(),
+            Type::TypeF32 => context.f32_type().into(),
+            Type::Any | Type::Unit | Type::Enum | Type::Struct(_) => return None,
+        };
+        Some(llvm_type)
+    }
+
+    fn declare_extern_fn(
+        &mut self,
+        ext: &ast::ExternFunction,
+        module: &Module<'ctx>,
+        llvm_module_name: &str,
+    ) -> Result<(), String> {
+        let mut param_types: Vec<BasicMetadataTypeEnum> = Vec::new();
+        for param in &ext.params {
+            let llvm_type = self.extern_llvm_type(&param.ty).ok_or_else(|| {
+                format!(
+                    "parameter '{}' of extern fn '{}' cannot be passed to C; use int, fp, bool, str or a sized type",
+                    param.ident, ext.ident
+                )
+            })?;
+            param_types.push(llvm_type.into());
+        }
+        let fn_type = match &ext.ret_ty {
+            None | Some(Type::Unit) => self.context.void_type().fn_type(&param_types, false),
+            Some(ty) => self
+                .extern_llvm_type(ty)
+                .ok_or_else(|| format!("extern fn '{}' cannot return {:?}", ext.ident, ty))?
+                .fn_type(&param_types, false),
+        };
+
+        // another module may already have declared the same C function
+        if module.get_function(&ext.ident).is_none() {
+            module.add_function(&ext.ident, fn_type, Some(Linkage::External));
+        }
+        self.extern_fns
+            .entry(llvm_module_name.to_string())
+            .or_default()
+            .insert(
+                ext.ident.clone(),
+                ExternSig {
+                    params: ext.params.iter().map(|p| p.ty.clone()).collect(),
+                },
+            );
+        Ok(())
+    }
+
+    fn declare_fn_prototype(
+        &mut self,
+        func: &ast::Function,
+        module: &Module<'ctx>,
+    ) -> Result<(), String> {
         let arg_types: Vec<BasicMetadataTypeEnum> = (0..func.params.len())
             .map(|_| self.context.ptr_type(AddressSpace::default()).into())
             .collect();
@@ -763,7 +1410,7 @@ impl<'ctx> Compiler<'ctx> {
         let fn_type = if let Some(ret_ty) = &func.ret_ty {
             match ret_ty {
                 Type::Any => self.runtime_value_type.fn_type(&arg_types, false),
-                Type::Int => self.context.i64_type().fn_type(&arg_types, false),
+                Type::Int => self.data_type().fn_type(&arg_types, false),
                 Type::Str => self
                     .context
                     .ptr_type(AddressSpace::default())
@@ -771,7 +1418,7 @@ impl<'ctx> Compiler<'ctx> {
                 Type::Float => self.context.f64_type().fn_type(&arg_types, false),
                 Type::Bool => self.context.bool_type().fn_type(&arg_types, false),
                 Type::Unit => self.context.void_type().fn_type(&arg_types, false),
-                Type::Enum => self.context.i64_type().fn_type(&arg_types, false),
+                Type::Enum => self.data_type().fn_type(&arg_types, false),
                 Type::Struct(_) => self.runtime_value_type.fn_type(&arg_types, false),
 
                 Type::TypeI8 => self.context.i8_type().fn_type(&arg_types, false),
@@ -797,15 +1444,18 @@ impl<'ctx> Compiler<'ctx> {
             &func.ident
         };
 
-        let fn_val = if let Some(f) = module.get_function(func_name) {
-            f
-        } else {
-            module.add_function(func_name, fn_type, None)
-        };
+        let fn_val = module.add_function(func_name, fn_type, None);
 
         if !func.is_public {
             fn_val.set_linkage(Linkage::Private);
         }
+
+        let module_name = module.get_name().to_string_lossy().into_owned();
+        self.fn_return_types.insert(
+            (module_name, func.ident.clone()),
+            func.ret_ty.clone().unwrap_or(Type::Any),
+        );
+        Ok(())
     }
 
     pub fn get_known_type_from_expr(&self, expr: &ast::Expr) -> Result<String, String> {
@@ -839,7 +1489,22 @@ impl<'ctx> Compiler<'ctx> {
         }
     }
 
-    fn infer_type(&self, expr: &ast::Expr) -> Type {
+    // Return type of a call made from `module`, following a selected import to its module.
+    fn declared_return_type(&self, module: &Module<'ctx>, ident: &str) -> Type {
+        let module_name = module.get_name().to_string_lossy().into_owned();
+        let target = self
+            .imported_names
+            .get(&module_name)
+            .and_then(|names| names.get(ident))
+            .cloned()
+            .unwrap_or(module_name);
+        self.fn_return_types
+            .get(&(target, ident.to_string()))
+            .cloned()
+            .unwrap_or(Type::Any)
+    }
+
+    fn infer_type(&self, expr: &ast::Expr, module: &Module<'ctx>) -> Type {
         match expr {
             ast::Expr::Number(_) => Type::Int,
             ast::Expr::Float(_) => Type::Float,
@@ -861,26 +1526,29 @@ impl<'ctx> Compiler<'ctx> {
             ast::Expr::TypeF16 => Type::TypeF16,
             ast::Expr::TypeF32 => Type::TypeF32,
             ast::Expr::TypeF64 => Type::TypeF64,
-            ast::Expr::Add(lhs, _)
-            | ast::Expr::Mul(lhs, _)
-            | ast::Expr::Minus(lhs, _)
-            | ast::Expr::Div(lhs, _)
-            | ast::Expr::Mod(lhs, _) => self.infer_type(lhs),
-            ast::Expr::Increment(value) | ast::Expr::Decrement(value) => self.infer_type(value),
+            ast::Expr::Add(lhs, _, _)
+            | ast::Expr::Mul(lhs, _, _)
+            | ast::Expr::Minus(lhs, _, _)
+            | ast::Expr::Div(lhs, _, _)
+            | ast::Expr::Mod(lhs, _, _) => self.infer_type(lhs, module),
+            ast::Expr::Increment(value) | ast::Expr::Decrement(value) => {
+                self.infer_type(value, module)
+            }
             ast::Expr::If(_, then, if_else) => {
-                let then_ty = self.infer_type(then);
-                let else_ty = self.infer_type(if_else);
+                let then_ty = self.infer_type(then, module);
+                let else_ty = self.infer_type(if_else, module);
                 if then_ty == else_ty {
                     then_ty
                 } else {
                     Type::Any
                 }
             }
-            ast::Expr::Call(_, _, ret_ty_opt) => {
+            ast::Expr::Call(ident, _, ret_ty_opt, _) => {
                 if let Some(ret_ty) = ret_ty_opt {
                     ret_ty.clone()
                 } else {
-                    Type::Any
+                    // declared in the prototype pass, so this also works for functions defined later
+                    self.declared_return_type(module, ident)
                 }
             }
             ast::Expr::StructInit(name, _) => Type::Struct(name.clone()),
@@ -910,23 +1578,86 @@ impl<'ctx> Compiler<'ctx> {
         let entry = self.context.append_basic_block(fn_val, "entry");
         self.builder.position_at_end(entry);
         self.function_signatures = Some(fn_val);
+        if let Some(debug_info) = &mut self.debug_info {
+            let is_optimized = self.options.effective_opt_level() > 0;
+            debug_info.enter_function(
+                &self.builder,
+                fn_val,
+                &func.ident,
+                func.span.start,
+                func.params.len(),
+                is_optimized,
+            );
+        }
+
+        self.elided_clones = llvm::last_use::elidable_clones(func);
+        self.stack_values = llvm::escape::stack_allocations(func);
+
+        let local_count = count_var_decls(&func.blk);
+        if local_count + func.params.len() > MAX_FUNCTION_LOCALS {
+            let path = module
+                .get_name()
+                .to_str()
+                .ok()
+                .and_then(|name| self.module_sources.get(name))
+                .map(|(path, _)| path.clone())
+                .unwrap_or_default();
+            self.diagnostics.push(
+                Diagnostic::warning(format!(
+                    "function '{}' has {} locals (limit {}), spilling them into a frame array",
+                    func.ident,
+                    local_count + func.params.len(),
+                    MAX_FUNCTION_LOCALS
+                ))
+                .in_file(path)
+                .with_primary(func.span, ""),
+            );
+            let capacity = (local_count + func.params.len()) as u32;
+            let base = self
+                .builder
+                .build_alloca(self.runtime_value_type.array_type(capacity), "frame")
+                .map_err(|e| e.to_string())?;
+            // replaced by an array of the right size in finish_frame
+            let temps = self
+                .builder
+                .build_alloca(self.runtime_value_type.array_type(0), "frame_temps")
+                .map_err(|e| e.to_string())?;
+            self.frame = Some(FrameSlots {
+                function: fn_val,
+                base,
+                capacity,
+                next: 0,
+                temps,
+                temp_next: 0,
+                temp_count: 0,
+            });
+        }
 
         self.enter_scope();
 
         for (idx, param) in func.params.iter().enumerate() {
             let arg_val = fn_val.get_nth_param(idx as u32).unwrap();
 
-            let alloca = self
-                .builder
-                .build_alloca(self.runtime_value_type, &param.ident)
-                .unwrap();
+            let alloca = match builder_helper::next_frame_slot(self, &param.ident) {
+                Some(slot) => slot,
+                None => self
+                    .builder
+                    .build_alloca(self.runtime_value_type, &param.ident)
+                    .unwrap(),
+            };
             self.builder
                 .build_store(alloca, arg_val)
                 .map_err(|e| e.to_string())?;
             self.add_variable(param.ident.clone(), alloca.into(), Type::Any);
         }
 
-        self.compile_block(&func.blk, module)?;
+        let result = self.compile_block(&func.blk, module);
+        if let Some(frame) = self.frame.take() {
+            self.finish_frame(frame);
+        }
+        self.elided_clones.clear();
+        self.stack_values.clear();
+        result?;
 
         let current_block = self.builder.get_insert_block().unwrap();
         if current_block.get_terminator().is_none() {
@@ -947,6 +1678,24 @@ impl<'ctx> Compiler<'ctx> {
         }
     }
 
+    // Gives the temporaries of a spilled function one array, as large as the most any statement
+    // had live at once.
+    fn finish_frame(&self, frame: FrameSlots<'ctx>) {
+        let placeholder = frame.temps.as_instruction().unwrap();
+        if frame.temp_count > 0 {
+            self.builder.position_before(&placeholder);
+            let temps = self
+                .builder
+                .build_alloca(
+                    self.runtime_value_type.array_type(frame.temp_count),
+                    "frame_temps",
+                )
+                .unwrap();
+            placeholder.replace_all_uses_with(&temps.as_instruction().unwrap());
+        }
+        placeholder.erase_from_basic_block();
+    }
+
     pub(crate) fn compile_block(
         &mut self,
         stmts: &Vec<ast::Stmt>,
@@ -965,16 +1714,32 @@ impl<'ctx> Compiler<'ctx> {
                 break;
             }
 
-            match stmt {
-                ast::Stmt::Var(var) => {
+            self.current_span = Some(stmt.span);
+            if let Some(debug_info) = &self.debug_info {
+                debug_info.set_location(&self.builder, stmt.span.start);
+            }
+            // statements have no value, so their temporaries are dead once they are done
+            let temp_mark = self.frame.as_ref().map(|frame| frame.temp_next);
+            match &stmt.kind {
+                ast::StmtKind::Var(var) => {
                     let init_val = self
                         .compile_expr(&var.expr.as_ref().unwrap_or(&ast::Expr::Unit()), module)?
                         .into_pointer_value();
 
                     let var_type =
-                        self.infer_type(&var.expr.as_ref().unwrap_or(&ast::Expr::Unit()));
-
-                    builder_helper::var_load_at_init_variable(self, init_val, &var.ident);
+                        self.infer_type(&var.expr.as_ref().unwrap_or(&ast::Expr::Unit()), module);
+
+                    // the variable owns its own copy, so moving the source doesn't affect it
+                    let var_ptr =
+                        builder_helper::var_load_at_init_variable(self, init_val, &var.ident);
+                    if let Some(debug_info) = &self.debug_info {
+                        debug_info.declare_variable(
+                            &self.builder,
+                            var_ptr,
+                            &var.ident,
+                            stmt.span.start,
+                        );
+                    }
 
                     if let Some(ast::Expr::Var(src_val_name)) = &var.expr {
                         let var_val = self.get_variables(src_val_name).map(|(v, _)| v);
@@ -982,9 +1747,9 @@ impl<'ctx> Compiler<'ctx> {
                             builder_helper::move_variable(self, &val, &var.ident);
                         }
                     }
-                    self.add_variable(var.ident.clone(), init_val.into(), var_type);
+                    self.add_variable(var.ident.clone(), var_ptr.into(), var_type);
                 }
-                ast::Stmt::Return(expr_opt) => {
+                ast::StmtKind::Return(expr_opt) => {
                     let ret_val = if let Some(expr) = expr_opt {
                         let ptr = self.compile_expr(expr, module)?.into_pointer_value();
 
@@ -999,7 +1764,7 @@ impl<'ctx> Compiler<'ctx> {
                         let current_fn = self.function_signatures.unwrap();
                         let return_type = current_fn.get_type().get_return_type();
 
-                        let expr_type = self.infer_type(expr);
+                        let expr_type = self.infer_type(expr, module);
 
                         if let Some(ret_ty) = return_type {
                             if ret_ty.is_pointer_type() {
@@ -1053,7 +1818,7 @@ impl<'ctx> Compiler<'ctx> {
                                     .unwrap();
                                 let data_val = self
                                     .builder
-                                    .build_load(self.context.i64_type(), data_ptr, "data_load")
+                                    .build_load(self.data_type(), data_ptr, "data_load")
                                     .unwrap()
                                     .into_int_value();
 
@@ -1069,15 +1834,8 @@ impl<'ctx> Compiler<'ctx> {
                                     }
                                 } else if ret_ty.is_float_type() {
                                     let float_type = ret_ty.into_float_type();
-                                    let f64_val = self
-                                        .builder
-                                        .build_bit_cast(
-                                            data_val,
-                                            self.context.f64_type(),
-                                            "casted_float",
-                                        )
-                                        .unwrap()
-                                        .into_float_value();
+                                    let f64_val =
+                                        builder_helper::data_to_f64(self, data_val, "casted_float");
 
                                     if float_type.get_bit_width() == 32 {
                                         self.builder
@@ -1118,7 +1876,7 @@ impl<'ctx> Compiler<'ctx> {
                         builder_helper::create_dummy_for_no_return(self);
                     }
                 }
-                ast::Stmt::If {
+                ast::StmtKind::If {
                     cond,
                     then_blk,
                     else_blk,
@@ -1126,17 +1884,17 @@ impl<'ctx> Compiler<'ctx> {
                     builder_helper::create_if_condition(self, cond, then_blk, else_blk, module)
                         .map_err(|e| e.to_string())?;
                 }
-                ast::Stmt::While { cond, body } => {
+                ast::StmtKind::While { cond, body } => {
                     builder_helper::create_while_condition(self, cond, body, module)
                         .map_err(|e| e.to_string())?;
                 }
-                ast::Stmt::Expr(expr) => {
+                ast::StmtKind::Expr(expr) => {
                     self.compile_expr(expr, module)?;
                 }
-                ast::Stmt::EnumItem(enm) => {
+                ast::StmtKind::EnumItem(enm) => {
                     self.register_enum(enm, &module, false);
                 }
-                ast::Stmt::Assign(assign_stmt) => {
+                ast::StmtKind::Assign(assign_stmt) => {
                     let val_ptr = self
                         .compile_expr(&assign_stmt.expr, module)?
                         .into_pointer_value();
@@ -1165,6 +1923,12 @@ impl<'ctx> Compiler<'ctx> {
                         }
                     }
                 }
+                ast::StmtKind::MultiAssign(assigns) => {
+                    builder_helper::create_multi_assign(self, assigns, module)?;
+                }
+            }
+            if let (Some(frame), Some(mark)) = (&mut self.frame, temp_mark) {
+                frame.temp_next = mark;
             }
         }
 
@@ -1177,6 +1941,24 @@ impl<'ctx> Compiler<'ctx> {
         &mut self,
         expr: &ast::Expr,
         module: &Module<'ctx>,
+    ) -> Result<BasicValueEnum<'ctx>, String> {
+        // panics in an operator, call or index report its own position, not the statement's
+        let Some(span) = expr.span() else {
+            return self.compile_expr_node(expr, module);
+        };
+        let outer = self.current_span.replace(span);
+        let result = self.compile_expr_node(expr, module);
+        // an error keeps pointing at the expression that failed
+        if result.is_ok() {
+            self.current_span = outer;
+        }
+        result
+    }
+
+    fn compile_expr_node(
+        &mut self,
+        expr: &ast::Expr,
+        module: &Module<'ctx>,
     ) -> Result<BasicValueEnum<'ctx>, String> {
         match expr {
             ast::Expr::Number(n) => {
@@ -1239,14 +2021,26 @@ impl<'ctx> Compiler<'ctx> {
                 let result = builder_helper::create_bool(self, boolean);
                 result
             }
+            ast::Expr::Char(c) => {
+                let result = builder_helper::create_char(self, *c);
+                result
+            }
             ast::Expr::Var(ident) => {
                 if let Some((var_addr, _)) = self.get_variables(ident) {
+                    if var_addr.is_pointer_value() {
+                        builder_helper::check_not_moved(
+                            self,
+                            var_addr.into_pointer_value(),
+                            ident,
+                            module,
+                        )?;
+                    }
                     Ok(var_addr)
                 } else {
                     Err(format!("Undefined variable: {}", ident))
                 }
             }
-            ast::Expr::Call(ident, args, _) => {
+            ast::Expr::Call(ident, args, _, _) => {
                 if ident == "println!" {
                     let result = builder_helper::call_builtin_macro_println(self, args, module);
                     return result;
@@ -1262,11 +2056,140 @@ impl<'ctx> Compiler<'ctx> {
                     return result;
                 }
 
+                if ident == "panic!" {
+                    let result = builder_helper::call_builtin_macro_panic(self, args, module);
+                    return result;
+                }
+
+                if ident == "assert!" {
+                    let result = builder_helper::call_builtin_macro_assert(self, args, module);
+                    return result;
+                }
+
                 if ident == "cast!" {
                     let result = builder_helper::call_builtin_macro_cast(self, args, module);
                     return result;
                 }
 
+                if ident == "type_of!" {
+                    let result = builder_helper::call_builtin_macro_type_of(self, args, module);
+                    return result;
+                }
+
+                if ident == "is!" {
+                    let result = builder_helper::call_builtin_macro_is(self, args, module);
+                    return result;
+                }
+
+                if ident == "exit!" {
+                    let result = builder_helper::call_builtin_macro_exit(self, args, module);
+                    return result;
+                }
+
+                if ident == "now!" {
+                    let result = builder_helper::call_builtin_macro_now(self, args, module);
+                    return result;
+                }
+
+                if ident == "sleep!" {
+                    let result = builder_helper::call_builtin_macro_sleep(self, args, module);
+                    return result;
+                }
+
+                if ident == "read_line!" {
+                    let result = builder_helper::call_builtin_macro_read_line(self, args, module);
+                    return result;
+                }
+
+                if ident == "rand!" {
+                    let result = builder_helper::call_builtin_macro_rand(self, args, module);
+                    return result;
+                }
+
+                if ident == "rand_seed!" {
+                    let result = builder_helper::call_builtin_macro_rand_seed(self, args, module);
+                    return result;
+                }
+
+                if ident == "asm!" {
+                    let result = builder_helper::call_builtin_macro_asm(self, args, module);
+                    return result;
+                }
+
+                if ident == "volatile_read!" {
+                    let result =
+                        builder_helper::call_builtin_macro_volatile_read(self, args, module);
+                    return result;
+                }
+
+                if ident == "volatile_write!" {
+                    let result =
+                        builder_helper::call_builtin_macro_volatile_write(self, args, module);
+                    return result;
+                }
+
+                let int_macro = match ident.as_str() {
+                    "wrapping_add!" => Some((ArithOp::Add, IntMacroMode::Wrapping)),
+                    "wrapping_sub!" => Some((ArithOp::Sub, IntMacroMode::Wrapping)),
+                    "wrapping_mul!" => Some((ArithOp::Mul, IntMacroMode::Wrapping)),
+                    "saturating_add!" => Some((ArithOp::Add, IntMacroMode::Saturating)),
+                    "saturating_sub!" => Some((ArithOp::Sub, IntMacroMode::Saturating)),
+                    _ => None,
+                };
+                if let Some((op, mode)) = int_macro {
+                    let result = builder_helper::call_builtin_macro_int_arith(
+                        self, ident, op, mode, args, module,
+                    );
+                    return result;
+                }
+
+                let numeric_macro = match ident.as_str() {
+                    "min!" => Some(NumericMacro::Min),
+                    "max!" => Some(NumericMacro::Max),
+                    "abs!" => Some(NumericMacro::Abs),
+                    "clamp!" => Some(NumericMacro::Clamp),
+                    _ => None,
+                };
+                if let Some(op) = numeric_macro {
+                    let result =
+                        builder_helper::call_builtin_macro_numeric(self, ident, op, args, module);
+                    return result;
+                }
+
+                // the calls that `expand` rewrites are gone since plugin::expand_macros
+                if let Some(plugin) = self.macros.get(ident).cloned() {
+                    return plugin.codegen(self, args, module);
+                }
+
+                let extern_sig = module
+                    .get_name()
+                    .to_str()
+                    .ok()
+                    .and_then(|name| self.extern_fns.get(name))
+                    .and_then(|fns| fns.get(ident))
+                    .cloned();
+                if let Some(sig) = extern_sig {
+                    return builder_helper::create_extern_call(self, ident, &sig, args, module);
+                }
+
+                let imported_from = module
+                    .get_name()
+                    .to_str()
+                    .ok()
+                    .and_then(|name| self.imported_names.get(name))
+                    .and_then(|names| names.get(ident))
+                    .cloned();
+                if let Some(module_name) = imported_from {
+                    let result = builder_helper::create_module_access(
+                        self,
+                        &module_name,
+                        ident,
+                        args,
+                        module,
+                    );
+                    return result;
+                }
+
                 let result = builder_helper::create_call_expr(self, ident, args, module);
                 result
             }
@@ -1282,7 +2205,7 @@ impl<'ctx> Compiler<'ctx> {
                     }
                 }
 
-                let lhs_type = self.infer_type(lhs);
+                let lhs_type = self.infer_type(lhs, module);
 
                 let struct_name = match lhs_type {
                     Type::Struct(name) => name,
@@ -1300,23 +2223,24 @@ impl<'ctx> Compiler<'ctx> {
                     builder_helper::create_field_access(self, lhs, index, &struct_name, module);
                 result
             }
-            ast::Expr::Add(lhs, rhs) => {
-                let result = builder_helper::create_add_expr(self, lhs, rhs, module);
+            ast::Expr::Add(lhs, rhs, _) => {
+                let on_stack = self.stack_values.contains(&(expr as *const ast::Expr));
+                let result = builder_helper::create_add_expr(self, lhs, rhs, on_stack, module);
                 result
             }
-            ast::Expr::Mul(lhs, rhs) => {
+            ast::Expr::Mul(lhs, rhs, _) => {
                 let result = builder_helper::create_mul_expr(self, lhs, rhs, module);
                 result
             }
-            ast::Expr::Minus(lhs, rhs) => {
+            ast::Expr::Minus(lhs, rhs, _) => {
                 let result = builder_helper::create_minus_expr(self, lhs, rhs, module);
                 result
             }
-            ast::Expr::Div(lhs, rhs) => {
+            ast::Expr::Div(lhs, rhs, _) => {
                 let result = builder_helper::create_div_expr(self, lhs, rhs, module);
                 result
             }
-            ast::Expr::Mod(lhs, rhs) => {
+            ast::Expr::Mod(lhs, rhs, _) => {
                 let result = builder_helper::create_mod_expr(self, lhs, rhs, module);
                 result
             }
@@ -1330,7 +2254,7 @@ impl<'ctx> Compiler<'ctx> {
                     builder_helper::create_increment_or_decrement(self, expr, UpDown::Down, module);
                 result
             }
-            ast::Expr::Eq(lhs, rhs) => {
+            ast::Expr::Eq(lhs, rhs, _) => {
                 let result = builder_helper::create_eq_or_neq(
                     self,
                     lhs,
@@ -1345,7 +2269,7 @@ impl<'ctx> Compiler<'ctx> {
                 );
                 result
             }
-            ast::Expr::Neq(lhs, rhs) => {
+            ast::Expr::Neq(lhs, rhs, _) => {
                 let result = builder_helper::create_eq_or_neq(
                     self,
                     lhs,
@@ -1360,7 +2284,7 @@ impl<'ctx> Compiler<'ctx> {
                 );
                 result
             }
-            ast::Expr::Gt(lhs, rhs) => {
+            ast::Expr::Gt(lhs, rhs, _) => {
                 let result = builder_helper::create_comparison(
                     self,
                     lhs,
@@ -1375,7 +2299,7 @@ impl<'ctx> Compiler<'ctx> {
                 );
                 result
             }
-            ast::Expr::Lt(lhs, rhs) => {
+            ast::Expr::Lt(lhs, rhs, _) => {
                 let result = builder_helper::create_comparison(
                     self,
                     lhs,
@@ -1390,7 +2314,7 @@ impl<'ctx> Compiler<'ctx> {
                 );
                 result
             }
-            ast::Expr::Ge(lhs, rhs) => {
+            ast::Expr::Ge(lhs, rhs, _) => {
                 let result = builder_helper::create_comparison(
                     self,
                     lhs,
@@ -1405,7 +2329,7 @@ impl<'ctx> Compiler<'ctx> {
                 );
                 result
             }
-            ast::Expr::Le(lhs, rhs) => {
+            ast::Expr::Le(lhs, rhs, _) => {
                 let result = builder_helper::create_comparison(
                     self,
                     lhs,
@@ -1429,7 +2353,7 @@ impl<'ctx> Compiler<'ctx> {
                 let result = builder_helper::create_list(self, elements, module);
                 result
             }
-            ast::Expr::Index(collection_expr, index_expr) => {
+            ast::Expr::Index(collection_expr, index_expr, _) => {
                 let result =
                     builder_helper::create_index(self, collection_expr, index_expr, module);
                 result
@@ -1438,6 +2362,19 @@ impl<'ctx> Compiler<'ctx> {
                 let result = builder_helper::create_range(self, start_expr, end_expr, module);
                 result
             }
+            ast::Expr::In(needle_expr, container_expr, _) => {
+                let on_stack = self
+                    .stack_values
+                    .contains(&(container_expr.as_ref() as *const ast::Expr));
+                let result = builder_helper::create_in_expr(
+                    self,
+                    needle_expr,
+                    container_expr,
+                    on_stack,
+                    module,
+                );
+                result
+            }
             ast::Expr::ModuleAccess(module_name, function_name, args) => {
                 let result = builder_helper::create_module_access(
                     self,
@@ -1459,3 +2396,97 @@ impl<'ctx> Compiler<'ctx> {
         }
     }
 }
+
+// `drivers.uart` lives in <src>/drivers/uart.sprs
+pub fn module_path(source_path: &str, module_name: &str) -> String {
+    format!("{}/{}.sprs", source_path, module_name.replace('.', "/"))
+}
+
+// `<dependency>.<module>` is a module of a `[dependencies]` project: `<module>` in its src_dir.
+pub fn dependency_module_path(
+    dependencies: &HashMap<String, String>,
+    module_name: &str,
+) -> Option<String> {
+    let (dependency, module) = module_name.split_once('.')?;
+    dependencies
+        .get(dependency)
+        .map(|root| module_path(root, module))
+}
+
+// `math`, `file` and `string` are built in unless the project has a module of the same name.
+pub fn is_builtin_package(source_path: &str, module_name: &str) -> bool {
+    math::is_builtin(source_path, module_name)
+        || file::is_builtin(source_path, module_name)
+        || string::is_builtin(source_path, module_name)
+}
+
+// The error for importing `name` from a built-in package that does not have it.
+fn builtin_function_problem(module_name: &str, name: &str) -> Option<String> {
+    match module_name {
+        file::MODULE => file::arity(name)
+            .is_none()
+            .then(|| file::unknown_function_error(name)),
+        string::MODULE => string::arity(name)
+            .is_none()
+            .then(|| string::unknown_function_error(name)),
+        _ => math::arity(name)
+            .is_none()
+            .then(|| math::unknown_function_error(name)),
+    }
+}
+
+// The last `#define Windows`, `#define RiscV`, ... of a module.
+fn target_from_defines(items: &[ast::Item]) -> Option<OS> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            ast::Item::Preprocessor(pre) if pre.starts_with("Windows") => Some(OS::Windows),
+            ast::Item::Preprocessor(pre) if pre.starts_with("Linux") => Some(OS::Linux),
+            ast::Item::Preprocessor(pre) if pre.starts_with("Wasm") => Some(OS::Wasm),
+            ast::Item::Preprocessor(pre) if pre.starts_with("RiscV") => Some(OS::RiscV),
+            ast::Item::Preprocessor(pre) if pre.starts_with("Avr") => Some(OS::Avr),
+            _ => None,
+        })
+        .next_back()
+}
+
+// `#if` symbols of a project: the `-D` defines plus the target symbols (preprocess::target_symbols).
+// Without `--target` the target is the `#define` in main.sprs, which is read once here
+// without the target symbols, so `#if TARGET_...` works in main.sprs too.
+pub fn project_symbols(
+    main_path: &str,
+    defines: &[(String, String)],
+    target: Option<OS>,
+    edition: Edition,
+) -> Symbols {
+    let target = target
+        .or_else(|| {
+            let source = std::fs::read_to_string(main_path).ok()?;
+            let text =
+                preprocess::preprocess(&source, main_path, &mut Symbols::new(defines)).ok()?;
+            target_from_defines(&parse_module(&text, main_path, edition).ok()?)
+        })
+        .unwrap_or(OS::Unknown);
+    let triple = llvm::llvm_executer::target_triple(target);
+
+    let mut symbols = Symbols::new(defines);
+    symbols.predefine(&preprocess::target_symbols(
+        &triple.as_str().to_string_lossy(),
+    ));
+    symbols
+}
+
+// Counts the var declarations in a function body, including nested if/while blocks.
+fn count_var_decls(stmts: &[ast::Stmt]) -> usize {
+    stmts
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            ast::StmtKind::Var(_) => 1,
+            ast::StmtKind::If {
+                then_blk, else_blk, ..
+            } => count_var_decls(then_blk) + else_blk.as_deref().map_or(0, count_var_decls),
+            ast::StmtKind::While { body, .. } => count_var_decls(body),
+            _ => 0,
+        })
+        .sum()
+}
@@ -0,0 +1,238 @@
+use inkwell::{
+    builder::Builder,
+    context::Context,
+    debug_info::{
+        AsDIScope, DICompileUnit, DIFile, DIFlags, DIFlagsConstants, DILocation, DISubprogram,
+        DIType, DWARFEmissionKind, DWARFSourceLanguage, DebugInfoBuilder,
+    },
+    module::{FlagBehavior, Module},
+    values::{FunctionValue, PointerValue},
+};
+
+use crate::front::diagnostics::line_col;
+use crate::llvm::compiler::OS;
+
+// DW_ATE_* encodings of the value fields
+const DW_ATE_SIGNED: u32 = 0x05;
+const DW_ATE_UNSIGNED: u32 = 0x08;
+
+// DWARF for one module of a `sprs build --debug`: a subprogram per function, a line per
+// statement and the locals as `sprs_value { tag, data }`, so gdb and lldb step through the
+// .sprs source.
+pub struct DebugInfo<'ctx> {
+    builder: DebugInfoBuilder<'ctx>,
+    compile_unit: DICompileUnit<'ctx>,
+    source: String,
+    value_type: DIType<'ctx>,
+    function: Option<DISubprogram<'ctx>>, // the function being compiled
+}
+
+impl<'ctx> DebugInfo<'ctx> {
+    pub fn new(
+        context: &'ctx Context,
+        module: &Module<'ctx>,
+        path: &str,
+        source: &str,
+        target_os: OS,
+        is_optimized: bool,
+    ) -> Self {
+        let directory = std::env::current_dir()
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let (builder, compile_unit) = module.create_debug_info_builder(
+            true,
+            // DWARF has no code for Sprs; C makes debuggers show the values without demangling
+            DWARFSourceLanguage::C,
+            path,
+            &directory,
+            "sprs",
+            is_optimized,
+            "",
+            0,
+            "",
+            DWARFEmissionKind::Full,
+            0,
+            false,
+            false,
+            "",
+            "",
+        );
+        let debug_version = context.i32_type().const_int(3, false);
+        module.add_basic_value_flag("Debug Info Version", FlagBehavior::Warning, debug_version);
+        let dwarf_version = context.i32_type().const_int(4, false);
+        module.add_basic_value_flag("Dwarf Version", FlagBehavior::Warning, dwarf_version);
+
+        let value_type = value_type(&builder, compile_unit.get_file(), target_os);
+        DebugInfo {
+            builder,
+            compile_unit,
+            source: source.to_string(),
+            value_type,
+            function: None,
+        }
+    }
+
+    fn file(&self) -> DIFile<'ctx> {
+        self.compile_unit.get_file()
+    }
+
+    fn line_col(&self, offset: usize) -> (u32, u32) {
+        let (line, col) = line_col(&self.source, offset);
+        (line as u32, col as u32)
+    }
+
+    // Attaches a subprogram to `fn_val`; the code before its first statement is on `offset`.
+    pub fn enter_function(
+        &mut self,
+        builder: &Builder<'ctx>,
+        fn_val: FunctionValue<'ctx>,
+        name: &str,
+        offset: usize,
+        param_count: usize,
+        is_optimized: bool,
+    ) {
+        let (line, _) = self.line_col(offset);
+        let params = vec![self.value_type; param_count];
+        let fn_type = self.builder.create_subroutine_type(
+            self.file(),
+            Some(self.value_type),
+            &params,
+            DIFlags::ZERO,
+        );
+        let linkage_name = fn_val.get_name().to_string_lossy().into_owned();
+        let subprogram = self.builder.create_function(
+            self.compile_unit.as_debug_info_scope(),
+            name,
+            Some(&linkage_name),
+            self.file(),
+            line,
+            fn_type,
+            false,
+            true,
+            line,
+            DIFlags::ZERO,
+            is_optimized,
+        );
+        fn_val.set_subprogram(subprogram);
+        self.function = Some(subprogram);
+        self.set_location(builder, offset);
+    }
+
+    // Code built from now on belongs to the statement at `offset`.
+    pub fn set_location(&self, builder: &Builder<'ctx>, offset: usize) {
+        if let Some(location) = self.location(builder, offset) {
+            builder.set_current_debug_location(location);
+        }
+    }
+
+    fn location(&self, builder: &Builder<'ctx>, offset: usize) -> Option<DILocation<'ctx>> {
+        let function = self.function?;
+        let (line, col) = self.line_col(offset);
+        let context = builder.get_insert_block()?.get_context();
+        Some(self.builder.create_debug_location(
+            context,
+            line,
+            col,
+            function.as_debug_info_scope(),
+            None,
+        ))
+    }
+
+    // Names the local `name` stored at `ptr` for the debugger.
+    pub fn declare_variable(
+        &self,
+        builder: &Builder<'ctx>,
+        ptr: PointerValue<'ctx>,
+        name: &str,
+        offset: usize,
+    ) {
+        let (Some(function), Some(location), Some(block)) = (
+            self.function,
+            self.location(builder, offset),
+            builder.get_insert_block(),
+        ) else {
+            return;
+        };
+        let (line, _) = self.line_col(offset);
+        let variable = self.builder.create_auto_variable(
+            function.as_debug_info_scope(),
+            name,
+            self.file(),
+            line,
+            self.value_type,
+            true,
+            DIFlags::ZERO,
+            0,
+        );
+        self.builder
+            .insert_declare_at_end(ptr, Some(variable), None, location, block);
+    }
+
+    // Code built after this, like the C `main`, has no source location.
+    pub fn leave_function(&mut self, builder: &Builder<'ctx>) {
+        self.function = None;
+        builder.unset_current_debug_location();
+    }
+
+    pub fn finalize(&self) {
+        self.builder.finalize();
+    }
+}
+
+// `struct sprs_value { tag; data; }` with the field widths of the target, see Compiler::tag_type.
+fn value_type<'ctx>(
+    builder: &DebugInfoBuilder<'ctx>,
+    file: DIFile<'ctx>,
+    target_os: OS,
+) -> DIType<'ctx> {
+    let (tag_bits, data_bits, data_offset) = if target_os == OS::Avr {
+        (8, 32, 8)
+    } else {
+        (32, 64, 64)
+    };
+    let tag_type = builder
+        .create_basic_type("sprs_tag", tag_bits, DW_ATE_UNSIGNED, DIFlags::ZERO)
+        .unwrap();
+    let data_type = builder
+        .create_basic_type("sprs_data", data_bits, DW_ATE_SIGNED, DIFlags::ZERO)
+        .unwrap();
+    let scope = file.as_debug_info_scope();
+    let tag = builder.create_member_type(
+        scope,
+        "tag",
+        file,
+        0,
+        tag_bits,
+        0,
+        0,
+        DIFlags::ZERO,
+        tag_type.as_type(),
+    );
+    let data = builder.create_member_type(
+        scope,
+        "data",
+        file,
+        0,
+        data_bits,
+        0,
+        data_offset,
+        DIFlags::ZERO,
+        data_type.as_type(),
+    );
+    builder
+        .create_struct_type(
+            scope,
+            "sprs_value",
+            file,
+            0,
+            data_offset + data_bits,
+            0,
+            DIFlags::ZERO,
+            None,
+            &[tag.as_type(), data.as_type()],
+            0,
+            None,
+            "sprs_value",
+        )
+        .as_type()
+}
@@ -0,0 +1,261 @@
+use crate::front::ast::Item;
+use crate::front::cst::{ItemNode, SyntaxTree, TokenStream, Trivia};
+use crate::front::lexer::Token;
+use crate::llvm::resolve::SourceFile;
+
+// `sprs doc`: a reference page per package, from the `##` doc comments of its items. The `##`
+// lines directly above `pkg` describe the package, the ones above a `fn`, `extern fn`, `struct`
+// or `enum` that item. Doc comments are Markdown; a fenced ``` block in them is an example and
+// is shown as code.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DocFormat {
+    Html,
+    Markdown,
+}
+
+impl DocFormat {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "html" => Ok(DocFormat::Html),
+            "markdown" | "md" => Ok(DocFormat::Markdown),
+            _ => Err(format!(
+                "Unknown doc format: {} (expected html or markdown)",
+                name
+            )),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Html => "html",
+            DocFormat::Markdown => "md",
+        }
+    }
+}
+
+pub struct PackageDoc {
+    pub module: String,
+    pub doc: String, // of the `pkg` line
+    pub items: Vec<ItemDoc>,
+}
+
+pub struct ItemDoc {
+    pub kind: &'static str, // fn, extern fn, struct or enum
+    pub name: String,
+    pub signature: String,
+    pub doc: String,
+}
+
+// The documented items of `file`: the `pub` ones, or all of them with `private`.
+pub fn package_doc(file: &SourceFile, private: bool) -> Result<PackageDoc, String> {
+    let tree = SyntaxTree::parse_preprocessed(&file.active, &file.active, &file.path, file.edition)
+        .map_err(|_| format!("{} has syntax errors, fix them first", file.path))?;
+
+    let mut package = PackageDoc {
+        module: file.module.clone(),
+        doc: String::new(),
+        items: Vec::new(),
+    };
+    for node in &tree.items {
+        let doc = doc_text(&tree.tokens, &node.leading_comments);
+        let (kind, name, is_public) = match &node.item {
+            Item::Package(_) => {
+                package.doc = doc;
+                continue;
+            }
+            Item::FunctionItem(f) => ("fn", &f.ident, f.is_public),
+            Item::ExternFnItem(f) => ("extern fn", &f.ident, true),
+            Item::StructItem(s) => ("struct", &s.ident, s.is_public),
+            Item::EnumItem(e) => ("enum", &e.ident, e.is_public),
+            _ => continue,
+        };
+        if !is_public && !private {
+            continue;
+        }
+        package.items.push(ItemDoc {
+            kind,
+            name: name.clone(),
+            signature: signature(&tree.tokens, node),
+            doc,
+        });
+    }
+    Ok(package)
+}
+
+// The run of `##` lines at the end of `comments`, without the `##` and one space after it.
+fn doc_text(tokens: &TokenStream<'_>, comments: &[Trivia]) -> String {
+    let mut lines: Vec<&str> = comments
+        .iter()
+        .rev()
+        .map(|c| tokens.text(c.span))
+        .take_while(|text| text.starts_with("##"))
+        .map(|text| {
+            let text = &text[2..];
+            text.strip_prefix(' ').unwrap_or(text).trim_end()
+        })
+        .collect();
+    lines.reverse();
+    lines.join("\n")
+}
+
+// A function up to its body, on one line; the other items as written.
+fn signature(tokens: &TokenStream<'_>, node: &ItemNode) -> String {
+    let text = match &node.item {
+        Item::FunctionItem(f) => {
+            let body = tokens
+                .tokens_in(node.span)
+                .iter()
+                .find(|t| t.token == Token::LBrace && t.span.start >= f.span.end)
+                .map_or(node.span.end, |t| t.span.start);
+            &tokens.source[node.span.start..body]
+        }
+        Item::ExternFnItem(_) => tokens.text(node.span).trim_end_matches(';'),
+        _ => return tokens.text(node.span).to_string(),
+    };
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn render_page(package: &PackageDoc, format: DocFormat) -> String {
+    match format {
+        DocFormat::Markdown => {
+            let mut out = format!("# Package `{}`\n\n", package.module);
+            if !package.doc.is_empty() {
+                out.push_str(&format!("{}\n\n", package.doc));
+            }
+            for item in &package.items {
+                out.push_str(&format!(
+                    "## {} `{}`\n\n```sprs\n{}\n```\n\n",
+                    item.kind, item.name, item.signature
+                ));
+                if !item.doc.is_empty() {
+                    out.push_str(&format!("{}\n\n", item.doc));
+                }
+            }
+            out
+        }
+        DocFormat::Html => {
+            let mut body = format!(
+                "<h1>Package <code>{}</code></h1>\n{}",
+                escape(&package.module),
+                markdown_to_html(&package.doc)
+            );
+            for item in &package.items {
+                body.push_str(&format!(
+                    "<h2 id=\"{}\">{} <code>{}</code></h2>\n<pre class=\"signature\"><code>{}</code></pre>\n{}",
+                    escape(&item.name),
+                    item.kind,
+                    escape(&item.name),
+                    escape(&item.signature),
+                    markdown_to_html(&item.doc)
+                ));
+            }
+            html_page(&package.module, &body)
+        }
+    }
+}
+
+// The list of packages, each with the first line of its documentation.
+pub fn render_index(packages: &[PackageDoc], format: DocFormat) -> String {
+    let summary = |package: &PackageDoc| package.doc.lines().next().unwrap_or("").to_string();
+    match format {
+        DocFormat::Markdown => {
+            let mut out = "# Packages\n\n".to_string();
+            for package in packages {
+                out.push_str(&format!(
+                    "- [`{}`]({}.md) {}\n",
+                    package.module,
+                    package.module,
+                    summary(package)
+                ));
+            }
+            out
+        }
+        DocFormat::Html => {
+            let mut body = "<h1>Packages</h1>\n<ul>\n".to_string();
+            for package in packages {
+                body.push_str(&format!(
+                    "<li><a href=\"{}.html\"><code>{}</code></a> {}</li>\n",
+                    escape(&package.module),
+                    escape(&package.module),
+                    inline_html(&summary(package))
+                ));
+            }
+            body.push_str("</ul>\n");
+            html_page("Packages", &body)
+        }
+    }
+}
+
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\nbody {{ font-family: sans-serif; max-width: 50em; margin: 2em auto; }}\npre {{ background: #f4f4f4; padding: 0.5em; overflow-x: auto; }}\n</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape(title),
+        body
+    )
+}
+
+// The Markdown of doc comments: paragraphs, fenced code blocks and `inline code`.
+fn markdown_to_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut code: Option<Vec<&str>> = None;
+
+    let flush = |paragraph: &mut Vec<&str>, out: &mut String| {
+        if !paragraph.is_empty() {
+            out.push_str(&format!("<p>{}</p>\n", inline_html(&paragraph.join(" "))));
+            paragraph.clear();
+        }
+    };
+    for line in text.lines() {
+        let fence = line.trim_start().starts_with("```");
+        if let Some(block) = &mut code {
+            if fence {
+                out.push_str(&format!(
+                    "<pre><code>{}</code></pre>\n",
+                    escape(&block.join("\n"))
+                ));
+                code = None;
+            } else {
+                block.push(line);
+            }
+        } else if fence {
+            flush(&mut paragraph, &mut out);
+            code = Some(Vec::new());
+        } else if line.trim().is_empty() {
+            flush(&mut paragraph, &mut out);
+        } else {
+            paragraph.push(line.trim());
+        }
+    }
+    if let Some(block) = code {
+        // a block left open runs to the end of the comment
+        out.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            escape(&block.join("\n"))
+        ));
+    }
+    flush(&mut paragraph, &mut out);
+    out
+}
+
+// Every other run between backticks is code.
+fn inline_html(text: &str) -> String {
+    text.split('`')
+        .enumerate()
+        .map(|(idx, part)| {
+            if idx % 2 == 1 {
+                format!("<code>{}</code>", escape(part))
+            } else {
+                escape(part)
+            }
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -1,78 +1,128 @@
-use crate::front::lexer::Token;
+use crate::front::ast::Span;
+use crate::front::cst::TokenStream;
+use crate::front::diagnostics::{Diagnostic, line_col};
+use crate::front::error_codes;
+use crate::front::lexer::{Edition, Token, describe_terminal, is_type_terminal};
 use lalrpop_util::ParseError;
 
-pub fn format_parse_error(
-    source: &str,
+// Turns a lalrpop error into "expected X, found Y" with the offending token underlined.
+// `source` is the module text, used to place the `;` of a missing-semicolon fix.
+pub fn parse_error_diagnostic(
     file_path: &str,
+    source: &str,
     error: ParseError<usize, Token, String>,
-) -> String {
-    match error {
-        ParseError::InvalidToken { location } => {
-            let (line, col) = get_line_col(source, location);
-            let snippet = get_snippet(source, line);
-            let pointer = "".repeat(col.saturating_add(1)) + "^";
-            format!(
-                "Error in {}:{}:{}: InvalidToken\n{}\n{}",
-                file_path, line, col, snippet, pointer
-            )
-        }
+) -> Diagnostic {
+    let semi_fix = match &error {
         ParseError::UnrecognizedToken {
-            token: (start, token, _end),
+            token: (start, _, _),
             expected,
-        } => {
-            let (line, col) = get_line_col(source, start);
-            let snippet = get_snippet(source, line);
-            let pointer = "".repeat(col.saturating_add(1)) + "^";
-            format!(
-                "Error in {}:{}:{}: UnrecognizedToken '{:?}'\n\n{}\n{}\nExpected: {:?}",
-                file_path, line, col, token, snippet, pointer, expected
-            )
+        } => missing_semi_fix(source, *start, expected),
+        ParseError::UnrecognizedEof { location, expected } => {
+            missing_semi_fix(source, *location, expected)
         }
+        _ => None,
+    };
+
+    let mut diagnostic = match error {
+        ParseError::InvalidToken { location } => Diagnostic::error("unrecognized character")
+            .with_primary(
+                Span {
+                    start: location,
+                    end: location + 1,
+                },
+                "not valid here",
+            ),
+        ParseError::UnrecognizedToken {
+            token: (start, token, end),
+            expected,
+        } => Diagnostic::error(expected_found(&expected, &token.describe()))
+            .with_primary(Span { start, end }, unexpected_label(&token, &expected)),
         ParseError::ExtraToken {
-            token: (start, token, _end),
-        } => {
-            let (line, col) = get_line_col(source, start);
-            let snippet = get_snippet(source, line);
-            let pointer = "".repeat(col.saturating_add(1)) + "^";
-            format!(
-                "Error in {}:{}:{}: ExtraToken '{:?}'\n\n{}\n{}",
-                file_path, line, col, token, snippet, pointer
-            )
-        }
-        ParseError::User { error } => {
-            format!("Error in {}: User error: {}", file_path, error)
-        }
+            token: (start, token, end),
+        } => Diagnostic::error(format!(
+            "unexpected {} after the last item",
+            token.describe()
+        ))
+        .with_primary(Span { start, end }, "expected an item here"),
+        ParseError::User { error } => Diagnostic::error(error),
         ParseError::UnrecognizedEof { location, expected } => {
-            let (line, col) = get_line_col(source, location);
-            format!(
-                "Error in {}:{}:{}: UnrecognizedEOF\nExpected: {:?}",
-                file_path, line, col, expected
+            Diagnostic::error(expected_found(&expected, "end of file")).with_primary(
+                Span {
+                    start: location,
+                    end: location,
+                },
+                "file ends here",
             )
         }
+    };
+    if let Some(at) = semi_fix {
+        diagnostic = diagnostic.with_fix(Span { start: at, end: at }, ";", "add `;` here");
     }
+    diagnostic
+        .with_code(error_codes::SYNTAX_ERROR)
+        .in_file(file_path)
 }
 
-fn get_line_col(source: &str, offset: usize) -> (usize, usize) {
-    let mut line = 1;
-    let mut col = 1;
-    for (i, c) in source.char_indices() {
-        if i == offset {
-            break;
-        }
-        if c == '\n' {
-            line += 1;
-            col = 1;
+// A keyword from a later edition where a name was expected is most likely older code.
+fn unexpected_label(token: &Token, expected: &[String]) -> String {
+    let describe = token.describe();
+    let word = describe.trim_matches('`');
+    let older = Edition::keyword_since(word).and_then(|since| {
+        Edition::ALL
+            .iter()
+            .rev()
+            .find(|e| **e < since)
+            .map(|e| (since, *e))
+    });
+    match older {
+        Some((since, older)) if expected.iter().any(|e| e == "IDENT") => format!(
+            "`{}` is a keyword since edition {}, `edition = \"{}\"` in sprs.toml makes it a name again",
+            word,
+            since.name(),
+            older.name()
+        ),
+        _ => format!("unexpected {}", describe),
+    }
+}
+
+// Offset after the previous token when a `;` is expected and the parser stopped on the
+// next line (or at the end of the file), the usual shape of a forgotten semicolon.
+fn missing_semi_fix(source: &str, found_at: usize, expected: &[String]) -> Option<usize> {
+    if !expected.iter().any(|e| e.trim_matches('"') == "Semi") {
+        return None;
+    }
+    // the `;` goes after the previous token, not after a trailing comment
+    let prev_end = TokenStream::lex(source).token_before(found_at)?.span.end;
+    let on_next_line = found_at >= source.len() || source[prev_end..found_at].contains('\n');
+    on_next_line.then_some(prev_end)
+}
+
+// "expected `;`, found ...", "expected `)` or `,`, found ...",
+// "expected one of `(`, `[`, an identifier, or a type name, found ..."
+fn expected_found(expected: &[String], found: &str) -> String {
+    let mut names: Vec<String> = Vec::new();
+    for terminal in expected {
+        let name = if is_type_terminal(terminal) {
+            "a type name".to_string()
         } else {
-            col += 1;
+            describe_terminal(terminal)
+        };
+        if !names.contains(&name) {
+            names.push(name);
         }
     }
-    (line, col)
+
+    let expected = match names.as_slice() {
+        [] => return format!("unexpected {}", found),
+        [one] => one.clone(),
+        [a, b] => format!("{} or {}", a, b),
+        [rest @ .., last] => format!("one of {}, or {}", rest.join(", "), last),
+    };
+    format!("expected {}, found {}", expected, found)
 }
 
-fn get_snippet(source: &str, line_number: usize) -> String {
-    source
-        .lines()
-        .nth(line_number - 1)
-        .unwrap_or("")
-        .to_string()
+// "path:line:col" for a byte offset, used in runtime panic messages.
+pub fn format_location(source: &str, file_path: &str, offset: usize) -> String {
+    let (line, col) = line_col(source, offset);
+    format!("{}:{}:{}", file_path, line, col)
 }
@@ -0,0 +1,109 @@
+use crate::front::ast;
+use crate::front::ast::visit::{self, Visitor};
+use std::collections::HashSet;
+
+// Escape analysis for string concatenation and list literals.
+// A `a + b` whose result is only read inside the same expression (printed, compared,
+// or concatenated again) never outlives the statement, so its bytes can live in a
+// fixed stack buffer instead of going through `__malloc`. Likewise the list of `x in [a, b]`
+// is only searched, so its elements can go in a stack array instead of a runtime list.
+
+// Returns the `Add` expressions in `func` whose string result does not escape, and the list
+// literals that are only searched with `in`.
+pub fn stack_allocations(func: &ast::Function) -> HashSet<*const ast::Expr> {
+    let mut analysis = Analysis {
+        consumed: false,
+        out: HashSet::new(),
+    };
+    analysis.visit_block(&func.blk);
+    analysis.out
+}
+
+struct Analysis {
+    // the parent of the next expression reads its value and keeps nothing pointing into it
+    consumed: bool,
+    out: HashSet<*const ast::Expr>,
+}
+
+impl Analysis {
+    fn read(&mut self, expr: &ast::Expr, consumed: bool) {
+        self.consumed = consumed;
+        self.visit_expr(expr);
+    }
+}
+
+impl<'a> Visitor<'a> for Analysis {
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        match &stmt.kind {
+            // the value of an expression statement is thrown away
+            ast::StmtKind::Expr(expr) => self.read(expr, true),
+            ast::StmtKind::If {
+                cond,
+                then_blk,
+                else_blk,
+            } => {
+                self.read(cond, true);
+                self.visit_block(then_blk);
+                if let Some(else_blk) = else_blk {
+                    self.visit_block(else_blk);
+                }
+            }
+            ast::StmtKind::While { cond, body } => {
+                self.read(cond, true);
+                self.visit_block(body);
+            }
+            // declarations, assignments and returns keep the value
+            _ => visit::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        // the children are not consumed unless stated below
+        let consumed = std::mem::take(&mut self.consumed);
+        match expr {
+            ast::Expr::Add(l, r, _) => {
+                if consumed {
+                    self.out.insert(expr);
+                }
+                // concatenation copies both operands
+                self.read(l, true);
+                self.read(r, true);
+            }
+            ast::Expr::Mul(l, r, _)
+            | ast::Expr::Eq(l, r, _)
+            | ast::Expr::Neq(l, r, _)
+            | ast::Expr::Lt(l, r, _)
+            | ast::Expr::Gt(l, r, _)
+            | ast::Expr::Le(l, r, _)
+            | ast::Expr::Ge(l, r, _) => {
+                self.read(l, true);
+                self.read(r, true);
+            }
+            ast::Expr::In(needle, container, _) => {
+                self.read(needle, true);
+                match container.as_ref() {
+                    // the elements are copied into the array and only compared
+                    ast::Expr::List(elements) => {
+                        self.out.insert(container.as_ref());
+                        for element in elements {
+                            self.read(element, true);
+                        }
+                    }
+                    _ => self.read(container, true),
+                }
+            }
+            ast::Expr::Call(ident, args, _, _) => {
+                let consumed = ident == "println!";
+                for arg in args {
+                    self.read(arg, consumed);
+                }
+            }
+            ast::Expr::If(cond, then_expr, else_expr) => {
+                self.read(cond, true);
+                self.read(then_expr, false);
+                self.read(else_expr, false);
+            }
+            _ => visit::walk_expr(self, expr),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+use crate::llvm::compiler;
+
+// The built-in `file` package: `import file;` then `var f = file.open("log.txt", "a");`.
+// Like `math` it has no source file; the calls compile to the `__file_*` functions of the
+// runtime. `file.open` returns an Int handle for the other functions, and any I/O error
+// panics for now. A project with its own src/file.sprs imports that module instead.
+
+pub const MODULE: &str = "file";
+
+// name, number of arguments
+pub const FUNCTIONS: &[(&str, usize)] = &[("open", 2), ("read_all", 1), ("write", 2), ("close", 1)];
+
+// Whether `import <module>` in a project under `src_path` refers to the built-in package.
+pub fn is_builtin(src_path: &str, module: &str) -> bool {
+    module == MODULE && !Path::new(&compiler::module_path(src_path, module)).exists()
+}
+
+pub fn arity(function: &str) -> Option<usize> {
+    FUNCTIONS
+        .iter()
+        .find(|(name, _)| *name == function)
+        .map(|(_, arity)| *arity)
+}
+
+pub fn unknown_function_error(function: &str) -> String {
+    let names: Vec<&str> = FUNCTIONS.iter().map(|(name, _)| *name).collect();
+    format!(
+        "module 'file' has no function '{}' (available: {})",
+        function,
+        names.join(", ")
+    )
+}
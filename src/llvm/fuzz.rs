@@ -0,0 +1,459 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use inkwell::context::Context;
+
+use crate::command_helper::BuildOptions;
+use crate::front::cst::{SyntaxTree, TokenStream};
+use crate::front::lexer::Edition;
+use crate::front::preprocess::Symbols;
+use crate::llvm::compiler::Compiler;
+
+// `sprs fuzz-grammar`: feeds generated programs through the preprocessor, lexer, parser and
+// code generator and collects the inputs that make the compiler panic. A program that is
+// rejected with a diagnostic is fine; only a panic is a finding. Each finding is shrunk to
+// a small reproducer before it is reported.
+// Three kinds of input: programs built from the grammar (reach codegen), those programs with
+// a few tokens dropped, doubled or swapped (reach error recovery), and random token soup.
+
+pub struct Crash {
+    pub message: String,
+    pub source: String, // minimized reproducer
+    pub seed: u64,      // `--seed` whose first case is the original input
+}
+
+pub fn fuzz(iterations: usize, seed: u64, work_dir: &Path) -> Result<Vec<Crash>, String> {
+    std::fs::create_dir_all(work_dir)
+        .map_err(|e| format!("Failed to create {}: {}", work_dir.display(), e))?;
+
+    // the default hook would print every caught panic
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut crashes: Vec<Crash> = Vec::new();
+    for idx in 0..iterations {
+        let case_seed = seed.wrapping_add(idx as u64);
+        let source = generate(&mut Rng::new(case_seed));
+        let Some(message) = run_case(&source, work_dir) else {
+            continue;
+        };
+        if crashes.iter().any(|c| c.message == message) {
+            continue;
+        }
+        let source = minimize(&source, &message, work_dir);
+        crashes.push(Crash {
+            message,
+            source,
+            seed: case_seed,
+        });
+    }
+
+    panic::set_hook(hook);
+    Ok(crashes)
+}
+
+// The panic message, or None when the compiler got through (with or without errors).
+fn run_case(source: &str, work_dir: &Path) -> Option<String> {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| compile_case(source, work_dir)));
+    let payload = result.err()?;
+    Some(match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(message) => message.clone(),
+            None => "panic without a message".to_string(),
+        },
+    })
+}
+
+fn compile_case(source: &str, work_dir: &Path) {
+    let tokens = TokenStream::lex(source);
+    assert!(
+        tokens.to_source() == source,
+        "lossless token stream does not reproduce the source"
+    );
+
+    let path = work_dir.join("main.sprs");
+    let path_str = path.to_string_lossy().to_string();
+    let mut symbols = Symbols::new(&[]);
+    let Ok(tree) = SyntaxTree::parse(source, &path_str, &mut symbols, Edition::default()) else {
+        return;
+    };
+    // a formatter moves items with their comments, so no two items may claim the same text
+    let mut end = 0;
+    for node in &tree.items {
+        let span = node.full_span();
+        assert!(
+            end <= span.start && span.end <= source.len(),
+            "items overlap in the lossless syntax tree"
+        );
+        end = span.end;
+    }
+
+    if std::fs::write(&path, source).is_err() {
+        return;
+    }
+    let context = Context::create();
+    let builder = context.create_builder();
+    let mut compiler = Compiler::new(
+        &context,
+        builder,
+        work_dir.to_string_lossy().to_string(),
+        BuildOptions::default(),
+    );
+    let _ = compiler.load_and_compile_module("main", None);
+}
+
+// Drops chunks of tokens (or of lines, when the input only panics with its original
+// layout) for as long as the same panic still happens.
+fn minimize(source: &str, message: &str, work_dir: &Path) -> String {
+    let stream = TokenStream::lex(source);
+    let tokens: Vec<String> = stream
+        .tokens
+        .iter()
+        .map(|t| stream.text(t.span).to_string())
+        .collect();
+    let lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+    let reproduces = |text: &str| run_case(text, work_dir).as_deref() == Some(message);
+    if reproduces(&tokens.join(" ")) {
+        shrink(tokens, " ", &reproduces)
+    } else {
+        shrink(lines, "\n", &reproduces)
+    }
+}
+
+fn shrink(mut units: Vec<String>, sep: &str, reproduces: &dyn Fn(&str) -> bool) -> String {
+    const MAX_ATTEMPTS: usize = 2000;
+    let mut attempts = 0;
+    let mut chunk = units.len().div_ceil(2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < units.len() && attempts < MAX_ATTEMPTS {
+            let end = (start + chunk).min(units.len());
+            let candidate: Vec<String> = units[..start]
+                .iter()
+                .chain(&units[end..])
+                .cloned()
+                .collect();
+            attempts += 1;
+            if reproduces(&candidate.join(sep)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if attempts >= MAX_ATTEMPTS || (chunk == 1 && !removed) {
+            break;
+        }
+        if !removed {
+            chunk = chunk.div_ceil(2);
+        }
+    }
+    units.join(sep)
+}
+
+// xorshift64*, so a `--seed` gives the same cases on every machine.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+fn generate(rng: &mut Rng) -> String {
+    match rng.below(10) {
+        0..=5 => Program::new(rng).generate(),
+        6..=8 => mutate(&Program::new(rng).generate(), rng),
+        _ => token_soup(rng),
+    }
+}
+
+const SOUP: &[&str] = &[
+    "{", "}", "[", "]", "(", ")", "+", "++", "*", "-", "--", "/", "%", "=", "==", "!=", "<", ">",
+    ">>", "<=", ">=", ".", "..", ";", ",", "\"s\"", "'c'", "if", "then", "else", "while", "in",
+    "x", "main", "println!", "cast!", "clone!", "0", "1", "2.5", "true", "false", "fn", "return",
+    "#define", "pkg", "import", "as", "var", "pub", "enum", "struct", "int", "str", "i8", "u8",
+    "fp32", "#if", "#else", "#endif", "\n",
+];
+
+fn token_soup(rng: &mut Rng) -> String {
+    let len = 1 + rng.below(60);
+    (0..len)
+        .map(|_| rng.pick(SOUP))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Drops, doubles or swaps a few tokens of a well-formed program.
+fn mutate(source: &str, rng: &mut Rng) -> String {
+    let stream = TokenStream::lex(source);
+    let mut tokens: Vec<String> = stream
+        .tokens
+        .iter()
+        .map(|t| stream.text(t.span).to_string())
+        .collect();
+    for _ in 0..1 + rng.below(3) {
+        if tokens.is_empty() {
+            break;
+        }
+        let idx = rng.below(tokens.len());
+        match rng.below(3) {
+            0 => {
+                tokens.remove(idx);
+            }
+            1 => tokens.insert(idx, tokens[idx].clone()),
+            _ => {
+                let other = rng.below(tokens.len());
+                tokens.swap(idx, other);
+            }
+        }
+    }
+    tokens.join(" ")
+}
+
+// A random program that follows the grammar. Names are mostly ones that exist, so the code
+// generator gets past name lookup and into the interesting paths.
+struct Program<'r> {
+    rng: &'r mut Rng,
+    functions: Vec<(String, usize)>,     // name, parameter count
+    structs: Vec<(String, Vec<String>)>, // name, fields
+    enums: Vec<(String, Vec<String>)>,   // name, variants
+    locals: Vec<String>,
+    depth: usize,
+}
+
+const TYPES: &[&str] = &[
+    "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "fp32", "fp64", "Str", "Int", "Bool",
+    "Char",
+];
+
+impl<'r> Program<'r> {
+    fn new(rng: &'r mut Rng) -> Self {
+        Program {
+            rng,
+            functions: Vec::new(),
+            structs: Vec::new(),
+            enums: Vec::new(),
+            locals: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    fn generate(mut self) -> String {
+        let mut out = String::new();
+        if self.rng.chance(30) {
+            out.push_str("#define FUZZ 1\n");
+        }
+        for idx in 0..self.rng.below(3) {
+            let fields: Vec<String> = (0..self.rng.below(4)).map(|f| format!("f{}", f)).collect();
+            let decls: Vec<String> = fields
+                .iter()
+                .map(|f| match self.rng.below(3) {
+                    0 => f.clone(),
+                    1 => format!("{} >> {}", f, self.rng.pick(TYPES)),
+                    _ => format!("{} = {}", f, self.rng.below(100)),
+                })
+                .collect();
+            out.push_str(&format!("struct S{} {{ {} }}\n", idx, decls.join(", ")));
+            self.structs.push((format!("S{}", idx), fields));
+        }
+        for idx in 0..self.rng.below(2) {
+            let variants: Vec<String> = (0..1 + self.rng.below(3))
+                .map(|v| format!("V{}", v))
+                .collect();
+            out.push_str(&format!("enum E{} {{ {} }}\n", idx, variants.join(", ")));
+            self.enums.push((format!("E{}", idx), variants));
+        }
+        let count = self.rng.below(4);
+        for idx in 0..count {
+            self.functions
+                .push((format!("f{}", idx), self.rng.below(3)));
+        }
+        for idx in 0..count {
+            let (name, params) = self.functions[idx].clone();
+            out.push_str(&self.function(&name, params));
+        }
+        out.push_str(&self.function("main", 0));
+        out
+    }
+
+    fn function(&mut self, name: &str, params: usize) -> String {
+        self.locals = (0..params).map(|p| format!("p{}", p)).collect();
+        let ret = if name != "main" && self.rng.chance(30) {
+            format!(" >> {}", self.rng.pick(TYPES))
+        } else {
+            String::new()
+        };
+        let body = self.block(1);
+        let function = format!(
+            "fn {}({}){} {}\n",
+            name,
+            self.locals[..params].join(", "),
+            ret,
+            body
+        );
+        if self.rng.chance(10) {
+            format!("#if FUZZ\n{}#else\nfn {}() {{}}\n#endif\n", function, name)
+        } else {
+            function
+        }
+    }
+
+    fn block(&mut self, indent: usize) -> String {
+        let scope = self.locals.len();
+        let stmts: Vec<String> = (0..self.rng.below(5))
+            .map(|_| format!("{}{}\n", "    ".repeat(indent), self.stmt(indent)))
+            .collect();
+        self.locals.truncate(scope);
+        format!("{{\n{}{}}}", stmts.concat(), "    ".repeat(indent - 1))
+    }
+
+    fn stmt(&mut self, indent: usize) -> String {
+        let nested = indent < 4;
+        match self.rng.below(10) {
+            0..=2 => {
+                let name = format!("v{}", self.locals.len());
+                let init = self.expr();
+                self.locals.push(name.clone());
+                format!("var {} = {};", name, init)
+            }
+            3 if !self.locals.is_empty() => format!("{} = {};", self.local(), self.expr()),
+            4 if nested => format!(
+                "if {} then {}{}",
+                self.expr(),
+                self.block(indent + 1),
+                if self.rng.chance(50) {
+                    format!(" else {}", self.block(indent + 1))
+                } else {
+                    String::new()
+                }
+            ),
+            5 if nested => format!("while {} {}", self.expr(), self.block(indent + 1)),
+            6 => match self.rng.chance(50) {
+                true => format!("return {};", self.expr()),
+                false => "return;".to_string(),
+            },
+            _ => format!("{};", self.expr()),
+        }
+    }
+
+    fn local(&mut self) -> String {
+        if self.locals.is_empty() || self.rng.chance(5) {
+            return "undefined".to_string();
+        }
+        self.locals[self.rng.below(self.locals.len())].clone()
+    }
+
+    fn expr(&mut self) -> String {
+        self.depth += 1;
+        let leaf = self.depth > 3 || self.rng.chance(35);
+        let expr = if leaf { self.atom() } else { self.compound() };
+        self.depth -= 1;
+        expr
+    }
+
+    fn atom(&mut self) -> String {
+        match self.rng.below(9) {
+            0 | 1 => self.local(),
+            2 => self.rng.below(300).to_string(),
+            3 => format!("{}.{}", self.rng.below(10), self.rng.below(100)),
+            4 => format!("\"{}\"", self.rng.pick(&["", "a", "hello", "\\\\n"])),
+            5 => self
+                .rng
+                .pick(&["true", "false", "'a'", "'\\n'"])
+                .to_string(),
+            6 => "()".to_string(),
+            7 if !self.enums.is_empty() => {
+                let (name, variants) = &self.enums[self.rng.below(self.enums.len())];
+                format!("{}.{}", name, variants[self.rng.below(variants.len())])
+            }
+            _ => "[]".to_string(),
+        }
+    }
+
+    fn compound(&mut self) -> String {
+        const BINARY: &[&str] = &[
+            "+", "-", "*", "/", "%", "==", "!=", "<", ">", "<=", ">=", "in", "..",
+        ];
+        match self.rng.below(12) {
+            0..=2 => {
+                let op = self.rng.pick(BINARY);
+                format!("{} {} {}", self.expr(), op, self.expr())
+            }
+            3 => format!("({})", self.expr()),
+            4 => {
+                let items: Vec<String> = (0..self.rng.below(4)).map(|_| self.expr()).collect();
+                format!("[{}]", items.join(", "))
+            }
+            5 => format!("{}[{}]", self.local(), self.expr()),
+            6 => format!("{}{}", self.local(), self.rng.pick(&["++", "--"])),
+            7 if !self.functions.is_empty() => {
+                let (name, params) = self.functions[self.rng.below(self.functions.len())].clone();
+                let args: Vec<String> = (0..params).map(|_| self.expr()).collect();
+                format!("{}({})", name, args.join(", "))
+            }
+            8 if !self.structs.is_empty() => {
+                let (name, fields) = self.structs[self.rng.below(self.structs.len())].clone();
+                let mut inits = Vec::new();
+                for field in fields {
+                    if self.rng.chance(70) {
+                        inits.push(format!("{} = {}", field, self.expr()));
+                    }
+                }
+                format!("{} {{ {} }}", name, inits.join(", "))
+            }
+            9 if !self.structs.is_empty() => {
+                let (_, fields) = &self.structs[self.rng.below(self.structs.len())];
+                let field = fields.first().cloned().unwrap_or_else(|| "f0".to_string());
+                format!("{}.{}", self.local(), field)
+            }
+            _ => self.builtin_macro(),
+        }
+    }
+
+    fn builtin_macro(&mut self) -> String {
+        match self.rng.below(7) {
+            0 => format!("println!({})", self.expr()),
+            1 => format!("clone!({})", self.expr()),
+            2 => format!("cast!({}, {})", self.expr(), self.rng.pick(TYPES)),
+            3 => format!("list_push!({}, {})", self.local(), self.expr()),
+            4 => format!(
+                "{}!({}, {}, {})",
+                self.rng.pick(&[
+                    "wrapping_add",
+                    "wrapping_sub",
+                    "wrapping_mul",
+                    "saturating_add",
+                    "saturating_sub"
+                ]),
+                self.expr(),
+                self.expr(),
+                self.rng.pick(&["i8", "u8", "i16", "u16", "i32", "u32"])
+            ),
+            5 => format!("assert!({}, \"fuzz\")", self.expr()),
+            _ => "panic!(\"fuzz\")".to_string(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::front::ast::{self, Span};
+use crate::front::diagnostics::line_col;
+use crate::front::lexer::Token;
+use crate::llvm::resolve::{Role, SourceFile, resolve_idents};
+
+// `sprs index`: a JSON database of the definitions and references in the project, for editors
+// and scripts that do not speak LSP. Symbols are named after their definition:
+//   function `module.f`, struct `S` (struct names are global), module `drivers.uart`,
+//   variable or parameter `module.f.x`, or `module.x` for a top-level `var`.
+// Names the resolver cannot tie to a definition (builtins, enum variants, fields) are left out.
+
+#[derive(Serialize)]
+pub struct Index {
+    pub files: Vec<String>,
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Serialize)]
+pub struct Entry {
+    pub symbol: String,
+    pub kind: &'static str, // function, struct, variable, parameter or module
+    pub definition: bool,   // false for a reference
+    pub file: String,
+    pub line: usize,   // 1-based
+    pub column: usize, // 1-based, in chars
+    pub start: usize,  // byte offsets of the name
+    pub end: usize,
+}
+
+pub fn build_index(files: &[SourceFile]) -> Result<Index, String> {
+    let mut entries = Vec::new();
+    for file in files {
+        let mut found: Vec<(String, &'static str, bool, Span)> = Vec::new();
+
+        // the module is defined by its `pkg` line, or else by the file itself
+        let paths = file.module_paths();
+        let pkg = paths
+            .iter()
+            .find(|(token, _)| *token == Token::Package)
+            .map(|(_, span)| *span);
+        found.push((file.module.clone(), "module", true, pkg.unwrap_or_default()));
+        for (token, span) in &paths {
+            if *token == Token::Import {
+                let module = file.active[span.start..span.end].to_string();
+                found.push((module, "module", false, *span));
+            }
+        }
+
+        let occurrences = resolve_idents(file, |_| true)?;
+        // (enclosing function, name) -> kind, None is the top level
+        let declared: HashMap<(Option<usize>, &str), &'static str> = occurrences
+            .iter()
+            .filter_map(|o| match o.role {
+                Role::Param => Some(((o.function, o.name.as_str()), "parameter")),
+                Role::Binding => Some(((o.function, o.name.as_str()), "variable")),
+                _ => None,
+            })
+            .collect();
+
+        for o in &occurrences {
+            let function = |module: &str| format!("{}.{}", module, o.name);
+            let entry = match &o.role {
+                Role::FunctionName => Some((function(&file.module), "function", true)),
+                Role::Call => {
+                    let module = if file.defines_function(&o.name) {
+                        Some(file.module.as_str())
+                    } else {
+                        file.imported_from(&o.name)
+                    };
+                    module.map(|m| (function(m), "function", false))
+                }
+                Role::ModuleFunction(qualifier) => file
+                    .qualified_module(qualifier)
+                    .map(|m| (function(m), "function", false)),
+                Role::ImportName(module) => Some((function(module), "function", false)),
+                Role::ModuleQualifier => file
+                    .qualified_module(&o.name)
+                    .map(|m| (m.to_string(), "module", false)),
+                Role::StructDef => Some((o.name.clone(), "struct", true)),
+                Role::StructName => Some((o.name.clone(), "struct", false)),
+                Role::Param | Role::Binding | Role::Use => {
+                    // a function's own declaration first, then a top-level `var`
+                    [o.function, None].into_iter().find_map(|scope| {
+                        let kind = declared.get(&(scope, o.name.as_str()))?;
+                        let definition = o.role != Role::Use;
+                        Some((variable_symbol(file, scope, &o.name), *kind, definition))
+                    })
+                }
+                Role::Other => None,
+            };
+            if let Some((symbol, kind, definition)) = entry {
+                found.push((symbol, kind, definition, o.span));
+            }
+        }
+
+        found.sort_by_key(|(_, _, _, span)| span.start);
+        entries.extend(found.into_iter().map(|(symbol, kind, definition, span)| {
+            let (line, column) = line_col(&file.active, span.start);
+            Entry {
+                symbol,
+                kind,
+                definition,
+                file: file.path.clone(),
+                line,
+                column,
+                start: span.start,
+                end: span.end,
+            }
+        }));
+    }
+
+    Ok(Index {
+        files: files.iter().map(|f| f.path.clone()).collect(),
+        entries,
+    })
+}
+
+fn variable_symbol(file: &SourceFile, scope: Option<usize>, name: &str) -> String {
+    match scope.map(|idx| &file.items[idx]) {
+        Some(ast::Item::FunctionItem(func)) => format!("{}.{}.{}", file.module, func.ident, name),
+        _ => format!("{}.{}", file.module, name),
+    }
+}
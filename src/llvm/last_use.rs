@@ -0,0 +1,174 @@
+use crate::front::ast;
+use crate::front::ast::visit::{self, Visitor};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// Last-use analysis for clone elision.
+// `f(clone!(x))` can pass `x` itself (and move it) when `x` is a local that is never read again,
+// which saves the `__clone` heap copy.
+
+struct CloneSite<'a> {
+    expr: *const ast::Expr, // the `clone!(x)` call passed as an argument
+    name: &'a str,
+    pos: usize,
+    shared_with_other_arg: bool,
+    is_fresh_local: bool, // declared in this function, inside the innermost enclosing loop
+}
+
+struct Analysis<'a> {
+    pos: usize,
+    last_use: HashMap<&'a str, usize>,
+    scopes: Vec<(HashSet<&'a str>, bool)>, // declared names, is a while body
+    sites: Vec<CloneSite<'a>>,
+}
+
+// Returns the `clone!(x)` call arguments in `func` whose clone can be skipped.
+pub fn elidable_clones(func: &ast::Function) -> HashSet<*const ast::Expr> {
+    let mut analysis = Analysis {
+        pos: 0,
+        last_use: HashMap::new(),
+        scopes: vec![(
+            func.params.iter().map(|p| p.ident.as_str()).collect(),
+            false,
+        )],
+        sites: Vec::new(),
+    };
+    analysis.visit_block(&func.blk);
+
+    analysis
+        .sites
+        .iter()
+        .filter(|site| {
+            site.is_fresh_local
+                && !site.shared_with_other_arg
+                && analysis.last_use.get(site.name) == Some(&site.pos)
+        })
+        .map(|site| site.expr)
+        .collect()
+}
+
+impl<'a> Analysis<'a> {
+    fn use_var(&mut self, name: &'a str) -> usize {
+        self.pos += 1;
+        self.last_use.insert(name, self.pos);
+        self.pos
+    }
+
+    fn scoped_block(&mut self, stmts: &'a [ast::Stmt], is_loop: bool) {
+        self.scopes.push((HashSet::new(), is_loop));
+        visit::walk_block(self, stmts);
+        self.scopes.pop();
+    }
+
+    fn visit_call_args(&mut self, args: &'a [ast::Expr]) {
+        for (idx, arg) in args.iter().enumerate() {
+            let Some(name) = cloned_var(arg) else {
+                self.visit_expr(arg);
+                continue;
+            };
+
+            let shared_with_other_arg = args
+                .iter()
+                .enumerate()
+                .any(|(other, e)| other != idx && mentions(e, name));
+            // a variable from outside the loop would be read again by the next iteration
+            let declared_at = self.scopes.iter().rposition(|(d, _)| d.contains(name));
+            let innermost_loop = self.scopes.iter().rposition(|(_, is_loop)| *is_loop);
+            let is_fresh_local = match (declared_at, innermost_loop) {
+                (Some(d), Some(l)) => d >= l,
+                (Some(_), None) => true,
+                (None, _) => false,
+            };
+            let pos = self.use_var(name);
+            self.sites.push(CloneSite {
+                expr: arg,
+                name,
+                pos,
+                shared_with_other_arg,
+                is_fresh_local,
+            });
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for Analysis<'a> {
+    fn visit_block(&mut self, stmts: &'a [ast::Stmt]) {
+        self.scoped_block(stmts, false);
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        match &stmt.kind {
+            // every value is evaluated before any of the names is assigned
+            ast::StmtKind::MultiAssign(assigns) => {
+                for assign in assigns {
+                    self.visit_expr(&assign.expr);
+                }
+                for assign in assigns {
+                    self.use_var(&assign.name);
+                }
+            }
+            ast::StmtKind::While { cond, body } => {
+                self.visit_expr(cond);
+                self.scoped_block(body, true);
+            }
+            _ => visit::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_var_decl(&mut self, var: &'a ast::VarDecl) {
+        visit::walk_var_decl(self, var);
+        self.scopes.last_mut().unwrap().0.insert(&var.ident);
+        self.use_var(&var.ident);
+    }
+
+    fn visit_assign(&mut self, assign: &'a ast::AssignStmt) {
+        visit::walk_assign(self, assign);
+        self.use_var(&assign.name);
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        match expr {
+            ast::Expr::Var(name) => {
+                self.use_var(name);
+            }
+            ast::Expr::Call(ident, args, _, _) if !ident.ends_with('!') => {
+                self.visit_call_args(args)
+            }
+            ast::Expr::ModuleAccess(_, _, args) => self.visit_call_args(args),
+            _ => visit::walk_expr(self, expr),
+        }
+    }
+}
+
+// `clone!(x)` -> Some("x")
+fn cloned_var(expr: &ast::Expr) -> Option<&str> {
+    match expr {
+        ast::Expr::Call(ident, args, _, _) if ident == "clone!" && args.len() == 1 => {
+            match &args[0] {
+                ast::Expr::Var(name) => Some(name),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn mentions(expr: &ast::Expr, name: &str) -> bool {
+    let mut finder = Mentions { name, found: false };
+    finder.visit_expr(expr);
+    finder.found
+}
+
+struct Mentions<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for Mentions<'_> {
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        match expr {
+            ast::Expr::Var(v) => self.found |= v == self.name,
+            _ => visit::walk_expr(self, expr),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::front::ast;
+use crate::front::ast::visit::{self, Visitor};
+use crate::front::diagnostics::Diagnostic;
+
+// Warnings that do not stop the build (unless `--deny-warnings` is given):
+// unused `var` declarations, unused function parameters and statements after `return`.
+// A name starting with `_` is never reported as unused.
+
+struct Local<'a> {
+    name: &'a str,
+    span: ast::Span,
+    ident_span: ast::Span, // replaced with `_name` by the fix
+    is_param: bool,
+    used: bool,
+    assigned: bool, // `x = ...` names it too, so renaming only the declaration would break that
+}
+
+struct Lint<'a> {
+    path: &'a str,
+    scopes: Vec<Vec<Local<'a>>>,
+    warnings: Vec<Diagnostic>,
+}
+
+// Returns the warnings for every function in the module.
+pub fn lint_module(items: &[ast::Item], path: &str) -> Vec<Diagnostic> {
+    let mut lint = Lint {
+        path,
+        scopes: Vec::new(),
+        warnings: Vec::new(),
+    };
+
+    visit::walk_items(&mut lint, items);
+
+    lint.warnings
+}
+
+impl<'a> Lint<'a> {
+    fn pop_scope(&mut self) {
+        let Some(locals) = self.scopes.pop() else {
+            return;
+        };
+        for local in locals {
+            if local.used || local.name.starts_with('_') {
+                continue;
+            }
+            let what = if local.is_param {
+                "parameter"
+            } else {
+                "variable"
+            };
+            let mut warning = Diagnostic::warning(format!("unused {} `{}`", what, local.name))
+                .in_file(self.path)
+                .with_primary(
+                    local.span,
+                    format!(
+                        "if this is intentional, prefix it with an underscore: `_{}`",
+                        local.name
+                    ),
+                );
+            if !local.assigned {
+                warning = warning.with_fix(
+                    local.ident_span,
+                    format!("_{}", local.name),
+                    format!("rename to `_{}`", local.name),
+                );
+            }
+            self.warnings.push(warning);
+        }
+    }
+
+    fn find_local(&mut self, name: &str) -> Option<&mut Local<'a>> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.iter_mut().rev().find(|l| l.name == name))
+    }
+
+    fn use_var(&mut self, name: &str) {
+        if let Some(local) = self.find_local(name) {
+            local.used = true;
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for Lint<'a> {
+    fn visit_function(&mut self, func: &'a ast::Function) {
+        self.scopes.push(
+            func.params
+                .iter()
+                .map(|p| Local {
+                    name: &p.ident,
+                    span: p.span,
+                    ident_span: p.span,
+                    is_param: true,
+                    used: false,
+                    assigned: false,
+                })
+                .collect(),
+        );
+        self.visit_block(&func.blk);
+        self.pop_scope();
+    }
+
+    fn visit_block(&mut self, stmts: &'a [ast::Stmt]) {
+        self.scopes.push(Vec::new());
+        let mut returned: Option<ast::Span> = None;
+        for stmt in stmts {
+            if let Some(return_span) = returned.take() {
+                self.warnings.push(
+                    Diagnostic::warning("unreachable statement")
+                        .in_file(self.path)
+                        .with_primary(stmt.span, "unreachable statement")
+                        .with_label(
+                            return_span,
+                            "any code following this `return` is unreachable",
+                        ),
+                );
+            }
+            if matches!(stmt.kind, ast::StmtKind::Return(_)) {
+                returned = Some(stmt.span);
+            }
+            self.visit_stmt(stmt);
+        }
+        self.pop_scope();
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        // the initializer is walked before the variable is in scope
+        visit::walk_stmt(self, stmt);
+        let assigns = match &stmt.kind {
+            ast::StmtKind::Assign(assign) => std::slice::from_ref(assign),
+            ast::StmtKind::MultiAssign(assigns) => assigns.as_slice(),
+            _ => &[],
+        };
+        for assign in assigns {
+            if let Some(local) = self.find_local(&assign.name) {
+                local.assigned = true;
+            }
+        }
+        if let ast::StmtKind::Var(var) = &stmt.kind {
+            self.scopes.last_mut().unwrap().push(Local {
+                name: &var.ident,
+                span: stmt.span,
+                ident_span: var.ident_span,
+                is_param: false,
+                used: false,
+                assigned: false,
+            });
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        if let ast::Expr::Var(name) = expr {
+            self.use_var(name);
+        }
+        visit::walk_expr(self, expr);
+    }
+}
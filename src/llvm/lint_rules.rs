@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::front::ast;
+use crate::front::ast::visit::{self, Visitor};
+use crate::front::diagnostics::{Diagnostic, Severity};
+
+// Rules of `sprs lint`, on top of the warnings every build reports (lint.rs).
+// Each rule can be set to "allow", "warn" or "deny" in the `[lint]` table of sprs.toml.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    fn parse(level: &str) -> Option<Self> {
+        match level {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+// name, default level, description
+pub const RULES: &[(&str, LintLevel, &str)] = &[
+    (
+        "shadowed-variable",
+        LintLevel::Warn,
+        "`var` declares a name that is already in scope",
+    ),
+    (
+        "constant-comparison",
+        LintLevel::Warn,
+        "comparison that is always true or always false",
+    ),
+    (
+        "clone-non-heap",
+        LintLevel::Warn,
+        "`clone!` of a number or boolean, which is copied anyway",
+    ),
+    (
+        "empty-loop",
+        LintLevel::Warn,
+        "`while` loop with an empty body",
+    ),
+];
+
+pub struct LintConfig {
+    levels: HashMap<&'static str, LintLevel>,
+}
+
+impl LintConfig {
+    // Defaults overridden by the `[lint]` table, e.g. `empty-loop = "deny"`.
+    pub fn from_table(table: &BTreeMap<String, String>) -> Result<Self, String> {
+        let mut levels: HashMap<&'static str, LintLevel> = RULES
+            .iter()
+            .map(|(name, level, _)| (*name, *level))
+            .collect();
+
+        for (name, level) in table {
+            let Some((rule, _, _)) = RULES.iter().find(|(rule, _, _)| rule == name) else {
+                return Err(format!("Unknown lint rule in sprs.toml: {}", name));
+            };
+            let level = LintLevel::parse(level).ok_or_else(|| {
+                format!(
+                    "Invalid level for lint rule {}: {} (expected allow, warn or deny)",
+                    name, level
+                )
+            })?;
+            levels.insert(rule, level);
+        }
+
+        Ok(LintConfig { levels })
+    }
+
+    fn level(&self, rule: &str) -> LintLevel {
+        self.levels.get(rule).copied().unwrap_or(LintLevel::Allow)
+    }
+}
+
+struct Local<'a> {
+    name: &'a str,
+    span: ast::Span,
+    scalar: bool, // holds a number or boolean as far as the lint can tell
+}
+
+struct Rules<'a> {
+    path: &'a str,
+    config: &'a LintConfig,
+    scopes: Vec<Vec<Local<'a>>>,
+    stmt_span: ast::Span, // for findings in expressions without a span of their own
+    diagnostics: Vec<Diagnostic>,
+}
+
+// Returns the findings of the enabled rules for one module.
+pub fn check_module(items: &[ast::Item], path: &str, config: &LintConfig) -> Vec<Diagnostic> {
+    let mut rules = Rules {
+        path,
+        config,
+        scopes: Vec::new(),
+        stmt_span: ast::Span::default(),
+        diagnostics: Vec::new(),
+    };
+
+    visit::walk_items(&mut rules, items);
+
+    rules.diagnostics
+}
+
+impl<'a> Rules<'a> {
+    // Diagnostic for a finding of `rule`, or None when the rule is allowed.
+    fn finding(&self, rule: &str, message: String) -> Option<Diagnostic> {
+        let severity = match self.config.level(rule) {
+            LintLevel::Allow => return None,
+            LintLevel::Warn => Severity::Warning,
+            LintLevel::Deny => Severity::Error,
+        };
+        Some(Diagnostic::new(severity, format!("{} [{}]", message, rule)).in_file(self.path))
+    }
+
+    fn find_local(&mut self, name: &str) -> Option<&mut Local<'a>> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.iter_mut().rev().find(|l| l.name == name))
+    }
+
+    fn is_scalar(&mut self, expr: &ast::Expr) -> bool {
+        match expr {
+            ast::Expr::Number(_)
+            | ast::Expr::Float(_)
+            | ast::Expr::Bool(_)
+            | ast::Expr::Char(_) => true,
+            ast::Expr::Var(name) => self.find_local(name).is_some_and(|l| l.scalar),
+            _ => false,
+        }
+    }
+
+    fn check_comparison(&mut self, expr: &ast::Expr) {
+        let (l, r, op): (&ast::Expr, &ast::Expr, fn(std::cmp::Ordering) -> bool) = match expr {
+            ast::Expr::Eq(l, r, _) => (l, r, |o| o.is_eq()),
+            ast::Expr::Neq(l, r, _) => (l, r, |o| o.is_ne()),
+            ast::Expr::Lt(l, r, _) => (l, r, |o| o.is_lt()),
+            ast::Expr::Gt(l, r, _) => (l, r, |o| o.is_gt()),
+            ast::Expr::Le(l, r, _) => (l, r, |o| o.is_le()),
+            ast::Expr::Ge(l, r, _) => (l, r, |o| o.is_ge()),
+            _ => return,
+        };
+
+        let ordering = match (l, r) {
+            (ast::Expr::Var(a), ast::Expr::Var(b)) if a == b => Some(std::cmp::Ordering::Equal),
+            (ast::Expr::Number(a), ast::Expr::Number(b)) => Some(a.cmp(b)),
+            (ast::Expr::Float(a), ast::Expr::Float(b)) => a.partial_cmp(b),
+            (ast::Expr::Bool(a), ast::Expr::Bool(b)) => Some(a.cmp(b)),
+            (ast::Expr::Str(a), ast::Expr::Str(b)) => Some(a.cmp(b)),
+            (ast::Expr::Char(a), ast::Expr::Char(b)) => Some(a.cmp(b)),
+            _ => None,
+        };
+
+        if let Some(ordering) = ordering {
+            let message = format!("comparison is always {}", op(ordering));
+            if let Some(d) = self.finding("constant-comparison", message) {
+                let span = expr.span().unwrap_or(self.stmt_span);
+                self.diagnostics.push(d.with_primary(span, ""));
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for Rules<'a> {
+    fn visit_function(&mut self, func: &'a ast::Function) {
+        self.scopes.push(
+            func.params
+                .iter()
+                .map(|p| Local {
+                    name: &p.ident,
+                    span: p.span,
+                    scalar: false,
+                })
+                .collect(),
+        );
+        visit::walk_function(self, func);
+        self.scopes.pop();
+    }
+
+    fn visit_block(&mut self, stmts: &'a [ast::Stmt]) {
+        self.scopes.push(Vec::new());
+        visit::walk_block(self, stmts);
+        self.scopes.pop();
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        self.stmt_span = stmt.span;
+        visit::walk_stmt(self, stmt);
+        self.stmt_span = stmt.span;
+
+        match &stmt.kind {
+            ast::StmtKind::Var(var) => {
+                let scalar = var.expr.as_ref().is_some_and(|e| self.is_scalar(e));
+                let earlier = self.find_local(&var.ident).map(|l| l.span);
+                if let Some(earlier) = earlier {
+                    let message = format!("`{}` shadows an earlier declaration", var.ident);
+                    if let Some(d) = self.finding("shadowed-variable", message) {
+                        self.diagnostics.push(
+                            d.with_primary(stmt.span, "declared again here")
+                                .with_label(earlier, "first declared here"),
+                        );
+                    }
+                }
+                self.scopes.last_mut().unwrap().push(Local {
+                    name: &var.ident,
+                    span: stmt.span,
+                    scalar,
+                });
+            }
+            ast::StmtKind::Assign(assign) => {
+                let scalar = self.is_scalar(&assign.expr);
+                if let Some(local) = self.find_local(&assign.name) {
+                    local.scalar = scalar;
+                }
+            }
+            ast::StmtKind::MultiAssign(assigns) => {
+                // all values are evaluated before any of them is stored
+                let scalars: Vec<bool> = assigns.iter().map(|a| self.is_scalar(&a.expr)).collect();
+                for (assign, scalar) in assigns.iter().zip(scalars) {
+                    if let Some(local) = self.find_local(&assign.name) {
+                        local.scalar = scalar;
+                    }
+                }
+            }
+            ast::StmtKind::While { body, .. } if body.is_empty() => {
+                let message = "`while` loop has an empty body".to_string();
+                if let Some(d) = self.finding("empty-loop", message) {
+                    self.diagnostics.push(d.with_primary(stmt.span, ""));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        self.check_comparison(expr);
+
+        if let ast::Expr::Call(ident, args, _, _) = expr {
+            if ident == "clone!" && args.len() == 1 && self.is_scalar(&args[0]) {
+                let message = "`clone!` of a number or boolean has no effect".to_string();
+                if let Some(d) = self.finding("clone-non-heap", message) {
+                    self.diagnostics.push(d.with_primary(
+                        expr.span().unwrap_or(self.stmt_span),
+                        "numbers and booleans are copied without `clone!`",
+                    ));
+                }
+            }
+        }
+
+        visit::walk_expr(self, expr);
+    }
+}
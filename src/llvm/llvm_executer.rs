@@ -1,154 +1,776 @@
-use std::{path::Path, process::Command};
+use std::{
+    collections::{HashMap, HashSet, VecDeque, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+    io::{BufRead, BufReader},
+    path::Path,
+    process::{Command, Stdio},
+    rc::Rc,
+    time::Instant,
+};
 
 use inkwell::{
     context::Context,
+    module::Linkage,
     passes::PassBuilderOptions,
     targets::{InitializationConfig, Target, TargetMachine, TargetTriple},
 };
 
 use crate::{
-    command_helper::ProjectConfig,
-    llvm::compiler::{self, OS},
+    command_helper::{self, BuildOptions, Emit, FlashConfig},
+    front::{
+        ast,
+        diagnostics::{Diagnostic, Diagnostics, apply_fixes, line_col},
+        lexer::{Edition, Lexer},
+        preprocess,
+    },
+    interpreter::runner::{parse_module, parse_module_spanned},
+    llvm::{
+        build_cache::BuildCache,
+        build_log::BuildLog,
+        c_header,
+        compiler::{self, OS},
+        doc::{self, DocFormat},
+        fuzz, index, lint, lint_rules, migrate, panic_table,
+        plugin::MacroPlugin,
+        rename, resolve, rodata, size_report, watch,
+    },
+    vendor,
 };
 
 const RUNTIME_SOURCE: &str = include_str!("../runtime/runtime.rs");
+// `mod embedded;` of runtime.rs, the platform layer of bare-metal and `--no-std` builds.
+const EMBEDDED_SOURCE: &str = include_str!("../runtime/embedded.rs");
+
+// WASI target: the runtime's println!/alloc go through WASI, so the module runs under
+// wasmtime or any WASI host (including browser shims).
+const WASM_TRIPLE: &str = "wasm32-wasip1";
+
+// Bare-metal RISC-V profile (rv32imac, ilp32). Linked with the project's `link.ld`.
+const RISCV_TRIPLE: &str = "riscv32-unknown-none-elf";
+const RISCV_RUST_TARGET: &str = "riscv32imac-unknown-none-elf";
+const RISCV_LINKER_SCRIPT: &str = "link.ld";
+
+// Experimental 8-bit AVR profile (ATmega328P, Arduino Uno class).
+// rustc ships no prebuilt core for AVR, so only the program objects are produced here.
+const AVR_TRIPLE: &str = "avr-unknown-unknown";
+const AVR_CPU: &str = "atmega328p";
+
+pub fn target_triple(os: OS) -> TargetTriple {
+    match os {
+        OS::Unknown => TargetMachine::get_default_triple(),
+        OS::Windows => TargetTriple::create("x86_64-pc-windows-msvc"),
+        OS::Linux => TargetTriple::create("x86_64-pc-linux-gnu"),
+        OS::Wasm => TargetTriple::create(WASM_TRIPLE),
+        OS::RiscV => TargetTriple::create(RISCV_TRIPLE),
+        OS::Avr => TargetTriple::create(AVR_TRIPLE),
+    }
+}
 
-#[derive(PartialEq)]
+#[derive(PartialEq, Clone)]
 pub enum ExecuteMode {
     Build,
     Run,
+    Watch, // `sprs run --watch`: the program is started and left to llvm/watch.rs
     Debug,
+    Flash,
+    Test { runner: Option<String> }, // `sprs test`, optionally through `--runner <cmd>`
+    Check, // `sprs check`: only the errors and warnings, nothing is written
+    // one program of `sprs test --examples`, whose output must be `expected`
+    Example {
+        expected: String,
+        runner: Option<String>,
+    },
 }
 
-pub fn build_and_run(_full_path: String, mode: ExecuteMode) {
-    let context = Context::create();
-    let builder = context.create_builder();
+// Returns false when any step fails; `sprs test` turns that into a non-zero exit code.
+pub fn build_and_run(full_path: String, mode: ExecuteMode, options: BuildOptions) -> bool {
+    build_and_run_with_macros(full_path, mode, options, Vec::new())
+}
 
-    let setting_toml_content =
-        std::fs::read_to_string("sprs.toml").unwrap_or_else(|_| "".to_string());
+// `build_and_run` for drivers that register plugin macros (see llvm/plugin.rs).
+pub fn build_and_run_with_macros(
+    _full_path: String,
+    mode: ExecuteMode,
+    options: BuildOptions,
+    macros: Vec<Rc<dyn MacroPlugin>>,
+) -> bool {
+    match command_helper::load_workspace_config() {
+        Some(workspace) => build_workspace(&workspace.members, mode, options, macros),
+        None => build_project(mode, options, macros),
+    }
+}
 
-    let config: Option<ProjectConfig> = if !setting_toml_content.is_empty() {
-        match toml::from_str(&setting_toml_content) {
-            Ok(cfg) => Some(cfg),
+// `sprs build`, `sprs test` and `sprs check` at the root of a workspace: every member in turn,
+// in its own directory, with one artifact cache for all of them. Building stops at the first
+// member that fails; tests and checks run in every member.
+fn build_workspace(
+    members: &[String],
+    mode: ExecuteMode,
+    options: BuildOptions,
+    macros: Vec<Rc<dyn MacroPlugin>>,
+) -> bool {
+    if !matches!(
+        mode,
+        ExecuteMode::Build | ExecuteMode::Test { .. } | ExecuteMode::Check
+    ) {
+        eprintln!(
+            "sprs.toml is a workspace, run this in the directory of a member ({})",
+            members.join(", ")
+        );
+        return false;
+    }
+    let root = match std::env::current_dir() {
+        Ok(root) => root,
+        Err(e) => {
+            eprintln!("Failed to read the current directory: {}", e);
+            return false;
+        }
+    };
+    let mut options = options;
+    options.cache_dir = Some(root.join(".sprs-cache").to_string_lossy().into_owned());
+
+    let log = BuildLog::new(options.verbosity);
+    let mut ok = true;
+    for member in members {
+        log.status(format_args!("--- Member {} ---", member));
+        // sprs.toml, the sources and out_dir are relative to the member
+        if let Err(e) = std::env::set_current_dir(root.join(member)) {
+            eprintln!("Workspace member {}: {}", member, e);
+            return false;
+        }
+        let built = if command_helper::load_workspace_config().is_some() {
+            eprintln!("Workspace member {} is a workspace itself", member);
+            false
+        } else {
+            build_project(mode.clone(), options.clone(), macros.clone())
+        };
+        if let Err(e) = std::env::set_current_dir(&root) {
+            eprintln!("Failed to return to {}: {}", root.display(), e);
+            return false;
+        }
+        if !built {
+            ok = false;
+            if mode == ExecuteMode::Build {
+                break;
+            }
+        }
+    }
+    ok
+}
+
+// The project in the current directory, or the `[[bin]]`s of it that `options` select.
+fn build_project(
+    mode: ExecuteMode,
+    options: BuildOptions,
+    macros: Vec<Rc<dyn MacroPlugin>>,
+) -> bool {
+    let config = command_helper::load_project_config();
+    let bins = match command_helper::select_bins(
+        config.as_ref(),
+        options.bin.as_deref(),
+        matches!(mode, ExecuteMode::Build | ExecuteMode::Check),
+    ) {
+        Ok(bins) => bins,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    if bins.is_empty() {
+        return build_binary(mode, options, macros, config);
+    }
+
+    // several only for `sprs build`
+    let count = bins.len();
+    for bin in bins {
+        if count > 1 {
+            BuildLog::new(options.verbosity).status(format_args!("--- Building {} ---", bin.name));
+        }
+        let bin_config = config.as_ref().map(|c| command_helper::bin_config(c, &bin));
+        if !build_binary(mode.clone(), options.clone(), macros.clone(), bin_config) {
+            return false;
+        }
+    }
+    true
+}
+
+// `sprs run <file.sprs>`: a script without sprs.toml, built as the entry of a project whose
+// src_dir is the script's directory (so it can import the modules next to it) into a directory
+// under the system temp dir, kept per script so a rerun hits the artifact cache.
+pub fn run_file(file: &str, mode: ExecuteMode, options: BuildOptions) -> bool {
+    match script_config(file) {
+        Ok(config) => build_binary(mode, options, Vec::new(), Some(config)),
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+fn script_config(file: &str) -> Result<command_helper::ProjectConfig, String> {
+    let path = Path::new(file);
+    let (Some(file_name), Some(stem)) = (path.file_name(), path.file_stem()) else {
+        return Err(format!("Not a file: {}", file));
+    };
+    if !path.is_file() {
+        return Err(format!("No such file: {}", file));
+    }
+    let src_dir = path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    let mut hasher = DefaultHasher::new();
+    std::fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .hash(&mut hasher);
+    let name = stem.to_string_lossy().into_owned();
+    let out_dir = std::env::temp_dir().join(format!("sprs-run-{}-{:016x}", name, hasher.finish()));
+
+    Ok(command_helper::ProjectConfig {
+        name,
+        src_dir: src_dir.to_string_lossy().into_owned(),
+        out_dir: out_dir.to_string_lossy().into_owned(),
+        entry: Some(file_name.to_string_lossy().into_owned()),
+        ..Default::default()
+    })
+}
+
+// `sprs test --examples`: every .sprs file under tests/ (or examples/ when there is no tests/)
+// that has a `.expected` file next to it is built as a script, like `sprs run <file>`, run,
+// and its standard output compared with the `.expected` file. The other .sprs files are
+// modules the programs import.
+pub fn test_examples(options: BuildOptions, runner: Option<String>) -> bool {
+    let Some(dir) = EXAMPLE_DIRS.iter().find(|dir| Path::new(dir).is_dir()) else {
+        eprintln!(
+            "No {} directory with programs to test",
+            EXAMPLE_DIRS.join(" or ")
+        );
+        return false;
+    };
+    let mut programs = Vec::new();
+    if let Err(e) = collect_examples(Path::new(dir), &mut programs) {
+        eprintln!("Failed to read {}: {}", dir, e);
+        return false;
+    }
+    programs.sort();
+
+    let mut failures = Vec::new();
+    for program in &programs {
+        let file = program.to_string_lossy().into_owned();
+        let expected = match std::fs::read_to_string(program.with_extension("expected")) {
+            Ok(expected) => expected,
+            Err(e) => {
+                eprintln!("Failed to read the expected output of {}: {}", file, e);
+                return false;
+            }
+        };
+        println!("--- Example {} ---", file);
+        let mode = ExecuteMode::Example {
+            expected,
+            runner: runner.clone(),
+        };
+        let passed = match script_config(&file) {
+            Ok(config) => build_binary(mode, options.clone(), Vec::new(), Some(config)),
             Err(e) => {
-                eprintln!("Failed to parse sprs.toml: {}", e);
-                None
+                eprintln!("{}", e);
+                false
             }
+        };
+        println!(
+            "example {} ... {}",
+            file,
+            if passed { "ok" } else { "FAILED" }
+        );
+        if !passed {
+            failures.push(file);
+        }
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:");
+        for failure in &failures {
+            println!("    {}", failure);
+        }
+    }
+    println!(
+        "\nexample result: {}. {} passed; {} failed",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        programs.len() - failures.len(),
+        failures.len()
+    );
+    failures.is_empty()
+}
+
+const EXAMPLE_DIRS: [&str; 2] = ["tests", "examples"];
+
+fn collect_examples(dir: &Path, programs: &mut Vec<std::path::PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_examples(&path, programs)?;
+        } else if path.extension().is_some_and(|ext| ext == "sprs")
+            && path.with_extension("expected").is_file()
+        {
+            programs.push(path);
+        }
+    }
+    Ok(())
+}
+
+// Builds the program of `config` (the project, or one of its `[[bin]]`s) and runs, flashes
+// or tests it as `mode` says.
+fn build_binary(
+    mode: ExecuteMode,
+    mut options: BuildOptions,
+    macros: Vec<Rc<dyn MacroPlugin>>,
+    config: Option<command_helper::ProjectConfig>,
+) -> bool {
+    let log = BuildLog::new(options.verbosity);
+    let build_started = Instant::now();
+    let context = Context::create();
+    let builder = context.create_builder();
+
+    options.edition = match command_helper::project_edition(config.as_ref()) {
+        Ok(edition) => edition,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
         }
-    } else {
-        None
     };
+    if let Err(e) = command_helper::apply_profile(&mut options, config.as_ref()) {
+        eprintln!("{}", e);
+        return false;
+    }
 
     let src_path = config
         .as_ref()
         .map(|c| c.src_dir.clone())
         .unwrap_or_else(|| "src".to_string());
 
-    let mut compiler = compiler::Compiler::new(&context, builder, src_path.clone());
+    if let Err(e) = vendor::fetch_git_dependencies(config.as_ref(), &log) {
+        eprintln!("{}", e);
+        return false;
+    }
+    let dependencies = match command_helper::dependency_roots(config.as_ref()) {
+        Ok(dependencies) => dependencies,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let emit_header = options.emit_header;
+    let mut compiler = compiler::Compiler::new(&context, builder, src_path.clone(), options);
+    compiler.dependencies = dependencies;
+    for plugin in macros {
+        if let Err(e) = compiler.register_macro(plugin) {
+            eprintln!("{}", e);
+            return false;
+        }
+    }
 
-    let path = format!("{}/main.sprs", src_path);
-    let proj_name = config
+    let path = command_helper::entry_path(config.as_ref(), &src_path);
+    let mut proj_name = config
         .as_ref()
-        .map(|c| c.name.clone())
+        .map(|c| c.bin_name.clone().unwrap_or_else(|| c.name.clone()))
         .unwrap_or_else(|| "sprs_project".to_string());
+    if compiler.options.test {
+        proj_name.push_str("_test");
+    }
     let out_dir = config
         .as_ref()
         .map(|c| c.out_dir.clone())
         .unwrap_or_else(|| "build".to_string());
 
-    if !Path::new(&out_dir).exists() {
+    if mode != ExecuteMode::Check && !Path::new(&out_dir).exists() {
         std::fs::create_dir_all(&out_dir).expect("Failed to create output directory");
     }
 
-    if let Err(e) = compiler.load_and_compile_module("main", Some(&path)) {
+    if emit_header && mode != ExecuteMode::Check {
+        let header_path = format!("{}/sprs.h", out_dir);
+        if let Err(e) = std::fs::write(&header_path, c_header::generate_c_header()) {
+            eprintln!("Failed to write C header: {}", e);
+            return false;
+        }
+        log.status(format_args!("Generated: {}", header_path));
+    }
+
+    let result = compiler.load_and_compile_module("main", Some(&path));
+    if !compiler.diagnostics.is_empty() {
+        compiler.diagnostics.emit();
+    }
+    if let Err(e) = result {
         eprintln!("Compile Error: {}", e);
-        return;
+        return false;
     };
+    // the IR is built, since that is where the checks run, but never optimized or emitted
+    if mode == ExecuteMode::Check {
+        log.status(format_args!(
+            "Checked {}: no errors in {} module(s)",
+            proj_name,
+            compiler.module_sources.len()
+        ));
+        return true;
+    }
+
+    if compiler.options.panic_codes {
+        let table_path = panic_table::table_path(&out_dir, &proj_name);
+        if let Err(e) = panic_table::write_table(&table_path, &compiler.panic_sites) {
+            eprintln!("{}", e);
+            return false;
+        }
+        log.status(format_args!("Generated: {}", table_path));
+    }
 
     Target::initialize_all(&InitializationConfig::default());
 
-    let target_triple = if compiler.target_os == compiler::OS::Unknown {
-        TargetMachine::get_default_triple()
-    } else if compiler.target_os == compiler::OS::Windows {
-        TargetTriple::create("x86_64-pc-windows-msvc")
-    } else {
-        TargetTriple::create("x86_64-pc-linux-gnu")
-    };
+    let target_triple = target_triple(compiler.target_os);
     let target = Target::from_triple(&target_triple)
         .map_err(|e| format!("Target error: {}", e))
         .unwrap();
 
+    let (cpu, features, reloc_mode) = if compiler.target_os == OS::RiscV {
+        (
+            "generic-rv32",
+            "+m,+a,+c",
+            inkwell::targets::RelocMode::Static,
+        )
+    } else if compiler.target_os == OS::Avr {
+        (AVR_CPU, "", inkwell::targets::RelocMode::Static)
+    } else {
+        ("generic", "", inkwell::targets::RelocMode::PIC)
+    };
+
+    let opt_level = compiler.options.effective_opt_level();
     let target_machine = target
         .create_target_machine(
             &target_triple,
-            "generic",
-            "",
-            inkwell::OptimizationLevel::Default,
-            inkwell::targets::RelocMode::PIC,
+            cpu,
+            features,
+            if opt_level == 3 {
+                inkwell::OptimizationLevel::Aggressive
+            } else {
+                inkwell::OptimizationLevel::Default
+            },
+            reloc_mode,
             inkwell::targets::CodeModel::Default,
         )
         .unwrap();
 
     let mut object_files = Vec::new();
 
+    let lto = config
+        .as_ref()
+        .and_then(|c| c.link.as_ref())
+        .is_some_and(|link| link.lto);
+    if lto {
+        if let Err(e) = link_modules_for_lto(&mut compiler) {
+            eprintln!("{}", e);
+            return false;
+        }
+    }
+
+    let cache = match &compiler.options.cache_dir {
+        Some(dir) => BuildCache::shared(dir),
+        None => BuildCache::new(&out_dir),
+    };
+    let cache_flags = format!(
+        "{} {} {} -O{} debug={} lto={}",
+        target_triple.as_str().to_string_lossy(),
+        cpu,
+        features,
+        opt_level,
+        compiler.options.debug,
+        lto
+    );
+    let mut extensions = Vec::new();
+    if compiler.options.emits(Emit::Ir) {
+        extensions.push("ll");
+    }
+    if compiler.options.emits(Emit::Asm) {
+        extensions.push("s");
+    }
+    if compiler.options.emits(Emit::Obj) || compiler.options.emits(Emit::Exe) {
+        extensions.push("o");
+    }
+
+    if compiler.options.size_report && !extensions.contains(&"o") {
+        eprintln!("--size-report reads the objects; add obj or exe to --emit");
+        return false;
+    }
+
+    let mut report_objects = Vec::new();
+    let mut report_functions = HashSet::new();
+
     for (name, module) in &compiler.modules {
         module.set_data_layout(&target_machine.get_target_data().get_data_layout());
         module.set_triple(&target_triple);
+        if compiler.options.size_report {
+            size_report::expose_private_functions(module);
+            report_functions.extend(
+                module
+                    .get_functions()
+                    .filter(|f| f.count_basic_blocks() > 0)
+                    .map(|f| f.get_name().to_string_lossy().into_owned()),
+            );
+            report_objects.push((name.clone(), format!("{}/{}.o", out_dir, name)));
+        }
+
+        let key = BuildCache::key(module, &cache_flags);
+        if cache.restore(name, key, &extensions, &out_dir) {
+            log.status(format_args!(
+                "Unchanged: {} (from {})",
+                name,
+                cache.dir().display()
+            ));
+            if extensions.contains(&"o") {
+                object_files.push(format!("{}/{}.o", out_dir, name));
+            }
+            continue;
+        }
 
-        // mem2reg
+        let started = Instant::now();
         let pass_options = PassBuilderOptions::create();
-        let _ = module.run_passes("mem2reg", &target_machine, pass_options);
+        let passes = if lto {
+            format!("{},globaldce", optimization_passes(opt_level))
+        } else {
+            optimization_passes(opt_level).to_string()
+        };
+        if let Err(e) = module.run_passes(&passes, &target_machine, pass_options) {
+            eprintln!("Failed to optimize {}: {}", name, e);
+            return false;
+        }
+        log.timed(format_args!("optimize {} ({})", name, passes), started);
+
+        // Flash is scarce on embedded targets: literals that are the tail of another one share it.
+        if matches!(compiler.target_os, OS::RiscV | OS::Avr) {
+            let saved = rodata::merge_string_suffixes(module);
+            if saved > 0 {
+                log.status(format_args!(
+                    "Merged string literals in {}: {} bytes saved",
+                    name, saved
+                ));
+            }
+        }
+
+        let started = Instant::now();
+        if compiler.options.emits(Emit::Ir) {
+            let ll_filename = format!("{}/{}.ll", out_dir, name);
+            if let Err(e) = module.print_to_file(Path::new(&ll_filename)) {
+                eprintln!("Failed to write LLVM IR to {}: {}", ll_filename, e);
+            }
+            log.status(format_args!("Generated: {}", ll_filename));
+        }
+
+        if compiler.options.emits(Emit::Asm) {
+            let asm_filename = format!("{}/{}.s", out_dir, name);
+            if let Err(e) = target_machine.write_to_file(
+                module,
+                inkwell::targets::FileType::Assembly,
+                Path::new(&asm_filename),
+            ) {
+                eprintln!("Failed to write assembly to {}: {}", asm_filename, e);
+                return false;
+            }
+            log.status(format_args!("Generated: {}", asm_filename));
+        }
+
+        // the objects are also the input of the link
+        if compiler.options.emits(Emit::Obj) || compiler.options.emits(Emit::Exe) {
+            let filename = format!("{}/{}.o", out_dir, name);
+            let obj_path = Path::new(&filename);
+
+            target_machine
+                .write_to_file(module, inkwell::targets::FileType::Object, obj_path)
+                .map_err(|e| format!("Failed to write object file: {}", e))
+                .unwrap();
+            log.status(format_args!("Generated: {}", filename));
+            object_files.push(filename);
+        }
+        log.timed(format_args!("emit {}", name), started);
+        cache.store(name, key, &extensions, &out_dir);
+    }
 
-        let ll_filename = format!("{}.ll", name);
-        if let Err(e) = module.print_to_file(Path::new(&ll_filename)) {
-            eprintln!("Failed to write LLVM IR to {}: {}", ll_filename, e);
+    if compiler.options.size_report {
+        if let Err(e) = size_report::print_size_report(&report_objects, &report_functions) {
+            eprintln!("{}", e);
+            return false;
         }
-        println!("Generated: {}", ll_filename);
+    }
+
+    if !compiler.options.emits(Emit::Exe) {
+        if mode != ExecuteMode::Build {
+            eprintln!("Only `sprs build` can leave out exe from --emit");
+            return false;
+        }
+        return true;
+    }
+
+    if mode == ExecuteMode::Flash && compiler.target_os != OS::RiscV {
+        eprintln!("`sprs flash` needs an embedded target with a linked image (#define RiscV)");
+        return false;
+    }
+
+    if matches!(mode, ExecuteMode::Test { .. }) && compiler.target_os == OS::Avr {
+        eprintln!("`sprs test` is not supported on the AVR target yet");
+        return false;
+    }
+
+    if compiler.options.no_std && compiler.target_os == OS::Wasm {
+        eprintln!("--no-std is not supported for Wasm; the runtime goes through WASI there");
+        return false;
+    }
+
+    // Built against the host architecture but without std, so nothing is linked here.
+    let freestanding_host =
+        compiler.options.no_std && !matches!(compiler.target_os, OS::RiscV | OS::Avr);
+
+    if matches!(mode, ExecuteMode::Test { .. } | ExecuteMode::Flash) && freestanding_host {
+        eprintln!(
+            "--no-std builds are linked by your own startup code; `sprs test` and `sprs flash` need an image"
+        );
+        return false;
+    }
+
+    if compiler.options.debug_alloc
+        && (compiler.options.no_std || matches!(compiler.target_os, OS::RiscV | OS::Avr))
+    {
+        eprintln!("--debug-alloc needs a hosted target (Linux, Windows or Wasm) and std");
+        return false;
+    }
 
-        let filename = format!("{}.o", name);
-        let obj_path = Path::new(&filename);
+    let (allocator, heap_size) = match command_helper::runtime_allocator(config.as_ref()) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    if allocator.as_deref() == Some("libc") && heap_size.is_some() {
+        log.warning("heap_size in sprs.toml has no effect with allocator = \"libc\"");
+    }
 
-        target_machine
-            .write_to_file(module, inkwell::targets::FileType::Object, obj_path)
-            .map_err(|e| format!("Failed to write object file: {}", e))
-            .unwrap();
-        println!("Generated: {}", filename);
-        object_files.push(filename);
+    if compiler.target_os == OS::Avr {
+        log.status(format_args!(
+            "[Experimental] AVR objects written. Build the runtime with a nightly toolchain:\n  \
+             RUSTFLAGS=\"--cfg sprs_embedded --cfg sprs_avr -C target-cpu={}\" cargo +nightly build -p sprs-runtime \
+             --release --target avr-none -Z build-std=core,alloc\n\
+             then link the objects and libsprs_runtime.a with avr-gcc -mmcu={}",
+            AVR_CPU, AVR_CPU
+        ));
+        return true;
     }
 
-    println!("Compile runtime...");
+    log.status("Compile runtime...");
+    let started = Instant::now();
 
     let runtime_src_path = format!("{}/runtime.rs", out_dir);
     if let Err(e) = std::fs::write(&runtime_src_path, RUNTIME_SOURCE) {
         eprintln!("Failed to write runtime source: {}", e);
-        return;
+        return false;
+    }
+    if let Err(e) = std::fs::write(format!("{}/embedded.rs", out_dir), EMBEDDED_SOURCE) {
+        eprintln!("Failed to write runtime source: {}", e);
+        return false;
     }
 
     let runtime_lib_path = format!("{}/libruntime.a", out_dir);
 
-    let status_runtime = Command::new("rustc")
-        .args(&[
-            &runtime_src_path,
-            "--crate-type",
-            "staticlib",
-            "-o",
-            &runtime_lib_path,
-        ])
-        .status()
-        .expect("Failed to compile runtime");
+    let mut runtime_args = vec![
+        runtime_src_path.clone(),
+        // same edition as the sprs-runtime crate
+        "--edition".to_string(),
+        "2024".to_string(),
+        "--crate-type".to_string(),
+        "staticlib".to_string(),
+        "-o".to_string(),
+        runtime_lib_path.clone(),
+    ];
+    if compiler.target_os == OS::Wasm {
+        // needs `rustup target add wasm32-wasip1`
+        runtime_args.extend(["--target".to_string(), WASM_TRIPLE.to_string()]);
+    } else if compiler.target_os == OS::RiscV {
+        // needs `rustup target add riscv32imac-unknown-none-elf`
+        runtime_args.extend(
+            [
+                "--target",
+                RISCV_RUST_TARGET,
+                "--cfg",
+                "sprs_embedded",
+                "-C",
+                "panic=abort",
+                "-C",
+                "opt-level=s",
+            ]
+            .map(String::from),
+        );
+    } else if compiler.options.no_std {
+        runtime_args.extend(
+            [
+                "--cfg",
+                "sprs_embedded",
+                "-C",
+                "panic=abort",
+                "-C",
+                "opt-level=s",
+            ]
+            .map(String::from),
+        );
+    }
+    if compiler.options.debug_alloc {
+        runtime_args.extend(["--cfg".to_string(), "sprs_debug_alloc".to_string()]);
+    }
+    if let Some(allocator) = &allocator {
+        runtime_args.extend(["--cfg".to_string(), format!("sprs_alloc=\"{}\"", allocator)]);
+    }
+
+    let mut runtime_command = Command::new("rustc");
+    runtime_command.args(&runtime_args);
+    log.verbose(format_args!("rustc {}", runtime_args.join(" ")));
+    if let Some(size) = heap_size {
+        // read by `option_env!` in the runtime's `mod heap`
+        runtime_command.env("SPRS_HEAP_SIZE", size.to_string());
+    }
+    let status_runtime = runtime_command.status().expect("Failed to compile runtime");
 
     if !status_runtime.success() {
         eprintln!("Failed to compile runtime");
-        return;
+        return false;
+    }
+    log.timed("compile runtime", started);
+
+    if freestanding_host {
+        log.status(format_args!(
+            "Freestanding objects written. Link {} and {} with your startup code, which calls `main` \
+             and provides sprs_write, sprs_clock_ms and sprs_sleep_ms, plus memcpy, memset, bcmp and strlen \
+             (and libm for `import math;`)",
+            object_files.join(" "),
+            runtime_lib_path
+        ));
+        let mut link_args = command_helper::link_args(config.as_ref());
+        if let Some(script) = config
+            .as_ref()
+            .and_then(|c| c.link.as_ref())
+            .and_then(|l| l.linker_script.clone())
+        {
+            link_args.extend(["-T".to_string(), script]);
+        }
+        if !link_args.is_empty() {
+            log.status(format_args!("[link] in sprs.toml: {}", link_args.join(" ")));
+        }
+        return true;
     }
 
-    println!("Linking...");
+    log.status("Linking...");
 
-    if (cfg!(target_os = "windows") && compiler.target_os != OS::Windows)
-        || (cfg!(target_os = "linux") && compiler.target_os == OS::Windows)
+    if ((cfg!(target_os = "windows") && compiler.target_os != OS::Windows)
+        || (cfg!(target_os = "linux") && compiler.target_os == OS::Windows))
+        && compiler.target_os != OS::Wasm
+        && compiler.target_os != OS::RiscV
     {
-        println!(
-            "[Warning] Running machine and target machine differ: host = {}, target = {}. Because maybe the generated executable will not run correctly.",
+        log.warning(format_args!(
+            "Running machine and target machine differ: host = {}, target = {}. Because maybe the generated executable will not run correctly.",
             if cfg!(target_os = "windows") {
                 "Windows"
             } else {
@@ -157,46 +779,974 @@ pub fn build_and_run(_full_path: String, mode: ExecuteMode) {
             match compiler.target_os {
                 OS::Windows => "Windows",
                 OS::Linux => "Linux",
+                OS::Wasm => "Wasm",
+                OS::RiscV => "RiscV",
+                OS::Avr => "Avr",
                 OS::Unknown => "Unknown",
             }
-        );
+        ));
     }
 
     let exec_filename = match compiler.target_os {
         compiler::OS::Windows => {
             format!("{}.exe", proj_name)
         }
+        compiler::OS::Wasm => {
+            format!("{}.wasm", proj_name)
+        }
+        compiler::OS::RiscV => {
+            format!("{}.elf", proj_name)
+        }
         _ => proj_name.clone(),
     };
 
+    let link_config = config
+        .as_ref()
+        .and_then(|c| c.link.clone())
+        .unwrap_or_default();
+    // `--target` and `-fuse-ld` are clang's; a cross gcc already knows its target
+    let custom_linker = link_config.linker.is_some();
+    let linker = link_config.linker.as_deref().unwrap_or("clang");
+
     let mut args = object_files.clone();
     args.extend(vec![
         runtime_lib_path,
         "-o".to_string(),
         format!("{}/{}", out_dir, exec_filename),
-        "-lm".to_string(),
-        "-ldl".to_string(),
-        "-lpthread".to_string(),
     ]);
+    args.extend(command_helper::link_args(config.as_ref()));
 
-    let status_link = Command::new("clang")
-        .args(&args)
-        .status()
-        .expect("Failed to link");
-
-    if status_link.success() {
-        println!("Successfully created executable: ./{}", exec_filename);
-        if (mode == ExecuteMode::Run) || (mode == ExecuteMode::Build && false) {
-            println!("--- Running ---");
-            if compiler.target_os == OS::Linux
-                || (compiler.target_os == OS::Unknown || cfg!(target_os = "linux"))
-            {
-                let _ = Command::new(format!("./{}/{}", out_dir, exec_filename))
-                    .status()
-                    .expect("Failed to run executable");
+    let linker_script = match (&link_config.linker_script, compiler.target_os) {
+        (Some(script), _) => Some(script.as_str()),
+        (None, OS::RiscV) => Some(RISCV_LINKER_SCRIPT),
+        (None, _) => None,
+    };
+    if let Some(script) = linker_script {
+        if !Path::new(script).exists() {
+            if link_config.linker_script.is_some() {
+                eprintln!("Missing linker script {} from sprs.toml", script);
+            } else {
+                eprintln!(
+                    "Missing {}; create one with `sprs init --template embedded`",
+                    script
+                );
             }
+            return false;
+        }
+        args.extend(["-T".to_string(), script.to_string()]);
+    }
+
+    if compiler.target_os == OS::Wasm {
+        // clang needs a WASI sysroot (e.g. from wasi-sdk) to link wasm32-wasip1
+        if !custom_linker {
+            args.push(format!("--target={}", WASM_TRIPLE));
         }
+        if let Ok(sysroot) = std::env::var("WASI_SYSROOT") {
+            args.push(format!("--sysroot={}", sysroot));
+        }
+    } else if compiler.target_os == OS::RiscV {
+        if !custom_linker {
+            args.extend(["--target=riscv32-unknown-elf", "-fuse-ld=lld"].map(String::from));
+        }
+        args.extend(["-march=rv32imac", "-mabi=ilp32", "-nostdlib"].map(String::from));
     } else {
+        args.extend(vec![
+            "-lm".to_string(),
+            "-ldl".to_string(),
+            "-lpthread".to_string(),
+        ]);
+    }
+
+    let started = Instant::now();
+    log.verbose(format_args!("{} {}", linker, args.join(" ")));
+    let status_link = match Command::new(linker).args(&args).status() {
+        Ok(status) => status,
+        Err(e) => {
+            eprintln!("Failed to run the linker {}: {}", linker, e);
+            return false;
+        }
+    };
+
+    if !status_link.success() {
         println!("--- Skipped ---");
+        return false;
+    }
+    log.timed("link", started);
+    log.timed("build", build_started);
+
+    log.status(format_args!(
+        "Successfully created executable: ./{}",
+        exec_filename
+    ));
+    if compiler.target_os == OS::RiscV {
+        let flash = config.as_ref().and_then(|c| c.flash.clone());
+        let elf_path = format!("{}/{}", out_dir, exec_filename);
+        if let Err(e) = process_flash_image(flash.as_ref(), &elf_path, mode == ExecuteMode::Flash) {
+            eprintln!("{}", e);
+            return false;
+        }
+    }
+    if let ExecuteMode::Example { expected, runner } = &mode {
+        let image_path = format!("{}/{}", out_dir, exec_filename);
+        return run_example(&image_path, expected, runner.as_deref(), compiler.target_os);
+    }
+    if let ExecuteMode::Test { runner } = &mode {
+        let image_path = format!("{}/{}", out_dir, exec_filename);
+        return run_tests(
+            &compiler.test_names,
+            &image_path,
+            runner.as_deref(),
+            compiler.target_os,
+        );
+    }
+    if matches!(mode, ExecuteMode::Run | ExecuteMode::Watch)
+        || (mode == ExecuteMode::Build && false)
+    {
+        log.status("--- Running ---");
+        if compiler.target_os == OS::RiscV {
+            log.status(format_args!(
+                "Bare-metal image; flash {} to the board to run it (see `sprs flash`)",
+                exec_filename
+            ));
+        } else if compiler.target_os == OS::Wasm {
+            let mut command = Command::new("wasmtime");
+            command.arg(format!("{}/{}", out_dir, exec_filename));
+            if mode == ExecuteMode::Watch {
+                return watch::start(&mut command);
+            }
+            let status = command.status().expect("Failed to run wasmtime");
+            exit_with_program_status(status);
+        } else if compiler.target_os == OS::Linux
+            || (compiler.target_os == OS::Unknown || cfg!(target_os = "linux"))
+        {
+            // `./` keeps a relative out_dir from being looked up on PATH
+            let mut command = Command::new(Path::new(".").join(&out_dir).join(&exec_filename));
+            if mode == ExecuteMode::Watch {
+                return watch::start(&mut command);
+            }
+            let status = command.status().expect("Failed to run executable");
+            exit_with_program_status(status);
+        }
+    }
+    true
+}
+
+// `sprs run` exits with the status of the program (`return 1;` from main, `exit!(1)`),
+// so it can be used in shell pipelines and CI scripts.
+// The LLVM pass pipeline for -O0..-O3. Even -O0 runs mem2reg, every value lives in an alloca
+// until then.
+fn optimization_passes(opt_level: u8) -> &'static str {
+    match opt_level {
+        0 => "function(mem2reg)",
+        1 => "function(mem2reg,instcombine,simplifycfg)",
+        2 => {
+            "function(mem2reg,instcombine,gvn,simplifycfg),cgscc(inline),function(instcombine,simplifycfg)"
+        }
+        _ => "default<O3>",
+    }
+}
+
+// `[link] lto = true`: links every module into the main one before optimizing, so calls
+// across modules can be inlined. Only `main` and the `pub fn`s of main.sprs, which startup
+// code may call, stay exported; the other functions become internal and globaldce drops
+// the ones nothing calls.
+fn link_modules_for_lto(compiler: &mut compiler::Compiler<'_>) -> Result<(), String> {
+    let main_name = compiler
+        .declared_modules
+        .get("main")
+        .cloned()
+        .unwrap_or_else(|| "main".to_string());
+    let main = compiler
+        .modules
+        .remove(&main_name)
+        .ok_or_else(|| format!("Module '{}' not compiled", main_name))?;
+
+    let exported: HashSet<String> = main
+        .get_functions()
+        .filter(|f| f.count_basic_blocks() > 0 && f.get_linkage() == Linkage::External)
+        .map(|f| f.get_name().to_string_lossy().into_owned())
+        .collect();
+    for (name, module) in compiler.modules.drain() {
+        main.link_in_module(module)
+            .map_err(|e| format!("LTO: failed to link module {}: {}", name, e))?;
+    }
+    for function in main.get_functions() {
+        let name = function.get_name().to_string_lossy().into_owned();
+        if function.count_basic_blocks() > 0 && !exported.contains(&name) {
+            function.set_linkage(Linkage::Internal);
+        }
+    }
+
+    compiler.modules.insert(main_name, main);
+    Ok(())
+}
+
+fn exit_with_program_status(status: std::process::ExitStatus) {
+    match status.code() {
+        Some(0) => {}
+        Some(code) => std::process::exit(code),
+        // killed by a signal
+        None => std::process::exit(1),
+    }
+}
+
+// `sprs lint`: checks the entry file and every module it imports with the `[lint]` rules of
+// sprs.toml, plus the warnings a build reports. Returns false when there are errors.
+pub fn lint_project() -> bool {
+    let config = command_helper::load_project_config();
+    let src_path = config
+        .as_ref()
+        .map(|c| c.src_dir.clone())
+        .unwrap_or_else(|| "src".to_string());
+    let main_path = command_helper::entry_path(config.as_ref(), &src_path);
+    let dependencies = match command_helper::dependency_roots(config.as_ref()) {
+        Ok(dependencies) => dependencies,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let edition = match command_helper::project_edition(config.as_ref()) {
+        Ok(edition) => edition,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let table = config.map(|c| c.lint).unwrap_or_default();
+    let rules = match lint_rules::LintConfig::from_table(&table) {
+        Ok(rules) => rules,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let (diagnostics, module_count) =
+        check_project(&src_path, &main_path, &dependencies, Some(&rules), edition);
+    if diagnostics.is_empty() {
+        println!("No lint findings in {} module(s)", module_count);
+        return true;
+    }
+    diagnostics.emit();
+    eprintln!(
+        "lint: {} error(s), {} warning(s)",
+        diagnostics.error_count(),
+        diagnostics.warning_count()
+    );
+    !diagnostics.has_errors()
+}
+
+// `sprs fix`: applies the suggested fixes (missing `;`, `_` prefix for unused names) to the
+// source files, re-checking until nothing is left to fix. Returns false on a write error.
+pub fn fix_project() -> bool {
+    let Some((src_path, main_path, dependencies, edition)) = project_sources() else {
+        return false;
+    };
+
+    // a fixed `;` can let the parser get further and find the next one
+    const MAX_PASSES: usize = 8;
+    let mut total = 0;
+    for _ in 0..MAX_PASSES {
+        let (diagnostics, _) = check_project(&src_path, &main_path, &dependencies, None, edition);
+        let mut applied_this_pass = 0;
+        for (path, fixes) in diagnostics.fixes_by_file() {
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let (fixed, applied) = apply_fixes(&source, &fixes);
+            if applied == 0 {
+                continue;
+            }
+            if let Err(e) = std::fs::write(&path, fixed) {
+                eprintln!("Failed to write {}: {}", path, e);
+                return false;
+            }
+            println!("Fixed {} issue(s) in {}", applied, path);
+            applied_this_pass += applied;
+        }
+        total += applied_this_pass;
+        if applied_this_pass == 0 {
+            break;
+        }
+    }
+
+    if total == 0 {
+        println!("Nothing to fix");
+    }
+    let (remaining, _) = check_project(&src_path, &main_path, &dependencies, None, edition);
+    if !remaining.is_empty() {
+        remaining.emit();
+    }
+    true
+}
+
+// `sprs index`: writes the definitions and references of the project to `output`,
+// `<out_dir>/index.json` by default.
+pub fn index_project(output: Option<&str>) -> bool {
+    let Some((src_path, main_path, dependencies, edition)) = project_sources() else {
+        return false;
+    };
+    let out_dir = command_helper::load_project_config()
+        .map(|c| c.out_dir)
+        .unwrap_or_else(|| "build".to_string());
+
+    let files = resolve::load_project(&src_path, &main_path, &dependencies, edition);
+    let index = match files.and_then(|files| index::build_index(&files)) {
+        Ok(index) => index,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let path = match output {
+        Some(path) => path.to_string(),
+        None => {
+            if let Err(e) = std::fs::create_dir_all(&out_dir) {
+                eprintln!("Failed to create {}: {}", out_dir, e);
+                return false;
+            }
+            format!("{}/index.json", out_dir)
+        }
+    };
+    let json = match serde_json::to_string_pretty(&index) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("Failed to serialize the index: {}", e);
+            return false;
+        }
+    };
+    if let Err(e) = std::fs::write(&path, json) {
+        eprintln!("Failed to write {}: {}", path, e);
+        return false;
+    }
+    println!(
+        "Indexed {} definition(s) and reference(s) in {} file(s): {}",
+        index.entries.len(),
+        index.files.len(),
+        path
+    );
+    true
+}
+
+// `sprs fuzz-grammar`: runs `iterations` generated programs through the compiler and writes a
+// minimized reproducer for each distinct panic to `<out_dir>/fuzz/crash-N.sprs`.
+// Returns false when a panic was found.
+pub fn fuzz_grammar(iterations: usize, seed: Option<u64>) -> bool {
+    let out_dir = command_helper::load_project_config()
+        .map(|c| c.out_dir)
+        .unwrap_or_else(|| "build".to_string());
+    let fuzz_dir = Path::new(&out_dir).join("fuzz");
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(1, |d| d.as_nanos() as u64)
+    });
+
+    println!("Fuzzing with {} case(s), seed {}", iterations, seed);
+    let crashes = match fuzz::fuzz(iterations, seed, &fuzz_dir.join("work")) {
+        Ok(crashes) => crashes,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let _ = std::fs::remove_dir_all(fuzz_dir.join("work"));
+
+    if crashes.is_empty() {
+        println!("No panics in {} case(s)", iterations);
+        return true;
+    }
+    for (idx, crash) in crashes.iter().enumerate() {
+        let path = fuzz_dir.join(format!("crash-{}.sprs", idx + 1));
+        if let Err(e) = std::fs::write(&path, &crash.source) {
+            eprintln!("Failed to write {}: {}", path.display(), e);
+        }
+        eprintln!(
+            "panic: {}
+  reproducer: {} (from --seed {} --iterations 1)
+{}
+",
+            crash.message,
+            path.display(),
+            crash.seed,
+            crash.source
+        );
+    }
+    eprintln!("fuzz-grammar: {} distinct panic(s)", crashes.len());
+    false
+}
+
+// `sprs migrate`: rewrites the sources for the next edition, then sets it in sprs.toml.
+pub fn migrate_project() -> bool {
+    let Some((src_path, main_path, dependencies, from)) = project_sources() else {
+        return false;
+    };
+    let migration = resolve::load_project(&src_path, &main_path, &dependencies, from)
+        .and_then(|files| migrate::plan(&files, from));
+    let migration = match migration {
+        Ok(migration) => migration,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    for (path, edits) in &migration.edits {
+        let source = match std::fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path, e);
+                return false;
+            }
+        };
+        let (migrated, _) = apply_fixes(&source, edits);
+        if let Err(e) = std::fs::write(path, migrated) {
+            eprintln!("Failed to write {}: {}", path, e);
+            return false;
+        }
+    }
+    if let Err(e) = command_helper::set_project_edition(migration.to) {
+        eprintln!("{}", e);
+        return false;
+    }
+
+    for (old, new, count) in &migration.renamed {
+        println!(
+            "Renamed `{}` to `{}`: {} occurrence(s), `{}` is a keyword now",
+            old, new, count, old
+        );
+    }
+    println!(
+        "Migrated {} file(s) from edition {} to {}",
+        migration.edits.len(),
+        from.name(),
+        migration.to.name()
+    );
+    true
+}
+
+// `sprs rename --symbol <old> --to <new> <file>`: `old` is looked up in `file`, then every
+// reference in the project is rewritten.
+pub fn rename_symbol(old: &str, new: &str, file: &str) -> bool {
+    let Some((src_path, main_path, dependencies, edition)) = project_sources() else {
+        return false;
+    };
+
+    let files = match resolve::load_project(&src_path, &main_path, &dependencies, edition) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let wanted = std::fs::canonicalize(file).ok();
+    let Some(target) = files
+        .iter()
+        .position(|f| wanted.is_some() && std::fs::canonicalize(&f.path).ok() == wanted)
+    else {
+        eprintln!("{} is not {} or a module it imports", file, main_path);
+        return false;
+    };
+
+    let plan = match rename::plan(&files, target, old, new, &src_path) {
+        Ok(plan) => plan,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut total = 0;
+    for (path, edits) in &plan.edits {
+        let source = match std::fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("Failed to read {}: {}", path, e);
+                return false;
+            }
+        };
+        let (renamed, applied) = apply_fixes(&source, edits);
+        if let Err(e) = std::fs::write(path, renamed) {
+            eprintln!("Failed to write {}: {}", path, e);
+            return false;
+        }
+        total += applied;
+    }
+
+    if let Some((from, to)) = &plan.moved {
+        if let Some(dir) = Path::new(to).parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        if let Err(e) = std::fs::rename(from, to) {
+            eprintln!("Failed to move {} to {}: {}", from, to, e);
+            return false;
+        }
+        println!("Moved {} to {}", from, to);
+    }
+
+    println!(
+        "Renamed {} `{}` to `{}`: {} occurrence(s) in {} file(s)",
+        plan.kind.describe(),
+        old,
+        new,
+        total,
+        plan.edits.len()
+    );
+    true
+}
+
+// `sprs doc`: a page per package of the project in <out_dir>/doc (or `output`) and an index
+// page linking them. Dependencies document themselves.
+pub fn doc_project(format: DocFormat, output: Option<&str>, private: bool) -> bool {
+    let Some((src_path, main_path, dependencies, edition)) = project_sources() else {
+        return false;
+    };
+    let doc_dir = match output {
+        Some(dir) => dir.to_string(),
+        None => {
+            let out_dir = command_helper::load_project_config()
+                .map(|c| c.out_dir)
+                .unwrap_or_else(|| "build".to_string());
+            format!("{}/doc", out_dir)
+        }
+    };
+
+    let files = resolve::load_project(&src_path, &main_path, &dependencies, edition);
+    let packages = files.and_then(|files| {
+        files
+            .iter()
+            .map(|file| doc::package_doc(file, private))
+            .collect::<Result<Vec<_>, _>>()
+    });
+    let packages = match packages {
+        Ok(packages) => packages,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    if let Err(e) = std::fs::create_dir_all(&doc_dir) {
+        eprintln!("Failed to create {}: {}", doc_dir, e);
+        return false;
+    }
+    let ext = format.extension();
+    let mut pages: Vec<(String, String)> = packages
+        .iter()
+        .map(|package| {
+            let path = format!("{}/{}.{}", doc_dir, package.module, ext);
+            (path, doc::render_page(package, format))
+        })
+        .collect();
+    let index_path = format!("{}/index.{}", doc_dir, ext);
+    pages.push((index_path.clone(), doc::render_index(&packages, format)));
+    for (path, content) in &pages {
+        if let Err(e) = std::fs::write(path, content) {
+            eprintln!("Failed to write {}: {}", path, e);
+            return false;
+        }
+    }
+    println!("Documented {} package(s): {}", packages.len(), index_path);
+    true
+}
+
+// `sprs dump`: prints the tokens and/or the AST of a single file, after `#if` processing with
+// the edition of sprs.toml (when there is one). Every token and item comes with its byte span.
+pub fn dump_file(file: &str, tokens: bool, ast: bool) -> bool {
+    let config = command_helper::load_project_config();
+    let edition = match command_helper::project_edition(config.as_ref()) {
+        Ok(edition) => edition,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    let source = match std::fs::read_to_string(file) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", file, e);
+            return false;
+        }
+    };
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.add_source(file, &source);
+    let mut symbols = compiler::project_symbols(file, &[], None, edition);
+    let text = match preprocess::preprocess(&source, file, &mut symbols) {
+        Ok(text) => text,
+        Err(errors) => {
+            diagnostics.extend(errors);
+            diagnostics.emit();
+            return false;
+        }
+    };
+
+    let mut ok = true;
+    if tokens {
+        for token in Lexer::with_edition(&text, edition) {
+            match token {
+                Ok((start, token, end)) => {
+                    let (line, col) = line_col(&source, start);
+                    println!(
+                        "{}:{} {}..{} {:?} {:?}",
+                        line,
+                        col,
+                        start,
+                        end,
+                        token,
+                        &text[start..end]
+                    );
+                }
+                Err(e) => {
+                    eprintln!("{}: {}", file, e);
+                    ok = false;
+                }
+            }
+        }
+    }
+    if ast {
+        match parse_module_spanned(&text, file, edition) {
+            Ok(items) => {
+                for (item, span) in items {
+                    let (line, col) = line_col(&source, span.start);
+                    println!("{}:{} {}..{} {:#?}", line, col, span.start, span.end, item);
+                }
+            }
+            Err(errors) => {
+                diagnostics.extend(errors);
+                diagnostics.emit();
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+// The source directory, entry file, dependency roots and edition from sprs.toml, for the
+// commands that only read sources. None (after printing why) when the edition is not one this
+// compiler knows or a dependency is missing.
+pub fn project_sources() -> Option<(String, String, HashMap<String, String>, Edition)> {
+    let config = command_helper::load_project_config();
+    let edition = command_helper::project_edition(config.as_ref())
+        .map_err(|e| eprintln!("{}", e))
+        .ok()?;
+    let dependencies = command_helper::dependency_roots(config.as_ref())
+        .map_err(|e| eprintln!("{}", e))
+        .ok()?;
+    let src_path = config
+        .as_ref()
+        .map(|c| c.src_dir.clone())
+        .unwrap_or_else(|| "src".to_string());
+    let main_path = command_helper::entry_path(config.as_ref(), &src_path);
+    Some((src_path, main_path, dependencies, edition))
+}
+
+// Parses the entry file and every module it imports and runs the build-time lint on them, plus
+// the `sprs lint` rules when given. Also returns how many modules were checked. The modules
+// of dependencies belong to their own projects and are left out.
+fn check_project(
+    src_path: &str,
+    main_path: &str,
+    dependencies: &HashMap<String, String>,
+    rules: Option<&lint_rules::LintConfig>,
+    edition: Edition,
+) -> (Diagnostics, usize) {
+    let mut diagnostics = Diagnostics::new();
+    let mut symbols = compiler::project_symbols(main_path, &[], None, edition);
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from([main_path.to_string()]);
+    while let Some(path) = queue.pop_front() {
+        if !seen.insert(path.clone()) {
+            continue;
+        }
+        let source = match std::fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                diagnostics.push(Diagnostic::error(format!(
+                    "Failed to read module file {}: {}",
+                    path, e
+                )));
+                continue;
+            }
+        };
+        diagnostics.add_source(&path, &source);
+
+        // spans into the preprocessed text are spans into the file, so fixes still apply
+        let text = match preprocess::preprocess(&source, &path, &mut symbols) {
+            Ok(text) => text,
+            Err(errors) => {
+                diagnostics.extend(errors);
+                continue;
+            }
+        };
+        let items = match parse_module(&text, &path, edition) {
+            Ok(items) => items,
+            Err(errors) => {
+                diagnostics.extend(errors);
+                continue;
+            }
+        };
+        for item in &items {
+            if let ast::Item::Import(import) = item {
+                if !compiler::is_builtin_package(src_path, &import.module)
+                    && compiler::dependency_module_path(dependencies, &import.module).is_none()
+                {
+                    queue.push_back(compiler::module_path(src_path, &import.module));
+                }
+            }
+        }
+
+        diagnostics.extend(lint::lint_module(&items, &path));
+        if let Some(rules) = rules {
+            diagnostics.extend(lint_rules::check_module(&items, &path, rules));
+        }
+    }
+    (diagnostics, seen.len())
+}
+
+// Writes the `.bin`/`.hex` images listed in `[flash] formats` next to the linked ELF,
+// then runs `[flash] command` with `{file}` replaced when `flash` is set.
+fn process_flash_image(
+    config: Option<&FlashConfig>,
+    elf_path: &str,
+    flash: bool,
+) -> Result<(), String> {
+    let Some(config) = config else {
+        return if flash {
+            Err("Missing [flash] section in sprs.toml".to_string())
+        } else {
+            Ok(())
+        };
+    };
+
+    let stem = elf_path.strip_suffix(".elf").unwrap_or(elf_path);
+    for format in &config.formats {
+        let objcopy_format = match format.as_str() {
+            "bin" => "binary",
+            "hex" => "ihex",
+            other => {
+                return Err(format!(
+                    "Unknown flash image format: {} (available: bin, hex)",
+                    other
+                ));
+            }
+        };
+        let image_path = format!("{}.{}", stem, format);
+        let status = Command::new(&config.objcopy)
+            .args(["-O", objcopy_format, elf_path, &image_path])
+            .status()
+            .map_err(|e| format!("Failed to run {}: {}", config.objcopy, e))?;
+        if !status.success() {
+            return Err(format!("{} failed for {}", config.objcopy, image_path));
+        }
+        println!("Generated: {}", image_path);
+    }
+
+    if !flash {
+        return Ok(());
+    }
+
+    let artifact = config.artifact.as_deref().unwrap_or("elf");
+    let file = match artifact {
+        "elf" => elf_path.to_string(),
+        "bin" | "hex" if config.formats.iter().any(|f| f == artifact) => {
+            format!("{}.{}", stem, artifact)
+        }
+        "bin" | "hex" => {
+            return Err(format!(
+                "flash.artifact is \"{}\" but it is not listed in flash.formats",
+                artifact
+            ));
+        }
+        other => {
+            return Err(format!(
+                "Unknown flash artifact: {} (available: elf, bin, hex)",
+                other
+            ));
+        }
+    };
+    let Some(command) = &config.command else {
+        return Err("Missing flash.command in sprs.toml".to_string());
+    };
+    let command = command.replace("{file}", &file);
+
+    println!("--- Flashing ---");
+    println!("{}", command);
+    let status = shell_command(&command)
+        .status()
+        .map_err(|e| format!("Failed to run flash command: {}", e))?;
+    if !status.success() {
+        return Err("Flash command failed".to_string());
+    }
+    Ok(())
+}
+
+// A user-configured command line (flash.command, --runner) run through the system shell.
+fn shell_command(command: &str) -> Command {
+    let mut cmd = if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    cmd.arg(command);
+    cmd
+}
+
+// How a test image is started: through `--runner` with `{file}` replaced by the image path
+// (appended when missing), wasmtime for Wasm, or directly.
+fn image_command(image_path: &str, runner: Option<&str>, target_os: OS) -> Result<Command, String> {
+    match runner {
+        Some(runner) if runner.contains("{file}") => {
+            Ok(shell_command(&runner.replace("{file}", image_path)))
+        }
+        Some(runner) => Ok(shell_command(&format!("{} {}", runner, image_path))),
+        None if matches!(target_os, OS::RiscV | OS::Avr) => Err(
+            "Bare-metal test images need --runner <cmd> to flash and read the board".to_string(),
+        ),
+        None if target_os == OS::Wasm => {
+            let mut cmd = Command::new("wasmtime");
+            cmd.arg(image_path);
+            Ok(cmd)
+        }
+        // `./` keeps a relative out_dir from being looked up on PATH
+        None => Ok(Command::new(Path::new(".").join(image_path))),
+    }
+}
+
+// Runs an example program and compares its standard output with `expected`, ignoring line
+// endings and trailing whitespace. The exit status is not compared; only a program killed by
+// a signal fails on its own.
+fn run_example(image_path: &str, expected: &str, runner: Option<&str>, target_os: OS) -> bool {
+    let output = match image_command(image_path, runner, target_os).and_then(|mut command| {
+        command
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", image_path, e))
+    }) {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    if output.status.code().is_none() {
+        eprintln!("{} was killed: {}", image_path, output.status);
+        return false;
+    }
+
+    let actual = String::from_utf8_lossy(&output.stdout).replace("\r\n", "\n");
+    let expected = expected.replace("\r\n", "\n");
+    if actual.trim_end() == expected.trim_end() {
+        return true;
+    }
+    let actual: Vec<&str> = actual.trim_end().lines().collect();
+    let expected: Vec<&str> = expected.trim_end().lines().collect();
+    let line = actual
+        .iter()
+        .zip(&expected)
+        .position(|(a, e)| a != e)
+        .unwrap_or(actual.len().min(expected.len()));
+    println!(
+        "output differs from the .expected file at line {}:",
+        line + 1
+    );
+    println!(
+        "  expected: {}",
+        expected.get(line).unwrap_or(&"<end of output>")
+    );
+    println!(
+        "  actual:   {}",
+        actual.get(line).unwrap_or(&"<end of output>")
+    );
+    false
+}
+
+// Runs a `sprs test` image and reads the results back from the runtime's test protocol
+// (`SPRS-TEST BEGIN/PASS/FAIL/DONE` lines, see runtime.rs). With `--runner` the image is
+// started by that command instead, e.g. a script that flashes a board and forwards its
+// serial output, with `{file}` replaced by the image path (appended when missing).
+fn run_tests(tests: &[String], image_path: &str, runner: Option<&str>, target_os: OS) -> bool {
+    let mut command = match image_command(image_path, runner, target_os) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    println!("--- Running {} tests ---", tests.len());
+    let mut child = match command.stdout(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!("Failed to start test runner: {}", e);
+            return false;
+        }
+    };
+
+    let mut passed = 0;
+    let mut current: Option<String> = None;
+    let mut failure: Option<String> = None;
+    let mut finished = false;
+
+    let stdout = child.stdout.take().expect("test runner stdout is piped");
+    for line in BufReader::new(stdout).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        // a serial console may prefix lines with noise or end them with '\r'
+        let Some(pos) = line.find("SPRS-TEST ") else {
+            println!("{}", line);
+            continue;
+        };
+        let message = line[pos + "SPRS-TEST ".len()..].trim_end();
+
+        if let Some(name) = message.strip_prefix("BEGIN ") {
+            current = Some(name.to_string());
+        } else if let Some(name) = message.strip_prefix("PASS ") {
+            println!("test {} ... ok", name);
+            passed += 1;
+            current = None;
+        } else if let Some(rest) = message.strip_prefix("FAIL ") {
+            let (name, reason) = rest.split_once(": ").unwrap_or((rest, ""));
+            println!("test {} ... FAILED", name);
+            failure = Some(format!("{}: {}", name, reason));
+            break;
+        } else if message.starts_with("DONE") {
+            finished = true;
+            break;
+        }
+    }
+
+    // a board keeps running after the last line, so the runner is stopped here
+    let _ = child.kill();
+    let _ = child.wait();
+
+    if failure.is_none() && !finished {
+        failure = Some(match current {
+            Some(name) => {
+                println!("test {} ... FAILED", name);
+                format!("{}: test image stopped before the test finished", name)
+            }
+            None => "test image stopped before reporting all results".to_string(),
+        });
+    }
+
+    let failed = usize::from(failure.is_some());
+    let not_run = tests.len().saturating_sub(passed + failed);
+    if let Some(failure) = &failure {
+        println!("\nfailures:\n    {}", failure);
     }
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} not run",
+        if failure.is_none() { "ok" } else { "FAILED" },
+        passed,
+        failed,
+        not_run
+    );
+    failure.is_none()
 }
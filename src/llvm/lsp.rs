@@ -0,0 +1,528 @@
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use inkwell::context::Context;
+use serde_json::{Value, json};
+
+use crate::command_helper::BuildOptions;
+use crate::front::ast::visit::{self, Visitor};
+use crate::front::ast::{self, Span};
+use crate::front::diagnostics::{Diagnostic, Severity};
+use crate::interpreter::type_helper::Type;
+use crate::llvm::index::{self, Entry};
+use crate::llvm::llvm_executer::project_sources;
+use crate::llvm::resolve::{self, SourceFile};
+use crate::llvm::{compiler, doc};
+
+// `sprs lsp`: a Language Server Protocol server on stdin/stdout. It publishes the diagnostics
+// of `sprs check` when a file is opened or saved, goes to the definition of functions, structs,
+// modules and variables with the resolver behind `sprs index`, and shows on hover the signature
+// and doc comment of a function or struct, or the runtime type a `var` starts with. It reads the
+// files as saved, so positions in a buffer with unsaved edits can be off.
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Default)]
+struct Server {
+    published: HashSet<String>, // URIs that have diagnostics in the editor
+    shutdown: bool,
+}
+
+// Returns whether the client asked for a shutdown before `exit`, as the protocol wants.
+pub fn run() -> bool {
+    let stdin = std::io::stdin();
+    let mut input = stdin.lock();
+    let mut server = Server::default();
+    loop {
+        let message = match read_message(&mut input) {
+            Ok(Some(message)) => message,
+            Ok(None) => return server.shutdown,
+            Err(e) => {
+                eprintln!("sprs lsp: {}", e);
+                return false;
+            }
+        };
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let result = match method {
+            "initialize" => Some(initialize(params)),
+            "shutdown" => {
+                server.shutdown = true;
+                Some(Ok(Value::Null))
+            }
+            "exit" => return server.shutdown,
+            "textDocument/didOpen" | "textDocument/didSave" => {
+                let checked = guarded(|| {
+                    server.check();
+                    Ok(())
+                });
+                checked.unwrap_or_else(|e| eprintln!("sprs lsp: {}", e));
+                None
+            }
+            "textDocument/definition" => Some(guarded(|| definition(params))),
+            "textDocument/hover" => Some(guarded(|| hover(params))),
+            _ => None,
+        };
+
+        // notifications get no response
+        let Some(id) = message.get("id").cloned() else {
+            continue;
+        };
+        let response = match result {
+            Some(Ok(result)) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Some(Err(e)) => error_response(id, INTERNAL_ERROR, &e),
+            None => error_response(id, METHOD_NOT_FOUND, &format!("Unsupported: {}", method)),
+        };
+        send(&response);
+    }
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+// A compiler panic fails the request instead of ending the server.
+fn guarded<T>(request: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(request)).unwrap_or_else(|_| {
+        Err("the compiler crashed on this project; run `sprs check` for a crash report".to_string())
+    })
+}
+
+// One message: `Content-Length` header lines, a blank line, then the JSON body.
+fn read_message(input: &mut impl BufRead) -> Result<Option<Value>, String> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() && length.is_some() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            let value = value.trim();
+            length = Some(
+                value
+                    .parse::<usize>()
+                    .map_err(|_| format!("Invalid Content-Length: {}", value))?,
+            );
+        }
+    }
+    let mut body = vec![0; length.unwrap_or_default()];
+    input.read_exact(&mut body).map_err(|e| e.to_string())?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| format!("Invalid message: {}", e))
+}
+
+fn send(message: &Value) {
+    let body = message.to_string();
+    let mut out = std::io::stdout().lock();
+    let _ = write!(out, "Content-Length: {}\r\n\r\n{}", body.len(), body);
+    let _ = out.flush();
+}
+
+fn initialize(params: &Value) -> Result<Value, String> {
+    // sprs.toml and the sources are looked up relative to the workspace
+    let root = params["rootUri"]
+        .as_str()
+        .and_then(uri_to_path)
+        .or_else(|| params["rootPath"].as_str().map(PathBuf::from));
+    if let Some(root) = root {
+        std::env::set_current_dir(&root)
+            .map_err(|e| format!("Failed to open {}: {}", root.display(), e))?;
+    }
+    Ok(json!({
+        "capabilities": {
+            "textDocumentSync": { "openClose": true, "change": 0, "save": true },
+            "definitionProvider": true,
+            "hoverProvider": true,
+        },
+        "serverInfo": { "name": "sprs", "version": env!("CARGO_PKG_VERSION") },
+    }))
+}
+
+impl Server {
+    // Checks the project like `sprs check` and replaces the diagnostics of every file.
+    fn check(&mut self) {
+        let Some((src_path, main_path, dependencies, edition)) = project_sources() else {
+            return;
+        };
+        let context = Context::create();
+        let options = BuildOptions {
+            edition,
+            ..Default::default()
+        };
+        let mut compiler =
+            compiler::Compiler::new(&context, context.create_builder(), src_path, options);
+        compiler.dependencies = dependencies;
+        // the errors are in `compiler.diagnostics`
+        let _ = compiler.load_and_compile_module("main", Some(&main_path));
+
+        let mut by_uri: HashMap<String, Vec<Value>> = HashMap::new();
+        for diagnostic in compiler.diagnostics.iter() {
+            let file = diagnostic.file.as_deref().unwrap_or(&main_path);
+            by_uri
+                .entry(path_to_uri(file))
+                .or_default()
+                .push(lsp_diagnostic(
+                    diagnostic,
+                    compiler.diagnostics.source(file),
+                ));
+        }
+        // files that are clean now still show the old diagnostics until they are cleared
+        for uri in self.published.drain() {
+            by_uri.entry(uri).or_default();
+        }
+        for (uri, diagnostics) in by_uri {
+            if !diagnostics.is_empty() {
+                self.published.insert(uri.clone());
+            }
+            send(&json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/publishDiagnostics",
+                "params": { "uri": uri, "diagnostics": diagnostics },
+            }));
+        }
+    }
+}
+
+fn lsp_diagnostic(diagnostic: &Diagnostic, source: Option<&str>) -> Value {
+    let span = diagnostic
+        .labels
+        .iter()
+        .find(|l| l.primary)
+        .or(diagnostic.labels.first())
+        .map(|l| l.span)
+        .unwrap_or_default();
+    let mut value = json!({
+        "range": range(source.unwrap_or_default(), span),
+        "severity": match diagnostic.severity {
+            Severity::Error => 1,
+            Severity::Warning => 2,
+        },
+        "source": "sprs",
+        "message": diagnostic.message,
+    });
+    if let Some(code) = diagnostic.code {
+        value["code"] = json!(code);
+    }
+    value
+}
+
+// The project files and index of a definition or hover request.
+struct Lookup {
+    files: Vec<SourceFile>,
+    entries: Vec<Entry>,
+    at: usize, // the entry under the cursor
+}
+
+// None when the file is not part of the project, does not parse or there is no name there.
+fn entry_at(params: &Value) -> Result<Option<Lookup>, String> {
+    let path = params["textDocument"]["uri"]
+        .as_str()
+        .and_then(uri_to_path)
+        .ok_or("Missing textDocument.uri")?;
+    let line = params["position"]["line"].as_u64().unwrap_or_default() as usize;
+    let character = params["position"]["character"].as_u64().unwrap_or_default() as usize;
+
+    let Some((src_path, main_path, dependencies, edition)) = project_sources() else {
+        return Ok(None);
+    };
+    let files = match resolve::load_project(&src_path, &main_path, &dependencies, edition) {
+        Ok(files) => files,
+        Err(e) => {
+            // the diagnostics already show why
+            eprintln!("sprs lsp: {}", e);
+            return Ok(None);
+        }
+    };
+    let wanted = std::fs::canonicalize(&path).ok();
+    let Some(file) = files
+        .iter()
+        .find(|f| wanted.is_some() && std::fs::canonicalize(&f.path).ok() == wanted)
+    else {
+        return Ok(None);
+    };
+    let offset = offset_of(&file.active, line, character);
+    let entries = index::build_index(&files)?.entries;
+    let at = entries
+        .iter()
+        .position(|e| e.file == file.path && e.start <= offset && offset <= e.end);
+    Ok(at.map(|at| Lookup { files, entries, at }))
+}
+
+// Where `entry` is defined: the last definition before it in its file (a variable can be
+// declared again), else the first one anywhere.
+fn definition_of<'a>(entries: &'a [Entry], entry: &Entry) -> Option<&'a Entry> {
+    let mut definitions = entries
+        .iter()
+        .filter(|e| e.definition && e.symbol == entry.symbol);
+    definitions
+        .clone()
+        .rfind(|e| e.file == entry.file && e.start <= entry.start)
+        .or_else(|| definitions.next())
+}
+
+fn definition(params: &Value) -> Result<Value, String> {
+    let Some(Lookup { files, entries, at }) = entry_at(params)? else {
+        return Ok(Value::Null);
+    };
+    let entry = &entries[at];
+    let Some(target) = definition_of(&entries, entry) else {
+        return Ok(Value::Null);
+    };
+    let source = source_of(&files, &target.file);
+    Ok(json!({
+        "uri": path_to_uri(&target.file),
+        "range": range(source, Span { start: target.start, end: target.end }),
+    }))
+}
+
+fn hover(params: &Value) -> Result<Value, String> {
+    let Some(Lookup { files, entries, at }) = entry_at(params)? else {
+        return Ok(Value::Null);
+    };
+    let entry = &entries[at];
+    let text = match entry.kind {
+        "function" => {
+            let (module, name) = entry.symbol.rsplit_once('.').unwrap_or(("", &entry.symbol));
+            item_doc(files.iter().filter(|f| f.module == module), name)
+                .unwrap_or_else(|| format!("function `{}`", entry.symbol))
+        }
+        "struct" => item_doc(files.iter(), &entry.symbol)
+            .unwrap_or_else(|| format!("struct `{}`", entry.symbol)),
+        "module" => {
+            let doc = files
+                .iter()
+                .find(|f| f.module == entry.symbol)
+                .and_then(|f| doc::package_doc(f, true).ok())
+                .map(|package| package.doc)
+                .unwrap_or_default();
+            join_doc(format!("module `{}`", entry.symbol), &doc)
+        }
+        "parameter" => format!(
+            "parameter `{}`\n\nIts runtime type is that of the argument.",
+            last_segment(&entry.symbol)
+        ),
+        _ => {
+            let name = last_segment(&entry.symbol);
+            let ty =
+                definition_of(&entries, entry).and_then(|d| initial_type(&files, &d.file, d.start));
+            match ty {
+                Some(ty) => format!("```sprs\nvar {}\n```\n\nStarts as `{}`.", name, ty),
+                None => format!(
+                    "```sprs\nvar {}\n```\n\nIts runtime type is only known when the program runs.",
+                    name
+                ),
+            }
+        }
+    };
+    let source = source_of(&files, &entry.file);
+    Ok(json!({
+        "contents": { "kind": "markdown", "value": text },
+        "range": range(source, Span { start: entry.start, end: entry.end }),
+    }))
+}
+
+// The signature and doc comment of the fn, extern fn, struct or enum `name` of `files`.
+fn item_doc<'a>(files: impl Iterator<Item = &'a SourceFile>, name: &str) -> Option<String> {
+    files
+        .filter_map(|f| doc::package_doc(f, true).ok())
+        .flat_map(|package| package.items)
+        .find(|item| item.name == name)
+        .map(|item| join_doc(format!("```sprs\n{}\n```", item.signature), &item.doc))
+}
+
+fn join_doc(header: String, doc: &str) -> String {
+    if doc.is_empty() {
+        header
+    } else {
+        format!("{}\n\n{}", header, doc)
+    }
+}
+
+fn last_segment(symbol: &str) -> &str {
+    symbol.rsplit('.').next().unwrap_or(symbol)
+}
+
+fn source_of<'a>(files: &'a [SourceFile], path: &str) -> &'a str {
+    files
+        .iter()
+        .find(|f| f.path == path)
+        .map_or("", |f| f.active.as_str())
+}
+
+// The runtime type of the value of the `var` whose name starts at `start` in `path`.
+fn initial_type(files: &[SourceFile], path: &str, start: usize) -> Option<String> {
+    let file = files.iter().find(|f| f.path == path)?;
+    let mut finder = FindVar { start, found: None };
+    visit::walk_items(&mut finder, &file.items);
+    runtime_type(finder.found?.expr.as_ref()?, file)
+}
+
+struct FindVar<'ast> {
+    start: usize,
+    found: Option<&'ast ast::VarDecl>,
+}
+
+impl<'ast> Visitor<'ast> for FindVar<'ast> {
+    fn visit_var_decl(&mut self, var: &'ast ast::VarDecl) {
+        if var.ident_span.start == self.start {
+            self.found = Some(var);
+        }
+        visit::walk_var_decl(self, var);
+    }
+}
+
+// The type, named as `type_of!` names it, when it follows from the expression alone.
+fn runtime_type(expr: &ast::Expr, file: &SourceFile) -> Option<String> {
+    use ast::Expr;
+    let name = match expr {
+        Expr::Number(_) => "Int",
+        Expr::Float(_) => "Float",
+        Expr::Str(_) => "Str",
+        Expr::Char(_) => "Char",
+        Expr::Bool(_)
+        | Expr::Eq(..)
+        | Expr::Neq(..)
+        | Expr::Lt(..)
+        | Expr::Gt(..)
+        | Expr::Le(..)
+        | Expr::Ge(..)
+        | Expr::In(..) => "Bool",
+        Expr::List(_) => "List",
+        Expr::Range(..) => "Range",
+        Expr::Unit() => "Unit",
+        Expr::StructInit(name, _) => return Some(format!("Struct ({})", name)),
+        // `+` also joins strings
+        Expr::Add(lhs, rhs, _) => {
+            let lhs = runtime_type(lhs, file)?;
+            return (lhs == "Str" || Some(&lhs) == runtime_type(rhs, file).as_ref()).then_some(lhs);
+        }
+        Expr::Mul(lhs, rhs, _)
+        | Expr::Minus(lhs, rhs, _)
+        | Expr::Div(lhs, rhs, _)
+        | Expr::Mod(lhs, rhs, _) => {
+            let lhs = runtime_type(lhs, file)?;
+            return (Some(&lhs) == runtime_type(rhs, file).as_ref()).then_some(lhs);
+        }
+        Expr::Call(name, _, _, _) => {
+            return file.items.iter().find_map(|item| match item {
+                ast::Item::FunctionItem(f) if f.ident == *name => type_name(f.ret_ty.as_ref()?),
+                _ => None,
+            });
+        }
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    let name = match ty {
+        Type::Any => return None,
+        Type::Int => "Int",
+        Type::Float => "Float",
+        Type::Bool => "Bool",
+        Type::Str => "Str",
+        Type::Unit => "Unit",
+        Type::Enum => "Enum",
+        Type::Struct(name) => return Some(format!("Struct ({})", name)),
+        Type::TypeI8 => "i8",
+        Type::TypeU8 => "u8",
+        Type::TypeI16 => "i16",
+        Type::TypeU16 => "u16",
+        Type::TypeI32 => "i32",
+        Type::TypeU32 => "u32",
+        Type::TypeI64 => "i64",
+        Type::TypeU64 => "u64",
+        Type::TypeF16 => "fp16",
+        Type::TypeF32 => "fp32",
+        Type::TypeF64 => "fp64",
+    };
+    Some(name.to_string())
+}
+
+// LSP positions count lines from 0 and characters in UTF-16 code units.
+fn position(source: &str, offset: usize) -> Value {
+    let before = &source[..offset.min(source.len())];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let character: usize = before[line_start..].chars().map(char::len_utf16).sum();
+    json!({ "line": before.matches('\n').count(), "character": character })
+}
+
+fn range(source: &str, span: Span) -> Value {
+    json!({ "start": position(source, span.start), "end": position(source, span.end) })
+}
+
+fn offset_of(source: &str, line: usize, character: usize) -> usize {
+    let mut offset = 0;
+    for (idx, text) in source.split_inclusive('\n').enumerate() {
+        if idx == line {
+            let mut units = 0;
+            for (pos, c) in text.char_indices() {
+                if units >= character || c == '\n' {
+                    return offset + pos;
+                }
+                units += c.len_utf16();
+            }
+            return offset + text.len();
+        }
+        offset += text.len();
+    }
+    source.len()
+}
+
+// `file:///home/me/blink/src/main.sprs`, or `file:///c%3A/...` on Windows.
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut rest = encoded.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = (byte == b'%')
+            .then(|| std::str::from_utf8(tail.get(..2)?).ok())
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(decoded) => {
+                bytes.push(decoded);
+                rest = &tail[2..];
+            }
+            None => {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+    }
+    let path = String::from_utf8(bytes).ok()?;
+    // `/c:/...` is a Windows drive
+    let path = match path.as_bytes() {
+        [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => path[1..].to_string(),
+        _ => path,
+    };
+    Some(PathBuf::from(path))
+}
+
+fn path_to_uri(path: &str) -> String {
+    let absolute = std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path));
+    let text = absolute.to_string_lossy().replace('\\', "/");
+    // canonicalize gives `\\?\C:\...` on Windows
+    let text = text.strip_prefix("//?/").unwrap_or(&text);
+    let mut uri = String::from("file://");
+    if !text.starts_with('/') {
+        uri.push('/');
+    }
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use crate::llvm::compiler;
+
+// The built-in `math` package: `import math;` then `math.sqrt(x)`, or `import math.{sin, cos};`.
+// It has no source file; the calls compile to the `__math_*` functions of the runtime.
+// Arguments can be any number and the result is a Float. A project with its own
+// src/math.sprs imports that module instead.
+
+pub const MODULE: &str = "math";
+
+// name, number of arguments
+pub const FUNCTIONS: &[(&str, usize)] = &[
+    ("sqrt", 1),
+    ("pow", 2),
+    ("sin", 1),
+    ("cos", 1),
+    ("floor", 1),
+    ("ceil", 1),
+];
+
+// Whether `import <module>` in a project under `src_path` refers to the built-in package.
+pub fn is_builtin(src_path: &str, module: &str) -> bool {
+    module == MODULE && !Path::new(&compiler::module_path(src_path, module)).exists()
+}
+
+pub fn arity(function: &str) -> Option<usize> {
+    FUNCTIONS
+        .iter()
+        .find(|(name, _)| *name == function)
+        .map(|(_, arity)| *arity)
+}
+
+pub fn unknown_function_error(function: &str) -> String {
+    let names: Vec<&str> = FUNCTIONS.iter().map(|(name, _)| *name).collect();
+    format!(
+        "module 'math' has no function '{}' (available: {})",
+        function,
+        names.join(", ")
+    )
+}
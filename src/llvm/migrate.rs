@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+
+use crate::front::ast::Span;
+use crate::front::cst::TokenStream;
+use crate::front::diagnostics::Fix;
+use crate::front::lexer::{Edition, Token};
+use crate::llvm::resolve::SourceFile;
+
+// `sprs migrate`: rewrites a project from its edition to the next one. A name that the next
+// edition makes a keyword gets a new spelling (`in` -> `in_`), the same in every module, so
+// functions, struct fields and `import m.{f}` lists keep matching. Inactive `#if` branches
+// are rewritten too; the `#` directive lines are left alone.
+// A later edition that needs more than new keywords adds its rewrite to `plan`.
+
+pub struct Migration {
+    pub to: Edition,
+    pub renamed: Vec<(String, String, usize)>, // old name, new name, occurrences
+    pub edits: Vec<(String, Vec<Fix>)>,        // path -> token replacements
+}
+
+pub fn plan(files: &[SourceFile], from: Edition) -> Result<Migration, String> {
+    let to = from
+        .next()
+        .ok_or_else(|| format!("Edition {} is already the latest edition", from.name()))?;
+
+    // every name in the files as written, inactive code included
+    let mut idents: Vec<(&str, String, Span)> = Vec::new();
+    let mut sources = Vec::new();
+    for file in files {
+        let source = std::fs::read_to_string(&file.path)
+            .map_err(|e| format!("Failed to read module file {}: {}", file.path, e))?;
+        sources.push((file.path.as_str(), source));
+    }
+    for (path, source) in &sources {
+        let tokens = TokenStream::lex_preprocessed(source, source, from).tokens;
+        for token in tokens {
+            if let Token::Ident(name) = token.token {
+                if !in_directive(source, token.span.start) {
+                    idents.push((path, name, token.span));
+                }
+            }
+        }
+    }
+    let mut taken: HashSet<String> = idents.iter().map(|(_, name, _)| name.clone()).collect();
+
+    let mut migration = Migration {
+        to,
+        renamed: Vec::new(),
+        edits: Vec::new(),
+    };
+    for word in to.new_keywords() {
+        if !taken.contains(word) {
+            continue;
+        }
+        // a module file would have to move as well, `sprs rename` does that
+        for file in files {
+            for (_, span) in file.module_paths() {
+                let path = &file.active[span.start..span.end];
+                if path.split('.').any(|segment| segment == word) {
+                    return Err(format!(
+                        "Module path `{}` in {} uses `{}`, a keyword in edition {}; rename the module with `sprs rename` first",
+                        path,
+                        file.path,
+                        word,
+                        to.name()
+                    ));
+                }
+            }
+        }
+
+        let new = fresh_name(word, &taken);
+        let mut count = 0;
+        for (path, name, span) in &idents {
+            if name != word {
+                continue;
+            }
+            let fix = Fix {
+                span: *span,
+                replacement: new.clone(),
+                message: String::new(),
+            };
+            match migration.edits.iter_mut().find(|(p, _)| p == path) {
+                Some((_, fixes)) => fixes.push(fix),
+                None => migration.edits.push((path.to_string(), vec![fix])),
+            }
+            count += 1;
+        }
+        taken.insert(new.clone());
+        migration.renamed.push((word.to_string(), new, count));
+    }
+    Ok(migration)
+}
+
+// `word_`, or `word_2`, `word_3`, ... when that is used already.
+fn fresh_name(word: &str, taken: &HashSet<String>) -> String {
+    let mut name = format!("{}_", word);
+    let mut n = 2;
+    while taken.contains(&name) {
+        name = format!("{}_{}", word, n);
+        n += 1;
+    }
+    name
+}
+
+fn in_directive(source: &str, offset: usize) -> bool {
+    let line_start = source[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+    source[line_start..].trim_start().starts_with('#')
+}
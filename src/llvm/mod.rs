@@ -1,4 +1,28 @@
+pub mod build_cache;
+pub mod build_log;
 pub mod builder_helper;
+pub mod c_header;
 pub mod compiler;
+pub mod debug_info;
+pub mod doc;
 pub mod error_helper;
+pub mod escape;
+pub mod file;
+pub mod fuzz;
+pub mod index;
+pub mod last_use;
+pub mod lint;
+pub mod lint_rules;
 pub mod llvm_executer;
+pub mod lsp;
+pub mod math;
+pub mod migrate;
+pub mod panic_table;
+pub mod plugin;
+pub mod rename;
+pub mod resolve;
+pub mod rodata;
+pub mod size_report;
+pub mod string;
+pub mod target_check;
+pub mod watch;
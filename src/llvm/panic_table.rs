@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// With `--panic-codes` the binary only carries a 16-bit code per panic site. The messages
+// stay on the host in `<name>.panics.json`, which `sprs decode-panic <code>` reads back.
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PanicSite {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>, // "path:line:col"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+}
+
+impl PanicSite {
+    pub fn describe(&self) -> String {
+        match (&self.location, &self.function) {
+            (Some(location), Some(function)) => format!(
+                "{} (at {} in function '{}')",
+                self.message, location, function
+            ),
+            (Some(location), None) => format!("{} (at {})", self.message, location),
+            (None, Some(function)) => format!("{} (in function '{}')", self.message, function),
+            (None, None) => self.message.clone(),
+        }
+    }
+}
+
+pub fn table_path(out_dir: &str, proj_name: &str) -> String {
+    format!("{}/{}.panics.json", out_dir, proj_name)
+}
+
+pub fn format_code(code: u16) -> String {
+    format!("0x{:04X}", code)
+}
+
+// Codes start at 1; `sites[0]` is code 0x0001.
+pub fn write_table(path: &str, sites: &[PanicSite]) -> Result<(), String> {
+    let table: BTreeMap<String, &PanicSite> = sites
+        .iter()
+        .enumerate()
+        .map(|(idx, site)| (format_code(idx as u16 + 1), site))
+        .collect();
+    let json = serde_json::to_string_pretty(&table)
+        .map_err(|e| format!("Failed to serialize panic table: {}", e))?;
+    std::fs::write(path, json).map_err(|e| format!("Failed to write {}: {}", path, e))
+}
+
+// "0x1A2B" as printed by the runtime; without the prefix the code is read as decimal.
+pub fn parse_code(code: &str) -> Result<u16, String> {
+    let parsed = match code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        Some(hex) => u16::from_str_radix(hex, 16),
+        None => code.parse(),
+    };
+    parsed.map_err(|_| format!("Invalid panic code: {}", code))
+}
+
+pub fn decode(path: &str, code: u16) -> Result<PanicSite, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let mut table: BTreeMap<String, PanicSite> =
+        serde_json::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path, e))?;
+    table
+        .remove(&format_code(code))
+        .ok_or_else(|| format!("Panic code {} is not in {}", format_code(code), path))
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use inkwell::module::Module;
+use inkwell::values::BasicValueEnum;
+
+use crate::front::ast;
+use crate::front::ast::visit::{self, VisitorMut};
+use crate::llvm::compiler::Compiler;
+
+// Builtins compiled by the core compiler itself; plugins cannot replace them.
+pub const BUILTIN_MACROS: &[&str] = &[
+    "println!",
+    "list_push!",
+    "clone!",
+    "cast!",
+    "type_of!",
+    "is!",
+    "exit!",
+    "read_line!",
+    "now!",
+    "sleep!",
+    "rand!",
+    "rand_seed!",
+    "volatile_read!",
+    "volatile_write!",
+    "asm!",
+    "assert!",
+    "panic!",
+    "wrapping_add!",
+    "wrapping_sub!",
+    "wrapping_mul!",
+    "saturating_add!",
+    "saturating_sub!",
+    "min!",
+    "max!",
+    "abs!",
+    "clamp!",
+];
+
+// Extra `name!(...)` builtins supplied by Rust code outside the compiler, e.g. a
+// `can_frame!` for a CAN driver. Registered with `Compiler::register_macro`.
+pub trait MacroPlugin {
+    // Macro name including the `!`, e.g. "can_frame!".
+    fn name(&self) -> &str;
+
+    // AST transform: the returned expression replaces the call before the module is linted
+    // and compiled. Return None to generate code with `codegen` instead. Nodes built here can
+    // take `ast::Span::default()`; their panics then point at the statement.
+    fn expand(&self, _args: &[ast::Expr]) -> Result<Option<ast::Expr>, String> {
+        Ok(None)
+    }
+
+    // Codegen callback: returns a pointer to the runtime value of the call, like the
+    // core builtins in builder_helper.rs.
+    fn codegen<'ctx>(
+        &self,
+        _compiler: &mut Compiler<'ctx>,
+        _args: &[ast::Expr],
+        _module: &Module<'ctx>,
+    ) -> Result<BasicValueEnum<'ctx>, String> {
+        Err(format!("Macro {} has no expansion", self.name()))
+    }
+}
+
+// Replaces the calls of plugin macros whose `expand` returns an expression, so the lints and
+// the move analyses see the expanded code. The calls left in place go through `codegen`.
+pub fn expand_macros(
+    items: &mut [ast::Item],
+    macros: &HashMap<String, Rc<dyn MacroPlugin>>,
+) -> Result<(), String> {
+    if macros.is_empty() {
+        return Ok(());
+    }
+    let mut expander = Expander {
+        macros,
+        error: None,
+    };
+    visit::walk_items_mut(&mut expander, items);
+    expander.error.map_or(Ok(()), Err)
+}
+
+struct Expander<'a> {
+    macros: &'a HashMap<String, Rc<dyn MacroPlugin>>,
+    error: Option<String>,
+}
+
+impl VisitorMut for Expander<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut ast::Expr) {
+        if self.error.is_some() {
+            return;
+        }
+        let expansion = match expr {
+            ast::Expr::Call(name, args, _, _) => {
+                self.macros.get(name).map(|plugin| plugin.expand(args))
+            }
+            _ => None,
+        };
+        match expansion {
+            Some(Ok(Some(expanded))) => {
+                // the expansion may call other plugin macros
+                *expr = expanded;
+                self.visit_expr_mut(expr);
+            }
+            Some(Err(e)) => self.error = Some(e),
+            Some(Ok(None)) | None => visit::walk_expr_mut(self, expr),
+        }
+    }
+}
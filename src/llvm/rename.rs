@@ -0,0 +1,281 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::front::ast;
+use crate::front::cst::TokenStream;
+use crate::front::diagnostics::Fix;
+use crate::front::lexer::{Edition, Token};
+use crate::llvm::compiler;
+use crate::llvm::resolve::{Occurrence, Role, SourceFile, occurrences};
+
+// `sprs rename`: renames a function, struct, variable or module and rewrites its references
+// in every module of the project. The references are found by llvm/resolve.rs; the edits
+// replace those tokens only, so the layout and comments of the files are kept.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Function,
+    Struct,
+    Variable,
+    Module,
+}
+
+impl SymbolKind {
+    pub fn describe(self) -> &'static str {
+        match self {
+            SymbolKind::Function => "function",
+            SymbolKind::Struct => "struct",
+            SymbolKind::Variable => "variable",
+            SymbolKind::Module => "module",
+        }
+    }
+}
+
+pub struct Rename {
+    pub kind: SymbolKind,
+    pub edits: Vec<(String, Vec<Fix>)>, // path -> token replacements
+    pub moved: Option<(String, String)>, // a renamed module's file, old path -> new path
+}
+
+// Finds what `old` names, seen from the file `files[target]`, and collects the edits.
+pub fn plan(
+    files: &[SourceFile],
+    target: usize,
+    old: &str,
+    new: &str,
+    src_path: &str,
+) -> Result<Rename, String> {
+    let file = &files[target];
+    let kind = if file.defines_function(old) {
+        SymbolKind::Function
+    } else if file
+        .items
+        .iter()
+        .any(|item| matches!(item, ast::Item::StructItem(s) if s.ident == old))
+    {
+        SymbolKind::Struct
+    } else if occurrences(file, old)?
+        .iter()
+        .any(|o| matches!(o.role, Role::Binding | Role::Param))
+    {
+        SymbolKind::Variable
+    } else if files.iter().any(|f| f.module == old) && old != "main" {
+        SymbolKind::Module
+    } else {
+        return Err(format!(
+            "No function, struct, variable or module named `{}` in {}",
+            old, file.path
+        ));
+    };
+
+    let valid = match kind {
+        SymbolKind::Module => new
+            .split('.')
+            .all(|segment| is_ident(segment, file.edition)),
+        _ => is_ident(new, file.edition),
+    };
+    if !valid {
+        return Err(format!("`{}` is not a valid name", new));
+    }
+    if old == new {
+        return Err(format!("`{}` already has that name", old));
+    }
+
+    let mut rename = Rename {
+        kind,
+        edits: Vec::new(),
+        moved: None,
+    };
+    match kind {
+        SymbolKind::Function => rename_function(files, target, old, new, &mut rename)?,
+        SymbolKind::Struct => rename_struct(files, old, new, &mut rename)?,
+        SymbolKind::Variable => rename_variable(file, old, new, &mut rename)?,
+        SymbolKind::Module => rename_module(files, old, new, src_path, &mut rename)?,
+    }
+    Ok(rename)
+}
+
+fn rename_function(
+    files: &[SourceFile],
+    target: usize,
+    old: &str,
+    new: &str,
+    rename: &mut Rename,
+) -> Result<(), String> {
+    let defining = &files[target];
+    if defining.defines_function(new) {
+        return Err(format!(
+            "A function `{}` is already defined in {}",
+            new, defining.path
+        ));
+    }
+
+    for (idx, file) in files.iter().enumerate() {
+        // `old()` here, or after `import m.{old}` in a module without its own `old`
+        let local = idx == target
+            || (file.imported_from(old) == Some(&defining.module) && !file.defines_function(old));
+
+        let spans = occurrences(file, old)?
+            .into_iter()
+            .filter(|o| match &o.role {
+                Role::FunctionName => idx == target,
+                Role::Call => local,
+                Role::ModuleFunction(qualifier) => {
+                    file.qualified_module(qualifier) == Some(&defining.module)
+                }
+                Role::ImportName(module) => *module == defining.module,
+                _ => false,
+            })
+            .map(|o| o.span);
+        push_edits(rename, file, spans, new);
+    }
+    Ok(())
+}
+
+fn rename_struct(
+    files: &[SourceFile],
+    old: &str,
+    new: &str,
+    rename: &mut Rename,
+) -> Result<(), String> {
+    // struct names are global, see Compiler::struct_defs
+    for file in files {
+        let taken = file
+            .items
+            .iter()
+            .any(|item| matches!(item, ast::Item::StructItem(s) if s.ident == new));
+        if taken {
+            return Err(format!(
+                "A struct `{}` is already defined in {}",
+                new, file.path
+            ));
+        }
+    }
+
+    for file in files {
+        let spans = occurrences(file, old)?
+            .into_iter()
+            .filter(|o| matches!(o.role, Role::StructDef | Role::StructName))
+            .map(|o| o.span);
+        push_edits(rename, file, spans, new);
+    }
+    Ok(())
+}
+
+fn rename_variable(
+    file: &SourceFile,
+    old: &str,
+    new: &str,
+    rename: &mut Rename,
+) -> Result<(), String> {
+    let found = occurrences(file, old)?;
+    let declaring = |occurrences: &[Occurrence]| -> HashSet<Option<usize>> {
+        occurrences
+            .iter()
+            .filter(|o| matches!(o.role, Role::Binding | Role::Param))
+            .map(|o| o.function)
+            .collect()
+    };
+    // None stands for a top-level `var`, which every function without its own `old` sees
+    let scopes = declaring(&found);
+    let taken = declaring(&occurrences(file, new)?);
+    if let Some(clash) = scopes.iter().find(|scope| taken.contains(scope)) {
+        let place = match clash {
+            Some(idx) => match &file.items[*idx] {
+                ast::Item::FunctionItem(func) => format!("function `{}`", func.ident),
+                _ => unreachable!(),
+            },
+            None => "the top level".to_string(),
+        };
+        return Err(format!(
+            "`{}` is already declared in {} of {}",
+            new, place, file.path
+        ));
+    }
+
+    let spans = found
+        .into_iter()
+        .filter(|o| matches!(o.role, Role::Binding | Role::Param | Role::Use))
+        .filter(|o| {
+            scopes.contains(&o.function) || (scopes.contains(&None) && o.function.is_some())
+        })
+        .map(|o| o.span);
+    push_edits(rename, file, spans, new);
+    Ok(())
+}
+
+fn rename_module(
+    files: &[SourceFile],
+    old: &str,
+    new: &str,
+    src_path: &str,
+    rename: &mut Rename,
+) -> Result<(), String> {
+    let old_path = compiler::module_path(src_path, old);
+    let new_path = compiler::module_path(src_path, new);
+    if Path::new(&new_path).exists() {
+        return Err(format!("{} already exists", new_path));
+    }
+
+    let old_last = old.rsplit('.').next().unwrap();
+    let new_last = new.rsplit('.').next().unwrap();
+    for file in files {
+        // `import old ...;` and `pkg old;`
+        let spans = file
+            .module_paths()
+            .into_iter()
+            .map(|(_, span)| span)
+            .filter(|span| file.active[span.start..span.end] == *old);
+        push_edits(rename, file, spans, new);
+
+        // `uart.init()` becomes `serial.init()` where the module was imported without `as`
+        let unaliased = file.items.iter().any(|item| {
+            matches!(item, ast::Item::Import(i) if i.module == old && i.alias.is_none() && i.names.is_empty())
+        });
+        if unaliased && old_last != new_last {
+            let spans = occurrences(file, old_last)?
+                .into_iter()
+                .filter(|o| o.role == Role::ModuleQualifier)
+                .map(|o| o.span);
+            push_edits(rename, file, spans, new_last);
+        }
+    }
+
+    rename.moved = Some((old_path, new_path));
+    Ok(())
+}
+
+fn push_edits(
+    rename: &mut Rename,
+    file: &SourceFile,
+    spans: impl IntoIterator<Item = ast::Span>,
+    new: &str,
+) {
+    let fixes: Vec<Fix> = spans
+        .into_iter()
+        .map(|span| Fix {
+            span,
+            replacement: new.to_string(),
+            message: String::new(),
+        })
+        .collect();
+    if fixes.is_empty() {
+        return;
+    }
+    match rename.edits.iter_mut().find(|(path, _)| *path == file.path) {
+        Some((_, edits)) => edits.extend(fixes),
+        None => rename.edits.push((file.path.clone(), fixes)),
+    }
+}
+
+fn is_ident(name: &str, edition: Edition) -> bool {
+    let tokens = TokenStream::lex_preprocessed(name, name, edition);
+    match tokens.tokens.as_slice() {
+        [token] => {
+            matches!(token.token, Token::Ident(_))
+                && token.leading.is_empty()
+                && tokens.trailing.is_empty()
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,298 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::front::ast::visit::{self, Visitor};
+use crate::front::ast::{self, Span};
+use crate::front::cst::{CstToken, TokenStream};
+use crate::front::lexer::{Edition, Token};
+use crate::front::preprocess;
+use crate::interpreter::runner::parse_module;
+use crate::llvm::compiler;
+
+// Name resolution for source tools (`sprs rename`, `sprs index`).
+// Expressions carry no spans, so each identifier token of interest is swapped for a numbered
+// probe name and the file is parsed again. Where each probe ends up in the AST tells whether
+// that token is a call, a variable, a struct field and so on.
+
+const PROBE: &str = "__sprs_resolve_probe_";
+
+pub struct SourceFile {
+    pub path: String,
+    pub module: String, // as written in `import`, e.g. "drivers.uart"
+    pub active: String, // preprocessor output, spans into it are spans into the file
+    pub items: Vec<ast::Item>,
+    pub edition: Edition,
+}
+
+impl SourceFile {
+    pub fn defines_function(&self, name: &str) -> bool {
+        self.items
+            .iter()
+            .any(|item| matches!(item, ast::Item::FunctionItem(f) if f.ident == name))
+    }
+
+    // The module a `q.f()` qualifier stands for here: an `as` alias or the last path segment.
+    pub fn qualified_module(&self, qualifier: &str) -> Option<&str> {
+        self.imports().find_map(|import| {
+            let last = import.module.rsplit('.').next().unwrap();
+            let name = import.alias.as_deref().unwrap_or(last);
+            (import.names.is_empty() && name == qualifier).then_some(import.module.as_str())
+        })
+    }
+
+    // The module `f` was imported from by name (`import m.{f}`).
+    pub fn imported_from(&self, name: &str) -> Option<&str> {
+        self.imports()
+            .find(|import| import.names.iter().any(|n| n == name))
+            .map(|import| import.module.as_str())
+    }
+
+    fn tokens(&self) -> Vec<CstToken> {
+        TokenStream::lex_preprocessed(&self.active, &self.active, self.edition).tokens
+    }
+
+    fn imports(&self) -> impl Iterator<Item = &ast::Import> {
+        self.items.iter().filter_map(|item| match item {
+            ast::Item::Import(import) => Some(import),
+            _ => None,
+        })
+    }
+
+    // `import m ...;` and `pkg m;`: the keyword token and the span of the module path.
+    pub fn module_paths(&self) -> Vec<(Token, Span)> {
+        let tokens = self.tokens();
+        let mut paths = Vec::new();
+        for (idx, token) in tokens.iter().enumerate() {
+            if !matches!(token.token, Token::Import | Token::Package) {
+                continue;
+            }
+            let path = module_path_tokens(&tokens[idx + 1..]);
+            if let (Some(first), Some(last)) = (path.first(), path.last()) {
+                let span = Span {
+                    start: first.span.start,
+                    end: last.span.end,
+                };
+                paths.push((token.token.clone(), span));
+            }
+        }
+        paths
+    }
+}
+
+// Loads the entry file (`main_path`) and every module it imports, except the modules of
+// dependencies. A module that does not parse is an error, since the names in it could not be
+// resolved.
+pub fn load_project(
+    src_path: &str,
+    main_path: &str,
+    dependencies: &HashMap<String, String>,
+    edition: Edition,
+) -> Result<Vec<SourceFile>, String> {
+    let mut symbols = compiler::project_symbols(main_path, &[], None, edition);
+    let mut files = Vec::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::from(["main".to_string()]);
+    while let Some(module) = queue.pop_front() {
+        if !seen.insert(module.clone()) {
+            continue;
+        }
+        let path = if module == "main" {
+            main_path.to_string()
+        } else {
+            compiler::module_path(src_path, &module)
+        };
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read module file {}: {}", path, e))?;
+        let syntax_error = || format!("{} has syntax errors, fix them first", path);
+        let active =
+            preprocess::preprocess(&source, &path, &mut symbols).map_err(|_| syntax_error())?;
+        let items = parse_module(&active, &path, edition).map_err(|_| syntax_error())?;
+        for item in &items {
+            if let ast::Item::Import(import) = item {
+                if !compiler::is_builtin_package(src_path, &import.module)
+                    && compiler::dependency_module_path(dependencies, &import.module).is_none()
+                {
+                    queue.push_back(import.module.clone());
+                }
+            }
+        }
+        files.push(SourceFile {
+            path,
+            module,
+            active,
+            items,
+            edition,
+        });
+    }
+    Ok(files)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Role {
+    Param,                  // a function parameter
+    Binding,                // `var x`
+    Use,                    // a variable read or assigned
+    FunctionName,           // `fn f(...)`
+    Call,                   // `f(...)`
+    ModuleQualifier,        // `m` in `m.f(...)`
+    ModuleFunction(String), // `f` in `m.f(...)`, with `m`
+    ImportName(String),     // `f` in `import m.{f}`, with `m`
+    StructDef,              // `struct S`
+    StructName,             // `S { ... }`
+    Other,                  // fields, enum names, aliases, ...
+}
+
+pub struct Occurrence {
+    pub name: String,
+    pub span: Span,
+    pub role: Role,
+    pub function: Option<usize>, // item index of the enclosing function
+}
+
+// Every identifier token in `file` spelled `name`, with its role.
+pub fn occurrences(file: &SourceFile, name: &str) -> Result<Vec<Occurrence>, String> {
+    resolve_idents(file, |ident| ident == name)
+}
+
+// Every identifier token in `file` whose name passes `wanted`, with its role.
+pub fn resolve_idents(
+    file: &SourceFile,
+    wanted: impl Fn(&str) -> bool,
+) -> Result<Vec<Occurrence>, String> {
+    let idents: Vec<(String, Span)> = file
+        .tokens()
+        .into_iter()
+        .filter_map(|t| match t.token {
+            Token::Ident(ident) if wanted(&ident) => Some((ident, t.span)),
+            _ => None,
+        })
+        .collect();
+    if idents.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut probed = String::with_capacity(file.active.len());
+    let mut pos = 0;
+    for (idx, (_, span)) in idents.iter().enumerate() {
+        probed.push_str(&file.active[pos..span.start]);
+        probed.push_str(&format!("{}{}", PROBE, idx));
+        pos = span.end;
+    }
+    probed.push_str(&file.active[pos..]);
+
+    let items = parse_module(&probed, &file.path, file.edition)
+        .map_err(|_| format!("Could not resolve the names in {}", file.path))?;
+    let mut probe = Probe {
+        idents: &idents,
+        roles: HashMap::new(),
+        function: None,
+    };
+    for (idx, item) in items.iter().enumerate() {
+        probe.function = matches!(item, ast::Item::FunctionItem(_)).then_some(idx);
+        probe.visit_item(item);
+    }
+
+    let mut roles = probe.roles;
+    Ok(idents
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (name, span))| {
+            let (role, function) = roles.remove(&idx).unwrap_or((Role::Other, None));
+            Occurrence {
+                name,
+                span,
+                role,
+                function,
+            }
+        })
+        .collect())
+}
+
+// `drivers . uart` at the start of `tokens`; stops before `.{` of a selective import.
+fn module_path_tokens(tokens: &[CstToken]) -> &[CstToken] {
+    let mut len = 0;
+    while matches!(tokens.get(len).map(|t| &t.token), Some(Token::Ident(_))) {
+        len += 1;
+        let dotted = matches!(tokens.get(len).map(|t| &t.token), Some(Token::Dot))
+            && matches!(tokens.get(len + 1).map(|t| &t.token), Some(Token::Ident(_)));
+        if !dotted {
+            break;
+        }
+        len += 1;
+    }
+    &tokens[..len]
+}
+
+struct Probe<'a> {
+    idents: &'a [(String, Span)],
+    roles: HashMap<usize, (Role, Option<usize>)>, // probe number -> role
+    function: Option<usize>,
+}
+
+impl Probe<'_> {
+    fn probe_index(ident: &str) -> Option<usize> {
+        ident.strip_prefix(PROBE)?.parse().ok()
+    }
+
+    fn record(&mut self, ident: &str, role: Role) {
+        if let Some(idx) = Self::probe_index(ident) {
+            self.roles.insert(idx, (role, self.function));
+        }
+    }
+
+    // the original spelling of a name that may be a probe
+    fn original(&self, ident: &str) -> String {
+        match Self::probe_index(ident) {
+            Some(idx) => self.idents[idx].0.clone(),
+            None => ident.to_string(),
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for Probe<'_> {
+    fn visit_import(&mut self, import: &'ast ast::Import) {
+        let module: Vec<String> = import.module.split('.').map(|s| self.original(s)).collect();
+        let module = module.join(".");
+        for name in &import.names {
+            self.record(name, Role::ImportName(module.clone()));
+        }
+    }
+
+    fn visit_function(&mut self, func: &'ast ast::Function) {
+        self.record(&func.ident, Role::FunctionName);
+        visit::walk_function(self, func);
+    }
+
+    fn visit_param(&mut self, param: &'ast ast::FunctionParam) {
+        self.record(&param.ident, Role::Param);
+    }
+
+    fn visit_struct(&mut self, strct: &'ast ast::Struct) {
+        self.record(&strct.ident, Role::StructDef);
+        visit::walk_struct(self, strct);
+    }
+
+    fn visit_var_decl(&mut self, var: &'ast ast::VarDecl) {
+        self.record(&var.ident, Role::Binding);
+        visit::walk_var_decl(self, var);
+    }
+
+    fn visit_assign(&mut self, assign: &'ast ast::AssignStmt) {
+        self.record(&assign.name, Role::Use);
+        visit::walk_assign(self, assign);
+    }
+
+    fn visit_expr(&mut self, expr: &'ast ast::Expr) {
+        match expr {
+            ast::Expr::Var(name) => self.record(name, Role::Use),
+            ast::Expr::Call(name, _, _, _) => self.record(name, Role::Call),
+            ast::Expr::ModuleAccess(module, func, _) => {
+                let qualifier = self.original(module);
+                self.record(module, Role::ModuleQualifier);
+                self.record(func, Role::ModuleFunction(qualifier));
+            }
+            ast::Expr::StructInit(name, _) => self.record(name, Role::StructName),
+            _ => {}
+        }
+        visit::walk_expr(self, expr);
+    }
+}
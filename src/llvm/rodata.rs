@@ -0,0 +1,101 @@
+use inkwell::module::Module;
+use inkwell::values::GlobalValue;
+
+// Flash-saving passes over string literals, used by the embedded targets.
+
+// Post-codegen: an interned literal that is the tail of a longer one ("world" in "hello world")
+// is replaced by a pointer into the longer one. Literals are NUL-terminated, so the pointer
+// reads back the same C string. Returns the number of bytes saved.
+pub fn merge_string_suffixes(module: &Module<'_>) -> usize {
+    let mut literals: Vec<(GlobalValue<'_>, Vec<u8>)> = module
+        .get_globals()
+        .filter(|g| g.get_name().to_bytes().starts_with(b"__sprs_str."))
+        .filter_map(|g| {
+            let bytes = g
+                .get_initializer()?
+                .into_array_value()
+                .as_const_string()?
+                .to_vec();
+            Some((g, bytes))
+        })
+        .collect();
+    literals.sort_by_key(|(_, bytes)| std::cmp::Reverse(bytes.len()));
+
+    let i8_type = module.get_context().i8_type();
+    let i64_type = module.get_context().i64_type();
+    let mut kept: Vec<(GlobalValue<'_>, Vec<u8>)> = Vec::new();
+    let mut saved = 0;
+
+    for (global, bytes) in literals {
+        let Some((target, target_bytes)) = kept
+            .iter()
+            .find(|(_, longer)| longer.len() > bytes.len() && longer.ends_with(&bytes))
+        else {
+            kept.push((global, bytes));
+            continue;
+        };
+
+        let offset = i64_type.const_int((target_bytes.len() - bytes.len()) as u64, false);
+        let tail = unsafe {
+            target
+                .as_pointer_value()
+                .const_in_bounds_gep(i8_type, &[offset])
+        };
+        global.as_pointer_value().replace_all_uses_with(tail);
+        unsafe { global.delete() };
+        saved += bytes.len();
+    }
+
+    saved
+}
+
+// Literals shorter than this are never compressed; the decompressor call costs more than it saves.
+pub const COMPRESS_MIN_LEN: usize = 48;
+
+// LZSS used by `--compress-strings`, unpacked once at run time by `__str_unpack`.
+// Layout: u16 LE length, then groups of a flag byte and 8 items (LSB first).
+// A 0 bit is a literal byte, a 1 bit a back-reference of two bytes:
+// distance low 8 bits, then (distance high 4 bits << 4) | (length - 3).
+const WINDOW: usize = 4095;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 18;
+
+// Returns None when the literal is too long for the u16 header or does not get smaller.
+pub fn lzss_pack(data: &[u8]) -> Option<Vec<u8>> {
+    let len = u16::try_from(data.len()).ok()?;
+    let mut out = len.to_le_bytes().to_vec();
+
+    let mut pos = 0;
+    while pos < data.len() {
+        let flag_idx = out.len();
+        out.push(0);
+        for bit in 0..8 {
+            if pos >= data.len() {
+                break;
+            }
+
+            let mut best = (0, 0); // (distance, length)
+            for start in pos.saturating_sub(WINDOW)..pos {
+                let length = (0..MAX_MATCH.min(data.len() - pos))
+                    .take_while(|&i| data[start + i] == data[pos + i])
+                    .count();
+                if length > best.1 {
+                    best = (pos - start, length);
+                }
+            }
+
+            if best.1 >= MIN_MATCH {
+                let (distance, length) = best;
+                out[flag_idx] |= 1 << bit;
+                out.push((distance & 0xFF) as u8);
+                out.push((((distance >> 8) << 4) | (length - MIN_MATCH)) as u8);
+                pos += length;
+            } else {
+                out.push(data[pos]);
+                pos += 1;
+            }
+        }
+    }
+
+    (out.len() < data.len()).then_some(out)
+}
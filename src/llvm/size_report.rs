@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use inkwell::{memory_buffer::MemoryBuffer, module::Linkage, module::Module};
+
+// `--size-report`: the code size of every Sprs function, read from the symbol tables of the
+// objects, and the text/data/bss totals of their sections (what `size` prints). The runtime
+// library is linked in later and is not counted.
+
+// Private functions never get a symbol; internal ones do and still can't be called from
+// other objects, so a report needs them internal.
+pub fn expose_private_functions(module: &Module<'_>) {
+    for function in module.get_functions() {
+        if function.count_basic_blocks() > 0 && function.get_linkage() == Linkage::Private {
+            function.set_linkage(Linkage::Internal);
+        }
+    }
+}
+
+#[derive(Default)]
+struct SectionTotals {
+    text: u64, // code and read-only data, which both go to flash
+    data: u64,
+    bss: u64,
+}
+
+impl SectionTotals {
+    fn add(&mut self, name: &str, size: u64) {
+        // ELF/COFF `.text.x`, Mach-O `__text`, Wasm `CODE`
+        let name = name.trim_start_matches('.').trim_start_matches("__");
+        let kind = name.split('.').next().unwrap_or("").to_ascii_lowercase();
+        match kind.as_str() {
+            "text" | "code" | "rodata" | "srodata" | "rdata" | "const" | "cstring" => {
+                self.text += size
+            }
+            "data" | "sdata" => self.data += size,
+            "bss" | "sbss" => self.bss += size,
+            _ => {}
+        }
+    }
+}
+
+// `objects` are (module name, object path); `functions` the names of the functions defined
+// in the modules, so runtime calls and literals are left out of the list.
+pub fn print_size_report(
+    objects: &[(String, String)],
+    functions: &HashSet<String>,
+) -> Result<(), String> {
+    let mut rows: Vec<(String, String, u64)> = Vec::new();
+    let mut totals = SectionTotals::default();
+
+    for (module, path) in objects {
+        let buffer = MemoryBuffer::create_from_file(Path::new(path))
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let object = buffer
+            .create_object_file()
+            .map_err(|_| format!("{} is not an object file", path))?;
+
+        for section in object.get_sections() {
+            if let Some(name) = section.get_name() {
+                totals.add(&name.to_string_lossy(), section.size());
+            }
+        }
+        for symbol in object.get_symbols() {
+            let Some(name) = symbol.get_name() else {
+                continue;
+            };
+            let name = name.to_string_lossy();
+            // Mach-O prefixes C symbols with `_`
+            let name = match name.strip_prefix('_') {
+                _ if functions.contains(name.as_ref()) => name.to_string(),
+                Some(stripped) if functions.contains(stripped) => stripped.to_string(),
+                _ => continue,
+            };
+            rows.push((module.clone(), name, symbol.size()));
+        }
+    }
+
+    rows.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.1.cmp(&b.1)));
+    let module_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(0).max(6);
+    let name_width = rows.iter().map(|r| r.1.len()).max().unwrap_or(0).max(8);
+
+    println!("--- Size report (without the runtime) ---");
+    if rows.iter().all(|r| r.2 == 0) {
+        // COFF symbols carry no size
+        println!("The object format has no function sizes; see the totals below");
+    } else {
+        println!(
+            "{:<module_width$}  {:<name_width$}  {:>8}",
+            "module", "function", "bytes"
+        );
+        for (module, name, size) in &rows {
+            println!(
+                "{:<module_width$}  {:<name_width$}  {:>8}",
+                module, name, size
+            );
+        }
+    }
+    println!(
+        "text: {} bytes, data: {} bytes, bss: {} bytes (flash: {} bytes, RAM: {} bytes)",
+        totals.text,
+        totals.data,
+        totals.bss,
+        totals.text + totals.data,
+        totals.data + totals.bss
+    );
+    Ok(())
+}
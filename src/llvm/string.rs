@@ -0,0 +1,42 @@
+use std::path::Path;
+
+use crate::llvm::compiler;
+
+// The built-in `string` package: `import string;` then `string.split(line, ",")`.
+// (`str` is the type keyword, so the package has the longer name.) Like `math` it has no
+// source file; the calls compile to the `__str_*` functions of the runtime. Every argument
+// is a Str. A project with its own src/string.sprs imports that module instead.
+
+pub const MODULE: &str = "string";
+
+// name, number of arguments
+pub const FUNCTIONS: &[(&str, usize)] = &[
+    ("split", 2),
+    ("trim", 1),
+    ("to_upper", 1),
+    ("to_lower", 1),
+    ("contains", 2),
+    ("find", 2),
+    ("chars", 1),
+];
+
+// Whether `import <module>` in a project under `src_path` refers to the built-in package.
+pub fn is_builtin(src_path: &str, module: &str) -> bool {
+    module == MODULE && !Path::new(&compiler::module_path(src_path, module)).exists()
+}
+
+pub fn arity(function: &str) -> Option<usize> {
+    FUNCTIONS
+        .iter()
+        .find(|(name, _)| *name == function)
+        .map(|(_, arity)| *arity)
+}
+
+pub fn unknown_function_error(function: &str) -> String {
+    let names: Vec<&str> = FUNCTIONS.iter().map(|(name, _)| *name).collect();
+    format!(
+        "module 'string' has no function '{}' (available: {})",
+        function,
+        names.join(", ")
+    )
+}
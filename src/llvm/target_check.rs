@@ -0,0 +1,98 @@
+use crate::front::ast;
+use crate::front::ast::visit::{self, Visitor};
+use crate::front::diagnostics::Diagnostic;
+use crate::interpreter::type_helper::Type;
+
+// Feature checks for the experimental AVR profile.
+// Values there are { i8 tag, i32 data }, so anything needing 64 bits of payload
+// (f64, i64/u64, wide integer literals) is rejected before codegen.
+
+struct AvrCheck<'a> {
+    path: &'a str,
+    span: Option<ast::Span>, // the statement being checked, None outside functions
+    errors: Vec<Diagnostic>,
+}
+
+// Returns one error per unsupported feature in the module.
+pub fn check_avr(items: &[ast::Item], path: &str) -> Vec<Diagnostic> {
+    let mut check = AvrCheck {
+        path,
+        span: None,
+        errors: Vec::new(),
+    };
+    visit::walk_items(&mut check, items);
+    check.errors
+}
+
+impl AvrCheck<'_> {
+    fn report(&mut self, span: Option<ast::Span>, message: String) {
+        let diagnostic =
+            Diagnostic::error(format!("{} is not supported on the AVR target", message))
+                .in_file(self.path);
+        self.errors.push(match span {
+            Some(span) => diagnostic.with_primary(span, ""),
+            None => diagnostic,
+        });
+    }
+
+    fn check_type(&mut self, ty: &Type, span: Option<ast::Span>, what: &str) {
+        match ty {
+            Type::Float | Type::TypeF64 => self.report(span, format!("f64 in {}", what)),
+            Type::TypeI64 | Type::TypeU64 => {
+                self.report(span, format!("64-bit integer in {}", what))
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for AvrCheck<'_> {
+    fn visit_item(&mut self, item: &'a ast::Item) {
+        self.span = None;
+        visit::walk_item(self, item);
+    }
+
+    fn visit_function(&mut self, func: &'a ast::Function) {
+        if let Some(ty) = &func.ret_ty {
+            self.check_type(
+                ty,
+                Some(func.span),
+                &format!("return type of '{}'", func.ident),
+            );
+        }
+        visit::walk_function(self, func);
+    }
+
+    fn visit_struct(&mut self, st: &'a ast::Struct) {
+        for field in &st.fields {
+            if let Some(ty) = &field.ty {
+                self.check_type(ty, None, &format!("field '{}.{}'", st.ident, field.ident));
+            }
+            self.visit_struct_field(field);
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &'a ast::Stmt) {
+        self.span = Some(stmt.span);
+        visit::walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &'a ast::Expr) {
+        let span = self.span;
+        match expr {
+            ast::Expr::Float(f) => self.report(span, format!("f64 literal {}", f)),
+            ast::Expr::Number(n) if i32::try_from(*n).is_err() => {
+                self.report(span, format!("integer literal {} (Int is 32 bits wide)", n))
+            }
+            ast::Expr::TypeF64 => self.report(span, "cast to fp64".to_string()),
+            ast::Expr::TypeI64 | ast::Expr::TypeU64 => {
+                self.report(span, "cast to a 64-bit integer".to_string())
+            }
+            ast::Expr::Call(ident, _, Some(ty), _) => {
+                self.check_type(ty, span, &format!("call to '{}'", ident));
+                visit::walk_expr(self, expr);
+            }
+            _ => visit::walk_expr(self, expr),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use crate::command_helper::{self, BuildOptions};
+use crate::llvm::build_log::BuildLog;
+use crate::llvm::llvm_executer::{self, ExecuteMode};
+
+// `sprs run --watch`: builds and starts the program, then rebuilds and restarts it whenever a
+// .sprs file of the source directory or of a path dependency, or sprs.toml, changes. The
+// artifact cache skips the optimization and code generation of the modules whose IR is unchanged.
+// A build that fails leaves the program stopped until the next change.
+
+// The running program; build_binary starts it in `ExecuteMode::Watch`.
+static PROGRAM: Mutex<Option<Child>> = Mutex::new(None);
+
+// How often the program is checked for having exited while no file changes.
+const POLL: Duration = Duration::from_millis(200);
+// An editor can write a file several times on save; a change counts once it is this quiet.
+const SETTLE: Duration = Duration::from_millis(100);
+
+// `file` is the script of `sprs run <file.sprs>`, None for the project.
+pub fn run(full_path: String, file: Option<&str>, options: BuildOptions) -> bool {
+    let log = BuildLog::new(options.verbosity);
+    let (sender, changes) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            eprintln!("Failed to watch the sources: {}", e);
+            return false;
+        }
+    };
+    for (path, mode) in watched_paths(file) {
+        if let Err(e) = watcher.watch(&path, mode) {
+            eprintln!("Failed to watch {}: {}", path.display(), e);
+            return false;
+        }
+    }
+
+    loop {
+        let built = match file {
+            Some(file) => llvm_executer::run_file(file, ExecuteMode::Watch, options.clone()),
+            None => {
+                llvm_executer::build_and_run(full_path.clone(), ExecuteMode::Watch, options.clone())
+            }
+        };
+        if !built {
+            log.status("Build failed");
+        }
+        log.status("--- Watching for changes (Ctrl+C to stop) ---");
+        let changed = wait_for_change(&changes, &log);
+        stop();
+        if !changed {
+            eprintln!("Stopped watching the sources");
+            return false;
+        }
+        log.status("--- Change detected, rebuilding ---");
+    }
+}
+
+// Starts the built program, which keeps running until the next change.
+pub fn start(command: &mut Command) -> bool {
+    match command.spawn() {
+        Ok(child) => {
+            *PROGRAM.lock().unwrap_or_else(|e| e.into_inner()) = Some(child);
+            true
+        }
+        Err(e) => {
+            eprintln!(
+                "Failed to run {}: {}",
+                command.get_program().to_string_lossy(),
+                e
+            );
+            false
+        }
+    }
+}
+
+fn stop() {
+    if let Some(mut child) = PROGRAM.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        // it may have exited already
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+// The source directory (the script's directory for a script) and the directories of path
+// dependencies, with their subdirectories for dotted modules, and the project directory
+// itself for sprs.toml.
+fn watched_paths(file: Option<&str>) -> Vec<(PathBuf, RecursiveMode)> {
+    if let Some(file) = file {
+        let dir = Path::new(file)
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        return vec![(dir.to_path_buf(), RecursiveMode::Recursive)];
+    }
+
+    let config = command_helper::load_project_config();
+    let src_dir = config
+        .as_ref()
+        .map(|c| c.src_dir.clone())
+        .unwrap_or_else(|| "src".to_string());
+    let mut paths = vec![(PathBuf::from(&src_dir), RecursiveMode::Recursive)];
+    if Path::new(&src_dir) != Path::new(".") {
+        paths.push((PathBuf::from("."), RecursiveMode::NonRecursive));
+    }
+    // git dependencies are pinned in vendor/ and do not change under a build
+    for dependency in config.iter().flat_map(|c| c.dependencies.values()) {
+        if let Some(path) = &dependency.path {
+            paths.push((PathBuf::from(path), RecursiveMode::Recursive));
+        }
+    }
+    paths
+}
+
+// Blocks until a source changes and reports when the program exits meanwhile. False when the
+// watcher stops.
+fn wait_for_change(changes: &Receiver<notify::Result<Event>>, log: &BuildLog) -> bool {
+    loop {
+        match changes.recv_timeout(POLL) {
+            Ok(Ok(event)) if is_source_change(&event) => break,
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => log.warning(format_args!("watch: {}", e)),
+            Err(RecvTimeoutError::Timeout) => report_exit(log),
+            Err(RecvTimeoutError::Disconnected) => return false,
+        }
+    }
+    while changes.recv_timeout(SETTLE).is_ok() {}
+    true
+}
+
+// Build outputs and editor backup files do not trigger a rebuild.
+fn is_source_change(event: &Event) -> bool {
+    !matches!(event.kind, EventKind::Access(_))
+        && event.paths.iter().any(|path| {
+            path.extension().is_some_and(|ext| ext == "sprs")
+                || path.file_name().is_some_and(|name| name == "sprs.toml")
+        })
+}
+
+fn report_exit(log: &BuildLog) {
+    let mut program = PROGRAM.lock().unwrap_or_else(|e| e.into_inner());
+    let exited = program
+        .as_mut()
+        .and_then(|child| child.try_wait().ok().flatten());
+    if let Some(status) = exited {
+        *program = None;
+        log.status(format_args!("--- Program exited ({}) ---", status));
+    }
+}
@@ -29,6 +29,16 @@
 //! 7. Install the Rust extension for VSCode.
 //! 8. Build and run the project using `cargo build` and `cargo run`
 //!
+//! Analyses over the syntax tree should use `front::ast::visit` instead of matching every node themselves. Implement `Visitor` (or `VisitorMut` to rewrite the tree), override the methods for the nodes of interest, and call the matching `walk_*` function to keep descending. `llvm/lint.rs` is a small example.
+//!
+//! Tools that rewrite source files (a formatter, rename or extract-function refactorings) should work on `front::cst` rather than printing the AST back out. `SyntaxTree::parse` is the lossless parse mode: it returns the AST together with a `TokenStream` in which every token keeps the whitespace and `#` comments in front of it, so `to_source()` reproduces the file byte for byte. Each item of the tree is an `ItemNode` with the span of the item from its first to its last token, the comment lines directly above it (up to a blank line) and a comment after it on the same line; `full_span()` covers all of them, so moving or printing an item keeps its comments with it. Express changes as `diagnostics::Fix` edits on token spans and apply them with `apply_fixes`, which leaves the layout of everything else untouched.
+//!
+//! After changing the lexer, grammar or code generator, run `sprs fuzz-grammar [--iterations N] [--seed S]` (1000 cases by default). It compiles programs generated from the grammar, mutated copies of them and random token soup, and reports every distinct compiler panic with a minimized reproducer, also written to `<out_dir>/fuzz/crash-N.sprs`. Programs the compiler rejects with an error are not findings. The printed seed regenerates the original input with `--seed S --iterations 1`; the command exits with status 1 when it found a panic.
+//!
+//! To see what the lexer and the parser make of a file, run `sprs dump --tokens <file>` or `sprs dump --ast <file>` (both flags together print both). `--tokens` prints one token per line as `line:col start..end Token "text"`, `--ast` every item pretty-printed with `{:#?}` after its position and byte span; the nodes inside carry their own spans. The file goes through `#if` processing first and is lexed with the edition of `sprs.toml` when there is one; syntax errors are reported as in a build.
+//!
+//! When the compiler itself panics, it writes a crash report to `sprs-crash-<time>/` in the current directory and prints one line asking to open an issue with it attached. The directory has `report.txt` (sprs version, host, the command line, the panic message and its backtrace), a copy of `sprs.toml` and the `.sprs` files of the project, so check it for anything private before sharing it. The process exits with status 101.
+//!
 //!
 //! ## Language Features
 //! ### **Basic data types:**
@@ -36,6 +46,8 @@
 //!  * Float (f64) : return type semantic use this word 'fp'
 //!  * Bool
 //!  * Str
+//!  * Char (a Unicode character, written `'a'`; escapes `'\n'`, `'\r'`, `'\t'`, `'\0'`, `'\\'`, `'\''`)
+//!  * Char (a Unicode character, written `'a'`; escapes `'\n'`, `'\r'`, `'\t'`, `'\0'`, `'\\'`, `'\''`)
 //!  * List(128) (dynamic array)
 //!  * Range
 //!  * Unit
@@ -71,6 +83,11 @@
 //! y = 20;
 //! y = "now a string"; # y is now a string
 //!
+//! # Parallel assignment (all values are evaluated first)
+//! var a = 1;
+//! var b = 2;
+//! a, b = b, a; # swap
+//!
 //! ```
 //!
 //! - Functions
@@ -87,6 +104,17 @@
 //!
 //! if a function is not marked as 'pub', it is private function.
 //! the function can call in same module.
+//! calling it from another module (`test.helper()`, a selective import or a bare call) is a compile error naming the module.
+//! functions can be called before they are defined in the file, but a function name can only be defined once per module.
+//!
+//! parameter lists, call arguments and list literals may end with a trailing comma, which keeps multi-line lists easy to edit.
+//! ```
+//! var pins = [
+//!     13,
+//!     14,
+//!     15,
+//! ];
+//! ```
 //!
 //! if when need to use a return type for function, use '>>' syntax.
 //! ```
@@ -101,11 +129,31 @@
 //!   |-----------------|--------------------------------------|
 //!   | __list_new | for creating a new list|
 //!   | __list_get | for getting an element from a list by index|
+//!   | __str_get | for getting a character from a string by index|
+//!   | __str_slice | for getting a substring (string[start..end])|
+//!   | __list_slice | for copying part of a list into a new list (list[start..end])|
 //!   | __list_push | for pushing an element to the end of a list|
+//!   | __list_concat | for concatenating two lists into a new list|
+//!   | __list_repeat | for repeating a list n times into a new list|
+//!   | __str_repeat | for repeating a string n times into a new string|
 //!   | __range_new | for creating a new range|
 //!   | __println | for printing values to the console|
 //!   | __strlen | for getting the length of a string|
+//!   | __exit | for ending the program with an exit status (exit!)|
+//!   | __clock_ms | for a monotonic clock in milliseconds (now!)|
+//!   | __sleep_ms | for waiting a number of milliseconds (sleep!)|
+//!   | __read_line | for reading a line of standard input (read_line!)|
+//!   | __rand_seed | for seeding the random number generator of rand!|
+//!   | __rand_range | for a random number in a range (rand!)|
+//!   | __math_sqrt, __math_pow, __math_sin, __math_cos, __math_floor, __math_ceil | for the built-in math package|
+//!   | __file_open, __file_read_all, __file_write, __file_close | for the built-in file package|
+//!   | __str_split, __str_trim, __str_to_upper, __str_to_lower, __str_contains, __str_find, __str_chars | for the built-in string package|
+//!   | __to_string | for converting a value to a string (cast! to Str)|
+//!   | __to_int | for converting a value to an Int (cast! to Int)|
+//!   | __to_bool | for converting a value to a Bool (cast! to Bool)|
+//!   | __to_char | for converting a value to a Char (cast! to Char)|
 //!   | __malloc | for allocating memory|
+//!   | __free | for freeing memory from __malloc|
 //!   | __drop | for dropping a value|
 //!   | __clone | for cloning a value|
 //!   | __panic | for handling panic situations|
@@ -161,12 +209,18 @@
 //! }
 //! ```
 //!
+//! Conditions of `if` and `while` must be Bool values; anything else panics with `TypeError: expected Bool in condition`.
+//!
 //! ###  **Operators**
 //! * Arithmetic: `+`, `-`, `*`, `/`, `%`
-//! * Comparison: `==`, `!=`, `<`, `>`, `<=`, `>=`
+//! * Arithmetic on lists: `list1 + list2` (concatenation), `list * 3` (repetition), both create a new list
+//! * String repetition: `"-" * 40` creates a new string
+//! * Comparison: `==`, `!=`, `<`, `>`, `<=`, `>=` (Chars compare by code point)
 //! * Increment/Decrement: `++`, `--`(only for postfix)
 //! * Range creation: `..`(e.g., `1..10`)
-//! * indexing: `list[index]`
+//! * Membership: `x in list`, `"sub" in str`, `'c' in str`, `x in (1..10)` (returns bool)
+//! * indexing: `list[index]`, `string[index]` (negative indices count from the end, e.g. `list[-1]`; a string is indexed by character and gives a one-character Str)
+//! * slicing: `string[start..end]` is the substring from character `start` up to, not including, `end`, and `list[start..end]` a new list with copies of those elements; negative bounds count from the end (`s[0..-1]` drops the last character) and bounds outside the string or list panic
 //!
 //! ###  **Built-in macros**
 //! * `println!(value)`: Print value to the console
@@ -196,6 +250,14 @@
 //! println!(b); # prints 100 as i8
 //! ```
 //!
+//! `cast!(value, Str)` gives the text `println!` would print for a number or Bool, `cast!(value, Int)` parses a Str (surrounding spaces are ignored) and truncates a float, `cast!(value, Bool)` accepts `"true"`, `"false"` and numbers (non-zero is true), and `cast!(value, Char)` takes a code point (`cast!(65, Char)` is `'A'`) or a one-character Str; `cast!(c, Int)` gives the code point of a Char. A value that does not convert panics with the location of the `cast!`:
+//! ```rust
+//! var reading = "  42";
+//! var n = cast!(reading, Int) + 1; # 43
+//! println!("temp=" + cast!(n, Str)); # temp=43
+//! cast!("4x2", Int); # Panic: ValueError: cast! could not convert the value to Int (at ...)
+//! ```
+//!
 //! **Note:** cast! macro is more faster then normal int type, because it use i8 and u8 llvm type directly.
 //! examples:
 //! ```
@@ -215,13 +277,136 @@
 //! }
 //! ```
 //!
+//! * `type_of!(value)`: The name of the value's runtime type as a Str: `Int`, `Float`, `Str`, `Bool`, `List`, `Range`, `Unit`, `Enum`, `Struct`, `Char`, `Moved` (a variable that was moved out), or one of the `cast!` type names `i8` ... `u64`, `fp16`, `fp32`, `fp64`
+//! examples:
+//! ```rust
+//! var a = [1, "two"];
+//! println!(type_of!(a)); # List
+//! println!(type_of!(a[1])); # Str
+//! println!(type_of!(cast!(3, u8))); # u8
+//! ```
+//!
+//! * `is!(value, type)`: Whether the value's runtime type is `type`, as a Bool. It takes the names `type_of!` returns and is a single tag comparison, so it never panics, whatever the value holds
+//! examples:
+//! ```rust
+//! fn describe(v) {
+//!   if is!(v, Str) then {
+//!     println!("text: " + v);
+//!   } else {
+//!     println!("not text: " + type_of!(v));
+//!   }
+//! }
+//! ```
+//!
+//! * `wrapping_add!(a, b, type)`, `wrapping_sub!`, `wrapping_mul!`: Sized integer arithmetic that wraps around on overflow
+//! * `saturating_add!(a, b, type)`, `saturating_sub!`: Sized integer arithmetic that clamps to the type's min/max
+//! examples:
+//! ```
+//! var a = cast!(250, u8);
+//! println!(wrapping_add!(a, cast!(10, u8), u8)); # prints 4
+//! println!(saturating_add!(a, cast!(10, u8), u8)); # prints 255
+//! ```
+//!
+//! * `min!(a, b)`, `max!(a, b)`, `abs!(x)`, `clamp!(x, lo, hi)`: Work on every number type. Integers compare as integers and a float on either side compares both as floats; the result is one of the arguments and keeps its type. Any other value panics with a TypeError
+//! examples:
+//! ```rust
+//! var error = 90;
+//! var duty = clamp!(error * 4, 0, 255); # 255
+//! println!(max!(cast!(3, u8), cast!(200, u8))); # 200
+//! println!(abs!(0.5 - 3.0)); # 2.5
+//! println!(min!(1, 0.5)); # 0.5
+//! ```
+//!
+//! * `exit!(code)`: Ends the program with the Int exit status `code`. Returning an integer from `main` does the same (`return 1;`), while `main` returning anything else exits with 0. `sprs run` exits with the status of the program, so both work in shell pipelines and CI scripts
+//! examples:
+//! ```rust
+//! fn main() {
+//!   var config = read_line!();
+//!   if is!(config, Unit) then {
+//!     println!("no input");
+//!     exit!(2);
+//!   }
+//!   return 0;
+//! }
+//! ```
+//!
+//! * `now!()`: Milliseconds from a monotonic clock, as an Int. Only the difference between two readings means anything. On bare metal the clock is the board's `sprs_clock_ms()`
+//! * `sleep!(ms)`: Waits for `ms` milliseconds. On bare metal it calls the board's `sprs_sleep_ms(ms)`
+//! examples:
+//! ```rust
+//! var start = now!();
+//! while now!() - start < 1000 {
+//!   poll();
+//!   sleep!(10);
+//! }
+//! ```
+//!
+//! * `read_line!()`: The next line of standard input as a Str, without the line ending. At the end of the input it returns Unit instead, so a loop can read until the input is closed. Bare-metal targets have no standard input and always get Unit
+//! examples:
+//! ```rust
+//! var line = read_line!();
+//! while is!(line, Str) {
+//!   println!("> " + line);
+//!   line = read_line!();
+//! }
+//! ```
+//!
+//! * `rand!(lo, hi)`: A random Int in `lo..hi` (`hi` excluded); an empty range panics. `rand_seed!(n)` restarts the sequence from `n`. The generator is a small xorshift in the runtime that needs no libc, and every run produces the same numbers until it is seeded, e.g. from a timer or an ADC reading
+//! examples:
+//! ```rust
+//! rand_seed!(1234);
+//! var roll = rand!(1, 7); # 1 to 6
+//! ```
+//!
+//! * `volatile_read!(addr, u32)`: Reads a memory-mapped register at the Int address `addr`, as a value of the given sized integer type (`i8` to `u64`). `volatile_write!(addr, value, u32)` writes the low bits of the integer `value` there and returns Unit. Both compile to LLVM volatile loads and stores, so the compiler never drops or merges them or reorders them against each other, even when a read looks unused or a write looks overwritten. On a hosted target an address that is not mapped crashes the program
+//! examples:
+//! ```rust
+//! var uart_tx = 268513280; # 0x10013000, UART transmit data register
+//! volatile_write!(uart_tx, 65, u8); # sends 'A'
+//! var status = volatile_read!(uart_tx + 4, u32);
+//! ```
+//!
+//! * `asm!("wfi")`: Inline assembly for the target, e.g. for sleep modes, barriers or control registers. An optional second string holds the LLVM constraints: `"=r"` first makes the value of `$0` the Int result, and every other operand constraint (`"r"`, a register like `"{a0}"`) takes one integer argument, passed in a register as wide as the target's. Clobbers such as `"~{memory}"` take none. The assembly string and the constraints must be literals, and the block is never removed or moved by the optimizer
+//! examples:
+//! ```rust
+//! asm!("fence"); # memory barrier
+//! var cause = asm!("csrr $0, mcause", "=r");
+//! asm!("csrw mtvec, $0", "r,~{memory}", trap_vector);
+//! ```
+//!
+//! * `panic!("message")`: Abort the program with the message and where the `panic!` is. Code after it is not run
+//! examples:
+//! ```
+//! if len > 64 then {
+//!     panic!("frame too long"); # Panic: frame too long (at src/main.sprs:4:5 in function 'send')
+//! }
+//! ```
+//!
+//! * `assert!(cond, "message")`: Panic with an `AssertionError` when `cond` is false. The panic message holds the message, the condition as written and where the `assert!` is. `sprs build --release` leaves the check out, `cond` is then not evaluated at all
+//! examples:
+//! ```
+//! assert!(len > 0, "empty buffer"); # AssertionError: empty buffer (`len > 0` is false) (at src/main.sprs:3:5 in function 'main')
+//! ```
+//!
 //! ###  **module and preprocessor**
 //!
 //! * `#define` for defining macros
 //! Currently this language has
-//! * `#define Windows` or `#define Linux` for OS detection
+//! * `#define Windows`, `#define Linux`, `#define Wasm`, `#define RiscV` or `#define Avr` for OS detection
 //! * 'pkg' for module definition
 //! * 'import' for module importing
+//!   modules may import each other; all functions are declared before any body is compiled, so mutually recursive calls across modules work
+//! * `import sensors as s;` imports a module under a shorter name, so `s.read()` calls `sensors.read()`
+//! * `import math.{sin, cos};` imports the listed functions, which are then called without the module prefix (`sin(x)`); naming a function the module does not have is a compile error
+//! * `import math;` is the built-in math package (unless the project has its own `src/math.sprs`): `math.sqrt(x)`, `math.pow(x, y)`, `math.sin(x)`, `math.cos(x)`, `math.floor(x)` and `math.ceil(x)` take any number and return a Float. They call the `__math_*` functions of the runtime, which use the C libm on bare-metal targets
+//! * `import file;` is the built-in file package (unless the project has its own `src/file.sprs`): `file.open(path, mode)` opens a file for reading (`"r"`), writing (`"w"`, truncating it) or appending (`"a"`) and returns an Int handle, `file.read_all(f)` returns the rest of the file as a Str, `file.write(f, text)` writes a Str and `file.close(f)` closes it. An I/O error, an unknown mode or a closed handle panics. Bare-metal targets have no file system and panic on `file.open`
+//! * `import string;` is the built-in string package (unless the project has its own `src/string.sprs`; `str` is taken by the type keyword): `string.split(s, sep)` returns a List of the parts between the separators (an empty `sep` splits on whitespace), `string.trim(s)` removes leading and trailing whitespace, `string.to_upper(s)` and `string.to_lower(s)` change the case of ASCII letters, `string.contains(s, part)` returns a Bool and `string.find(s, part)` the byte offset of the first match or -1, and `string.chars(s)` returns a List of the Chars of `s`. Every argument must be a Str
+//! * module paths follow the directory layout: `import drivers.uart;` loads `src/drivers/uart.sprs`, which is then used as `uart.init()` (or under an `as` alias); `pkg` accepts the same dotted paths
+//! * `extern fn gpio_write(pin >> i32, val >> i32);` declares a C function, which is then called like a Sprs function in that module: `gpio_write(13, 1);`. Parameters and the result (`extern fn millis() >> u32;`) take `int`, `fp`, `bool`, `str` (a `const char *`) or a sized type such as `i32` or `fp32`; the arguments are converted to those C types, a value of the wrong kind panics with a TypeError, and the C result comes back as an Int, Float, Bool or Str. The function is resolved by the linker, e.g. from the board support code
+//! * `#define DEBUG 1` defines a symbol with a value (a bare `#define Name` has the value 1); `#if DEBUG`, `#if !DEBUG`, `#if BOARD == uno`, `#if BOARD != uno`, `#else` and `#endif` select the code that is compiled. Blocks can be nested, and a symbol that is not defined or is `0` counts as false
+//! * `-D NAME=VALUE` (or `-D NAME` for the value 1) on `build`, `run`, `debug`, `flash` and `test` defines a symbol for every module and takes precedence over a `#define` of the same name, so the source can keep a default: `sprs build -D BOARD=uno`
+//! * symbols defined in `main.sprs` are also seen by the modules it imports
+//! * the compiler defines `TARGET_<ARCH>` and `POINTER_WIDTH_<bits>` for the target triple, so drivers can check the architecture without a `#define` of their own: `TARGET_X86_64`, `TARGET_X86`, `TARGET_AARCH64`, `TARGET_ARM`, `TARGET_RISCV32`, `TARGET_RISCV64`, `TARGET_WASM32` or `TARGET_AVR`, and `POINTER_WIDTH_16`, `POINTER_WIDTH_32` or `POINTER_WIDTH_64`. The target is the one from `--target`, the `#define` in `main.sprs` or the host
 //!
 //! examples:
 //! ```
@@ -263,7 +448,7 @@
 //!
 //! pkg test;
 //!
-//!  fn test() {
+//!  pub fn test() {
 //!            var a = 5 - 1;
 //!            var b = 10;
 //!            var c = "hello" + " world";
@@ -292,14 +477,394 @@
 //!
 //! # To run the project
 //! sprs run
+//!
+//! # Run a single file without sprs.toml; it can import the modules in its directory
+//! sprs run examples/blink.sprs
+//!
+//! # Rebuild and restart the program whenever a source file changes
+//! sprs run --watch
+//!
+//! # Panic on integer overflow instead of wrapping
+//! sprs run --overflow-checks
+//!
+//! # Also write the C header sprs.h to the output directory
+//! sprs build --emit-header
+//!
+//! # Fail the build when the compiler reports warnings
+//! sprs build --deny-warnings
+//!
+//! # Optimize (-O0 to -O3; --release also leaves out assert! and means -O2)
+//! sprs build -O3
+//!
+//! # Print the code size of every function and the text/data/bss totals
+//! sprs build --size-report
+//!
+//! # Time every build phase; -q prints only errors and warnings
+//! sprs build -v
+//!
+//! # Add DWARF debug info for gdb and lldb
+//! sprs build --debug
+//!
+//! # Only write the LLVM IR and assembly of every module, without linking
+//! sprs build --emit=ir,asm
+//!
+//! # Build an embedded image and run flash.command from sprs.toml
+//! sprs flash
+//!
+//! # Run the test_* functions of main.sprs
+//! sprs test
+//! # Check the project with the lint rules from sprs.toml
+//! sprs lint
+//! # Apply the suggested fixes to the source files
+//! sprs fix
+//! # Rename a function everywhere it is used
+//! sprs rename --symbol read_temp --to read_celsius src/sensors.sprs
+//! # Write the definitions and references of the project to out/index.json
+//! sprs index
+//! # Write the documentation of every package from its ## comments to out/doc
+//! sprs doc
+//! # Try statements and expressions one at a time
+//! sprs repl
+//! # Describe a compiler error code with an example
+//! sprs explain E0003
+//! # Serve diagnostics, go-to-definition and hover to an editor
+//! sprs lsp
+//! # Move the sources to the next edition
+//! sprs migrate
+//! # Remove the output directory with its cache; --all also removes vendor/
+//! sprs clean --all
 //! ```
+//! `sprs help` lists the commands, `sprs <command> --help` (or `sprs help <command>`) shows the usage and options of one of them, and `sprs help --all` everything at once. An unknown command or option, or a missing value, stops before anything runs with the usage of the command. `sprs` exits with 0 on success, 1 when the command fails (a compile error, a failing test, ...) and 2 when the command line is wrong, so scripts and CI can tell them apart.
+//!
+//! `-v` (`--verbose`) also prints how long every phase of the build took: parsing the modules, the code generation, optimization and writing of each module, compiling the runtime and linking, plus the `rustc` and linker command lines. `-q` (`--quiet`) prints nothing but errors and warnings, so a successful `sprs build -q` is silent and `sprs run -q` shows only the output of the program.
+//!
+//! Without `-O`, only mem2reg runs, which keeps the values of locals in registers instead of stack slots. `-O1` adds instcombine and simplifycfg, `-O2` (also `-O` and `--release`) gvn and inlining, and `-O3` runs LLVM's full `default<O3>` pipeline and code generation.
+//!
+//! The build writes its artifacts to the output directory (`out_dir` of `sprs.toml`): `<module>.ll` with the LLVM IR after optimization, `<module>.o`, the runtime and the linked program. `--emit=` picks the artifacts instead, as a comma-separated list of `ir` (`.ll`), `asm` (`.s`, the target's assembly), `obj` (`.o`) and `exe` (the program, linked from the objects). Without `exe` nothing is linked, so other build systems can take the objects from there; `run`, `test` and `flash` need it.
+//!
+//! Every module is still parsed and lowered to LLVM IR on each build, but the optimization and code generation of a module whose IR and flags did not change since an earlier build are skipped: its `.ll`, `.s` and `.o` are copied from `<out_dir>/.sprs-cache` instead (`Unchanged: <module>`). Because the key is the IR, a module is rebuilt when a declaration it imports from another module changes, but not for a change inside that function's body. A rebuilt `sprs` binary does not reuse the entries of an earlier one. Delete the directory, or run `sprs clean`, to start over.
+//!
+//! `sprs run --watch` keeps going after the program starts: when a `.sprs` file in the source directory or in a path dependency, or `sprs.toml`, is saved, it stops the program, rebuilds and starts it again. The rebuild goes through the same cache, so only the modules that changed are optimized and compiled again. A build that fails is reported and the program stays stopped until the next save; when the program exits by itself, its exit status is printed and the watch goes on. Ctrl+C ends both. `sprs run <file.sprs> --watch` watches the directory of the file.
+//!
+//! `--size-report` prints, after the objects are written, the code size of every Sprs function from their symbol tables, largest first, and the section totals the way `size` counts them: `text` is code plus read-only data such as string literals, `data` initialized variables and `bss` zeroed ones, so flash use is text + data and RAM use data + bss. The runtime library is linked in afterwards and is not counted. Windows objects carry no symbol sizes, so only the totals are shown there.
+//!
+//! With `--debug`, the objects carry DWARF debug info: every function is a subprogram, every statement a line of its `.sprs` file, and `var` locals keep their names, so `gdb out/<name>` can set breakpoints like `break main.sprs:12`, step through the source and `print count`. A local shows as `sprs_value { tag, data }`, the tag and payload of the runtime value (the tags are listed in the `--emit-header` header); parameters are not listed yet. Combined with `-O`, lines and locals may be merged or optimized out.
+//!
+//! The `[profile.dev]` and `[profile.release]` sections of `sprs.toml` set these per build, like Cargo's profiles: `sprs build --release` uses `[profile.release]`, every other build `[profile.dev]`. A flag on the command line wins over the profile.
+//! ```toml
+//! [profile.dev]
+//! debug = true           # as --debug
+//!
+//! [profile.release]
+//! opt_level = 3          # 0 to 3, as -O0..-O3 (default 2 with --release)
+//! overflow_checks = true # as --overflow-checks
+//! panic = "codes"        # "message" (default) or "codes", as --panic-codes
+//! ```
+//!
+//! With `--overflow-checks`, integer `+`, `-`, `*`, `++` and `--` abort the program with an `OverflowError` panic when the result does not fit in its type. A sized integer is checked at its own width and signedness, so `cast!(1, u8) - cast!(2, u8)` panics, and a `u64` above the `i64` range is not an overflow.
+//!
+//! Runtime panics report where they happened as `file:line:column` and the enclosing function, e.g. `OverflowError: integer overflow in '+' (at src/main.sprs:4:17 in function 'main')`. The position is that of the operator, call or index expression that panicked, so `var total = count + step;` points at `count + step`; other panics point at their statement.
+//!
+//! For size-constrained builds, `--panic-codes` keeps these messages out of the binary. Each panic site passes a 16-bit code instead, printed as `Panic: code 0x0003`. The messages and locations go to `<name>.panics.json` in the output directory:
+//! ```bash
+//! sprs build --panic-codes
+//! sprs decode-panic 0x0003
+//! # OverflowError: integer overflow in '+' (at src/main.sprs:4:17 in function 'main')
+//! ```
+//! `decode-panic` reads the table of the project in the current directory; pass `--table <path>` to use another one. Codes are only valid for the build that wrote the table.
+//!
+//! A `fn panic_handler(msg)` in `main.sprs` replaces the printed `Panic: ...` line, e.g. to blink an LED or stop feeding a watchdog on a board without a console. It receives the message as a Str (cut to at most 127 bytes, between characters; with `--panic-codes` it is `code 0x0003`) and runs once: the program exits with status 1 when it returns, and a panic inside the handler itself is printed as usual.
+//! ```rust
+//! fn panic_handler(msg) {
+//!     println!("fatal: " + msg);
+//!     exit!(3);
+//! }
+//! ```
+//!
+//! Compile errors are collected across all modules and reported together instead of stopping at the first one. The parser recovers at the next `;`, and each function is compiled independently, so one run lists every syntax error and every failing function:
+//!
+//! ```text
+//! error[E0008]: function 'add' is defined more than once
+//!   --> src/main.sprs:7:1
+//!   |
+//! 1 | fn add(a, b) {
+//!   | ------------ first defined here
+//! 7 | fn add(x, y) {
+//!   | ^^^^^^^^^^^^ redefined here
+//! ```
+//!
+//! Errors carry a stable code, such as `E0008` above, that stays with its kind of error across releases. `sprs explain E0008` prints a longer description of it with an example and how to fix it, and `sprs explain` lists all codes. They cover syntax errors (`E0001`), undefined variables, functions, structs and enum variants, type mismatches, functions defined twice, imports of missing or private functions, unknown modules, wrong macro arguments, `#if` errors and features the target does not support. `E0005` explains the `use of moved value` panic, which is found when the program runs.
+//!
+//! Diagnostics are colored when stderr is a terminal; set `NO_COLOR` to disable colors.
+//!
+//! The compiler also warns about unused `var` declarations, unused function parameters and statements after a `return`. Warnings do not stop the build; pass `--deny-warnings` to make them fail it. Names starting with `_` are never reported as unused:
+//! ```sprs
+//! fn on_event(_id, value) {
+//!     println!(value);
+//! }
+//! ```
+//!
+//! `sprs check` reports the same errors and warnings as `sprs build` without optimizing, generating code or linking, and writes nothing to the output directory, which makes it the fast loop for an editor or CI. It takes the build options that change what is compiled (`--target`, `-D`, `--bin`, `--deny-warnings`, ...) and exits with status 1 when there are errors.
+//!
+//! `sprs lint` checks `main.sprs` and every module it imports without building anything. It reports the warnings above plus these rules:
+//!
+//! | Rule | Finds |
+//! | --- | --- |
+//! | `shadowed-variable` | a `var` that redeclares a name already in scope |
+//! | `constant-comparison` | a comparison that is always true or always false, e.g. `x == x` or `1 < 2` |
+//! | `clone-non-heap` | `clone!` of a number or boolean, which is copied anyway |
+//! | `empty-loop` | a `while` loop with an empty body |
+//!
+//! Every rule is a warning by default. The `[lint]` table of `sprs.toml` sets a rule to `"allow"`, `"warn"` or `"deny"`; a denied rule is reported as an error and makes `sprs lint` exit with status 1:
+//! ```toml
+//! [lint]
+//! shadowed-variable = "deny"
+//! empty-loop = "allow"
+//! ```
+//!
+//! Some diagnostics carry a machine-applicable fix, shown as a `= help:` line. `sprs fix` applies them to the source files, similar to `cargo fix`:
+//! * a missing `;` at the end of a line is inserted
+//! * an unused variable or parameter is renamed with a `_` prefix, unless it is assigned later
+//!
+//! `sprs rename --symbol <old> --to <new> <file>` renames a function, struct, variable or module and rewrites its references in `main.sprs` and every module it imports. `old` is looked up in `<file>`:
+//! * a function defined there is renamed along with its calls, `module.old()` calls in other modules and `import module.{old}` lists
+//! * a struct is renamed in every module, struct literals included
+//! * a variable is renamed in every function of that file that declares it (or in the whole file for a top-level `var`)
+//! * a module (`--symbol drivers.uart --to drivers.serial`) has its file moved and its `import` and `pkg` lines and `uart.` prefixes updated; imports with an `as` alias keep the alias
+//!
+//! Only the names change, comments and layout are left alone. Code in an inactive `#if` branch is not rewritten.
+//!
+//! `sprs index [--output <path>]` writes the definitions and references of the project to `<out_dir>/index.json`, for editors and scripts without LSP support. `files` lists the modules that were read and `entries` holds one object per name: `symbol`, `kind` (`function`, `struct`, `variable`, `parameter` or `module`), `definition` (false for a reference), `file`, the 1-based `line` and `column`, and the byte offsets `start` and `end`. Symbols are named after their definition, so every reference to a function shares its `symbol` with the `fn` line:
+//! * `uart.init` for a function, `Point` for a struct and `drivers.uart` for a module
+//! * `main.run.count` for a variable or parameter of `run`, `main.count` for a top-level `var`
+//!
+//! Builtins, enum variants and struct fields are not indexed.
+//!
+//! `--emit-header` writes `sprs.h`, a C header with the value tags, the `SprsValue` struct layout (`int32_t tag; uint64_t data;`) and the runtime function prototypes, for C code that exchanges values with a Sprs program.
+//!
+//! A function with more than 1024 local variables prints a warning at build time, and its locals and parameters are stored in a single frame array instead of one stack slot each. The intermediate values of its expressions go into a second array, where each statement reuses the slots of the statements before it, so the frame grows with the largest statement rather than with the length of the function.
+//!
+//! ## WebAssembly
+//! With `#define Wasm` the program is compiled for `wasm32-wasip1` and linked into `<name>.wasm`; `sprs run` starts it with `wasmtime`.
+//! Printing and allocation go through WASI, so the module also runs in browsers with a WASI shim.
+//! This needs the Rust target (`rustup target add wasm32-wasip1`) and a WASI sysroot for clang, e.g. from wasi-sdk, passed through the `WASI_SYSROOT` environment variable.
+//!
+//! ## Embedded RISC-V
+//! `#define RiscV` compiles for bare-metal rv32imac (`riscv32-unknown-none-elf`) and links `<name>.elf` with the project's `link.ld`.
+//! `sprs init --template embedded` creates that linker script. Adjust its `MEMORY` block for your chip.
+//! The runtime is built with `--cfg sprs_embedded` (no_std, a 4 KiB bump heap, and startup code that runs `main`; see [Freestanding builds](#freestanding-builds)).
+//! `println!` output goes to `sprs_write(ptr, len)`, which your board support code provides, e.g. for a UART. Without it, the output is discarded.
+//! `now!` and `sleep!` use `sprs_clock_ms()` and `sprs_sleep_ms(ms)` from the board support code, e.g. backed by a SysTick timer. Without them, the clock reads 0 and `sleep!` returns at once.
+//! This requires `rustup target add riscv32imac-unknown-none-elf`, plus clang and lld or the `linker` of [Linking](#linking).
+//!
+//! After linking, the `[flash]` section of `sprs.toml` controls the flash images. `formats` lists the extra images written next to the `.elf` (`bin` for a raw binary, `hex` for Intel HEX), produced with `objcopy` (default `llvm-objcopy`).
+//! `sprs flash` builds the project and then runs `command`, with `{file}` replaced by the image selected by `artifact` (`elf` by default):
+//! ```toml
+//! [flash]
+//! formats = ["bin", "hex"]
+//! artifact = "elf"
+//! command = "probe-rs download --chip <your-chip> {file}"
+//! # or: command = "openocd -f board.cfg -c \"program {file} verify reset exit\""
+//! ```
+//! `sprs init --template embedded` writes a `[flash]` section with both formats; only `command` has to be filled in.
+//!
+//! On RISC-V and AVR, a string literal that is the tail of a longer one (`"error"` and `"fatal error"`) is stored once, and the build prints how many bytes that saved.
+//! `--compress-strings` goes further for literals of 48 bytes or more: they are stored LZSS-compressed and unpacked into the heap the first time they are used. This trades some CPU time and heap space for flash, and the heap copy is never freed, so keep the 4 KiB bump heap in mind.
+//!
+//! ## Freestanding builds
+//! `sprs build --no-std` builds the runtime without std or libc for the host architecture, the same way bare-metal targets get it: with `--cfg sprs_embedded`, its core in `runtime.rs` and the platform layer in `embedded.rs`.
+//! Nothing is linked; the build prints the objects and `libruntime.a` to link with your own startup code, which calls `main`.
+//! The board support code provides `sprs_write`, `sprs_clock_ms` and `sprs_sleep_ms` (declared in the `--emit-header` header), and the toolchain the usual `memcpy`, `memset`, `bcmp` and `strlen`; `import math;` also needs libm.
+//! The heap is the one chosen in [Runtime allocator](#runtime-allocator); startup code can call `__sprs_heap_init(ptr, len)` before `main` to give the bump or pool allocator its own RAM region instead.
+//! `--no-std` cannot be combined with `--debug-alloc`, `sprs test` or Wasm.
+//!
+//! ## Runtime allocator
+//! The `[runtime]` section of `sprs.toml` chooses the allocator the runtime uses for lists, ranges, structs and strings:
+//! ```toml
+//! [runtime]
+//! allocator = "pool" # "libc", "bump" or "pool"
+//! heap_size = 16384  # bytes, for "bump" and "pool"
+//! ```
+//! * `libc`: `malloc` and `free` of the C library. The default on hosted targets; on bare metal it needs the toolchain's libc, e.g. newlib
+//! * `bump`: a static heap that only grows, frees are ignored. The default on bare metal, with 4 KiB
+//! * `pool`: the same static heap carved into blocks of 16 bytes to 4 KiB in power-of-two sizes. A freed block is reused by the next allocation of its size, so a program that drops what it allocates keeps running; a single allocation over 4 KiB fails
+//!
+//! `heap_size` defaults to 4 KiB on bare metal and 1 MiB on hosted targets. When the heap is used up, hosted programs abort with `memory allocation of N bytes failed` and bare-metal ones stop.
+//! The AVR build command printed by `sprs build` always uses the bump heap.
+//!
+//! ## Linking
+//! The `[link]` section of `sprs.toml` controls the final link. `libs` and `search_paths` add libraries, so the C functions behind `extern fn` declarations resolve:
+//! ```toml
+//! [link]
+//! libs = ["m", "mydriver"]  # passed as -lm -lmydriver
+//! search_paths = ["vendor"] # passed as -Lvendor
+//! ```
+//! For a board image, `linker` replaces clang, `linker_script` is passed as `-T` on any target (on RISC-V in place of `link.ld`), and `flags` are passed to the linker as they are:
+//! ```toml
+//! [link]
+//! linker = "riscv64-unknown-elf-gcc"
+//! linker_script = "boards/hifive1.ld"
+//! flags = ["-Wl,--gc-sections", "-Wl,-Map=out/app.map"]
+//! ```
+//! With a `linker` set, the clang-only `--target` and `-fuse-ld=lld` are left out; a clang used as `linker` gets them back through `flags`. The arguments come after the runtime library on the command line. `--no-std` builds print them with the link instructions instead.
+//!
+//! `lto = true` in `[link]` optimizes the program as a whole: all modules are linked into one before the passes of the `-O` level run, so a call into another module can be inlined. Only `main` and the `pub fn`s of `main.sprs` stay visible to the linker (make a function `pub` there when startup code calls it by name); functions nothing calls are dropped. The build then writes a single `main.ll`/`main.o`.
+//!
+//! ## Experimental AVR
+//! `#define Avr` targets 8-bit AVR (ATmega328P, Arduino Uno class). This is a feasibility mode, not a finished target.
+//! Values use a reduced layout there: a `u8` tag and 32 bits of data, so `Int` is 32 bits wide.
+//! Features that need 64 bits are rejected at compile time with their `file:line:column`: `f64` (float literals, `Float`, `fp64`), `i64`/`u64` and integer literals outside the 32-bit range.
+//! `sprs build` only writes the program objects. The runtime has to be built with a nightly toolchain (`-Z build-std`, `--cfg sprs_embedded --cfg sprs_avr`) and linked with `avr-gcc`; the build prints the exact commands.
+//!
+//! ## Documentation
+//! `##` comments directly above `pkg`, `fn`, `extern fn`, `struct` or `enum` are doc comments, written in Markdown. `sprs doc` turns them into a page per package in `<out_dir>/doc`, with the signature of every `pub` item (the first line of a function, up to its body) and its documentation, and an `index` page linking the packages. A fenced block in a doc comment is shown as code, which is the place for examples:
+//! ```sprs
+//! ## Temperature sensors on the I2C bus.
+//! pkg sensors;
+//!
+//! ## Reads the sensor at `pin` and returns degrees Celsius.
+//! ##
+//! ## ```
+//! ## var t = sensors.read_temp(4);
+//! ## ```
+//! pub fn read_temp(pin) >> int {
+//!     return pin * 10;
+//! }
+//! ```
+//! ```bash
+//! sprs doc                      # HTML in out/doc
+//! sprs doc --format markdown    # Markdown instead
+//! sprs doc --private --output docs
+//! ```
+//! `--private` also documents the items that are not `pub`, `--output` writes the pages to another directory. A blank line or a plain `#` comment between a doc comment and its item detaches it. The modules of dependencies are not documented; run `sprs doc` in their projects.
+//!
+//! ## REPL
+//! `sprs repl` reads a statement, an item (`fn`, `struct`, `enum`, `import`) or an expression at a time and evaluates it with the interpreter, without building anything. Every entry runs once, in the environment the earlier ones left: variables, functions and types stay defined, and the value of an expression is printed. An entry continues on the next line while a bracket is open.
+//!
+//! ```
+//! repl> var x = 20;
+//! repl> fn double(n) >> int { return n * 2; }
+//! repl> double(x) + 2
+//! 42
+//! ```
+//!
+//! A panic ends only its entry; what the entry did before it stays done. A function, struct or enum entered again replaces the old one. `import` loads modules from `src/` of the current directory and the built-in `math`, `file` and `string` packages, and `read_line!` reads the lines typed after the entry. `asm!`, `volatile_read!`, `volatile_write!` and `extern fn` need compiled code and are not available, and the interpreter does not move values, so `a` stays usable after `var b = a;`. `:vars` prints the variables, `:reset` forgets every definition and `:quit` (or the end of the input) leaves.
+//!
+//! ## Editor support
+//! `sprs lsp` is a language server for editors, spoken over stdin/stdout, for the project in the folder the editor opens. When a file is opened or saved it publishes the diagnostics of `sprs check` for every module, with their error codes. Go-to-definition jumps to the definition of a function, struct, module or variable, across modules and dependencies. Hover shows the signature and `##` doc comment of a function or struct, the doc of a module, and for a `var` the runtime type it starts with (as `type_of!` names it) when that follows from its value, such as a literal, a struct literal or a call to a function with a return type. The server reads the saved files, so save before jumping from code you just typed.
+//!
+//! ## Testing
+//! `sprs test` builds `main.sprs` with a test entry point instead of `main`. It runs every `test_*` function without parameters, in source order, and a test fails when it panics.
+//! ```bash
+//! sprs test
+//! # compile for another target and run the image through a command, {file} is the image path
+//! sprs test --target riscv32imac-unknown-none-elf --runner "./flash-and-read-uart.sh {file}"
+//! ```
+//! `--target <triple>` overrides the `#define` target and also works with `build`, `run` and `flash`.
+//! Without `--runner`, the image runs on the host (or under `wasmtime` for Wasm). Bare-metal images need a runner, for example a script that flashes the board and prints its serial output.
+//!
+//! The runtime reports results as plain lines on the console: stdout on the host, `sprs_write` on bare metal. This is the same path a UART uses, so results can be read back from real hardware in CI:
+//! ```text
+//! SPRS-TEST BEGIN test_add
+//! SPRS-TEST PASS test_add
+//! SPRS-TEST BEGIN test_mul
+//! SPRS-TEST FAIL test_mul: OverflowError: integer overflow in '*' (at src/main.sprs:9:5 in function 'test_mul')
+//! ```
+//! When every test passes, the last line is `SPRS-TEST DONE <total>`. Other output, including text before `SPRS-TEST` on the same line, is passed through. The runner is stopped after `DONE` or the first `FAIL`, because a panic ends the image. `sprs test` exits with status 1 when a test fails or the image stops early.
+//!
+//! `sprs test --examples` runs golden-output tests instead: every `.sprs` file under `tests/` (or `examples/` when there is no `tests/`) that has a `.expected` file next to it is built on its own, like `sprs run <file>`, and run, and its standard output has to match the `.expected` file. Line endings and trailing whitespace at the end are ignored, and the exit status is not checked. The `.sprs` files without a `.expected` file are modules the programs import. The build options and `--runner` apply to every program, so the same programs can run on a board. The compiler repository tests itself this way:
+//! ```bash
+//! sprs test --examples
+//! # example tests/arithmetic.sprs ... ok
+//! # ...
+//! # example result: ok. 4 passed; 0 failed
+//! ```
+//!
+//! ## Embedding the Runtime
+//! The runtime is also its own package, `sprs-runtime`, so other frontends or test harnesses can link it directly:
+//! ```bash
+//! cargo build -p sprs-runtime --release
+//! ```
+//! This produces `libsprs_runtime.a` and a shared library (`libsprs_runtime.so` / `sprs_runtime.dll`) in `target/release`.
+//! Its C ABI is the one described by the header from `sprs build --emit-header`.
+//!
+//! ## Plugin Macros
+//! Domain-specific builtins can live outside the core compiler. Besides the `sprs` binary, the package has a library target, so a Rust driver can depend on `sprs` (as a git or path dependency), implement `sprs::llvm::plugin::MacroPlugin` for a macro name such as `can_frame!` and pass it to `sprs::llvm::llvm_executer::build_and_run_with_macros` along with its `sprs::command_helper::BuildOptions`.
+//! `expand` rewrites the call into another Sprs expression before the module is linted and compiled, and `codegen` emits LLVM IR through the `Compiler` when no rewrite is returned.
+//! The core builtins (`println!`, `clone!`, `cast!`, ...) cannot be replaced, and a name can only be registered once.
 //!
 //! ## Project Initialization
-//! To initialize a new Sprs project, use the following command:
+//! To create a new Sprs project, use one of the following commands:
 //! ```bash
+//! # in a new directory blinky/
+//! sprs new blinky --template embedded
+//! # in the current directory
 //! sprs init --name <project_name>
 //! ```
-//! This command creates a new directory structure with a default `sprs.toml` configuration file and a sample `main.sprs` source file.
+//! Both write a default `sprs.toml` configuration file and a sample `src/main.sprs` source file. `--template` picks the starter project:
+//! * `cli` (the default): a program for the host that prints a greeting
+//! * `embedded`: a `main.sprs` targeting bare-metal RISC-V, a `link.ld` linker script and a `[flash]` section
+//! * `lib`: a module `src/util.sprs` for other projects to import as `<name>.util`, and a `main.sprs` with a test for `sprs test`
+//!
+//! ### Project layout
+//! `sprs.toml` says where the sources are and what the build produces:
+//! ```toml
+//! name = "blinky"
+//! version = "0.1.0"
+//! src_dir = "src"        # the sources, and where imported modules are looked up
+//! out_dir = "out"        # IR, objects and the linked program
+//! entry = "main.sprs"    # the file in src_dir the build starts from
+//! bin_name = "firmware"  # the linked program, `name` when not set
+//! ```
+//! The entry file is the `main` module whatever its file name, so `sprs lint`, `fix`, `migrate` and `rename` start from it too.
+//!
+//! A project can hold several programs, e.g. the firmware and a tool for the host, as `[[bin]]` tables. Each has an entry file in `src_dir` and builds into `<out_dir>/<name>/`:
+//! ```toml
+//! [[bin]]
+//! name = "firmware"
+//! entry = "firmware.sprs"
+//!
+//! [[bin]]
+//! name = "uploader"
+//! entry = "uploader.sprs"
+//! ```
+//! `sprs build` builds all of them, `--bin <name>` picks one: `sprs run --bin uploader`, `sprs flash --bin firmware`. `run`, `flash` and `test` need `--bin` when there is more than one. `lint`, `fix`, `migrate` and `rename` still read the sources from `entry`.
+//!
+//! Modules of another Sprs project are imported through `[dependencies]`. Its modules are found in the `src_dir` of its own `sprs.toml` and are imported with the dependency name in front:
+//! ```toml
+//! [dependencies]
+//! mylib = { path = "../mylib" }
+//! ```
+//! ```sprs
+//! import mylib.uart;           # ../mylib/src/uart.sprs
+//! import mylib.drivers.gpio;   # ../mylib/src/drivers/gpio.sprs
+//! ```
+//! Inside `mylib` its modules still import each other by their own names (`import regs;`). Only its `pub fn`s can be called. The dependencies of a dependency are not followed, and `lint`, `fix`, `migrate` and `rename` leave its files alone.
+//!
+//! `sprs add mylib --path ../mylib` writes the entry (or changes the path of an existing one) after checking that `../mylib` has a `sprs.toml`, and `sprs remove mylib` deletes it. Both only touch that line, so the comments and layout of `sprs.toml` are kept.
+//!
+//! A dependency can also come from a git repository, e.g. a driver library shared by several firmware projects:
+//! ```toml
+//! [dependencies]
+//! drivers = { git = "https://github.com/example/sprs-drivers", rev = "v1.2" }
+//! ```
+//! `sprs build` (and `run`, `flash`, `test`) clones it into `vendor/drivers/` and writes the commit it used to `sprs.lock`. Later builds check out that commit, so a project builds the same code everywhere until `git` or `rev` changes; then the dependency is fetched again and the lock is updated. `rev` is a branch, tag or commit, the default branch without it. Commit `sprs.lock` with the project. `sprs add drivers --git <url> --rev v1.2` adds such an entry.
+//!
+//! A repository with several projects, e.g. firmware, bootloader and host tools, can have a workspace `sprs.toml` at its root that lists them:
+//! ```toml
+//! [workspace]
+//! members = ["firmware", "bootloader", "tools/flasher"]
+//! ```
+//! `sprs build` at the root builds every member in its own directory, with its own `sprs.toml`, and stops at the first one that fails; `sprs test` runs the tests of all of them. The members share one artifact cache in `.sprs-cache` at the root, so a module they have in common (such as a path dependency) is compiled once. Unlike a project's own cache it keeps older versions too; delete it to free the space. `run`, `flash` and the other commands work in a member's directory.
+//!
+//! ### Editions
+//! `edition` in `sprs.toml` selects the keyword set, so a program written before a word became a keyword keeps using it as a name. `sprs init` writes the current edition; without the field the current edition is used.
+//! * `edition = "2025"`: `in` and `as` are ordinary names (no `x in list` or `import m as n`)
+//! * `edition = "2026"`: `in` and `as` are keywords
+//!
+//! A parse error on such a word points at the edition that reserved it.
+//!
+//! `sprs migrate` moves a project to the next edition: every name that became a keyword is respelled with a `_` suffix in all modules (`in` becomes `in_`, or `in_2` when `in_` is taken), inactive `#if` branches included, and the `edition` line of `sprs.toml` is updated. A module whose path contains such a word has to be renamed with `sprs rename` first.
 //!
 //! ## Memory Management
 //!
@@ -320,103 +885,205 @@
 //!}
 //!
 //! ```
+//!
+//! **Evaluation order:**
+//! Function arguments are evaluated from left to right.
+//! Variables passed as arguments are moved only after all arguments have been evaluated. In `f(a, a)` the first parameter takes the value of `a` and the second gets a copy of it, as with `clone!`, so the callee owns two separate values.
+//! Only heap values (strings, lists, ranges and structs) are moved; numbers and booleans are copied.
+//!
+//! Reading a variable after its value was moved panics with `use of moved value: <name>`.
+//!
+//! A struct owns its fields: a variable used in a struct literal is moved into it, reading a field gives a copy of a string, list or struct stored there, and when the struct is dropped its fields are dropped with it, nested structs included. `clone!` copies a struct together with its fields.
+//!
+//! `--debug-alloc` (build/run) is for checking the move system: the runtime keeps a table of the lists, ranges, structs and `__malloc` string buffers it hands out, and when `main` returns or `exit!` is called it prints the ones still live to stderr, counted per type:
+//! ```text
+//! debug-alloc: 3 allocations (152 bytes) still live at exit
+//!   List        2        128 bytes
+//!   Struct      1         24 bytes
+//! ```
+//! Heap strings are never freed, so concatenations always show up under `Str`. The table needs `std`, so bare-metal targets reject the flag.
+//!
+//! The same table catches memory errors: dropping a list, range or struct twice, or cloning one after it was dropped, panics with `__drop: List at 0x... was already dropped or was never allocated` instead of corrupting the heap. Without the flag the runtime still ignores drops of Unit, moved and null values, and `__clone` panics on a null pointer or an unknown tag rather than dereferencing it.
+//!
+//! When `clone!(x)` is passed straight to a function call and `x` is not used again afterwards, the compiler skips the copy and moves `x` into the call instead.
+//!
+//! A string concatenation whose result is only printed, compared or concatenated again never leaves its statement, so it is built in a 256-byte stack buffer instead of on the heap. Longer strings fall back to the heap. A list literal that is only searched, as in `x in [a, b, c]`, is not allocated either: its elements are compared in an array on the stack. Other lists are heap-allocated because the runtime owns their storage.
 
-use crate::command_helper::HelpCommand;
-use crate::command_helper::get_all_arguments;
-use crate::command_helper::help_print;
-use crate::llvm::llvm_executer;
-
-mod command_helper;
-mod front;
-mod grammar;
-mod interpreter;
-mod llvm;
-mod runtime;
+use sprs::cli::{self, Command};
+use sprs::llvm::{self, llvm_executer};
+use sprs::{command_helper, crash_report, front, repl};
 
 fn main() {
-    let argv: Vec<String> = std::env::args().collect();
-
-    let argc = argv.len();
-
-    if argc <= 1 {
-        eprintln!("Usage: sprs help --all");
-        return;
+    // a compiler panic leaves a crash report instead of only a backtrace on the console
+    crash_report::install_hook();
+    if std::panic::catch_unwind(run).is_err() {
+        let argv: Vec<String> = std::env::args().collect();
+        crash_report::report(&argv);
+        std::process::exit(101);
     }
+}
 
-    if argc > 1 {
-        let _path = argv[0].clone();
-        let command = argv[1].clone();
+fn run() {
+    let argv: Vec<String> = std::env::args().collect();
 
-        if command == "init" {
-            if argc > 2 {
-                let args = &argv[2..];
+    let command = match cli::parse(&argv[1..]) {
+        Ok(command) => command,
+        Err(e) => {
+            cli::print_usage_error(&e);
+            std::process::exit(cli::EXIT_USAGE);
+        }
+    };
 
-                let mut iter = args.iter();
-                while let Some(arg) = iter.next() {
-                    if arg == "--name" {
-                        if let Some(proj_name) = iter.next() {
-                            command_helper::init_project(Some(proj_name));
-                            return;
-                        }
-                    } else {
-                        eprintln!("Usage: sprs init --name <project_name>");
-                        return;
-                    }
+    let full_path = argv[0].clone();
+    let ok = match command {
+        Command::New { path, template } => command_helper::new_project(&path, template),
+        Command::Init { name, template } => command_helper::init_project(name.as_deref(), template),
+        Command::Build(options) => {
+            llvm_executer::build_and_run(full_path, llvm_executer::ExecuteMode::Build, options)
+        }
+        Command::Run {
+            file,
+            options,
+            watch: true,
+        } => llvm::watch::run(full_path, file.as_deref(), options),
+        Command::Run {
+            file: Some(file),
+            options,
+            watch: false,
+        } => llvm_executer::run_file(&file, llvm_executer::ExecuteMode::Run, options),
+        Command::Run {
+            file: None,
+            options,
+            watch: false,
+        } => llvm_executer::build_and_run(full_path, llvm_executer::ExecuteMode::Run, options),
+        Command::Flash(options) => {
+            llvm_executer::build_and_run(full_path, llvm_executer::ExecuteMode::Flash, options)
+        }
+        Command::Test {
+            options,
+            runner,
+            examples: true,
+        } => llvm_executer::test_examples(options, runner),
+        Command::Test {
+            options,
+            runner,
+            examples: false,
+        } => llvm_executer::build_and_run(
+            full_path,
+            llvm_executer::ExecuteMode::Test { runner },
+            options,
+        ),
+        Command::Check(options) => {
+            llvm_executer::build_and_run(full_path, llvm_executer::ExecuteMode::Check, options)
+        }
+        Command::Debug(options) => {
+            println!("interpreter currently not support yet.");
+            llvm_executer::build_and_run(full_path, llvm_executer::ExecuteMode::Debug, options)
+        }
+        Command::Repl => repl::run(),
+        Command::Lsp => llvm::lsp::run(),
+        Command::Dump { file, tokens, ast } => llvm_executer::dump_file(&file, tokens, ast),
+        Command::Lint => llvm_executer::lint_project(),
+        Command::Fix => llvm_executer::fix_project(),
+        Command::Migrate => llvm_executer::migrate_project(),
+        Command::Clean { all } => match command_helper::clean_project(all) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{}", e);
+                false
+            }
+        },
+        Command::Rename { symbol, to, file } => llvm_executer::rename_symbol(&symbol, &to, &file),
+        Command::FuzzGrammar { iterations, seed } => llvm_executer::fuzz_grammar(iterations, seed),
+        Command::Add { name, dependency } => {
+            let source = dependency
+                .path
+                .clone()
+                .or_else(|| dependency.git.clone())
+                .unwrap_or_default();
+            match command_helper::add_dependency(&name, &dependency) {
+                Ok(()) => {
+                    println!("Added dependency {} ({})", name, source);
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    false
                 }
-            } else {
-                println!("Initializing project without arguments.");
-                command_helper::init_project(None);
             }
-            eprintln!("Unknown error during project initialization.");
-            return;
         }
-
-        if command == "build" {
-            if argc > 2 {
-                println!("not supported yet with arguments.");
-            } else {
-                llvm_executer::build_and_run(argv[0].clone(), llvm_executer::ExecuteMode::Build);
+        Command::Remove { name } => match command_helper::remove_dependency(&name) {
+            Ok(()) => {
+                println!("Removed dependency {}", name);
+                true
             }
-            return;
-        }
-
-        if command == "run" {
-            if argc > 2 {
-                println!("not supported yet with arguments.");
-            } else {
-                llvm_executer::build_and_run(argv[0].clone(), llvm_executer::ExecuteMode::Run);
+            Err(e) => {
+                eprintln!("{}", e);
+                false
             }
-            return;
-        }
-
-        if command == "debug" {
-            if argc > 2 {
-                println!("not supported yet with arguments.");
-            } else {
-                println!("interpreter currently not support yet.");
-                llvm_executer::build_and_run(argv[0].clone(), llvm_executer::ExecuteMode::Debug);
+        },
+        Command::Index { output } => llvm_executer::index_project(output.as_deref()),
+        Command::Doc {
+            format,
+            output,
+            private,
+        } => llvm_executer::doc_project(format, output.as_deref(), private),
+        Command::Explain { code } => match code {
+            Some(code) => match front::error_codes::find(&code) {
+                Some(entry) => {
+                    println!("{}: {}\n\n{}", entry.code, entry.title, entry.explanation);
+                    true
+                }
+                None => {
+                    eprintln!("Unknown error code: {} (`sprs explain` lists them)", code);
+                    false
+                }
+            },
+            None => {
+                for entry in front::error_codes::ERROR_CODES {
+                    println!("{}  {}", entry.code, entry.title);
+                }
+                true
             }
-            return;
-        }
-
-        if command == "help" {
-            let args = get_all_arguments(argv.clone());
-
-            if args.is_empty() {
-                help_print(HelpCommand::NoArg);
-                return;
-            } else {
-                if args.contains(&"--all".to_string()) {
-                    help_print(HelpCommand::All);
-                    return;
+        },
+        Command::DecodePanic { code, table } => {
+            let table = table.unwrap_or_else(|| {
+                let config = command_helper::load_project_config();
+                let out_dir = config
+                    .as_ref()
+                    .map(|c| c.out_dir.clone())
+                    .unwrap_or_else(|| "build".to_string());
+                let proj_name = config
+                    .as_ref()
+                    .map(|c| c.bin_name.clone().unwrap_or_else(|| c.name.clone()))
+                    .unwrap_or_else(|| "sprs_project".to_string());
+                llvm::panic_table::table_path(&out_dir, &proj_name)
+            });
+            let site = llvm::panic_table::parse_code(&code)
+                .and_then(|code| llvm::panic_table::decode(&table, code));
+            match site {
+                Ok(site) => {
+                    println!("{}", site.describe());
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    false
                 }
             }
         }
-        if command == "version" {
+        Command::Help { command, all } => {
+            cli::print_help(command, all);
+            true
+        }
+        Command::Version => {
             println!("sprs version: {}", env!("CARGO_PKG_VERSION"));
-            return;
+            true
         }
     };
+    if !ok {
+        std::process::exit(cli::EXIT_FAILURE);
+    }
 
     // interprinter
     /*
@@ -0,0 +1,169 @@
+use std::io::Write;
+
+use crate::front::ast::{Expr, Item, Stmt, StmtKind};
+use crate::interpreter::executer::{RuntimeContext, Value};
+use crate::interpreter::runner::parse_only;
+
+// `sprs repl`: reads one statement, item or expression at a time and evaluates it with the
+// interpreter. Every entry runs once, in the environment the earlier ones left: variables,
+// functions and types stay defined, and an expression is printed. A panic only ends the entry.
+
+const PROMPT: &str = "repl> ";
+const CONTINUE_PROMPT: &str = "...> ";
+
+// The interpreter recurses with the program, so deep recursion needs a larger stack.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+enum Entry {
+    Items(Vec<Item>),
+    Statements(Vec<Stmt>),
+    Expression(Expr),
+}
+
+pub fn run() -> bool {
+    let session = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(session);
+    match session.map(|handle| handle.join()) {
+        Ok(Ok(())) => true,
+        Ok(Err(_)) => false,
+        Err(e) => {
+            eprintln!("Failed to start the repl: {}", e);
+            false
+        }
+    }
+}
+
+fn session() {
+    println!(
+        "Sprs {} repl. Enter statements, items or expressions; :help for commands.",
+        env!("CARGO_PKG_VERSION")
+    );
+    let mut ctx = RuntimeContext::new();
+    while let Some(input) = read_entry() {
+        let input = input.trim();
+        match input {
+            "" => continue,
+            ":quit" | ":q" => break,
+            ":help" => {
+                println!(":vars   print the variables and their values");
+                println!(":reset  forget every variable, function and type");
+                println!(":quit   leave the repl (or end the input)");
+                continue;
+            }
+            ":vars" => {
+                let mut names: Vec<_> = ctx.global_scope.keys().collect();
+                names.sort();
+                for name in names {
+                    println!("{} = {}", name, ctx.global_scope[name]);
+                }
+                continue;
+            }
+            ":reset" => {
+                ctx = RuntimeContext::new();
+                continue;
+            }
+            _ => {}
+        }
+
+        let result = match parse_entry(input) {
+            Ok(Entry::Items(items)) => items.into_iter().try_for_each(|item| ctx.define(item)),
+            Ok(Entry::Statements(stmts)) => ctx.run(&stmts),
+            Ok(Entry::Expression(expr)) => ctx.evaluate(&expr).map(|val| {
+                if val != Value::Unit {
+                    println!("{}", val);
+                }
+            }),
+            Err(e) => Err(e),
+        };
+        let _ = std::io::stdout().flush();
+        if let Err(e) = result {
+            eprintln!("{}", e);
+        }
+    }
+}
+
+// One entry, continued on further lines while its braces, brackets or parentheses are open.
+// Stdin is not kept locked, so `read_line!` in an entry reads the lines after it.
+fn read_entry() -> Option<String> {
+    let mut entry = String::new();
+    loop {
+        print!(
+            "{}",
+            if entry.is_empty() {
+                PROMPT
+            } else {
+                CONTINUE_PROMPT
+            }
+        );
+        let _ = std::io::stdout().flush();
+        let mut line = String::new();
+        match std::io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) if entry.is_empty() => {
+                println!();
+                return None;
+            }
+            Ok(0) | Err(_) => return Some(entry),
+            Ok(_) => {}
+        }
+        entry.push_str(&line);
+        if depth(&entry) <= 0 {
+            return Some(entry);
+        }
+    }
+}
+
+// Open brackets minus closed ones, outside strings and comments.
+fn depth(source: &str) -> i32 {
+    let mut depth = 0;
+    for line in source.lines() {
+        let mut in_string = false;
+        let mut escaped = false;
+        for c in line.chars() {
+            if in_string {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match c {
+                '"' => in_string = true,
+                '#' => break,
+                '{' | '[' | '(' => depth += 1,
+                '}' | ']' | ')' => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+    depth
+}
+
+// Statements when the input parses as the body of a function, else items, else an expression
+// to print. Input that is none of them reports the errors of reading it as statements.
+fn parse_entry(input: &str) -> Result<Entry, String> {
+    let body = |source: &str| match parse_only(source, "repl") {
+        Ok(mut items) => match items.pop() {
+            Some(Item::FunctionItem(func)) => Ok(func.blk),
+            _ => Ok(Vec::new()),
+        },
+        Err(e) => Err(e),
+    };
+    let statement_errors = match body(&format!("fn main() {{\n{}\n}}", input)) {
+        Ok(stmts) => return Ok(Entry::Statements(stmts)),
+        Err(e) => e,
+    };
+    if let Ok(items) = parse_only(input, "repl") {
+        return Ok(Entry::Items(items));
+    }
+    if !input.ends_with(';') {
+        let mut stmts = body(&format!("fn main() {{\n{};\n}}", input)).unwrap_or_default();
+        let only = if stmts.len() == 1 { stmts.pop() } else { None };
+        if let Some(StmtKind::Expr(expr)) = only.map(|stmt| stmt.kind) {
+            return Ok(Entry::Expression(expr));
+        }
+    }
+    Err(statement_errors)
+}
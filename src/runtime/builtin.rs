@@ -2,7 +2,7 @@ use crate::interpreter::executer::Value;
 
 pub fn builtin_function_push(args: &[Value]) -> Result<Value, String> {
     if args.len() != 2 {
-        return Err("push function requires 2 arguments".to_string());
+        return Err("list_push! expects 2 arguments".to_string());
     }
     let list = &args[0];
     let value = &args[1];
@@ -13,14 +13,13 @@ pub fn builtin_function_push(args: &[Value]) -> Result<Value, String> {
             vec.push(value.clone());
             Ok(Value::Unit)
         }
-        _ => Err("First argument to push must be a list".to_string()),
+        _ => Err("Panic: TypeError: list_push! expects a List".to_string()),
     }
 }
 
 pub fn builtin_function_println(args: &[Value]) -> Result<Value, String> {
-    for (_, arg) in args.iter().enumerate() {
+    for arg in args {
         println!("{}", arg);
     }
-    println!();
     Ok(Value::Unit)
 }
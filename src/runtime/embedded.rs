@@ -0,0 +1,104 @@
+// Platform layer of the freestanding runtime (`--cfg sprs_embedded`): everything the core in
+// runtime.rs would otherwise take from std or libc. Board support code plugs in through the
+// `sprs_*` functions below; the heap itself is in runtime.rs (`mod heap`).
+
+use core::fmt::Write;
+
+unsafe extern "C" {
+    // Provided by the board support code, e.g. writes the bytes to a UART.
+    fn sprs_write(ptr: *const u8, len: usize);
+    // A millisecond timer and a delay for `now!` / `sleep!`, e.g. from SysTick.
+    pub fn sprs_clock_ms() -> u32;
+    pub fn sprs_sleep_ms(ms: u32);
+}
+
+// Defaults (via PROVIDE in the linker script) when the board has none.
+#[unsafe(no_mangle)]
+pub extern "C" fn __sprs_write_null(_ptr: *const u8, _len: usize) {}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __sprs_clock_ms_null() -> u32 {
+    0
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __sprs_sleep_ms_null(_ms: u32) {}
+
+struct Console;
+
+impl Write for Console {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        unsafe { sprs_write(s.as_ptr(), s.len()) };
+        Ok(())
+    }
+}
+
+pub fn write_line(args: core::fmt::Arguments) {
+    let _ = Console.write_fmt(args);
+    let _ = Console.write_str("\n");
+}
+
+// `[runtime] allocator = "libc"`: the toolchain's C malloc (e.g. newlib) instead of the
+// heap in runtime.rs. malloc only guarantees 8-byte alignment; the runtime needs no more.
+#[cfg(sprs_alloc = "libc")]
+mod c_malloc {
+    unsafe extern "C" {
+        fn malloc(size: usize) -> *mut u8;
+        fn free(ptr: *mut u8);
+    }
+
+    struct MallocAllocator;
+
+    unsafe impl core::alloc::GlobalAlloc for MallocAllocator {
+        unsafe fn alloc(&self, layout: core::alloc::Layout) -> *mut u8 {
+            if layout.align() > 8 {
+                return core::ptr::null_mut();
+            }
+            unsafe { malloc(layout.size()) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, _layout: core::alloc::Layout) {
+            unsafe { free(ptr) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: MallocAllocator = MallocAllocator;
+}
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    super::std::process::exit(1)
+}
+
+// Reset entry: set up gp/sp, zero .bss, copy .data from flash, then run main.
+// The symbols come from the linker script written by `sprs init --template embedded`.
+#[cfg(target_arch = "riscv32")]
+core::arch::global_asm!(
+    ".section .text.init",
+    ".global _start",
+    "_start:",
+    ".option push",
+    ".option norelax",
+    "    la gp, __global_pointer$",
+    ".option pop",
+    "    la sp, _stack_top",
+    "    la t0, _sbss",
+    "    la t1, _ebss",
+    "1:  bgeu t0, t1, 2f",
+    "    sw zero, 0(t0)",
+    "    addi t0, t0, 4",
+    "    j 1b",
+    "2:  la t0, _sidata",
+    "    la t1, _sdata",
+    "    la t2, _edata",
+    "3:  bgeu t1, t2, 4f",
+    "    lw t3, 0(t0)",
+    "    sw t3, 0(t1)",
+    "    addi t0, t0, 4",
+    "    addi t1, t1, 4",
+    "    j 3b",
+    "4:  call main",
+    "5:  wfi",
+    "    j 5b",
+);
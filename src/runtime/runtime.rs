@@ -1,7 +1,202 @@
+// The exported functions are the C ABI called by generated code and take raw pointers by design.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+// Bare-metal targets (e.g. riscv32imac) and `sprs build --no-std` build the runtime with `--cfg sprs_embedded`.
+#![cfg_attr(sprs_embedded, no_std)]
+// With the AVR value layout some payload casts below become same-width casts.
+#![cfg_attr(sprs_avr, allow(clippy::unnecessary_cast))]
+
+#[cfg(sprs_embedded)]
+extern crate alloc;
+#[cfg(sprs_embedded)]
+use alloc::{boxed::Box, vec::Vec};
+
+// Bare-metal stand-ins for the std paths used below.
+#[cfg(sprs_embedded)]
+mod std {
+    pub use alloc::alloc;
+    pub use core::{ffi, ptr};
+
+    pub mod process {
+        pub fn exit(_code: i32) -> ! {
+            #[allow(clippy::empty_loop)]
+            loop {}
+        }
+    }
+}
+
+// The platform layer for freestanding builds (console, clock, heap, panic, startup).
+#[cfg(sprs_embedded)]
+mod embedded;
+
+// The allocator behind every Box and Vec of the runtime, chosen with `[runtime] allocator` in
+// sprs.toml (`--cfg sprs_alloc="libc" | "bump" | "pool"`). Hosted builds default to libc
+// malloc through std, bare-metal ones to the bump heap. The runtime is single-threaded, so
+// the heap state needs no lock.
+#[cfg(any(
+    sprs_alloc = "bump",
+    sprs_alloc = "pool",
+    all(sprs_embedded, not(sprs_alloc = "libc"))
+))]
+mod heap {
+    use core::alloc::{GlobalAlloc, Layout};
+    use core::ptr::null_mut;
+
+    // `[runtime] heap_size` in bytes, passed by `sprs build` in the environment of rustc.
+    const HEAP_SIZE: usize = match option_env!("SPRS_HEAP_SIZE") {
+        Some(size) => parse_size(size),
+        None if cfg!(sprs_embedded) => 4 * 1024,
+        None => 1024 * 1024,
+    };
+
+    const fn parse_size(text: &str) -> usize {
+        let bytes = text.as_bytes();
+        let mut value = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i].is_ascii_digit(), "SPRS_HEAP_SIZE is not a number");
+            value = value * 10 + (bytes[i] - b'0') as usize;
+            i += 1;
+        }
+        value
+    }
+
+    static mut HEAP: [u8; HEAP_SIZE] = [0; HEAP_SIZE];
+    static mut HEAP_BASE: *mut u8 = null_mut();
+    static mut HEAP_LEN: usize = HEAP_SIZE;
+    static mut NEXT: usize = 0;
+
+    // Startup code can hand the allocator its own RAM region instead of the built-in heap,
+    // before `main` runs: later calls would orphan what was already allocated.
+    #[unsafe(no_mangle)]
+    pub extern "C" fn __sprs_heap_init(ptr: *mut u8, len: usize) {
+        unsafe {
+            HEAP_BASE = ptr;
+            HEAP_LEN = len;
+            NEXT = 0;
+        }
+    }
+
+    // Takes the next `size` bytes of the heap region; null when it is used up.
+    unsafe fn carve(size: usize, align: usize) -> *mut u8 {
+        unsafe {
+            if HEAP_BASE.is_null() {
+                HEAP_BASE = &raw mut HEAP as *mut u8;
+            }
+            let base = HEAP_BASE as usize;
+            let start = ((base + NEXT + align - 1) & !(align - 1)) - base;
+            if start + size > HEAP_LEN {
+                return null_mut();
+            }
+            NEXT = start + size;
+            HEAP_BASE.add(start)
+        }
+    }
+
+    // `allocator = "bump"` (bare-metal default): frees are ignored, so the heap only fills up.
+    #[cfg(not(sprs_alloc = "pool"))]
+    struct BumpAllocator;
+
+    #[cfg(not(sprs_alloc = "pool"))]
+    unsafe impl GlobalAlloc for BumpAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            unsafe { carve(layout.size(), layout.align()) }
+        }
+
+        unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+    }
+
+    #[cfg(not(sprs_alloc = "pool"))]
+    #[global_allocator]
+    static ALLOCATOR: BumpAllocator = BumpAllocator;
+
+    // `allocator = "pool"`: blocks of 16 bytes to 4 KiB in power-of-two classes, carved from
+    // the heap on first use. A freed block goes on the free list of its class (the list link
+    // lives in the block) and the next allocation of that class reuses it. Larger requests fail.
+    #[cfg(sprs_alloc = "pool")]
+    const POOL_CLASSES: usize = 9;
+
+    #[cfg(sprs_alloc = "pool")]
+    static mut FREE_LISTS: [*mut u8; POOL_CLASSES] = [null_mut(); POOL_CLASSES];
+
+    #[cfg(sprs_alloc = "pool")]
+    fn pool_class(layout: Layout) -> Option<usize> {
+        let block = layout.size().max(layout.align()).max(16).next_power_of_two();
+        let class = block.trailing_zeros() as usize - 4;
+        (class < POOL_CLASSES).then_some(class)
+    }
+
+    #[cfg(sprs_alloc = "pool")]
+    struct PoolAllocator;
+
+    #[cfg(sprs_alloc = "pool")]
+    unsafe impl GlobalAlloc for PoolAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let Some(class) = pool_class(layout) else {
+                return null_mut();
+            };
+            unsafe {
+                let head = (&raw mut FREE_LISTS as *mut *mut u8).add(class);
+                if !(*head).is_null() {
+                    let block = *head;
+                    *head = *(block as *mut *mut u8);
+                    return block;
+                }
+                // aligned to its own size, so a reused block fits any request of its class
+                let block_size = 16 << class;
+                carve(block_size, block_size)
+            }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            if let Some(class) = pool_class(layout) {
+                unsafe {
+                    let head = (&raw mut FREE_LISTS as *mut *mut u8).add(class);
+                    *(ptr as *mut *mut u8) = *head;
+                    *head = ptr;
+                }
+            }
+        }
+    }
+
+    #[cfg(sprs_alloc = "pool")]
+    #[global_allocator]
+    static ALLOCATOR: PoolAllocator = PoolAllocator;
+}
+
+#[cfg(sprs_embedded)]
+macro_rules! println {
+    ($($arg:tt)*) => {
+        embedded::write_line(format_args!($($arg)*))
+    };
+}
+
+#[cfg(sprs_embedded)]
+macro_rules! eprintln {
+    ($($arg:tt)*) => {
+        embedded::write_line(format_args!($($arg)*))
+    };
+}
+
+// Value layout shared with the generated code: { i32 tag, i64 data }, or { i8 tag, i32 data }
+// on the experimental AVR target (`--cfg sprs_avr`). `Word` is the width of Int, indices and sizes.
+#[cfg(not(sprs_avr))]
+pub type RawTag = i32;
+#[cfg(not(sprs_avr))]
+pub type RawData = u64;
+#[cfg(not(sprs_avr))]
+pub type Word = i64;
+
+#[cfg(sprs_avr)]
+pub type RawTag = u8;
+#[cfg(sprs_avr)]
+pub type RawData = u32;
+#[cfg(sprs_avr)]
+pub type Word = i32;
+
 #[repr(C)]
 pub struct SprsValue {
-    pub tag: i32,
-    pub data: u64,
+    pub tag: RawTag,
+    pub data: RawData,
 }
 
 pub enum Tag {
@@ -15,6 +210,8 @@ pub enum Tag {
     Unit = 6,
     Enum = 7,
     Struct = 8,
+    Moved = 9, // the compiler writes it over a variable whose value was moved
+    Char = 10, // a Unicode scalar value
 
     // System types
     Int8 = 100,
@@ -31,6 +228,12 @@ pub enum Tag {
     Float64 = 110,
 }
 
+// RawData is only narrower than u64 on AVR, where f64 is rejected at compile time.
+#[allow(clippy::useless_conversion)]
+fn f64_from_data(data: RawData) -> f64 {
+    f64::from_bits(data.into())
+}
+
 fn f16_tof32(bit: u16) -> f32 {
     let sign = (bit >> 15) as u32;
     let exp = ((bit >> 10) & 0x1F) as u32;
@@ -61,42 +264,247 @@ fn f16_tof32(bit: u16) -> f32 {
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn __list_new(capacity: i64) -> *mut Vec<SprsValue> {
-    let vec = Vec::with_capacity(capacity as usize);
-    Box::into_raw(Box::new(vec))
+pub extern "C" fn __list_new(capacity: Word) -> *mut Vec<SprsValue> {
+    new_list(Vec::with_capacity(capacity as usize))
+}
+
+// Every list handed to the generated code is boxed here, so `--debug-alloc` sees it.
+fn new_list(list: Vec<SprsValue>) -> *mut Vec<SprsValue> {
+    let size = core::mem::size_of::<Vec<SprsValue>>()
+        + list.capacity() * core::mem::size_of::<SprsValue>();
+    let ptr = Box::into_raw(Box::new(list));
+    track_alloc(ptr as RawData, size, Tag::List);
+    ptr
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn __list_push(list_ptr: *mut Vec<SprsValue>, tag: i32, data: u64) {
+pub extern "C" fn __list_push(list_ptr: *mut Vec<SprsValue>, tag: RawTag, data: RawData) {
     let list = unsafe { &mut *list_ptr };
     list.push(SprsValue { tag, data });
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn __list_get(list_ptr: *mut Vec<SprsValue>, index: i64) -> *mut SprsValue {
+pub extern "C" fn __list_get(list_ptr: *mut Vec<SprsValue>, index: Word) -> *mut SprsValue {
     let list = unsafe { &mut *list_ptr };
 
-    if index < 0 || (index as usize) >= list.len() {
-        eprintln!("Index out of bounds: {}", index);
-        std::process::exit(1);
+    let idx = normalize_index(index, list.len());
+    &mut list[idx]
+}
+
+// Negative indices count from the end, so -1 is the last element.
+fn normalize_index(index: Word, len: usize) -> usize {
+    let idx = if index < 0 {
+        index + len as Word
+    } else {
+        index
+    };
+    if idx < 0 || (idx as usize) >= len {
+        report_panic(&format_args!(
+            "Index out of bounds: {} (length {})",
+            index, len
+        ));
+    }
+    idx as usize
+}
+
+// `s[index]` counts characters, not bytes, and gives the character as a one-character Str,
+// written to `out` (the caller's result slot).
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_get(s_ptr: *const i8, index: Word, out: *mut SprsValue) {
+    let chars: Vec<char> = utf8_chars(c_bytes(s_ptr)).collect();
+    let c = chars[normalize_index(index, chars.len())];
+
+    let mut buf = [0; 4];
+    unsafe {
+        *out = SprsValue {
+            tag: Tag::String as RawTag,
+            data: new_c_string(c.encode_utf8(&mut buf).as_bytes()) as RawData,
+        };
+    }
+}
+
+// `s[start..end]`: the characters from `start` up to, not including, `end`, counted like
+// `s[index]`. Negative bounds count from the end like indices do, so `s[0..-1]` drops the
+// last character.
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_slice(s_ptr: *const i8, start: Word, end: Word) -> *mut i8 {
+    let chars: Vec<char> = utf8_chars(c_bytes(s_ptr)).collect();
+    let (from, to) = slice_bounds(start, end, chars.len());
+
+    let mut bytes = Vec::new();
+    for c in &chars[from..to] {
+        bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+    }
+    new_c_string(&bytes)
+}
+
+// `xs[start..end]`: a new list with copies of the elements, bounds as in `__str_slice`.
+#[unsafe(no_mangle)]
+pub extern "C" fn __list_slice(
+    list_ptr: *mut Vec<SprsValue>,
+    start: Word,
+    end: Word,
+) -> *mut Vec<SprsValue> {
+    let list = unsafe { &*list_ptr };
+    let (from, to) = slice_bounds(start, end, list.len());
+
+    let new_vec = list[from..to]
+        .iter()
+        .map(|val| __clone(val.tag, val.data))
+        .collect();
+    new_list(new_vec)
+}
+
+// `start..end` of a slice of `len` items, with negative bounds counted from the end.
+fn slice_bounds(start: Word, end: Word, len: usize) -> (usize, usize) {
+    let len = len as Word;
+    let from = if start < 0 { start + len } else { start };
+    let to = if end < 0 { end + len } else { end };
+    if from < 0 || to > len || from > to {
+        report_panic(&format_args!(
+            "Slice out of bounds: {}..{} (length {})",
+            start, end, len
+        ));
+    }
+    (from as usize, to as usize)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __list_concat(
+    l_ptr: *mut Vec<SprsValue>,
+    r_ptr: *mut Vec<SprsValue>,
+) -> *mut Vec<SprsValue> {
+    let l_list = unsafe { &*l_ptr };
+    let r_list = unsafe { &*r_ptr };
+
+    let mut new_vec = Vec::with_capacity(l_list.len() + r_list.len());
+    for val in l_list.iter().chain(r_list.iter()) {
+        new_vec.push(__clone(val.tag, val.data));
+    }
+    new_list(new_vec)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __list_repeat(list_ptr: *mut Vec<SprsValue>, times: Word) -> *mut Vec<SprsValue> {
+    let list = unsafe { &*list_ptr };
+    let times = if times < 0 { 0 } else { times as usize };
+
+    let mut new_vec = Vec::with_capacity(list.len() * times);
+    for _ in 0..times {
+        for val in list {
+            new_vec.push(__clone(val.tag, val.data));
+        }
+    }
+    new_list(new_vec)
+}
+
+fn values_equal(a_tag: RawTag, a_data: RawData, b_tag: RawTag, b_data: RawData) -> bool {
+    if a_tag != b_tag {
+        return false;
+    }
+    if a_tag == Tag::String as RawTag {
+        let a = unsafe { std::ffi::CStr::from_ptr(a_data as *const i8) };
+        let b = unsafe { std::ffi::CStr::from_ptr(b_data as *const i8) };
+        return a == b;
+    }
+    a_data == b_data
+}
+
+// `needle in container` for lists, strings and ranges. Returns 1 or 0.
+#[unsafe(no_mangle)]
+pub extern "C" fn __contains(
+    needle_tag: RawTag,
+    needle_data: RawData,
+    container_tag: RawTag,
+    container_data: RawData,
+) -> Word {
+    let found = match container_tag {
+        t if t == Tag::List as RawTag => {
+            let list = unsafe { &*(container_data as *const Vec<SprsValue>) };
+            list.iter()
+                .any(|val| values_equal(val.tag, val.data, needle_tag, needle_data))
+        }
+        t if t == Tag::String as RawTag && needle_tag == Tag::String as RawTag => {
+            let haystack = unsafe { std::ffi::CStr::from_ptr(container_data as *const i8) };
+            let needle = unsafe { std::ffi::CStr::from_ptr(needle_data as *const i8) };
+            haystack
+                .to_string_lossy()
+                .contains(needle.to_string_lossy().as_ref())
+        }
+        t if t == Tag::String as RawTag && needle_tag == Tag::Char as RawTag => {
+            let haystack = unsafe { std::ffi::CStr::from_ptr(container_data as *const i8) };
+            haystack
+                .to_string_lossy()
+                .contains(char_from_data(needle_data))
+        }
+        t if t == Tag::Range as RawTag && needle_tag == Tag::Integer as RawTag => {
+            let range = unsafe { &*(container_data as *const SprsRange) };
+            let n = needle_data as Word;
+            range.start <= n && n < range.end
+        }
+        _ => {
+            eprintln!("TypeError: unsupported operand types for 'in'");
+            std::process::exit(1);
+        }
+    };
+    found as Word
+}
+
+// `x in [a, b]` whose list does not escape: the compiler passes the elements in an array on
+// its stack instead of allocating a list.
+#[unsafe(no_mangle)]
+pub extern "C" fn __contains_values(
+    needle_tag: RawTag,
+    needle_data: RawData,
+    items: *const SprsValue,
+    len: Word,
+) -> Word {
+    let items = unsafe { core::slice::from_raw_parts(items, len as usize) };
+    items
+        .iter()
+        .any(|val| values_equal(val.tag, val.data, needle_tag, needle_data)) as Word
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_repeat(s_ptr: *const i8, times: Word) -> *mut i8 {
+    let c_str = unsafe { std::ffi::CStr::from_ptr(s_ptr) };
+    let bytes = c_str.to_bytes();
+    let times = if times < 0 { 0 } else { times as usize };
+
+    let len = bytes.len() * times;
+    let layout = std::alloc::Layout::from_size_align(len + 1, 1).unwrap();
+    let ptr = unsafe { std::alloc::alloc(layout) };
+    for i in 0..times {
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.add(i * bytes.len()), bytes.len());
+        }
     }
-    &mut list[index as usize]
+    unsafe {
+        *ptr.add(len) = 0;
+    }
+    ptr as *mut i8
 }
 
+#[repr(C)]
 pub struct SprsRange {
-    pub start: i64,
-    pub end: i64,
+    pub start: Word,
+    pub end: Word,
 }
 #[unsafe(no_mangle)]
-pub extern "C" fn __range_new(start: i64, end: i64) -> *mut SprsRange {
-    let range = Box::new(SprsRange { start, end });
-    Box::into_raw(range)
+pub extern "C" fn __range_new(start: Word, end: Word) -> *mut SprsRange {
+    let ptr = Box::into_raw(Box::new(SprsRange { start, end }));
+    track_alloc(
+        ptr as RawData,
+        core::mem::size_of::<SprsRange>(),
+        Tag::Range,
+    );
+    ptr
 }
 
 #[repr(C)]
 pub struct EnumInfo {
     pub name: *const i8,
-    pub variant_index: i64,
+    pub variant_index: Word,
 }
 
 #[unsafe(no_mangle)]
@@ -105,94 +513,98 @@ pub extern "C" fn __println(list_ptr: *mut Vec<SprsValue>) {
 
     for (i, val) in list.iter().enumerate() {
         match val.tag {
-            t if t == Tag::Integer as i32 => {
+            t if t == Tag::Integer as RawTag => {
                 // integer
-                println!("{}", val.data as i64);
+                println!("{}", val.data as Word);
             }
-            t if t == Tag::Float as i32 => {
+            t if t == Tag::Float as RawTag => {
                 // float
                 let float_bits = val.data;
-                let float_value = f64::from_bits(float_bits);
+                let float_value = f64_from_data(float_bits);
                 println!("{}", float_value);
             }
-            t if t == Tag::Float16 as i32 => {
+            t if t == Tag::Float16 as RawTag => {
                 // f16
                 let float_bits = val.data as u16;
                 let float_value = f16_tof32(float_bits);
                 println!("{}", float_value);
             }
-            t if t == Tag::Float32 as i32 => {
+            t if t == Tag::Float32 as RawTag => {
                 // f32
                 let float_bits = val.data as u32;
                 let float_value = f32::from_bits(float_bits);
                 println!("{}", float_value);
             }
-            t if t == Tag::Float64 as i32 => {
+            t if t == Tag::Float64 as RawTag => {
                 // f64
                 let float_bits = val.data;
-                let float_value = f64::from_bits(float_bits);
+                let float_value = f64_from_data(float_bits);
                 println!("{}", float_value);
             }
-            t if t == Tag::String as i32 => {
+            t if t == Tag::String as RawTag => {
                 // string
                 let c_str = unsafe { std::ffi::CStr::from_ptr(val.data as *const i8) };
                 println!("{}", c_str.to_string_lossy());
             }
-            t if t == Tag::Boolean as i32 => {
+            t if t == Tag::Boolean as RawTag => {
                 // boolean
                 let bool_str = if val.data != 0 { "true" } else { "false" };
                 println!("{}", bool_str);
             }
-            t if t == Tag::List as i32 => {
+            t if t == Tag::Char as RawTag => {
+                // char
+                println!("{}", char_from_data(val.data));
+            }
+            t if t == Tag::List as RawTag => {
                 // list
                 println!(
                     "Value[{}]: <list at {:p}>",
                     i, val.data as *mut Vec<SprsValue>
                 );
             }
-            t if t == Tag::Range as i32 => {
+            t if t == Tag::Range as RawTag => {
                 // range
                 let range_ptr = val.data as *mut SprsRange;
                 let range = unsafe { &*range_ptr };
                 println!("Value[{}]: <range {}..{}>", i, range.start, range.end);
             }
-            t if t == Tag::Int8 as i32 => {
+            t if t == Tag::Int8 as RawTag => {
                 // i8
                 println!("{}", val.data as i8);
             }
-            t if t == Tag::Uint8 as i32 => {
+            t if t == Tag::Uint8 as RawTag => {
                 // u8
                 println!("{}", val.data as u8);
             }
-            t if t == Tag::Int16 as i32 => {
+            t if t == Tag::Int16 as RawTag => {
                 // i16
                 println!("{}", val.data as i16);
             }
-            t if t == Tag::Uint16 as i32 => {
+            t if t == Tag::Uint16 as RawTag => {
                 // u16
                 println!("{}", val.data as u16);
             }
-            t if t == Tag::Int32 as i32 => {
+            t if t == Tag::Int32 as RawTag => {
                 // i32
                 println!("{}", val.data as i32);
             }
-            t if t == Tag::Uint32 as i32 => {
+            t if t == Tag::Uint32 as RawTag => {
                 // u32
                 println!("{}", val.data as u32);
             }
-            t if t == Tag::Int64 as i32 => {
+            t if t == Tag::Int64 as RawTag => {
                 // i64
                 println!("{}", val.data as i64);
             }
-            t if t == Tag::Uint64 as i32 => {
+            t if t == Tag::Uint64 as RawTag => {
                 // u64
-                println!("{}", val.data as u64);
+                println!("{}", val.data);
             }
-            t if t == Tag::Unit as i32 => {
+            t if t == Tag::Unit as RawTag => {
                 // unit
                 println!("Value[{}]: ()", i);
             }
-            t if t == Tag::Enum as i32 => {
+            t if t == Tag::Enum as RawTag => {
                 // enum
                 let info = unsafe { &*(val.data as *const EnumInfo) };
                 let c_str = unsafe { std::ffi::CStr::from_ptr(info.name) };
@@ -202,7 +614,7 @@ pub extern "C" fn __println(list_ptr: *mut Vec<SprsValue>) {
                     name_str, info.variant_index
                 );
             }
-            t if t == Tag::Struct as i32 => {
+            t if t == Tag::Struct as RawTag => {
                 // struct
                 println!("Value[{}]: <struct at {:p}>", i, val.data as *mut u8);
             }
@@ -214,53 +626,801 @@ pub extern "C" fn __println(list_ptr: *mut Vec<SprsValue>) {
 }
 
 #[unsafe(no_mangle)]
-pub extern "C" fn __strlen(s_ptr: *const i8) -> i64 {
+pub extern "C" fn __strlen(s_ptr: *const i8) -> Word {
     let c_str = unsafe { std::ffi::CStr::from_ptr(s_ptr) };
-    c_str.to_bytes().len() as i64
+    c_str.to_bytes().len() as Word
+}
+
+// Conversions behind `cast!(x, Str)`, `cast!(x, Int)` and `cast!(x, Bool)`.
+// They report failure (null, or 0 with nothing written to `out`) and the generated code
+// panics with the location of the `cast!`.
+#[unsafe(no_mangle)]
+pub extern "C" fn __to_string(tag: RawTag, data: RawData) -> *mut i8 {
+    use core::fmt::Write;
+
+    let mut out = CStrBuf(Vec::new());
+    let _ = match tag {
+        t if t == Tag::String as RawTag => {
+            let c_str = unsafe { std::ffi::CStr::from_ptr(data as *const i8) };
+            out.0.extend_from_slice(c_str.to_bytes());
+            Ok(())
+        }
+        t if t == Tag::Boolean as RawTag => out.write_str(if data != 0 { "true" } else { "false" }),
+        t if t == Tag::Char as RawTag => out.write_char(char_from_data(data)),
+        t if t == Tag::Float as RawTag || t == Tag::Float64 as RawTag => {
+            write!(out, "{}", f64_from_data(data))
+        }
+        t if t == Tag::Float16 as RawTag => write!(out, "{}", f16_tof32(data as u16)),
+        t if t == Tag::Float32 as RawTag => write!(out, "{}", f32::from_bits(data as u32)),
+        _ => match int_value(tag, data) {
+            Some(value) => write!(out, "{}", value),
+            None => return std::ptr::null_mut(),
+        },
+    };
+    out.0.push(0);
+    Box::into_raw(out.0.into_boxed_slice()) as *mut i8
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __to_int(tag: RawTag, data: RawData, out: *mut Word) -> Word {
+    let value = match tag {
+        t if t == Tag::String as RawTag => {
+            let c_str = unsafe { std::ffi::CStr::from_ptr(data as *const i8) };
+            c_str
+                .to_str()
+                .ok()
+                .and_then(|s| s.trim().parse::<Word>().ok())
+        }
+        t if t == Tag::Boolean as RawTag => Some((data != 0) as Word),
+        t if t == Tag::Char as RawTag => Some(data as Word),
+        _ => match float_value(tag, data) {
+            // truncated toward zero, NaN and values out of range fail
+            Some(f) if f.is_finite() && f >= Word::MIN as f64 && f < -(Word::MIN as f64) => {
+                Some(f as Word)
+            }
+            Some(_) => None,
+            None => int_value(tag, data).and_then(|v| Word::try_from(v).ok()),
+        },
+    };
+    match value {
+        Some(value) => {
+            unsafe { *out = value };
+            1
+        }
+        None => 0,
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __to_bool(tag: RawTag, data: RawData, out: *mut Word) -> Word {
+    let value = match tag {
+        t if t == Tag::String as RawTag => {
+            let c_str = unsafe { std::ffi::CStr::from_ptr(data as *const i8) };
+            match c_str.to_bytes() {
+                b"true" => Some(true),
+                b"false" => Some(false),
+                _ => None,
+            }
+        }
+        t if t == Tag::Boolean as RawTag => Some(data != 0),
+        _ => match float_value(tag, data) {
+            Some(f) => Some(f != 0.0),
+            None => int_value(tag, data).map(|v| v != 0),
+        },
+    };
+    match value {
+        Some(value) => {
+            unsafe { *out = value as Word };
+            1
+        }
+        None => 0,
+    }
+}
+
+// `cast!(x, Char)`: a code point from an integer, or the only character of a Str.
+#[unsafe(no_mangle)]
+pub extern "C" fn __to_char(tag: RawTag, data: RawData, out: *mut Word) -> Word {
+    let value = match tag {
+        t if t == Tag::Char as RawTag => Some(char_from_data(data)),
+        t if t == Tag::String as RawTag => {
+            let c_str = unsafe { std::ffi::CStr::from_ptr(data as *const i8) };
+            let mut chars = c_str.to_str().ok().map(|s| s.chars());
+            match chars.as_mut().map(|c| (c.next(), c.next())) {
+                Some((Some(c), None)) => Some(c),
+                _ => None,
+            }
+        }
+        _ => int_value(tag, data)
+            .and_then(|v| u32::try_from(v).ok())
+            .and_then(char::from_u32),
+    };
+    match value {
+        Some(value) => {
+            unsafe { *out = value as Word };
+            1
+        }
+        None => 0,
+    }
+}
+
+// Char values only ever hold valid scalar values; anything else prints as U+FFFD.
+fn char_from_data(data: RawData) -> char {
+    char::from_u32(data as u32).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+// The value of an integer tag, widened so u64 and i64 both fit.
+fn int_value(tag: RawTag, data: RawData) -> Option<i128> {
+    let value = match tag {
+        t if t == Tag::Integer as RawTag => data as Word as i128,
+        t if t == Tag::Int8 as RawTag => data as i8 as i128,
+        t if t == Tag::Uint8 as RawTag => data as u8 as i128,
+        t if t == Tag::Int16 as RawTag => data as i16 as i128,
+        t if t == Tag::Uint16 as RawTag => data as u16 as i128,
+        t if t == Tag::Int32 as RawTag => data as i32 as i128,
+        t if t == Tag::Uint32 as RawTag => data as u32 as i128,
+        t if t == Tag::Int64 as RawTag => data as i64 as i128,
+        t if t == Tag::Uint64 as RawTag => data as i128,
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn float_value(tag: RawTag, data: RawData) -> Option<f64> {
+    let value = match tag {
+        t if t == Tag::Float as RawTag || t == Tag::Float64 as RawTag => f64_from_data(data),
+        t if t == Tag::Float16 as RawTag => f16_tof32(data as u16) as f64,
+        t if t == Tag::Float32 as RawTag => f32::from_bits(data as u32) as f64,
+        _ => return None,
+    };
+    Some(value)
+}
+
+// `write!` target for building a NUL-terminated string without `String` (not in no_std).
+struct CStrBuf(Vec<u8>);
+
+impl core::fmt::Write for CStrBuf {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+// The built-in `string` package (src/llvm/string.rs). Like indexing, it works on bytes:
+// positions are byte offsets and case mapping only changes ASCII letters.
+fn c_bytes<'a>(s_ptr: *const i8) -> &'a [u8] {
+    unsafe { std::ffi::CStr::from_ptr(s_ptr) }.to_bytes()
+}
+
+fn new_c_string(bytes: &[u8]) -> *mut i8 {
+    let mut out = Vec::with_capacity(bytes.len() + 1);
+    out.extend_from_slice(bytes);
+    out.push(0);
+    Box::into_raw(out.into_boxed_slice()) as *mut i8
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
 }
 
+// Invalid bytes become U+FFFD.
+fn utf8_chars(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    bytes.utf8_chunks().flat_map(|chunk| {
+        let invalid = (!chunk.invalid().is_empty()).then_some(char::REPLACEMENT_CHARACTER);
+        chunk.valid().chars().chain(invalid)
+    })
+}
+
+// The characters of a UTF-8 string as Char values.
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_chars(s_ptr: *const i8) -> *mut Vec<SprsValue> {
+    let list: Vec<SprsValue> = utf8_chars(c_bytes(s_ptr))
+        .map(|c| SprsValue {
+            tag: Tag::Char as RawTag,
+            data: c as RawData,
+        })
+        .collect();
+    new_list(list)
+}
+
+// An empty separator splits on runs of whitespace.
 #[unsafe(no_mangle)]
-pub extern "C" fn __malloc(size: i64) -> *mut i8 {
+pub extern "C" fn __str_split(s_ptr: *const i8, sep_ptr: *const i8) -> *mut Vec<SprsValue> {
+    let s = c_bytes(s_ptr);
+    let sep = c_bytes(sep_ptr);
+    let mut parts = Vec::new();
+    if sep.is_empty() {
+        for part in s
+            .split(|b| b.is_ascii_whitespace())
+            .filter(|part| !part.is_empty())
+        {
+            parts.push(part);
+        }
+    } else {
+        let mut rest = s;
+        while let Some(at) = find_bytes(rest, sep) {
+            parts.push(&rest[..at]);
+            rest = &rest[at + sep.len()..];
+        }
+        parts.push(rest);
+    }
+
+    let list: Vec<SprsValue> = parts
+        .into_iter()
+        .map(|part| SprsValue {
+            tag: Tag::String as RawTag,
+            data: new_c_string(part) as RawData,
+        })
+        .collect();
+    new_list(list)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_trim(s_ptr: *const i8) -> *mut i8 {
+    new_c_string(c_bytes(s_ptr).trim_ascii())
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_to_upper(s_ptr: *const i8) -> *mut i8 {
+    let upper: Vec<u8> = c_bytes(s_ptr).iter().map(u8::to_ascii_uppercase).collect();
+    new_c_string(&upper)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_to_lower(s_ptr: *const i8) -> *mut i8 {
+    let lower: Vec<u8> = c_bytes(s_ptr).iter().map(u8::to_ascii_lowercase).collect();
+    new_c_string(&lower)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_contains(s_ptr: *const i8, needle_ptr: *const i8) -> Word {
+    find_bytes(c_bytes(s_ptr), c_bytes(needle_ptr)).is_some() as Word
+}
+
+// The offset of the first match, or -1.
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_find(s_ptr: *const i8, needle_ptr: *const i8) -> Word {
+    match find_bytes(c_bytes(s_ptr), c_bytes(needle_ptr)) {
+        Some(at) => at as Word,
+        None => -1,
+    }
+}
+
+// The built-in `math` package (src/llvm/math.rs). Arguments arrive as f64 already.
+#[unsafe(no_mangle)]
+pub extern "C" fn __math_sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __math_pow(x: f64, y: f64) -> f64 {
+    x.powf(y)
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __math_sin(x: f64) -> f64 {
+    x.sin()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __math_cos(x: f64) -> f64 {
+    x.cos()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __math_floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __math_ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+// The f64 methods above are std-only; bare metal takes them from the toolchain's C libm.
+#[cfg(sprs_embedded)]
+mod libm {
+    unsafe extern "C" {
+        fn sqrt(x: f64) -> f64;
+        fn pow(x: f64, y: f64) -> f64;
+        fn sin(x: f64) -> f64;
+        fn cos(x: f64) -> f64;
+        fn floor(x: f64) -> f64;
+        fn ceil(x: f64) -> f64;
+    }
+
+    pub trait F64Math {
+        fn sqrt(self) -> f64;
+        fn powf(self, y: f64) -> f64;
+        fn sin(self) -> f64;
+        fn cos(self) -> f64;
+        fn floor(self) -> f64;
+        fn ceil(self) -> f64;
+    }
+
+    impl F64Math for f64 {
+        fn sqrt(self) -> f64 {
+            unsafe { sqrt(self) }
+        }
+        fn powf(self, y: f64) -> f64 {
+            unsafe { pow(self, y) }
+        }
+        fn sin(self) -> f64 {
+            unsafe { sin(self) }
+        }
+        fn cos(self) -> f64 {
+            unsafe { cos(self) }
+        }
+        fn floor(self) -> f64 {
+            unsafe { floor(self) }
+        }
+        fn ceil(self) -> f64 {
+            unsafe { ceil(self) }
+        }
+    }
+}
+#[cfg(sprs_embedded)]
+use libm::F64Math;
+
+// `now!()`: monotonic milliseconds, counted from the first call on the host and from the
+// board's timer on bare metal. Only the difference between two readings means anything.
+#[cfg(not(sprs_embedded))]
+static CLOCK_START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __clock_ms() -> Word {
+    #[cfg(not(sprs_embedded))]
+    {
+        CLOCK_START
+            .get_or_init(std::time::Instant::now)
+            .elapsed()
+            .as_millis() as Word
+    }
+    #[cfg(sprs_embedded)]
+    {
+        unsafe { embedded::sprs_clock_ms() as Word }
+    }
+}
+
+// `sleep!(ms)`; zero or a negative time returns at once.
+#[unsafe(no_mangle)]
+pub extern "C" fn __sleep_ms(ms: Word) {
+    if ms <= 0 {
+        return;
+    }
+    #[cfg(not(sprs_embedded))]
+    std::thread::sleep(std::time::Duration::from_millis(ms as u64));
+    #[cfg(sprs_embedded)]
+    unsafe {
+        embedded::sprs_sleep_ms(ms as u32)
+    }
+}
+
+// `exit!(code)`. Bare-metal targets have nowhere to return to and stop like a panic does.
+#[unsafe(no_mangle)]
+pub extern "C" fn __exit(code: Word) {
+    #[cfg(not(sprs_embedded))]
+    {
+        use std::io::Write;
+        let _ = std::io::stdout().flush();
+    }
+    __alloc_report();
+    std::process::exit(code as i32)
+}
+
+// `read_line!()`: the next line of stdin without its line ending, or null at end of input.
+// Bare-metal targets have no stdin and always report end of input.
+#[unsafe(no_mangle)]
+pub extern "C" fn __read_line() -> *mut i8 {
+    #[cfg(not(sprs_embedded))]
+    {
+        let mut line = String::new();
+        match std::io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => return std::ptr::null_mut(),
+            Ok(_) => {}
+        }
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        let mut bytes = text.as_bytes().to_vec();
+        bytes.push(0);
+        Box::into_raw(bytes.into_boxed_slice()) as *mut i8
+    }
+    #[cfg(sprs_embedded)]
+    std::ptr::null_mut()
+}
+
+// The `file` package. A handle is the index of the open file plus one, so a closed or
+// made-up handle is caught instead of touching freed memory. Errors panic for now.
+#[cfg(not(sprs_embedded))]
+static FILES: std::sync::Mutex<Vec<Option<std::fs::File>>> = std::sync::Mutex::new(Vec::new());
+
+#[cfg(not(sprs_embedded))]
+fn with_file<T>(handle: Word, function: &str, f: impl FnOnce(&mut std::fs::File) -> T) -> T {
+    let mut files = FILES.lock().unwrap_or_else(|e| e.into_inner());
+    let file = usize::try_from(handle)
+        .ok()
+        .and_then(|h| h.checked_sub(1))
+        .and_then(|i| files.get_mut(i))
+        .and_then(|slot| slot.as_mut());
+    match file {
+        Some(file) => f(file),
+        None => report_panic(&format_args!(
+            "IOError: {} got a file that is not open ({})",
+            function, handle
+        )),
+    }
+}
+
+#[cfg(sprs_embedded)]
+fn file_unavailable(function: &str) -> ! {
+    report_panic(&format_args!(
+        "IOError: {} is not available on this target",
+        function
+    ))
+}
+
+// mode: "r" to read, "w" to create or truncate, "a" to append
+#[unsafe(no_mangle)]
+pub extern "C" fn __file_open(path_ptr: *const i8, mode_ptr: *const i8) -> Word {
+    #[cfg(not(sprs_embedded))]
+    {
+        let path = unsafe { std::ffi::CStr::from_ptr(path_ptr) }.to_string_lossy();
+        let mode = unsafe { std::ffi::CStr::from_ptr(mode_ptr) }.to_string_lossy();
+        let mut options = std::fs::OpenOptions::new();
+        match mode.as_ref() {
+            "r" => options.read(true),
+            "w" => options.write(true).create(true).truncate(true),
+            "a" => options.append(true).create(true),
+            _ => report_panic(&format_args!(
+                "IOError: file.open mode must be \"r\", \"w\" or \"a\", not \"{}\"",
+                mode
+            )),
+        };
+        let file = match options.open(path.as_ref()) {
+            Ok(file) => file,
+            Err(e) => report_panic(&format_args!("IOError: could not open {}: {}", path, e)),
+        };
+        let mut files = FILES.lock().unwrap_or_else(|e| e.into_inner());
+        let index = match files.iter().position(|slot| slot.is_none()) {
+            Some(index) => {
+                files[index] = Some(file);
+                index
+            }
+            None => {
+                files.push(Some(file));
+                files.len() - 1
+            }
+        };
+        index as Word + 1
+    }
+    #[cfg(sprs_embedded)]
+    {
+        let _ = (path_ptr, mode_ptr);
+        file_unavailable("file.open")
+    }
+}
+
+// The rest of the file from the current position, as a Str.
+#[unsafe(no_mangle)]
+pub extern "C" fn __file_read_all(handle: Word) -> *mut i8 {
+    #[cfg(not(sprs_embedded))]
+    {
+        use std::io::Read;
+        let mut bytes = Vec::new();
+        with_file(handle, "file.read_all", |file| {
+            if let Err(e) = file.read_to_end(&mut bytes) {
+                report_panic(&format_args!("IOError: could not read the file: {}", e));
+            }
+        });
+        bytes.push(0);
+        Box::into_raw(bytes.into_boxed_slice()) as *mut i8
+    }
+    #[cfg(sprs_embedded)]
+    {
+        let _ = handle;
+        file_unavailable("file.read_all")
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __file_write(handle: Word, text_ptr: *const i8) {
+    #[cfg(not(sprs_embedded))]
+    {
+        use std::io::Write;
+        let text = unsafe { std::ffi::CStr::from_ptr(text_ptr) };
+        with_file(handle, "file.write", |file| {
+            if let Err(e) = file.write_all(text.to_bytes()) {
+                report_panic(&format_args!("IOError: could not write the file: {}", e));
+            }
+        });
+    }
+    #[cfg(sprs_embedded)]
+    {
+        let _ = (handle, text_ptr);
+        file_unavailable("file.write")
+    }
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __file_close(handle: Word) {
+    #[cfg(not(sprs_embedded))]
+    {
+        // checks the handle first; dropping the File closes it
+        with_file(handle, "file.close", |_| {});
+        let mut files = FILES.lock().unwrap_or_else(|e| e.into_inner());
+        files[handle as usize - 1] = None;
+    }
+    #[cfg(sprs_embedded)]
+    {
+        let _ = handle;
+        file_unavailable("file.close")
+    }
+}
+
+// `rand!` / `rand_seed!`: xorshift64, so it needs neither libc nor an OS entropy source.
+// Every run starts from the same state until `rand_seed!` is called.
+const RAND_DEFAULT_SEED: u64 = 0x2545_F491_4F6C_DD1D;
+static mut RAND_STATE: u64 = RAND_DEFAULT_SEED;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __rand_seed(seed: Word) {
+    // xorshift never leaves a zero state
+    let state = if seed == 0 {
+        RAND_DEFAULT_SEED
+    } else {
+        seed as u64
+    };
+    unsafe { RAND_STATE = state };
+}
+
+// A number in `lo..hi` (hi excluded); `lo` when the range is empty.
+#[unsafe(no_mangle)]
+pub extern "C" fn __rand_range(lo: Word, hi: Word) -> Word {
+    if hi <= lo {
+        return lo;
+    }
+    let mut x = unsafe { RAND_STATE };
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    unsafe { RAND_STATE = x };
+
+    let span = (hi as i128 - lo as i128) as u64;
+    (lo as i128 + (x % span) as i128) as Word
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __malloc(size: Word) -> *mut i8 {
     let layout = std::alloc::Layout::from_size_align(size as usize, 8).unwrap();
     let ptr = unsafe { std::alloc::alloc(layout) };
+    track_alloc(ptr as RawData, size as usize, Tag::String);
     ptr as *mut i8
 }
 
+// Frees a `__malloc` allocation of `size` bytes, e.g. a struct the generated code dropped.
 #[unsafe(no_mangle)]
-pub extern "C" fn __drop(val: SprsValue) {
-    match val.tag {
-        t if t == Tag::List as i32 => {
-            let ptr = val.data as *mut Vec<SprsValue>;
-            if !ptr.is_null() {
-                unsafe {
-                    let _ = Box::from_raw(ptr);
+pub extern "C" fn __free(ptr: *mut i8, size: Word) {
+    untrack_alloc(ptr as RawData);
+    let layout = std::alloc::Layout::from_size_align(size as usize, 8).unwrap();
+    unsafe { std::alloc::dealloc(ptr as *mut u8, layout) };
+}
+
+// `--compress-strings`: unpacks an LZSS literal (see src/llvm/rodata.rs) into a heap buffer the
+// first time it is used. The buffer is cached per literal and never freed, like other strings.
+#[unsafe(no_mangle)]
+pub extern "C" fn __str_unpack(packed: *const u8, cache: *mut *mut i8) -> *mut i8 {
+    unsafe {
+        if !(*cache).is_null() {
+            return *cache;
+        }
+
+        let len = u16::from_le_bytes([*packed, *packed.add(1)]) as usize;
+        let mut out: Vec<u8> = Vec::with_capacity(len + 1);
+        let mut pos = 2;
+        while out.len() < len {
+            let flags = *packed.add(pos);
+            pos += 1;
+            for bit in 0..8 {
+                if out.len() >= len {
+                    break;
+                }
+                if flags & (1 << bit) == 0 {
+                    out.push(*packed.add(pos));
+                    pos += 1;
+                } else {
+                    let b0 = *packed.add(pos) as usize;
+                    let b1 = *packed.add(pos + 1) as usize;
+                    pos += 2;
+                    let start = out.len() - (b0 | ((b1 >> 4) << 8));
+                    for i in 0..(b1 & 0x0F) + 3 {
+                        out.push(out[start + i]);
+                    }
                 }
             }
         }
-        t if t == Tag::Range as i32 => {
-            let ptr = val.data as *mut SprsRange;
-            if !ptr.is_null() {
-                unsafe {
-                    let _ = Box::from_raw(ptr);
-                }
+        out.push(0);
+
+        let ptr = Box::into_raw(out.into_boxed_slice()) as *mut i8;
+        *cache = ptr;
+        ptr
+    }
+}
+
+// `--debug-alloc` builds the runtime with `--cfg sprs_debug_alloc`: the lists, ranges, structs
+// and `__malloc` buffers still live when the program exits are reported by `__alloc_report`.
+#[cfg(sprs_debug_alloc)]
+mod alloc_debug {
+    use super::{RawData, RawTag, tag_name};
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    // address -> (size in bytes, tag)
+    static LIVE: Mutex<BTreeMap<RawData, (usize, RawTag)>> = Mutex::new(BTreeMap::new());
+
+    pub fn track(ptr: RawData, size: usize, tag: RawTag) {
+        LIVE.lock().unwrap().insert(ptr, (size, tag));
+    }
+
+    pub fn untrack(ptr: RawData) {
+        LIVE.lock().unwrap().remove(&ptr);
+    }
+
+    pub fn is_live(ptr: RawData) -> bool {
+        LIVE.lock().unwrap().contains_key(&ptr)
+    }
+
+    pub fn report() {
+        let live = LIVE.lock().unwrap();
+        if live.is_empty() {
+            eprintln!("debug-alloc: no leaks");
+            return;
+        }
+        // tag -> (count, bytes)
+        let mut by_tag: BTreeMap<RawTag, (usize, usize)> = BTreeMap::new();
+        for (size, tag) in live.values() {
+            let entry = by_tag.entry(*tag).or_default();
+            entry.0 += 1;
+            entry.1 += size;
+        }
+        let total: usize = by_tag.values().map(|(_, bytes)| bytes).sum();
+        eprintln!(
+            "debug-alloc: {} allocations ({} bytes) still live at exit",
+            live.len(),
+            total
+        );
+        for (tag, (count, bytes)) in by_tag {
+            eprintln!("  {:<6} {:>6} {:>10} bytes", tag_name(tag), count, bytes);
+        }
+    }
+}
+
+// For the runtime's own diagnostics; `type_of!` names are generated by the compiler.
+fn tag_name(tag: RawTag) -> &'static str {
+    match tag {
+        t if t == Tag::String as RawTag => "Str",
+        t if t == Tag::List as RawTag => "List",
+        t if t == Tag::Range as RawTag => "Range",
+        t if t == Tag::Struct as RawTag => "Struct",
+        t if t == Tag::Moved as RawTag => "moved value",
+        _ => "value",
+    }
+}
+
+fn track_alloc(ptr: RawData, size: usize, tag: Tag) {
+    #[cfg(sprs_debug_alloc)]
+    alloc_debug::track(ptr, size, tag as RawTag);
+    #[cfg(not(sprs_debug_alloc))]
+    let _ = (ptr, size, tag);
+}
+
+fn untrack_alloc(ptr: RawData) {
+    #[cfg(sprs_debug_alloc)]
+    alloc_debug::untrack(ptr);
+    #[cfg(not(sprs_debug_alloc))]
+    let _ = ptr;
+}
+
+// Called by the generated code for the structs it allocates itself.
+#[unsafe(no_mangle)]
+pub extern "C" fn __alloc_track(ptr: *mut u8, size: Word, tag: RawTag) {
+    #[cfg(sprs_debug_alloc)]
+    alloc_debug::track(ptr as RawData, size as usize, tag);
+    #[cfg(not(sprs_debug_alloc))]
+    let _ = (ptr, size, tag);
+}
+
+// Called when `main` returns, and by `exit!`.
+#[unsafe(no_mangle)]
+pub extern "C" fn __alloc_report() {
+    #[cfg(sprs_debug_alloc)]
+    alloc_debug::report();
+}
+
+// Every struct allocation starts with a pointer to the `{ drop, clone }` functions the compiler
+// generated for its definition.
+#[repr(C)]
+struct SprsStructVtable {
+    drop: extern "C" fn(*mut u8),
+    clone: extern "C" fn(*mut u8) -> *mut u8,
+}
+
+fn struct_vtable(data: RawData) -> &'static SprsStructVtable {
+    unsafe { &**(data as *const *const SprsStructVtable) }
+}
+
+#[unsafe(no_mangle)]
+// Takes tag and data separately, matching the declaration in the generated code
+// (a by-value struct is passed differently on wasm32).
+pub extern "C" fn __drop(tag: RawTag, data: RawData) {
+    match tag {
+        t if t == Tag::Struct as RawTag && live_heap_ptr(tag, data, "__drop") => {
+            untrack_alloc(data);
+            (struct_vtable(data).drop)(data as *mut u8);
+        }
+        t if t == Tag::List as RawTag && live_heap_ptr(tag, data, "__drop") => {
+            untrack_alloc(data);
+            unsafe {
+                let _ = Box::from_raw(data as *mut Vec<SprsValue>);
+            }
+        }
+        t if t == Tag::Range as RawTag && live_heap_ptr(tag, data, "__drop") => {
+            untrack_alloc(data);
+            unsafe {
+                let _ = Box::from_raw(data as *mut SprsRange);
             }
         }
+        // the data now belongs to the variable it was moved into
+        t if t == Tag::Moved as RawTag => {}
+        #[cfg(sprs_debug_alloc)]
+        t if !is_known_tag(t) => report_panic(&format_args!("__drop: invalid value tag {}", t)),
+        // numbers, Unit and strings own nothing to free
         _ => {}
     }
 }
 
+fn is_known_tag(tag: RawTag) -> bool {
+    matches!(tag, 0..=10 | 100..=110)
+}
+
+// Whether the List, Range or Struct pointer in `data` may be dereferenced: null never is, and
+// with `--debug-alloc` a pointer that is not a live allocation (a double free, or a value
+// used after it was dropped) aborts with a message instead of corrupting the heap.
+fn live_heap_ptr(tag: RawTag, data: RawData, op: &str) -> bool {
+    if data == 0 {
+        return false;
+    }
+    #[cfg(sprs_debug_alloc)]
+    if !alloc_debug::is_live(data) {
+        report_panic(&format_args!(
+            "{}: {} at {:#x} was already dropped or was never allocated",
+            op,
+            tag_name(tag),
+            data
+        ));
+    }
+    #[cfg(not(sprs_debug_alloc))]
+    let _ = (tag, op);
+    true
+}
+
 #[unsafe(no_mangle)]
-pub extern "C" fn __clone(tag: i32, data: u64) -> SprsValue {
+pub extern "C" fn __clone(tag: RawTag, data: RawData) -> SprsValue {
     match tag {
-        t if t == Tag::Integer as i32 => SprsValue { tag, data },
-        t if t == Tag::Float as i32 => SprsValue { tag, data },
-        t if t == Tag::Float16 as i32 => SprsValue { tag, data },
-        t if t == Tag::Float32 as i32 => SprsValue { tag, data },
-        t if t == Tag::Float64 as i32 => SprsValue { tag, data },
-        t if t == Tag::Boolean as i32 => SprsValue { tag, data },
-        t if t == Tag::String as i32 => {
+        t if t == Tag::Integer as RawTag => SprsValue { tag, data },
+        t if t == Tag::Float as RawTag => SprsValue { tag, data },
+        t if t == Tag::Float16 as RawTag => SprsValue { tag, data },
+        t if t == Tag::Float32 as RawTag => SprsValue { tag, data },
+        t if t == Tag::Float64 as RawTag => SprsValue { tag, data },
+        t if t == Tag::Boolean as RawTag => SprsValue { tag, data },
+        // a moved value stays moved in the copy and keeps no data of its own
+        t if t == Tag::Moved as RawTag => SprsValue { tag, data: 0 },
+        t if t == Tag::String as RawTag && data != 0 => {
             let c_str = unsafe { std::ffi::CStr::from_ptr(data as *const i8) };
-            let bytes = c_str.to_bytes();
+            let bytes = c_str.to_bytes_with_nul();
             let layout = std::alloc::Layout::from_size_align(bytes.len(), 1).unwrap();
             let ptr = unsafe { std::alloc::alloc(layout) };
             unsafe {
@@ -268,10 +1428,10 @@ pub extern "C" fn __clone(tag: i32, data: u64) -> SprsValue {
             }
             SprsValue {
                 tag,
-                data: ptr as u64,
+                data: ptr as RawData,
             }
         }
-        t if t == Tag::List as i32 => {
+        t if t == Tag::List as RawTag && live_heap_ptr(tag, data, "__clone") => {
             let src_vec = unsafe { &*(data as *mut Vec<SprsValue>) };
             let mut new_vec = Vec::with_capacity(src_vec.len());
             for val in src_vec {
@@ -279,20 +1439,31 @@ pub extern "C" fn __clone(tag: i32, data: u64) -> SprsValue {
             }
             SprsValue {
                 tag,
-                data: Box::into_raw(Box::new(new_vec)) as u64,
+                data: new_list(new_vec) as RawData,
             }
         }
-        t if t == Tag::Range as i32 => {
+        t if t == Tag::Struct as RawTag && live_heap_ptr(tag, data, "__clone") => SprsValue {
+            tag,
+            data: (struct_vtable(data).clone)(data as *mut u8) as RawData,
+        },
+        t if t == Tag::Range as RawTag && live_heap_ptr(tag, data, "__clone") => {
             let src_range = unsafe { &*(data as *mut SprsRange) };
-            let new_range = Box::new(SprsRange {
-                start: src_range.start,
-                end: src_range.end,
-            });
             SprsValue {
                 tag,
-                data: Box::into_raw(new_range) as u64,
+                data: __range_new(src_range.start, src_range.end) as RawData,
             }
         }
+        t if t == Tag::String as RawTag
+            || t == Tag::List as RawTag
+            || t == Tag::Range as RawTag
+            || t == Tag::Struct as RawTag =>
+        {
+            report_panic(&format_args!(
+                "__clone: {} with a null pointer",
+                tag_name(t)
+            ))
+        }
+        t if !is_known_tag(t) => report_panic(&format_args!("__clone: invalid value tag {}", t)),
         _ => SprsValue { tag, data },
     }
 }
@@ -300,7 +1471,94 @@ pub extern "C" fn __clone(tag: i32, data: u64) -> SprsValue {
 #[unsafe(no_mangle)]
 pub extern "C" fn __panic(message_ptr: *const i8) {
     let c_str = unsafe { std::ffi::CStr::from_ptr(message_ptr) };
-    let message = c_str.to_string_lossy();
-    eprintln!("Panic: {}", message);
-    std::process::exit(1);
+    report_panic(&c_str.to_string_lossy());
+}
+
+// `--panic-codes` builds pass a 16-bit code instead of the message;
+// `sprs decode-panic` looks it up in the `<name>.panics.json` table written by the build.
+#[unsafe(no_mangle)]
+pub extern "C" fn __panic_code(code: u16) {
+    report_panic(&format_args!("code 0x{:04X}", code));
+}
+
+fn report_panic(message: &dyn core::fmt::Display) -> ! {
+    let test = unsafe { CURRENT_TEST };
+    let handler = unsafe { core::ptr::replace(&raw mut PANIC_HANDLER, None) };
+    if !test.is_null() {
+        let name = unsafe { std::ffi::CStr::from_ptr(test) }.to_string_lossy();
+        println!("SPRS-TEST FAIL {}: {}", name, message);
+    } else if let Some(handler) = handler {
+        // taken above, so a panic inside the handler is reported the default way
+        let mut text = PanicMessage {
+            buf: [0; PANIC_MESSAGE_LEN],
+            len: 0,
+        };
+        let _ = core::fmt::write(&mut text, format_args!("{}", message));
+        text.buf[text.len] = 0;
+        handler(text.buf.as_ptr() as *const i8);
+    } else {
+        eprintln!("Panic: {}", message);
+    }
+    std::process::exit(1)
+}
+
+// A `fn panic_handler(msg)` in main.sprs is installed by the generated `main` and called with
+// the message instead of printing it. The program still stops when the handler returns.
+static mut PANIC_HANDLER: Option<extern "C" fn(*const i8)> = None;
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __set_panic_handler(handler: extern "C" fn(*const i8)) {
+    unsafe { PANIC_HANDLER = Some(handler) };
+}
+
+// The message is formatted on the stack, the heap may be what ran out. Longer ones are cut.
+const PANIC_MESSAGE_LEN: usize = 128;
+
+struct PanicMessage {
+    buf: [u8; PANIC_MESSAGE_LEN],
+    len: usize,
+}
+
+impl core::fmt::Write for PanicMessage {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        // keep the last byte for the NUL, and cut at a character so the handler gets a valid Str
+        let mut n = s.len().min(PANIC_MESSAGE_LEN - 1 - self.len);
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+        self.buf[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+        // nothing more after a cut, or a shorter piece could still fit behind it
+        if n < s.len() {
+            return Err(core::fmt::Error);
+        }
+        Ok(())
+    }
+}
+
+// Test harness for `sprs test`. Results are plain lines on the console (stdout, or
+// `sprs_write` on bare metal) so the host can read them back over a serial port:
+//   SPRS-TEST BEGIN <name>
+//   SPRS-TEST PASS <name>
+//   SPRS-TEST FAIL <name>: <panic message>
+//   SPRS-TEST DONE <total>
+static mut CURRENT_TEST: *const i8 = std::ptr::null();
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __test_begin(name_ptr: *const i8) {
+    unsafe { CURRENT_TEST = name_ptr };
+    let name = unsafe { std::ffi::CStr::from_ptr(name_ptr) }.to_string_lossy();
+    println!("SPRS-TEST BEGIN {}", name);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __test_pass(name_ptr: *const i8) {
+    unsafe { CURRENT_TEST = std::ptr::null() };
+    let name = unsafe { std::ffi::CStr::from_ptr(name_ptr) }.to_string_lossy();
+    println!("SPRS-TEST PASS {}", name);
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn __test_done(total: Word) {
+    println!("SPRS-TEST DONE {}", total);
 }
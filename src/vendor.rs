@@ -0,0 +1,187 @@
+use std::path::Path;
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::command_helper::ProjectConfig;
+use crate::front::preprocess;
+use crate::llvm::build_log::BuildLog;
+
+// Git dependencies: `sprs build` clones them into vendor/<name> and pins the commit it used in
+// sprs.lock, so every later build, on any machine, gets the same code until the `git` or `rev`
+// of the dependency in sprs.toml changes.
+
+pub const VENDOR_DIR: &str = "vendor";
+const LOCK_FILE: &str = "sprs.lock";
+
+#[derive(Serialize, Deserialize, Default)]
+struct LockFile {
+    #[serde(default, rename = "package")]
+    packages: Vec<LockedPackage>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+struct LockedPackage {
+    name: String,
+    git: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rev: Option<String>, // as written in sprs.toml
+    commit: String, // what `rev` (or the default branch) resolved to
+}
+
+// The project directory of the git dependency `name`.
+pub fn vendor_dir(name: &str) -> String {
+    format!("{}/{}", VENDOR_DIR, name)
+}
+
+// Checks out the locked commit of every git dependency, cloning the ones not in vendor/ yet.
+// A dependency without a matching lock entry is fetched and its commit is added to sprs.lock.
+pub fn fetch_git_dependencies(
+    config: Option<&ProjectConfig>,
+    log: &BuildLog,
+) -> Result<(), String> {
+    let Some(config) = config else {
+        return Ok(());
+    };
+    let lock = read_lock()?;
+    let mut packages = Vec::new();
+    for (name, dependency) in &config.dependencies {
+        let Some(url) = &dependency.git else {
+            continue;
+        };
+        // the name is a directory under vendor/, and url and rev go to git's command line
+        if !preprocess::is_symbol_name(name) {
+            return Err(format!(
+                "Invalid dependency name in sprs.toml: {} (it is used in imports)",
+                name
+            ));
+        }
+        if let Some(rev) = dependency.rev.as_deref().filter(|rev| rev.starts_with('-')) {
+            return Err(format!("Dependency {}: invalid rev {}", name, rev));
+        }
+        let dir = vendor_dir(name);
+        let cloned = !Path::new(&dir).exists();
+        if cloned {
+            log.status(format_args!("Fetching {} from {}", name, url));
+            git(None, &["clone", "--quiet", "--", url, &dir])?;
+        }
+
+        let locked = lock
+            .packages
+            .iter()
+            .find(|p| p.name == *name && p.git == *url && p.rev == dependency.rev);
+        let commit = match locked {
+            Some(locked) => {
+                if head(&dir)? != locked.commit && checkout(&dir, &locked.commit).is_err() {
+                    // locked after the vendored copy was fetched
+                    git(Some(&dir), &["fetch", "--quiet", "origin"])?;
+                    checkout(&dir, &locked.commit)?;
+                }
+                locked.commit.clone()
+            }
+            None => {
+                if !cloned {
+                    log.status(format_args!("Updating {} from {}", name, url));
+                    git(Some(&dir), &["remote", "set-url", "--", "origin", url])?;
+                    git(Some(&dir), &["fetch", "--quiet", "origin"])?;
+                }
+                let target = resolve_rev(&dir, dependency.rev.as_deref())
+                    .map_err(|e| format!("Dependency {}: {}", name, e))?;
+                checkout(&dir, &target)?;
+                head(&dir)?
+            }
+        };
+        packages.push(LockedPackage {
+            name: name.clone(),
+            git: url.clone(),
+            rev: dependency.rev.clone(),
+            commit,
+        });
+    }
+
+    if packages != lock.packages {
+        write_lock(&LockFile { packages })?;
+    }
+    Ok(())
+}
+
+fn read_lock() -> Result<LockFile, String> {
+    match std::fs::read_to_string(LOCK_FILE) {
+        Ok(content) => {
+            toml::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", LOCK_FILE, e))
+        }
+        Err(_) => Ok(LockFile::default()),
+    }
+}
+
+fn write_lock(lock: &LockFile) -> Result<(), String> {
+    let content = toml::to_string_pretty(lock)
+        .map_err(|e| format!("Failed to serialize {}: {}", LOCK_FILE, e))?;
+    std::fs::write(
+        LOCK_FILE,
+        format!("# Written by `sprs build`, do not edit.\n\n{}", content),
+    )
+    .map_err(|e| format!("Failed to write {}: {}", LOCK_FILE, e))
+}
+
+// A branch is taken from the remote, so a fetched `rev = "main"` is its latest commit; tags
+// and commit hashes are used as they are. No `rev` is the default branch.
+fn resolve_rev(dir: &str, rev: Option<&str>) -> Result<String, String> {
+    let Some(rev) = rev else {
+        return Ok("origin/HEAD".to_string());
+    };
+    [format!("origin/{}", rev), rev.to_string()]
+        .into_iter()
+        .find(|target| {
+            let commit = format!("{}^{{commit}}", target);
+            git(
+                Some(dir),
+                &[
+                    "rev-parse",
+                    "--verify",
+                    "--quiet",
+                    "--end-of-options",
+                    &commit,
+                ],
+            )
+            .is_ok()
+        })
+        .ok_or_else(|| format!("no branch, tag or commit {} in the repository", rev))
+}
+
+// The trailing `--` keeps git from reading the target as a path; it does not stop an option,
+// so a target from an edited sprs.lock is checked here.
+fn checkout(dir: &str, target: &str) -> Result<(), String> {
+    if target.starts_with('-') {
+        return Err(format!("Invalid commit {} for {}", target, dir));
+    }
+    git(
+        Some(dir),
+        &["checkout", "--quiet", "--detach", target, "--"],
+    )
+    .map(|_| ())
+}
+
+fn head(dir: &str) -> Result<String, String> {
+    git(Some(dir), &["rev-parse", "HEAD"])
+}
+
+// Runs git (in `dir`) and returns what it printed, or its error output.
+fn git(dir: Option<&str>, args: &[&str]) -> Result<String, String> {
+    let mut command = Command::new("git");
+    if let Some(dir) = dir {
+        command.arg("-C").arg(dir);
+    }
+    let output = command
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}